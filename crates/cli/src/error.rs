@@ -0,0 +1,122 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use std::fmt;
+use techalib::errors::TechalibError;
+
+/// Errors reported by the command-line tool
+#[derive(Debug)]
+pub enum CliError {
+    /// The input or the output file could not be read or written.
+    Io(std::io::Error),
+    /// The CSV input is malformed.
+    Csv(csv::Error),
+    /// The Parquet input is malformed.
+    #[cfg(feature = "parquet")]
+    Parquet(parquet::errors::ParquetError),
+    /// An indicator specification cannot be parsed.
+    Spec(String),
+    /// A column needed by an indicator is missing from the input.
+    MissingColumn(String),
+    /// A cell of a column needed by an indicator is not a number.
+    InvalidNumber {
+        /// The name of the column.
+        column: String,
+        /// The index of the row, the header excluded.
+        row: usize,
+        /// The content of the cell.
+        value: String,
+    },
+    /// An indicator failed on the input data.
+    Indicator {
+        /// The specification of the indicator, as given on the command line.
+        spec: String,
+        /// The error returned by the indicator.
+        error: TechalibError,
+    },
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Io(e) => write!(f, "{e}"),
+            CliError::Csv(e) => write!(f, "invalid CSV: {e}"),
+            #[cfg(feature = "parquet")]
+            CliError::Parquet(e) => write!(f, "invalid Parquet: {e}"),
+            CliError::Spec(message) => write!(f, "invalid indicator: {message}"),
+            CliError::MissingColumn(column) => write!(f, "column '{column}' not found"),
+            CliError::InvalidNumber { column, row, value } => {
+                write!(f, "column '{column}', row {row}: '{value}' is not a number")
+            }
+            CliError::Indicator { spec, error } => write!(f, "{spec}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl CliError {
+    /// Whether the output was closed by the reader, e.g. when piped to `head`
+    pub fn is_broken_pipe(&self) -> bool {
+        let io = match self {
+            CliError::Io(e) => Some(e),
+            CliError::Csv(e) => match e.kind() {
+                csv::ErrorKind::Io(e) => Some(e),
+                _ => None,
+            },
+            _ => None,
+        };
+        io.is_some_and(|e| e.kind() == std::io::ErrorKind::BrokenPipe)
+    }
+}
+
+impl From<std::io::Error> for CliError {
+    fn from(e: std::io::Error) -> Self {
+        CliError::Io(e)
+    }
+}
+
+impl From<csv::Error> for CliError {
+    fn from(e: csv::Error) -> Self {
+        CliError::Csv(e)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for CliError {
+    fn from(e: parquet::errors::ParquetError) -> Self {
+        CliError::Parquet(e)
+    }
+}
@@ -0,0 +1,421 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use crate::error::CliError;
+use crate::spec::{IndicatorSpec, Params};
+use crate::table::{Column, Table};
+use std::collections::HashMap;
+use techalib::errors::TechalibError;
+use techalib::indicators::{
+    alma::alma,
+    atr::atr,
+    bbands::{bbands, BBandsMA, DeviationMulipliers},
+    chandelier_exit::chandelier_exit,
+    cmf::cmf,
+    dema::dema,
+    donchian::donchian,
+    elder_ray::elder_ray,
+    ema::ema,
+    envelope::envelope,
+    eom::eom,
+    force_index::force_index,
+    hma::hma,
+    kama::kama,
+    keltner::keltner,
+    ma::MaType,
+    macd::macd,
+    max::max,
+    min::min,
+    price_transform::{avgprice, medprice, typprice, wclprice},
+    rsi::rsi,
+    sma::sma,
+    smma::smma,
+    stats::{
+        kurtosis::kurtosis, median::median, percent_rank::percent_rank, skew::skew, zscore::zscore,
+    },
+    sum::sum,
+    supertrend::supertrend,
+    t3::t3,
+    tema::tema,
+    trima::trima,
+    vidya::vidya,
+    wma::wma,
+    zlema::zlema,
+};
+use techalib::types::{Float, Ohlcv};
+
+/// The indicators available on the command line
+/// ---
+/// Each entry holds the name, the parameters with their default value, the
+/// suffixes of the output columns and a short description.
+pub const INDICATORS: &[(&str, &str, &str, &str)] = &[
+    ("sma", "period=14", "", "Simple Moving Average"),
+    ("ema", "period=14", "", "Exponential Moving Average"),
+    ("wma", "period=14", "", "Weighted Moving Average"),
+    ("dema", "period=14", "", "Double Exponential Moving Average"),
+    ("tema", "period=14", "", "Triple Exponential Moving Average"),
+    ("trima", "period=14", "", "Triangular Moving Average"),
+    (
+        "t3",
+        "period=5,vfactor=0.7",
+        "",
+        "Tillson Triple Moving Average",
+    ),
+    ("kama", "period=30", "", "Kaufman Adaptive Moving Average"),
+    ("hma", "period=16", "", "Hull Moving Average"),
+    (
+        "zlema",
+        "period=14",
+        "",
+        "Zero-Lag Exponential Moving Average",
+    ),
+    (
+        "alma",
+        "period=9,offset=0.85,sigma=6",
+        "",
+        "Arnaud Legoux Moving Average",
+    ),
+    ("smma", "period=14", "", "Smoothed Moving Average"),
+    (
+        "vidya",
+        "period=14,cmo_period=9",
+        "",
+        "Variable Index Dynamic Average",
+    ),
+    (
+        "bbands",
+        "period=20,std_up=2,std_down=2",
+        "upper,middle,lower",
+        "Bollinger Bands",
+    ),
+    (
+        "envelope",
+        "period=20,percent=2.5",
+        "upper,middle,lower",
+        "Moving Average Envelope",
+    ),
+    (
+        "macd",
+        "fast=12,slow=26,signal=9",
+        "macd,signal,histogram",
+        "Moving Average Convergence Divergence",
+    ),
+    ("rsi", "period=14", "", "Relative Strength Index"),
+    ("max", "period=30", "", "Highest value over a period"),
+    ("min", "period=30", "", "Lowest value over a period"),
+    ("sum", "period=30", "", "Summation over a period"),
+    ("zscore", "period=20", "", "Rolling Z-Score"),
+    ("percent_rank", "period=20", "", "Rolling Percent Rank"),
+    ("skew", "period=20", "", "Rolling Skewness"),
+    ("kurtosis", "period=20", "", "Rolling Excess Kurtosis"),
+    ("median", "period=20", "", "Rolling Median"),
+    ("atr", "period=14", "", "Average True Range"),
+    (
+        "donchian",
+        "period=20",
+        "upper,middle,lower",
+        "Donchian Channels",
+    ),
+    (
+        "keltner",
+        "period=20,atr_period=10,multiplier=2",
+        "upper,middle,lower",
+        "Keltner Channels",
+    ),
+    (
+        "supertrend",
+        "period=10,multiplier=3",
+        "supertrend,direction",
+        "SuperTrend",
+    ),
+    (
+        "chandelier_exit",
+        "period=22,multiplier=3",
+        "long_stop,short_stop",
+        "Chandelier Exit",
+    ),
+    ("cmf", "period=20", "", "Chaikin Money Flow"),
+    ("force_index", "period=13", "", "Force Index"),
+    ("eom", "period=14,scale=10000", "", "Ease of Movement"),
+    (
+        "elder_ray",
+        "period=13",
+        "bull_power,bear_power",
+        "Elder Ray Index",
+    ),
+    ("avgprice", "", "", "Average Price"),
+    ("medprice", "", "", "Median Price"),
+    ("typprice", "", "", "Typical Price"),
+    ("wclprice", "", "", "Weighted Close Price"),
+];
+
+/// The input columns of the indicators
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Field {
+    Open,
+    High,
+    Low,
+    Close,
+    Volume,
+    /// The column read by the single-series indicators.
+    Price,
+}
+
+/// The names of the input columns
+#[derive(Debug, Clone)]
+pub struct ColumnNames {
+    /// The open prices.
+    pub open: String,
+    /// The high prices.
+    pub high: String,
+    /// The low prices.
+    pub low: String,
+    /// The close prices.
+    pub close: String,
+    /// The volumes.
+    pub volume: String,
+    /// The column read by the single-series indicators.
+    pub price: String,
+}
+
+/// The input columns, parsed the first time an indicator needs them
+pub(crate) struct Inputs<'a> {
+    table: &'a Table,
+    names: &'a ColumnNames,
+    columns: HashMap<usize, Vec<Float>>,
+}
+
+impl<'a> Inputs<'a> {
+    pub(crate) fn new(table: &'a Table, names: &'a ColumnNames) -> Self {
+        Inputs {
+            table,
+            names,
+            columns: HashMap::new(),
+        }
+    }
+
+    fn load(&mut self, field: Field) -> Result<usize, CliError> {
+        let name = match field {
+            Field::Open => &self.names.open,
+            Field::High => &self.names.high,
+            Field::Low => &self.names.low,
+            Field::Close => &self.names.close,
+            Field::Volume => &self.names.volume,
+            Field::Price => &self.names.price,
+        };
+        let idx = self
+            .table
+            .find(name)
+            .ok_or_else(|| CliError::MissingColumn(name.clone()))?;
+        if !self.columns.contains_key(&idx) {
+            self.columns.insert(idx, self.table.parse_column(idx)?);
+        }
+        Ok(idx)
+    }
+
+    /// The column read by the single-series indicators
+    fn price(&mut self) -> Result<&[Float], CliError> {
+        let idx = self.load(Field::Price)?;
+        Ok(&self.columns[&idx])
+    }
+
+    /// The OHLCV view holding the `fields` columns, the other ones being empty
+    fn ohlcv(&mut self, fields: &[Field]) -> Result<Ohlcv<'_>, CliError> {
+        let idx = fields
+            .iter()
+            .map(|&field| Ok((field, self.load(field)?)))
+            .collect::<Result<Vec<_>, CliError>>()?;
+        let mut ohlcv = Ohlcv::default();
+        for (field, idx) in idx {
+            let column = self.columns[&idx].as_slice();
+            match field {
+                Field::Open => ohlcv.open = column,
+                Field::High => ohlcv.high = column,
+                Field::Low => ohlcv.low = column,
+                Field::Close | Field::Price => ohlcv.close = column,
+                Field::Volume => ohlcv.volume = column,
+            }
+        }
+        Ok(ohlcv)
+    }
+}
+
+type Outputs = Vec<(&'static str, Vec<Float>)>;
+
+fn single(values: Vec<Float>) -> Outputs {
+    vec![("", values)]
+}
+
+/// Calculates the columns of the indicator `spec`
+/// ---
+/// The columns are named after the prefix of the spec, followed by the name
+/// of the output for the indicators with several outputs, e.g. `bbands_20_2_2_upper`.
+pub(crate) fn compute(spec: &IndicatorSpec, inputs: &mut Inputs) -> Result<Vec<Column>, CliError> {
+    use Field::{Close, High, Low, Open, Volume};
+
+    let mut params = Params::new(spec);
+    let p = &mut params;
+    let outputs: Result<Outputs, TechalibError> = match spec.name.as_str() {
+        "sma" => sma(inputs.price()?, p.usize("period", 14)?).map(|r| single(r.values)),
+        "ema" => ema(inputs.price()?, p.usize("period", 14)?, None).map(|r| single(r.values)),
+        "wma" => wma(inputs.price()?, p.usize("period", 14)?).map(|r| single(r.values)),
+        "dema" => dema(inputs.price()?, p.usize("period", 14)?, None).map(|r| single(r.values)),
+        "tema" => tema(inputs.price()?, p.usize("period", 14)?, None).map(|r| single(r.values)),
+        "trima" => trima(inputs.price()?, p.usize("period", 14)?).map(|r| single(r.values)),
+        "t3" => {
+            let (period, vfactor) = (p.usize("period", 5)?, p.float("vfactor", 0.7)?);
+            t3(inputs.price()?, period, vfactor, None).map(|r| single(r.values))
+        }
+        "kama" => kama(inputs.price()?, p.usize("period", 30)?).map(|r| single(r.values)),
+        "hma" => hma(inputs.price()?, p.usize("period", 16)?).map(|r| single(r.values)),
+        "zlema" => zlema(inputs.price()?, p.usize("period", 14)?, None).map(|r| single(r.values)),
+        "alma" => {
+            let period = p.usize("period", 9)?;
+            let (offset, sigma) = (p.float("offset", 0.85)?, p.float("sigma", 6.0)?);
+            alma(inputs.price()?, period, offset, sigma).map(|r| single(r.values))
+        }
+        "smma" => smma(inputs.price()?, p.usize("period", 14)?).map(|r| single(r.values)),
+        "vidya" => {
+            let (period, cmo_period) = (p.usize("period", 14)?, p.usize("cmo_period", 9)?);
+            vidya(inputs.price()?, period, cmo_period).map(|r| single(r.values))
+        }
+        "bbands" => {
+            let period = p.usize("period", 20)?;
+            let std_dev_mul = DeviationMulipliers {
+                up: p.float("std_up", 2.0)?,
+                down: p.float("std_down", 2.0)?,
+            };
+            bbands(inputs.price()?, period, std_dev_mul, BBandsMA::SMA)
+                .map(|r| vec![("upper", r.upper), ("middle", r.middle), ("lower", r.lower)])
+        }
+        "envelope" => {
+            let (period, percent) = (p.usize("period", 20)?, p.float("percent", 2.5)?);
+            envelope(inputs.price()?, period, percent, MaType::SMA)
+                .map(|r| vec![("upper", r.upper), ("middle", r.middle), ("lower", r.lower)])
+        }
+        "macd" => {
+            let fast = p.usize("fast period", 12)?;
+            let slow = p.usize("slow period", 26)?;
+            let signal = p.usize("signal period", 9)?;
+            macd(inputs.price()?, fast, slow, signal).map(|r| {
+                vec![
+                    ("macd", r.macd),
+                    ("signal", r.signal),
+                    ("histogram", r.histogram),
+                ]
+            })
+        }
+        "rsi" => rsi(inputs.price()?, p.usize("period", 14)?).map(|r| single(r.values)),
+        "max" => max(inputs.price()?, p.usize("period", 30)?).map(|r| single(r.values)),
+        "min" => min(inputs.price()?, p.usize("period", 30)?).map(|r| single(r.values)),
+        "sum" => sum(inputs.price()?, p.usize("period", 30)?).map(|r| single(r.values)),
+        "zscore" => zscore(inputs.price()?, p.usize("period", 20)?).map(|r| single(r.values)),
+        "percent_rank" => {
+            percent_rank(inputs.price()?, p.usize("period", 20)?).map(|r| single(r.values))
+        }
+        "skew" => skew(inputs.price()?, p.usize("period", 20)?).map(|r| single(r.values)),
+        "kurtosis" => kurtosis(inputs.price()?, p.usize("period", 20)?).map(|r| single(r.values)),
+        "median" => median(inputs.price()?, p.usize("period", 20)?).map(|r| single(r.values)),
+        "atr" => {
+            let period = p.usize("period", 14)?;
+            atr(&inputs.ohlcv(&[High, Low, Close])?, period).map(|r| single(r.values))
+        }
+        "donchian" => {
+            let period = p.usize("period", 20)?;
+            donchian(&inputs.ohlcv(&[High, Low])?, period)
+                .map(|r| vec![("upper", r.upper), ("middle", r.middle), ("lower", r.lower)])
+        }
+        "keltner" => {
+            let (period, atr_period) = (p.usize("period", 20)?, p.usize("atr period", 10)?);
+            let multiplier = p.float("multiplier", 2.0)?;
+            let ohlcv = inputs.ohlcv(&[High, Low, Close])?;
+            keltner(&ohlcv, period, atr_period, multiplier)
+                .map(|r| vec![("upper", r.upper), ("middle", r.middle), ("lower", r.lower)])
+        }
+        "supertrend" => {
+            let (period, multiplier) = (p.usize("period", 10)?, p.float("multiplier", 3.0)?);
+            supertrend(&inputs.ohlcv(&[High, Low, Close])?, period, multiplier)
+                .map(|r| vec![("supertrend", r.supertrend), ("direction", r.direction)])
+        }
+        "chandelier_exit" => {
+            let (period, multiplier) = (p.usize("period", 22)?, p.float("multiplier", 3.0)?);
+            chandelier_exit(&inputs.ohlcv(&[High, Low, Close])?, period, multiplier)
+                .map(|r| vec![("long_stop", r.long_stop), ("short_stop", r.short_stop)])
+        }
+        "cmf" => {
+            let period = p.usize("period", 20)?;
+            cmf(&inputs.ohlcv(&[High, Low, Close, Volume])?, period).map(|r| single(r.values))
+        }
+        "force_index" => {
+            let period = p.usize("period", 13)?;
+            force_index(&inputs.ohlcv(&[Close, Volume])?, period).map(|r| single(r.values))
+        }
+        "eom" => {
+            let (period, scale) = (p.usize("period", 14)?, p.float("scale", 10_000.0)?);
+            eom(&inputs.ohlcv(&[High, Low, Volume])?, period, scale).map(|r| single(r.values))
+        }
+        "elder_ray" => {
+            let period = p.usize("period", 13)?;
+            elder_ray(&inputs.ohlcv(&[High, Low, Close])?, period)
+                .map(|r| vec![("bull_power", r.bull_power), ("bear_power", r.bear_power)])
+        }
+        "avgprice" => avgprice(&inputs.ohlcv(&[Open, High, Low, Close])?).map(single),
+        "medprice" => medprice(&inputs.ohlcv(&[High, Low])?).map(single),
+        "typprice" => typprice(&inputs.ohlcv(&[High, Low, Close])?).map(single),
+        "wclprice" => wclprice(&inputs.ohlcv(&[High, Low, Close])?).map(single),
+        name => {
+            return Err(CliError::Spec(format!(
+                "unknown indicator '{name}', see --list"
+            )))
+        }
+    };
+    let outputs = outputs.map_err(|error| CliError::Indicator {
+        spec: spec.to_string(),
+        error,
+    })?;
+
+    let prefix = params.prefix()?;
+    Ok(outputs
+        .into_iter()
+        .map(|(suffix, values)| Column {
+            name: if suffix.is_empty() {
+                prefix.clone()
+            } else {
+                format!("{prefix}_{suffix}")
+            },
+            values,
+        })
+        .collect())
+}
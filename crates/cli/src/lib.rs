@@ -0,0 +1,277 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+#![warn(missing_docs)]
+//! The `techalib` command-line tool
+//! ---
+//! It reads a CSV or a Parquet table holding OHLCV columns, calculates a list of
+//! indicators over it and writes the table augmented with one column per indicator
+//! output as CSV, e.g.
+//!
+//! ```text
+//! techalib prices.csv --ind ema:20 --ind bbands:20,2,2 -o out.csv
+//! ```
+//!
+//! The columns are named after the indicator and its parameters, defaults
+//! included (`ema_20`, `bbands_20_2_2_upper`), unless an alias is given
+//! (`--ind ema:20=fast`). The warm-up period is written as an empty cell, or as
+//! the text given by `--na`, and `--drop-na` drops those rows.
+
+/// This module contains the errors reported by the command-line tool.
+pub mod error;
+
+/// This module contains the parsing of the `name[:p1,p2,...][=alias]`
+/// indicator specifications.
+pub mod spec;
+
+/// This module contains the table read from the input and written back with
+/// the calculated columns.
+pub mod table;
+
+/// This module contains the indicators available on the command line.
+pub mod indicators;
+
+use crate::error::CliError;
+use crate::indicators::{compute, ColumnNames, Inputs, INDICATORS};
+use crate::spec::IndicatorSpec;
+use crate::table::{Column, Table, WriteOptions};
+use clap::{Args as ClapArgs, Parser, ValueEnum};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+/// The format of the input file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Comma-separated values, with a header row.
+    Csv,
+    /// Apache Parquet, when built with the `parquet` feature.
+    Parquet,
+}
+
+/// The names of the input columns, matched case-insensitively
+#[derive(Debug, Clone, ClapArgs)]
+pub struct ColumnArgs {
+    /// Column of the open prices
+    #[arg(long, default_value = "open", value_name = "COLUMN")]
+    pub open: String,
+    /// Column of the high prices
+    #[arg(long, default_value = "high", value_name = "COLUMN")]
+    pub high: String,
+    /// Column of the low prices
+    #[arg(long, default_value = "low", value_name = "COLUMN")]
+    pub low: String,
+    /// Column of the close prices
+    #[arg(long, default_value = "close", value_name = "COLUMN")]
+    pub close: String,
+    /// Column of the volumes
+    #[arg(long, default_value = "volume", value_name = "COLUMN")]
+    pub volume: String,
+    /// Column read by the single-series indicators (sma, ema, rsi, bbands, ...)
+    #[arg(long, value_name = "COLUMN")]
+    pub price: Option<String>,
+}
+
+/// Calculates technical indicators over a CSV or Parquet file
+#[derive(Debug, Clone, Parser)]
+#[command(name = "techalib", version)]
+pub struct Args {
+    /// Input file, read as CSV from stdin when omitted or `-`
+    pub input: Option<PathBuf>,
+
+    /// Indicator to calculate, as `name[:p1,p2,...][=alias]` (repeatable)
+    #[arg(
+        short = 'i',
+        long = "ind",
+        value_name = "SPEC",
+        required_unless_present = "list"
+    )]
+    pub indicators: Vec<IndicatorSpec>,
+
+    /// Output CSV file, stdout when omitted or `-`
+    #[arg(short, long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// Format of the input, guessed from its extension when omitted
+    #[arg(short, long, value_enum)]
+    pub format: Option<Format>,
+
+    /// Field delimiter of the CSV input and output
+    #[arg(short, long, default_value_t = ',')]
+    pub delimiter: char,
+
+    /// Text written in place of the values of the warm-up period
+    #[arg(long, default_value = "", value_name = "TEXT")]
+    pub na: String,
+
+    /// Drop the rows where a calculated column has no value
+    #[arg(long)]
+    pub drop_na: bool,
+
+    /// Number of decimals of the calculated values
+    #[arg(short, long, value_name = "DIGITS")]
+    pub precision: Option<usize>,
+
+    /// The names of the input columns.
+    #[command(flatten)]
+    pub columns: ColumnArgs,
+
+    /// List the available indicators and exit
+    #[arg(long)]
+    pub list: bool,
+}
+
+impl Args {
+    fn delimiter(&self) -> Result<u8, CliError> {
+        u8::try_from(self.delimiter)
+            .ok()
+            .filter(u8::is_ascii)
+            .ok_or_else(|| {
+                CliError::Spec(format!("'{}' is not an ASCII delimiter", self.delimiter))
+            })
+    }
+
+    fn format(&self) -> Format {
+        self.format.unwrap_or_else(|| {
+            match self
+                .input
+                .as_ref()
+                .and_then(|path| path.extension())
+                .and_then(|extension| extension.to_str())
+            {
+                Some(extension)
+                    if extension.eq_ignore_ascii_case("parquet")
+                        || extension.eq_ignore_ascii_case("pq") =>
+                {
+                    Format::Parquet
+                }
+                _ => Format::Csv,
+            }
+        })
+    }
+
+    fn column_names(&self) -> ColumnNames {
+        let columns = &self.columns;
+        ColumnNames {
+            open: columns.open.clone(),
+            high: columns.high.clone(),
+            low: columns.low.clone(),
+            close: columns.close.clone(),
+            volume: columns.volume.clone(),
+            price: columns
+                .price
+                .clone()
+                .unwrap_or_else(|| columns.close.clone()),
+        }
+    }
+}
+
+/// Reads the input table described by `args`
+pub fn read_input(args: &Args) -> Result<Table, CliError> {
+    let path = args.input.as_ref().filter(|path| path.as_os_str() != "-");
+    match (args.format(), path) {
+        (Format::Csv, Some(path)) => Table::read_csv(File::open(path)?, args.delimiter()?),
+        (Format::Csv, None) => Table::read_csv(io::stdin().lock(), args.delimiter()?),
+        #[cfg(feature = "parquet")]
+        (Format::Parquet, Some(path)) => Table::read_parquet(path),
+        #[cfg(feature = "parquet")]
+        (Format::Parquet, None) => Err(CliError::Spec(
+            "Parquet input must be read from a file".to_string(),
+        )),
+        #[cfg(not(feature = "parquet"))]
+        (Format::Parquet, _) => Err(CliError::Spec(
+            "this build does not support Parquet, enable the `parquet` feature".to_string(),
+        )),
+    }
+}
+
+/// Calculates the columns of every indicator of `args` over `table`
+/// ---
+/// It fails if two columns have the same name, or the name of an input column.
+pub fn compute_columns(table: &Table, args: &Args) -> Result<Vec<Column>, CliError> {
+    let names = args.column_names();
+    let mut inputs = Inputs::new(table, &names);
+    let mut columns = Vec::new();
+    for spec in &args.indicators {
+        columns.extend(compute(spec, &mut inputs)?);
+    }
+
+    let mut seen: HashSet<&str> = table.headers.iter().map(String::as_str).collect();
+    for column in &columns {
+        if !seen.insert(&column.name) {
+            return Err(CliError::Spec(format!(
+                "column '{}' already exists, give the indicator an alias with `=name`",
+                column.name
+            )));
+        }
+    }
+    Ok(columns)
+}
+
+/// Runs the command line described by `args`
+pub fn run(args: &Args) -> Result<(), CliError> {
+    if args.list {
+        return list(io::stdout().lock());
+    }
+
+    let table = read_input(args)?;
+    let columns = compute_columns(&table, args)?;
+    let options = WriteOptions {
+        delimiter: args.delimiter()?,
+        na: args.na.clone(),
+        drop_na: args.drop_na,
+        precision: args.precision,
+    };
+    match args.output.as_ref().filter(|path| path.as_os_str() != "-") {
+        Some(path) => table.write_csv(BufWriter::new(File::create(path)?), &columns, &options),
+        None => table.write_csv(BufWriter::new(io::stdout().lock()), &columns, &options),
+    }
+}
+
+/// Writes the table of the available indicators
+fn list<W: Write>(mut writer: W) -> Result<(), CliError> {
+    writeln!(
+        writer,
+        "{:<16} {:<38} {:<24} DESCRIPTION",
+        "NAME", "PARAMETERS", "OUTPUTS"
+    )?;
+    for (name, params, outputs, description) in INDICATORS {
+        writeln!(
+            writer,
+            "{name:<16} {params:<38} {outputs:<24} {description}"
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,165 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use crate::error::CliError;
+use std::fmt;
+use std::str::FromStr;
+use techalib::types::Float;
+
+/// An indicator requested on the command line
+/// ---
+/// It is written `name[:p1,p2,...][=alias]`, e.g. `ema:20` or `bbands:20,2,2=bb`.
+/// The omitted trailing parameters take their default value.
+///
+/// Attributes
+/// ---
+/// - `name`: The name of the indicator.
+/// - `params`: The positional parameters, as written.
+/// - `alias`: The prefix of the output columns, replacing the generated one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicatorSpec {
+    /// The name of the indicator.
+    pub name: String,
+    /// The positional parameters, as written.
+    pub params: Vec<String>,
+    /// The prefix of the output columns, replacing the generated one.
+    pub alias: Option<String>,
+}
+
+impl FromStr for IndicatorSpec {
+    type Err = CliError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let (spec, alias) = match spec.split_once('=') {
+            Some((spec, alias)) if alias.trim().is_empty() => {
+                return Err(CliError::Spec(format!("'{spec}=': the alias is empty")));
+            }
+            Some((spec, alias)) => (spec, Some(alias.trim().to_string())),
+            None => (spec, None),
+        };
+        let (name, params) = match spec.split_once(':') {
+            Some((name, params)) => (
+                name,
+                params.split(',').map(|p| p.trim().to_string()).collect(),
+            ),
+            None => (spec, Vec::new()),
+        };
+        let name = name.trim().to_lowercase();
+        if name.is_empty() {
+            return Err(CliError::Spec(format!("'{spec}': the name is empty")));
+        }
+        Ok(IndicatorSpec {
+            name,
+            params,
+            alias,
+        })
+    }
+}
+
+impl fmt::Display for IndicatorSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.params.is_empty() {
+            write!(f, ":{}", self.params.join(","))?;
+        }
+        if let Some(alias) = &self.alias {
+            write!(f, "={alias}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the parameters of an [`IndicatorSpec`] in order
+/// ---
+/// Every parameter is read with its default value, and the resolved values
+/// are kept to name the output columns, so that `ema` and `ema:14` give the
+/// same `ema_14` column.
+pub(crate) struct Params<'a> {
+    spec: &'a IndicatorSpec,
+    resolved: Vec<String>,
+}
+
+impl<'a> Params<'a> {
+    pub(crate) fn new(spec: &'a IndicatorSpec) -> Self {
+        Params {
+            spec,
+            resolved: Vec::new(),
+        }
+    }
+
+    fn next<V: FromStr + ToString>(&mut self, name: &str, default: V) -> Result<V, CliError> {
+        let value = match self.spec.params.get(self.resolved.len()) {
+            Some(text) if !text.is_empty() => text.parse().map_err(|_| {
+                CliError::Spec(format!("{}: '{text}' is not a valid {name}", self.spec))
+            })?,
+            _ => default,
+        };
+        self.resolved.push(value.to_string());
+        Ok(value)
+    }
+
+    /// The next parameter, as a period or a count
+    pub(crate) fn usize(&mut self, name: &str, default: usize) -> Result<usize, CliError> {
+        self.next(name, default)
+    }
+
+    /// The next parameter, as a float
+    pub(crate) fn float(&mut self, name: &str, default: Float) -> Result<Float, CliError> {
+        self.next(name, default)
+    }
+
+    /// The prefix of the output columns
+    /// ---
+    /// It is the alias when given, otherwise the name followed by the resolved
+    /// parameters, e.g. `bbands_20_2_2`. It fails if more parameters were given
+    /// than the indicator takes.
+    pub(crate) fn prefix(self) -> Result<String, CliError> {
+        if self.spec.params.len() > self.resolved.len() {
+            return Err(CliError::Spec(format!(
+                "{}: takes at most {} parameters, got {}",
+                self.spec,
+                self.resolved.len(),
+                self.spec.params.len()
+            )));
+        }
+        Ok(match &self.spec.alias {
+            Some(alias) => alias.clone(),
+            None => std::iter::once(self.spec.name.clone())
+                .chain(self.resolved)
+                .collect::<Vec<_>>()
+                .join("_"),
+        })
+    }
+}
@@ -0,0 +1,186 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use crate::error::CliError;
+use csv::{ReaderBuilder, StringRecord, WriterBuilder};
+use std::io::{Read, Write};
+use techalib::types::Float;
+
+/// A table read from the input file, kept as text
+/// ---
+/// The cells are written back unchanged, only the columns used by the
+/// indicators are parsed.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    /// The names of the columns.
+    pub headers: Vec<String>,
+    /// The rows, with one cell per column.
+    pub rows: Vec<StringRecord>,
+}
+
+/// A column calculated by an indicator
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// The name of the column.
+    pub name: String,
+    /// The values, `NaN` during the warm-up period.
+    pub values: Vec<Float>,
+}
+
+/// How the augmented table is written
+#[derive(Debug, Clone)]
+pub struct WriteOptions {
+    /// The field delimiter.
+    pub delimiter: u8,
+    /// The text written in place of `NaN`.
+    pub na: String,
+    /// Whether the rows holding a `NaN` in a calculated column are dropped.
+    pub drop_na: bool,
+    /// The number of decimals of the calculated values, all of them when `None`.
+    pub precision: Option<usize>,
+}
+
+impl Table {
+    /// Reads a CSV table with a header row
+    pub fn read_csv<R: Read>(reader: R, delimiter: u8) -> Result<Self, CliError> {
+        let mut reader = ReaderBuilder::new()
+            .delimiter(delimiter)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let headers = reader.headers()?.iter().map(str::to_string).collect();
+        let rows = reader.records().collect::<Result<_, _>>()?;
+        Ok(Table { headers, rows })
+    }
+
+    /// Reads the rows of a Parquet file
+    /// ---
+    /// Every cell is converted to its text representation, nulls being empty.
+    #[cfg(feature = "parquet")]
+    pub fn read_parquet(path: &std::path::Path) -> Result<Self, CliError> {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+        use parquet::record::Field;
+
+        let reader = SerializedFileReader::new(std::fs::File::open(path)?)?;
+        let headers = reader
+            .metadata()
+            .file_metadata()
+            .schema()
+            .get_fields()
+            .iter()
+            .map(|field| field.name().to_string())
+            .collect();
+        let rows = reader
+            .get_row_iter(None)?
+            .map(|row| {
+                Ok(row?
+                    .get_column_iter()
+                    .map(|(_, field)| match field {
+                        Field::Null => String::new(),
+                        Field::Str(value) => value.clone(),
+                        field => field.to_string(),
+                    })
+                    .collect())
+            })
+            .collect::<Result<_, CliError>>()?;
+        Ok(Table { headers, rows })
+    }
+
+    /// The index of the column `name`
+    /// ---
+    /// An exact match is preferred, otherwise the case is ignored.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.headers
+            .iter()
+            .position(|header| header == name)
+            .or_else(|| {
+                self.headers
+                    .iter()
+                    .position(|header| header.eq_ignore_ascii_case(name))
+            })
+    }
+
+    /// Parses the column at `idx` as numbers
+    pub fn parse_column(&self, idx: usize) -> Result<Vec<Float>, CliError> {
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(row, record)| {
+                let value = record.get(idx).unwrap_or_default();
+                value.parse().map_err(|_| CliError::InvalidNumber {
+                    column: self.headers[idx].clone(),
+                    row,
+                    value: value.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Writes the table followed by the calculated `columns` as CSV
+    pub fn write_csv<W: Write>(
+        &self,
+        writer: W,
+        columns: &[Column],
+        options: &WriteOptions,
+    ) -> Result<(), CliError> {
+        let mut writer = WriterBuilder::new()
+            .delimiter(options.delimiter)
+            .from_writer(writer);
+        writer.write_record(
+            self.headers
+                .iter()
+                .map(String::as_str)
+                .chain(columns.iter().map(|column| column.name.as_str())),
+        )?;
+
+        let mut cells = Vec::with_capacity(columns.len());
+        for (row, record) in self.rows.iter().enumerate() {
+            let values = columns.iter().map(|column| column.values[row]);
+            if options.drop_na && values.clone().any(Float::is_nan) {
+                continue;
+            }
+            cells.clear();
+            cells.extend(
+                values.map(|value| match (value.is_nan(), options.precision) {
+                    (true, _) => options.na.clone(),
+                    (false, Some(precision)) => format!("{value:.precision$}"),
+                    (false, None) => value.to_string(),
+                }),
+            );
+            writer.write_record(record.iter().chain(cells.iter().map(String::as_str)))?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
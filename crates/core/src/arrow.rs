@@ -0,0 +1,328 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Indicators over Arrow arrays
+//! ---
+//! The functions of this module take a `Float64Array` or a `Float32Array` and
+//! return Arrow arrays of the same type, so that columns coming from Arrow, Polars
+//! or Parquet are processed without going through an intermediate `Vec`.
+//!
+//! Null handling
+//! ---
+//! - When the input has no null, its values are read in place.
+//! - Otherwise the indicator runs over the valid values only, as if the null rows
+//!   were not part of the series, and the results are written back at their rows.
+//! - Null input rows, as well as the `NaN` of the warm-up period, are null in the
+//!   outputs. This follows the Arrow convention of marking missing values with the
+//!   validity bitmap, where the slice functions return `NaN`. The values buffer is
+//!   left untouched, so `PrimitiveArray::values` still gives the `NaN` of the slice
+//!   functions for the rows where the input is valid.
+//!
+//! The returned state is the same as the one of the slice functions and follows
+//! the last valid value.
+
+use crate::errors::TechalibError;
+use crate::indicators::alma::{alma_into, AlmaState};
+use crate::indicators::bbands::{bbands_into, BBandsMA, BBandsState, DeviationMulipliers};
+use crate::indicators::dema::{dema_into, DemaState};
+use crate::indicators::ema::{ema_into, EmaState};
+use crate::indicators::hma::{hma_into, HmaState};
+use crate::indicators::kama::{kama_into, KamaState};
+use crate::indicators::ma::{ma_into, MaState, MaType};
+use crate::indicators::macd::{macd_into, MacdState};
+use crate::indicators::max::{max_into, MaxState};
+use crate::indicators::min::{min_into, MinState};
+use crate::indicators::rsi::{rsi_into, RsiState};
+use crate::indicators::sma::{sma_into, SmaState};
+use crate::indicators::smma::{smma_into, SmmaState};
+use crate::indicators::stats::kurtosis::{kurtosis_into, KurtosisState};
+use crate::indicators::stats::median::{median_into, MedianState};
+use crate::indicators::stats::percent_rank::{percent_rank_into, PercentRankState};
+use crate::indicators::stats::skew::{skew_into, SkewState};
+use crate::indicators::stats::zscore::{zscore_into, ZscoreState};
+use crate::indicators::sum::{sum_into, SumState};
+use crate::indicators::t3::{t3_into, T3State};
+use crate::indicators::tema::{tema_into, TemaState};
+use crate::indicators::trima::{trima_into, TrimaState};
+use crate::indicators::vidya::{vidya_into, VidyaState};
+use crate::indicators::wma::{wma_into, WmaState};
+use crate::indicators::zlema::{zlema_into, ZlemaState};
+use crate::types::{Float, TechalibFloat};
+use alloc::{vec, vec::Vec};
+use arrow_array::types::{ArrowPrimitiveType, Float32Type, Float64Type};
+use arrow_array::{Array, PrimitiveArray};
+use arrow_buffer::{ArrowNativeType, NullBuffer, ScalarBuffer};
+
+/// Float types stored in Arrow arrays
+/// ---
+/// `f64` is stored in a `Float64Array` and `f32` in a `Float32Array`.
+pub trait ArrowFloat: TechalibFloat + ArrowNativeType {
+    /// The Arrow type of the arrays holding `Self`
+    type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+impl ArrowFloat for f64 {
+    type ArrowType = Float64Type;
+}
+
+impl ArrowFloat for f32 {
+    type ArrowType = Float32Type;
+}
+
+/// The Arrow array holding [`Float`] values
+pub type FloatArray = PrimitiveArray<<Float as ArrowFloat>::ArrowType>;
+
+/// Result of an indicator over an Arrow array
+/// ---
+/// Attributes
+/// ---
+/// - `values`: The calculated values, null during the warm-up period and where
+///   the input is null.
+/// - `state`: The state of the indicator after the last valid value, which can be
+///   used to calculate the next values incrementally.
+#[derive(Debug)]
+pub struct ArrowResult<A: ArrowPrimitiveType, S> {
+    /// The calculated values.
+    pub values: PrimitiveArray<A>,
+    /// The state of the indicator after the last valid value.
+    pub state: S,
+}
+
+/// Result of the Bollinger Bands over an Arrow array
+/// ---
+/// Attributes
+/// ---
+/// - `upper`: The upper Bollinger Band values.
+/// - `middle`: The middle Bollinger Band values.
+/// - `lower`: The lower Bollinger Band values.
+/// - `state`: A [`BBandsState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct ArrowBBandsResult<A: ArrowPrimitiveType> {
+    /// The upper Bollinger Band values.
+    pub upper: PrimitiveArray<A>,
+    /// The middle Bollinger Band values.
+    pub middle: PrimitiveArray<A>,
+    /// The lower Bollinger Band values.
+    pub lower: PrimitiveArray<A>,
+    /// A [`BBandsState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: BBandsState<A::Native>,
+}
+
+/// Result of the MACD over an Arrow array
+/// ---
+/// Attributes
+/// ---
+/// - `macd`: The MACD line values.
+/// - `signal`: The signal line values.
+/// - `histogram`: The histogram values.
+/// - `state`: A [`MacdState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct ArrowMacdResult<A: ArrowPrimitiveType> {
+    /// The MACD line values.
+    pub macd: PrimitiveArray<A>,
+    /// The signal line values.
+    pub signal: PrimitiveArray<A>,
+    /// The histogram values.
+    pub histogram: PrimitiveArray<A>,
+    /// A [`MacdState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: MacdState<A::Native>,
+}
+
+/// Runs `f` over the valid values of `data` and returns its `N` outputs as arrays
+/// ---
+/// `f` receives the input values and `N` output buffers of the same length,
+/// filled with `NaN`. The null rows of `data` are skipped, and null in the outputs.
+pub fn map_array<T, A, S, F, const N: usize>(
+    data: &PrimitiveArray<A>,
+    f: F,
+) -> Result<([PrimitiveArray<A>; N], S), TechalibError>
+where
+    T: ArrowFloat,
+    A: ArrowPrimitiveType<Native = T>,
+    F: FnOnce(&[T], [&mut [T]; N]) -> Result<S, TechalibError>,
+{
+    let Some(nulls) = data.nulls().filter(|nulls| nulls.null_count() > 0) else {
+        let mut outputs: [Vec<T>; N] = core::array::from_fn(|_| vec![T::NAN; data.len()]);
+        let state = f(data.values(), outputs.each_mut().map(Vec::as_mut_slice))?;
+        return Ok((outputs.map(to_array), state));
+    };
+
+    let values: Vec<T> = nulls.valid_indices().map(|idx| data.value(idx)).collect();
+    let mut compact: [Vec<T>; N] = core::array::from_fn(|_| vec![T::NAN; values.len()]);
+    let state = f(&values, compact.each_mut().map(Vec::as_mut_slice))?;
+
+    let outputs = compact.map(|compact| {
+        let mut output = vec![T::NAN; data.len()];
+        for (idx, value) in nulls.valid_indices().zip(compact) {
+            output[idx] = value;
+        }
+        to_array(output)
+    });
+    Ok((outputs, state))
+}
+
+/// Wraps `values` in an array without copying, with the `NaN` values as null
+/// ---
+/// Only the validity bitmap is built, the `NaN` stay in the values buffer.
+fn to_array<T: ArrowFloat, A: ArrowPrimitiveType<Native = T>>(values: Vec<T>) -> PrimitiveArray<A> {
+    let nulls = values
+        .iter()
+        .any(|value| value.is_nan())
+        .then(|| NullBuffer::from_iter(values.iter().map(|value| !value.is_nan())));
+    PrimitiveArray::new(ScalarBuffer::from(values), nulls)
+}
+
+/// Generates the Arrow entry point of a single-output indicator from its
+/// `<name>_into` function.
+macro_rules! arrow_function {
+    (
+        $name:ident,
+        $func_into:ident,
+        $state:ident,
+        $label:literal
+        $(; $($param:ident: $ty:ty),+)?
+    ) => {
+        #[doc = concat!("Calculation of the ", $label, " function over an Arrow array")]
+        /// ---
+        #[doc = concat!(
+            "The arguments following `data` are the same as [`",
+            stringify!($func_into),
+            "`]."
+        )]
+        /// The null rows of `data` are skipped, see the [module](self) documentation.
+        ///
+        /// Returns
+        /// ---
+        #[doc = concat!("A `Result` containing an [`ArrowResult`] with a [`", stringify!($state), "`],")]
+        /// or a [`TechalibError`] if the valid values cannot be processed.
+        pub fn $name<T: ArrowFloat, A: ArrowPrimitiveType<Native = T>>(
+            data: &PrimitiveArray<A>,
+            $($($param: $ty),+)?
+        ) -> Result<ArrowResult<A, $state<T>>, TechalibError> {
+            let ([values], state) = map_array(data, |data, [output]| {
+                $func_into(data, $($($param,)+)? output)
+            })?;
+            Ok(ArrowResult { values, state })
+        }
+    };
+}
+
+arrow_function!(sma, sma_into, SmaState, "SMA"; period: usize);
+arrow_function!(ema, ema_into, EmaState, "EMA"; period: usize, alpha: Option<T>);
+arrow_function!(wma, wma_into, WmaState, "WMA"; period: usize);
+arrow_function!(dema, dema_into, DemaState, "DEMA"; period: usize, alpha: Option<T>);
+arrow_function!(tema, tema_into, TemaState, "TEMA"; period: usize, alpha: Option<T>);
+arrow_function!(trima, trima_into, TrimaState, "TRIMA"; period: usize);
+arrow_function!(t3, t3_into, T3State, "T3"; period: usize, volume_factor: T, alpha: Option<T>);
+arrow_function!(kama, kama_into, KamaState, "KAMA"; period: usize);
+arrow_function!(hma, hma_into, HmaState, "HMA"; period: usize);
+arrow_function!(zlema, zlema_into, ZlemaState, "ZLEMA"; period: usize, alpha: Option<T>);
+arrow_function!(alma, alma_into, AlmaState, "ALMA"; period: usize, offset: T, sigma: T);
+arrow_function!(smma, smma_into, SmmaState, "SMMA"; period: usize);
+arrow_function!(vidya, vidya_into, VidyaState, "VIDYA"; period: usize, cmo_period: usize);
+arrow_function!(ma, ma_into, MaState, "MA"; period: usize, ma_type: MaType<T>);
+arrow_function!(rsi, rsi_into, RsiState, "RSI"; period: usize);
+arrow_function!(max, max_into, MaxState, "MAX"; period: usize);
+arrow_function!(min, min_into, MinState, "MIN"; period: usize);
+arrow_function!(sum, sum_into, SumState, "SUM"; period: usize);
+arrow_function!(zscore, zscore_into, ZscoreState, "ZSCORE"; period: usize);
+arrow_function!(percent_rank, percent_rank_into, PercentRankState, "PERCENT_RANK"; period: usize);
+arrow_function!(skew, skew_into, SkewState, "SKEW"; period: usize);
+arrow_function!(kurtosis, kurtosis_into, KurtosisState, "KURTOSIS"; period: usize);
+arrow_function!(median, median_into, MedianState, "MEDIAN"; period: usize);
+
+/// Calculation of the Bollinger Bands function over an Arrow array
+/// ---
+/// The arguments following `data` are the same as [`bbands_into`].
+/// The null rows of `data` are skipped, see the [module](self) documentation.
+///
+/// Returns
+/// ---
+/// A `Result` containing an [`ArrowBBandsResult`],
+/// or a [`TechalibError`] if the valid values cannot be processed.
+pub fn bbands<T: ArrowFloat, A: ArrowPrimitiveType<Native = T>>(
+    data: &PrimitiveArray<A>,
+    period: usize,
+    std_dev_mul: DeviationMulipliers<T>,
+    ma_type: BBandsMA<T>,
+) -> Result<ArrowBBandsResult<A>, TechalibError> {
+    let ([upper, middle, lower], state) = map_array(data, |data, [upper, middle, lower]| {
+        bbands_into(data, period, std_dev_mul, ma_type, upper, middle, lower)
+    })?;
+    Ok(ArrowBBandsResult {
+        upper,
+        middle,
+        lower,
+        state,
+    })
+}
+
+/// Calculation of the MACD function over an Arrow array
+/// ---
+/// The arguments following `data` are the same as [`macd_into`].
+/// The null rows of `data` are skipped, see the [module](self) documentation.
+///
+/// Returns
+/// ---
+/// A `Result` containing an [`ArrowMacdResult`],
+/// or a [`TechalibError`] if the valid values cannot be processed.
+pub fn macd<T: ArrowFloat, A: ArrowPrimitiveType<Native = T>>(
+    data: &PrimitiveArray<A>,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+) -> Result<ArrowMacdResult<A>, TechalibError> {
+    let ([macd, signal, histogram], state) = map_array(data, |data, [macd, signal, histogram]| {
+        macd_into(
+            data,
+            fast_period,
+            slow_period,
+            signal_period,
+            macd,
+            signal,
+            histogram,
+        )
+    })?;
+    Ok(ArrowMacdResult {
+        macd,
+        signal,
+        histogram,
+        state,
+    })
+}
@@ -0,0 +1,466 @@
+use crate::errors::TechalibError;
+use crate::precision;
+use crate::simd;
+use crate::types::{columns_len, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec::Vec};
+use core::ops::Range;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The input of an indicator, made of one or several columns of samples
+/// ---
+/// A slice is a single column. An [`Ohlcv`] leaves its empty columns empty
+/// and its other columns must share the same length.
+pub(crate) trait BatchInput: Copy + Sync + Send {
+    /// The length of the columns
+    fn columns_len(&self) -> Result<usize, TechalibError>;
+
+    /// The samples at the positions of `range` of every column
+    fn slice(&self, range: Range<usize>) -> Self;
+}
+
+impl<T: Sync> BatchInput for &[T] {
+    fn columns_len(&self) -> Result<usize, TechalibError> {
+        Ok(self.len())
+    }
+
+    fn slice(&self, range: Range<usize>) -> Self {
+        &self[range]
+    }
+}
+
+impl<T: Copy + Sync> BatchInput for Ohlcv<'_, T> {
+    fn columns_len(&self) -> Result<usize, TechalibError> {
+        let columns = [
+            ("open", self.open),
+            ("high", self.high),
+            ("low", self.low),
+            ("close", self.close),
+            ("volume", self.volume),
+        ];
+        let columns: Vec<_> = columns
+            .into_iter()
+            .filter(|(_, column)| !column.is_empty())
+            .collect();
+        columns_len(&columns)
+    }
+
+    fn slice(&self, range: Range<usize>) -> Self {
+        fn slice<T>(column: &[T], range: Range<usize>) -> &[T] {
+            if column.is_empty() {
+                column
+            } else {
+                &column[range]
+            }
+        }
+        Ohlcv {
+            open: slice(self.open, range.clone()),
+            high: slice(self.high, range.clone()),
+            low: slice(self.low, range.clone()),
+            close: slice(self.close, range.clone()),
+            volume: slice(self.volume, range),
+        }
+    }
+}
+
+/// Applies `f` to every series of `series`
+/// ---
+/// Series are processed in parallel when the `rayon` feature is enabled.
+/// The first error encountered is returned and the other results are dropped.
+pub(crate) fn batch<I, R, F>(series: &[I], f: F) -> Result<Vec<R>, TechalibError>
+where
+    I: BatchInput,
+    R: Send,
+    F: Fn(I) -> Result<R, TechalibError> + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    {
+        series.par_iter().map(|&input| f(input)).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        series.iter().map(|&input| f(input)).collect()
+    }
+}
+
+/// Applies `f` to every column of a column-major 2-D array
+/// ---
+/// The columns of `input` and every buffer of `outputs` are flat contiguous
+/// buffers holding consecutive series of `series_len` samples each. Every
+/// series of `input` is written to the same series of each output, and the
+/// states are returned in series order.
+pub(crate) fn batch_into<I, T, S, F, const N: usize>(
+    input: I,
+    series_len: usize,
+    outputs: [&mut [T]; N],
+    f: F,
+) -> Result<Vec<S>, TechalibError>
+where
+    I: BatchInput,
+    T: Send,
+    S: Send,
+    F: Fn(I, [&mut [T]; N]) -> Result<S, TechalibError> + Sync + Send,
+{
+    if series_len == 0 {
+        return Err(TechalibError::BadParam(
+            "Series length must be greater than 0".to_string(),
+        ));
+    }
+    let len = input.columns_len()?;
+    if len % series_len != 0 {
+        return Err(TechalibError::BadParam(format!(
+            "Input length ({len}) must be a multiple of the series length ({series_len})"
+        )));
+    }
+    if outputs.iter().any(|output| output.len() < len) {
+        let arrays = if N == 1 { "array" } else { "arrays" };
+        return Err(TechalibError::BadParam(format!(
+            "Output {arrays} must be at least as long as the input data array"
+        )));
+    }
+
+    let mut outputs = outputs;
+    let columns: Vec<_> = (0..len)
+        .step_by(series_len)
+        .map(|start| {
+            let out = outputs.each_mut().map(|output| {
+                let (column, rest) = core::mem::take(output).split_at_mut(series_len);
+                *output = rest;
+                column
+            });
+            (input.slice(start..start + series_len), out)
+        })
+        .collect();
+
+    #[cfg(feature = "rayon")]
+    {
+        columns
+            .into_par_iter()
+            .map(|(column, out)| f(column, out))
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        columns
+            .into_iter()
+            .map(|(column, out)| f(column, out))
+            .collect()
+    }
+}
+
+/// Applies `f` to every period of a parameter sweep
+/// ---
+/// `rows` holds the output buffers of each period, in the same order as `periods`.
+/// Periods are processed in parallel when the `rayon` feature is enabled.
+/// The first error encountered is returned and the other results are dropped.
+pub(crate) fn sweep<R, S, F>(periods: &[usize], rows: Vec<R>, f: F) -> Result<Vec<S>, TechalibError>
+where
+    R: Send,
+    S: Send,
+    F: Fn(usize, R) -> Result<S, TechalibError> + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    {
+        periods
+            .par_iter()
+            .zip(rows.into_par_iter())
+            .map(|(&period, row)| f(period, row))
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        periods
+            .iter()
+            .zip(rows)
+            .map(|(&period, row)| f(period, row))
+            .collect()
+    }
+}
+
+/// Checks that a sweep output holds one column of `len` samples per period
+/// ---
+/// As for the single-period functions, the output can be longer, the values
+/// after the last column are left untouched.
+pub(crate) fn check_sweep_output(
+    len: usize,
+    periods: &[usize],
+    output_len: usize,
+) -> Result<(), TechalibError> {
+    if output_len < len * periods.len() {
+        return Err(TechalibError::BadParam(format!(
+            "Output array must hold at least one column of {len} values per period ({} periods)",
+            periods.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Prefix sums of `f` applied to `data`, accumulated in `f64`
+/// ---
+/// `sums[i]` is the sum of the first `i` mapped values, so the sum over
+/// `data[i - period..i]` is `sums[i] - sums[i - period]`.
+/// It fails if a value of `data` is not finite.
+pub(crate) fn prefix_sums<T: TechalibFloat>(
+    data: &[T],
+    f: impl Fn(f64) -> f64,
+) -> Result<Vec<f64>, TechalibError> {
+    if let Some(idx) = simd::first_non_finite(data) {
+        return Err(TechalibError::DataNonFinite(format!(
+            "data[{idx}] = {:?}",
+            data[idx]
+        )));
+    }
+    let mut sums = Vec::with_capacity(data.len() + 1);
+    let mut sum = 0.0;
+    sums.push(sum);
+    for &value in data {
+        sum += f(value.to_f64());
+        sums.push(sum);
+    }
+    Ok(sums)
+}
+
+/// Smallest number of windows sharing the same prefix sums in [`BlockPrefixSums`]
+const MIN_BLOCK: usize = 4096;
+
+/// Prefix sums of `f` applied to `data`, restarted every block
+/// ---
+/// The windows ending in block `b` (`b * block <= idx < (b + 1) * block`) are
+/// summed from `sums[b]`, the prefix sums of the values starting `max_period`
+/// values before the block. A sum then never runs over more than
+/// `block + max_period` values, so the rounding error of the window sums does
+/// not grow with the length of the series as with [`prefix_sums`].
+///
+/// The blocks hold at least `MIN_BLOCK` windows, or exactly `max_period` with
+/// the `high-precision` feature, as often as the states re-sum their window.
+pub(crate) struct BlockPrefixSums {
+    sums: Vec<Vec<f64>>,
+    len: usize,
+    block: usize,
+    max_period: usize,
+}
+
+impl BlockPrefixSums {
+    /// It fails if a value of `data` is not finite.
+    pub(crate) fn new<T: TechalibFloat>(
+        data: &[T],
+        max_period: usize,
+        f: impl Fn(f64) -> f64,
+    ) -> Result<Self, TechalibError> {
+        if let Some(idx) = simd::first_non_finite(data) {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        let block = if precision::HIGH_PRECISION {
+            max_period.max(1)
+        } else {
+            max_period.max(MIN_BLOCK)
+        };
+        let sums = (0..data.len().div_ceil(block))
+            .map(|b| {
+                let origin = (b * block).saturating_sub(max_period);
+                let end = ((b + 1) * block).min(data.len());
+                let mut sums = Vec::with_capacity(end - origin + 1);
+                let mut sum = 0.0;
+                sums.push(sum);
+                for &value in &data[origin..end] {
+                    sum += f(value.to_f64());
+                    sums.push(sum);
+                }
+                sums
+            })
+            .collect();
+        Ok(BlockPrefixSums {
+            sums,
+            len: data.len(),
+            block,
+            max_period,
+        })
+    }
+
+    /// The windows of `period` values, block by block
+    /// ---
+    /// Each item is `(sums, range)`: the window ending at `range.start` is the
+    /// first one of `sums`, laid out as in [`prefix_sums`], and `range` holds
+    /// the positions of the last values of the windows of the block.
+    pub(crate) fn windows(&self, period: usize) -> impl Iterator<Item = (&[f64], Range<usize>)> {
+        debug_assert!(0 < period && period <= self.max_period);
+        self.sums.iter().enumerate().filter_map(move |(b, sums)| {
+            let range = (b * self.block).max(period - 1)..((b + 1) * self.block).min(self.len);
+            if range.is_empty() {
+                return None;
+            }
+            let origin = (b * self.block).saturating_sub(self.max_period);
+            Some((&sums[range.start + 1 - period - origin..], range))
+        })
+    }
+
+    /// The sum of `data[start..end]`, with `end - start <= max_period`
+    pub(crate) fn sum(&self, start: usize, end: usize) -> f64 {
+        debug_assert!(start < end && end - start <= self.max_period);
+        let b = (end - 1) / self.block;
+        let origin = (b * self.block).saturating_sub(self.max_period);
+        self.sums[b][end - origin] - self.sums[b][start - origin]
+    }
+}
+
+/// Generates the `<name>_batch` and `<name>_batch_into` entry points of a
+/// single-series indicator from its `<name>` and `<name>_into` functions.
+///
+/// The input is `data` (a slice) by default, or `ohlcv` (an [`Ohlcv`]), and
+/// the outputs default to a single `output`:
+/// `batch_functions!(..., "label", ohlcv -> [output_upper, output_lower]; period: usize)`.
+macro_rules! batch_functions {
+    (
+        $batch:ident,
+        $batch_into:ident,
+        $func:ident,
+        $func_into:ident,
+        $result:ident,
+        $state:ident,
+        $label:literal,
+        data -> [$($output:ident),+]
+        $(; $($param:ident: $ty:ty),+)?
+    ) => {
+        $crate::batch::batch_functions!(
+            @impl $batch, $batch_into, $func, $func_into, $result, $state, $label,
+            data: [&[T]], [&[T]], [], [],
+            "each one a slice of [`Float`](crate::types::Float)",
+            "A slice of [`Float`](crate::types::Float) holding consecutive series",
+            "`data`",
+            [$($output),+]
+            $(; $($param: $ty),+)?
+        );
+    };
+    (
+        $batch:ident,
+        $batch_into:ident,
+        $func:ident,
+        $func_into:ident,
+        $result:ident,
+        $state:ident,
+        $label:literal,
+        ohlcv -> [$($output:ident),+]
+        $(; $($param:ident: $ty:ty),+)?
+    ) => {
+        $crate::batch::batch_functions!(
+            @impl $batch, $batch_into, $func, $func_into, $result, $state, $label,
+            ohlcv: [&$crate::types::Ohlcv<T>], [$crate::types::Ohlcv<T>], [*], [&],
+            "each one an [`Ohlcv`](crate::types::Ohlcv)",
+            "An [`Ohlcv`](crate::types::Ohlcv) whose columns hold consecutive series",
+            "the columns of `ohlcv`",
+            [$($output),+]
+            $(; $($param: $ty),+)?
+        );
+    };
+    (
+        $batch:ident,
+        $batch_into:ident,
+        $func:ident,
+        $func_into:ident,
+        $result:ident,
+        $state:ident,
+        $label:literal
+        $(; $($param:ident: $ty:ty),+)?
+    ) => {
+        $crate::batch::batch_functions!(
+            $batch, $batch_into, $func, $func_into, $result, $state, $label,
+            data -> [output]
+            $(; $($param: $ty),+)?
+        );
+    };
+    (
+        @impl
+        $batch:ident,
+        $batch_into:ident,
+        $func:ident,
+        $func_into:ident,
+        $result:ident,
+        $state:ident,
+        $label:literal,
+        $input:ident: [$($input_ty:tt)+], [$($series_ty:tt)+], [$($deref:tt)?], [$($ref:tt)?],
+        $series_doc:literal,
+        $input_doc:literal,
+        $len_doc:literal,
+        [$($output:ident),+]
+        $(; $($param:ident: $ty:ty),+)?
+    ) => {
+        #[doc = concat!("Calculation of the ", $label, " function over several series")]
+        /// ---
+        #[doc = concat!("It returns a `Vec` of [`", stringify!($result), "`], one per series.")]
+        ///
+        /// Input Arguments
+        /// ---
+        #[doc = concat!("- `series`: A slice of series, ", $series_doc, ".")]
+        #[doc = concat!(
+            "- The remaining arguments are the same as [`",
+            stringify!($func),
+            "`] and are shared by every series."
+        )]
+        ///
+        /// Returns
+        /// ---
+        #[doc = concat!("A `Result` containing a `Vec` of [`", stringify!($result), "`],")]
+        /// or the first [`TechalibError`] encountered.
+        /// Series are processed in parallel when the `rayon` feature is enabled.
+        pub fn $batch<T: TechalibFloat>(
+            series: &[$($series_ty)+],
+            $($($param: $ty),+)?
+        ) -> Result<Vec<$result<T>>, TechalibError> {
+            $crate::batch::batch(series, |$input| $func($($ref)? $input, $($($param),+)?))
+        }
+
+        #[doc = concat!("Calculation of the ", $label, " function over several series")]
+        /// ---
+        /// It reads a column-major 2-D array stored in a flat contiguous slice,
+        /// stores the results with the same layout in the provided output arrays and
+        #[doc = concat!("returns a `Vec` of [`", stringify!($state), "`], one per column.")]
+        ///
+        /// Input Arguments
+        /// ---
+        #[doc = concat!("- `", stringify!($input), "`: ", $input_doc, ".")]
+        /// - `series_len`: The number of samples of each series.
+        #[doc = concat!(
+            "- The remaining arguments are the same as [`",
+            stringify!($func_into),
+            "`] and are shared by every series."
+        )]
+        ///
+        /// Output Arguments
+        /// ---
+        $(
+            #[doc = concat!(
+                "- `",
+                stringify!($output),
+                "`: A mutable slice of [`Float`](crate::types::Float) at least as long as ",
+                $len_doc,
+                ", where the calculated values will be stored."
+            )]
+        )+
+        ///
+        /// Returns
+        /// ---
+        #[doc = concat!("A `Result` containing a `Vec` of [`", stringify!($state), "`],")]
+        /// or the first [`TechalibError`] encountered.
+        /// Columns are processed in parallel when the `rayon` feature is enabled.
+        #[allow(clippy::too_many_arguments)]
+        pub fn $batch_into<T: TechalibFloat>(
+            $input: $($input_ty)+,
+            series_len: usize,
+            $($($param: $ty,)+)?
+            $($output: &mut [T],)+
+        ) -> Result<Vec<$state<T>>, TechalibError> {
+            $crate::batch::batch_into(
+                $($deref)? $input,
+                series_len,
+                [$($output),+],
+                |column, [$($output),+]| $func_into($($ref)? column, $($($param,)+)? $($output),+),
+            )
+        }
+    };
+}
+
+pub(crate) use batch_functions;
@@ -1,4 +1,4 @@
-use crate::types::Float;
+use alloc::string::String;
 
 /// Techalib error types
 /// ---
@@ -10,7 +10,8 @@ use crate::types::Float;
 /// - `BadParam(String)`: Indicates that a parameter passed to a function is invalid.
 /// - `InsufficientData`: Indicates that there is not enough data to perform a calculation.
 /// - `DataNonFinite(String)`: Indicates that a data point is not finite (e.g., NaN or Infinity).
-/// - `Overflow(usize, Float)`: Indicates that an overflow occurred at a specific index.
+/// - `Overflow(usize, f64)`: Indicates that an overflow occurred at a specific index,
+///   with the overflowing value converted to `f64`.
 /// - `NotImplementedYet`: Indicates that a feature or function is not yet implemented.
 #[derive(Debug)]
 pub enum TechalibError {
@@ -21,7 +22,22 @@ pub enum TechalibError {
     /// Indicates that a data point is not finite (e.g., NaN or Infinity).
     DataNonFinite(String),
     /// Indicates that an overflow occurred at a specific index.
-    Overflow(usize, Float),
+    Overflow(usize, f64),
     /// Indicates that a feature or function is not yet implemented.
     NotImplementedYet,
 }
+
+impl core::fmt::Display for TechalibError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TechalibError::BadParam(msg) => write!(f, "Bad parameter: {msg}"),
+            TechalibError::InsufficientData => write!(f, "Insufficient data"),
+            TechalibError::DataNonFinite(msg) => write!(f, "Non finite data: {msg}"),
+            TechalibError::Overflow(idx, value) => write!(f, "Overflow at index {idx}: {value}"),
+            TechalibError::NotImplementedYet => write!(f, "Not implemented yet"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TechalibError {}
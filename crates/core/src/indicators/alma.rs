@@ -0,0 +1,314 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Arnaud Legoux Moving Average (ALMA) implementation
+//!
+//! A moving average weighted by a gaussian curve over the window. The
+//! `offset` (between 0 and 1) places the center of the curve, from the oldest
+//! to the newest value, and the `sigma` sets its width: the standard
+//! deviation of the curve is `period / sigma`.
+
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+
+/// Default offset of the gaussian curve.
+pub const DEFAULT_OFFSET: Float = 0.85;
+/// Default sigma of the gaussian curve.
+pub const DEFAULT_SIGMA: Float = 6.0;
+
+/// ALMA calculation result
+/// ---
+/// This struct holds the result and the state ([`AlmaState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated ALMA values.
+/// - `state`: A [`AlmaState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct AlmaResult<T = Float> {
+    /// The calculated ALMA values.
+    pub values: Vec<T>,
+    /// A [`AlmaState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: AlmaState<T>,
+}
+
+/// ALMA calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `alma`: The last calculated ALMA value.
+///
+/// **State values**
+/// - `last_window`: A ring buffer containing the last `period` input values.
+///
+/// **Parameters**
+/// - `period`: The period used for the ALMA calculation.
+/// - `offset`: The position of the center of the gaussian curve in the window.
+/// - `sigma`: The inverse width of the gaussian curve.
+#[derive(Debug, Clone)]
+pub struct AlmaState<T = Float> {
+    // Outputs
+    /// The last calculated ALMA value.
+    pub alma: T,
+
+    // State values
+    /// A ring buffer containing the last `period` input values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The period used for the ALMA calculation.
+    pub period: usize,
+    /// The position of the center of the gaussian curve in the window.
+    pub offset: T,
+    /// The inverse width of the gaussian curve.
+    pub sigma: T,
+}
+
+impl<T: TechalibFloat> State<T> for AlmaState<T> {
+    /// Update the [`AlmaState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the ALMA state.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        check_param_unchecked(self.period, self.offset, self.sigma)?;
+        if self.last_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "ALMA state window length ({}) does not match period ({})",
+                self.last_window.len(),
+                self.period
+            )));
+        }
+        if !sample.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
+        }
+
+        let weights = gaussian_weights_unchecked(self.period, self.offset, self.sigma);
+        let window = self
+            .last_window
+            .iter()
+            .skip(1)
+            .chain(core::iter::once(&sample));
+        let alma = alma_unchecked(window, &weights);
+        if !alma.is_finite() {
+            return Err(TechalibError::Overflow(0, alma.to_f64()));
+        }
+        self.alma = alma;
+        self.last_window.push(sample);
+        Ok(())
+    }
+}
+
+/// Lookback period for ALMA calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the ALMA values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the ALMA function
+/// ---
+/// It returns a [`AlmaResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period for the ALMA calculation.
+/// - `offset`: The position of the center of the gaussian curve, between 0 and 1
+///   (default [`DEFAULT_OFFSET`]).
+/// - `sigma`: The inverse width of the gaussian curve (default [`DEFAULT_SIGMA`]).
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`AlmaResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn alma<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    offset: T,
+    sigma: T,
+) -> Result<AlmaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let alma_state = alma_into(data, period, offset, sigma, output.as_mut_slice())?;
+    Ok(AlmaResult {
+        values: output,
+        state: alma_state,
+    })
+}
+
+/// Calculation of the ALMA function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`AlmaState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period for the ALMA calculation.
+/// - `offset`: The position of the center of the gaussian curve, between 0 and 1
+///   (default [`DEFAULT_OFFSET`]).
+/// - `sigma`: The inverse width of the gaussian curve (default [`DEFAULT_SIGMA`]).
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated ALMA values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`AlmaState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn alma_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    offset: T,
+    sigma: T,
+    output: &mut [T],
+) -> Result<AlmaState<T>, TechalibError> {
+    check_param_unchecked(period, offset, sigma)?;
+    let len = data.len();
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let weights = gaussian_weights_unchecked(period, offset, sigma);
+    for idx in 0..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        if idx < lookback {
+            output[idx] = T::NAN;
+            continue;
+        }
+        output[idx] = alma_unchecked(&data[idx - lookback..=idx], &weights);
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(AlmaState {
+        alma: output[len - 1],
+        last_window: RingBuffer::from(&data[len - period..len]),
+        period,
+        offset,
+        sigma,
+    })
+}
+
+batch_functions!(
+    alma_batch,
+    alma_batch_into,
+    alma,
+    alma_into,
+    AlmaResult,
+    AlmaState,
+    "ALMA";
+    period: usize, offset: T, sigma: T
+);
+
+/// Returns the gaussian weights of the window, from the oldest to the newest
+/// value, normalized so that their sum is 1.
+#[inline(always)]
+fn gaussian_weights_unchecked<T: TechalibFloat>(period: usize, offset: T, sigma: T) -> Vec<T> {
+    let center = offset * T::from_usize(period - 1);
+    let width = T::from_usize(period) / sigma;
+    let two_var = T::from_f64(2.0) * width * width;
+    let mut weights: Vec<T> = (0..period)
+        .map(|idx| (-(T::from_usize(idx) - center).powi(2) / two_var).exp())
+        .collect();
+    let inv_sum = T::ONE / weights.iter().sum::<T>();
+    weights.iter_mut().for_each(|weight| *weight *= inv_sum);
+    weights
+}
+
+#[inline(always)]
+fn alma_unchecked<'a, I, T: TechalibFloat>(window: I, weights: &[T]) -> T
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    window
+        .into_iter()
+        .zip(weights)
+        .map(|(&value, &weight)| value * weight)
+        .sum()
+}
+
+#[inline(always)]
+fn check_param_unchecked<T: TechalibFloat>(
+    period: usize,
+    offset: T,
+    sigma: T,
+) -> Result<(), TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(
+            "ALMA period must be greater than 1".to_string(),
+        ));
+    }
+    if !(T::ZERO..=T::ONE).contains(&offset) {
+        return Err(TechalibError::BadParam(format!(
+            "ALMA offset must be between 0 and 1, got: {offset:?}"
+        )));
+    }
+    if !sigma.is_finite() || sigma <= T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "ALMA sigma must be a finite value greater than 0, got: {sigma:?}"
+        )));
+    }
+    Ok(())
+}
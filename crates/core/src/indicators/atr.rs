@@ -0,0 +1,300 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB ATR implementation
+*/
+
+//! Average True Range (ATR) implementation
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// ATR calculation result
+/// ---
+/// This struct holds the result and the state ([`AtrState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated ATR values.
+/// - `state`: A [`AtrState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct AtrResult<T = Float> {
+    /// The calculated ATR values.
+    pub values: Vec<T>,
+    /// A [`AtrState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: AtrState<T>,
+}
+
+/// ATR calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `atr`: The last calculated ATR value.
+///
+/// **State values**
+/// - `prev_close`: The last close price, used to calculate the next true range.
+///
+/// **Parameters**
+/// - `period`: The period used for the Wilder smoothing of the true range.
+#[derive(Debug, Clone, Copy)]
+pub struct AtrState<T = Float> {
+    // Outputs
+    /// The last calculated ATR value.
+    pub atr: T,
+
+    // State values
+    /// The last close price, used to calculate the next true range.
+    pub prev_close: T,
+
+    // Parameters
+    /// The period used for the Wilder smoothing of the true range.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<(T, T, T)> for AtrState<T> {
+    /// Update the [`AtrState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, close)` of the new bar.
+    fn update(&mut self, sample: (T, T, T)) -> Result<(), TechalibError> {
+        let (high, low, close) = sample;
+        if self.period == 0 {
+            return Err(TechalibError::BadParam(
+                "ATR period must be greater than 0".to_string(),
+            ));
+        }
+        if !high.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("high = {high:?}")));
+        }
+        if !low.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("low = {low:?}")));
+        }
+        if !close.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("close = {close:?}")));
+        }
+        if !self.atr.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.atr = {:?}",
+                self.atr
+            )));
+        }
+        if !self.prev_close.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.prev_close = {:?}",
+                self.prev_close
+            )));
+        }
+
+        let atr = atr_next_unchecked(
+            true_range_unchecked(high, low, self.prev_close),
+            self.atr,
+            T::from_usize(self.period),
+        );
+        if !atr.is_finite() {
+            return Err(TechalibError::Overflow(0, atr.to_f64()));
+        }
+        self.atr = atr;
+        self.prev_close = close;
+        Ok(())
+    }
+}
+
+/// Lookback period for ATR calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the ATR values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period
+}
+
+/// Calculation of the ATR function
+/// ---
+/// It returns a [`AtrResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period used for the Wilder smoothing of the true range.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`AtrResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn atr<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+) -> Result<AtrResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; ohlcv.close.len()];
+    let atr_state = atr_into(ohlcv, period, output.as_mut_slice())?;
+    Ok(AtrResult {
+        values: output,
+        state: atr_state,
+    })
+}
+
+/// Calculation of the ATR function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`AtrState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period used for the Wilder smoothing of the true range.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the ATR values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`AtrState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn atr_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    output: &mut [T],
+) -> Result<AtrState<T>, TechalibError> {
+    if period == 0 {
+        return Err(TechalibError::BadParam(
+            "ATR period must be greater than 0".to_string(),
+        ));
+    }
+
+    let (high, low, close) = (ohlcv.high, ohlcv.low, ohlcv.close);
+    let len = columns_len(&[("high", high), ("low", low), ("close", close)])?;
+    let lookback = lookback_from_period(period);
+
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    check_finite_unchecked(ohlcv, 0)?;
+    output[0] = T::NAN;
+    let mut tr_sum = T::ZERO;
+    for idx in 1..=lookback {
+        check_finite_unchecked(ohlcv, idx)?;
+        tr_sum += true_range_unchecked(high[idx], low[idx], close[idx - 1]);
+        output[idx] = T::NAN;
+    }
+    output[lookback] = tr_sum / T::from_usize(period);
+    if !output[lookback].is_finite() {
+        return Err(TechalibError::Overflow(lookback, output[lookback].to_f64()));
+    }
+
+    for idx in lookback + 1..len {
+        check_finite_unchecked(ohlcv, idx)?;
+        output[idx] = atr_next_unchecked(
+            true_range_unchecked(high[idx], low[idx], close[idx - 1]),
+            output[idx - 1],
+            T::from_usize(period),
+        );
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(AtrState {
+        atr: output[len - 1],
+        prev_close: close[len - 1],
+        period,
+    })
+}
+
+batch_functions!(
+    atr_batch,
+    atr_batch_into,
+    atr,
+    atr_into,
+    AtrResult,
+    AtrState,
+    "ATR",
+    ohlcv -> [output];
+    period: usize
+);
+
+/// Returns the true range of a bar, given the close of the previous bar.
+#[inline(always)]
+pub(crate) fn true_range_unchecked<T: TechalibFloat>(high: T, low: T, prev_close: T) -> T {
+    (high - low)
+        .max((high - prev_close).abs())
+        .max((low - prev_close).abs())
+}
+
+#[inline(always)]
+pub(crate) fn atr_next_unchecked<T: TechalibFloat>(true_range: T, prev_atr: T, period: T) -> T {
+    (prev_atr * (period - T::ONE) + true_range) / period
+}
+
+#[inline(always)]
+fn check_finite_unchecked<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    idx: usize,
+) -> Result<(), TechalibError> {
+    for (name, column) in [
+        ("high", ohlcv.high),
+        ("low", ohlcv.low),
+        ("close", ohlcv.close),
+    ] {
+        if !column[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "{name}[{idx}] = {:?}",
+                column[idx]
+            )));
+        }
+    }
+    Ok(())
+}
@@ -39,13 +39,21 @@
 */
 
 //! Bollinger Bands (BBANDS) implementation
+//!
+//! Besides the bands, [`bbands_ext`] also returns the %B and the bandwidth
+//! derived from them in the same pass.
 
+use crate::batch::{batch_functions, check_sweep_output, prefix_sums, sweep, BlockPrefixSums};
 use crate::errors::TechalibError;
 use crate::indicators::ema::{ema_next_unchecked, period_to_alpha};
+use crate::indicators::ma::{self, ma_into, MaState, MaType};
 use crate::indicators::sma::sma_next_unchecked;
+use crate::precision;
+use crate::ring_buffer::RingBuffer;
+use crate::simd;
 use crate::traits::State;
-use crate::types::Float;
-use std::collections::VecDeque;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 /// Bollinger Bands result
 /// ---
@@ -60,16 +68,75 @@ use std::collections::VecDeque;
 /// - `state`: A [`BBandsState`], which can be used to calculate the next values
 ///   incrementally.
 #[derive(Debug)]
-pub struct BBandsResult {
+pub struct BBandsResult<T = Float> {
     /// The upper Bollinger Band values.
-    pub upper: Vec<Float>,
+    pub upper: Vec<T>,
     /// The middle Bollinger Band values (usually a moving average).
-    pub middle: Vec<Float>,
+    pub middle: Vec<T>,
     /// The lower Bollinger Band values.
-    pub lower: Vec<Float>,
+    pub lower: Vec<T>,
     /// A [`BBandsState`], which can be used to calculate the next values
     /// incrementally.
-    pub state: BBandsState,
+    pub state: BBandsState<T>,
+}
+
+/// Bollinger Bands result with %B and bandwidth
+/// ---
+/// This struct holds the result of the [`bbands_ext`] calculation.
+/// It contains the bands, the %B and the bandwidth as well as the state of the calculation.
+///
+/// Attributes
+/// ---
+/// - `upper`: The upper Bollinger Band values.
+/// - `middle`: The middle Bollinger Band values (usually a moving average).
+/// - `lower`: The lower Bollinger Band values.
+/// - `percent_b`: The %B values, `(value - lower) / (upper - lower)`.
+/// - `bandwidth`: The bandwidth values, `(upper - lower) / middle`.
+/// - `state`: A [`BBandsState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct BBandsExtResult<T = Float> {
+    /// The upper Bollinger Band values.
+    pub upper: Vec<T>,
+    /// The middle Bollinger Band values (usually a moving average).
+    pub middle: Vec<T>,
+    /// The lower Bollinger Band values.
+    pub lower: Vec<T>,
+    /// The %B values, `(value - lower) / (upper - lower)`.
+    pub percent_b: Vec<T>,
+    /// The bandwidth values, `(upper - lower) / middle`.
+    pub bandwidth: Vec<T>,
+    /// A [`BBandsState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: BBandsState<T>,
+}
+
+/// Bollinger Bands sweep result
+/// ---
+/// This struct holds the result of the Bollinger Bands calculation for several
+/// periods over the same series.
+///
+/// Attributes
+/// ---
+/// - `upper`: The upper Bollinger Band values, one column per period.
+/// - `middle`: The middle Bollinger Band values, one column per period.
+/// - `lower`: The lower Bollinger Band values, one column per period.
+/// - `states`: A vector of [`BBandsState`], one per period, which can be used
+///   to calculate the next values incrementally.
+///
+/// Each band is a column-major 2-D array with one column of `data.len()` values
+/// per period, in the order of the periods.
+#[derive(Debug)]
+pub struct BBandsSweepResult<T = Float> {
+    /// The upper Bollinger Band values, one column per period.
+    pub upper: Vec<T>,
+    /// The middle Bollinger Band values, one column per period.
+    pub middle: Vec<T>,
+    /// The lower Bollinger Band values, one column per period.
+    pub lower: Vec<T>,
+    /// A [`BBandsState`] per period, which can be used to calculate the next values
+    /// incrementally.
+    pub states: Vec<BBandsState<T>>,
 }
 
 /// Bollinger Bands calculation state
@@ -84,40 +151,52 @@ pub struct BBandsResult {
 /// - `upper`: The last upper Bollinger Band value.
 /// - `middle`: The last middle Bollinger Band value (usually a moving average).
 /// - `lower`: The last lower Bollinger Band value.
+/// - `percent_b`: The last %B value.
+/// - `bandwidth`: The last bandwidth value.
 ///
 /// **State values**
 /// - `moving_averages`: The state of the moving averages used in the calculation.
-/// - `last_window`: A deque containing the last `period` values used for the calculation.
+/// - `last_window`: A ring buffer containing the last `period` values used for the calculation.
+/// - `ma`: The [`MaState`] of the middle band, `None` for SMA and EMA.
 ///
 /// **Parameters**
 /// - `period`: The number of periods used to calculate the moving average and
 ///   standard deviation.
 /// - `std_dev_mult`: The multipliers for the standard deviation used to calculate
 ///   the upper and lower bands.
-/// - `ma_type`: The type of moving average used (SMA or EMA).
+/// - `ma_type`: The type of moving average used for the middle band.
 #[derive(Debug, Clone)]
-pub struct BBandsState {
+pub struct BBandsState<T = Float> {
     // Outputs values
     /// The last upper Bollinger Band value.
-    pub upper: Float,
+    pub upper: T,
     /// The last middle Bollinger Band value (usually a moving average).
-    pub middle: Float,
+    pub middle: T,
     /// The last lower Bollinger Band value.
-    pub lower: Float,
+    pub lower: T,
+    /// The last %B value, `(value - lower) / (upper - lower)`.
+    /// It is `NaN` when the bands are merged.
+    pub percent_b: T,
+    /// The last bandwidth value, `(upper - lower) / middle`.
+    /// It is `NaN` when the middle band is zero.
+    pub bandwidth: T,
 
     // State values
     /// The [`MovingAverageState`] state of the moving averages used in the calculation.
-    pub moving_averages: MovingAverageState,
-    /// A deque containing the last `period` values used for the calculation.
-    pub last_window: VecDeque<Float>,
+    pub moving_averages: MovingAverageState<T>,
+    /// A ring buffer containing the last `period` values used for the calculation.
+    pub last_window: RingBuffer<T>,
+    /// The [`MaState`] of the middle band, `None` for SMA and EMA
+    /// which are calculated from `moving_averages`.
+    pub ma: Option<MaState<T>>,
 
     // Parameters
     /// The number of periods used to calculate the moving average and standard deviation.
     pub period: usize,
     /// The multipliers for the standard deviation used to calculate the upper and lower bands.
-    pub std_dev_mult: DeviationMulipliers,
+    pub std_dev_mult: DeviationMulipliers<T>,
     /// The [`BBandsMA`] enum variant representing the type of moving average used.
-    pub ma_type: BBandsMA,
+    pub ma_type: BBandsMA<T>,
 }
 
 /// Deviation multipliers for Bollinger Bands.
@@ -130,11 +209,11 @@ pub struct BBandsState {
 /// - `up`: The multiplier for the upper Bollinger Band.
 /// - `down`: The multiplier for the lower Bollinger Band.
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DeviationMulipliers {
+pub struct DeviationMulipliers<T = Float> {
     /// The multiplier for the upper Bollinger Band.
-    pub up: Float,
+    pub up: T,
     /// The multiplier for the lower Bollinger Band.
-    pub down: Float,
+    pub down: T,
 }
 
 /// Moving average state for Bollinger Bands.
@@ -148,14 +227,14 @@ pub struct DeviationMulipliers {
 /// - `ma_square`: The square of the moving average value, used for variance calculation.
 ///   The moving average depends on the `ma_type` used in the Bollinger Bands calculation.
 #[derive(Debug, Clone, Copy)]
-pub struct MovingAverageState {
+pub struct MovingAverageState<T = Float> {
     /// The simple moving average value.
-    pub sma: Float,
+    pub sma: T,
     /// The square of the moving average value, used for variance calculation.
     /// The moving average depends on the `ma_type` used in the Bollinger Bands calculation.
     /// This value is used to calculate the standard deviation and is essential for
     /// determining the upper and lower Bollinger Bands.
-    pub ma_square: Float,
+    pub ma_square: T,
 }
 
 /// Type of moving average used in Bollinger Bands.
@@ -167,21 +246,57 @@ pub struct MovingAverageState {
 /// ---
 /// - `SMA`: Simple Moving Average.
 /// - `EMA`: Exponential Moving Average, with an optional alpha value for the calculation.
+/// - `HMA`: Hull Moving Average.
+/// - `ZLEMA`: Zero-Lag Exponential Moving Average, with an optional alpha value.
+/// - `ALMA`: Arnaud Legoux Moving Average, with its offset and sigma.
+/// - `SMMA`: Smoothed Moving Average (Wilder's RMA).
+/// - `VIDYA`: Variable Index Dynamic Average, with the period of its CMO.
+///
+/// The standard deviation is always the one of the last `period` values,
+/// only the middle band depends on the moving average. The bands start
+/// when the moving average does, see [`ma::lookback_from_period`].
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub enum BBandsMA {
+pub enum BBandsMA<T = Float> {
     /// Simple Moving Average.
     SMA,
     /// Exponential Moving Average, with an optional alpha value for the calculation.
-    EMA(Option<Float>),
+    EMA(Option<T>),
+    /// Hull Moving Average.
+    HMA,
+    /// Zero-Lag Exponential Moving Average, with an optional alpha value.
+    ZLEMA(Option<T>),
+    /// Arnaud Legoux Moving Average, with its offset and sigma.
+    ALMA(T, T),
+    /// Smoothed Moving Average (Wilder's RMA).
+    SMMA,
+    /// Variable Index Dynamic Average, with the period of its CMO.
+    VIDYA(usize),
 }
 
-impl State<Float> for BBandsState {
+impl<T> From<BBandsMA<T>> for MaType<T> {
+    fn from(ma_type: BBandsMA<T>) -> Self {
+        match ma_type {
+            BBandsMA::SMA => MaType::SMA,
+            BBandsMA::EMA(alpha) => MaType::EMA(alpha),
+            BBandsMA::HMA => MaType::HMA,
+            BBandsMA::ZLEMA(alpha) => MaType::ZLEMA(alpha),
+            BBandsMA::ALMA(offset, sigma) => MaType::ALMA(offset, sigma),
+            BBandsMA::SMMA => MaType::SMMA,
+            BBandsMA::VIDYA(cmo_period) => MaType::VIDYA(cmo_period),
+        }
+    }
+}
+
+impl<T: TechalibFloat> State<T> for BBandsState<T> {
     /// Update the [`BBandsState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input value to update the Bollinger Bands state. Generally, it is the closing price.
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    ///
+    /// With a middle band other than SMA or EMA, the [`MaState`] is updated in
+    /// place, so it has already taken `sample` when an overflow of the bands is returned.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if self.period <= 1 {
             return Err(TechalibError::BadParam(
                 "SMA period must be greater than 1".to_string(),
@@ -190,7 +305,7 @@ impl State<Float> for BBandsState {
         if !sample.is_finite() {
             return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
         }
-        if self.std_dev_mult.up <= 0.0 || self.std_dev_mult.down <= 0.0 {
+        if self.std_dev_mult.up <= T::ZERO || self.std_dev_mult.down <= T::ZERO {
             return Err(TechalibError::BadParam(
                 "Standard deviations must be greater than 0".to_string(),
             ));
@@ -239,19 +354,40 @@ impl State<Float> for BBandsState {
             }
         }
 
-        let mut window = self.last_window.clone();
+        if !matches!(self.ma_type, BBandsMA::SMA | BBandsMA::EMA(_)) {
+            let ma = self.ma.as_ref().ok_or(TechalibError::BadParam(
+                "BBands state must hold the MA state of its middle band".to_string(),
+            ))?;
+            if ma.period() != self.period {
+                return Err(TechalibError::BadParam(
+                    "MA state period must match the BBands period".to_string(),
+                ));
+            }
+        }
+
+        let inv_period = T::ONE / T::from_usize(self.period);
+        let (moving_averages, prev_sma) =
+            if precision::resync_due(self.last_window.head(), self.period) {
+                let moving_averages =
+                    window_moving_averages(self.last_window.make_contiguous(), inv_period);
+                (moving_averages, moving_averages.sma)
+            } else {
+                (self.moving_averages, self.middle)
+            };
 
-        let old_value = window.pop_front().ok_or(TechalibError::InsufficientData)?;
-        window.push_back(sample);
+        let old_value = self
+            .last_window
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
 
         let (upper, middle, lower, ma_sq, sma) = match self.ma_type {
             BBandsMA::SMA => bbands_sma_next_unchecked(
                 sample,
                 old_value,
-                self.middle,
-                self.moving_averages.ma_square,
+                prev_sma,
+                moving_averages.ma_square,
                 self.std_dev_mult,
-                1.0 / self.period as Float,
+                inv_period,
             ),
             BBandsMA::EMA(alpha) => {
                 let alpha = if let Some(value) = alpha {
@@ -263,30 +399,45 @@ impl State<Float> for BBandsState {
                     sample,
                     old_value,
                     self.middle,
-                    self.moving_averages,
+                    moving_averages,
                     alpha,
                     self.std_dev_mult,
-                    1.0 / self.period as Float,
+                    inv_period,
+                )
+            }
+            _ => {
+                // The MA state was validated above and leaves itself untouched on error
+                let ma = self.ma.as_mut().ok_or(TechalibError::InsufficientData)?;
+                ma.update(sample)?;
+                bbands_ma_next_unchecked(
+                    sample,
+                    old_value,
+                    ma.value(),
+                    moving_averages,
+                    self.std_dev_mult,
+                    inv_period,
                 )
             }
         };
 
         if !upper.is_finite() {
-            return Err(TechalibError::Overflow(0, upper));
+            return Err(TechalibError::Overflow(0, upper.to_f64()));
         }
         if !middle.is_finite() {
-            return Err(TechalibError::Overflow(0, middle));
+            return Err(TechalibError::Overflow(0, middle.to_f64()));
         }
         if !lower.is_finite() {
-            return Err(TechalibError::Overflow(0, lower));
+            return Err(TechalibError::Overflow(0, lower.to_f64()));
         }
 
         self.upper = upper;
         self.middle = middle;
         self.lower = lower;
+        self.percent_b = percent_b_unchecked(sample, upper, lower);
+        self.bandwidth = bandwidth_unchecked(upper, middle, lower);
         self.moving_averages.sma = sma;
         self.moving_averages.ma_square = ma_sq;
-        self.last_window = window;
+        self.last_window.push(sample);
         Ok(())
     }
 }
@@ -298,21 +449,21 @@ impl State<Float> for BBandsState {
 /// - `data`: A slice of `Float` values representing the data to calculate the Bollinger Bands on.
 /// - `period`: The time period over which to calculate the Bollinger Bands.
 /// - `std_dev_mul`: A struct containing the multipliers for the standard deviation used to calculate the upper and lower bands.
-/// - `ma_type`: The type of moving average to use (SMA or EMA).
+/// - `ma_type`: The type of moving average to use for the middle band.
 ///
 /// Returns
 /// ---
 /// A `Result` containing a [`BBandsResult`] with the upper, middle, and lower bands,
 /// or an error if the calculation fails.
-pub fn bbands(
-    data: &[Float],
+pub fn bbands<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    std_dev_mul: DeviationMulipliers,
-    ma_type: BBandsMA,
-) -> Result<BBandsResult, TechalibError> {
-    let mut output_upper = vec![0.0; data.len()];
-    let mut output_middle = vec![0.0; data.len()];
-    let mut output_lower = vec![0.0; data.len()];
+    std_dev_mul: DeviationMulipliers<T>,
+    ma_type: BBandsMA<T>,
+) -> Result<BBandsResult<T>, TechalibError> {
+    let mut output_upper = vec![T::ZERO; data.len()];
+    let mut output_middle = vec![T::ZERO; data.len()];
+    let mut output_lower = vec![T::ZERO; data.len()];
 
     let bbands_state = bbands_into(
         data,
@@ -339,7 +490,7 @@ pub fn bbands(
 /// - `data`: A slice of `Float` values representing the data to calculate the Bollinger Bands on.
 /// - `period`: The time period over which to calculate the Bollinger Bands.
 /// - `std_dev_mul`: A struct containing the multipliers for the standard deviation used to calculate the upper and lower bands.
-/// - `ma_type`: The type of moving average to use (SMA or EMA).
+/// - `ma_type`: The type of moving average to use for the middle band.
 ///
 /// Output Arguments
 /// ---
@@ -350,17 +501,17 @@ pub fn bbands(
 /// Returns
 /// ---
 /// A `Result` containing a [`BBandsState`] with the last calculated values and state, or an error if the calculation fails.
-pub fn bbands_into(
-    data: &[Float],
+pub fn bbands_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    std_dev_mul: DeviationMulipliers,
-    ma_type: BBandsMA,
-    output_upper: &mut [Float],
-    output_middle: &mut [Float],
-    output_lower: &mut [Float],
-) -> Result<BBandsState, TechalibError> {
+    std_dev_mul: DeviationMulipliers<T>,
+    ma_type: BBandsMA<T>,
+    output_upper: &mut [T],
+    output_middle: &mut [T],
+    output_lower: &mut [T],
+) -> Result<BBandsState<T>, TechalibError> {
     let len = data.len();
-    let inv_period = 1.0 / (period as Float);
+    let inv_period = T::ONE / T::from_usize(period);
     if period > len {
         return Err(TechalibError::InsufficientData);
     }
@@ -371,15 +522,15 @@ pub fn bbands_into(
         ));
     }
 
-    if std_dev_mul.up <= 0.0 || std_dev_mul.down <= 0.0 {
+    if std_dev_mul.up <= T::ZERO || std_dev_mul.down <= T::ZERO {
         return Err(TechalibError::BadParam(
             "Standard deviations must be greater than 0".to_string(),
         ));
     }
 
-    if output_upper.len() != len || output_middle.len() != len || output_lower.len() != len {
+    if output_upper.len() < len || output_middle.len() < len || output_lower.len() < len {
         return Err(TechalibError::BadParam(
-            "Output arrays must have the same length as input data".to_string(),
+            "Output arrays must be at least as long as the input data array".to_string(),
         ));
     }
 
@@ -397,6 +548,7 @@ pub fn bbands_into(
         sma: output_middle[period - 1],
         ma_square: ma_sq,
     };
+    let mut middle_ma = None;
     match ma_type {
         BBandsMA::SMA => {
             for idx in period..len {
@@ -406,6 +558,9 @@ pub fn bbands_into(
                         data[idx]
                     )));
                 }
+                if precision::resync_due(idx, period) {
+                    ma = window_moving_averages(&data[idx - period..idx], inv_period);
+                }
                 (
                     output_upper[idx],
                     output_middle[idx],
@@ -415,7 +570,7 @@ pub fn bbands_into(
                 ) = bbands_sma_next_unchecked(
                     data[idx],
                     data[idx - period],
-                    output_middle[idx - 1],
+                    ma.sma,
                     ma.ma_square,
                     std_dev_mul,
                     inv_period,
@@ -435,6 +590,9 @@ pub fn bbands_into(
                         data[idx]
                     )));
                 }
+                if precision::resync_due(idx, period) {
+                    ma = window_moving_averages(&data[idx - period..idx], inv_period);
+                }
                 (
                     output_upper[idx],
                     output_middle[idx],
@@ -451,13 +609,52 @@ pub fn bbands_into(
                     inv_period,
                 );
                 if !output_upper[idx].is_finite() {
-                    return Err(TechalibError::Overflow(idx, output_upper[idx]));
+                    return Err(TechalibError::Overflow(idx, output_upper[idx].to_f64()));
                 }
                 if !output_middle[idx].is_finite() {
-                    return Err(TechalibError::Overflow(idx, output_middle[idx]));
+                    return Err(TechalibError::Overflow(idx, output_middle[idx].to_f64()));
+                }
+                if !output_lower[idx].is_finite() {
+                    return Err(TechalibError::Overflow(idx, output_lower[idx].to_f64()));
+                }
+            }
+        }
+        _ => {
+            let ma_lookback = ma::lookback_from_period(period, MaType::from(ma_type));
+            middle_ma = Some(ma_into(data, period, ma_type.into(), output_middle)?);
+            (output_upper[period - 1], output_lower[period - 1]) = bands(
+                output_middle[period - 1],
+                ma.sma,
+                ma.ma_square,
+                std_dev_mul.up,
+                std_dev_mul.down,
+            );
+            for idx in period..len {
+                if precision::resync_due(idx, period) {
+                    ma = window_moving_averages(&data[idx - period..idx], inv_period);
+                }
+                (
+                    output_upper[idx],
+                    _,
+                    output_lower[idx],
+                    ma.ma_square,
+                    ma.sma,
+                ) = bbands_ma_next_unchecked(
+                    data[idx],
+                    data[idx - period],
+                    output_middle[idx],
+                    ma,
+                    std_dev_mul,
+                    inv_period,
+                );
+                if idx < ma_lookback {
+                    continue;
+                }
+                if !output_upper[idx].is_finite() {
+                    return Err(TechalibError::Overflow(idx, output_upper[idx].to_f64()));
                 }
                 if !output_lower[idx].is_finite() {
-                    return Err(TechalibError::Overflow(idx, output_lower[idx]));
+                    return Err(TechalibError::Overflow(idx, output_lower[idx].to_f64()));
                 }
             }
         }
@@ -467,23 +664,365 @@ pub fn bbands_into(
         upper: output_upper[len - 1],
         middle: output_middle[len - 1],
         lower: output_lower[len - 1],
+        percent_b: percent_b_unchecked(data[len - 1], output_upper[len - 1], output_lower[len - 1]),
+        bandwidth: bandwidth_unchecked(
+            output_upper[len - 1],
+            output_middle[len - 1],
+            output_lower[len - 1],
+        ),
         moving_averages: ma,
-        last_window: VecDeque::from(data[len - period..len].to_vec()),
+        last_window: RingBuffer::from(&data[len - period..len]),
+        ma: middle_ma,
         period,
         std_dev_mult: std_dev_mul,
         ma_type,
     })
 }
 
+batch_functions!(
+    bbands_batch,
+    bbands_batch_into,
+    bbands,
+    bbands_into,
+    BBandsResult,
+    BBandsState,
+    "Bollinger Bands",
+    data -> [output_upper, output_middle, output_lower];
+    period: usize, std_dev_mul: DeviationMulipliers<T>, ma_type: BBandsMA<T>
+);
+
+/// Calculate Bollinger Bands with their %B and bandwidth for a given data array and return the result.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of `Float` values representing the data to calculate the Bollinger Bands on.
+/// - `period`: The time period over which to calculate the Bollinger Bands.
+/// - `std_dev_mul`: A struct containing the multipliers for the standard deviation used to calculate the upper and lower bands.
+/// - `ma_type`: The type of moving average to use for the middle band.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`BBandsExtResult`] with the bands, the %B and the bandwidth,
+/// or an error if the calculation fails.
+pub fn bbands_ext<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    std_dev_mul: DeviationMulipliers<T>,
+    ma_type: BBandsMA<T>,
+) -> Result<BBandsExtResult<T>, TechalibError> {
+    let mut output_upper = vec![T::ZERO; data.len()];
+    let mut output_middle = vec![T::ZERO; data.len()];
+    let mut output_lower = vec![T::ZERO; data.len()];
+    let mut output_percent_b = vec![T::ZERO; data.len()];
+    let mut output_bandwidth = vec![T::ZERO; data.len()];
+
+    let bbands_state = bbands_ext_into(
+        data,
+        period,
+        std_dev_mul,
+        ma_type,
+        output_upper.as_mut_slice(),
+        output_middle.as_mut_slice(),
+        output_lower.as_mut_slice(),
+        output_percent_b.as_mut_slice(),
+        output_bandwidth.as_mut_slice(),
+    )?;
+
+    Ok(BBandsExtResult {
+        upper: output_upper,
+        middle: output_middle,
+        lower: output_lower,
+        percent_b: output_percent_b,
+        bandwidth: output_bandwidth,
+        state: bbands_state,
+    })
+}
+
+/// Calculate Bollinger Bands with their %B and bandwidth, store the results in provided output arrays and return the state.
+///
+/// The %B is `NaN` when the bands are merged (zero standard deviation) and
+/// the bandwidth is `NaN` when the middle band is zero.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of `Float` values representing the data to calculate the Bollinger Bands on.
+/// - `period`: The time period over which to calculate the Bollinger Bands.
+/// - `std_dev_mul`: A struct containing the multipliers for the standard deviation used to calculate the upper and lower bands.
+/// - `ma_type`: The type of moving average to use for the middle band.
+///
+/// Output Arguments
+/// ---
+/// - `output_upper`: A mutable slice to store the upper Bollinger Band values.
+/// - `output_middle`: A mutable slice to store the middle Bollinger Band values.
+/// - `output_lower`: A mutable slice to store the lower Bollinger Band values.
+/// - `output_percent_b`: A mutable slice to store the %B values.
+/// - `output_bandwidth`: A mutable slice to store the bandwidth values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`BBandsState`] with the last calculated values and state, or an error if the calculation fails.
+#[allow(clippy::too_many_arguments)]
+pub fn bbands_ext_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    std_dev_mul: DeviationMulipliers<T>,
+    ma_type: BBandsMA<T>,
+    output_upper: &mut [T],
+    output_middle: &mut [T],
+    output_lower: &mut [T],
+    output_percent_b: &mut [T],
+    output_bandwidth: &mut [T],
+) -> Result<BBandsState<T>, TechalibError> {
+    if output_percent_b.len() < data.len() || output_bandwidth.len() < data.len() {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let bbands_state = bbands_into(
+        data,
+        period,
+        std_dev_mul,
+        ma_type,
+        output_upper,
+        output_middle,
+        output_lower,
+    )?;
+
+    for idx in 0..data.len() {
+        output_percent_b[idx] =
+            percent_b_unchecked(data[idx], output_upper[idx], output_lower[idx]);
+        output_bandwidth[idx] =
+            bandwidth_unchecked(output_upper[idx], output_middle[idx], output_lower[idx]);
+    }
+
+    Ok(bbands_state)
+}
+
+batch_functions!(
+    bbands_ext_batch,
+    bbands_ext_batch_into,
+    bbands_ext,
+    bbands_ext_into,
+    BBandsExtResult,
+    BBandsState,
+    "extended Bollinger Bands",
+    data -> [output_upper, output_middle, output_lower, output_percent_b, output_bandwidth];
+    period: usize, std_dev_mul: DeviationMulipliers<T>, ma_type: BBandsMA<T>
+);
+
+/// Calculate Bollinger Bands for several periods and return the result.
+///
+/// The prefix sums of the values and of their squares are computed once and
+/// shared by every period.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of `Float` values representing the data to calculate the Bollinger Bands on.
+/// - `periods`: The time periods over which to calculate the Bollinger Bands.
+/// - `std_dev_mul`: A struct containing the multipliers for the standard deviation used to calculate the upper and lower bands.
+/// - `ma_type`: The type of moving average to use for the middle band.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`BBandsSweepResult`] with the upper, middle, and lower bands,
+/// or an error if the calculation fails.
+pub fn bbands_sweep<T: TechalibFloat>(
+    data: &[T],
+    periods: &[usize],
+    std_dev_mul: DeviationMulipliers<T>,
+    ma_type: BBandsMA<T>,
+) -> Result<BBandsSweepResult<T>, TechalibError> {
+    let size = data.len() * periods.len();
+    let mut output_upper = vec![T::ZERO; size];
+    let mut output_middle = vec![T::ZERO; size];
+    let mut output_lower = vec![T::ZERO; size];
+
+    let states = bbands_sweep_into(
+        data,
+        periods,
+        std_dev_mul,
+        ma_type,
+        output_upper.as_mut_slice(),
+        output_middle.as_mut_slice(),
+        output_lower.as_mut_slice(),
+    )?;
+
+    Ok(BBandsSweepResult {
+        upper: output_upper,
+        middle: output_middle,
+        lower: output_lower,
+        states,
+    })
+}
+
+/// Calculate Bollinger Bands for several periods, store the results in provided output arrays and return the states.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of `Float` values representing the data to calculate the Bollinger Bands on.
+/// - `periods`: The time periods over which to calculate the Bollinger Bands.
+/// - `std_dev_mul`: A struct containing the multipliers for the standard deviation used to calculate the upper and lower bands.
+/// - `ma_type`: The type of moving average to use for the middle band.
+///
+/// Output Arguments
+/// ---
+/// - `output_upper`: A mutable slice of length at least `data.len() * periods.len()` to store
+///   the upper Bollinger Band values of each period as consecutive columns.
+/// - `output_middle`: A mutable slice to store the middle Bollinger Band values, with the same layout.
+/// - `output_lower`: A mutable slice to store the lower Bollinger Band values, with the same layout.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`BBandsState`] per period, or an error if the calculation fails.
+pub fn bbands_sweep_into<T: TechalibFloat>(
+    data: &[T],
+    periods: &[usize],
+    std_dev_mul: DeviationMulipliers<T>,
+    ma_type: BBandsMA<T>,
+    output_upper: &mut [T],
+    output_middle: &mut [T],
+    output_lower: &mut [T],
+) -> Result<Vec<BBandsState<T>>, TechalibError> {
+    let len = data.len();
+    for &period in periods {
+        if period > len {
+            return Err(TechalibError::InsufficientData);
+        }
+        if period <= 1 {
+            return Err(TechalibError::BadParam(
+                "SMA period must be greater than 1".to_string(),
+            ));
+        }
+    }
+
+    if std_dev_mul.up <= T::ZERO || std_dev_mul.down <= T::ZERO {
+        return Err(TechalibError::BadParam(
+            "Standard deviations must be greater than 0".to_string(),
+        ));
+    }
+
+    check_sweep_output(len, periods, output_upper.len())?;
+    check_sweep_output(len, periods, output_middle.len())?;
+    check_sweep_output(len, periods, output_lower.len())?;
+
+    // The sums are centered on the mean of the series: the variance does not
+    // depend on the offset, and it keeps the prefix sums of squares small.
+    let sums = prefix_sums(data, |value| value)?;
+    let center = if len == 0 {
+        0.0
+    } else {
+        sums[len] / len as f64
+    };
+    let max_period = periods.iter().copied().max().unwrap_or(1);
+    let sums = BlockPrefixSums::new(data, max_period, |value| value - center)?;
+    let sums_sq = BlockPrefixSums::new(data, max_period, |value| {
+        (value - center) * (value - center)
+    })?;
+
+    let columns = output_upper
+        .chunks_mut(len.max(1))
+        .zip(output_middle.chunks_mut(len.max(1)))
+        .zip(output_lower.chunks_mut(len.max(1)))
+        .map(|((upper, middle), lower)| (upper, middle, lower))
+        .collect();
+    sweep(periods, columns, |period, (upper, middle, lower)| {
+        let inv_period = 1.0 / period as f64;
+        let alpha = match ma_type {
+            BBandsMA::EMA(Some(alpha)) => Some(alpha),
+            BBandsMA::EMA(None) => Some(period_to_alpha(period, None)?),
+            _ => None,
+        };
+        let middle_ma = match ma_type {
+            BBandsMA::SMA | BBandsMA::EMA(_) => None,
+            _ => Some(ma_into(data, period, ma_type.into(), middle)?),
+        };
+        let ma_lookback = ma::lookback_from_period(period, MaType::from(ma_type));
+        upper[..period - 1].fill(T::NAN);
+        middle[..period - 1].fill(T::NAN);
+        lower[..period - 1].fill(T::NAN);
+
+        if let Some(alpha) = alpha {
+            middle[period - 1] = T::from_f64(center + sums.sum(0, period) * inv_period);
+            for idx in period..len {
+                middle[idx] = ema_next_unchecked(data[idx], middle[idx - 1], alpha);
+            }
+        }
+        for ((sums, range), (sums_sq, _)) in sums.windows(period).zip(sums_sq.windows(period)) {
+            simd::window_bands(
+                sums,
+                sums_sq,
+                period,
+                center,
+                (std_dev_mul.up, std_dev_mul.down),
+                alpha.is_none() && middle_ma.is_none(),
+                &mut upper[range.clone()],
+                &mut middle[range.clone()],
+                &mut lower[range],
+            );
+        }
+        let start = ma_lookback.max(period - 1).min(len);
+        let non_finite = [&*upper, &*middle, &*lower]
+            .into_iter()
+            .filter_map(|output| {
+                simd::first_non_finite(&output[start..]).map(|idx| (start + idx, output))
+            })
+            .min_by_key(|&(idx, _)| idx);
+        if let Some((idx, output)) = non_finite {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+
+        let mean = sums.sum(len - period, len) * inv_period;
+        let variance = sums_sq.sum(len - period, len) * inv_period - mean * mean;
+        let ma = MovingAverageState {
+            sma: T::from_f64(center + mean),
+            ma_square: T::from_f64(variance + (center + mean) * (center + mean)),
+        };
+
+        Ok(BBandsState {
+            upper: upper[len - 1],
+            middle: middle[len - 1],
+            lower: lower[len - 1],
+            percent_b: percent_b_unchecked(data[len - 1], upper[len - 1], lower[len - 1]),
+            bandwidth: bandwidth_unchecked(upper[len - 1], middle[len - 1], lower[len - 1]),
+            moving_averages: ma,
+            last_window: RingBuffer::from(&data[len - period..len]),
+            ma: middle_ma,
+            period,
+            std_dev_mult: std_dev_mul,
+            ma_type,
+        })
+    })
+}
+
 #[inline(always)]
-fn bbands_sma_next_unchecked(
-    new_value: Float,
-    old_value: Float,
-    prev_ma: Float,
-    prev_ma_sq: Float,
-    std: DeviationMulipliers,
-    inv_period: Float,
-) -> (Float, Float, Float, Float, Float) {
+fn percent_b_unchecked<T: TechalibFloat>(value: T, upper: T, lower: T) -> T {
+    let width = upper - lower;
+    if width == T::ZERO {
+        T::NAN
+    } else {
+        (value - lower) / width
+    }
+}
+
+#[inline(always)]
+fn bandwidth_unchecked<T: TechalibFloat>(upper: T, middle: T, lower: T) -> T {
+    if middle == T::ZERO {
+        T::NAN
+    } else {
+        (upper - lower) / middle
+    }
+}
+
+#[inline(always)]
+fn bbands_sma_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    old_value: T,
+    prev_ma: T,
+    prev_ma_sq: T,
+    std: DeviationMulipliers<T>,
+    inv_period: T,
+) -> (T, T, T, T, T) {
     let ma_sq = sma_next_unchecked(
         new_value * new_value,
         old_value * old_value,
@@ -496,65 +1035,77 @@ fn bbands_sma_next_unchecked(
 }
 
 #[inline(always)]
-fn bbands_ema_next_unchecked(
-    new_value: Float,
-    old_value: Float,
-    prev_middle: Float,
-    moving_avgs: MovingAverageState,
-    alpha: Float,
-    std: DeviationMulipliers,
-    inv_period: Float,
-) -> (Float, Float, Float, Float, Float) {
+fn bbands_ema_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    old_value: T,
+    prev_middle: T,
+    moving_avgs: MovingAverageState<T>,
+    alpha: T,
+    std: DeviationMulipliers<T>,
+    inv_period: T,
+) -> (T, T, T, T, T) {
+    let middle = ema_next_unchecked(new_value, prev_middle, alpha);
+    bbands_ma_next_unchecked(new_value, old_value, middle, moving_avgs, std, inv_period)
+}
+
+#[inline(always)]
+fn bbands_ma_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    old_value: T,
+    middle: T,
+    moving_avgs: MovingAverageState<T>,
+    std: DeviationMulipliers<T>,
+    inv_period: T,
+) -> (T, T, T, T, T) {
     let sma_sq = sma_next_unchecked(
         new_value * new_value,
         old_value * old_value,
         moving_avgs.ma_square,
         inv_period,
     );
-    let sma: Float = sma_next_unchecked(new_value, old_value, moving_avgs.sma, inv_period);
-    let middle = ema_next_unchecked(new_value, prev_middle, alpha);
+    let sma: T = sma_next_unchecked(new_value, old_value, moving_avgs.sma, inv_period);
     let (upper, lower) = bands(middle, sma, sma_sq, std.up, std.down);
     (upper, middle, lower, sma_sq, sma)
 }
 
 #[inline(always)]
-fn bands(
-    middle: Float,
-    mean: Float,
-    mean_sq: Float,
-    std_up: Float,
-    std_down: Float,
-) -> (Float, Float) {
+fn bands<T: TechalibFloat>(middle: T, mean: T, mean_sq: T, std_up: T, std_down: T) -> (T, T) {
     let std = (mean_sq - mean * mean).abs().sqrt();
     (middle + std_up * std, middle - std_down * std)
 }
 
+/// Mean and mean of the squares of a full window
+#[inline(always)]
+fn window_moving_averages<T: TechalibFloat>(window: &[T], inv_period: T) -> MovingAverageState<T> {
+    let (sum, sum_sq) = simd::sum_and_sum_sq(window);
+    MovingAverageState {
+        sma: sum * inv_period,
+        ma_square: sum_sq * inv_period,
+    }
+}
+
 #[inline(always)]
-fn init_state_unchecked(
-    data: &[Float],
+fn init_state_unchecked<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    inv_period: Float,
-    std: DeviationMulipliers,
-    output_upper: &mut [Float],
-    output_middle: &mut [Float],
-    output_lower: &mut [Float],
-) -> Result<Float, TechalibError> {
-    let (mut sum, mut sum_sq) = (0.0, 0.0);
-    for idx in 0..period {
-        let value = &data[idx];
-        if !value.is_finite() {
+    inv_period: T,
+    std: DeviationMulipliers<T>,
+    output_upper: &mut [T],
+    output_middle: &mut [T],
+    output_lower: &mut [T],
+) -> Result<T, TechalibError> {
+    let (sum, sum_sq) = simd::sum_and_sum_sq(&data[..period]);
+    if !sum.is_finite() || !sum_sq.is_finite() {
+        if let Some(idx) = simd::first_non_finite(&data[..period]) {
             return Err(TechalibError::DataNonFinite(format!(
                 "data[{idx}] = {:?}",
-                value
+                data[idx]
             )));
-        } else {
-            sum += value;
-            sum_sq += value * value;
         }
-        output_upper[idx] = Float::NAN;
-        output_middle[idx] = Float::NAN;
-        output_lower[idx] = Float::NAN;
     }
+    output_upper[..period].fill(T::NAN);
+    output_middle[..period].fill(T::NAN);
+    output_lower[..period].fill(T::NAN);
     output_middle[period - 1] = sum * inv_period;
     let ma_sq = sum_sq * inv_period;
     (output_upper[period - 1], output_lower[period - 1]) = bands(
@@ -567,19 +1118,19 @@ fn init_state_unchecked(
     if !output_middle[period - 1].is_finite() {
         return Err(TechalibError::Overflow(
             period - 1,
-            output_middle[period - 1],
+            output_middle[period - 1].to_f64(),
         ));
     }
     if !output_upper[period - 1].is_finite() {
         return Err(TechalibError::Overflow(
             period - 1,
-            output_upper[period - 1],
+            output_upper[period - 1].to_f64(),
         ));
     }
     if !output_lower[period - 1].is_finite() {
         return Err(TechalibError::Overflow(
             period - 1,
-            output_lower[period - 1],
+            output_lower[period - 1].to_f64(),
         ));
     }
     Ok(ma_sq)
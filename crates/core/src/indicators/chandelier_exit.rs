@@ -0,0 +1,330 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Chandelier Exit (CHANDELIER_EXIT) implementation
+//!
+//! The long stop hangs a multiple of the Average True Range
+//! ([`atr`](crate::indicators::atr)) below the highest high of the period,
+//! and the short stop the same distance above the lowest low.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::atr::{
+    atr_next_unchecked, lookback_from_period as atr_lookback, true_range_unchecked, AtrState,
+};
+use crate::indicators::max::{max_into, MaxState};
+use crate::indicators::min::{min_into, MinState};
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Chandelier Exit calculation result
+/// ---
+/// This struct holds the result of the Chandelier Exit calculation.
+///
+/// Attributes
+/// ---
+/// - `long_stop`: The trailing stop values of long positions.
+/// - `short_stop`: The trailing stop values of short positions.
+/// - `state`: A [`ChandelierExitState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct ChandelierExitResult<T = Float> {
+    /// The trailing stop values of long positions.
+    pub long_stop: Vec<T>,
+    /// The trailing stop values of short positions.
+    pub short_stop: Vec<T>,
+    /// A [`ChandelierExitState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: ChandelierExitState<T>,
+}
+
+/// Chandelier Exit calculation state
+/// ---
+/// This struct holds the state of the Chandelier Exit calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `long_stop`: The last trailing stop of long positions.
+/// - `short_stop`: The last trailing stop of short positions.
+///
+/// **State values**
+/// - `max_state`: The [`MaxState`] of the highs.
+/// - `min_state`: The [`MinState`] of the lows.
+/// - `atr`: The [`AtrState`] of the stops.
+///
+/// **Parameters**
+/// - `multiplier`: The multiplier of the ATR used to calculate the stops.
+#[derive(Debug, Clone)]
+pub struct ChandelierExitState<T = Float> {
+    // Outputs
+    /// The last trailing stop of long positions.
+    pub long_stop: T,
+    /// The last trailing stop of short positions.
+    pub short_stop: T,
+
+    // State values
+    /// The [`MaxState`] of the highs.
+    pub max_state: MaxState<T>,
+    /// The [`MinState`] of the lows.
+    pub min_state: MinState<T>,
+    /// The [`AtrState`] of the stops.
+    pub atr: AtrState<T>,
+
+    // Parameters
+    /// The multiplier of the ATR used to calculate the stops.
+    pub multiplier: T,
+}
+
+impl<T: TechalibFloat> State<(T, T, T)> for ChandelierExitState<T> {
+    /// Update the [`ChandelierExitState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, close)` of the new bar.
+    fn update(&mut self, sample: (T, T, T)) -> Result<(), TechalibError> {
+        check_multiplier_unchecked(self.multiplier)?;
+        if self.max_state.period != self.min_state.period
+            || self.max_state.period != self.atr.period
+        {
+            return Err(TechalibError::BadParam(format!(
+                "CHANDELIER_EXIT periods do not match: max_state ({}), min_state ({}), atr ({})",
+                self.max_state.period, self.min_state.period, self.atr.period
+            )));
+        }
+
+        let (high, low, _) = sample;
+        let highest = self.max_state.next(high)?;
+        let lowest = self.min_state.next(low)?;
+        let mut atr = self.atr;
+        atr.update(sample)?;
+
+        let (long_stop, short_stop) = stops_unchecked(highest, lowest, atr.atr, self.multiplier);
+        if !long_stop.is_finite() {
+            return Err(TechalibError::Overflow(0, long_stop.to_f64()));
+        }
+        if !short_stop.is_finite() {
+            return Err(TechalibError::Overflow(0, short_stop.to_f64()));
+        }
+
+        self.long_stop = long_stop;
+        self.short_stop = short_stop;
+        self.max_state.commit_unchecked(high, highest);
+        self.min_state.commit_unchecked(low, lowest);
+        self.atr = atr;
+        Ok(())
+    }
+}
+
+/// Lookback period for Chandelier Exit calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Chandelier Exit values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    atr_lookback(period)
+}
+
+/// Calculation of the Chandelier Exit function
+/// ---
+/// It returns a [`ChandelierExitResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period of the highest high, the lowest low and the ATR.
+/// - `multiplier`: The multiplier of the ATR used to calculate the stops.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ChandelierExitResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn chandelier_exit<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    multiplier: T,
+) -> Result<ChandelierExitResult<T>, TechalibError> {
+    let len = ohlcv.close.len();
+    let mut output_long_stop = vec![T::ZERO; len];
+    let mut output_short_stop = vec![T::ZERO; len];
+
+    let chandelier_exit_state = chandelier_exit_into(
+        ohlcv,
+        period,
+        multiplier,
+        output_long_stop.as_mut_slice(),
+        output_short_stop.as_mut_slice(),
+    )?;
+
+    Ok(ChandelierExitResult {
+        long_stop: output_long_stop,
+        short_stop: output_short_stop,
+        state: chandelier_exit_state,
+    })
+}
+
+/// Calculation of the Chandelier Exit function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`ChandelierExitState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period of the highest high, the lowest low and the ATR.
+/// - `multiplier`: The multiplier of the ATR used to calculate the stops.
+///
+/// Output Arguments
+/// ---
+/// - `output_long_stop`: A mutable slice to store the trailing stops of long positions.
+/// - `output_short_stop`: A mutable slice to store the trailing stops of short positions.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ChandelierExitState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn chandelier_exit_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    multiplier: T,
+    output_long_stop: &mut [T],
+    output_short_stop: &mut [T],
+) -> Result<ChandelierExitState<T>, TechalibError> {
+    check_multiplier_unchecked(multiplier)?;
+
+    let (high, low, close) = (ohlcv.high, ohlcv.low, ohlcv.close);
+    let len = columns_len(&[("high", high), ("low", low), ("close", close)])?;
+    if output_long_stop.len() < len || output_short_stop.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    // The stops are shifted from the highest highs and lowest lows in place
+    let max_state = max_into(high, period, output_long_stop)?;
+    let min_state = min_into(low, period, output_short_stop)?;
+
+    let mut atr = T::ZERO;
+    for idx in 0..len {
+        if !close[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "close[{idx}] = {:?}",
+                close[idx]
+            )));
+        }
+        if idx == 0 {
+            continue;
+        }
+
+        let true_range = true_range_unchecked(high[idx], low[idx], close[idx - 1]);
+        if idx < lookback {
+            atr += true_range;
+            output_long_stop[idx] = T::NAN;
+            output_short_stop[idx] = T::NAN;
+            continue;
+        }
+        atr = if idx == lookback {
+            (atr + true_range) / T::from_usize(period)
+        } else {
+            atr_next_unchecked(true_range, atr, T::from_usize(period))
+        };
+
+        (output_long_stop[idx], output_short_stop[idx]) = stops_unchecked(
+            output_long_stop[idx],
+            output_short_stop[idx],
+            atr,
+            multiplier,
+        );
+        if !output_long_stop[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output_long_stop[idx].to_f64()));
+        }
+        if !output_short_stop[idx].is_finite() {
+            return Err(TechalibError::Overflow(
+                idx,
+                output_short_stop[idx].to_f64(),
+            ));
+        }
+    }
+
+    Ok(ChandelierExitState {
+        long_stop: output_long_stop[len - 1],
+        short_stop: output_short_stop[len - 1],
+        max_state,
+        min_state,
+        atr: AtrState {
+            atr,
+            prev_close: close[len - 1],
+            period,
+        },
+        multiplier,
+    })
+}
+
+batch_functions!(
+    chandelier_exit_batch,
+    chandelier_exit_batch_into,
+    chandelier_exit,
+    chandelier_exit_into,
+    ChandelierExitResult,
+    ChandelierExitState,
+    "Chandelier Exit",
+    ohlcv -> [output_long_stop, output_short_stop];
+    period: usize, multiplier: T
+);
+
+#[inline(always)]
+fn stops_unchecked<T: TechalibFloat>(highest: T, lowest: T, atr: T, multiplier: T) -> (T, T) {
+    let width = multiplier * atr;
+    (highest - width, lowest + width)
+}
+
+#[inline(always)]
+fn check_multiplier_unchecked<T: TechalibFloat>(multiplier: T) -> Result<(), TechalibError> {
+    if !multiplier.is_finite() || multiplier <= T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "Multiplier must be a finite value greater than 0, got: {multiplier:?}"
+        )));
+    }
+    Ok(())
+}
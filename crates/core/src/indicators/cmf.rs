@@ -0,0 +1,349 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Chaikin Money Flow (CMF) implementation
+//!
+//! The money flow volume of a bar is the volume weighted by the position of
+//! the close in the range, `((close - low) - (high - close)) / (high - low)`,
+//! and the CMF is the sum of the money flow volumes over the period divided
+//! by the sum of the volumes.
+//!
+//! A bar with `high == low` has no money flow, and the CMF is `0` while the
+//! sum of the volumes is zero.
+
+use alloc::collections::VecDeque;
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+
+/// Chaikin Money Flow calculation result
+/// ---
+/// This struct holds the result of the Chaikin Money Flow calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: The Chaikin Money Flow values.
+/// - `state`: A [`CmfState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct CmfResult<T = Float> {
+    /// The Chaikin Money Flow values.
+    pub values: Vec<T>,
+    /// A [`CmfState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: CmfState<T>,
+}
+
+/// Chaikin Money Flow calculation state
+/// ---
+/// This struct holds the state of the Chaikin Money Flow calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `cmf`: The last Chaikin Money Flow value.
+///
+/// **State values**
+/// - `mfv_sum`: The sum of the money flow volumes over the period.
+/// - `volume_sum`: The sum of the volumes over the period.
+/// - `last_mfv`: The last `period` money flow volumes.
+/// - `last_volume`: The last `period` volumes.
+///
+/// **Parameters**
+/// - `period`: The period of the sums.
+#[derive(Debug, Clone)]
+pub struct CmfState<T = Float> {
+    // Outputs
+    /// The last Chaikin Money Flow value.
+    pub cmf: T,
+
+    // State values
+    /// The sum of the money flow volumes over the period.
+    pub mfv_sum: T,
+    /// The sum of the volumes over the period.
+    pub volume_sum: T,
+    /// The last `period` money flow volumes.
+    pub last_mfv: RingBuffer<T>,
+    /// The last `period` volumes.
+    pub last_volume: RingBuffer<T>,
+
+    // Parameters
+    /// The period of the sums.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<(T, T, T, T)> for CmfState<T> {
+    /// Update the [`CmfState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, close, volume)` of the new bar.
+    fn update(&mut self, sample: (T, T, T, T)) -> Result<(), TechalibError> {
+        if self.period == 0 {
+            return Err(TechalibError::BadParam(
+                "Period must be greater than 0".to_string(),
+            ));
+        }
+        if self.last_mfv.len() != self.period || self.last_volume.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "CMF state windows length ({}, {}) do not match period ({})",
+                self.last_mfv.len(),
+                self.last_volume.len(),
+                self.period
+            )));
+        }
+        let (high, low, close, volume) = sample;
+        check_finite_unchecked(0, high, low, close, volume)?;
+        if !self.mfv_sum.is_finite() || !self.volume_sum.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.mfv_sum = {:?}, self.volume_sum = {:?}",
+                self.mfv_sum, self.volume_sum
+            )));
+        }
+
+        let mfv = money_flow_volume_unchecked(high, low, close, volume);
+        let old_mfv = self
+            .last_mfv
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
+        let old_volume = self
+            .last_volume
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
+
+        let mfv_sum = self.mfv_sum + mfv - old_mfv;
+        let volume_sum = self.volume_sum + volume - old_volume;
+        let cmf = cmf_unchecked(mfv_sum, volume_sum);
+        if !cmf.is_finite() {
+            return Err(TechalibError::Overflow(0, cmf.to_f64()));
+        }
+
+        self.cmf = cmf;
+        self.mfv_sum = mfv_sum;
+        self.volume_sum = volume_sum;
+        self.last_mfv.push(mfv);
+        self.last_volume.push(volume);
+        Ok(())
+    }
+}
+
+/// Lookback period for Chaikin Money Flow calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Chaikin Money Flow values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the Chaikin Money Flow function
+/// ---
+/// It returns a [`CmfResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low`, `close` and `volume` columns are used.
+/// - `period`: The period of the sums.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`CmfResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn cmf<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+) -> Result<CmfResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; ohlcv.close.len()];
+
+    let cmf_state = cmf_into(ohlcv, period, output.as_mut_slice())?;
+
+    Ok(CmfResult {
+        values: output,
+        state: cmf_state,
+    })
+}
+
+/// Calculation of the Chaikin Money Flow function
+/// ---
+/// It stores the results in the provided output array and
+/// return the state [`CmfState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low`, `close` and `volume` columns are used.
+/// - `period`: The period of the sums.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice to store the Chaikin Money Flow values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`CmfState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn cmf_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    output: &mut [T],
+) -> Result<CmfState<T>, TechalibError> {
+    if period == 0 {
+        return Err(TechalibError::BadParam(
+            "Period must be greater than 0".to_string(),
+        ));
+    }
+
+    let (high, low, close, volume) = (ohlcv.high, ohlcv.low, ohlcv.close, ohlcv.volume);
+    let len = columns_len(&[
+        ("high", high),
+        ("low", low),
+        ("close", close),
+        ("volume", volume),
+    ])?;
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let mut last_mfv = VecDeque::with_capacity(period + 1);
+    let mut last_volume = VecDeque::with_capacity(period + 1);
+    let mut mfv_sum = T::ZERO;
+    let mut volume_sum = T::ZERO;
+    for idx in 0..len {
+        check_finite_unchecked(idx, high[idx], low[idx], close[idx], volume[idx])?;
+        let mfv = money_flow_volume_unchecked(high[idx], low[idx], close[idx], volume[idx]);
+        last_mfv.push_back(mfv);
+        last_volume.push_back(volume[idx]);
+        mfv_sum += mfv;
+        volume_sum += volume[idx];
+        if idx > lookback {
+            mfv_sum -= last_mfv
+                .pop_front()
+                .ok_or(TechalibError::InsufficientData)?;
+            volume_sum -= last_volume
+                .pop_front()
+                .ok_or(TechalibError::InsufficientData)?;
+        }
+        if idx < lookback {
+            output[idx] = T::NAN;
+            continue;
+        }
+        output[idx] = cmf_unchecked(mfv_sum, volume_sum);
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(CmfState {
+        cmf: output[len - 1],
+        mfv_sum,
+        volume_sum,
+        last_mfv: RingBuffer::from(Vec::from(last_mfv)),
+        last_volume: RingBuffer::from(Vec::from(last_volume)),
+        period,
+    })
+}
+
+batch_functions!(
+    cmf_batch,
+    cmf_batch_into,
+    cmf,
+    cmf_into,
+    CmfResult,
+    CmfState,
+    "Chaikin Money Flow",
+    ohlcv -> [output];
+    period: usize
+);
+
+#[inline(always)]
+fn money_flow_volume_unchecked<T: TechalibFloat>(high: T, low: T, close: T, volume: T) -> T {
+    let range = high - low;
+    if range == T::ZERO {
+        return T::ZERO;
+    }
+    ((close - low) - (high - close)) / range * volume
+}
+
+#[inline(always)]
+fn cmf_unchecked<T: TechalibFloat>(mfv_sum: T, volume_sum: T) -> T {
+    if volume_sum == T::ZERO {
+        return T::ZERO;
+    }
+    mfv_sum / volume_sum
+}
+
+#[inline(always)]
+fn check_finite_unchecked<T: TechalibFloat>(
+    idx: usize,
+    high: T,
+    low: T,
+    close: T,
+    volume: T,
+) -> Result<(), TechalibError> {
+    if !high.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "high[{idx}] = {high:?}"
+        )));
+    }
+    if !low.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "low[{idx}] = {low:?}"
+        )));
+    }
+    if !close.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "close[{idx}] = {close:?}"
+        )));
+    }
+    if !volume.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "volume[{idx}] = {volume:?}"
+        )));
+    }
+    Ok(())
+}
@@ -40,11 +40,13 @@
 
 //! Double Exponential Moving Average (DEMA) implementation
 
+use crate::batch::batch_functions;
 use crate::errors::TechalibError;
 use crate::indicators::ema::{ema_next_unchecked, period_to_alpha};
 use crate::indicators::sma::init_sma_unchecked;
 use crate::traits::State;
-use crate::types::Float;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 /// Double Exponential Moving Average (DEMA) result.
 /// ---
@@ -57,12 +59,12 @@ use crate::types::Float;
 /// - `state`: A [`DemaState`], which can be used to calculate the next values
 ///   incrementally.
 #[derive(Debug)]
-pub struct DemaResult {
+pub struct DemaResult<T = Float> {
     /// The calculated DEMA values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// A [`DemaState`], which can be used to calculate the next values
     /// incrementally.
-    pub state: DemaState,
+    pub state: DemaState<T>,
 }
 
 /// DEMA calculation state
@@ -83,31 +85,31 @@ pub struct DemaResult {
 /// - `period`: The period used for the DEMA calculation.
 /// - `alpha`: The alpha factor used for the EMA calculation.
 #[derive(Debug, Clone, Copy)]
-pub struct DemaState {
+pub struct DemaState<T = Float> {
     // Outputs values
     /// The last calculated DEMA value
-    pub dema: Float,
+    pub dema: T,
 
     // State values
     /// The last calculated EMA value
-    pub ema_1: Float,
+    pub ema_1: T,
     /// The last calculated EMA2 value
-    pub ema_2: Float,
+    pub ema_2: T,
 
     // Parameters
     /// The period used for the DEMA calculation
     pub period: usize,
     /// The alpha factor used for the EMA calculation
-    pub alpha: Float,
+    pub alpha: T,
 }
 
-impl State<Float> for DemaState {
+impl<T: TechalibFloat> State<T> for DemaState<T> {
     /// Update the [`DemaState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input value to update the state with.
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if self.period <= 1 {
             return Err(TechalibError::BadParam(
                 "Period must be greater than 1".to_string(),
@@ -141,7 +143,7 @@ impl State<Float> for DemaState {
         let (dema, ema_1, ema_2) = dema_next_unchecked(sample, self.ema_1, self.ema_2, self.alpha);
 
         if !dema.is_finite() {
-            return Err(TechalibError::Overflow(0, dema));
+            return Err(TechalibError::Overflow(0, dema.to_f64()));
         }
         self.dema = dema;
         self.ema_1 = ema_1;
@@ -162,12 +164,12 @@ impl State<Float> for DemaState {
 /// ---
 /// A `Result` containing a [`DemaResult`] with the calculated DEMA values and state,
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn dema(
-    data: &[Float],
+pub fn dema<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    alpha: Option<Float>,
-) -> Result<DemaResult, TechalibError> {
-    let mut output = vec![0.0; data.len()];
+    alpha: Option<T>,
+) -> Result<DemaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
 
     let dema_state = dema_into(data, period, alpha, &mut output)?;
 
@@ -196,14 +198,14 @@ pub fn dema(
 /// ---
 /// A `Result` containing a [`DemaState`]
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn dema_into(
-    data: &[Float],
+pub fn dema_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    alpha: Option<Float>,
-    output: &mut [Float],
-) -> Result<DemaState, TechalibError> {
+    alpha: Option<T>,
+    output: &mut [T],
+) -> Result<DemaState<T>, TechalibError> {
     let len = data.len();
-    let inv_period = 1.0 / period as Float;
+    let inv_period = T::ONE / T::from_usize(period);
     let skip_period = dema_skip_period_unchecked(period);
 
     if period == 0 || len < skip_period + 1 {
@@ -224,7 +226,10 @@ pub fn dema_into(
         init_dema_unchecked(data, period, inv_period, skip_period, alpha, output)?;
     output[skip_period] = output_value;
     if !output[skip_period].is_finite() {
-        return Err(TechalibError::Overflow(skip_period, output[skip_period]));
+        return Err(TechalibError::Overflow(
+            skip_period,
+            output[skip_period].to_f64(),
+        ));
     }
 
     for idx in skip_period + 1..len {
@@ -238,7 +243,7 @@ pub fn dema_into(
         (output[idx], ema_1, ema_2) = dema_next_unchecked(data[idx], ema_1, ema_2, alpha);
 
         if !output[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output[idx]));
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
         }
     }
 
@@ -251,27 +256,38 @@ pub fn dema_into(
     })
 }
 
+batch_functions!(
+    dema_batch,
+    dema_batch_into,
+    dema,
+    dema_into,
+    DemaResult,
+    DemaState,
+    "DEMA";
+    period: usize, alpha: Option<T>
+);
+
 #[inline(always)]
-pub(crate) fn dema_next_unchecked(
-    new_value: Float,
-    prev_ema_1: Float,
-    prev_ema_2: Float,
-    alpha: Float,
-) -> (Float, Float, Float) {
+pub(crate) fn dema_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    prev_ema_1: T,
+    prev_ema_2: T,
+    alpha: T,
+) -> (T, T, T) {
     let ema_1 = ema_next_unchecked(new_value, prev_ema_1, alpha);
     let ema_2 = ema_next_unchecked(ema_1, prev_ema_2, alpha);
     (calculate_dema(ema_1, ema_2), ema_1, ema_2)
 }
 
 #[inline(always)]
-pub(crate) fn init_dema_unchecked(
-    data: &[Float],
+pub(crate) fn init_dema_unchecked<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    inv_period: Float,
+    inv_period: T,
     skip_period: usize,
-    alpha: Float,
-    output: &mut [Float],
-) -> Result<(Float, Float, Float), TechalibError> {
+    alpha: T,
+    output: &mut [T],
+) -> Result<(T, T, T), TechalibError> {
     let mut ema_1 = init_sma_unchecked(data, period, inv_period, output)?;
 
     let mut sum_ema_2 = ema_1;
@@ -284,7 +300,7 @@ pub(crate) fn init_dema_unchecked(
         }
         ema_1 = ema_next_unchecked(data[idx], ema_1, alpha);
         sum_ema_2 += ema_1;
-        output[idx] = Float::NAN;
+        output[idx] = T::NAN;
     }
     ema_1 = ema_next_unchecked(data[skip_period], ema_1, alpha);
     sum_ema_2 += ema_1;
@@ -294,8 +310,8 @@ pub(crate) fn init_dema_unchecked(
 }
 
 #[inline(always)]
-fn calculate_dema(ema_1: Float, ema_2: Float) -> Float {
-    (2.0 * ema_1) - ema_2
+fn calculate_dema<T: TechalibFloat>(ema_1: T, ema_2: T) -> T {
+    (T::from_f64(2.0) * ema_1) - ema_2
 }
 
 /// Calculate the period to skip for DEMA.
@@ -0,0 +1,266 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Donchian Channels (DONCHIAN) implementation
+//!
+//! The upper band is the highest high and the lower band the lowest low
+//! over the period, the middle band is the average of both.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::max::{max_into, MaxState};
+use crate::indicators::min::{min_into, MinState};
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Donchian Channels result
+/// ---
+/// This struct holds the result of the Donchian Channels calculation.
+/// It contains the upper, middle, and lower bands as well as the state of the calculation.
+///
+/// Attributes
+/// ---
+/// - `upper`: The upper band values (highest high).
+/// - `middle`: The middle band values (average of the upper and lower bands).
+/// - `lower`: The lower band values (lowest low).
+/// - `state`: A [`DonchianState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct DonchianResult<T = Float> {
+    /// The upper band values (highest high).
+    pub upper: Vec<T>,
+    /// The middle band values (average of the upper and lower bands).
+    pub middle: Vec<T>,
+    /// The lower band values (lowest low).
+    pub lower: Vec<T>,
+    /// A [`DonchianState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: DonchianState<T>,
+}
+
+/// Donchian Channels calculation state
+/// ---
+/// This struct holds the state of the Donchian Channels calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `upper`: The last upper band value.
+/// - `middle`: The last middle band value.
+/// - `lower`: The last lower band value.
+///
+/// **State values**
+/// - `max_state`: The [`MaxState`] of the highs (upper band).
+/// - `min_state`: The [`MinState`] of the lows (lower band).
+#[derive(Debug, Clone)]
+pub struct DonchianState<T = Float> {
+    // Outputs values
+    /// The last upper band value.
+    pub upper: T,
+    /// The last middle band value.
+    pub middle: T,
+    /// The last lower band value.
+    pub lower: T,
+
+    // State values
+    /// The [`MaxState`] of the highs (upper band).
+    pub max_state: MaxState<T>,
+    /// The [`MinState`] of the lows (lower band).
+    pub min_state: MinState<T>,
+}
+
+impl<T: TechalibFloat> State<(T, T)> for DonchianState<T> {
+    /// Update the [`DonchianState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low)` of the new bar.
+    fn update(&mut self, sample: (T, T)) -> Result<(), TechalibError> {
+        let next = self.next(sample)?;
+        self.commit_unchecked(sample, next);
+        Ok(())
+    }
+}
+
+impl<T: TechalibFloat> DonchianState<T> {
+    /// Checks the state and `sample`, and returns the next `(upper, middle,
+    /// lower)` channels without modifying the state.
+    pub(crate) fn next(&self, sample: (T, T)) -> Result<(T, T, T), TechalibError> {
+        let (high, low) = sample;
+        if self.max_state.period != self.min_state.period {
+            return Err(TechalibError::BadParam(format!(
+                "DONCHIAN max_state period ({}) does not match min_state period ({})",
+                self.max_state.period, self.min_state.period
+            )));
+        }
+
+        let upper = self.max_state.next(high)?;
+        let lower = self.min_state.next(low)?;
+        let middle = donchian_middle_unchecked(upper, lower);
+        if !middle.is_finite() {
+            return Err(TechalibError::Overflow(0, middle.to_f64()));
+        }
+        Ok((upper, middle, lower))
+    }
+
+    /// Pushes `sample` into the MAX and MIN states and stores the channels
+    /// returned by [`DonchianState::next`].
+    pub(crate) fn commit_unchecked(&mut self, sample: (T, T), next: (T, T, T)) {
+        let (high, low) = sample;
+        let (upper, middle, lower) = next;
+        self.max_state.commit_unchecked(high, upper);
+        self.min_state.commit_unchecked(low, lower);
+        self.upper = upper;
+        self.middle = middle;
+        self.lower = lower;
+    }
+}
+
+/// Lookback period for Donchian Channels calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Donchian Channels values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculate Donchian Channels for the given bars and return the result.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high` and `low` columns are used.
+/// - `period`: The number of bars over which the highest high and lowest low are searched.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`DonchianResult`] with the upper, middle, and lower bands,
+/// or an error if the calculation fails.
+pub fn donchian<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+) -> Result<DonchianResult<T>, TechalibError> {
+    let len = ohlcv.high.len();
+    let mut output_upper = vec![T::ZERO; len];
+    let mut output_middle = vec![T::ZERO; len];
+    let mut output_lower = vec![T::ZERO; len];
+
+    let donchian_state = donchian_into(
+        ohlcv,
+        period,
+        output_upper.as_mut_slice(),
+        output_middle.as_mut_slice(),
+        output_lower.as_mut_slice(),
+    )?;
+
+    Ok(DonchianResult {
+        upper: output_upper,
+        middle: output_middle,
+        lower: output_lower,
+        state: donchian_state,
+    })
+}
+
+/// Calculate Donchian Channels and store the results in provided output arrays and return the state.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high` and `low` columns are used.
+/// - `period`: The number of bars over which the highest high and lowest low are searched.
+///
+/// Output Arguments
+/// ---
+/// - `output_upper`: A mutable slice to store the upper band values.
+/// - `output_middle`: A mutable slice to store the middle band values.
+/// - `output_lower`: A mutable slice to store the lower band values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`DonchianState`] with the last calculated values and state, or an error if the calculation fails.
+pub fn donchian_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    output_upper: &mut [T],
+    output_middle: &mut [T],
+    output_lower: &mut [T],
+) -> Result<DonchianState<T>, TechalibError> {
+    let len = columns_len(&[("high", ohlcv.high), ("low", ohlcv.low)])?;
+    if output_upper.len() < len || output_middle.len() < len || output_lower.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let max_state = max_into(ohlcv.high, period, output_upper)?;
+    let min_state = min_into(ohlcv.low, period, output_lower)?;
+
+    let lookback = lookback_from_period(period);
+    output_middle[..lookback].fill(T::NAN);
+    for idx in lookback..len {
+        output_middle[idx] = donchian_middle_unchecked(output_upper[idx], output_lower[idx]);
+        if !output_middle[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output_middle[idx].to_f64()));
+        }
+    }
+
+    Ok(DonchianState {
+        upper: output_upper[len - 1],
+        middle: output_middle[len - 1],
+        lower: output_lower[len - 1],
+        max_state,
+        min_state,
+    })
+}
+
+batch_functions!(
+    donchian_batch,
+    donchian_batch_into,
+    donchian,
+    donchian_into,
+    DonchianResult,
+    DonchianState,
+    "Donchian Channels",
+    ohlcv -> [output_upper, output_middle, output_lower];
+    period: usize
+);
+
+#[inline(always)]
+fn donchian_middle_unchecked<T: TechalibFloat>(upper: T, lower: T) -> T {
+    (upper + lower) / T::from_f64(2.0)
+}
@@ -0,0 +1,280 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Elder Ray Index (ELDER_RAY) implementation
+//!
+//! The bull power is the distance of the high above an EMA of the close,
+//! and the bear power the distance of the low below the same EMA.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::ema::{ema_into, EmaState};
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Elder Ray Index calculation result
+/// ---
+/// This struct holds the result of the Elder Ray Index calculation.
+///
+/// Attributes
+/// ---
+/// - `bull_power`: The bull power values (`high - ema`).
+/// - `bear_power`: The bear power values (`low - ema`).
+/// - `state`: A [`ElderRayState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct ElderRayResult<T = Float> {
+    /// The bull power values (`high - ema`).
+    pub bull_power: Vec<T>,
+    /// The bear power values (`low - ema`).
+    pub bear_power: Vec<T>,
+    /// A [`ElderRayState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: ElderRayState<T>,
+}
+
+/// Elder Ray Index calculation state
+/// ---
+/// This struct holds the state of the Elder Ray Index calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `bull_power`: The last bull power value.
+/// - `bear_power`: The last bear power value.
+///
+/// **State values**
+/// - `ema`: The [`EmaState`] of the close.
+#[derive(Debug, Clone, Copy)]
+pub struct ElderRayState<T = Float> {
+    // Outputs
+    /// The last bull power value.
+    pub bull_power: T,
+    /// The last bear power value.
+    pub bear_power: T,
+
+    // State values
+    /// The [`EmaState`] of the close.
+    pub ema: EmaState<T>,
+}
+
+impl<T: TechalibFloat> State<(T, T, T)> for ElderRayState<T> {
+    /// Update the [`ElderRayState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, close)` of the new bar.
+    fn update(&mut self, sample: (T, T, T)) -> Result<(), TechalibError> {
+        let (high, low, close) = sample;
+        check_finite_unchecked(0, high, low)?;
+
+        let mut ema = self.ema;
+        ema.update(close)?;
+
+        let (bull_power, bear_power) = powers_unchecked(high, low, ema.ema);
+        if !bull_power.is_finite() {
+            return Err(TechalibError::Overflow(0, bull_power.to_f64()));
+        }
+        if !bear_power.is_finite() {
+            return Err(TechalibError::Overflow(0, bear_power.to_f64()));
+        }
+
+        self.bull_power = bull_power;
+        self.bear_power = bear_power;
+        self.ema = ema;
+        Ok(())
+    }
+}
+
+/// Lookback period for Elder Ray Index calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Elder Ray Index values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the Elder Ray Index function
+/// ---
+/// It returns a [`ElderRayResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period of the EMA of the close.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ElderRayResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn elder_ray<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+) -> Result<ElderRayResult<T>, TechalibError> {
+    let len = ohlcv.close.len();
+    let mut output_bull_power = vec![T::ZERO; len];
+    let mut output_bear_power = vec![T::ZERO; len];
+
+    let elder_ray_state = elder_ray_into(
+        ohlcv,
+        period,
+        output_bull_power.as_mut_slice(),
+        output_bear_power.as_mut_slice(),
+    )?;
+
+    Ok(ElderRayResult {
+        bull_power: output_bull_power,
+        bear_power: output_bear_power,
+        state: elder_ray_state,
+    })
+}
+
+/// Calculation of the Elder Ray Index function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`ElderRayState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period of the EMA of the close.
+///
+/// Output Arguments
+/// ---
+/// - `output_bull_power`: A mutable slice to store the bull power values.
+/// - `output_bear_power`: A mutable slice to store the bear power values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ElderRayState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn elder_ray_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    output_bull_power: &mut [T],
+    output_bear_power: &mut [T],
+) -> Result<ElderRayState<T>, TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(
+            "EMA period must be greater than 1".to_string(),
+        ));
+    }
+
+    let (high, low) = (ohlcv.high, ohlcv.low);
+    let len = columns_len(&[("high", high), ("low", low), ("close", ohlcv.close)])?;
+    if output_bull_power.len() < len || output_bear_power.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    // The EMA is stored in the bull power until the powers are calculated
+    let ema = ema_into(ohlcv.close, period, None, output_bull_power)?;
+
+    for idx in 0..len {
+        check_finite_unchecked(idx, high[idx], low[idx])?;
+        if idx < lookback {
+            output_bull_power[idx] = T::NAN;
+            output_bear_power[idx] = T::NAN;
+            continue;
+        }
+        (output_bull_power[idx], output_bear_power[idx]) =
+            powers_unchecked(high[idx], low[idx], output_bull_power[idx]);
+        if !output_bull_power[idx].is_finite() {
+            return Err(TechalibError::Overflow(
+                idx,
+                output_bull_power[idx].to_f64(),
+            ));
+        }
+        if !output_bear_power[idx].is_finite() {
+            return Err(TechalibError::Overflow(
+                idx,
+                output_bear_power[idx].to_f64(),
+            ));
+        }
+    }
+
+    Ok(ElderRayState {
+        bull_power: output_bull_power[len - 1],
+        bear_power: output_bear_power[len - 1],
+        ema,
+    })
+}
+
+batch_functions!(
+    elder_ray_batch,
+    elder_ray_batch_into,
+    elder_ray,
+    elder_ray_into,
+    ElderRayResult,
+    ElderRayState,
+    "Elder Ray Index",
+    ohlcv -> [output_bull_power, output_bear_power];
+    period: usize
+);
+
+#[inline(always)]
+fn powers_unchecked<T: TechalibFloat>(high: T, low: T, ema: T) -> (T, T) {
+    (high - ema, low - ema)
+}
+
+#[inline(always)]
+fn check_finite_unchecked<T: TechalibFloat>(
+    idx: usize,
+    high: T,
+    low: T,
+) -> Result<(), TechalibError> {
+    if !high.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "high[{idx}] = {high:?}"
+        )));
+    }
+    if !low.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "low[{idx}] = {low:?}"
+        )));
+    }
+    Ok(())
+}
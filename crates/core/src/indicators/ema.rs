@@ -40,12 +40,14 @@
 
 //! Exponential Moving Average (EMA) implementation
 
+use crate::batch::{batch_functions, check_sweep_output, prefix_sums, sweep};
 use crate::errors::TechalibError;
 use crate::indicators::sma::init_sma_unchecked;
 use crate::traits::State;
-use crate::types::Float;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
-const DEFAULT_SMOOTHING: Float = 2.0;
+const DEFAULT_SMOOTHING: f64 = 2.0;
 
 /// EMA calculation result
 /// ---
@@ -58,12 +60,32 @@ const DEFAULT_SMOOTHING: Float = 2.0;
 /// - `state`: A [`EmaState`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct EmaResult {
+pub struct EmaResult<T = Float> {
     /// The calculated EMA values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// A [`EmaState`], which can be used to calculate the next values
     /// incrementally.
-    pub state: EmaState,
+    pub state: EmaState<T>,
+}
+
+/// EMA sweep result
+/// ---
+/// This struct holds the result of the EMA calculation for several periods
+/// over the same series.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] holding a column-major 2-D array with
+///   one column of `data.len()` values per period, in the order of the periods.
+/// - `states`: A vector of [`EmaState`], one per period, which can be used
+///   to calculate the next values incrementally.
+#[derive(Debug)]
+pub struct EmaSweepResult<T = Float> {
+    /// The calculated EMA values, one column per period.
+    pub values: Vec<T>,
+    /// An [`EmaState`] per period, which can be used to calculate
+    /// the next values incrementally.
+    pub states: Vec<EmaState<T>>,
 }
 
 /// EMA calculation state
@@ -81,26 +103,26 @@ pub struct EmaResult {
 /// - `alpha`: The alpha factor used in the EMA calculation.
 ///   Traditionally, it is calculated as `smoothing / (period + 1)`.
 #[derive(Debug, Clone, Copy)]
-pub struct EmaState {
+pub struct EmaState<T = Float> {
     // Outputs values
     /// The last calculated Exponential Moving Average (EMA) value.
-    pub ema: Float,
+    pub ema: T,
 
     // Parameters
     /// The period used for the EMA calculation.
     pub period: usize,
     /// The alpha factor used in the EMA calculation
     /// Traditionally, it is calculated as `smoothing / (period + 1)`.
-    pub alpha: Float,
+    pub alpha: T,
 }
 
-impl State<Float> for EmaState {
+impl<T: TechalibFloat> State<T> for EmaState<T> {
     /// Update the [`EmaState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the EMA state.
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if self.period <= 1 {
             return Err(TechalibError::BadParam(
                 "Period must be greater than 1".to_string(),
@@ -129,7 +151,7 @@ impl State<Float> for EmaState {
 
         let ema = ema_next_unchecked(sample, self.ema, self.alpha);
         if !ema.is_finite() {
-            return Err(TechalibError::Overflow(0, ema));
+            return Err(TechalibError::Overflow(0, ema.to_f64()));
         }
         self.ema = ema;
         Ok(())
@@ -150,12 +172,12 @@ impl State<Float> for EmaState {
 /// ---
 /// A `Result` containing a [`EmaResult`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn ema(
-    data: &[Float],
+pub fn ema<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    alpha: Option<Float>,
-) -> Result<EmaResult, TechalibError> {
-    let mut output = vec![0.0; data.len()];
+    alpha: Option<T>,
+) -> Result<EmaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
     let ema_state = ema_into(data, period, alpha, &mut output)?;
     Ok(EmaResult {
         values: output,
@@ -182,14 +204,14 @@ pub fn ema(
 /// ---
 /// A `Result` containing a [`EmaState`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn ema_into(
-    data: &[Float],
+pub fn ema_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    alpha: Option<Float>,
-    output: &mut [Float],
-) -> Result<EmaState, TechalibError> {
+    alpha: Option<T>,
+    output: &mut [T],
+) -> Result<EmaState<T>, TechalibError> {
     let len = data.len();
-    let inv_period = 1.0 / period as Float;
+    let inv_period = T::ONE / T::from_usize(period);
     if period == 0 || len < period {
         return Err(TechalibError::InsufficientData);
     }
@@ -204,7 +226,10 @@ pub fn ema_into(
 
     output[period - 1] = init_sma_unchecked(data, period, inv_period, output)?;
     if !output[period - 1].is_finite() {
-        return Err(TechalibError::Overflow(period - 1, output[period - 1]));
+        return Err(TechalibError::Overflow(
+            period - 1,
+            output[period - 1].to_f64(),
+        ));
     }
 
     for idx in period..len {
@@ -216,7 +241,7 @@ pub fn ema_into(
         }
         output[idx] = ema_next_unchecked(data[idx], output[idx - 1], alpha);
         if !output[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output[idx]));
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
         }
     }
 
@@ -227,6 +252,107 @@ pub fn ema_into(
     })
 }
 
+batch_functions!(
+    ema_batch,
+    ema_batch_into,
+    ema,
+    ema_into,
+    EmaResult,
+    EmaState,
+    "EMA";
+    period: usize, alpha: Option<T>
+);
+
+/// Calculation of the EMA function for several periods
+/// ---
+/// It returns an [`EmaSweepResult`]. The alpha of each period is derived
+/// with [`period_to_alpha`], and a single prefix sum of `data` is shared by
+/// the SMA seeds of every period.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `periods`: The periods for the EMA calculation.
+///
+/// Returns
+/// ---
+/// A `Result` containing an [`EmaSweepResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn ema_sweep<T: TechalibFloat>(
+    data: &[T],
+    periods: &[usize],
+) -> Result<EmaSweepResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len() * periods.len()];
+    let states = ema_sweep_into(data, periods, &mut output)?;
+    Ok(EmaSweepResult {
+        values: output,
+        states,
+    })
+}
+
+/// Calculation of the EMA function for several periods
+/// ---
+/// It stores the results in the provided output array and
+/// returns one [`EmaState`] per period.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `periods`: The periods for the EMA calculation.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] of length at least `data.len() * periods.len()`,
+///   where the EMA values of each period are stored as consecutive columns.
+///
+/// Returns
+/// ---
+/// A `Result` containing a vector of [`EmaState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn ema_sweep_into<T: TechalibFloat>(
+    data: &[T],
+    periods: &[usize],
+    output: &mut [T],
+) -> Result<Vec<EmaState<T>>, TechalibError> {
+    let len = data.len();
+    for &period in periods {
+        if period == 0 || len < period {
+            return Err(TechalibError::InsufficientData);
+        }
+        if period == 1 {
+            return Err(TechalibError::BadParam(
+                "EMA period must be greater than 1".to_string(),
+            ));
+        }
+    }
+    check_sweep_output(len, periods, output.len())?;
+
+    let sums = prefix_sums(data, |value| value)?;
+    let columns = output.chunks_mut(len.max(1)).collect();
+    sweep(periods, columns, |period, output: &mut [T]| {
+        let alpha = period_to_alpha(period, None)?;
+        output[..period - 1].fill(T::NAN);
+        output[period - 1] = T::from_f64(sums[period] / period as f64);
+        if !output[period - 1].is_finite() {
+            return Err(TechalibError::Overflow(
+                period - 1,
+                output[period - 1].to_f64(),
+            ));
+        }
+        for idx in period..len {
+            output[idx] = ema_next_unchecked(data[idx], output[idx - 1], alpha);
+            if !output[idx].is_finite() {
+                return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+            }
+        }
+        Ok(EmaState {
+            ema: output[len - 1],
+            period,
+            alpha,
+        })
+    })
+}
+
 /// Converts a period to an alpha value for EMA calculation.
 /// According to the formula:
 /// alpha = smoothing / (period + 1)
@@ -240,7 +366,10 @@ pub fn ema_into(
 /// ---
 /// A `Result` containing the calculated alpha value as `Float`, or a
 /// [`TechalibError`] if the period is invalid or if the smoothing factor is invalid.
-pub fn period_to_alpha(period: usize, smoothing: Option<Float>) -> Result<Float, TechalibError> {
+pub fn period_to_alpha<T: TechalibFloat>(
+    period: usize,
+    smoothing: Option<T>,
+) -> Result<T, TechalibError> {
     if period == 0 {
         return Err(TechalibError::BadParam(
             "Period must be greater than 0".to_string(),
@@ -249,25 +378,28 @@ pub fn period_to_alpha(period: usize, smoothing: Option<Float>) -> Result<Float,
 
     let smoothing = match smoothing {
         Some(s) => {
-            if s <= 0.0 {
+            if s <= T::ZERO {
                 return Err(TechalibError::BadParam(
                     "Smoothing must be greater than 0".to_string(),
                 ));
             }
             s
         }
-        None => DEFAULT_SMOOTHING,
+        None => T::from_f64(DEFAULT_SMOOTHING),
     };
 
-    Ok(smoothing / (period as Float + 1.0))
+    Ok(smoothing / (T::from_usize(period) + T::ONE))
 }
 
 #[inline(always)]
-pub(crate) fn ema_next_unchecked(new_value: Float, prev_ema: Float, alpha: Float) -> Float {
-    new_value * alpha + prev_ema * (1.0 - alpha)
+pub(crate) fn ema_next_unchecked<T: TechalibFloat>(new_value: T, prev_ema: T, alpha: T) -> T {
+    new_value * alpha + prev_ema * (T::ONE - alpha)
 }
 
-pub(crate) fn get_alpha_value(alpha: Option<Float>, period: usize) -> Result<Float, TechalibError> {
+pub(crate) fn get_alpha_value<T: TechalibFloat>(
+    alpha: Option<T>,
+    period: usize,
+) -> Result<T, TechalibError> {
     match alpha {
         Some(a) => Ok(a),
         None => period_to_alpha(period, None),
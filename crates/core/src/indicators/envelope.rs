@@ -0,0 +1,282 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Moving Average Envelope (ENVELOPE) implementation
+//!
+//! The middle band is a moving average of any [`MaType`] and the upper and
+//! lower bands are shifted by a percentage of it.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::ma::{self, ma_into, MaState, MaType};
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Moving Average Envelope result
+/// ---
+/// This struct holds the result of the envelope calculation.
+/// It contains the upper, middle, and lower bands as well as the state of the calculation.
+///
+/// Attributes
+/// ---
+/// - `upper`: The upper band values.
+/// - `middle`: The middle band values (moving average).
+/// - `lower`: The lower band values.
+/// - `state`: A [`EnvelopeState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct EnvelopeResult<T = Float> {
+    /// The upper band values.
+    pub upper: Vec<T>,
+    /// The middle band values (moving average).
+    pub middle: Vec<T>,
+    /// The lower band values.
+    pub lower: Vec<T>,
+    /// A [`EnvelopeState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: EnvelopeState<T>,
+}
+
+/// Moving Average Envelope calculation state
+/// ---
+/// This struct holds the state of the envelope calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `upper`: The last upper band value.
+/// - `middle`: The last middle band value.
+/// - `lower`: The last lower band value.
+///
+/// **State values**
+/// - `ma`: The [`MaState`] of the middle band.
+///
+/// **Parameters**
+/// - `percent`: The distance of the bands from the moving average, in percent.
+#[derive(Debug, Clone)]
+pub struct EnvelopeState<T = Float> {
+    // Outputs values
+    /// The last upper band value.
+    pub upper: T,
+    /// The last middle band value.
+    pub middle: T,
+    /// The last lower band value.
+    pub lower: T,
+
+    // State values
+    /// The [`MaState`] of the middle band.
+    pub ma: MaState<T>,
+
+    // Parameters
+    /// The distance of the bands from the moving average, in percent.
+    pub percent: T,
+}
+
+impl<T: TechalibFloat> State<T> for EnvelopeState<T> {
+    /// Update the [`EnvelopeState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input value to update the envelope state.
+    ///
+    /// The [`MaState`] is updated in place, so it has already taken `sample`
+    /// when an overflow of the bands is returned.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        check_percent_unchecked(self.percent)?;
+
+        self.ma.update(sample)?;
+
+        let middle = self.ma.value();
+        let (upper, lower) = envelope_bands_unchecked(middle, self.percent);
+        if !upper.is_finite() {
+            return Err(TechalibError::Overflow(0, upper.to_f64()));
+        }
+        if !lower.is_finite() {
+            return Err(TechalibError::Overflow(0, lower.to_f64()));
+        }
+
+        self.upper = upper;
+        self.middle = middle;
+        self.lower = lower;
+        Ok(())
+    }
+}
+
+/// Lookback period for envelope calculation
+/// ---
+/// With `n = lookback_from_period(period, ma_type)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the envelope values.
+#[inline(always)]
+pub fn lookback_from_period<T: TechalibFloat>(period: usize, ma_type: MaType<T>) -> usize {
+    ma::lookback_from_period(period, ma_type)
+}
+
+/// Calculate the Moving Average Envelope for a given data array and return the result.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of `Float` values representing the data to calculate the envelope on.
+/// - `period`: The period of the moving average.
+/// - `percent`: The distance of the bands from the moving average, in percent
+///   (e.g. `2.5` for bands at ±2.5%).
+/// - `ma_type`: The type of moving average to use for the middle band.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`EnvelopeResult`] with the upper, middle, and lower bands,
+/// or an error if the calculation fails.
+pub fn envelope<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    percent: T,
+    ma_type: MaType<T>,
+) -> Result<EnvelopeResult<T>, TechalibError> {
+    let mut output_upper = vec![T::ZERO; data.len()];
+    let mut output_middle = vec![T::ZERO; data.len()];
+    let mut output_lower = vec![T::ZERO; data.len()];
+
+    let envelope_state = envelope_into(
+        data,
+        period,
+        percent,
+        ma_type,
+        output_upper.as_mut_slice(),
+        output_middle.as_mut_slice(),
+        output_lower.as_mut_slice(),
+    )?;
+
+    Ok(EnvelopeResult {
+        upper: output_upper,
+        middle: output_middle,
+        lower: output_lower,
+        state: envelope_state,
+    })
+}
+
+/// Calculate the Moving Average Envelope and store the results in provided output arrays and return the state.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of `Float` values representing the data to calculate the envelope on.
+/// - `period`: The period of the moving average.
+/// - `percent`: The distance of the bands from the moving average, in percent
+///   (e.g. `2.5` for bands at ±2.5%).
+/// - `ma_type`: The type of moving average to use for the middle band.
+///
+/// Output Arguments
+/// ---
+/// - `output_upper`: A mutable slice to store the upper band values.
+/// - `output_middle`: A mutable slice to store the middle band values.
+/// - `output_lower`: A mutable slice to store the lower band values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`EnvelopeState`] with the last calculated values and state, or an error if the calculation fails.
+pub fn envelope_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    percent: T,
+    ma_type: MaType<T>,
+    output_upper: &mut [T],
+    output_middle: &mut [T],
+    output_lower: &mut [T],
+) -> Result<EnvelopeState<T>, TechalibError> {
+    check_percent_unchecked(percent)?;
+
+    let len = data.len();
+    if output_upper.len() < len || output_middle.len() < len || output_lower.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let ma = ma_into(data, period, ma_type, output_middle)?;
+
+    let lookback = lookback_from_period(period, ma_type);
+    for idx in 0..lookback {
+        output_upper[idx] = T::NAN;
+        output_lower[idx] = T::NAN;
+    }
+    for idx in lookback..len {
+        (output_upper[idx], output_lower[idx]) =
+            envelope_bands_unchecked(output_middle[idx], percent);
+        if !output_upper[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output_upper[idx].to_f64()));
+        }
+        if !output_lower[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output_lower[idx].to_f64()));
+        }
+    }
+
+    Ok(EnvelopeState {
+        upper: output_upper[len - 1],
+        middle: output_middle[len - 1],
+        lower: output_lower[len - 1],
+        ma,
+        percent,
+    })
+}
+
+batch_functions!(
+    envelope_batch,
+    envelope_batch_into,
+    envelope,
+    envelope_into,
+    EnvelopeResult,
+    EnvelopeState,
+    "Envelope",
+    data -> [output_upper, output_middle, output_lower];
+    period: usize, percent: T, ma_type: MaType<T>
+);
+
+#[inline(always)]
+fn envelope_bands_unchecked<T: TechalibFloat>(middle: T, percent: T) -> (T, T) {
+    let width = middle * percent / T::from_f64(100.0);
+    (middle + width, middle - width)
+}
+
+#[inline(always)]
+fn check_percent_unchecked<T: TechalibFloat>(percent: T) -> Result<(), TechalibError> {
+    if !percent.is_finite() || percent <= T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "Percent must be a finite value greater than 0, got: {percent:?}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,354 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Ease of Movement (EOM) implementation
+//!
+//! The raw Ease of Movement of a bar is the move of the midpoint `(high + low) / 2`
+//! divided by the box ratio `volume / scale / (high - low)`, and the EOM is
+//! a simple moving average of the raw values over the period.
+//!
+//! A bar without volume has a raw value of `0`.
+
+use alloc::collections::VecDeque;
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::price_transform::medprice_unchecked;
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+
+/// Default scale of the volume in the box ratio
+pub const DEFAULT_SCALE: Float = 10_000.0;
+
+/// Ease of Movement calculation result
+/// ---
+/// This struct holds the result of the Ease of Movement calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: The Ease of Movement values.
+/// - `state`: A [`EomState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct EomResult<T = Float> {
+    /// The Ease of Movement values.
+    pub values: Vec<T>,
+    /// A [`EomState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: EomState<T>,
+}
+
+/// Ease of Movement calculation state
+/// ---
+/// This struct holds the state of the Ease of Movement calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `eom`: The last Ease of Movement value.
+///
+/// **State values**
+/// - `prev_high`: The high of the last bar.
+/// - `prev_low`: The low of the last bar.
+/// - `last_window`: The last `period` raw Ease of Movement values.
+///
+/// **Parameters**
+/// - `period`: The period of the moving average.
+/// - `scale`: The scale of the volume in the box ratio.
+#[derive(Debug, Clone)]
+pub struct EomState<T = Float> {
+    // Outputs
+    /// The last Ease of Movement value.
+    pub eom: T,
+
+    // State values
+    /// The high of the last bar.
+    pub prev_high: T,
+    /// The low of the last bar.
+    pub prev_low: T,
+    /// The last `period` raw Ease of Movement values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The period of the moving average.
+    pub period: usize,
+    /// The scale of the volume in the box ratio.
+    pub scale: T,
+}
+
+impl<T: TechalibFloat> State<(T, T, T)> for EomState<T> {
+    /// Update the [`EomState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, volume)` of the new bar.
+    fn update(&mut self, sample: (T, T, T)) -> Result<(), TechalibError> {
+        check_param_unchecked(self.period, self.scale)?;
+        if self.last_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "EOM state last_window length ({}) does not match period ({})",
+                self.last_window.len(),
+                self.period
+            )));
+        }
+        let (high, low, volume) = sample;
+        check_finite_unchecked(0, high, low, volume)?;
+        if !self.eom.is_finite() || !self.prev_high.is_finite() || !self.prev_low.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.eom = {:?}, self.prev_high = {:?}, self.prev_low = {:?}",
+                self.eom, self.prev_high, self.prev_low
+            )));
+        }
+
+        let raw = raw_eom_unchecked(high, low, self.prev_high, self.prev_low, volume, self.scale);
+        if !raw.is_finite() {
+            return Err(TechalibError::Overflow(0, raw.to_f64()));
+        }
+        let old_value = self
+            .last_window
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
+
+        let eom = self.eom + (raw - old_value) / T::from_usize(self.period);
+        if !eom.is_finite() {
+            return Err(TechalibError::Overflow(0, eom.to_f64()));
+        }
+
+        self.eom = eom;
+        self.prev_high = high;
+        self.prev_low = low;
+        self.last_window.push(raw);
+        Ok(())
+    }
+}
+
+/// Lookback period for Ease of Movement calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Ease of Movement values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period
+}
+
+/// Calculation of the Ease of Movement function
+/// ---
+/// It returns a [`EomResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `volume` columns are used.
+/// - `period`: The period of the moving average.
+/// - `scale`: The scale of the volume in the box ratio ([`DEFAULT_SCALE`] is common).
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`EomResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn eom<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    scale: T,
+) -> Result<EomResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; ohlcv.high.len()];
+
+    let eom_state = eom_into(ohlcv, period, scale, output.as_mut_slice())?;
+
+    Ok(EomResult {
+        values: output,
+        state: eom_state,
+    })
+}
+
+/// Calculation of the Ease of Movement function
+/// ---
+/// It stores the results in the provided output array and
+/// return the state [`EomState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `volume` columns are used.
+/// - `period`: The period of the moving average.
+/// - `scale`: The scale of the volume in the box ratio ([`DEFAULT_SCALE`] is common).
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice to store the Ease of Movement values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`EomState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn eom_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    scale: T,
+    output: &mut [T],
+) -> Result<EomState<T>, TechalibError> {
+    check_param_unchecked(period, scale)?;
+
+    let (high, low, volume) = (ohlcv.high, ohlcv.low, ohlcv.volume);
+    let len = columns_len(&[("high", high), ("low", low), ("volume", volume)])?;
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    check_finite_unchecked(0, high[0], low[0], volume[0])?;
+    output[0] = T::NAN;
+    let inv_period = T::ONE / T::from_usize(period);
+    let mut last_window = VecDeque::with_capacity(period);
+    let mut eom = T::ZERO;
+    for idx in 1..len {
+        check_finite_unchecked(idx, high[idx], low[idx], volume[idx])?;
+        let raw = raw_eom_unchecked(
+            high[idx],
+            low[idx],
+            high[idx - 1],
+            low[idx - 1],
+            volume[idx],
+            scale,
+        );
+        if !raw.is_finite() {
+            return Err(TechalibError::Overflow(idx, raw.to_f64()));
+        }
+        last_window.push_back(raw);
+        if idx < lookback {
+            eom += raw;
+            output[idx] = T::NAN;
+            continue;
+        }
+        eom = if idx == lookback {
+            (eom + raw) * inv_period
+        } else {
+            let old_value = last_window
+                .pop_front()
+                .ok_or(TechalibError::InsufficientData)?;
+            eom + (raw - old_value) * inv_period
+        };
+        if !eom.is_finite() {
+            return Err(TechalibError::Overflow(idx, eom.to_f64()));
+        }
+        output[idx] = eom;
+    }
+
+    Ok(EomState {
+        eom,
+        prev_high: high[len - 1],
+        prev_low: low[len - 1],
+        last_window: RingBuffer::from(Vec::from(last_window)),
+        period,
+        scale,
+    })
+}
+
+batch_functions!(
+    eom_batch,
+    eom_batch_into,
+    eom,
+    eom_into,
+    EomResult,
+    EomState,
+    "Ease of Movement",
+    ohlcv -> [output];
+    period: usize, scale: T
+);
+
+#[inline(always)]
+fn raw_eom_unchecked<T: TechalibFloat>(
+    high: T,
+    low: T,
+    prev_high: T,
+    prev_low: T,
+    volume: T,
+    scale: T,
+) -> T {
+    if volume == T::ZERO {
+        return T::ZERO;
+    }
+    let distance = medprice_unchecked(high, low) - medprice_unchecked(prev_high, prev_low);
+    distance * (high - low) * scale / volume
+}
+
+#[inline(always)]
+fn check_param_unchecked<T: TechalibFloat>(period: usize, scale: T) -> Result<(), TechalibError> {
+    if period == 0 {
+        return Err(TechalibError::BadParam(
+            "Period must be greater than 0".to_string(),
+        ));
+    }
+    if !scale.is_finite() || scale <= T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "Scale must be a finite value greater than 0, got: {scale:?}"
+        )));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn check_finite_unchecked<T: TechalibFloat>(
+    idx: usize,
+    high: T,
+    low: T,
+    volume: T,
+) -> Result<(), TechalibError> {
+    if !high.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "high[{idx}] = {high:?}"
+        )));
+    }
+    if !low.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "low[{idx}] = {low:?}"
+        )));
+    }
+    if !volume.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "volume[{idx}] = {volume:?}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,279 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Force Index (FORCE_INDEX) implementation
+//!
+//! The raw force of a bar is the change of the close multiplied by the
+//! volume, and the Force Index is an EMA of the raw force, seeded with the
+//! average of the first `period` raw forces. A period of 1 gives the raw force.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::ema::{ema_next_unchecked, period_to_alpha};
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Force Index calculation result
+/// ---
+/// This struct holds the result of the Force Index calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: The Force Index values.
+/// - `state`: A [`ForceIndexState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct ForceIndexResult<T = Float> {
+    /// The Force Index values.
+    pub values: Vec<T>,
+    /// A [`ForceIndexState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: ForceIndexState<T>,
+}
+
+/// Force Index calculation state
+/// ---
+/// This struct holds the state of the Force Index calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `force_index`: The last Force Index value.
+///
+/// **State values**
+/// - `prev_close`: The close of the last bar.
+///
+/// **Parameters**
+/// - `period`: The period of the EMA of the raw force.
+/// - `alpha`: The smoothing factor of the EMA.
+#[derive(Debug, Clone, Copy)]
+pub struct ForceIndexState<T = Float> {
+    // Outputs
+    /// The last Force Index value.
+    pub force_index: T,
+
+    // State values
+    /// The close of the last bar.
+    pub prev_close: T,
+
+    // Parameters
+    /// The period of the EMA of the raw force.
+    pub period: usize,
+    /// The smoothing factor of the EMA.
+    pub alpha: T,
+}
+
+impl<T: TechalibFloat> State<(T, T)> for ForceIndexState<T> {
+    /// Update the [`ForceIndexState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(close, volume)` of the new bar.
+    fn update(&mut self, sample: (T, T)) -> Result<(), TechalibError> {
+        if self.period == 0 {
+            return Err(TechalibError::BadParam(
+                "Period must be greater than 0".to_string(),
+            ));
+        }
+        let (close, volume) = sample;
+        check_finite_unchecked(0, close, volume)?;
+        if !self.force_index.is_finite() || !self.prev_close.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.force_index = {:?}, self.prev_close = {:?}",
+                self.force_index, self.prev_close
+            )));
+        }
+        if !self.alpha.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "alpha = {:?}",
+                self.alpha
+            )));
+        }
+
+        let force = raw_force_unchecked(close, self.prev_close, volume);
+        let force_index = ema_next_unchecked(force, self.force_index, self.alpha);
+        if !force_index.is_finite() {
+            return Err(TechalibError::Overflow(0, force_index.to_f64()));
+        }
+
+        self.force_index = force_index;
+        self.prev_close = close;
+        Ok(())
+    }
+}
+
+/// Lookback period for Force Index calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Force Index values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period
+}
+
+/// Calculation of the Force Index function
+/// ---
+/// It returns a [`ForceIndexResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `close` and `volume` columns are used.
+/// - `period`: The period of the EMA of the raw force.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ForceIndexResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn force_index<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+) -> Result<ForceIndexResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; ohlcv.close.len()];
+
+    let force_index_state = force_index_into(ohlcv, period, output.as_mut_slice())?;
+
+    Ok(ForceIndexResult {
+        values: output,
+        state: force_index_state,
+    })
+}
+
+/// Calculation of the Force Index function
+/// ---
+/// It stores the results in the provided output array and
+/// return the state [`ForceIndexState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `close` and `volume` columns are used.
+/// - `period`: The period of the EMA of the raw force.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice to store the Force Index values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ForceIndexState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn force_index_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    output: &mut [T],
+) -> Result<ForceIndexState<T>, TechalibError> {
+    let alpha = period_to_alpha(period, None)?;
+
+    let (close, volume) = (ohlcv.close, ohlcv.volume);
+    let len = columns_len(&[("close", close), ("volume", volume)])?;
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    check_finite_unchecked(0, close[0], volume[0])?;
+    output[0] = T::NAN;
+    let mut force_index = T::ZERO;
+    for idx in 1..len {
+        check_finite_unchecked(idx, close[idx], volume[idx])?;
+        let force = raw_force_unchecked(close[idx], close[idx - 1], volume[idx]);
+        if idx < lookback {
+            force_index += force;
+            output[idx] = T::NAN;
+            continue;
+        }
+        force_index = if idx == lookback {
+            (force_index + force) / T::from_usize(period)
+        } else {
+            ema_next_unchecked(force, force_index, alpha)
+        };
+        if !force_index.is_finite() {
+            return Err(TechalibError::Overflow(idx, force_index.to_f64()));
+        }
+        output[idx] = force_index;
+    }
+
+    Ok(ForceIndexState {
+        force_index,
+        prev_close: close[len - 1],
+        period,
+        alpha,
+    })
+}
+
+batch_functions!(
+    force_index_batch,
+    force_index_batch_into,
+    force_index,
+    force_index_into,
+    ForceIndexResult,
+    ForceIndexState,
+    "Force Index",
+    ohlcv -> [output];
+    period: usize
+);
+
+#[inline(always)]
+fn raw_force_unchecked<T: TechalibFloat>(close: T, prev_close: T, volume: T) -> T {
+    (close - prev_close) * volume
+}
+
+#[inline(always)]
+fn check_finite_unchecked<T: TechalibFloat>(
+    idx: usize,
+    close: T,
+    volume: T,
+) -> Result<(), TechalibError> {
+    if !close.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "close[{idx}] = {close:?}"
+        )));
+    }
+    if !volume.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "volume[{idx}] = {volume:?}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,329 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Heikin-Ashi (HEIKIN_ASHI) candles implementation
+//!
+//! - `close = (open + high + low + close) / 4`
+//! - `open = (previous HA open + previous HA close) / 2`, the first HA open
+//!   is `(open + close) / 2`
+//! - `high = max(high, HA open, HA close)`
+//! - `low = min(low, HA open, HA close)`
+//!
+//! The candles of a [`HeikinAshiResult`] can be used as the input of the
+//! other indicators with [`HeikinAshiResult::view`].
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::price_transform::avgprice_unchecked;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Heikin-Ashi calculation result
+/// ---
+/// This struct holds the Heikin-Ashi candles and the state of the calculation.
+///
+/// Attributes
+/// ---
+/// - `open`: The Heikin-Ashi open prices.
+/// - `high`: The Heikin-Ashi high prices.
+/// - `low`: The Heikin-Ashi low prices.
+/// - `close`: The Heikin-Ashi close prices.
+/// - `state`: A [`HeikinAshiState`], which can be used to calculate the next
+///   candles incrementally.
+#[derive(Debug)]
+pub struct HeikinAshiResult<T = Float> {
+    /// The Heikin-Ashi open prices.
+    pub open: Vec<T>,
+    /// The Heikin-Ashi high prices.
+    pub high: Vec<T>,
+    /// The Heikin-Ashi low prices.
+    pub low: Vec<T>,
+    /// The Heikin-Ashi close prices.
+    pub close: Vec<T>,
+    /// A [`HeikinAshiState`], which can be used to calculate the next
+    /// candles incrementally.
+    pub state: HeikinAshiState<T>,
+}
+
+impl<T: TechalibFloat> HeikinAshiResult<T> {
+    /// Returns an [`Ohlcv`] view of the Heikin-Ashi candles.
+    ///
+    /// The `volume` column is empty, it can be set with
+    /// `Ohlcv { volume: &volume, ..result.view() }`.
+    pub fn view(&self) -> Ohlcv<'_, T> {
+        Ohlcv {
+            open: &self.open,
+            high: &self.high,
+            low: &self.low,
+            close: &self.close,
+            volume: &[],
+        }
+    }
+}
+
+/// Heikin-Ashi calculation state
+/// ---
+/// This struct holds the last Heikin-Ashi candle, the open and the close
+/// are used to calculate the next candle.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `open`: The last Heikin-Ashi open price.
+/// - `high`: The last Heikin-Ashi high price.
+/// - `low`: The last Heikin-Ashi low price.
+/// - `close`: The last Heikin-Ashi close price.
+#[derive(Debug, Clone, Copy)]
+pub struct HeikinAshiState<T = Float> {
+    // Outputs
+    /// The last Heikin-Ashi open price.
+    pub open: T,
+    /// The last Heikin-Ashi high price.
+    pub high: T,
+    /// The last Heikin-Ashi low price.
+    pub low: T,
+    /// The last Heikin-Ashi close price.
+    pub close: T,
+}
+
+impl<T: TechalibFloat> State<(T, T, T, T)> for HeikinAshiState<T> {
+    /// Update the [`HeikinAshiState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(open, high, low, close)` of the new bar.
+    fn update(&mut self, sample: (T, T, T, T)) -> Result<(), TechalibError> {
+        let (open, high, low, close) = sample;
+        for (name, value) in [
+            ("open", open),
+            ("high", high),
+            ("low", low),
+            ("close", close),
+        ] {
+            if !value.is_finite() {
+                return Err(TechalibError::DataNonFinite(format!("{name} = {value:?}")));
+            }
+        }
+        if !self.open.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.open = {:?}",
+                self.open
+            )));
+        }
+        if !self.close.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.close = {:?}",
+                self.close
+            )));
+        }
+
+        let ha_open = ha_open_unchecked(self.open, self.close);
+        let (ha_high, ha_low, ha_close) = ha_candle_unchecked(open, high, low, close, ha_open);
+        if !ha_close.is_finite() {
+            return Err(TechalibError::Overflow(0, ha_close.to_f64()));
+        }
+
+        self.open = ha_open;
+        self.high = ha_high;
+        self.low = ha_low;
+        self.close = ha_close;
+        Ok(())
+    }
+}
+
+/// Calculation of the Heikin-Ashi candles
+/// ---
+/// It returns a [`HeikinAshiResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `open`, `high`, `low` and `close`
+///   columns are used.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`HeikinAshiResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn heikin_ashi<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+) -> Result<HeikinAshiResult<T>, TechalibError> {
+    let len = ohlcv.close.len();
+    let mut output_open = vec![T::ZERO; len];
+    let mut output_high = vec![T::ZERO; len];
+    let mut output_low = vec![T::ZERO; len];
+    let mut output_close = vec![T::ZERO; len];
+
+    let heikin_ashi_state = heikin_ashi_into(
+        ohlcv,
+        output_open.as_mut_slice(),
+        output_high.as_mut_slice(),
+        output_low.as_mut_slice(),
+        output_close.as_mut_slice(),
+    )?;
+
+    Ok(HeikinAshiResult {
+        open: output_open,
+        high: output_high,
+        low: output_low,
+        close: output_close,
+        state: heikin_ashi_state,
+    })
+}
+
+/// Calculation of the Heikin-Ashi candles
+/// ---
+/// It stores the candles in the provided output arrays and
+/// return the state [`HeikinAshiState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `open`, `high`, `low` and `close`
+///   columns are used.
+///
+/// Output Arguments
+/// ---
+/// - `output_open`: A mutable slice to store the Heikin-Ashi open prices.
+/// - `output_high`: A mutable slice to store the Heikin-Ashi high prices.
+/// - `output_low`: A mutable slice to store the Heikin-Ashi low prices.
+/// - `output_close`: A mutable slice to store the Heikin-Ashi close prices.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`HeikinAshiState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn heikin_ashi_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    output_open: &mut [T],
+    output_high: &mut [T],
+    output_low: &mut [T],
+    output_close: &mut [T],
+) -> Result<HeikinAshiState<T>, TechalibError> {
+    let (open, high, low, close) = (ohlcv.open, ohlcv.high, ohlcv.low, ohlcv.close);
+    let len = columns_len(&[
+        ("open", open),
+        ("high", high),
+        ("low", low),
+        ("close", close),
+    ])?;
+    if len == 0 {
+        return Err(TechalibError::InsufficientData);
+    }
+    if output_open.len() < len
+        || output_high.len() < len
+        || output_low.len() < len
+        || output_close.len() < len
+    {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    for idx in 0..len {
+        check_finite_unchecked(ohlcv, idx)?;
+        output_open[idx] = if idx == 0 {
+            ha_open_unchecked(open[0], close[0])
+        } else {
+            ha_open_unchecked(output_open[idx - 1], output_close[idx - 1])
+        };
+        (output_high[idx], output_low[idx], output_close[idx]) =
+            ha_candle_unchecked(open[idx], high[idx], low[idx], close[idx], output_open[idx]);
+        if !output_close[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output_close[idx].to_f64()));
+        }
+    }
+
+    Ok(HeikinAshiState {
+        open: output_open[len - 1],
+        high: output_high[len - 1],
+        low: output_low[len - 1],
+        close: output_close[len - 1],
+    })
+}
+
+batch_functions!(
+    heikin_ashi_batch,
+    heikin_ashi_batch_into,
+    heikin_ashi,
+    heikin_ashi_into,
+    HeikinAshiResult,
+    HeikinAshiState,
+    "Heikin-Ashi",
+    ohlcv -> [output_open, output_high, output_low, output_close]
+);
+
+#[inline(always)]
+fn ha_open_unchecked<T: TechalibFloat>(prev_open: T, prev_close: T) -> T {
+    (prev_open + prev_close) * T::from_f64(0.5)
+}
+
+/// Returns the Heikin-Ashi high, low and close of a bar.
+#[inline(always)]
+fn ha_candle_unchecked<T: TechalibFloat>(
+    open: T,
+    high: T,
+    low: T,
+    close: T,
+    ha_open: T,
+) -> (T, T, T) {
+    let ha_close = avgprice_unchecked(open, high, low, close);
+    (
+        high.max(ha_open).max(ha_close),
+        low.min(ha_open).min(ha_close),
+        ha_close,
+    )
+}
+
+#[inline(always)]
+fn check_finite_unchecked<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    idx: usize,
+) -> Result<(), TechalibError> {
+    for (name, column) in [
+        ("open", ohlcv.open),
+        ("high", ohlcv.high),
+        ("low", ohlcv.low),
+        ("close", ohlcv.close),
+    ] {
+        if !column[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "{name}[{idx}] = {:?}",
+                column[idx]
+            )));
+        }
+    }
+    Ok(())
+}
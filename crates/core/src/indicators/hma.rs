@@ -0,0 +1,253 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Hull Moving Average (HMA) implementation
+//!
+//! `HMA = WMA(2 × WMA(period / 2) - WMA(period), sqrt(period))`, built on
+//! the [`wma`](crate::indicators::wma). The halved period and the square root
+//! of the period are rounded down.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::wma::{self, wma_into, WmaState};
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// HMA calculation result
+/// ---
+/// This struct holds the result and the state ([`HmaState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated HMA values.
+/// - `state`: A [`HmaState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct HmaResult<T = Float> {
+    /// The calculated HMA values.
+    pub values: Vec<T>,
+    /// A [`HmaState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: HmaState<T>,
+}
+
+/// HMA calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `hma`: The last calculated HMA value.
+///
+/// **State values**
+/// - `half_wma`: The [`WmaState`] over half of the period.
+/// - `full_wma`: The [`WmaState`] over the period.
+/// - `hull_wma`: The [`WmaState`] of `2 × half_wma - full_wma` over the
+///   square root of the period.
+///
+/// **Parameters**
+/// - `period`: The period used for the HMA calculation.
+#[derive(Debug, Clone)]
+pub struct HmaState<T = Float> {
+    // Outputs
+    /// The last calculated HMA value.
+    pub hma: T,
+
+    // State values
+    /// The [`WmaState`] over half of the period.
+    pub half_wma: WmaState<T>,
+    /// The [`WmaState`] over the period.
+    pub full_wma: WmaState<T>,
+    /// The [`WmaState`] of `2 × half_wma - full_wma` over the
+    /// square root of the period.
+    pub hull_wma: WmaState<T>,
+
+    // Parameters
+    /// The period used for the HMA calculation.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for HmaState<T> {
+    /// Update the [`HmaState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the HMA state.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        check_period_unchecked(self.period)?;
+        let (half_period, sqrt_period) = sub_periods_unchecked(self.period);
+        if self.half_wma.period != half_period
+            || self.full_wma.period != self.period
+            || self.hull_wma.period != sqrt_period
+        {
+            return Err(TechalibError::BadParam(format!(
+                "HMA periods do not match: half_wma ({}), full_wma ({}), hull_wma ({}) for period ({})",
+                self.half_wma.period, self.full_wma.period, self.hull_wma.period, self.period
+            )));
+        }
+
+        let half = self.half_wma.next(sample)?;
+        let full = self.full_wma.next(sample)?;
+        let hull_sample = T::from_f64(2.0) * half.0 - full.0;
+        let hull = self.hull_wma.next(hull_sample)?;
+
+        self.half_wma.commit_unchecked(sample, half);
+        self.full_wma.commit_unchecked(sample, full);
+        self.hull_wma.commit_unchecked(hull_sample, hull);
+        self.hma = hull.0;
+        Ok(())
+    }
+}
+
+/// Lookback period for HMA calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the HMA values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    let (_, sqrt_period) = sub_periods_unchecked(period);
+    wma::lookback_from_period(period) + wma::lookback_from_period(sqrt_period)
+}
+
+/// Calculation of the HMA function
+/// ---
+/// It returns a [`HmaResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period for the HMA calculation, at least 4.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`HmaResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn hma<T: TechalibFloat>(data: &[T], period: usize) -> Result<HmaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let hma_state = hma_into(data, period, output.as_mut_slice())?;
+    Ok(HmaResult {
+        values: output,
+        state: hma_state,
+    })
+}
+
+/// Calculation of the HMA function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`HmaState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period for the HMA calculation, at least 4.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated HMA values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`HmaState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn hma_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<HmaState<T>, TechalibError> {
+    check_period_unchecked(period)?;
+    let len = data.len();
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let (half_period, sqrt_period) = sub_periods_unchecked(period);
+    let mut half = vec![T::ZERO; len];
+    let half_wma = wma_into(data, half_period, &mut half)?;
+    let full_wma = wma_into(data, period, output)?;
+
+    // The difference of the WMAs is defined once the WMA over the period is
+    let start = wma::lookback_from_period(period);
+    for idx in start..len {
+        half[idx] = T::from_f64(2.0) * half[idx] - output[idx];
+    }
+    output[..start].fill(T::NAN);
+    let hull_wma = wma_into(&half[start..], sqrt_period, &mut output[start..])?;
+
+    Ok(HmaState {
+        hma: output[len - 1],
+        half_wma,
+        full_wma,
+        hull_wma,
+        period,
+    })
+}
+
+batch_functions!(
+    hma_batch,
+    hma_batch_into,
+    hma,
+    hma_into,
+    HmaResult,
+    HmaState,
+    "HMA";
+    period: usize
+);
+
+#[inline(always)]
+fn sub_periods_unchecked(period: usize) -> (usize, usize) {
+    (period / 2, (period as f64).sqrt() as usize)
+}
+
+#[inline(always)]
+fn check_period_unchecked(period: usize) -> Result<(), TechalibError> {
+    if period < 4 {
+        return Err(TechalibError::BadParam(format!(
+            "HMA period must be at least 4, got: {period}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,427 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Ichimoku Kinko Hyo (ICHIMOKU) implementation
+//!
+//! Displaced outputs
+//! ---
+//! Every output has the same length as the input and the value at index `i`
+//! is the value **plotted** at bar `i`:
+//! - `senkou_a` and `senkou_b` are shifted forward by `displacement` bars:
+//!   `senkou_a[i]` is calculated from the bars up to `i - displacement`.
+//!   The values projected beyond the last bar do not fit in the outputs, they
+//!   are kept in the state ([`IchimokuState::senkou_a_ahead`] and
+//!   [`IchimokuState::senkou_b_ahead`]).
+//! - `chikou` is shifted back by `displacement` bars: `chikou[i]` is the
+//!   close of bar `i + displacement`, so the last `displacement` values are `NaN`.
+//!
+//! The Tenkan-sen, Kijun-sen and Senkou span B lines are the middle bands of
+//! [`donchian`](crate::indicators::donchian) channels.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::donchian::{donchian_into, DonchianState};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Ichimoku calculation result
+/// ---
+/// This struct holds the result of the Ichimoku calculation.
+/// All the outputs are aligned on the bar where they are plotted,
+/// see the [module documentation](self).
+///
+/// Attributes
+/// ---
+/// - `tenkan`: The Tenkan-sen (conversion line) values.
+/// - `kijun`: The Kijun-sen (base line) values.
+/// - `senkou_a`: The Senkou span A (leading span A) values, shifted forward.
+/// - `senkou_b`: The Senkou span B (leading span B) values, shifted forward.
+/// - `chikou`: The Chikou span (lagging span) values, shifted back.
+/// - `state`: A [`IchimokuState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct IchimokuResult<T = Float> {
+    /// The Tenkan-sen (conversion line) values.
+    pub tenkan: Vec<T>,
+    /// The Kijun-sen (base line) values.
+    pub kijun: Vec<T>,
+    /// The Senkou span A (leading span A) values, shifted forward.
+    pub senkou_a: Vec<T>,
+    /// The Senkou span B (leading span B) values, shifted forward.
+    pub senkou_b: Vec<T>,
+    /// The Chikou span (lagging span) values, shifted back.
+    pub chikou: Vec<T>,
+    /// A [`IchimokuState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: IchimokuState<T>,
+}
+
+/// Ichimoku calculation state
+/// ---
+/// This struct holds the state of the Ichimoku calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `tenkan`: The last Tenkan-sen value.
+/// - `kijun`: The last Kijun-sen value.
+/// - `senkou_a`: The Senkou span A value plotted at the last bar.
+/// - `senkou_b`: The Senkou span B value plotted at the last bar.
+/// - `chikou`: The last close, plotted `displacement` bars back.
+///
+/// **State values**
+/// - `tenkan_state`: The [`DonchianState`] of the Tenkan-sen.
+/// - `kijun_state`: The [`DonchianState`] of the Kijun-sen.
+/// - `senkou_b_state`: The [`DonchianState`] of the Senkou span B.
+/// - `senkou_a_ahead`: The projected Senkou span A values of the next
+///   `displacement` bars (the front is the next bar).
+/// - `senkou_b_ahead`: The projected Senkou span B values of the next
+///   `displacement` bars (the front is the next bar).
+///
+/// **Parameters**
+/// - `displacement`: The number of bars the spans are shifted by.
+#[derive(Debug, Clone)]
+pub struct IchimokuState<T = Float> {
+    // Outputs
+    /// The last Tenkan-sen value.
+    pub tenkan: T,
+    /// The last Kijun-sen value.
+    pub kijun: T,
+    /// The Senkou span A value plotted at the last bar.
+    pub senkou_a: T,
+    /// The Senkou span B value plotted at the last bar.
+    pub senkou_b: T,
+    /// The last close, plotted `displacement` bars back.
+    pub chikou: T,
+
+    // State values
+    /// The [`DonchianState`] of the Tenkan-sen.
+    pub tenkan_state: DonchianState<T>,
+    /// The [`DonchianState`] of the Kijun-sen.
+    pub kijun_state: DonchianState<T>,
+    /// The [`DonchianState`] of the Senkou span B.
+    pub senkou_b_state: DonchianState<T>,
+    /// The projected Senkou span A values of the next `displacement` bars
+    /// (the front is the next bar).
+    pub senkou_a_ahead: RingBuffer<T>,
+    /// The projected Senkou span B values of the next `displacement` bars
+    /// (the front is the next bar).
+    pub senkou_b_ahead: RingBuffer<T>,
+
+    // Parameters
+    /// The number of bars the spans are shifted by.
+    pub displacement: usize,
+}
+
+/// Periods of the Ichimoku lines.
+/// ---
+///
+/// The default value is the classic `(9, 26, 52, 26)` setting.
+///
+/// Attributes
+/// ---
+/// - `tenkan`: The period of the Tenkan-sen.
+/// - `kijun`: The period of the Kijun-sen.
+/// - `senkou_b`: The period of the Senkou span B.
+/// - `displacement`: The number of bars the spans are shifted by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IchimokuPeriods {
+    /// The period of the Tenkan-sen.
+    pub tenkan: usize,
+    /// The period of the Kijun-sen.
+    pub kijun: usize,
+    /// The period of the Senkou span B.
+    pub senkou_b: usize,
+    /// The number of bars the spans are shifted by.
+    pub displacement: usize,
+}
+
+impl Default for IchimokuPeriods {
+    fn default() -> Self {
+        IchimokuPeriods {
+            tenkan: 9,
+            kijun: 26,
+            senkou_b: 52,
+            displacement: 26,
+        }
+    }
+}
+
+impl<T: TechalibFloat> State<(T, T, T)> for IchimokuState<T> {
+    /// Update the [`IchimokuState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, close)` of the new bar.
+    fn update(&mut self, sample: (T, T, T)) -> Result<(), TechalibError> {
+        let (high, low, close) = sample;
+        if !close.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("close = {close:?}")));
+        }
+        if self.senkou_a_ahead.len() != self.displacement
+            || self.senkou_b_ahead.len() != self.displacement
+        {
+            return Err(TechalibError::BadParam(format!(
+                "ICHIMOKU state projected spans lengths ({}, {}) do not match displacement ({})",
+                self.senkou_a_ahead.len(),
+                self.senkou_b_ahead.len(),
+                self.displacement
+            )));
+        }
+
+        let tenkan = self.tenkan_state.next((high, low))?;
+        let kijun = self.kijun_state.next((high, low))?;
+        let senkou_b = self.senkou_b_state.next((high, low))?;
+
+        let senkou_a = senkou_a_unchecked(tenkan.1, kijun.1);
+        if !senkou_a.is_finite() {
+            return Err(TechalibError::Overflow(0, senkou_a.to_f64()));
+        }
+
+        self.tenkan_state.commit_unchecked((high, low), tenkan);
+        self.kijun_state.commit_unchecked((high, low), kijun);
+        self.senkou_b_state.commit_unchecked((high, low), senkou_b);
+        self.tenkan = tenkan.1;
+        self.kijun = kijun.1;
+        self.senkou_a = self.senkou_a_ahead.push(senkou_a).unwrap_or(senkou_a);
+        self.senkou_b = self.senkou_b_ahead.push(senkou_b.1).unwrap_or(senkou_b.1);
+        self.chikou = close;
+        Ok(())
+    }
+}
+
+/// Lookback period for Ichimoku calculation
+/// ---
+/// With `n = lookback_from_period(periods)`,
+/// the `n` first values of the Senkou spans will be `NaN`. It is the longest
+/// warm-up of the outputs, the Tenkan-sen and Kijun-sen start earlier.
+#[inline(always)]
+pub fn lookback_from_period(periods: IchimokuPeriods) -> usize {
+    periods.tenkan.max(periods.kijun).max(periods.senkou_b) - 1 + periods.displacement
+}
+
+/// Calculation of the Ichimoku function
+/// ---
+/// It returns a [`IchimokuResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `periods`: The [`IchimokuPeriods`] of the lines.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`IchimokuResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn ichimoku<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    periods: IchimokuPeriods,
+) -> Result<IchimokuResult<T>, TechalibError> {
+    let len = ohlcv.close.len();
+    let mut output_tenkan = vec![T::ZERO; len];
+    let mut output_kijun = vec![T::ZERO; len];
+    let mut output_senkou_a = vec![T::ZERO; len];
+    let mut output_senkou_b = vec![T::ZERO; len];
+    let mut output_chikou = vec![T::ZERO; len];
+
+    let ichimoku_state = ichimoku_into(
+        ohlcv,
+        periods,
+        output_tenkan.as_mut_slice(),
+        output_kijun.as_mut_slice(),
+        output_senkou_a.as_mut_slice(),
+        output_senkou_b.as_mut_slice(),
+        output_chikou.as_mut_slice(),
+    )?;
+
+    Ok(IchimokuResult {
+        tenkan: output_tenkan,
+        kijun: output_kijun,
+        senkou_a: output_senkou_a,
+        senkou_b: output_senkou_b,
+        chikou: output_chikou,
+        state: ichimoku_state,
+    })
+}
+
+/// Calculation of the Ichimoku function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`IchimokuState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `periods`: The [`IchimokuPeriods`] of the lines.
+///
+/// Output Arguments
+/// ---
+/// - `output_tenkan`: A mutable slice to store the Tenkan-sen values.
+/// - `output_kijun`: A mutable slice to store the Kijun-sen values.
+/// - `output_senkou_a`: A mutable slice to store the Senkou span A values.
+/// - `output_senkou_b`: A mutable slice to store the Senkou span B values.
+/// - `output_chikou`: A mutable slice to store the Chikou span values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`IchimokuState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn ichimoku_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    periods: IchimokuPeriods,
+    output_tenkan: &mut [T],
+    output_kijun: &mut [T],
+    output_senkou_a: &mut [T],
+    output_senkou_b: &mut [T],
+    output_chikou: &mut [T],
+) -> Result<IchimokuState<T>, TechalibError> {
+    let len = columns_len(&[
+        ("high", ohlcv.high),
+        ("low", ohlcv.low),
+        ("close", ohlcv.close),
+    ])?;
+    if output_tenkan.len() < len
+        || output_kijun.len() < len
+        || output_senkou_a.len() < len
+        || output_senkou_b.len() < len
+        || output_chikou.len() < len
+    {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    for (idx, close) in ohlcv.close.iter().enumerate() {
+        if !close.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "close[{idx}] = {close:?}"
+            )));
+        }
+    }
+
+    let displacement = periods.displacement;
+
+    // The spans and the chikou outputs are used as buffers for the
+    // unused bands of the donchian channels, before being calculated.
+    let tenkan_state = donchian_into(
+        ohlcv,
+        periods.tenkan,
+        output_senkou_a,
+        output_tenkan,
+        output_senkou_b,
+    )?;
+    let kijun_state = donchian_into(
+        ohlcv,
+        periods.kijun,
+        output_senkou_a,
+        output_kijun,
+        output_senkou_b,
+    )?;
+    // The unshifted Senkou span B is stored in the chikou output
+    let senkou_b_state = donchian_into(
+        ohlcv,
+        periods.senkou_b,
+        output_senkou_a,
+        output_chikou,
+        output_senkou_b,
+    )?;
+
+    let mut senkou_a_ahead = Vec::with_capacity(displacement);
+    let mut senkou_b_ahead = Vec::with_capacity(displacement);
+    for idx in (len as isize - displacement as isize)..len as isize {
+        if idx < 0 {
+            senkou_a_ahead.push(T::NAN);
+            senkou_b_ahead.push(T::NAN);
+        } else {
+            let idx = idx as usize;
+            senkou_a_ahead.push(senkou_a_unchecked(output_tenkan[idx], output_kijun[idx]));
+            senkou_b_ahead.push(output_chikou[idx]);
+        }
+    }
+
+    for idx in 0..len {
+        if idx < displacement {
+            output_senkou_a[idx] = T::NAN;
+            output_senkou_b[idx] = T::NAN;
+        } else {
+            let shifted_idx = idx - displacement;
+            output_senkou_a[idx] =
+                senkou_a_unchecked(output_tenkan[shifted_idx], output_kijun[shifted_idx]);
+            output_senkou_b[idx] = output_chikou[shifted_idx];
+        }
+        if output_senkou_a[idx].is_infinite() {
+            return Err(TechalibError::Overflow(idx, output_senkou_a[idx].to_f64()));
+        }
+    }
+
+    let shifted = len.saturating_sub(displacement);
+    output_chikou[..shifted].copy_from_slice(&ohlcv.close[displacement.min(len)..]);
+    output_chikou[shifted..].fill(T::NAN);
+
+    Ok(IchimokuState {
+        tenkan: output_tenkan[len - 1],
+        kijun: output_kijun[len - 1],
+        senkou_a: output_senkou_a[len - 1],
+        senkou_b: output_senkou_b[len - 1],
+        chikou: ohlcv.close[len - 1],
+        tenkan_state,
+        kijun_state,
+        senkou_b_state,
+        senkou_a_ahead: RingBuffer::from(senkou_a_ahead),
+        senkou_b_ahead: RingBuffer::from(senkou_b_ahead),
+        displacement,
+    })
+}
+
+batch_functions!(
+    ichimoku_batch,
+    ichimoku_batch_into,
+    ichimoku,
+    ichimoku_into,
+    IchimokuResult,
+    IchimokuState,
+    "Ichimoku",
+    ohlcv -> [output_tenkan, output_kijun, output_senkou_a, output_senkou_b, output_chikou];
+    periods: IchimokuPeriods
+);
+
+#[inline(always)]
+fn senkou_a_unchecked<T: TechalibFloat>(tenkan: T, kijun: T) -> T {
+    (tenkan + kijun) / T::from_f64(2.0)
+}
@@ -40,19 +40,21 @@
 
 //! Kaufman Adaptive Moving Average (KAMA) implementation
 
-use std::collections::VecDeque;
-
+use crate::batch::batch_functions;
 use crate::errors::TechalibError;
+use crate::precision;
+use crate::ring_buffer::RingBuffer;
 use crate::traits::State;
-use crate::types::Float;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 /// Fast period for KAMA calculation. It is used to calculate the Smoothing Constant (SC).
-pub const FAST_PERIOD: Float = 2.0;
+pub const FAST_PERIOD: f64 = 2.0;
 /// Slow period for KAMA calculation. It is used to calculate the Smoothing Constant (SC).
-pub const SLOW_PERIOD: Float = 30.0;
+pub const SLOW_PERIOD: f64 = 30.0;
 
-const SC_SLOW: Float = 2.0 / (SLOW_PERIOD + 1.0);
-const SC_DELTA: Float = (2.0 / (FAST_PERIOD + 1.0)) - SC_SLOW;
+const SC_SLOW: f64 = 2.0 / (SLOW_PERIOD + 1.0);
+const SC_DELTA: f64 = (2.0 / (FAST_PERIOD + 1.0)) - SC_SLOW;
 
 /// KAMA calculation result
 /// ---
@@ -65,11 +67,11 @@ const SC_DELTA: Float = (2.0 / (FAST_PERIOD + 1.0)) - SC_SLOW;
 /// - `state`: A [`KamaState`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct KamaResult {
+pub struct KamaResult<T = Float> {
     /// The calculated KAMA values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// The [`KamaState`] state of the KAMA calculation.
-    pub state: KamaState,
+    pub state: KamaState<T>,
 }
 
 /// KAMA calculation state
@@ -91,33 +93,33 @@ pub struct KamaResult {
 /// **Parameters**
 /// - `period`: The period used for the KAMA calculation to calculate Efficiency Ratio.
 #[derive(Debug, Clone)]
-pub struct KamaState {
+pub struct KamaState<T = Float> {
     // Outputs
     /// The last calculated KAMA value.
-    pub kama: Float,
+    pub kama: T,
 
     // State values
     /// The last calculated Efficiency Ratio sum.
     /// Define such as: `roc_sum = roc1 + roc_sum - prev_roc1` or `sum of |data[t-i] - data[t-i-1]| for i in [1, period]`
-    pub roc_sum: Float,
+    pub roc_sum: T,
     /// The last window containing the previous input value over the last period.
-    pub last_window: VecDeque<Float>,
+    pub last_window: RingBuffer<T>,
     /// The last trailing value used in the calculation.
     /// It is the value that was removed from the `last_window` during the last update.
-    pub trailing_value: Float,
+    pub trailing_value: T,
 
     // Parameters
     /// The period used for the KAMA calculation to calculate Efficiency Ratio.
     pub period: usize,
 }
 
-impl State<Float> for KamaState {
+impl<T: TechalibFloat> State<T> for KamaState<T> {
     /// Update the [`KamaState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the KAMA state
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if !sample.is_finite() {
             return Err(TechalibError::DataNonFinite(
                 format!("sample = {sample:?}",),
@@ -151,27 +153,37 @@ impl State<Float> for KamaState {
             }
         }
 
-        let mut window = self.last_window.clone();
-
-        let new_trailing_value = window.pop_front().ok_or(TechalibError::InsufficientData)?;
-        let prev_value = *window.back().ok_or(TechalibError::InsufficientData)?;
-        window.push_back(sample);
+        let roc_sum = if precision::resync_due(self.last_window.head(), self.period) {
+            let window = self.last_window.make_contiguous();
+            (window[0] - self.trailing_value).abs() + window_roc_sum(window)
+        } else {
+            self.roc_sum
+        };
+
+        let new_trailing_value = self
+            .last_window
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
+        let prev_value = self
+            .last_window
+            .back()
+            .ok_or(TechalibError::InsufficientData)?;
 
         let (kama, roc_sum) = kama_next_unchecked(
             sample,
             prev_value,
             new_trailing_value,
             (new_trailing_value - self.trailing_value).abs(),
-            self.roc_sum,
+            roc_sum,
             self.kama,
         );
 
         if !kama.is_finite() {
-            return Err(TechalibError::Overflow(0, kama));
+            return Err(TechalibError::Overflow(0, kama.to_f64()));
         }
         self.kama = kama;
         self.roc_sum = roc_sum;
-        self.last_window = window;
+        self.last_window.push(sample);
         self.trailing_value = new_trailing_value;
 
         Ok(())
@@ -201,8 +213,8 @@ pub fn lookback_from_period(period: usize) -> usize {
 /// ---
 /// A `Result` containing a [`KamaResult`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn kama(data: &[Float], period: usize) -> Result<KamaResult, TechalibError> {
-    let mut output = vec![0.0; data.len()];
+pub fn kama<T: TechalibFloat>(data: &[T], period: usize) -> Result<KamaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
 
     let kama_state = kama_into(data, period, output.as_mut_slice())?;
 
@@ -230,11 +242,11 @@ pub fn kama(data: &[Float], period: usize) -> Result<KamaResult, TechalibError>
 /// ---
 /// A `Result` containing a [`KamaState`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn kama_into(
-    data: &[Float],
+pub fn kama_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    output: &mut [Float],
-) -> Result<KamaState, TechalibError> {
+    output: &mut [T],
+) -> Result<KamaState<T>, TechalibError> {
     let len = data.len();
     let lookback = lookback_from_period(period);
 
@@ -252,7 +264,7 @@ pub fn kama_into(
     let (kama, mut roc_sum) = init_kama_unchecked(data, lookback, output)?;
 
     if !kama.is_finite() {
-        return Err(TechalibError::Overflow(lookback, output[lookback]));
+        return Err(TechalibError::Overflow(lookback, output[lookback].to_f64()));
     }
     output[lookback] = kama;
 
@@ -264,6 +276,9 @@ pub fn kama_into(
             )));
         }
 
+        if precision::resync_due(idx, period) {
+            roc_sum = window_roc_sum(&data[idx - period - 1..idx]);
+        }
         (output[idx], roc_sum) = kama_next_unchecked(
             data[idx],
             data[idx - 1],
@@ -274,27 +289,38 @@ pub fn kama_into(
         );
 
         if !output[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output[idx]));
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
         }
     }
 
     Ok(KamaState {
         kama: output[len - 1],
         roc_sum,
-        last_window: VecDeque::from(data[len - period..len].to_vec()),
+        last_window: RingBuffer::from(&data[len - period..len]),
         trailing_value: data[len - period - 1],
         period,
     })
 }
 
+batch_functions!(
+    kama_batch,
+    kama_batch_into,
+    kama,
+    kama_into,
+    KamaResult,
+    KamaState,
+    "KAMA";
+    period: usize
+);
+
 #[inline(always)]
-fn init_kama_unchecked(
-    data: &[Float],
+fn init_kama_unchecked<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    output: &mut [Float],
-) -> Result<(Float, Float), TechalibError> {
-    let mut roc_sum = 0.0;
-    output[0] = Float::NAN;
+    output: &mut [T],
+) -> Result<(T, T), TechalibError> {
+    let mut roc_sum = T::ZERO;
+    output[0] = T::NAN;
 
     if !data[0].is_finite() {
         return Err(TechalibError::DataNonFinite(format!(
@@ -310,7 +336,7 @@ fn init_kama_unchecked(
     }
     let first_roc1 = (data[1] - data[0]).abs();
     roc_sum += first_roc1;
-    output[1] = Float::NAN;
+    output[1] = T::NAN;
 
     for idx in 2..period {
         if !data[idx].is_finite() {
@@ -320,7 +346,7 @@ fn init_kama_unchecked(
             )));
         }
         roc_sum += (data[idx] - data[idx - 1]).abs();
-        output[idx] = Float::NAN;
+        output[idx] = T::NAN;
     }
     if !data[period].is_finite() {
         return Err(TechalibError::DataNonFinite(format!(
@@ -333,28 +359,41 @@ fn init_kama_unchecked(
     let period_roc = (data[period] - data[0]).abs();
 
     let mut sc = if roc_sum < period_roc {
-        1.0
+        T::ONE
     } else {
         period_roc / roc_sum
-    } * SC_DELTA
-        + SC_SLOW;
+    } * T::from_f64(SC_DELTA)
+        + T::from_f64(SC_SLOW);
     sc *= sc;
 
     Ok(((data[period] - prev_kama) * sc + prev_kama, roc_sum))
 }
 
+/// Sum of the absolute differences between consecutive values of `window`
 #[inline(always)]
-fn kama_next_unchecked(
-    new_value: Float,
-    prev_value: Float,
-    trailing_value: Float,
-    trailing_roc: Float,
-    roc_sum: Float,
-    prev_kama: Float,
-) -> (Float, Float) {
+fn window_roc_sum<T: TechalibFloat>(window: &[T]) -> T {
+    window
+        .windows(2)
+        .fold(T::ZERO, |sum, pair| sum + (pair[1] - pair[0]).abs())
+}
+
+#[inline(always)]
+fn kama_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    prev_value: T,
+    trailing_value: T,
+    trailing_roc: T,
+    roc_sum: T,
+    prev_kama: T,
+) -> (T, T) {
     let diff = (new_value - trailing_value).abs();
     let roc_sum = roc_sum - trailing_roc + (new_value - prev_value).abs();
-    let mut sc = if roc_sum <= diff { 1.0 } else { diff / roc_sum } * SC_DELTA + SC_SLOW;
+    let mut sc = if roc_sum <= diff {
+        T::ONE
+    } else {
+        diff / roc_sum
+    } * T::from_f64(SC_DELTA)
+        + T::from_f64(SC_SLOW);
     sc *= sc;
     ((new_value - prev_kama) * sc + prev_kama, roc_sum)
 }
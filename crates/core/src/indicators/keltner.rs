@@ -0,0 +1,303 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Keltner Channels (KELTNER) implementation
+//!
+//! The middle band is an EMA of the close and the upper and lower bands
+//! are shifted by a multiple of the Average True Range ([`atr`](crate::indicators::atr)).
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::atr::{atr_into, AtrState};
+use crate::indicators::ema::{ema_into, EmaState};
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Keltner Channels result
+/// ---
+/// This struct holds the result of the Keltner Channels calculation.
+/// It contains the upper, middle, and lower bands as well as the state of the calculation.
+///
+/// Attributes
+/// ---
+/// - `upper`: The upper band values.
+/// - `middle`: The middle band values (EMA of the close).
+/// - `lower`: The lower band values.
+/// - `state`: A [`KeltnerState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct KeltnerResult<T = Float> {
+    /// The upper band values.
+    pub upper: Vec<T>,
+    /// The middle band values (EMA of the close).
+    pub middle: Vec<T>,
+    /// The lower band values.
+    pub lower: Vec<T>,
+    /// A [`KeltnerState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: KeltnerState<T>,
+}
+
+/// Keltner Channels calculation state
+/// ---
+/// This struct holds the state of the Keltner Channels calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `upper`: The last upper band value.
+/// - `middle`: The last middle band value.
+/// - `lower`: The last lower band value.
+///
+/// **State values**
+/// - `ema`: The [`EmaState`] of the middle band.
+/// - `atr`: The [`AtrState`] used for the width of the bands.
+///
+/// **Parameters**
+/// - `multiplier`: The multiplier of the ATR used to calculate the upper and lower bands.
+#[derive(Debug, Clone, Copy)]
+pub struct KeltnerState<T = Float> {
+    // Outputs values
+    /// The last upper band value.
+    pub upper: T,
+    /// The last middle band value.
+    pub middle: T,
+    /// The last lower band value.
+    pub lower: T,
+
+    // State values
+    /// The [`EmaState`] of the middle band.
+    pub ema: EmaState<T>,
+    /// The [`AtrState`] used for the width of the bands.
+    pub atr: AtrState<T>,
+
+    // Parameters
+    /// The multiplier of the ATR used to calculate the upper and lower bands.
+    pub multiplier: T,
+}
+
+impl<T: TechalibFloat> State<(T, T, T)> for KeltnerState<T> {
+    /// Update the [`KeltnerState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, close)` of the new bar.
+    fn update(&mut self, sample: (T, T, T)) -> Result<(), TechalibError> {
+        check_multiplier_unchecked(self.multiplier)?;
+
+        let mut ema = self.ema;
+        let mut atr = self.atr;
+        ema.update(sample.2)?;
+        atr.update(sample)?;
+
+        let (upper, lower) = keltner_bands_unchecked(ema.ema, atr.atr, self.multiplier);
+        if !upper.is_finite() {
+            return Err(TechalibError::Overflow(0, upper.to_f64()));
+        }
+        if !lower.is_finite() {
+            return Err(TechalibError::Overflow(0, lower.to_f64()));
+        }
+
+        self.upper = upper;
+        self.middle = ema.ema;
+        self.lower = lower;
+        self.ema = ema;
+        self.atr = atr;
+        Ok(())
+    }
+}
+
+/// Lookback period for Keltner Channels calculation
+/// ---
+/// With `n = lookback_from_period(period, atr_period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Keltner Channels values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize, atr_period: usize) -> usize {
+    (period - 1).max(atr_period)
+}
+
+/// Calculate Keltner Channels for the given bars and return the result.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period of the EMA of the close (middle band).
+/// - `atr_period`: The period of the ATR.
+/// - `multiplier`: The multiplier of the ATR used to calculate the upper and lower bands.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`KeltnerResult`] with the upper, middle, and lower bands,
+/// or an error if the calculation fails.
+pub fn keltner<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    atr_period: usize,
+    multiplier: T,
+) -> Result<KeltnerResult<T>, TechalibError> {
+    let len = ohlcv.close.len();
+    let mut output_upper = vec![T::ZERO; len];
+    let mut output_middle = vec![T::ZERO; len];
+    let mut output_lower = vec![T::ZERO; len];
+
+    let keltner_state = keltner_into(
+        ohlcv,
+        period,
+        atr_period,
+        multiplier,
+        output_upper.as_mut_slice(),
+        output_middle.as_mut_slice(),
+        output_lower.as_mut_slice(),
+    )?;
+
+    Ok(KeltnerResult {
+        upper: output_upper,
+        middle: output_middle,
+        lower: output_lower,
+        state: keltner_state,
+    })
+}
+
+/// Calculate Keltner Channels and store the results in provided output arrays and return the state.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period of the EMA of the close (middle band).
+/// - `atr_period`: The period of the ATR.
+/// - `multiplier`: The multiplier of the ATR used to calculate the upper and lower bands.
+///
+/// Output Arguments
+/// ---
+/// - `output_upper`: A mutable slice to store the upper band values.
+/// - `output_middle`: A mutable slice to store the middle band values.
+/// - `output_lower`: A mutable slice to store the lower band values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`KeltnerState`] with the last calculated values and state, or an error if the calculation fails.
+pub fn keltner_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    atr_period: usize,
+    multiplier: T,
+    output_upper: &mut [T],
+    output_middle: &mut [T],
+    output_lower: &mut [T],
+) -> Result<KeltnerState<T>, TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(
+            "EMA period must be greater than 1".to_string(),
+        ));
+    }
+    check_multiplier_unchecked(multiplier)?;
+
+    let len = columns_len(&[
+        ("high", ohlcv.high),
+        ("low", ohlcv.low),
+        ("close", ohlcv.close),
+    ])?;
+    if output_upper.len() < len || output_middle.len() < len || output_lower.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_period(period, atr_period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    // The ATR is stored in the upper band until the bands are calculated
+    let atr = atr_into(ohlcv, atr_period, output_upper)?;
+    let ema = ema_into(ohlcv.close, period, None, output_middle)?;
+
+    for idx in 0..lookback {
+        output_upper[idx] = T::NAN;
+        output_middle[idx] = T::NAN;
+        output_lower[idx] = T::NAN;
+    }
+    for idx in lookback..len {
+        (output_upper[idx], output_lower[idx]) =
+            keltner_bands_unchecked(output_middle[idx], output_upper[idx], multiplier);
+        if !output_upper[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output_upper[idx].to_f64()));
+        }
+        if !output_lower[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output_lower[idx].to_f64()));
+        }
+    }
+
+    Ok(KeltnerState {
+        upper: output_upper[len - 1],
+        middle: output_middle[len - 1],
+        lower: output_lower[len - 1],
+        ema,
+        atr,
+        multiplier,
+    })
+}
+
+batch_functions!(
+    keltner_batch,
+    keltner_batch_into,
+    keltner,
+    keltner_into,
+    KeltnerResult,
+    KeltnerState,
+    "Keltner Channels",
+    ohlcv -> [output_upper, output_middle, output_lower];
+    period: usize, atr_period: usize, multiplier: T
+);
+
+#[inline(always)]
+fn keltner_bands_unchecked<T: TechalibFloat>(middle: T, atr: T, multiplier: T) -> (T, T) {
+    let width = multiplier * atr;
+    (middle + width, middle - width)
+}
+
+#[inline(always)]
+fn check_multiplier_unchecked<T: TechalibFloat>(multiplier: T) -> Result<(), TechalibError> {
+    if !multiplier.is_finite() || multiplier <= T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "Multiplier must be a finite value greater than 0, got: {multiplier:?}"
+        )));
+    }
+    Ok(())
+}
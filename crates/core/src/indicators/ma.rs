@@ -0,0 +1,345 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB MA implementation
+*/
+
+//! Moving Average (MA) implementation
+//!
+//! Generic moving average, dispatching to one of the moving averages of the
+//! crate according to a [`MaType`]. It is used by the indicators built on
+//! top of "any" moving average (e.g. the envelope bands).
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::alma::{self, alma_into, AlmaState};
+use crate::indicators::dema::{dema_into, dema_skip_period_unchecked, DemaState};
+use crate::indicators::ema::{ema_into, EmaState};
+use crate::indicators::hma::{self, hma_into, HmaState};
+use crate::indicators::kama::{self, kama_into, KamaState};
+use crate::indicators::sma::{sma_into, SmaState};
+use crate::indicators::smma::{smma_into, SmmaState};
+use crate::indicators::t3::{t3_into, t3_skip_period_unchecked, T3State};
+use crate::indicators::tema::{tema_into, tema_skip_period_unchecked, TemaState};
+use crate::indicators::trima::{trima_into, TrimaState};
+use crate::indicators::vidya::{self, vidya_into, VidyaState};
+use crate::indicators::wma::{wma_into, WmaState};
+use crate::indicators::zlema::{self, zlema_into, ZlemaState};
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{string::ToString, vec, vec::Vec};
+
+/// MA calculation result
+/// ---
+/// This struct holds the result and the state ([`MaState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated MA values.
+/// - `state`: A [`MaState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct MaResult<T = Float> {
+    /// The calculated MA values.
+    pub values: Vec<T>,
+    /// A [`MaState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: MaState<T>,
+}
+
+/// Type of moving average
+/// ---
+///
+/// This enum defines the type of moving average used by [`ma`] and
+/// the indicators built on top of it.
+///
+/// Variants
+/// ---
+/// - `SMA`: Simple Moving Average.
+/// - `EMA`: Exponential Moving Average, with an optional alpha value.
+/// - `WMA`: Weighted Moving Average.
+/// - `DEMA`: Double Exponential Moving Average, with an optional alpha value.
+/// - `TEMA`: Triple Exponential Moving Average, with an optional alpha value.
+/// - `TRIMA`: Triangular Moving Average.
+/// - `KAMA`: Kaufman Adaptive Moving Average.
+/// - `T3`: Tillson Triple Moving Average, with its volume factor and
+///   an optional alpha value.
+/// - `HMA`: Hull Moving Average.
+/// - `ZLEMA`: Zero-Lag Exponential Moving Average, with an optional alpha value.
+/// - `ALMA`: Arnaud Legoux Moving Average, with its offset and sigma.
+/// - `SMMA`: Smoothed Moving Average (Wilder's RMA).
+/// - `VIDYA`: Variable Index Dynamic Average, with the period of its CMO.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaType<T = Float> {
+    /// Simple Moving Average.
+    SMA,
+    /// Exponential Moving Average, with an optional alpha value.
+    EMA(Option<T>),
+    /// Weighted Moving Average.
+    WMA,
+    /// Double Exponential Moving Average, with an optional alpha value.
+    DEMA(Option<T>),
+    /// Triple Exponential Moving Average, with an optional alpha value.
+    TEMA(Option<T>),
+    /// Triangular Moving Average.
+    TRIMA,
+    /// Kaufman Adaptive Moving Average.
+    KAMA,
+    /// Tillson Triple Moving Average, with its volume factor and
+    /// an optional alpha value.
+    T3(T, Option<T>),
+    /// Hull Moving Average.
+    HMA,
+    /// Zero-Lag Exponential Moving Average, with an optional alpha value.
+    ZLEMA(Option<T>),
+    /// Arnaud Legoux Moving Average, with its offset and sigma.
+    ALMA(T, T),
+    /// Smoothed Moving Average (Wilder's RMA).
+    SMMA,
+    /// Variable Index Dynamic Average, with the period of its CMO.
+    VIDYA(usize),
+}
+
+/// MA calculation state
+/// ---
+/// This enum holds the state of the moving average selected by [`MaType`].
+/// It is used to calculate the next values in a incremental way.
+#[derive(Debug, Clone)]
+pub enum MaState<T = Float> {
+    /// State of a Simple Moving Average.
+    SMA(SmaState<T>),
+    /// State of an Exponential Moving Average.
+    EMA(EmaState<T>),
+    /// State of a Weighted Moving Average.
+    WMA(WmaState<T>),
+    /// State of a Double Exponential Moving Average.
+    DEMA(DemaState<T>),
+    /// State of a Triple Exponential Moving Average.
+    TEMA(TemaState<T>),
+    /// State of a Triangular Moving Average.
+    TRIMA(TrimaState<T>),
+    /// State of a Kaufman Adaptive Moving Average.
+    KAMA(KamaState<T>),
+    /// State of a Tillson Triple Moving Average.
+    T3(T3State<T>),
+    /// State of a Hull Moving Average.
+    HMA(HmaState<T>),
+    /// State of a Zero-Lag Exponential Moving Average.
+    ZLEMA(ZlemaState<T>),
+    /// State of an Arnaud Legoux Moving Average.
+    ALMA(AlmaState<T>),
+    /// State of a Smoothed Moving Average.
+    SMMA(SmmaState<T>),
+    /// State of a Variable Index Dynamic Average.
+    VIDYA(VidyaState<T>),
+}
+
+impl<T: TechalibFloat> MaState<T> {
+    /// The last calculated MA value.
+    pub fn value(&self) -> T {
+        match self {
+            MaState::SMA(state) => state.sma,
+            MaState::EMA(state) => state.ema,
+            MaState::WMA(state) => state.wma,
+            MaState::DEMA(state) => state.dema,
+            MaState::TEMA(state) => state.tema,
+            MaState::TRIMA(state) => state.trima,
+            MaState::KAMA(state) => state.kama,
+            MaState::T3(state) => state.t3,
+            MaState::HMA(state) => state.hma,
+            MaState::ZLEMA(state) => state.zlema,
+            MaState::ALMA(state) => state.alma,
+            MaState::SMMA(state) => state.smma,
+            MaState::VIDYA(state) => state.vidya,
+        }
+    }
+
+    /// The period of the moving average.
+    pub fn period(&self) -> usize {
+        match self {
+            MaState::SMA(state) => state.period,
+            MaState::EMA(state) => state.period,
+            MaState::WMA(state) => state.period,
+            MaState::DEMA(state) => state.period,
+            MaState::TEMA(state) => state.period,
+            MaState::TRIMA(state) => state.period,
+            MaState::KAMA(state) => state.period,
+            MaState::T3(state) => state.period,
+            MaState::HMA(state) => state.period,
+            MaState::ZLEMA(state) => state.period,
+            MaState::ALMA(state) => state.period,
+            MaState::SMMA(state) => state.period,
+            MaState::VIDYA(state) => state.period,
+        }
+    }
+}
+
+impl<T: TechalibFloat> State<T> for MaState<T> {
+    /// Update the [`MaState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the MA state.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        match self {
+            MaState::SMA(state) => state.update(sample),
+            MaState::EMA(state) => state.update(sample),
+            MaState::WMA(state) => state.update(sample),
+            MaState::DEMA(state) => state.update(sample),
+            MaState::TEMA(state) => state.update(sample),
+            MaState::TRIMA(state) => state.update(sample),
+            MaState::KAMA(state) => state.update(sample),
+            MaState::T3(state) => state.update(sample),
+            MaState::HMA(state) => state.update(sample),
+            MaState::ZLEMA(state) => state.update(sample),
+            MaState::ALMA(state) => state.update(sample),
+            MaState::SMMA(state) => state.update(sample),
+            MaState::VIDYA(state) => state.update(sample),
+        }
+    }
+}
+
+/// Lookback period for MA calculation
+/// ---
+/// With `n = lookback_from_period(period, ma_type)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the MA values.
+#[inline(always)]
+pub fn lookback_from_period<T: TechalibFloat>(period: usize, ma_type: MaType<T>) -> usize {
+    match ma_type {
+        MaType::SMA | MaType::EMA(_) | MaType::WMA | MaType::TRIMA | MaType::SMMA => period - 1,
+        MaType::DEMA(_) => dema_skip_period_unchecked(period),
+        MaType::TEMA(_) => tema_skip_period_unchecked(period),
+        MaType::KAMA => kama::lookback_from_period(period),
+        MaType::T3(_, _) => t3_skip_period_unchecked(period),
+        MaType::HMA => hma::lookback_from_period(period),
+        MaType::ZLEMA(_) => zlema::lookback_from_period(period),
+        MaType::ALMA(_, _) => alma::lookback_from_period(period),
+        MaType::VIDYA(cmo_period) => vidya::lookback_from_period(period, cmo_period),
+    }
+}
+
+/// Calculation of the MA function
+/// ---
+/// It returns a [`MaResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period of the moving average.
+/// - `ma_type`: The type of moving average to calculate.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MaResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn ma<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    ma_type: MaType<T>,
+) -> Result<MaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let ma_state = ma_into(data, period, ma_type, output.as_mut_slice())?;
+    Ok(MaResult {
+        values: output,
+        state: ma_state,
+    })
+}
+
+/// Calculation of the MA function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`MaState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period of the moving average.
+/// - `ma_type`: The type of moving average to calculate.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the MA values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MaState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn ma_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    ma_type: MaType<T>,
+    output: &mut [T],
+) -> Result<MaState<T>, TechalibError> {
+    if output.len() < data.len() {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    Ok(match ma_type {
+        MaType::SMA => MaState::SMA(sma_into(data, period, output)?),
+        MaType::EMA(alpha) => MaState::EMA(ema_into(data, period, alpha, output)?),
+        MaType::WMA => MaState::WMA(wma_into(data, period, output)?),
+        MaType::DEMA(alpha) => MaState::DEMA(dema_into(data, period, alpha, output)?),
+        MaType::TEMA(alpha) => MaState::TEMA(tema_into(data, period, alpha, output)?),
+        MaType::TRIMA => MaState::TRIMA(trima_into(data, period, output)?),
+        MaType::KAMA => MaState::KAMA(kama_into(data, period, output)?),
+        MaType::T3(volume_factor, alpha) => {
+            MaState::T3(t3_into(data, period, volume_factor, alpha, output)?)
+        }
+        MaType::HMA => MaState::HMA(hma_into(data, period, output)?),
+        MaType::ZLEMA(alpha) => MaState::ZLEMA(zlema_into(data, period, alpha, output)?),
+        MaType::ALMA(offset, sigma) => {
+            MaState::ALMA(alma_into(data, period, offset, sigma, output)?)
+        }
+        MaType::SMMA => MaState::SMMA(smma_into(data, period, output)?),
+        MaType::VIDYA(cmo_period) => MaState::VIDYA(vidya_into(data, period, cmo_period, output)?),
+    })
+}
+
+batch_functions!(
+    ma_batch,
+    ma_batch_into,
+    ma,
+    ma_into,
+    MaResult,
+    MaState,
+    "MA";
+    period: usize, ma_type: MaType<T>
+);
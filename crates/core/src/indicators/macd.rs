@@ -41,10 +41,12 @@
 //! Moving Average Convergence Divergence (MACD) implementation
 
 use super::ema::period_to_alpha;
+use crate::batch::batch_functions;
 use crate::errors::TechalibError;
 use crate::indicators::ema::ema_next_unchecked;
 use crate::traits::State;
-use crate::types::Float;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 /// MACD calculation result
 /// ---
@@ -59,16 +61,16 @@ use crate::types::Float;
 /// - `state`: A [`MacdState`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct MacdResult {
+pub struct MacdResult<T = Float> {
     /// The calculated MACD line values.
-    pub macd: Vec<Float>,
+    pub macd: Vec<T>,
     /// The calculated signal line values.
-    pub signal: Vec<Float>,
+    pub signal: Vec<T>,
     /// The calculated histogram values.
-    pub histogram: Vec<Float>,
+    pub histogram: Vec<T>,
     /// A [`MacdState`], which can be used to calculate the next values
     /// incrementally.
-    pub state: MacdState,
+    pub state: MacdState<T>,
 }
 
 /// MACD calculation state
@@ -92,20 +94,20 @@ pub struct MacdResult {
 /// - `slow_period`: The period used for the slow EMA calculation.
 /// - `signal_period`: The period used for the signal line calculation.
 #[derive(Debug, Clone, Copy)]
-pub struct MacdState {
+pub struct MacdState<T = Float> {
     // Outputs values
     /// The last calculated MACD value
-    pub macd: Float,
+    pub macd: T,
     /// The last calculated signal line value
-    pub signal: Float,
+    pub signal: T,
     /// The last calculated histogram value
-    pub histogram: Float,
+    pub histogram: T,
 
     // State values
     /// The last calculated fast Exponential Moving Average (EMA) value
-    pub fast_ema: Float,
+    pub fast_ema: T,
     /// The last calculated slow Exponential Moving Average (EMA) value
-    pub slow_ema: Float,
+    pub slow_ema: T,
 
     // Parameters
     /// The period used for the fast EMA calculation
@@ -116,13 +118,13 @@ pub struct MacdState {
     pub signal_period: usize,
 }
 
-impl State<Float> for MacdState {
+impl<T: TechalibFloat> State<T> for MacdState<T> {
     /// Update the [`MacdState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the MACD state.
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if self.fast_period >= self.slow_period {
             return Err(TechalibError::BadParam(
                 "Fast period must be less than slow period".to_string(),
@@ -183,13 +185,13 @@ impl State<Float> for MacdState {
         );
 
         if !macd.is_finite() {
-            return Err(TechalibError::Overflow(0, macd));
+            return Err(TechalibError::Overflow(0, macd.to_f64()));
         }
         if !signal.is_finite() {
-            return Err(TechalibError::Overflow(0, signal));
+            return Err(TechalibError::Overflow(0, signal.to_f64()));
         }
         if !histogram.is_finite() {
-            return Err(TechalibError::Overflow(0, histogram));
+            return Err(TechalibError::Overflow(0, histogram.to_f64()));
         }
 
         self.fast_ema = fast_ema;
@@ -217,17 +219,17 @@ impl State<Float> for MacdState {
 /// ---
 /// A `Result` containing a [`MacdResult`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn macd(
-    data: &[Float],
+pub fn macd<T: TechalibFloat>(
+    data: &[T],
     fast_period: usize,
     slow_period: usize,
     signal_period: usize,
-) -> Result<MacdResult, TechalibError> {
+) -> Result<MacdResult<T>, TechalibError> {
     let size: usize = data.len();
 
-    let mut output_macd = vec![0.0; size];
-    let mut output_signal = vec![0.0; size];
-    let mut output_histogram = vec![0.0; size];
+    let mut output_macd = vec![T::ZERO; size];
+    let mut output_signal = vec![T::ZERO; size];
+    let mut output_histogram = vec![T::ZERO; size];
 
     let macd_state = macd_into(
         data,
@@ -272,15 +274,15 @@ pub fn macd(
 /// ---
 /// A `Result` containing a [`MacdState`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn macd_into(
-    data: &[Float],
+pub fn macd_into<T: TechalibFloat>(
+    data: &[T],
     fast_period: usize,
     slow_period: usize,
     signal_period: usize,
-    output_macd: &mut [Float],
-    output_signal: &mut [Float],
-    output_histogram: &mut [Float],
-) -> Result<MacdState, TechalibError> {
+    output_macd: &mut [T],
+    output_signal: &mut [T],
+    output_histogram: &mut [T],
+) -> Result<MacdState<T>, TechalibError> {
     if fast_period >= slow_period {
         return Err(TechalibError::BadParam(
             "Fast period must be less than slow period".to_string(),
@@ -305,16 +307,16 @@ pub fn macd_into(
         return Err(TechalibError::InsufficientData);
     }
 
-    output_macd[..macd_start_idx].fill(Float::NAN);
-    output_signal[..macd_start_idx].fill(Float::NAN);
-    output_histogram[..macd_start_idx].fill(Float::NAN);
+    output_macd[..macd_start_idx].fill(T::NAN);
+    output_signal[..macd_start_idx].fill(T::NAN);
+    output_histogram[..macd_start_idx].fill(T::NAN);
 
     let fast_alpha = period_to_alpha(fast_period, None)?;
     let slow_alpha = period_to_alpha(slow_period, None)?;
     let signal_alpha = period_to_alpha(signal_period, None)?;
 
-    let mut fast_sum = 0.0;
-    let mut slow_sum = 0.0;
+    let mut fast_sum = T::ZERO;
+    let mut slow_sum = T::ZERO;
 
     for (idx, value) in data
         .iter()
@@ -327,7 +329,7 @@ pub fn macd_into(
                 "data[{idx}] = {value:?}",
             )));
         }
-        slow_sum += value;
+        slow_sum += *value;
     }
 
     for (idx, value) in data
@@ -341,11 +343,11 @@ pub fn macd_into(
                 "data[{idx}] = {value:?}"
             )));
         }
-        slow_sum += value;
-        fast_sum += value;
+        slow_sum += *value;
+        fast_sum += *value;
     }
-    let mut fast_ema = fast_sum / fast_period as Float;
-    let mut slow_ema = slow_sum / slow_period as Float;
+    let mut fast_ema = fast_sum / T::from_usize(fast_period);
+    let mut slow_ema = slow_sum / T::from_usize(slow_period);
     let mut sum_macd = fast_ema - slow_ema;
 
     for (idx, value) in data
@@ -368,7 +370,7 @@ pub fn macd_into(
     slow_ema = ema_next_unchecked(data[macd_start_idx], slow_ema, slow_alpha);
     output_macd[macd_start_idx] = fast_ema - slow_ema;
     sum_macd += output_macd[macd_start_idx];
-    output_signal[macd_start_idx] = sum_macd / signal_period as Float;
+    output_signal[macd_start_idx] = sum_macd / T::from_usize(signal_period);
     output_histogram[macd_start_idx] = output_macd[macd_start_idx] - output_signal[macd_start_idx];
 
     for idx in macd_start_idx + 1..len {
@@ -394,13 +396,13 @@ pub fn macd_into(
             signal_alpha,
         );
         if !output_macd[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output_macd[idx]));
+            return Err(TechalibError::Overflow(idx, output_macd[idx].to_f64()));
         }
         if !output_signal[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output_signal[idx]));
+            return Err(TechalibError::Overflow(idx, output_signal[idx].to_f64()));
         }
         if !output_histogram[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output_histogram[idx]));
+            return Err(TechalibError::Overflow(idx, output_histogram[idx].to_f64()));
         }
     }
 
@@ -416,16 +418,28 @@ pub fn macd_into(
     })
 }
 
+batch_functions!(
+    macd_batch,
+    macd_batch_into,
+    macd,
+    macd_into,
+    MacdResult,
+    MacdState,
+    "MACD",
+    data -> [output_macd, output_signal, output_histogram];
+    fast_period: usize, slow_period: usize, signal_period: usize
+);
+
 #[inline(always)]
-fn macd_next_unchecked(
-    new_value: Float,
-    prev_fast_ema: Float,
-    prev_slow_ema: Float,
-    prev_signal: Float,
-    fast_alpha: Float,
-    slow_alpha: Float,
-    signal_alpha: Float,
-) -> (Float, Float, Float, Float, Float) {
+fn macd_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    prev_fast_ema: T,
+    prev_slow_ema: T,
+    prev_signal: T,
+    fast_alpha: T,
+    slow_alpha: T,
+    signal_alpha: T,
+) -> (T, T, T, T, T) {
     let fast_ema = ema_next_unchecked(new_value, prev_fast_ema, fast_alpha);
     let slow_ema = ema_next_unchecked(new_value, prev_slow_ema, slow_alpha);
     let macd = fast_ema - slow_ema;
@@ -0,0 +1,136 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB Math Operator functions
+*/
+
+//! Math Operator functions (ADD, SUB, MULT, DIV) implementation
+//!
+//! Every function is applied element-wise on two input slices of the same
+//! length and is NaN-aware: a `NaN` in one of the inputs produces a `NaN` at
+//! the same index in the output instead of an error.
+
+use crate::errors::TechalibError;
+use crate::types::TechalibFloat;
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+macro_rules! math_operator {
+    ($name:ident, $name_into:ident, $op:expr, $desc:literal) => {
+        #[doc = concat!("Calculation of the ", $desc, " function")]
+        /// ---
+        /// It returns a vector of [`Float`] with the same length as the inputs.
+        ///
+        /// Input Arguments
+        /// ---
+        /// - `data0`: A slice of [`Float`] representing the first input data.
+        /// - `data1`: A slice of [`Float`] representing the second input data.
+        ///
+        /// Returns
+        /// ---
+        /// A `Result` containing the calculated values,
+        /// or a [`TechalibError`] error if the calculation fails.
+        pub fn $name<T: TechalibFloat>(data0: &[T], data1: &[T]) -> Result<Vec<T>, TechalibError> {
+            let mut output = vec![T::ZERO; data0.len()];
+            $name_into(data0, data1, output.as_mut_slice())?;
+            Ok(output)
+        }
+
+        #[doc = concat!("Calculation of the ", $desc, " function")]
+        /// ---
+        /// It stores the results in the provided output array.
+        ///
+        /// Input Arguments
+        /// ---
+        /// - `data0`: A slice of [`Float`] representing the first input data.
+        /// - `data1`: A slice of [`Float`] representing the second input data.
+        ///
+        /// Output Arguments
+        /// ---
+        /// - `output`: A mutable slice of [`Float`] where the calculated values
+        ///   will be stored.
+        ///
+        /// Returns
+        /// ---
+        /// An empty `Result`, or a [`TechalibError`] error if the calculation fails.
+        pub fn $name_into<T: TechalibFloat>(
+            data0: &[T],
+            data1: &[T],
+            output: &mut [T],
+        ) -> Result<(), TechalibError> {
+            check_binary_unchecked(data0, data1, output)?;
+            let op: fn(T, T) -> T = $op;
+            for ((out, &lhs), &rhs) in output.iter_mut().zip(data0).zip(data1) {
+                *out = op(lhs, rhs);
+            }
+            Ok(())
+        }
+    };
+}
+
+math_operator!(add, add_into, |lhs, rhs| lhs + rhs, "vector arithmetic ADD");
+math_operator!(sub, sub_into, |lhs, rhs| lhs - rhs, "vector arithmetic SUB");
+math_operator!(
+    mult,
+    mult_into,
+    |lhs, rhs| lhs * rhs,
+    "vector arithmetic MULT"
+);
+math_operator!(div, div_into, |lhs, rhs| lhs / rhs, "vector arithmetic DIV");
+
+#[inline(always)]
+fn check_binary_unchecked<T: TechalibFloat>(
+    data0: &[T],
+    data1: &[T],
+    output: &[T],
+) -> Result<(), TechalibError> {
+    if data0.is_empty() {
+        return Err(TechalibError::InsufficientData);
+    }
+    if data0.len() != data1.len() {
+        return Err(TechalibError::BadParam(format!(
+            "Input arrays must have the same length, got: {} and {}",
+            data0.len(),
+            data1.len()
+        )));
+    }
+    if output.len() < data0.len() {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB Math Transform functions
+*/
+
+//! Math Transform functions (ACOS, ASIN, ATAN, CEIL, COS, COSH, EXP, FLOOR,
+//! LN, LOG10, SIN, SINH, SQRT, TAN, TANH) implementation
+//!
+//! Every function is applied element-wise and is NaN-aware: a `NaN` (or any
+//! value outside of the domain of the function) in the input produces a `NaN`
+//! at the same index in the output instead of an error.
+
+use crate::errors::TechalibError;
+use crate::types::TechalibFloat;
+use alloc::{string::ToString, vec, vec::Vec};
+
+macro_rules! math_transform {
+    ($name:ident, $name_into:ident, $op:expr, $desc:literal) => {
+        #[doc = concat!("Calculation of the ", $desc, " function")]
+        /// ---
+        /// It returns a vector of [`Float`] with the same length as the input.
+        ///
+        /// Input Arguments
+        /// ---
+        /// - `data`: A slice of [`Float`] representing the input data.
+        ///
+        /// Returns
+        /// ---
+        /// A `Result` containing the calculated values,
+        /// or a [`TechalibError`] error if the calculation fails.
+        pub fn $name<T: TechalibFloat>(data: &[T]) -> Result<Vec<T>, TechalibError> {
+            let mut output = vec![T::ZERO; data.len()];
+            $name_into(data, output.as_mut_slice())?;
+            Ok(output)
+        }
+
+        #[doc = concat!("Calculation of the ", $desc, " function")]
+        /// ---
+        /// It stores the results in the provided output array.
+        ///
+        /// Input Arguments
+        /// ---
+        /// - `data`: A slice of [`Float`] representing the input data.
+        ///
+        /// Output Arguments
+        /// ---
+        /// - `output`: A mutable slice of [`Float`] where the calculated values
+        ///   will be stored.
+        ///
+        /// Returns
+        /// ---
+        /// An empty `Result`, or a [`TechalibError`] error if the calculation fails.
+        pub fn $name_into<T: TechalibFloat>(
+            data: &[T],
+            output: &mut [T],
+        ) -> Result<(), TechalibError> {
+            check_unary_unchecked(data, output)?;
+            let op: fn(T) -> T = $op;
+            for (out, &value) in output.iter_mut().zip(data) {
+                *out = op(value);
+            }
+            Ok(())
+        }
+    };
+}
+
+math_transform!(acos, acos_into, T::acos, "vector trigonometric ACOS");
+math_transform!(asin, asin_into, T::asin, "vector trigonometric ASIN");
+math_transform!(atan, atan_into, T::atan, "vector trigonometric ATAN");
+math_transform!(ceil, ceil_into, T::ceil, "vector CEIL");
+math_transform!(cos, cos_into, T::cos, "vector trigonometric COS");
+math_transform!(cosh, cosh_into, T::cosh, "vector trigonometric COSH");
+math_transform!(exp, exp_into, T::exp, "vector arithmetic EXP");
+math_transform!(floor, floor_into, T::floor, "vector FLOOR");
+math_transform!(ln, ln_into, T::ln, "vector natural logarithm LN");
+math_transform!(log10, log10_into, T::log10, "vector LOG10");
+math_transform!(sin, sin_into, T::sin, "vector trigonometric SIN");
+math_transform!(sinh, sinh_into, T::sinh, "vector trigonometric SINH");
+math_transform!(sqrt, sqrt_into, T::sqrt, "vector square root SQRT");
+math_transform!(tan, tan_into, T::tan, "vector trigonometric TAN");
+math_transform!(tanh, tanh_into, T::tanh, "vector trigonometric TANH");
+
+#[inline(always)]
+fn check_unary_unchecked<T: TechalibFloat>(data: &[T], output: &[T]) -> Result<(), TechalibError> {
+    if data.is_empty() {
+        return Err(TechalibError::InsufficientData);
+    }
+    if output.len() < data.len() {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    Ok(())
+}
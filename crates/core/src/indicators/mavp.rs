@@ -0,0 +1,629 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB MAVP implementation
+*/
+
+//! Moving Average with Variable Period (MAVP) implementation
+
+use crate::errors::TechalibError;
+use crate::indicators::ema::ema_next_unchecked;
+use crate::indicators::ma::{self, ma_into, MaState, MaType};
+use crate::indicators::sma::sma_next_unchecked;
+use crate::indicators::wma::{inv_weight_sum_linear, wma_next_unchecked};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// MAVP calculation result
+/// ---
+/// This struct holds the result and the state ([`MavpState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated MAVP values.
+/// - `state`: A [`MavpState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct MavpResult<T = Float> {
+    /// The calculated MAVP values.
+    pub values: Vec<T>,
+    /// A [`MavpState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: MavpState<T>,
+}
+
+/// MAVP calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `mavp`: The last calculated MAVP value.
+///
+/// **State values**
+/// - `period`: The (clamped) period used for the last MAVP value.
+/// - `period_sub`: The sum of the last `period` values (WMA only).
+/// - `period_sum`: The weighted sum of the last `period` values (WMA only).
+/// - `emas`: The EMA of each period between `min_period` and `max_period`
+///   (EMA only).
+/// - `ma_states`: The [`MaState`] of each period between `min_period` and
+///   `max_period` (HMA, ZLEMA, ALMA, SMMA and VIDYA only).
+/// - `last_window`: A ring buffer containing the last `max_period` values.
+///
+/// **Parameters**
+/// - `min_period`: The lowest period allowed, lower periods are clamped to it.
+/// - `max_period`: The highest period allowed, higher periods are clamped to it.
+/// - `ma_type`: The type of moving average used.
+#[derive(Debug, Clone)]
+pub struct MavpState<T = Float> {
+    // Outputs
+    /// The last calculated MAVP value.
+    pub mavp: T,
+
+    // State values
+    /// The (clamped) period used for the last MAVP value.
+    pub period: usize,
+    /// The sum of the last `period` values (WMA only).
+    pub period_sub: T,
+    /// The weighted sum of the last `period` values (WMA only).
+    pub period_sum: T,
+    /// The EMA of each period between `min_period` and `max_period` (EMA only).
+    pub emas: Vec<T>,
+    /// The [`MaState`] of each period between `min_period` and `max_period`
+    /// (HMA, ZLEMA, ALMA, SMMA and VIDYA only).
+    pub ma_states: Vec<MaState<T>>,
+    /// A ring buffer containing the last `max_period` values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The lowest period allowed, lower periods are clamped to it.
+    pub min_period: usize,
+    /// The highest period allowed, higher periods are clamped to it.
+    pub max_period: usize,
+    /// The [`MavpMA`] enum variant representing the type of moving average used.
+    pub ma_type: MavpMA<T>,
+}
+
+/// Type of moving average used in MAVP.
+/// ---
+///
+/// This enum defines the type of moving average used in the MAVP calculation.
+///
+/// Variants
+/// ---
+/// - `SMA`: Simple Moving Average.
+/// - `EMA`: Exponential Moving Average. One EMA is maintained for each
+///   possible period, so a change of period gives the value of the EMA of that
+///   period, as in TA-LIB.
+/// - `WMA`: Weighted Moving Average.
+/// - `HMA`: Hull Moving Average.
+/// - `ZLEMA`: Zero-Lag Exponential Moving Average.
+/// - `ALMA`: Arnaud Legoux Moving Average, with its offset and sigma.
+/// - `SMMA`: Smoothed Moving Average (Wilder's RMA).
+/// - `VIDYA`: Variable Index Dynamic Average, with the period of its CMO.
+///
+/// As for the EMA, the state of the HMA, ZLEMA, ALMA, SMMA and VIDYA is
+/// maintained for each possible period.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MavpMA<T = Float> {
+    /// Simple Moving Average.
+    SMA,
+    /// Exponential Moving Average.
+    EMA,
+    /// Weighted Moving Average.
+    WMA,
+    /// Hull Moving Average.
+    HMA,
+    /// Zero-Lag Exponential Moving Average.
+    ZLEMA,
+    /// Arnaud Legoux Moving Average, with its offset and sigma.
+    ALMA(T, T),
+    /// Smoothed Moving Average (Wilder's RMA).
+    SMMA,
+    /// Variable Index Dynamic Average, with the period of its CMO.
+    VIDYA(usize),
+}
+
+impl<T> From<MavpMA<T>> for MaType<T> {
+    fn from(ma_type: MavpMA<T>) -> Self {
+        match ma_type {
+            MavpMA::SMA => MaType::SMA,
+            MavpMA::EMA => MaType::EMA(None),
+            MavpMA::WMA => MaType::WMA,
+            MavpMA::HMA => MaType::HMA,
+            MavpMA::ZLEMA => MaType::ZLEMA(None),
+            MavpMA::ALMA(offset, sigma) => MaType::ALMA(offset, sigma),
+            MavpMA::SMMA => MaType::SMMA,
+            MavpMA::VIDYA(cmo_period) => MaType::VIDYA(cmo_period),
+        }
+    }
+}
+
+impl<T: TechalibFloat> State<(T, T)> for MavpState<T> {
+    /// Update the [`MavpState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(value, period)` with the new input value and
+    ///   the period to use for it.
+    fn update(&mut self, sample: (T, T)) -> Result<(), TechalibError> {
+        let (value, period) = sample;
+        check_param_unchecked(self.min_period, self.max_period)?;
+        if !value.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {value:?}")));
+        }
+        if !period.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("period = {period:?}")));
+        }
+        if !self.mavp.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.mavp = {:?}",
+                self.mavp
+            )));
+        }
+        if self.last_window.len() != self.max_period {
+            return Err(TechalibError::BadParam(format!(
+                "MAVP state last_window length ({}) does not match max_period ({})",
+                self.last_window.len(),
+                self.max_period
+            )));
+        }
+        if self.ma_type == MavpMA::EMA && self.emas.len() != self.max_period - self.min_period + 1 {
+            return Err(TechalibError::BadParam(format!(
+                "MAVP state emas length ({}) does not match the number of periods ({})",
+                self.emas.len(),
+                self.max_period - self.min_period + 1
+            )));
+        }
+        if has_ma_states(self.ma_type)
+            && self.ma_states.len() != self.max_period - self.min_period + 1
+        {
+            return Err(TechalibError::BadParam(format!(
+                "MAVP state ma_states length ({}) does not match the number of periods ({})",
+                self.ma_states.len(),
+                self.max_period - self.min_period + 1
+            )));
+        }
+
+        if has_ma_states(self.ma_type) {
+            for (idx, ma_state) in self.ma_states.iter().enumerate() {
+                if ma_state.period() != self.min_period + idx {
+                    return Err(TechalibError::BadParam(format!(
+                        "MAVP state ma_states[{idx}] period ({}) does not match {}",
+                        ma_state.period(),
+                        self.min_period + idx
+                    )));
+                }
+            }
+        }
+
+        for (idx, &value) in self.last_window.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(TechalibError::DataNonFinite(format!(
+                    "window[{idx}] = {value:?}"
+                )));
+            }
+        }
+
+        let period = clamp_period(period, self.min_period, self.max_period);
+        let old_value = self
+            .last_window
+            .get(self.max_period - period)
+            .ok_or(TechalibError::InsufficientData)?;
+        let last_values = self
+            .last_window
+            .iter()
+            .skip(self.max_period - period + 1)
+            .chain(core::iter::once(&value));
+
+        let mavp = match self.ma_type {
+            MavpMA::SMA => mavp_sma_next_unchecked(
+                value,
+                old_value,
+                period,
+                self.period,
+                self.mavp,
+                last_values,
+            ),
+            MavpMA::EMA => {
+                // The EMA step cannot fail, the EMAs are updated in place
+                mavp_ema_next_unchecked(value, &mut self.emas, self.min_period);
+                self.emas[period - self.min_period]
+            }
+            MavpMA::WMA => {
+                let mavp;
+                (mavp, self.period_sub, self.period_sum) = mavp_wma_next_unchecked(
+                    value,
+                    old_value,
+                    period,
+                    self.period,
+                    self.period_sub,
+                    self.period_sum,
+                    last_values,
+                );
+                mavp
+            }
+            _ => {
+                for ma_state in self.ma_states.iter_mut() {
+                    ma_state.update(value)?;
+                }
+                self.ma_states[period - self.min_period].value()
+            }
+        };
+
+        if !mavp.is_finite() {
+            return Err(TechalibError::Overflow(0, mavp.to_f64()));
+        }
+        self.mavp = mavp;
+        self.period = period;
+        self.last_window.push(value);
+
+        Ok(())
+    }
+}
+
+/// Lookback period for MAVP calculation
+/// ---
+/// With `n = lookback_from_period(max_period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the MAVP values.
+#[inline(always)]
+pub fn lookback_from_period(max_period: usize) -> usize {
+    max_period - 1
+}
+
+/// Lookback period for MAVP calculation with any [`MavpMA`]
+/// ---
+/// With `n = lookback_from_ma_type(min_period, max_period, ma_type)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the MAVP values.
+/// It is the longest lookback of the moving averages of the periods
+/// between `min_period` and `max_period`.
+#[inline(always)]
+pub fn lookback_from_ma_type<T: TechalibFloat>(
+    min_period: usize,
+    max_period: usize,
+    ma_type: MavpMA<T>,
+) -> usize {
+    (min_period..=max_period)
+        .map(|period| ma::lookback_from_period(period, ma_type.into()))
+        .fold(lookback_from_period(max_period), usize::max)
+}
+
+/// Calculation of the MAVP function
+/// ---
+/// It returns a [`MavpResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `periods`: A slice of [`Float`] with the period to use for each value of
+///   `data`. The periods are truncated and clamped between `min_period` and
+///   `max_period`.
+/// - `min_period`: The lowest period allowed.
+/// - `max_period`: The highest period allowed.
+/// - `ma_type`: The type of moving average to use.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MavpResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn mavp<T: TechalibFloat>(
+    data: &[T],
+    periods: &[T],
+    min_period: usize,
+    max_period: usize,
+    ma_type: MavpMA<T>,
+) -> Result<MavpResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let mavp_state = mavp_into(
+        data,
+        periods,
+        min_period,
+        max_period,
+        ma_type,
+        output.as_mut_slice(),
+    )?;
+    Ok(MavpResult {
+        values: output,
+        state: mavp_state,
+    })
+}
+
+/// Calculation of the MAVP function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`MavpState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `periods`: A slice of [`Float`] with the period to use for each value of
+///   `data`. The periods are truncated and clamped between `min_period` and
+///   `max_period`.
+/// - `min_period`: The lowest period allowed.
+/// - `max_period`: The highest period allowed.
+/// - `ma_type`: The type of moving average to use.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the MAVP values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MavpState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn mavp_into<T: TechalibFloat>(
+    data: &[T],
+    periods: &[T],
+    min_period: usize,
+    max_period: usize,
+    ma_type: MavpMA<T>,
+    output: &mut [T],
+) -> Result<MavpState<T>, TechalibError> {
+    check_param_unchecked(min_period, max_period)?;
+
+    let len = data.len();
+    let lookback = lookback_from_ma_type(min_period, max_period, ma_type);
+
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    if periods.len() != len {
+        return Err(TechalibError::BadParam(
+            "Periods array must have the same length as input data".to_string(),
+        ));
+    }
+
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    for idx in 0..lookback {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        output[idx] = T::NAN;
+    }
+
+    let mut prev_period = 0;
+    let (mut period_sub, mut period_sum) = (T::ZERO, T::ZERO);
+    let mut emas = Vec::new();
+    if ma_type == MavpMA::EMA {
+        emas = (min_period..=max_period)
+            .map(|period| {
+                data[len_window(lookback, period)].iter().sum::<T>() / T::from_usize(period)
+            })
+            .collect();
+    }
+    let mut ma_states = Vec::new();
+    if has_ma_states(ma_type) {
+        let mut values = vec![T::ZERO; lookback + 1];
+        ma_states = (min_period..=max_period)
+            .map(|period| ma_into(&data[..=lookback], period, ma_type.into(), &mut values))
+            .collect::<Result<_, _>>()?;
+    }
+
+    for idx in lookback..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        if !periods[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "periods[{idx}] = {:?}",
+                periods[idx]
+            )));
+        }
+        let period = clamp_period(periods[idx], min_period, max_period);
+        let last_values = &data[len_window(idx, period)];
+        // Only used when the period did not change, so never on the first value
+        let old_value = if idx >= period {
+            data[idx - period]
+        } else {
+            T::NAN
+        };
+        output[idx] = match ma_type {
+            MavpMA::SMA => mavp_sma_next_unchecked(
+                data[idx],
+                old_value,
+                period,
+                prev_period,
+                output[idx - 1],
+                last_values,
+            ),
+            MavpMA::EMA => {
+                if idx > lookback {
+                    mavp_ema_next_unchecked(data[idx], &mut emas, min_period);
+                }
+                emas[period - min_period]
+            }
+            MavpMA::WMA => {
+                let wma;
+                (wma, period_sub, period_sum) = mavp_wma_next_unchecked(
+                    data[idx],
+                    old_value,
+                    period,
+                    prev_period,
+                    period_sub,
+                    period_sum,
+                    last_values,
+                );
+                wma
+            }
+            _ => {
+                if idx > lookback {
+                    for ma_state in ma_states.iter_mut() {
+                        ma_state.update(data[idx])?;
+                    }
+                }
+                ma_states[period - min_period].value()
+            }
+        };
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+        prev_period = period;
+    }
+
+    Ok(MavpState {
+        mavp: output[len - 1],
+        period: prev_period,
+        period_sub,
+        period_sum,
+        emas,
+        ma_states,
+        last_window: RingBuffer::from(&data[len - max_period..len]),
+        min_period,
+        max_period,
+        ma_type,
+    })
+}
+
+#[inline(always)]
+fn check_param_unchecked(min_period: usize, max_period: usize) -> Result<(), TechalibError> {
+    if min_period <= 1 {
+        return Err(TechalibError::BadParam(format!(
+            "Min period must be greater than 1, got: {}",
+            min_period
+        )));
+    }
+    if max_period < min_period {
+        return Err(TechalibError::BadParam(format!(
+            "Max period ({}) must be greater or equal than min period ({})",
+            max_period, min_period
+        )));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn has_ma_states<T>(ma_type: MavpMA<T>) -> bool {
+    !matches!(ma_type, MavpMA::SMA | MavpMA::EMA | MavpMA::WMA)
+}
+
+#[inline(always)]
+fn clamp_period<T: TechalibFloat>(period: T, min_period: usize, max_period: usize) -> usize {
+    (period.to_f64() as usize).clamp(min_period, max_period)
+}
+
+#[inline(always)]
+fn len_window(idx: usize, period: usize) -> core::ops::RangeInclusive<usize> {
+    idx + 1 - period..=idx
+}
+
+#[inline(always)]
+fn mavp_sma_next_unchecked<'a, I, T: TechalibFloat>(
+    new_value: T,
+    old_value: T,
+    period: usize,
+    prev_period: usize,
+    prev_sma: T,
+    last_values: I,
+) -> T
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    if period == prev_period {
+        sma_next_unchecked(
+            new_value,
+            old_value,
+            prev_sma,
+            T::ONE / T::from_usize(period),
+        )
+    } else {
+        last_values.into_iter().sum::<T>() / T::from_usize(period)
+    }
+}
+
+#[inline(always)]
+fn mavp_wma_next_unchecked<'a, I, T: TechalibFloat>(
+    new_value: T,
+    old_value: T,
+    period: usize,
+    prev_period: usize,
+    period_sub: T,
+    period_sum: T,
+    last_values: I,
+) -> (T, T, T)
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    let inv_weight_sum = inv_weight_sum_linear(period);
+    if period == prev_period {
+        wma_next_unchecked(
+            new_value,
+            old_value,
+            T::from_usize(period),
+            period_sub,
+            period_sum,
+            inv_weight_sum,
+        )
+    } else {
+        let (mut period_sub, mut period_sum) = (T::ZERO, T::ZERO);
+        for (idx, value) in last_values.into_iter().enumerate() {
+            period_sub += *value;
+            period_sum += *value * T::from_usize(idx);
+        }
+        (
+            (period_sum + period_sub) * inv_weight_sum,
+            period_sub,
+            period_sum,
+        )
+    }
+}
+
+#[inline(always)]
+fn mavp_ema_next_unchecked<T: TechalibFloat>(new_value: T, emas: &mut [T], min_period: usize) {
+    for (idx, ema) in emas.iter_mut().enumerate() {
+        let alpha = T::from_f64(2.0) / (T::from_usize(min_period + idx) + T::ONE);
+        *ema = ema_next_unchecked(new_value, *ema, alpha);
+    }
+}
@@ -0,0 +1,319 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB MAXINDEX implementation
+*/
+
+//! Index of highest value over a specified period (MAXINDEX) implementation
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::max::highest_unchecked;
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// MAXINDEX calculation result
+/// ---
+/// This struct holds the result and the state ([`MaxIndexState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the indexes (in the input data)
+///   of the highest values over the period.
+/// - `state`: A [`MaxIndexState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct MaxIndexResult<T = Float> {
+    /// The indexes (in the input data) of the highest values over the period.
+    pub values: Vec<T>,
+    /// A [`MaxIndexState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: MaxIndexState<T>,
+}
+
+/// MAXINDEX calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `maxindex`: The last calculated index of the highest value.
+///
+/// **State values**
+/// - `max`: The highest value, located at `maxindex`.
+/// - `index`: The index of the last sample.
+/// - `last_window`: A ring buffer containing the last `period` values.
+///
+/// **Parameters**
+/// - `period`: The number of values over which the highest value is searched.
+#[derive(Debug, Clone)]
+pub struct MaxIndexState<T = Float> {
+    // Outputs
+    /// The last calculated index of the highest value.
+    pub maxindex: usize,
+
+    // State values
+    /// The highest value, located at `maxindex`.
+    pub max: T,
+    /// The index of the last sample.
+    pub index: usize,
+    /// A ring buffer containing the last `period` values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The number of values over which the highest value is searched.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for MaxIndexState<T> {
+    /// Update the [`MaxIndexState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the MAXINDEX state
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        if self.period <= 1 {
+            return Err(TechalibError::BadParam(
+                "MAXINDEX period must be greater than 1".to_string(),
+            ));
+        }
+        if !sample.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
+        }
+        if !self.max.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.max = {:?}",
+                self.max
+            )));
+        }
+        if self.last_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "MAXINDEX state last_window length ({}) does not match period ({})",
+                self.last_window.len(),
+                self.period
+            )));
+        }
+        if self.index + 1 < self.period || self.maxindex > self.index {
+            return Err(TechalibError::BadParam(format!(
+                "MAXINDEX state maxindex ({}) is inconsistent with index ({})",
+                self.maxindex, self.index
+            )));
+        }
+
+        for (idx, &value) in self.last_window.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(TechalibError::DataNonFinite(format!(
+                    "window[{idx}] = {value:?}"
+                )));
+            }
+        }
+
+        self.last_window
+            .push(sample)
+            .ok_or(TechalibError::InsufficientData)?;
+
+        let index = self.index + 1;
+        (self.maxindex, self.max) = maxindex_next_unchecked(
+            sample,
+            index,
+            index + 1 - self.period,
+            self.maxindex,
+            self.max,
+            self.last_window.iter(),
+        );
+        self.index = index;
+
+        Ok(())
+    }
+}
+
+/// Lookback period for MAXINDEX calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the MAXINDEX values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the MAXINDEX function
+/// ---
+/// It returns a [`MaxIndexResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The number of values over which the highest value is searched.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MaxIndexResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn maxindex<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+) -> Result<MaxIndexResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let maxindex_state = maxindex_into(data, period, output.as_mut_slice())?;
+    Ok(MaxIndexResult {
+        values: output,
+        state: maxindex_state,
+    })
+}
+
+/// Calculation of the MAXINDEX function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`MaxIndexState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The number of values over which the highest value is searched.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the indexes of the highest
+///   values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MaxIndexState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn maxindex_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<MaxIndexState<T>, TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(format!(
+            "Period must be greater than 1, got: {}",
+            period
+        )));
+    }
+
+    let len = data.len();
+    let lookback = lookback_from_period(period);
+
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    for idx in 0..period {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        output[idx] = T::NAN;
+    }
+    let (mut max_idx, mut max) = highest_unchecked(&data[..period]);
+    output[lookback] = T::from_usize(max_idx);
+
+    for idx in period..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        let trailing_idx = idx + 1 - period;
+        (max_idx, max) = maxindex_next_unchecked(
+            data[idx],
+            idx,
+            trailing_idx,
+            max_idx,
+            max,
+            &data[trailing_idx..=idx],
+        );
+        output[idx] = T::from_usize(max_idx);
+    }
+
+    Ok(MaxIndexState {
+        maxindex: max_idx,
+        max,
+        index: len - 1,
+        last_window: RingBuffer::from(&data[len - period..len]),
+        period,
+    })
+}
+
+batch_functions!(
+    maxindex_batch,
+    maxindex_batch_into,
+    maxindex,
+    maxindex_into,
+    MaxIndexResult,
+    MaxIndexState,
+    "MAXINDEX";
+    period: usize
+);
+
+#[inline(always)]
+fn maxindex_next_unchecked<'a, I, T: TechalibFloat>(
+    new_value: T,
+    new_idx: usize,
+    trailing_idx: usize,
+    prev_max_idx: usize,
+    prev_max: T,
+    window: I,
+) -> (usize, T)
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    if prev_max_idx < trailing_idx {
+        let (idx, max) = highest_unchecked(window);
+        (trailing_idx + idx, max)
+    } else if new_value >= prev_max {
+        (new_idx, new_value)
+    } else {
+        (prev_max_idx, prev_max)
+    }
+}
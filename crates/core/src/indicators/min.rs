@@ -0,0 +1,328 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB MIN implementation
+*/
+
+//! Lowest value over a specified period (MIN) implementation
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// MIN calculation result
+/// ---
+/// This struct holds the result and the state ([`MinState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the lowest values over the period.
+/// - `state`: A [`MinState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct MinResult<T = Float> {
+    /// The lowest values over the period.
+    pub values: Vec<T>,
+    /// A [`MinState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: MinState<T>,
+}
+
+/// MIN calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `min`: The last calculated lowest value.
+///
+/// **State values**
+/// - `last_window`: A ring buffer containing the last `period` values.
+///
+/// **Parameters**
+/// - `period`: The number of values over which the lowest value is searched.
+#[derive(Debug, Clone)]
+pub struct MinState<T = Float> {
+    // Outputs
+    /// The last calculated lowest value.
+    pub min: T,
+
+    // State values
+    /// A ring buffer containing the last `period` values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The number of values over which the lowest value is searched.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for MinState<T> {
+    /// Update the [`MinState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the MIN state
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        let min = self.next(sample)?;
+        self.commit_unchecked(sample, min);
+        Ok(())
+    }
+}
+
+impl<T: TechalibFloat> MinState<T> {
+    /// Checks the state and `sample`, and returns the next MIN value without
+    /// modifying the state, so that composite states can check every step
+    /// before updating any of their sub-states.
+    pub(crate) fn next(&self, sample: T) -> Result<T, TechalibError> {
+        if self.period <= 1 {
+            return Err(TechalibError::BadParam(
+                "MIN period must be greater than 1".to_string(),
+            ));
+        }
+        if !sample.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
+        }
+        if !self.min.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.min = {:?}",
+                self.min
+            )));
+        }
+        if self.last_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "MIN state last_window length ({}) does not match period ({})",
+                self.last_window.len(),
+                self.period
+            )));
+        }
+
+        for (idx, &value) in self.last_window.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(TechalibError::DataNonFinite(format!(
+                    "window[{idx}] = {value:?}"
+                )));
+            }
+        }
+
+        let old_value = self
+            .last_window
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
+
+        Ok(min_next_unchecked(
+            sample,
+            old_value,
+            self.min,
+            self.last_window
+                .iter()
+                .skip(1)
+                .chain(core::iter::once(&sample)),
+        ))
+    }
+
+    /// Pushes `sample` into the window and stores the `min` returned by
+    /// [`MinState::next`].
+    pub(crate) fn commit_unchecked(&mut self, sample: T, min: T) {
+        self.last_window.push(sample);
+        self.min = min;
+    }
+}
+
+/// Lookback period for MIN calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the MIN values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the MIN function
+/// ---
+/// It returns a [`MinResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The number of values over which the lowest value is searched.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MinResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn min<T: TechalibFloat>(data: &[T], period: usize) -> Result<MinResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let min_state = min_into(data, period, output.as_mut_slice())?;
+    Ok(MinResult {
+        values: output,
+        state: min_state,
+    })
+}
+
+/// Calculation of the MIN function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`MinState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The number of values over which the lowest value is searched.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the lowest values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MinState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn min_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<MinState<T>, TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(format!(
+            "Period must be greater than 1, got: {}",
+            period
+        )));
+    }
+
+    let len = data.len();
+    let lookback = lookback_from_period(period);
+
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    for idx in 0..period {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        output[idx] = T::NAN;
+    }
+    (_, output[lookback]) = lowest_unchecked(&data[..period]);
+
+    for idx in period..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        output[idx] = min_next_unchecked(
+            data[idx],
+            data[idx - period],
+            output[idx - 1],
+            &data[idx + 1 - period..=idx],
+        );
+    }
+
+    Ok(MinState {
+        min: output[len - 1],
+        last_window: RingBuffer::from(&data[len - period..len]),
+        period,
+    })
+}
+
+batch_functions!(
+    min_batch,
+    min_batch_into,
+    min,
+    min_into,
+    MinResult,
+    MinState,
+    "MIN";
+    period: usize
+);
+
+/// Returns the position and the value of the first lowest value of `window`.
+#[inline(always)]
+pub(crate) fn lowest_unchecked<'a, I, T: TechalibFloat>(window: I) -> (usize, T)
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    let mut iter = window.into_iter();
+    let mut lowest = *iter.next().unwrap_or(&T::NAN);
+    let mut lowest_idx = 0;
+    for (idx, &value) in iter.enumerate() {
+        if value < lowest {
+            lowest = value;
+            lowest_idx = idx + 1;
+        }
+    }
+    (lowest_idx, lowest)
+}
+
+/// Rolls the lowest value of a window for which `old_value` has just been
+/// removed and `new_value` has just been added. The `window` is only scanned
+/// again when the previous lowest value leaves it.
+#[inline(always)]
+pub(crate) fn min_next_unchecked<'a, I, T: TechalibFloat>(
+    new_value: T,
+    old_value: T,
+    prev_min: T,
+    window: I,
+) -> T
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    if new_value <= prev_min {
+        new_value
+    } else if old_value <= prev_min {
+        lowest_unchecked(window).1
+    } else {
+        prev_min
+    }
+}
@@ -0,0 +1,294 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB MINMAX implementation
+*/
+
+//! Lowest and highest values over a specified period (MINMAX) implementation
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::max::{highest_unchecked, max_next_unchecked};
+use crate::indicators::min::{lowest_unchecked, min_next_unchecked};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// MINMAX calculation result
+/// ---
+/// This struct holds the result and the state ([`MinMaxState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `min`: A vector of [`Float`] representing the lowest values over the period.
+/// - `max`: A vector of [`Float`] representing the highest values over the period.
+/// - `state`: A [`MinMaxState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct MinMaxResult<T = Float> {
+    /// The lowest values over the period.
+    pub min: Vec<T>,
+    /// The highest values over the period.
+    pub max: Vec<T>,
+    /// A [`MinMaxState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: MinMaxState<T>,
+}
+
+/// MINMAX calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `min`: The last calculated lowest value.
+/// - `max`: The last calculated highest value.
+///
+/// **State values**
+/// - `last_window`: A ring buffer containing the last `period` values.
+///
+/// **Parameters**
+/// - `period`: The number of values over which the extremums are searched.
+#[derive(Debug, Clone)]
+pub struct MinMaxState<T = Float> {
+    // Outputs
+    /// The last calculated lowest value.
+    pub min: T,
+    /// The last calculated highest value.
+    pub max: T,
+
+    // State values
+    /// A ring buffer containing the last `period` values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The number of values over which the extremums are searched.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for MinMaxState<T> {
+    /// Update the [`MinMaxState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the MINMAX state
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        if self.period <= 1 {
+            return Err(TechalibError::BadParam(
+                "MINMAX period must be greater than 1".to_string(),
+            ));
+        }
+        if !sample.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
+        }
+        if !self.min.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.min = {:?}",
+                self.min
+            )));
+        }
+        if !self.max.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.max = {:?}",
+                self.max
+            )));
+        }
+        if self.last_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "MINMAX state last_window length ({}) does not match period ({})",
+                self.last_window.len(),
+                self.period
+            )));
+        }
+
+        for (idx, &value) in self.last_window.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(TechalibError::DataNonFinite(format!(
+                    "window[{idx}] = {value:?}"
+                )));
+            }
+        }
+
+        let old_value = self
+            .last_window
+            .push(sample)
+            .ok_or(TechalibError::InsufficientData)?;
+
+        self.min = min_next_unchecked(sample, old_value, self.min, self.last_window.iter());
+        self.max = max_next_unchecked(sample, old_value, self.max, self.last_window.iter());
+
+        Ok(())
+    }
+}
+
+/// Lookback period for MINMAX calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the MINMAX values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the MINMAX function
+/// ---
+/// It returns a [`MinMaxResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The number of values over which the extremums are searched.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MinMaxResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn minmax<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+) -> Result<MinMaxResult<T>, TechalibError> {
+    let mut output_min = vec![T::ZERO; data.len()];
+    let mut output_max = vec![T::ZERO; data.len()];
+    let minmax_state = minmax_into(
+        data,
+        period,
+        output_min.as_mut_slice(),
+        output_max.as_mut_slice(),
+    )?;
+    Ok(MinMaxResult {
+        min: output_min,
+        max: output_max,
+        state: minmax_state,
+    })
+}
+
+/// Calculation of the MINMAX function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`MinMaxState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The number of values over which the extremums are searched.
+///
+/// Output Arguments
+/// ---
+/// - `output_min`: A mutable slice of [`Float`] where the lowest values will be stored.
+/// - `output_max`: A mutable slice of [`Float`] where the highest values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MinMaxState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn minmax_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output_min: &mut [T],
+    output_max: &mut [T],
+) -> Result<MinMaxState<T>, TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(format!(
+            "Period must be greater than 1, got: {}",
+            period
+        )));
+    }
+
+    let len = data.len();
+    let lookback = lookback_from_period(period);
+
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    if output_min.len() < len || output_max.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    for idx in 0..period {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        output_min[idx] = T::NAN;
+        output_max[idx] = T::NAN;
+    }
+    (_, output_min[lookback]) = lowest_unchecked(&data[..period]);
+    (_, output_max[lookback]) = highest_unchecked(&data[..period]);
+
+    for idx in period..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        let window = &data[idx + 1 - period..=idx];
+        output_min[idx] =
+            min_next_unchecked(data[idx], data[idx - period], output_min[idx - 1], window);
+        output_max[idx] =
+            max_next_unchecked(data[idx], data[idx - period], output_max[idx - 1], window);
+    }
+
+    Ok(MinMaxState {
+        min: output_min[len - 1],
+        max: output_max[len - 1],
+        last_window: RingBuffer::from(&data[len - period..len]),
+        period,
+    })
+}
+
+batch_functions!(
+    minmax_batch,
+    minmax_batch_into,
+    minmax,
+    minmax_into,
+    MinMaxResult,
+    MinMaxState,
+    "MINMAX",
+    data -> [output_min, output_max];
+    period: usize
+);
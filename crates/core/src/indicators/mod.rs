@@ -1,11 +1,45 @@
+pub mod alma;
+pub mod atr;
 pub mod bbands;
+pub mod chandelier_exit;
+pub mod cmf;
 pub mod dema;
+pub mod donchian;
+pub mod elder_ray;
 pub mod ema;
+pub mod envelope;
+pub mod eom;
+pub mod force_index;
+pub mod heikin_ashi;
+pub mod hma;
+pub mod ichimoku;
 pub mod kama;
+pub mod keltner;
+pub mod ma;
 pub mod macd;
+pub mod math_operator;
+pub mod math_transform;
+pub mod mavp;
+pub mod max;
+pub mod maxindex;
+pub mod min;
+pub mod minindex;
+pub mod minmax;
+pub mod pivots;
+pub mod price_transform;
 pub mod rsi;
 pub mod sma;
+pub mod smma;
+pub mod stats;
+pub mod sum;
+pub mod supertrend;
+pub mod swing;
 pub mod t3;
 pub mod tema;
 pub mod trima;
+pub mod vidya;
+pub mod volatility;
+pub mod vwap;
 pub mod wma;
+pub mod zigzag;
+pub mod zlema;
@@ -0,0 +1,500 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Pivot Points (PIVOTS) implementation
+//!
+//! The levels of a session are calculated from the open, high, low and close
+//! of the previous session, and are repeated on every bar of the session, so
+//! they are aligned with the intraday index. The bars of the first session
+//! have no previous session: their levels are `NaN`.
+//!
+//! With `H`, `L`, `C` and `O` the previous session high, low, close and open:
+//! - [`PivotMethod::Classic`]: `P = (H + L + C) / 3`, `R1 = 2P - L`,
+//!   `R2 = P + (H - L)`, `R3 = H + 2(P - L)` and symmetric supports.
+//! - [`PivotMethod::Fibonacci`]: `P = (H + L + C) / 3`, the levels are
+//!   `P ± 0.382, 0.618 and 1.0 × (H - L)`.
+//! - [`PivotMethod::Camarilla`]: `P = (H + L + C) / 3`, the levels are
+//!   `C ± 1.1 × (H - L) / 12, / 6 and / 4`.
+//! - [`PivotMethod::Woodie`]: `P = (H + L + 2 × open) / 4` with the open of
+//!   the current session, then the levels of the classic method.
+//! - [`PivotMethod::Demark`]: `P = X / 4`, `R1 = X / 2 - L`, `S1 = X / 2 - H`,
+//!   with `X` depending on the close of the session relatively to its open.
+//!   DeMark has no second and third levels, they are `NaN`.
+
+use crate::errors::TechalibError;
+use crate::indicators::price_transform::typprice_unchecked;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Pivot Points calculation method
+/// ---
+/// See the [module documentation](self) for the formulas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PivotMethod {
+    /// Classic (floor) pivot points.
+    Classic,
+    /// Fibonacci pivot points.
+    Fibonacci,
+    /// Camarilla pivot points.
+    Camarilla,
+    /// Woodie pivot points.
+    Woodie,
+    /// DeMark pivot points.
+    Demark,
+}
+
+/// Pivot Points calculation result
+/// ---
+/// This struct holds the result of the Pivot Points calculation.
+///
+/// Attributes
+/// ---
+/// - `pivot`: The pivot point values.
+/// - `r1`, `r2`, `r3`: The resistance levels values.
+/// - `s1`, `s2`, `s3`: The support levels values.
+/// - `state`: A [`PivotsState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct PivotsResult<T = Float> {
+    /// The pivot point values.
+    pub pivot: Vec<T>,
+    /// The first resistance level values.
+    pub r1: Vec<T>,
+    /// The second resistance level values.
+    pub r2: Vec<T>,
+    /// The third resistance level values.
+    pub r3: Vec<T>,
+    /// The first support level values.
+    pub s1: Vec<T>,
+    /// The second support level values.
+    pub s2: Vec<T>,
+    /// The third support level values.
+    pub s3: Vec<T>,
+    /// A [`PivotsState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: PivotsState<T>,
+}
+
+/// Pivot Points calculation state
+/// ---
+/// This struct holds the state of the Pivot Points calculation.
+/// It is used to calculate the next values in an incremental way.
+/// Call [`PivotsState::rollover`] before the first bar of a new session.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `pivot`: The pivot point of the current session.
+/// - `r1`, `r2`, `r3`: The resistance levels of the current session.
+/// - `s1`, `s2`, `s3`: The support levels of the current session.
+///
+/// **State values**
+/// - `prev_open`, `prev_high`, `prev_low`, `prev_close`: The open, high, low
+///   and close of the previous session.
+/// - `session_open`, `session_high`, `session_low`, `session_close`: The open,
+///   high, low and close of the current session, `NaN` until its first bar.
+///
+/// **Parameters**
+/// - `method`: The [`PivotMethod`] of the levels.
+#[derive(Debug, Clone, Copy)]
+pub struct PivotsState<T = Float> {
+    // Outputs
+    /// The pivot point of the current session.
+    pub pivot: T,
+    /// The first resistance level of the current session.
+    pub r1: T,
+    /// The second resistance level of the current session.
+    pub r2: T,
+    /// The third resistance level of the current session.
+    pub r3: T,
+    /// The first support level of the current session.
+    pub s1: T,
+    /// The second support level of the current session.
+    pub s2: T,
+    /// The third support level of the current session.
+    pub s3: T,
+
+    // State values
+    /// The open of the previous session.
+    pub prev_open: T,
+    /// The high of the previous session.
+    pub prev_high: T,
+    /// The low of the previous session.
+    pub prev_low: T,
+    /// The close of the previous session.
+    pub prev_close: T,
+    /// The open of the current session, `NaN` until its first bar.
+    pub session_open: T,
+    /// The high of the current session, `NaN` until its first bar.
+    pub session_high: T,
+    /// The low of the current session, `NaN` until its first bar.
+    pub session_low: T,
+    /// The close of the current session, `NaN` until its first bar.
+    pub session_close: T,
+
+    // Parameters
+    /// The [`PivotMethod`] of the levels.
+    pub method: PivotMethod,
+}
+
+impl<T: TechalibFloat> PivotsState<T> {
+    fn new(method: PivotMethod) -> Self {
+        PivotsState {
+            pivot: T::NAN,
+            r1: T::NAN,
+            r2: T::NAN,
+            r3: T::NAN,
+            s1: T::NAN,
+            s2: T::NAN,
+            s3: T::NAN,
+            prev_open: T::NAN,
+            prev_high: T::NAN,
+            prev_low: T::NAN,
+            prev_close: T::NAN,
+            session_open: T::NAN,
+            session_high: T::NAN,
+            session_low: T::NAN,
+            session_close: T::NAN,
+            method,
+        }
+    }
+
+    /// Start a new session
+    ///
+    /// The current session becomes the previous session, and the levels of
+    /// the new session are calculated with its first bar.
+    /// Calling it again before any update starts an empty session: the
+    /// previous session is kept.
+    pub fn rollover(&mut self) {
+        if self.session_open.is_nan() {
+            return;
+        }
+        self.prev_open = self.session_open;
+        self.prev_high = self.session_high;
+        self.prev_low = self.session_low;
+        self.prev_close = self.session_close;
+        self.session_open = T::NAN;
+        self.session_high = T::NAN;
+        self.session_low = T::NAN;
+        self.session_close = T::NAN;
+    }
+
+    #[inline(always)]
+    fn update_unchecked(&mut self, open: T, high: T, low: T, close: T) {
+        if self.session_open.is_nan() {
+            [
+                self.pivot, self.r1, self.r2, self.r3, self.s1, self.s2, self.s3,
+            ] = pivot_levels_unchecked(
+                self.method,
+                self.prev_open,
+                self.prev_high,
+                self.prev_low,
+                self.prev_close,
+                open,
+            );
+            self.session_open = open;
+            self.session_high = high;
+            self.session_low = low;
+        } else {
+            self.session_high = self.session_high.max(high);
+            self.session_low = self.session_low.min(low);
+        }
+        self.session_close = close;
+    }
+}
+
+impl<T: TechalibFloat> State<(T, T, T, T)> for PivotsState<T> {
+    /// Update the [`PivotsState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(open, high, low, close)` of the new bar.
+    fn update(&mut self, sample: (T, T, T, T)) -> Result<(), TechalibError> {
+        let (open, high, low, close) = sample;
+        for (name, value) in [
+            ("open", open),
+            ("high", high),
+            ("low", low),
+            ("close", close),
+        ] {
+            if !value.is_finite() {
+                return Err(TechalibError::DataNonFinite(format!("{name} = {value:?}")));
+            }
+        }
+        self.update_unchecked(open, high, low, close);
+        Ok(())
+    }
+}
+
+/// Calculation of the Pivot Points function
+/// ---
+/// It returns a [`PivotsResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `open`, `high`, `low` and `close`
+///   columns are used.
+/// - `new_session`: A mask with the same length as the input, `true` on the
+///   first bar of each session. The first bar always starts a session.
+/// - `method`: The [`PivotMethod`] of the levels.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`PivotsResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn pivots<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    new_session: &[bool],
+    method: PivotMethod,
+) -> Result<PivotsResult<T>, TechalibError> {
+    let len = ohlcv.close.len();
+    let mut output_pivot = vec![T::ZERO; len];
+    let mut output_r1 = vec![T::ZERO; len];
+    let mut output_r2 = vec![T::ZERO; len];
+    let mut output_r3 = vec![T::ZERO; len];
+    let mut output_s1 = vec![T::ZERO; len];
+    let mut output_s2 = vec![T::ZERO; len];
+    let mut output_s3 = vec![T::ZERO; len];
+
+    let pivots_state = pivots_into(
+        ohlcv,
+        new_session,
+        method,
+        output_pivot.as_mut_slice(),
+        output_r1.as_mut_slice(),
+        output_r2.as_mut_slice(),
+        output_r3.as_mut_slice(),
+        output_s1.as_mut_slice(),
+        output_s2.as_mut_slice(),
+        output_s3.as_mut_slice(),
+    )?;
+
+    Ok(PivotsResult {
+        pivot: output_pivot,
+        r1: output_r1,
+        r2: output_r2,
+        r3: output_r3,
+        s1: output_s1,
+        s2: output_s2,
+        s3: output_s3,
+        state: pivots_state,
+    })
+}
+
+/// Calculation of the Pivot Points function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`PivotsState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `open`, `high`, `low` and `close`
+///   columns are used.
+/// - `new_session`: A mask with the same length as the input, `true` on the
+///   first bar of each session. The first bar always starts a session.
+/// - `method`: The [`PivotMethod`] of the levels.
+///
+/// Output Arguments
+/// ---
+/// - `output_pivot`: A mutable slice to store the pivot point values.
+/// - `output_r1`, `output_r2`, `output_r3`: Mutable slices to store the resistance levels values.
+/// - `output_s1`, `output_s2`, `output_s3`: Mutable slices to store the support levels values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`PivotsState`],
+/// or a [`TechalibError`] error if the calculation fails.
+#[allow(clippy::too_many_arguments)]
+pub fn pivots_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    new_session: &[bool],
+    method: PivotMethod,
+    output_pivot: &mut [T],
+    output_r1: &mut [T],
+    output_r2: &mut [T],
+    output_r3: &mut [T],
+    output_s1: &mut [T],
+    output_s2: &mut [T],
+    output_s3: &mut [T],
+) -> Result<PivotsState<T>, TechalibError> {
+    let (open, high, low, close) = (ohlcv.open, ohlcv.high, ohlcv.low, ohlcv.close);
+    let len = columns_len(&[
+        ("open", open),
+        ("high", high),
+        ("low", low),
+        ("close", close),
+    ])?;
+    if len == 0 {
+        return Err(TechalibError::InsufficientData);
+    }
+    if new_session.len() != len {
+        return Err(TechalibError::BadParam(format!(
+            "Session mask must have the same length as input data, got: {} and {}",
+            new_session.len(),
+            len
+        )));
+    }
+    if [
+        &*output_pivot,
+        &*output_r1,
+        &*output_r2,
+        &*output_r3,
+        &*output_s1,
+        &*output_s2,
+        &*output_s3,
+    ]
+    .iter()
+    .any(|output| output.len() < len)
+    {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let mut state = PivotsState::new(method);
+    for idx in 0..len {
+        for (name, column) in [
+            ("open", open),
+            ("high", high),
+            ("low", low),
+            ("close", close),
+        ] {
+            if !column[idx].is_finite() {
+                return Err(TechalibError::DataNonFinite(format!(
+                    "{name}[{idx}] = {:?}",
+                    column[idx]
+                )));
+            }
+        }
+        if new_session[idx] {
+            state.rollover();
+        }
+        state.update_unchecked(open[idx], high[idx], low[idx], close[idx]);
+
+        output_pivot[idx] = state.pivot;
+        output_r1[idx] = state.r1;
+        output_r2[idx] = state.r2;
+        output_r3[idx] = state.r3;
+        output_s1[idx] = state.s1;
+        output_s2[idx] = state.s2;
+        output_s3[idx] = state.s3;
+    }
+
+    Ok(state)
+}
+
+/// Returns the levels `[P, R1, R2, R3, S1, S2, S3]` of a session.
+#[inline(always)]
+fn pivot_levels_unchecked<T: TechalibFloat>(
+    method: PivotMethod,
+    prev_open: T,
+    prev_high: T,
+    prev_low: T,
+    prev_close: T,
+    session_open: T,
+) -> [T; 7] {
+    let range = prev_high - prev_low;
+    match method {
+        PivotMethod::Classic => classic_levels_unchecked(
+            typprice_unchecked(prev_high, prev_low, prev_close),
+            prev_high,
+            prev_low,
+        ),
+        PivotMethod::Woodie => classic_levels_unchecked(
+            (prev_high + prev_low + T::from_f64(2.0) * session_open) * T::from_f64(0.25),
+            prev_high,
+            prev_low,
+        ),
+        PivotMethod::Fibonacci => {
+            let pivot = typprice_unchecked(prev_high, prev_low, prev_close);
+            [
+                pivot,
+                pivot + T::from_f64(0.382) * range,
+                pivot + T::from_f64(0.618) * range,
+                pivot + range,
+                pivot - T::from_f64(0.382) * range,
+                pivot - T::from_f64(0.618) * range,
+                pivot - range,
+            ]
+        }
+        PivotMethod::Camarilla => {
+            let pivot = typprice_unchecked(prev_high, prev_low, prev_close);
+            let width = T::from_f64(1.1) * range;
+            [
+                pivot,
+                prev_close + width / T::from_f64(12.0),
+                prev_close + width / T::from_f64(6.0),
+                prev_close + width * T::from_f64(0.25),
+                prev_close - width / T::from_f64(12.0),
+                prev_close - width / T::from_f64(6.0),
+                prev_close - width * T::from_f64(0.25),
+            ]
+        }
+        PivotMethod::Demark => {
+            let x = if prev_close < prev_open {
+                prev_high + T::from_f64(2.0) * prev_low + prev_close
+            } else if prev_close > prev_open {
+                T::from_f64(2.0) * prev_high + prev_low + prev_close
+            } else {
+                prev_high + prev_low + T::from_f64(2.0) * prev_close
+            };
+            [
+                x * T::from_f64(0.25),
+                x * T::from_f64(0.5) - prev_low,
+                T::NAN,
+                T::NAN,
+                x * T::from_f64(0.5) - prev_high,
+                T::NAN,
+                T::NAN,
+            ]
+        }
+    }
+}
+
+#[inline(always)]
+fn classic_levels_unchecked<T: TechalibFloat>(pivot: T, prev_high: T, prev_low: T) -> [T; 7] {
+    let range = prev_high - prev_low;
+    [
+        pivot,
+        T::from_f64(2.0) * pivot - prev_low,
+        pivot + range,
+        prev_high + T::from_f64(2.0) * (pivot - prev_low),
+        T::from_f64(2.0) * pivot - prev_high,
+        pivot - range,
+        prev_low - T::from_f64(2.0) * (prev_high - pivot),
+    ]
+}
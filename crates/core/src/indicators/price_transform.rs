@@ -0,0 +1,295 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB Price Transform functions
+*/
+
+//! Price Transform functions (AVGPRICE, MEDPRICE, TYPPRICE, WCLPRICE) implementation
+//!
+//! Every function is applied bar by bar on the columns of an [`Ohlcv`] view
+//! and is NaN-aware: a `NaN` in one of the used columns produces a `NaN`
+//! at the same index in the output instead of an error.
+
+use crate::errors::TechalibError;
+use crate::types::{columns_len, Ohlcv, TechalibFloat};
+use alloc::{string::ToString, vec, vec::Vec};
+
+/// Calculation of the AVGPRICE (Average Price) function
+/// ---
+/// It returns a vector of [`Float`] with `(open + high + low + close) / 4`.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `open`, `high`, `low` and `close`
+///   columns are used.
+///
+/// Returns
+/// ---
+/// A `Result` containing the calculated values,
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn avgprice<T: TechalibFloat>(ohlcv: &Ohlcv<T>) -> Result<Vec<T>, TechalibError> {
+    let mut output = vec![T::ZERO; ohlcv.close.len()];
+    avgprice_into(ohlcv, output.as_mut_slice())?;
+    Ok(output)
+}
+
+/// Calculation of the AVGPRICE (Average Price) function
+/// ---
+/// It stores `(open + high + low + close) / 4` in the provided output array.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `open`, `high`, `low` and `close`
+///   columns are used.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated values
+///   will be stored.
+///
+/// Returns
+/// ---
+/// An empty `Result`, or a [`TechalibError`] error if the calculation fails.
+pub fn avgprice_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    output: &mut [T],
+) -> Result<(), TechalibError> {
+    let len = check_columns_unchecked(
+        &[
+            ("open", ohlcv.open),
+            ("high", ohlcv.high),
+            ("low", ohlcv.low),
+            ("close", ohlcv.close),
+        ],
+        output,
+    )?;
+    for (idx, out) in output[..len].iter_mut().enumerate() {
+        *out = avgprice_unchecked(
+            ohlcv.open[idx],
+            ohlcv.high[idx],
+            ohlcv.low[idx],
+            ohlcv.close[idx],
+        );
+    }
+    Ok(())
+}
+
+/// Calculation of the MEDPRICE (Median Price) function
+/// ---
+/// It returns a vector of [`Float`] with `(high + low) / 2`.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high` and `low` columns are used.
+///
+/// Returns
+/// ---
+/// A `Result` containing the calculated values,
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn medprice<T: TechalibFloat>(ohlcv: &Ohlcv<T>) -> Result<Vec<T>, TechalibError> {
+    let mut output = vec![T::ZERO; ohlcv.high.len()];
+    medprice_into(ohlcv, output.as_mut_slice())?;
+    Ok(output)
+}
+
+/// Calculation of the MEDPRICE (Median Price) function
+/// ---
+/// It stores `(high + low) / 2` in the provided output array.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high` and `low` columns are used.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated values
+///   will be stored.
+///
+/// Returns
+/// ---
+/// An empty `Result`, or a [`TechalibError`] error if the calculation fails.
+pub fn medprice_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    output: &mut [T],
+) -> Result<(), TechalibError> {
+    let len = check_columns_unchecked(&[("high", ohlcv.high), ("low", ohlcv.low)], output)?;
+    for (idx, out) in output[..len].iter_mut().enumerate() {
+        *out = medprice_unchecked(ohlcv.high[idx], ohlcv.low[idx]);
+    }
+    Ok(())
+}
+
+/// Calculation of the TYPPRICE (Typical Price) function
+/// ---
+/// It returns a vector of [`Float`] with `(high + low + close) / 3`.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+///
+/// Returns
+/// ---
+/// A `Result` containing the calculated values,
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn typprice<T: TechalibFloat>(ohlcv: &Ohlcv<T>) -> Result<Vec<T>, TechalibError> {
+    let mut output = vec![T::ZERO; ohlcv.close.len()];
+    typprice_into(ohlcv, output.as_mut_slice())?;
+    Ok(output)
+}
+
+/// Calculation of the TYPPRICE (Typical Price) function
+/// ---
+/// It stores `(high + low + close) / 3` in the provided output array.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated values
+///   will be stored.
+///
+/// Returns
+/// ---
+/// An empty `Result`, or a [`TechalibError`] error if the calculation fails.
+pub fn typprice_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    output: &mut [T],
+) -> Result<(), TechalibError> {
+    let len = check_columns_unchecked(
+        &[
+            ("high", ohlcv.high),
+            ("low", ohlcv.low),
+            ("close", ohlcv.close),
+        ],
+        output,
+    )?;
+    for (idx, out) in output[..len].iter_mut().enumerate() {
+        *out = typprice_unchecked(ohlcv.high[idx], ohlcv.low[idx], ohlcv.close[idx]);
+    }
+    Ok(())
+}
+
+/// Calculation of the WCLPRICE (Weighted Close Price) function
+/// ---
+/// It returns a vector of [`Float`] with `(high + low + 2 * close) / 4`.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+///
+/// Returns
+/// ---
+/// A `Result` containing the calculated values,
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn wclprice<T: TechalibFloat>(ohlcv: &Ohlcv<T>) -> Result<Vec<T>, TechalibError> {
+    let mut output = vec![T::ZERO; ohlcv.close.len()];
+    wclprice_into(ohlcv, output.as_mut_slice())?;
+    Ok(output)
+}
+
+/// Calculation of the WCLPRICE (Weighted Close Price) function
+/// ---
+/// It stores `(high + low + 2 * close) / 4` in the provided output array.
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated values
+///   will be stored.
+///
+/// Returns
+/// ---
+/// An empty `Result`, or a [`TechalibError`] error if the calculation fails.
+pub fn wclprice_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    output: &mut [T],
+) -> Result<(), TechalibError> {
+    let len = check_columns_unchecked(
+        &[
+            ("high", ohlcv.high),
+            ("low", ohlcv.low),
+            ("close", ohlcv.close),
+        ],
+        output,
+    )?;
+    for (idx, out) in output[..len].iter_mut().enumerate() {
+        *out = wclprice_unchecked(ohlcv.high[idx], ohlcv.low[idx], ohlcv.close[idx]);
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) fn avgprice_unchecked<T: TechalibFloat>(open: T, high: T, low: T, close: T) -> T {
+    (open + high + low + close) * T::from_f64(0.25)
+}
+
+#[inline(always)]
+pub(crate) fn medprice_unchecked<T: TechalibFloat>(high: T, low: T) -> T {
+    (high + low) * T::from_f64(0.5)
+}
+
+#[inline(always)]
+pub(crate) fn typprice_unchecked<T: TechalibFloat>(high: T, low: T, close: T) -> T {
+    (high + low + close) / T::from_f64(3.0)
+}
+
+#[inline(always)]
+pub(crate) fn wclprice_unchecked<T: TechalibFloat>(high: T, low: T, close: T) -> T {
+    (high + low + close * T::from_f64(2.0)) * T::from_f64(0.25)
+}
+
+#[inline(always)]
+fn check_columns_unchecked<T: TechalibFloat>(
+    columns: &[(&str, &[T])],
+    output: &[T],
+) -> Result<usize, TechalibError> {
+    let len = columns_len(columns)?;
+    if len == 0 {
+        return Err(TechalibError::InsufficientData);
+    }
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    Ok(len)
+}
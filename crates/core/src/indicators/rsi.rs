@@ -40,9 +40,12 @@
 
 //! Relative Strength Index (RSI) implementation
 
+use crate::batch::{batch_functions, check_sweep_output, prefix_sums, sweep};
 use crate::errors::TechalibError;
+use crate::indicators::smma::smma_next_unchecked;
 use crate::traits::State;
-use crate::types::Float;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 /// RSI calculation result
 /// ---
@@ -55,12 +58,32 @@ use crate::types::Float;
 /// - `state`: A [`RsiState`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct RsiResult {
+pub struct RsiResult<T = Float> {
     /// The calculated RSI values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// A [`RsiState`], which can be used to calculate
     /// the next values incrementally.
-    pub state: RsiState,
+    pub state: RsiState<T>,
+}
+
+/// RSI sweep result
+/// ---
+/// This struct holds the result of the RSI calculation for several periods
+/// over the same series.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] holding a column-major 2-D array with
+///   one column of `data.len()` values per period, in the order of the periods.
+/// - `states`: A vector of [`RsiState`], one per period, which can be used
+///   to calculate the next values incrementally.
+#[derive(Debug)]
+pub struct RsiSweepResult<T = Float> {
+    /// The calculated RSI values, one column per period.
+    pub values: Vec<T>,
+    /// A [`RsiState`] per period, which can be used to calculate
+    /// the next values incrementally.
+    pub states: Vec<RsiState<T>>,
 }
 
 /// RSI calculation state
@@ -81,31 +104,31 @@ pub struct RsiResult {
 /// **Parameters**
 /// - `period`: The period used for the RSI calculation.
 #[derive(Debug, Clone, Copy)]
-pub struct RsiState {
+pub struct RsiState<T = Float> {
     // Outputs
     /// The last calculated RSI value.
-    pub rsi: Float,
+    pub rsi: T,
 
     // State values
     /// The previous input value used for the RSI calculation.
-    pub prev_value: Float,
+    pub prev_value: T,
     /// The average gain calculated from the input data.
-    pub avg_gain: Float,
+    pub avg_gain: T,
     /// The average loss calculated from the input data.
-    pub avg_loss: Float,
+    pub avg_loss: T,
 
     // Parameters
     /// The period used for the RSI calculation.
     pub period: usize,
 }
 
-impl State<Float> for RsiState {
+impl<T: TechalibFloat> State<T> for RsiState<T> {
     /// Update the [`RsiState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the RSI state.
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if self.period <= 1 {
             return Err(TechalibError::BadParam(
                 "RSI period must be greater than 1".to_string(),
@@ -140,10 +163,10 @@ impl State<Float> for RsiState {
             sample - self.prev_value,
             self.avg_gain,
             self.avg_loss,
-            self.period as Float,
+            T::from_usize(self.period),
         );
         if !rsi.is_finite() {
-            return Err(TechalibError::Overflow(0, rsi));
+            return Err(TechalibError::Overflow(0, rsi.to_f64()));
         }
         self.rsi = rsi;
         self.prev_value = sample;
@@ -166,9 +189,9 @@ impl State<Float> for RsiState {
 /// ---
 /// A `Result` containing a [`RsiResult`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn rsi(data: &[Float], period: usize) -> Result<RsiResult, TechalibError> {
+pub fn rsi<T: TechalibFloat>(data: &[T], period: usize) -> Result<RsiResult<T>, TechalibError> {
     let size: usize = data.len();
-    let mut output = vec![0.0; size];
+    let mut output = vec![T::ZERO; size];
     let rsi_state = rsi_into(data, period, output.as_mut_slice())?;
     Ok(RsiResult {
         values: output,
@@ -194,13 +217,13 @@ pub fn rsi(data: &[Float], period: usize) -> Result<RsiResult, TechalibError> {
 /// ---
 /// A `Result` containing a [`RsiState`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn rsi_into(
-    data: &[Float],
+pub fn rsi_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    output: &mut [Float],
-) -> Result<RsiState, TechalibError> {
+    output: &mut [T],
+) -> Result<RsiState<T>, TechalibError> {
     let len = data.len();
-    let period_as_float = period as Float;
+    let period_as_float = T::from_usize(period);
     if period == 0 || period + 1 > len {
         return Err(TechalibError::InsufficientData);
     }
@@ -217,9 +240,9 @@ pub fn rsi_into(
         ));
     }
 
-    let mut avg_gain: Float = 0.0;
-    let mut avg_loss: Float = 0.0;
-    output[0] = Float::NAN;
+    let mut avg_gain: T = T::ZERO;
+    let mut avg_loss: T = T::ZERO;
+    output[0] = T::NAN;
     for i in 1..=period {
         let delta = data[i] - data[i - 1];
         if !delta.is_finite() {
@@ -228,18 +251,18 @@ pub fn rsi_into(
                 i, data[i]
             )));
         }
-        if delta > 0.0 {
+        if delta > T::ZERO {
             avg_gain += delta;
         } else {
             avg_loss -= delta;
         }
-        output[i] = Float::NAN;
+        output[i] = T::NAN;
     }
     avg_gain /= period_as_float;
     avg_loss /= period_as_float;
     output[period] = calculate_rsi(avg_gain, avg_loss);
     if !output[period].is_finite() {
-        return Err(TechalibError::Overflow(period, output[period]));
+        return Err(TechalibError::Overflow(period, output[period].to_f64()));
     }
 
     for i in (period + 1)..len {
@@ -253,7 +276,7 @@ pub fn rsi_into(
         (output[i], avg_gain, avg_loss) =
             rsi_next_unchecked(data[i] - data[i - 1], avg_gain, avg_loss, period_as_float);
         if !output[i].is_finite() {
-            return Err(TechalibError::Overflow(i, output[i]));
+            return Err(TechalibError::Overflow(i, output[i].to_f64()));
         }
     }
     Ok(RsiState {
@@ -265,40 +288,142 @@ pub fn rsi_into(
     })
 }
 
+batch_functions!(
+    rsi_batch,
+    rsi_batch_into,
+    rsi,
+    rsi_into,
+    RsiResult,
+    RsiState,
+    "RSI";
+    period: usize
+);
+
+/// Calculation of the RSI function for several periods
+/// ---
+/// It returns a [`RsiSweepResult`]. The price changes and the prefix sums
+/// of the gains and losses are computed once and shared by every period.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `periods`: The periods for the RSI calculation.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`RsiSweepResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn rsi_sweep<T: TechalibFloat>(
+    data: &[T],
+    periods: &[usize],
+) -> Result<RsiSweepResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len() * periods.len()];
+    let states = rsi_sweep_into(data, periods, &mut output)?;
+    Ok(RsiSweepResult {
+        values: output,
+        states,
+    })
+}
+
+/// Calculation of the RSI function for several periods
+/// ---
+/// It stores the results in the provided output array and
+/// returns one [`RsiState`] per period.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `periods`: The periods for the RSI calculation.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] of length at least `data.len() * periods.len()`,
+///   where the RSI values of each period are stored as consecutive columns.
+///
+/// Returns
+/// ---
+/// A `Result` containing a vector of [`RsiState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn rsi_sweep_into<T: TechalibFloat>(
+    data: &[T],
+    periods: &[usize],
+    output: &mut [T],
+) -> Result<Vec<RsiState<T>>, TechalibError> {
+    let len = data.len();
+    for &period in periods {
+        if period == 0 || period + 1 > len {
+            return Err(TechalibError::InsufficientData);
+        }
+        if period == 1 {
+            return Err(TechalibError::BadParam(
+                "RSI window size must be greater than 1".to_string(),
+            ));
+        }
+    }
+    check_sweep_output(len, periods, output.len())?;
+
+    let mut deltas = vec![T::ZERO; len];
+    for i in 1..len {
+        deltas[i] = data[i] - data[i - 1];
+        if !deltas[i].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{}] = {:?}",
+                i, data[i]
+            )));
+        }
+    }
+    let gains = prefix_sums(&deltas, |delta| delta.max(0.0))?;
+    let losses = prefix_sums(&deltas, |delta| (-delta).max(0.0))?;
+
+    let columns = output.chunks_mut(len.max(1)).collect();
+    sweep(periods, columns, |period, output: &mut [T]| {
+        let period_as_float = T::from_usize(period);
+        let mut avg_gain = T::from_f64(gains[period + 1] / period as f64);
+        let mut avg_loss = T::from_f64(losses[period + 1] / period as f64);
+        output[..period].fill(T::NAN);
+        output[period] = calculate_rsi(avg_gain, avg_loss);
+        if !output[period].is_finite() {
+            return Err(TechalibError::Overflow(period, output[period].to_f64()));
+        }
+        for i in (period + 1)..len {
+            (output[i], avg_gain, avg_loss) =
+                rsi_next_unchecked(deltas[i], avg_gain, avg_loss, period_as_float);
+            if !output[i].is_finite() {
+                return Err(TechalibError::Overflow(i, output[i].to_f64()));
+            }
+        }
+        Ok(RsiState {
+            rsi: output[len - 1],
+            prev_value: data[len - 1],
+            avg_gain,
+            avg_loss,
+            period,
+        })
+    })
+}
+
 #[inline(always)]
-fn rsi_next_unchecked(
-    delta: Float,
-    prev_avg_gain: Float,
-    prev_avg_loss: Float,
-    period: Float,
-) -> (Float, Float, Float) {
-    let k = 1.0 / period;
-    let one_minus_k = 1.0 - k;
-    let (avg_gain, avg_loss) = if delta > 0.0 {
-        (
-            prev_avg_gain * one_minus_k + delta * k,
-            prev_avg_loss * one_minus_k,
-        )
-    } else if delta < 0.0 {
-        (
-            prev_avg_gain * one_minus_k,
-            prev_avg_loss * one_minus_k - delta * k,
-        )
-    } else {
-        (prev_avg_gain * one_minus_k, prev_avg_loss * one_minus_k)
-    };
+fn rsi_next_unchecked<T: TechalibFloat>(
+    delta: T,
+    prev_avg_gain: T,
+    prev_avg_loss: T,
+    period: T,
+) -> (T, T, T) {
+    let k = T::ONE / period;
+    let avg_gain = smma_next_unchecked(delta.max(T::ZERO), prev_avg_gain, k);
+    let avg_loss = smma_next_unchecked((-delta).max(T::ZERO), prev_avg_loss, k);
 
     (calculate_rsi(avg_gain, avg_loss), avg_gain, avg_loss)
 }
 
 #[inline(always)]
-fn calculate_rsi(avg_gain: Float, avg_loss: Float) -> Float {
-    if avg_loss == 0.0 {
-        if avg_gain == 0.0 {
-            return 50.0;
+fn calculate_rsi<T: TechalibFloat>(avg_gain: T, avg_loss: T) -> T {
+    if avg_loss == T::ZERO {
+        if avg_gain == T::ZERO {
+            return T::from_f64(50.0);
         }
-        return 100.0;
+        return T::from_f64(100.0);
     }
     let rs = avg_gain / avg_loss;
-    100.0 - (100.0 / (1.0 + rs))
+    T::from_f64(100.0) - (T::from_f64(100.0) / (T::ONE + rs))
 }
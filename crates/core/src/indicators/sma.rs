@@ -40,10 +40,14 @@
 
 //! Simple Moving Average (SMA) implementation
 
+use crate::batch::{batch_functions, check_sweep_output, sweep, BlockPrefixSums};
 use crate::errors::TechalibError;
+use crate::precision;
+use crate::ring_buffer::RingBuffer;
+use crate::simd;
 use crate::traits::State;
-use crate::types::Float;
-use std::collections::VecDeque;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 /// SMA calculation result
 /// ---
@@ -56,12 +60,32 @@ use std::collections::VecDeque;
 /// - `state`: A [`SmaState`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct SmaResult {
+pub struct SmaResult<T = Float> {
     /// The calculated SMA values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// A [`SmaState`], which can be used to calculate
     /// the next values incrementally.
-    pub state: SmaState,
+    pub state: SmaState<T>,
+}
+
+/// SMA sweep result
+/// ---
+/// This struct holds the result of the SMA calculation for several periods
+/// over the same series.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] holding a column-major 2-D array with
+///   one column of `data.len()` values per period, in the order of the periods.
+/// - `states`: A vector of [`SmaState`], one per period, which can be used
+///   to calculate the next values incrementally.
+#[derive(Debug)]
+pub struct SmaSweepResult<T = Float> {
+    /// The calculated SMA values, one column per period.
+    pub values: Vec<T>,
+    /// A [`SmaState`] per period, which can be used to calculate
+    /// the next values incrementally.
+    pub states: Vec<SmaState<T>>,
 }
 
 /// SMA calculation state
@@ -75,22 +99,22 @@ pub struct SmaResult {
 /// - `sma`: The last calculated Simple Moving Average (SMA) value.
 ///
 /// **State values**
-/// - `last_window`: A deque containing the last `period` values used for
+/// - `last_window`: A ring buffer containing the last `period` values used for
 ///   the SMA calculation.
 ///
 /// **Parameters**
 /// - `period`: The period used for the SMA calculation, which determines
 ///   how many values are averaged to compute the SMA.
 #[derive(Debug, Clone)]
-pub struct SmaState {
+pub struct SmaState<T = Float> {
     // Outputs
     /// The last calculated Simple Moving Average (SMA) value.
-    pub sma: Float,
+    pub sma: T,
 
     // State values
-    /// A deque containing the last `period` values used for
+    /// A ring buffer containing the last `period` values used for
     /// the SMA calculation.
-    pub last_window: VecDeque<Float>,
+    pub last_window: RingBuffer<T>,
 
     // Parameters
     /// The period used for the SMA calculation, which determines
@@ -98,13 +122,13 @@ pub struct SmaState {
     pub period: usize,
 }
 
-impl State<Float> for SmaState {
+impl<T: TechalibFloat> State<T> for SmaState<T> {
     /// Update the [`SmaState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the SMA state
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if self.period <= 1 {
             return Err(TechalibError::BadParam(
                 "SMA period must be greater than 1".to_string(),
@@ -135,17 +159,24 @@ impl State<Float> for SmaState {
             }
         }
 
-        let mut window = self.last_window.clone();
+        let inv_period = T::ONE / T::from_usize(self.period);
+        let prev_sma = if precision::resync_due(self.last_window.head(), self.period) {
+            simd::sum(self.last_window.make_contiguous()) * inv_period
+        } else {
+            self.sma
+        };
 
-        let old_value = window.pop_front().ok_or(TechalibError::InsufficientData)?;
-        window.push_back(sample);
+        let old_value = self
+            .last_window
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
 
-        let sma = sma_next_unchecked(sample, old_value, self.sma, 1.0 / (self.period as Float));
+        let sma = sma_next_unchecked(sample, old_value, prev_sma, inv_period);
         if !sma.is_finite() {
-            return Err(TechalibError::Overflow(0, sma));
+            return Err(TechalibError::Overflow(0, sma.to_f64()));
         }
         self.sma = sma;
-        self.last_window = window;
+        self.last_window.push(sample);
 
         Ok(())
     }
@@ -163,9 +194,9 @@ impl State<Float> for SmaState {
 /// ---
 /// A `Result` containing a [`SmaResult`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn sma(data: &[Float], period: usize) -> Result<SmaResult, TechalibError> {
+pub fn sma<T: TechalibFloat>(data: &[T], period: usize) -> Result<SmaResult<T>, TechalibError> {
     let len = data.len();
-    let mut output = vec![0.0; len];
+    let mut output = vec![T::ZERO; len];
     let sma_state = sma_into(data, period, &mut output)?;
     Ok(SmaResult {
         values: output,
@@ -192,13 +223,13 @@ pub fn sma(data: &[Float], period: usize) -> Result<SmaResult, TechalibError> {
 /// ---
 /// A `Result` containing a [`SmaState`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn sma_into(
-    data: &[Float],
+pub fn sma_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    output: &mut [Float],
-) -> Result<SmaState, TechalibError> {
+    output: &mut [T],
+) -> Result<SmaState<T>, TechalibError> {
     let len = data.len();
-    let inv_period = 1.0 / (period as Float);
+    let inv_period = T::ONE / T::from_usize(period);
     if period == 0 || period > len {
         return Err(TechalibError::InsufficientData);
     }
@@ -217,7 +248,10 @@ pub fn sma_into(
 
     output[period - 1] = init_sma_unchecked(data, period, inv_period, output)?;
     if !output[period - 1].is_finite() {
-        return Err(TechalibError::Overflow(period - 1, output[period - 1]));
+        return Err(TechalibError::Overflow(
+            period - 1,
+            output[period - 1].to_f64(),
+        ));
     }
 
     for idx in period..len {
@@ -227,47 +261,143 @@ pub fn sma_into(
                 data[idx]
             )));
         }
-        output[idx] =
-            sma_next_unchecked(data[idx], data[idx - period], output[idx - 1], inv_period);
+        let prev_sma = if precision::resync_due(idx, period) {
+            simd::sum(&data[idx - period..idx]) * inv_period
+        } else {
+            output[idx - 1]
+        };
+        output[idx] = sma_next_unchecked(data[idx], data[idx - period], prev_sma, inv_period);
         if !output[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output[idx]));
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
         }
     }
     Ok(SmaState {
         sma: output[len - 1],
         period,
-        last_window: VecDeque::from(data[len - period..len].to_vec()),
+        last_window: RingBuffer::from(&data[len - period..len]),
+    })
+}
+
+batch_functions!(
+    sma_batch,
+    sma_batch_into,
+    sma,
+    sma_into,
+    SmaResult,
+    SmaState,
+    "SMA";
+    period: usize
+);
+
+/// Calculation of the SMA function for several periods
+/// ---
+/// It returns a [`SmaSweepResult`]. A single prefix sum of `data` is
+/// shared by every period.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `periods`: The periods for the SMA calculation.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SmaSweepResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn sma_sweep<T: TechalibFloat>(
+    data: &[T],
+    periods: &[usize],
+) -> Result<SmaSweepResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len() * periods.len()];
+    let states = sma_sweep_into(data, periods, &mut output)?;
+    Ok(SmaSweepResult {
+        values: output,
+        states,
+    })
+}
+
+/// Calculation of the SMA function for several periods
+/// ---
+/// It stores the results in the provided output array and
+/// returns one [`SmaState`] per period.
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `periods`: The periods for the SMA calculation.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] of length at least `data.len() * periods.len()`,
+///   where the SMA values of each period are stored as consecutive columns.
+///
+/// Returns
+/// ---
+/// A `Result` containing a vector of [`SmaState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn sma_sweep_into<T: TechalibFloat>(
+    data: &[T],
+    periods: &[usize],
+    output: &mut [T],
+) -> Result<Vec<SmaState<T>>, TechalibError> {
+    let len = data.len();
+    for &period in periods {
+        if period == 0 || period > len {
+            return Err(TechalibError::InsufficientData);
+        }
+        if period == 1 {
+            return Err(TechalibError::BadParam(
+                "SMA period must be greater than 1".to_string(),
+            ));
+        }
+    }
+    check_sweep_output(len, periods, output.len())?;
+
+    let max_period = periods.iter().copied().max().unwrap_or(1);
+    let sums = BlockPrefixSums::new(data, max_period, |value| value)?;
+    let columns = output.chunks_mut(len.max(1)).collect();
+    sweep(periods, columns, |period, output: &mut [T]| {
+        output[..period - 1].fill(T::NAN);
+        for (sums, range) in sums.windows(period) {
+            simd::window_means(sums, period, 1.0 / period as f64, &mut output[range]);
+        }
+        if let Some(idx) = simd::first_non_finite(&output[period - 1..]) {
+            let idx = idx + period - 1;
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+        Ok(SmaState {
+            sma: output[len - 1],
+            period,
+            last_window: RingBuffer::from(&data[len - period..len]),
+        })
     })
 }
 
 #[inline(always)]
-pub(crate) fn sma_next_unchecked(
-    new_value: Float,
-    old_value: Float,
-    prev_sma: Float,
-    inv_period: Float,
-) -> Float {
+pub(crate) fn sma_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    old_value: T,
+    prev_sma: T,
+    inv_period: T,
+) -> T {
     prev_sma + (new_value - old_value) * inv_period
 }
 
 #[inline(always)]
-pub(crate) fn init_sma_unchecked(
-    data: &[Float],
+pub(crate) fn init_sma_unchecked<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    inv_period: Float,
-    output: &mut [Float],
-) -> Result<Float, TechalibError> {
-    let mut sum: Float = 0.0;
-    for idx in 0..period {
-        let value = &data[idx];
-        if !value.is_finite() {
+    inv_period: T,
+    output: &mut [T],
+) -> Result<T, TechalibError> {
+    let sum = simd::sum(&data[..period]);
+    if !sum.is_finite() {
+        if let Some(idx) = simd::first_non_finite(&data[..period]) {
             return Err(TechalibError::DataNonFinite(format!(
-                "data_array[{idx}] = {value:?}"
+                "data_array[{idx}] = {:?}",
+                data[idx]
             )));
-        } else {
-            sum += value;
         }
-        output[idx] = Float::NAN;
     }
+    output[..period].fill(T::NAN);
     Ok(sum * inv_period)
 }
@@ -0,0 +1,239 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Smoothed Moving Average (SMMA) implementation
+//!
+//! Also known as Wilder's Running Moving Average (RMA): an exponential
+//! moving average with `alpha = 1 / period`, seeded with the simple moving
+//! average of the first `period` values. It is the smoothing used by the
+//! [`rsi`](crate::indicators::rsi) and the [`atr`](crate::indicators::atr).
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::ema::ema_next_unchecked;
+use crate::indicators::sma::init_sma_unchecked;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// SMMA calculation result
+/// ---
+/// This struct holds the result and the state ([`SmmaState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated SMMA values.
+/// - `state`: A [`SmmaState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct SmmaResult<T = Float> {
+    /// The calculated SMMA values.
+    pub values: Vec<T>,
+    /// A [`SmmaState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: SmmaState<T>,
+}
+
+/// SMMA calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `smma`: The last calculated SMMA value.
+///
+/// **Parameters**
+/// - `period`: The period used for the SMMA calculation.
+#[derive(Debug, Clone, Copy)]
+pub struct SmmaState<T = Float> {
+    // Outputs
+    /// The last calculated SMMA value.
+    pub smma: T,
+
+    // Parameters
+    /// The period used for the SMMA calculation.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for SmmaState<T> {
+    /// Update the [`SmmaState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the SMMA state.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        if self.period <= 1 {
+            return Err(TechalibError::BadParam(
+                "SMMA period must be greater than 1".to_string(),
+            ));
+        }
+        if !sample.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
+        }
+        if !self.smma.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.smma = {:?}",
+                self.smma
+            )));
+        }
+
+        let smma = smma_next_unchecked(sample, self.smma, T::ONE / T::from_usize(self.period));
+        if !smma.is_finite() {
+            return Err(TechalibError::Overflow(0, smma.to_f64()));
+        }
+        self.smma = smma;
+        Ok(())
+    }
+}
+
+/// Lookback period for SMMA calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the SMMA values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the SMMA function
+/// ---
+/// It returns a [`SmmaResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period for the SMMA calculation.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SmmaResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn smma<T: TechalibFloat>(data: &[T], period: usize) -> Result<SmmaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let smma_state = smma_into(data, period, output.as_mut_slice())?;
+    Ok(SmmaResult {
+        values: output,
+        state: smma_state,
+    })
+}
+
+/// Calculation of the SMMA function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`SmmaState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period for the SMMA calculation.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated SMMA values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SmmaState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn smma_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<SmmaState<T>, TechalibError> {
+    let len = data.len();
+    if period == 0 || len < period {
+        return Err(TechalibError::InsufficientData);
+    }
+    if period == 1 {
+        return Err(TechalibError::BadParam(
+            "SMMA period must be greater than 1".to_string(),
+        ));
+    }
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let inv_period = T::ONE / T::from_usize(period);
+    let lookback = lookback_from_period(period);
+    output[lookback] = init_sma_unchecked(data, period, inv_period, output)?;
+    if !output[lookback].is_finite() {
+        return Err(TechalibError::Overflow(lookback, output[lookback].to_f64()));
+    }
+
+    for idx in period..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        output[idx] = smma_next_unchecked(data[idx], output[idx - 1], inv_period);
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(SmmaState {
+        smma: output[len - 1],
+        period,
+    })
+}
+
+batch_functions!(
+    smma_batch,
+    smma_batch_into,
+    smma,
+    smma_into,
+    SmmaResult,
+    SmmaState,
+    "SMMA";
+    period: usize
+);
+
+#[inline(always)]
+pub(crate) fn smma_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    prev_smma: T,
+    inv_period: T,
+) -> T {
+    ema_next_unchecked(new_value, prev_smma, inv_period)
+}
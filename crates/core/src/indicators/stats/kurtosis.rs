@@ -0,0 +1,256 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Rolling Kurtosis (KURTOSIS) implementation
+//!
+//! The kurtosis is the bias corrected excess kurtosis of the window
+//! (the estimator of spreadsheets and `pandas`):
+//! `G2 = ((n + 1) * g2 + 6) * (n - 1) / ((n - 2) * (n - 3))`
+//! with `g2 = n * m4 / m2² - 3`.
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::stats::{
+    check_period, check_sample, check_window, is_constant_unchecked, Moments,
+};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+
+/// Rolling Kurtosis calculation result
+/// ---
+/// This struct holds the result of the rolling Kurtosis calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: The Kurtosis values.
+/// - `state`: A [`KurtosisState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct KurtosisResult<T = Float> {
+    /// The Kurtosis values.
+    pub values: Vec<T>,
+    /// A [`KurtosisState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: KurtosisState<T>,
+}
+
+/// Rolling Kurtosis calculation state
+/// ---
+/// This struct holds the state of the rolling Kurtosis calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `kurtosis`: The last Kurtosis value.
+///
+/// **State values**
+/// - `moments`: The [`Moments`] of the window.
+/// - `last_window`: The last `period` values.
+///
+/// **Parameters**
+/// - `period`: The size of the window.
+#[derive(Debug, Clone)]
+pub struct KurtosisState<T = Float> {
+    // Outputs
+    /// The last Kurtosis value.
+    pub kurtosis: T,
+
+    // State values
+    /// The [`Moments`] of the window.
+    pub moments: Moments<T>,
+    /// The last `period` values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The size of the window.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for KurtosisState<T> {
+    /// Update the [`KurtosisState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new value.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        check_period(self.period, 4)?;
+        check_sample(0, sample)?;
+        check_window(&self.last_window, self.period)?;
+
+        let moments = Moments::from_window_unchecked(
+            self.last_window.iter().skip(1).copied().chain([sample]),
+        );
+        let kurtosis = kurtosis_unchecked(&moments, self.period);
+        if !kurtosis.is_finite() {
+            return Err(TechalibError::Overflow(0, kurtosis.to_f64()));
+        }
+
+        self.kurtosis = kurtosis;
+        self.moments = moments;
+        self.last_window.push(sample);
+        Ok(())
+    }
+}
+
+/// Lookback period for rolling Kurtosis calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Kurtosis values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the rolling Kurtosis function
+/// ---
+/// It returns a [`KurtosisResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] values.
+/// - `period`: The size of the window (at least 4).
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`KurtosisResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn kurtosis<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+) -> Result<KurtosisResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+
+    let kurtosis_state = kurtosis_into(data, period, output.as_mut_slice())?;
+
+    Ok(KurtosisResult {
+        values: output,
+        state: kurtosis_state,
+    })
+}
+
+/// Calculation of the rolling Kurtosis function
+/// ---
+/// It stores the results in the provided output array and
+/// return the state [`KurtosisState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] values.
+/// - `period`: The size of the window (at least 4).
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice to store the Kurtosis values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`KurtosisState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn kurtosis_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<KurtosisState<T>, TechalibError> {
+    check_period(period, 4)?;
+    let len = data.len();
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let mut moments = Moments::default();
+    for idx in 0..len {
+        check_sample(idx, data[idx])?;
+        if idx < period {
+            moments.push_unchecked(idx, data[idx]);
+        } else {
+            moments.roll_unchecked(
+                period,
+                data[idx + 1 - period..=idx].iter().copied(),
+                data[idx - period],
+                data[idx],
+                idx % period == 0,
+            );
+        }
+        if idx < lookback {
+            output[idx] = T::NAN;
+            continue;
+        }
+        output[idx] = kurtosis_unchecked(&moments, period);
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(KurtosisState {
+        kurtosis: output[len - 1],
+        moments,
+        last_window: RingBuffer::from(&data[len - period..]),
+        period,
+    })
+}
+
+batch_functions!(
+    kurtosis_batch,
+    kurtosis_batch_into,
+    kurtosis,
+    kurtosis_into,
+    KurtosisResult,
+    KurtosisState,
+    "KURTOSIS";
+    period: usize
+);
+
+#[inline(always)]
+fn kurtosis_unchecked<T: TechalibFloat>(moments: &Moments<T>, period: usize) -> T {
+    if is_constant_unchecked(moments.mean, moments.m2, period) {
+        return T::ZERO;
+    }
+    let n = T::from_usize(period);
+    let g2 = n * moments.m4 / (moments.m2 * moments.m2) - T::from_f64(3.0);
+    ((n + T::ONE) * g2 + T::from_f64(6.0)) * (n - T::ONE)
+        / ((n - T::from_f64(2.0)) * (n - T::from_f64(3.0)))
+}
@@ -0,0 +1,248 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Rolling Median (MEDIAN) implementation
+//!
+//! The values of the window are kept sorted, so that the median is read
+//! in the middle of the sorted window. For an even period, the median is
+//! the mean of the two middle values.
+
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::stats::{
+    check_period, check_sample, check_window, sorted_insert_unchecked, sorted_replace_unchecked,
+};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+
+/// Rolling Median calculation result
+/// ---
+/// This struct holds the result of the rolling Median calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: The Median values.
+/// - `state`: A [`MedianState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct MedianResult<T = Float> {
+    /// The Median values.
+    pub values: Vec<T>,
+    /// A [`MedianState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: MedianState<T>,
+}
+
+/// Rolling Median calculation state
+/// ---
+/// This struct holds the state of the rolling Median calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `median`: The last Median value.
+///
+/// **State values**
+/// - `sorted_window`: The last `period` values, sorted in ascending order.
+/// - `last_window`: The last `period` values.
+///
+/// **Parameters**
+/// - `period`: The size of the window.
+#[derive(Debug, Clone)]
+pub struct MedianState<T = Float> {
+    // Outputs
+    /// The last Median value.
+    pub median: T,
+
+    // State values
+    /// The last `period` values, sorted in ascending order.
+    pub sorted_window: Vec<T>,
+    /// The last `period` values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The size of the window.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for MedianState<T> {
+    /// Update the [`MedianState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new value.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        check_period(self.period, 1)?;
+        check_sample(0, sample)?;
+        check_window(&self.last_window, self.period)?;
+        if self.sorted_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "MEDIAN state sorted_window length ({}) does not match period ({})",
+                self.sorted_window.len(),
+                self.period
+            )));
+        }
+
+        let old_value = self
+            .last_window
+            .push(sample)
+            .ok_or(TechalibError::InsufficientData)?;
+        sorted_replace_unchecked(&mut self.sorted_window, old_value, sample);
+
+        self.median = median_unchecked(&self.sorted_window);
+        Ok(())
+    }
+}
+
+/// Lookback period for rolling Median calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Median values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the rolling Median function
+/// ---
+/// It returns a [`MedianResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] values.
+/// - `period`: The size of the window.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MedianResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn median<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+) -> Result<MedianResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+
+    let median_state = median_into(data, period, output.as_mut_slice())?;
+
+    Ok(MedianResult {
+        values: output,
+        state: median_state,
+    })
+}
+
+/// Calculation of the rolling Median function
+/// ---
+/// It stores the results in the provided output array and
+/// return the state [`MedianState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] values.
+/// - `period`: The size of the window.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice to store the Median values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`MedianState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn median_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<MedianState<T>, TechalibError> {
+    check_period(period, 1)?;
+    let len = data.len();
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let mut sorted_window = Vec::with_capacity(period);
+    for idx in 0..len {
+        check_sample(idx, data[idx])?;
+        if idx >= period {
+            sorted_replace_unchecked(&mut sorted_window, data[idx - period], data[idx]);
+        } else {
+            sorted_insert_unchecked(&mut sorted_window, data[idx]);
+        }
+        output[idx] = if idx < lookback {
+            T::NAN
+        } else {
+            median_unchecked(&sorted_window)
+        };
+    }
+
+    Ok(MedianState {
+        median: output[len - 1],
+        sorted_window,
+        last_window: RingBuffer::from(&data[len - period..]),
+        period,
+    })
+}
+
+batch_functions!(
+    median_batch,
+    median_batch_into,
+    median,
+    median_into,
+    MedianResult,
+    MedianState,
+    "MEDIAN";
+    period: usize
+);
+
+#[inline(always)]
+fn median_unchecked<T: TechalibFloat>(sorted_window: &[T]) -> T {
+    let mid = sorted_window.len() / 2;
+    if sorted_window.len() % 2 == 0 {
+        (sorted_window[mid - 1] + sorted_window[mid]) * T::from_f64(0.5)
+    } else {
+        sorted_window[mid]
+    }
+}
@@ -0,0 +1,265 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Rolling statistics
+//!
+//! Rolling features over a window of `period` values:
+//! [`zscore`], [`percent_rank`], [`skew`], [`kurtosis`] and [`median`].
+//!
+//! The moments are updated incrementally with Welford's algorithm and its
+//! extension to the third and fourth central moments (Pébay, 2008), a value
+//! is removed from the window with the inverse update. This avoids the
+//! cancellation of the `mean(x²) - mean(x)²` formula when the variance is
+//! small compared to the mean. To bound the accumulation of rounding errors,
+//! the moments are computed again from the window every `period` values,
+//! and after an update which cancels most of the variance. The states
+//! compute them from their window at each update.
+//!
+//! The order statistics (rank and median) are read from a sorted copy of the
+//! window: a rank or a median is then a binary search or a direct read. At each
+//! new value, the leaving value and the new one are located with a binary search,
+//! and only the values between them are shifted, in place.
+//!
+//! A window with a variance below the rounding error of its mean is treated
+//! as constant: the z-score, skewness and kurtosis are `0`.
+
+use alloc::{format, vec::Vec};
+
+use crate::errors::TechalibError;
+use crate::ring_buffer::RingBuffer;
+use crate::types::{Float, TechalibFloat};
+
+pub mod kurtosis;
+pub mod median;
+pub mod percent_rank;
+pub mod skew;
+pub mod zscore;
+
+/// Ratio of the variance under which an update is treated as a cancellation
+const CANCELLATION_RATIO: f64 = 1.0 / 1024.0;
+
+/// Relative tolerance, in machine epsilons, under which a variance is treated as zero
+const ZERO_VARIANCE_TOLERANCE: f64 = 64.0;
+
+/// Central moments of a window
+/// ---
+/// This struct holds the mean and the sums of the powers of the deviations
+/// from the mean of the values of a window.
+///
+/// Attributes
+/// ---
+/// - `mean`: The mean of the values.
+/// - `m2`: The sum of the squared deviations.
+/// - `m3`: The sum of the cubed deviations.
+/// - `m4`: The sum of the deviations to the fourth power.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Moments<T = Float> {
+    /// The mean of the values.
+    pub mean: T,
+    /// The sum of the squared deviations.
+    pub m2: T,
+    /// The sum of the cubed deviations.
+    pub m3: T,
+    /// The sum of the deviations to the fourth power.
+    pub m4: T,
+}
+
+impl<T: TechalibFloat> Moments<T> {
+    /// Add `value` to a window of `count` values
+    #[inline(always)]
+    pub(crate) fn push_unchecked(&mut self, count: usize, value: T) {
+        let new_count = T::from_usize(count + 1);
+        let delta = value - self.mean;
+        let delta_n = delta / new_count;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * T::from_usize(count);
+
+        self.mean += delta_n;
+        self.m4 += term
+            * delta_n2
+            * (new_count * new_count - T::from_f64(3.0) * new_count + T::from_f64(3.0))
+            + T::from_f64(6.0) * delta_n2 * self.m2
+            - T::from_f64(4.0) * delta_n * self.m3;
+        self.m3 +=
+            term * delta_n * (new_count - T::from_f64(2.0)) - T::from_f64(3.0) * delta_n * self.m2;
+        self.m2 += term;
+    }
+
+    /// Remove `value` from a window of `count` values
+    #[inline(always)]
+    pub(crate) fn pop_unchecked(&mut self, count: usize, value: T) {
+        if count <= 1 {
+            *self = Moments::default();
+            return;
+        }
+        let old_count = T::from_usize(count);
+        let new_count = T::from_usize(count - 1);
+        let mean = self.mean + (self.mean - value) / new_count;
+        let delta = value - mean;
+        let delta_n = delta / old_count;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * new_count;
+
+        self.mean = mean;
+        self.m2 = (self.m2 - term).max(T::ZERO);
+        self.m3 -=
+            term * delta_n * (old_count - T::from_f64(2.0)) - T::from_f64(3.0) * delta_n * self.m2;
+        self.m4 -= term
+            * delta_n2
+            * (old_count * old_count - T::from_f64(3.0) * old_count + T::from_f64(3.0))
+            + T::from_f64(6.0) * delta_n2 * self.m2
+            - T::from_f64(4.0) * delta_n * self.m3;
+    }
+
+    /// Replace `old_value` by `new_value` in a window of `count` values
+    #[inline(always)]
+    pub(crate) fn replace_unchecked(&mut self, count: usize, old_value: T, new_value: T) {
+        self.pop_unchecked(count, old_value);
+        self.push_unchecked(count - 1, new_value);
+    }
+
+    /// Roll a window of `count` values from `old_value` to `new_value`,
+    /// `window` being the values of the new window
+    ///
+    /// The moments are computed again from `window` when `resync` is set, or
+    /// when the update cancelled most of the variance, which would leave
+    /// the rounding errors of the previous window in the new one.
+    #[inline(always)]
+    pub(crate) fn roll_unchecked(
+        &mut self,
+        count: usize,
+        window: impl IntoIterator<Item = T>,
+        old_value: T,
+        new_value: T,
+        resync: bool,
+    ) {
+        let previous_m2 = self.m2;
+        if !resync {
+            self.replace_unchecked(count, old_value, new_value);
+        }
+        if resync || self.m2 < previous_m2 * T::from_f64(CANCELLATION_RATIO) {
+            *self = Moments::from_window_unchecked(window);
+        }
+    }
+
+    /// Moments of the values of a window
+    pub(crate) fn from_window_unchecked(window: impl IntoIterator<Item = T>) -> Self {
+        let mut moments = Moments::default();
+        for (count, value) in window.into_iter().enumerate() {
+            moments.push_unchecked(count, value);
+        }
+        moments
+    }
+}
+
+/// Whether the variance of a window of `count` values is zero,
+/// up to the rounding error of its mean
+#[inline(always)]
+pub(crate) fn is_constant_unchecked<T: TechalibFloat>(mean: T, m2: T, count: usize) -> bool {
+    let rounding = T::from_f64(ZERO_VARIANCE_TOLERANCE) * T::EPSILON * mean;
+    m2 <= T::from_usize(count) * rounding * rounding
+}
+
+/// Insert `value` in the sorted window
+#[inline(always)]
+pub(crate) fn sorted_insert_unchecked<T: TechalibFloat>(sorted: &mut Vec<T>, value: T) {
+    let idx = sorted.partition_point(|&v| v < value);
+    sorted.insert(idx, value);
+}
+
+/// Replace `old_value` by `new_value` in the sorted window
+/// ---
+/// Only the values between the two positions are shifted. If `old_value` is not
+/// in the window, the first value above it, or the largest one, is replaced.
+#[inline(always)]
+pub(crate) fn sorted_replace_unchecked<T: TechalibFloat>(
+    sorted: &mut [T],
+    old_value: T,
+    new_value: T,
+) {
+    let Some(last) = sorted.len().checked_sub(1) else {
+        return;
+    };
+    let old_idx = sorted.partition_point(|&v| v < old_value).min(last);
+    let new_idx = sorted.partition_point(|&v| v < new_value);
+    if new_idx > old_idx {
+        sorted[old_idx..new_idx].rotate_left(1);
+        sorted[new_idx - 1] = new_value;
+    } else {
+        sorted[new_idx..=old_idx].rotate_right(1);
+        sorted[new_idx] = new_value;
+    }
+}
+
+pub(crate) fn check_period(period: usize, min_period: usize) -> Result<(), TechalibError> {
+    if period < min_period {
+        return Err(TechalibError::BadParam(format!(
+            "Period must be greater than or equal to {min_period}, got: {period}"
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn check_window<T: TechalibFloat>(
+    last_window: &RingBuffer<T>,
+    period: usize,
+) -> Result<(), TechalibError> {
+    if last_window.len() != period {
+        return Err(TechalibError::BadParam(format!(
+            "State last_window length ({}) does not match period ({})",
+            last_window.len(),
+            period
+        )));
+    }
+    for (idx, &value) in last_window.iter().enumerate() {
+        if !value.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "window[{idx}] = {value:?}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) fn check_sample<T: TechalibFloat>(idx: usize, value: T) -> Result<(), TechalibError> {
+    if !value.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "data[{idx}] = {value:?}"
+        )));
+    }
+    Ok(())
+}
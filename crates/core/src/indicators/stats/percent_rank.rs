@@ -0,0 +1,244 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Rolling Percent Rank (PERCENT_RANK) implementation
+//!
+//! The percent rank is the percentage of the `period` previous values
+//! which are lower than or equal to the current value, between `0` and `100`.
+//! The previous values are kept sorted, so that the rank is found with
+//! a binary search.
+
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::stats::{
+    check_period, check_sample, check_window, sorted_insert_unchecked, sorted_replace_unchecked,
+};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+
+/// Rolling Percent Rank calculation result
+/// ---
+/// This struct holds the result of the rolling Percent Rank calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: The Percent Rank values.
+/// - `state`: A [`PercentRankState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct PercentRankResult<T = Float> {
+    /// The Percent Rank values.
+    pub values: Vec<T>,
+    /// A [`PercentRankState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: PercentRankState<T>,
+}
+
+/// Rolling Percent Rank calculation state
+/// ---
+/// This struct holds the state of the rolling Percent Rank calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `percent_rank`: The last Percent Rank value.
+///
+/// **State values**
+/// - `sorted_window`: The last `period` values, sorted in ascending order.
+/// - `last_window`: The last `period` values.
+///
+/// **Parameters**
+/// - `period`: The number of previous values.
+#[derive(Debug, Clone)]
+pub struct PercentRankState<T = Float> {
+    // Outputs
+    /// The last Percent Rank value.
+    pub percent_rank: T,
+
+    // State values
+    /// The last `period` values, sorted in ascending order.
+    pub sorted_window: Vec<T>,
+    /// The last `period` values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The number of previous values.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for PercentRankState<T> {
+    /// Update the [`PercentRankState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new value.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        check_period(self.period, 1)?;
+        check_sample(0, sample)?;
+        check_window(&self.last_window, self.period)?;
+        if self.sorted_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "PERCENT_RANK state sorted_window length ({}) does not match period ({})",
+                self.sorted_window.len(),
+                self.period
+            )));
+        }
+
+        let percent_rank = percent_rank_unchecked(&self.sorted_window, sample);
+
+        let old_value = self
+            .last_window
+            .push(sample)
+            .ok_or(TechalibError::InsufficientData)?;
+        sorted_replace_unchecked(&mut self.sorted_window, old_value, sample);
+
+        self.percent_rank = percent_rank;
+        Ok(())
+    }
+}
+
+/// Lookback period for rolling Percent Rank calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Percent Rank values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period
+}
+
+/// Calculation of the rolling Percent Rank function
+/// ---
+/// It returns a [`PercentRankResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] values.
+/// - `period`: The number of previous values the current value is ranked in.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`PercentRankResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn percent_rank<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+) -> Result<PercentRankResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+
+    let percent_rank_state = percent_rank_into(data, period, output.as_mut_slice())?;
+
+    Ok(PercentRankResult {
+        values: output,
+        state: percent_rank_state,
+    })
+}
+
+/// Calculation of the rolling Percent Rank function
+/// ---
+/// It stores the results in the provided output array and
+/// return the state [`PercentRankState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] values.
+/// - `period`: The number of previous values the current value is ranked in.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice to store the Percent Rank values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`PercentRankState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn percent_rank_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<PercentRankState<T>, TechalibError> {
+    check_period(period, 1)?;
+    let len = data.len();
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let mut sorted_window = Vec::with_capacity(period);
+    for idx in 0..len {
+        check_sample(idx, data[idx])?;
+        if idx < lookback {
+            output[idx] = T::NAN;
+            sorted_insert_unchecked(&mut sorted_window, data[idx]);
+        } else {
+            output[idx] = percent_rank_unchecked(&sorted_window, data[idx]);
+            sorted_replace_unchecked(&mut sorted_window, data[idx - period], data[idx]);
+        }
+    }
+
+    Ok(PercentRankState {
+        percent_rank: output[len - 1],
+        sorted_window,
+        last_window: RingBuffer::from(&data[len - period..]),
+        period,
+    })
+}
+
+batch_functions!(
+    percent_rank_batch,
+    percent_rank_batch_into,
+    percent_rank,
+    percent_rank_into,
+    PercentRankResult,
+    PercentRankState,
+    "PERCENT_RANK";
+    period: usize
+);
+
+#[inline(always)]
+fn percent_rank_unchecked<T: TechalibFloat>(sorted_window: &[T], value: T) -> T {
+    let count = sorted_window.partition_point(|&v| v <= value);
+    T::from_f64(100.0) * T::from_usize(count) / T::from_usize(sorted_window.len())
+}
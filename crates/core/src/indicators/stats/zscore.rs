@@ -0,0 +1,250 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Rolling Z-Score (ZSCORE) implementation
+//!
+//! The z-score is the distance of the last value from the mean of the
+//! window, in population standard deviations of the window.
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::stats::{
+    check_period, check_sample, check_window, is_constant_unchecked, Moments,
+};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+
+/// Rolling Z-Score calculation result
+/// ---
+/// This struct holds the result of the rolling Z-Score calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: The Z-Score values.
+/// - `state`: A [`ZscoreState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct ZscoreResult<T = Float> {
+    /// The Z-Score values.
+    pub values: Vec<T>,
+    /// A [`ZscoreState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: ZscoreState<T>,
+}
+
+/// Rolling Z-Score calculation state
+/// ---
+/// This struct holds the state of the rolling Z-Score calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `zscore`: The last Z-Score value.
+///
+/// **State values**
+/// - `moments`: The [`Moments`] of the window.
+/// - `last_window`: The last `period` values.
+///
+/// **Parameters**
+/// - `period`: The size of the window.
+#[derive(Debug, Clone)]
+pub struct ZscoreState<T = Float> {
+    // Outputs
+    /// The last Z-Score value.
+    pub zscore: T,
+
+    // State values
+    /// The [`Moments`] of the window.
+    pub moments: Moments<T>,
+    /// The last `period` values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The size of the window.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for ZscoreState<T> {
+    /// Update the [`ZscoreState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new value.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        check_period(self.period, 2)?;
+        check_sample(0, sample)?;
+        check_window(&self.last_window, self.period)?;
+        let moments = Moments::from_window_unchecked(
+            self.last_window.iter().skip(1).copied().chain([sample]),
+        );
+        let zscore = zscore_unchecked(sample, &moments, self.period);
+        if !zscore.is_finite() {
+            return Err(TechalibError::Overflow(0, zscore.to_f64()));
+        }
+
+        self.zscore = zscore;
+        self.moments = moments;
+        self.last_window.push(sample);
+        Ok(())
+    }
+}
+
+/// Lookback period for rolling Z-Score calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the Z-Score values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the rolling Z-Score function
+/// ---
+/// It returns a [`ZscoreResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] values.
+/// - `period`: The size of the window (at least 2).
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ZscoreResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn zscore<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+) -> Result<ZscoreResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+
+    let zscore_state = zscore_into(data, period, output.as_mut_slice())?;
+
+    Ok(ZscoreResult {
+        values: output,
+        state: zscore_state,
+    })
+}
+
+/// Calculation of the rolling Z-Score function
+/// ---
+/// It stores the results in the provided output array and
+/// return the state [`ZscoreState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] values.
+/// - `period`: The size of the window (at least 2).
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice to store the Z-Score values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ZscoreState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn zscore_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<ZscoreState<T>, TechalibError> {
+    check_period(period, 2)?;
+    let len = data.len();
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let mut moments = Moments::default();
+    for idx in 0..len {
+        check_sample(idx, data[idx])?;
+        if idx < period {
+            moments.push_unchecked(idx, data[idx]);
+        } else {
+            moments.roll_unchecked(
+                period,
+                data[idx + 1 - period..=idx].iter().copied(),
+                data[idx - period],
+                data[idx],
+                idx % period == 0,
+            );
+        }
+        if idx < lookback {
+            output[idx] = T::NAN;
+            continue;
+        }
+        output[idx] = zscore_unchecked(data[idx], &moments, period);
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(ZscoreState {
+        zscore: output[len - 1],
+        moments,
+        last_window: RingBuffer::from(&data[len - period..]),
+        period,
+    })
+}
+
+batch_functions!(
+    zscore_batch,
+    zscore_batch_into,
+    zscore,
+    zscore_into,
+    ZscoreResult,
+    ZscoreState,
+    "ZSCORE";
+    period: usize
+);
+
+#[inline(always)]
+fn zscore_unchecked<T: TechalibFloat>(value: T, moments: &Moments<T>, period: usize) -> T {
+    if is_constant_unchecked(moments.mean, moments.m2, period) {
+        return T::ZERO;
+    }
+    (value - moments.mean) / (moments.m2 / T::from_usize(period)).sqrt()
+}
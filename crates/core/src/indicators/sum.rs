@@ -0,0 +1,289 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+/*
+    Inspired by TA-LIB SUM implementation
+*/
+
+//! Summation (SUM) implementation
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// SUM calculation result
+/// ---
+/// This struct holds the result and the state ([`SumState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated rolling sums.
+/// - `state`: A [`SumState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct SumResult<T = Float> {
+    /// The calculated rolling sums.
+    pub values: Vec<T>,
+    /// A [`SumState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: SumState<T>,
+}
+
+/// SUM calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `sum`: The last calculated rolling sum.
+///
+/// **State values**
+/// - `last_window`: A ring buffer containing the last `period` values used for
+///   the SUM calculation.
+///
+/// **Parameters**
+/// - `period`: The number of values summed.
+#[derive(Debug, Clone)]
+pub struct SumState<T = Float> {
+    // Outputs
+    /// The last calculated rolling sum.
+    pub sum: T,
+
+    // State values
+    /// A ring buffer containing the last `period` values used for
+    /// the SUM calculation.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The number of values summed.
+    pub period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for SumState<T> {
+    /// Update the [`SumState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the SUM state
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        if self.period <= 1 {
+            return Err(TechalibError::BadParam(
+                "SUM period must be greater than 1".to_string(),
+            ));
+        }
+        if !sample.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
+        }
+        if !self.sum.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.sum = {:?}",
+                self.sum
+            )));
+        }
+        if self.last_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "SUM state last_window length ({}) does not match period ({})",
+                self.last_window.len(),
+                self.period
+            )));
+        }
+
+        for (idx, &value) in self.last_window.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(TechalibError::DataNonFinite(format!(
+                    "window[{idx}] = {value:?}"
+                )));
+            }
+        }
+
+        let old_value = self
+            .last_window
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
+
+        let sum = sum_next_unchecked(sample, old_value, self.sum);
+        if !sum.is_finite() {
+            return Err(TechalibError::Overflow(0, sum.to_f64()));
+        }
+        self.sum = sum;
+        self.last_window.push(sample);
+
+        Ok(())
+    }
+}
+
+/// Lookback period for SUM calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the SUM values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
+/// Calculation of the SUM function
+/// ---
+/// It returns a [`SumResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The number of values summed.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SumResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn sum<T: TechalibFloat>(data: &[T], period: usize) -> Result<SumResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let sum_state = sum_into(data, period, output.as_mut_slice())?;
+    Ok(SumResult {
+        values: output,
+        state: sum_state,
+    })
+}
+
+/// Calculation of the SUM function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`SumState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The number of values summed.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated rolling sums
+///   will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SumState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn sum_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<SumState<T>, TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(format!(
+            "Period must be greater than 1, got: {}",
+            period
+        )));
+    }
+
+    let len = data.len();
+    let lookback = lookback_from_period(period);
+
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    output[lookback] = init_sum_unchecked(data, period, output)?;
+    if !output[lookback].is_finite() {
+        return Err(TechalibError::Overflow(lookback, output[lookback].to_f64()));
+    }
+
+    for idx in period..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        output[idx] = sum_next_unchecked(data[idx], data[idx - period], output[idx - 1]);
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(SumState {
+        sum: output[len - 1],
+        last_window: RingBuffer::from(&data[len - period..len]),
+        period,
+    })
+}
+
+batch_functions!(
+    sum_batch,
+    sum_batch_into,
+    sum,
+    sum_into,
+    SumResult,
+    SumState,
+    "SUM";
+    period: usize
+);
+
+#[inline(always)]
+pub(crate) fn sum_next_unchecked<T: TechalibFloat>(new_value: T, old_value: T, prev_sum: T) -> T {
+    prev_sum + new_value - old_value
+}
+
+#[inline(always)]
+pub(crate) fn init_sum_unchecked<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    output: &mut [T],
+) -> Result<T, TechalibError> {
+    let mut sum: T = T::ZERO;
+    for idx in 0..period {
+        let value = &data[idx];
+        if !value.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {value:?}"
+            )));
+        }
+        sum += *value;
+        output[idx] = T::NAN;
+    }
+    Ok(sum)
+}
@@ -0,0 +1,397 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! SuperTrend (SUPERTREND) implementation
+//!
+//! The basic bands are the median price `(high + low) / 2` shifted by a
+//! multiple of the Average True Range ([`atr`](crate::indicators::atr)).
+//! The final bands only tighten while the trend lasts, and the SuperTrend
+//! line follows the lower band in an uptrend and the upper band in a downtrend.
+//!
+//! The direction is `1.0` for an uptrend and `-1.0` for a downtrend.
+//! As in the TradingView implementation, the first value is in a downtrend.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::atr::{atr_into, lookback_from_period as atr_lookback, AtrState};
+use crate::indicators::price_transform::medprice_unchecked;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Direction of an uptrend
+const UPTREND: f64 = 1.0;
+/// Direction of a downtrend
+const DOWNTREND: f64 = -1.0;
+
+/// SuperTrend calculation result
+/// ---
+/// This struct holds the result of the SuperTrend calculation.
+///
+/// Attributes
+/// ---
+/// - `supertrend`: The SuperTrend line values.
+/// - `direction`: The trend direction, `1.0` for up and `-1.0` for down.
+/// - `state`: A [`SupertrendState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct SupertrendResult<T = Float> {
+    /// The SuperTrend line values.
+    pub supertrend: Vec<T>,
+    /// The trend direction, `1.0` for up and `-1.0` for down.
+    pub direction: Vec<T>,
+    /// A [`SupertrendState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: SupertrendState<T>,
+}
+
+/// SuperTrend calculation state
+/// ---
+/// This struct holds the state of the SuperTrend calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `supertrend`: The last SuperTrend value.
+/// - `direction`: The last trend direction, `1.0` for up and `-1.0` for down.
+///
+/// **State values**
+/// - `final_upper`: The last final upper band.
+/// - `final_lower`: The last final lower band.
+/// - `atr`: The [`AtrState`] of the bands, it also holds the previous close.
+///
+/// **Parameters**
+/// - `multiplier`: The multiplier of the ATR used to calculate the bands.
+#[derive(Debug, Clone, Copy)]
+pub struct SupertrendState<T = Float> {
+    // Outputs
+    /// The last SuperTrend value.
+    pub supertrend: T,
+    /// The last trend direction, `1.0` for up and `-1.0` for down.
+    pub direction: T,
+
+    // State values
+    /// The last final upper band.
+    pub final_upper: T,
+    /// The last final lower band.
+    pub final_lower: T,
+    /// The [`AtrState`] of the bands, it also holds the previous close.
+    pub atr: AtrState<T>,
+
+    // Parameters
+    /// The multiplier of the ATR used to calculate the bands.
+    pub multiplier: T,
+}
+
+impl<T: TechalibFloat> State<(T, T, T)> for SupertrendState<T> {
+    /// Update the [`SupertrendState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, close)` of the new bar.
+    fn update(&mut self, sample: (T, T, T)) -> Result<(), TechalibError> {
+        check_multiplier_unchecked(self.multiplier)?;
+        if self.direction != T::from_f64(UPTREND) && self.direction != T::from_f64(DOWNTREND) {
+            return Err(TechalibError::BadParam(format!(
+                "SUPERTREND direction must be 1.0 or -1.0, got: {:?}",
+                self.direction
+            )));
+        }
+        if !self.final_upper.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.final_upper = {:?}",
+                self.final_upper
+            )));
+        }
+        if !self.final_lower.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.final_lower = {:?}",
+                self.final_lower
+            )));
+        }
+
+        let (high, low, close) = sample;
+        let prev_close = self.atr.prev_close;
+        let mut atr = self.atr;
+        atr.update(sample)?;
+
+        let (basic_upper, basic_lower) = basic_bands_unchecked(high, low, atr.atr, self.multiplier);
+        let (final_upper, final_lower) = final_bands_unchecked(
+            basic_upper,
+            basic_lower,
+            self.final_upper,
+            self.final_lower,
+            prev_close,
+        );
+        let direction = direction_unchecked(close, final_upper, final_lower, self.direction);
+        let supertrend = supertrend_unchecked(final_upper, final_lower, direction);
+        if !supertrend.is_finite() {
+            return Err(TechalibError::Overflow(0, supertrend.to_f64()));
+        }
+
+        self.supertrend = supertrend;
+        self.direction = direction;
+        self.final_upper = final_upper;
+        self.final_lower = final_lower;
+        self.atr = atr;
+        Ok(())
+    }
+}
+
+/// Lookback period for SuperTrend calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the SuperTrend values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    atr_lookback(period)
+}
+
+/// Calculation of the SuperTrend function
+/// ---
+/// It returns a [`SupertrendResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period of the ATR.
+/// - `multiplier`: The multiplier of the ATR used to calculate the bands.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SupertrendResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn supertrend<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    multiplier: T,
+) -> Result<SupertrendResult<T>, TechalibError> {
+    let len = ohlcv.close.len();
+    let mut output_supertrend = vec![T::ZERO; len];
+    let mut output_direction = vec![T::ZERO; len];
+
+    let supertrend_state = supertrend_into(
+        ohlcv,
+        period,
+        multiplier,
+        output_supertrend.as_mut_slice(),
+        output_direction.as_mut_slice(),
+    )?;
+
+    Ok(SupertrendResult {
+        supertrend: output_supertrend,
+        direction: output_direction,
+        state: supertrend_state,
+    })
+}
+
+/// Calculation of the SuperTrend function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`SupertrendState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high`, `low` and `close` columns are used.
+/// - `period`: The period of the ATR.
+/// - `multiplier`: The multiplier of the ATR used to calculate the bands.
+///
+/// Output Arguments
+/// ---
+/// - `output_supertrend`: A mutable slice to store the SuperTrend values.
+/// - `output_direction`: A mutable slice to store the trend direction.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SupertrendState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn supertrend_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    multiplier: T,
+    output_supertrend: &mut [T],
+    output_direction: &mut [T],
+) -> Result<SupertrendState<T>, TechalibError> {
+    check_multiplier_unchecked(multiplier)?;
+
+    let (high, low, close) = (ohlcv.high, ohlcv.low, ohlcv.close);
+    let len = columns_len(&[("high", high), ("low", low), ("close", close)])?;
+    if output_supertrend.len() < len || output_direction.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    // The ATR is stored in the supertrend output until the line is calculated
+    let atr = atr_into(ohlcv, period, output_supertrend)?;
+    let lookback = lookback_from_period(period);
+
+    output_direction[..lookback].fill(T::NAN);
+
+    let (mut final_upper, mut final_lower) = basic_bands_unchecked(
+        high[lookback],
+        low[lookback],
+        output_supertrend[lookback],
+        multiplier,
+    );
+    output_direction[lookback] = T::from_f64(DOWNTREND);
+    output_supertrend[lookback] =
+        supertrend_unchecked(final_upper, final_lower, output_direction[lookback]);
+    if !output_supertrend[lookback].is_finite() {
+        return Err(TechalibError::Overflow(
+            lookback,
+            output_supertrend[lookback].to_f64(),
+        ));
+    }
+
+    for idx in lookback + 1..len {
+        let (basic_upper, basic_lower) =
+            basic_bands_unchecked(high[idx], low[idx], output_supertrend[idx], multiplier);
+        (final_upper, final_lower) = final_bands_unchecked(
+            basic_upper,
+            basic_lower,
+            final_upper,
+            final_lower,
+            close[idx - 1],
+        );
+        output_direction[idx] = direction_unchecked(
+            close[idx],
+            final_upper,
+            final_lower,
+            output_direction[idx - 1],
+        );
+        output_supertrend[idx] =
+            supertrend_unchecked(final_upper, final_lower, output_direction[idx]);
+        if !output_supertrend[idx].is_finite() {
+            return Err(TechalibError::Overflow(
+                idx,
+                output_supertrend[idx].to_f64(),
+            ));
+        }
+    }
+
+    Ok(SupertrendState {
+        supertrend: output_supertrend[len - 1],
+        direction: output_direction[len - 1],
+        final_upper,
+        final_lower,
+        atr,
+        multiplier,
+    })
+}
+
+batch_functions!(
+    supertrend_batch,
+    supertrend_batch_into,
+    supertrend,
+    supertrend_into,
+    SupertrendResult,
+    SupertrendState,
+    "SuperTrend",
+    ohlcv -> [output_supertrend, output_direction];
+    period: usize, multiplier: T
+);
+
+#[inline(always)]
+fn basic_bands_unchecked<T: TechalibFloat>(high: T, low: T, atr: T, multiplier: T) -> (T, T) {
+    let middle = medprice_unchecked(high, low);
+    let width = multiplier * atr;
+    (middle + width, middle - width)
+}
+
+/// The upper band can only go down (and the lower band only go up),
+/// unless the previous close crossed it.
+#[inline(always)]
+fn final_bands_unchecked<T: TechalibFloat>(
+    basic_upper: T,
+    basic_lower: T,
+    prev_final_upper: T,
+    prev_final_lower: T,
+    prev_close: T,
+) -> (T, T) {
+    let final_upper = if basic_upper < prev_final_upper || prev_close > prev_final_upper {
+        basic_upper
+    } else {
+        prev_final_upper
+    };
+    let final_lower = if basic_lower > prev_final_lower || prev_close < prev_final_lower {
+        basic_lower
+    } else {
+        prev_final_lower
+    };
+    (final_upper, final_lower)
+}
+
+#[inline(always)]
+fn direction_unchecked<T: TechalibFloat>(
+    close: T,
+    final_upper: T,
+    final_lower: T,
+    prev_direction: T,
+) -> T {
+    if prev_direction == T::from_f64(DOWNTREND) {
+        if close > final_upper {
+            T::from_f64(UPTREND)
+        } else {
+            T::from_f64(DOWNTREND)
+        }
+    } else if close < final_lower {
+        T::from_f64(DOWNTREND)
+    } else {
+        T::from_f64(UPTREND)
+    }
+}
+
+#[inline(always)]
+fn supertrend_unchecked<T: TechalibFloat>(final_upper: T, final_lower: T, direction: T) -> T {
+    if direction == T::from_f64(UPTREND) {
+        final_lower
+    } else {
+        final_upper
+    }
+}
+
+#[inline(always)]
+fn check_multiplier_unchecked<T: TechalibFloat>(multiplier: T) -> Result<(), TechalibError> {
+    if !multiplier.is_finite() || multiplier <= T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "Multiplier must be a finite value greater than 0, got: {multiplier:?}"
+        )));
+    }
+    Ok(())
+}
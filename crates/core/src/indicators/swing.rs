@@ -0,0 +1,326 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Swing High/Low (SWING) implementation
+//!
+//! A swing high is a bar whose high is strictly greater than the highs of
+//! the `left` previous bars, and greater than or equal to the highs of the
+//! `right` next bars, so that a flat top is marked on its first bar.
+//! The swing lows are defined in the same way from the lows.
+//! With `left = right = 2`, these are the Williams fractals.
+//!
+//! A swing is only confirmed `right` bars after the bar of the swing. The
+//! outputs are aligned on the bar of the swing, so the `right` last values are
+//! always `NaN`. The state reports the swings confirmed by the last bar
+//! ([`SwingState::confirmed_high`] and [`SwingState::confirmed_low`]).
+
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+
+/// Swing High/Low calculation result
+/// ---
+/// This struct holds the result of the Swing High/Low calculation.
+///
+/// Attributes
+/// ---
+/// - `swing_high`: The high of the swing highs, on the bar of the swing.
+/// - `swing_low`: The low of the swing lows, on the bar of the swing.
+/// - `state`: A [`SwingState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct SwingResult<T = Float> {
+    /// The high of the swing highs, on the bar of the swing.
+    pub swing_high: Vec<T>,
+    /// The low of the swing lows, on the bar of the swing.
+    pub swing_low: Vec<T>,
+    /// A [`SwingState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: SwingState<T>,
+}
+
+/// Swing High/Low calculation state
+/// ---
+/// This struct holds the state of the Swing High/Low calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `confirmed_high`: The swing high confirmed by the last bar,
+///   located `right` bars before it, `NaN` if none.
+/// - `confirmed_low`: The swing low confirmed by the last bar,
+///   located `right` bars before it, `NaN` if none.
+///
+/// **State values**
+/// - `high_window`: The `left + right + 1` last highs.
+/// - `low_window`: The `left + right + 1` last lows.
+///
+/// **Parameters**
+/// - `left`: The number of bars before the swing.
+/// - `right`: The number of bars after the swing.
+#[derive(Debug, Clone)]
+pub struct SwingState<T = Float> {
+    // Outputs
+    /// The swing high confirmed by the last bar, located `right` bars before it,
+    /// `NaN` if none.
+    pub confirmed_high: T,
+    /// The swing low confirmed by the last bar, located `right` bars before it,
+    /// `NaN` if none.
+    pub confirmed_low: T,
+
+    // State values
+    /// The `left + right + 1` last highs.
+    pub high_window: RingBuffer<T>,
+    /// The `left + right + 1` last lows.
+    pub low_window: RingBuffer<T>,
+
+    // Parameters
+    /// The number of bars before the swing.
+    pub left: usize,
+    /// The number of bars after the swing.
+    pub right: usize,
+}
+
+impl<T: TechalibFloat> State<(T, T)> for SwingState<T> {
+    /// Update the [`SwingState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low)` of the new bar.
+    fn update(&mut self, sample: (T, T)) -> Result<(), TechalibError> {
+        check_param_unchecked(self.left, self.right)?;
+        let window_len = self.left + self.right + 1;
+        if self.high_window.len() != window_len || self.low_window.len() != window_len {
+            return Err(TechalibError::BadParam(format!(
+                "SWING windows must have a length of {window_len}, got: high_window ({}), low_window ({})",
+                self.high_window.len(),
+                self.low_window.len()
+            )));
+        }
+
+        let (high, low) = sample;
+        if !high.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("high = {high:?}")));
+        }
+        if !low.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("low = {low:?}")));
+        }
+
+        self.high_window.push(high);
+        self.low_window.push(low);
+
+        self.confirmed_high = swing_high_unchecked(self.high_window.make_contiguous(), self.left);
+        self.confirmed_low = swing_low_unchecked(self.low_window.make_contiguous(), self.left);
+        Ok(())
+    }
+}
+
+/// Lookback period for Swing High/Low calculation
+/// ---
+/// With `n = lookback_from_period(left, right)`, the first swing
+/// can be confirmed on the bar `n`, as the `left` first values and the
+/// `right` last values that will be return will be `NaN`.
+#[inline(always)]
+pub fn lookback_from_period(left: usize, right: usize) -> usize {
+    left + right
+}
+
+/// Calculation of the Swing High/Low function
+/// ---
+/// It returns a [`SwingResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high` and `low` columns are used.
+/// - `left`: The number of bars before the swing.
+/// - `right`: The number of bars after the swing.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SwingResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn swing<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    left: usize,
+    right: usize,
+) -> Result<SwingResult<T>, TechalibError> {
+    let len = ohlcv.high.len();
+    let mut output_swing_high = vec![T::ZERO; len];
+    let mut output_swing_low = vec![T::ZERO; len];
+
+    let swing_state = swing_into(
+        ohlcv,
+        left,
+        right,
+        output_swing_high.as_mut_slice(),
+        output_swing_low.as_mut_slice(),
+    )?;
+
+    Ok(SwingResult {
+        swing_high: output_swing_high,
+        swing_low: output_swing_low,
+        state: swing_state,
+    })
+}
+
+/// Calculation of the Swing High/Low function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`SwingState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high` and `low` columns are used.
+/// - `left`: The number of bars before the swing.
+/// - `right`: The number of bars after the swing.
+///
+/// Output Arguments
+/// ---
+/// - `output_swing_high`: A mutable slice to store the swing highs.
+/// - `output_swing_low`: A mutable slice to store the swing lows.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`SwingState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn swing_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    left: usize,
+    right: usize,
+    output_swing_high: &mut [T],
+    output_swing_low: &mut [T],
+) -> Result<SwingState<T>, TechalibError> {
+    check_param_unchecked(left, right)?;
+
+    let (high, low) = (ohlcv.high, ohlcv.low);
+    let len = columns_len(&[("high", high), ("low", low)])?;
+    if output_swing_high.len() < len || output_swing_low.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_period(left, right);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    for idx in 0..len {
+        if !high[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "high[{idx}] = {:?}",
+                high[idx]
+            )));
+        }
+        if !low[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "low[{idx}] = {:?}",
+                low[idx]
+            )));
+        }
+    }
+
+    output_swing_high[..len].fill(T::NAN);
+    output_swing_low[..len].fill(T::NAN);
+    for idx in left..len - right {
+        let window = idx - left..=idx + right;
+        output_swing_high[idx] = swing_high_unchecked(&high[window.clone()], left);
+        output_swing_low[idx] = swing_low_unchecked(&low[window], left);
+    }
+
+    let window = len - lookback - 1..len;
+    Ok(SwingState {
+        confirmed_high: output_swing_high[len - right - 1],
+        confirmed_low: output_swing_low[len - right - 1],
+        high_window: RingBuffer::from(high[window.clone()].to_vec()),
+        low_window: RingBuffer::from(low[window].to_vec()),
+        left,
+        right,
+    })
+}
+
+batch_functions!(
+    swing_batch,
+    swing_batch_into,
+    swing,
+    swing_into,
+    SwingResult,
+    SwingState,
+    "Swing High/Low",
+    ohlcv -> [output_swing_high, output_swing_low];
+    left: usize, right: usize
+);
+
+/// Returns the value at `left` in the window if it is a swing high, `NaN` otherwise.
+#[inline(always)]
+fn swing_high_unchecked<T: TechalibFloat>(window: &[T], left: usize) -> T {
+    swing_unchecked(window, left, |candidate, value| candidate > value)
+}
+
+/// Returns the value at `left` in the window if it is a swing low, `NaN` otherwise.
+#[inline(always)]
+fn swing_low_unchecked<T: TechalibFloat>(window: &[T], left: usize) -> T {
+    swing_unchecked(window, left, |candidate, value| candidate < value)
+}
+
+#[inline(always)]
+fn swing_unchecked<T: TechalibFloat>(window: &[T], left: usize, beats: impl Fn(T, T) -> bool) -> T {
+    let candidate = window[left];
+    let is_swing = window[..left].iter().all(|&value| beats(candidate, value))
+        && window[left + 1..]
+            .iter()
+            .all(|&value| !beats(value, candidate));
+    if is_swing {
+        candidate
+    } else {
+        T::NAN
+    }
+}
+
+#[inline(always)]
+fn check_param_unchecked(left: usize, right: usize) -> Result<(), TechalibError> {
+    if left == 0 || right == 0 {
+        return Err(TechalibError::BadParam(format!(
+            "SWING left and right must be greater than 0, got: left ({left}), right ({right})"
+        )));
+    }
+    Ok(())
+}
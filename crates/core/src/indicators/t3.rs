@@ -40,11 +40,13 @@
 
 //! Tillson Triple Exponential Moving Average (T3) implementation
 
+use crate::batch::batch_functions;
 use crate::errors::TechalibError;
 use crate::indicators::ema::{ema_next_unchecked, get_alpha_value};
 use crate::indicators::sma::init_sma_unchecked;
 use crate::traits::State;
-use crate::types::Float;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, vec, vec::Vec};
 
 /// T3 calculation result
 /// ---
@@ -57,11 +59,11 @@ use crate::types::Float;
 /// - `state`: A [`T3State`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct T3Result {
+pub struct T3Result<T = Float> {
     /// The calculated T3 values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// The [`T3State`] state of the T3 calculation.
-    pub state: T3State,
+    pub state: T3State<T>,
 }
 
 /// T3 calculation state
@@ -85,55 +87,60 @@ pub struct T3Result {
 /// - `period`: The period used for the T3 calculation.
 /// - `volume_factor`: The volume_factor used for the T3 calculation.
 #[derive(Debug, Clone, Copy)]
-pub struct T3State {
+pub struct T3State<T = Float> {
     // Outputs
     /// The last calculated T3 value.
-    pub t3: Float,
+    pub t3: T,
 
     // State values
     /// The last calculated EMA values.
-    pub ema_values: T3EmaValues,
+    pub ema_values: T3EmaValues<T>,
 
     // Parameters
     /// The period used for the T3 calculation.
     pub period: usize,
     /// The alpha value used for the T3 calculation.
-    pub alpha: Float,
+    pub alpha: T,
     /// The volume_factor used for the T3 calculation.
     /// The volume_factor is a smoothing factor that affects the responsiveness of the T3.
     /// It is typically set to a value between 0.0 and 1.0.
     /// A common value is 0.7, but it can be adjusted based on the desired sensitivity.
-    pub volume_factor: Float,
+    pub volume_factor: T,
 
     /// The T3 coefficients used in the T3 calculation.
     /// These coefficients are derived from the volume_factor and are used in the T3 calculation.
-    pub t3_coefficients: T3Coefficients,
+    pub t3_coefficients: T3Coefficients<T>,
 }
 
 /// Coefficients used in the T3 calculation
 ///
 /// These coefficients are derived from the volume_factor and are used in the T3 calculation.
 #[derive(Debug, Clone, Copy)]
-pub struct T3Coefficients {
+pub struct T3Coefficients<T = Float> {
     /// The c1 coefficient used in the T3 calculation: `c1 = -volume_factor³`
-    pub c1: Float,
+    pub c1: T,
     /// The c2 coefficient used in the T3 calculation: `c2 = 3 * volume_factor² + 3 * volume_factor³`
-    pub c2: Float,
+    pub c2: T,
     /// The c3 coefficient used in the T3 calculation: `c3 = - 3 * volume_factor - 6 * volume_factor² - 3 * volume_factor³`
-    pub c3: Float,
+    pub c3: T,
     /// The c4 coefficient used in the T3 calculation: `c4 = 1 + 3 * volume_factor + 3 * volume_factor² + volume_factor³`
-    pub c4: Float,
+    pub c4: T,
 }
 
-impl T3Coefficients {
-    fn new(volume_factor: Float) -> Self {
+impl<T: TechalibFloat> T3Coefficients<T> {
+    fn new(volume_factor: T) -> Self {
         let vfactor_square = volume_factor.powi(2);
         let vfactor_cube = volume_factor.powi(3);
         T3Coefficients {
             c1: -vfactor_cube,
-            c2: 3.0 * vfactor_square + 3.0 * vfactor_cube,
-            c3: -3.0 * volume_factor - 6.0 * vfactor_square - 3.0 * vfactor_cube,
-            c4: 1.0 + 3.0 * volume_factor + 3.0 * vfactor_square + vfactor_cube,
+            c2: T::from_f64(3.0) * vfactor_square + T::from_f64(3.0) * vfactor_cube,
+            c3: -T::from_f64(3.0) * volume_factor
+                - T::from_f64(6.0) * vfactor_square
+                - T::from_f64(3.0) * vfactor_cube,
+            c4: T::ONE
+                + T::from_f64(3.0) * volume_factor
+                + T::from_f64(3.0) * vfactor_square
+                + vfactor_cube,
         }
     }
 }
@@ -142,28 +149,28 @@ impl T3Coefficients {
 ///
 /// These values are used to store the last calculated EMA values
 #[derive(Debug, Clone, Copy)]
-pub struct T3EmaValues {
+pub struct T3EmaValues<T = Float> {
     /// Represent the 1st order EMA value
-    pub ema1: Float,
+    pub ema1: T,
     /// Represent the 2nd order EMA value
-    pub ema2: Float,
+    pub ema2: T,
     /// Represent the 3rd order EMA value
-    pub ema3: Float,
+    pub ema3: T,
     /// Represent the 4th order EMA value
-    pub ema4: Float,
+    pub ema4: T,
     /// Represent the 5th order EMA value
-    pub ema5: Float,
+    pub ema5: T,
     /// Represent the 6th order EMA value
-    pub ema6: Float,
+    pub ema6: T,
 }
 
-impl State<Float> for T3State {
+impl<T: TechalibFloat> State<T> for T3State<T> {
     /// Update the [`T3State`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the T3 state
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if !sample.is_finite() {
             return Err(TechalibError::DataNonFinite(
                 format!("sample = {sample:?}",),
@@ -211,7 +218,10 @@ impl State<Float> for T3State {
             )));
         }
 
-        if !self.volume_factor.is_finite() || self.volume_factor < 0.0 || self.volume_factor > 1.0 {
+        if !self.volume_factor.is_finite()
+            || self.volume_factor < T::ZERO
+            || self.volume_factor > T::ONE
+        {
             return Err(TechalibError::BadParam(format!(
                 "Volume factor must be between 0.0 and 1.0, got: {}",
                 self.volume_factor
@@ -226,7 +236,7 @@ impl State<Float> for T3State {
         );
 
         if !t3.is_finite() {
-            return Err(TechalibError::Overflow(0, t3));
+            return Err(TechalibError::Overflow(0, t3.to_f64()));
         }
 
         self.t3 = t3;
@@ -251,13 +261,13 @@ impl State<Float> for T3State {
 /// ---
 /// A `Result` containing a [`T3Result`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn t3(
-    data: &[Float],
+pub fn t3<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    volume_factor: Float,
-    alpha: Option<Float>,
-) -> Result<T3Result, TechalibError> {
-    let mut output = vec![0.0; data.len()];
+    volume_factor: T,
+    alpha: Option<T>,
+) -> Result<T3Result<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
 
     let t3_state = t3_into(data, period, volume_factor, alpha, output.as_mut_slice())?;
 
@@ -287,13 +297,13 @@ pub fn t3(
 /// ---
 /// A `Result` containing a [`T3State`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn t3_into(
-    data: &[Float],
+pub fn t3_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    volume_factor: Float,
-    alpha: Option<Float>,
-    output: &mut [Float],
-) -> Result<T3State, TechalibError> {
+    volume_factor: T,
+    alpha: Option<T>,
+    output: &mut [T],
+) -> Result<T3State<T>, TechalibError> {
     let len = data.len();
     let skip_period = t3_skip_period_unchecked(period);
 
@@ -308,7 +318,7 @@ pub fn t3_into(
         )));
     }
 
-    if !volume_factor.is_finite() || !(0.0..=1.0).contains(&volume_factor) {
+    if !volume_factor.is_finite() || !(T::ZERO..=T::ONE).contains(&volume_factor) {
         return Err(TechalibError::BadParam(format!(
             "Volume factor must be between 0.0 and 1.0, got: {}",
             volume_factor
@@ -323,7 +333,7 @@ pub fn t3_into(
         data,
         period,
         &t3_coefficients,
-        1.0 / period as Float,
+        T::ONE / T::from_usize(period),
         skip_period,
         alpha,
         output,
@@ -331,7 +341,10 @@ pub fn t3_into(
 
     output[skip_period] = t3;
     if !output[skip_period].is_finite() {
-        return Err(TechalibError::Overflow(skip_period, output[skip_period]));
+        return Err(TechalibError::Overflow(
+            skip_period,
+            output[skip_period].to_f64(),
+        ));
     }
 
     for idx in skip_period + 1..len {
@@ -343,7 +356,7 @@ pub fn t3_into(
         }
         output[idx] = t3_next_unchecked(data[idx], &mut t3_ema_values, &t3_coefficients, alpha);
         if !output[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output[idx]));
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
         }
     }
 
@@ -357,13 +370,24 @@ pub fn t3_into(
     })
 }
 
+batch_functions!(
+    t3_batch,
+    t3_batch_into,
+    t3,
+    t3_into,
+    T3Result,
+    T3State,
+    "T3";
+    period: usize, volume_factor: T, alpha: Option<T>
+);
+
 #[inline(always)]
-fn t3_next_unchecked(
-    new_value: Float,
-    ema_values: &mut T3EmaValues,
-    t3_coefficients: &T3Coefficients,
-    alpha: Float,
-) -> Float {
+fn t3_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    ema_values: &mut T3EmaValues<T>,
+    t3_coefficients: &T3Coefficients<T>,
+    alpha: T,
+) -> T {
     ema_values.ema1 = ema_next_unchecked(new_value, ema_values.ema1, alpha);
     ema_values.ema2 = ema_next_unchecked(ema_values.ema1, ema_values.ema2, alpha);
     ema_values.ema3 = ema_next_unchecked(ema_values.ema2, ema_values.ema3, alpha);
@@ -383,15 +407,15 @@ fn t3_next_unchecked(
 }
 
 #[inline(always)]
-fn init_t3_unchecked(
-    data: &[Float],
+fn init_t3_unchecked<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    t3_coefficients: &T3Coefficients,
-    inv_period: Float,
+    t3_coefficients: &T3Coefficients<T>,
+    inv_period: T,
     skip_period: usize,
-    alpha: Float,
-    output: &mut [Float],
-) -> Result<(Float, T3EmaValues), TechalibError> {
+    alpha: T,
+    output: &mut [T],
+) -> Result<(T, T3EmaValues<T>), TechalibError> {
     // Initialiaztion of ema1
     let mut ema1 = init_sma_unchecked(data, period, inv_period, output)?;
 
@@ -407,7 +431,7 @@ fn init_t3_unchecked(
         }
         ema1 = ema_next_unchecked(data[idx], ema1, alpha);
         sum_ema2 += ema1;
-        output[idx] = Float::NAN;
+        output[idx] = T::NAN;
     }
     let mut ema2 = sum_ema2 * inv_period;
 
@@ -424,7 +448,7 @@ fn init_t3_unchecked(
         ema1 = ema_next_unchecked(data[idx], ema1, alpha);
         ema2 = ema_next_unchecked(ema1, ema2, alpha);
         sum_ema3 += ema2;
-        output[idx] = Float::NAN;
+        output[idx] = T::NAN;
     }
     let mut ema3 = sum_ema3 * inv_period;
 
@@ -442,7 +466,7 @@ fn init_t3_unchecked(
         ema2 = ema_next_unchecked(ema1, ema2, alpha);
         ema3 = ema_next_unchecked(ema2, ema3, alpha);
         sum_ema4 += ema3;
-        output[idx] = Float::NAN;
+        output[idx] = T::NAN;
     }
     let mut ema4 = sum_ema4 * inv_period;
 
@@ -461,7 +485,7 @@ fn init_t3_unchecked(
         ema3 = ema_next_unchecked(ema2, ema3, alpha);
         ema4 = ema_next_unchecked(ema3, ema4, alpha);
         sum_ema5 += ema4;
-        output[idx] = Float::NAN;
+        output[idx] = T::NAN;
     }
     let mut ema5 = sum_ema5 * inv_period;
 
@@ -480,7 +504,7 @@ fn init_t3_unchecked(
         ema4 = ema_next_unchecked(ema3, ema4, alpha);
         ema5 = ema_next_unchecked(ema4, ema5, alpha);
         sum_ema6 += ema5;
-        output[idx] = Float::NAN;
+        output[idx] = T::NAN;
     }
     ema1 = ema_next_unchecked(data[skip_period], ema1, alpha);
     ema2 = ema_next_unchecked(ema1, ema2, alpha);
@@ -522,16 +546,16 @@ pub fn t3_skip_period_unchecked(period: usize) -> usize {
 
 #[inline(always)]
 #[allow(clippy::too_many_arguments)]
-fn t3_from_coefficients_unchecked(
-    ema3: Float,
-    ema4: Float,
-    ema5: Float,
-    ema6: Float,
-    c1: Float,
-    c2: Float,
-    c3: Float,
-    c4: Float,
-) -> Float {
+fn t3_from_coefficients_unchecked<T: TechalibFloat>(
+    ema3: T,
+    ema4: T,
+    ema5: T,
+    ema6: T,
+    c1: T,
+    c2: T,
+    c3: T,
+    c4: T,
+) -> T {
     c1 * ema6 + c2 * ema5 + c3 * ema4 + c4 * ema3
 }
 
@@ -40,14 +40,16 @@
 
 //! Triple Exponential Moving Average (TEMA) implementation
 
+use crate::batch::batch_functions;
 use crate::errors::TechalibError;
 use crate::indicators::dema::{
     dema_next_unchecked, dema_skip_period_unchecked, init_dema_unchecked,
 };
 use crate::indicators::ema::{ema_next_unchecked, get_alpha_value};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 use crate::traits::State;
-use crate::types::Float;
+use crate::types::{Float, TechalibFloat};
 
 /// TEMA calculation result
 /// ---
@@ -60,12 +62,12 @@ use crate::types::Float;
 /// - `state`: A [`TemaState`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct TemaResult {
+pub struct TemaResult<T = Float> {
     /// The calculated TEMA values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// A [`TemaState`], which can be used to calculate the next values
     /// incrementally.
-    pub state: TemaState,
+    pub state: TemaState<T>,
 }
 
 /// TEMA calculation state
@@ -91,34 +93,34 @@ pub struct TemaResult {
 /// - `alpha`: The alpha factor used in the TEMA calculation,
 ///   which is traditionally calculated as `smoothing / (period + 1)`.
 #[derive(Debug, Clone, Copy)]
-pub struct TemaState {
+pub struct TemaState<T = Float> {
     // Outputs
     /// The last calculated Triple Exponential Moving Average (TEMA) value.
-    pub tema: Float,
+    pub tema: T,
 
     // State values
     /// The last calculated Exponential Moving Average (EMA) value for the EMA.
-    pub ema_1: Float,
+    pub ema_1: T,
     /// The last calculated Exponential Moving Average (EMA) value for the EMA2.
-    pub ema_2: Float,
+    pub ema_2: T,
     /// The last calculated Exponential Moving Average (EMA) value for the EMA3.
-    pub ema_3: Float,
+    pub ema_3: T,
 
     // Parameters
     /// The period used for the TEMA calculation.
     pub period: usize,
     /// The alpha factor used in the TEMA calculation,
     /// which is traditionally calculated as `smoothing / (period + 1)`.
-    pub alpha: Float,
+    pub alpha: T,
 }
 
-impl State<Float> for TemaState {
+impl<T: TechalibFloat> State<T> for TemaState<T> {
     /// Update the [`TemaState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the TEMA state
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if self.period <= 1 {
             return Err(TechalibError::BadParam(
                 "Period must be greater than 1".to_string(),
@@ -163,7 +165,7 @@ impl State<Float> for TemaState {
             tema_next_unchecked(sample, self.ema_1, self.ema_2, self.ema_3, self.alpha);
 
         if !tema.is_finite() {
-            return Err(TechalibError::Overflow(0, tema));
+            return Err(TechalibError::Overflow(0, tema.to_f64()));
         }
 
         self.tema = tema;
@@ -187,12 +189,12 @@ impl State<Float> for TemaState {
 /// ---
 /// A `Result` containing a [`TemaResult`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn tema(
-    data: &[Float],
+pub fn tema<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    alpha: Option<Float>,
-) -> Result<TemaResult, TechalibError> {
-    let mut output = vec![0.0; data.len()];
+    alpha: Option<T>,
+) -> Result<TemaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
 
     let tema_state = tema_into(data, period, alpha, &mut output)?;
 
@@ -223,14 +225,14 @@ pub fn tema(
 /// ---
 /// A `Result` containing a [`TemaState`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn tema_into(
-    data: &[Float],
+pub fn tema_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    alpha: Option<Float>,
-    output: &mut [Float],
-) -> Result<TemaState, TechalibError> {
+    alpha: Option<T>,
+    output: &mut [T],
+) -> Result<TemaState<T>, TechalibError> {
     let len = data.len();
-    let inv_period = 1.0 / period as Float;
+    let inv_period = T::ONE / T::from_usize(period);
     let skip_period = tema_skip_period_unchecked(period);
 
     if period == 0 || len < skip_period + 1 {
@@ -248,7 +250,10 @@ pub fn tema_into(
         init_tema_unchecked(data, period, inv_period, skip_period, alpha, output)?;
     output[skip_period] = output_value;
     if !output[skip_period].is_finite() {
-        return Err(TechalibError::Overflow(skip_period, output[skip_period]));
+        return Err(TechalibError::Overflow(
+            skip_period,
+            output[skip_period].to_f64(),
+        ));
     }
 
     for idx in skip_period + 1..len {
@@ -263,7 +268,7 @@ pub fn tema_into(
             tema_next_unchecked(data[idx], ema_1, ema_2, ema_3, alpha);
 
         if !output[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output[idx]));
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
         }
     }
 
@@ -277,14 +282,25 @@ pub fn tema_into(
     })
 }
 
+batch_functions!(
+    tema_batch,
+    tema_batch_into,
+    tema,
+    tema_into,
+    TemaResult,
+    TemaState,
+    "TEMA";
+    period: usize, alpha: Option<T>
+);
+
 #[inline(always)]
-pub(crate) fn tema_next_unchecked(
-    new_value: Float,
-    prev_ema_1: Float,
-    prev_ema_2: Float,
-    prev_ema_3: Float,
-    alpha: Float,
-) -> (Float, Float, Float, Float) {
+pub(crate) fn tema_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    prev_ema_1: T,
+    prev_ema_2: T,
+    prev_ema_3: T,
+    alpha: T,
+) -> (T, T, T, T) {
     let ema_1 = ema_next_unchecked(new_value, prev_ema_1, alpha);
     let ema_2 = ema_next_unchecked(ema_1, prev_ema_2, alpha);
     let ema_3 = ema_next_unchecked(ema_2, prev_ema_3, alpha);
@@ -292,18 +308,18 @@ pub(crate) fn tema_next_unchecked(
 }
 
 #[inline(always)]
-pub(crate) fn init_tema_unchecked(
-    data: &[Float],
+pub(crate) fn init_tema_unchecked<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    inv_period: Float,
+    inv_period: T,
     skip_period: usize,
-    alpha: Float,
-    output: &mut [Float],
-) -> Result<(Float, Float, Float, Float), TechalibError> {
+    alpha: T,
+    output: &mut [T],
+) -> Result<(T, T, T, T), TechalibError> {
     let dema_skip_period = dema_skip_period_unchecked(period);
     let (_, mut ema_1, mut ema_2) =
         init_dema_unchecked(data, period, inv_period, dema_skip_period, alpha, output)?;
-    output[dema_skip_period] = Float::NAN;
+    output[dema_skip_period] = T::NAN;
 
     let mut sum_ema_3 = ema_2;
     for idx in dema_skip_period + 1..skip_period {
@@ -315,7 +331,7 @@ pub(crate) fn init_tema_unchecked(
         }
         (_, ema_1, ema_2) = dema_next_unchecked(data[idx], ema_1, ema_2, alpha);
         sum_ema_3 += ema_2;
-        output[idx] = Float::NAN;
+        output[idx] = T::NAN;
     }
     (_, ema_1, ema_2) = dema_next_unchecked(data[skip_period], ema_1, ema_2, alpha);
     sum_ema_3 += ema_2;
@@ -325,8 +341,8 @@ pub(crate) fn init_tema_unchecked(
 }
 
 #[inline(always)]
-fn calculate_tema(ema_1: Float, ema_2: Float, ema_3: Float) -> Float {
-    (3.0 * ema_1) - (3.0 * ema_2) + ema_3
+fn calculate_tema<T: TechalibFloat>(ema_1: T, ema_2: T, ema_3: T) -> T {
+    (T::from_f64(3.0) * ema_1) - (T::from_f64(3.0) * ema_2) + ema_3
 }
 
 /// Calculate the period to skip for TEMA.
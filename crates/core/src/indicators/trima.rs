@@ -40,10 +40,14 @@
 
 //! Triangular Moving Average (TRIMA) implementation
 
+use crate::batch::batch_functions;
 use crate::errors::TechalibError;
+use crate::precision;
+use crate::ring_buffer::RingBuffer;
+use crate::simd;
 use crate::traits::State;
-use crate::types::Float;
-use std::collections::VecDeque;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 /// TRIMA calculation result
 /// ---
@@ -56,12 +60,12 @@ use std::collections::VecDeque;
 /// - `state`: A [`TrimaState`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct TrimaResult {
+pub struct TrimaResult<T = Float> {
     /// The calculated TRIMA values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// A [`TrimaState`], which can be used to calculate
     /// the next values incrementally.
-    pub state: TrimaState,
+    pub state: TrimaState<T>,
 }
 
 /// TRIMA calculation state
@@ -80,7 +84,7 @@ pub struct TrimaResult {
 ///   the current window. It is used to optimize the calculation of the TRIMA.
 /// - `heading_sum`: The sum of the second half of the values in the current window.
 ///   It is used to optimize the calculation of the TRIMA.
-/// - `last_window`: A deque containing the last `period` values used for
+/// - `last_window`: A ring buffer containing the last `period` values used for
 ///   the TRIMA calculation.
 ///
 /// **Parameters**
@@ -88,38 +92,38 @@ pub struct TrimaResult {
 /// - `period`: The period used for the TRIMA calculation, which determines
 ///   how many values are averaged to compute the TRIMA.
 #[derive(Debug, Clone)]
-pub struct TrimaState {
+pub struct TrimaState<T = Float> {
     // Outputs
     /// The last calculated TRIMA value
-    pub trima: Float,
+    pub trima: T,
 
     // State values
     /// The weighted sum of the values in the current window
-    pub weighted_sum: Float,
+    pub weighted_sum: T,
     /// The sum of the first half of the values in the current window.
-    pub trailing_sum: Float,
+    pub trailing_sum: T,
     /// The sum of the second half of the values in the current window.
-    pub heading_sum: Float,
-    /// A deque containing the last `period` values used for
-    pub last_window: VecDeque<Float>,
+    pub heading_sum: T,
+    /// A ring buffer containing the last `period` values used for
+    pub last_window: RingBuffer<T>,
 
     // Parameters
     /// The inverse of the sum of weights used in the TRIMA calculation
     /// It is calculated as `1.0 / ((period // 2) * (period // 2 + 1))` for even periods
     /// and `1.0 / ((period // 2 + 1) * (period // 2 + 1))` for odd periods.
     /// This value is used to optimize the calculation of the TRIMA.
-    pub inv_weight_sum: Float,
+    pub inv_weight_sum: T,
     /// The period used for the TRIMA calculation, which determines
     pub period: usize,
 }
 
-impl State<Float> for TrimaState {
+impl<T: TechalibFloat> State<T> for TrimaState<T> {
     /// Update the [`TrimaState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the TRIMA state
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
         if self.period <= 1 {
             return Err(TechalibError::BadParam(
                 "TRIMA period must be greater than 1".to_string(),
@@ -149,22 +153,31 @@ impl State<Float> for TrimaState {
         }
         let is_odd = self.period % 2 != 0;
 
-        let mut window = self.last_window.clone();
-
-        let old_value = window.pop_front().ok_or(TechalibError::InsufficientData)?;
-        window.push_back(sample);
-        let vec = Vec::from(window.clone());
         let middle_idx = get_middle_idx(self.period);
-        let middle_value = vec[middle_idx];
+        let (weighted_sum, trailing_sum, heading_sum) =
+            if precision::resync_due(self.last_window.head(), self.period) {
+                trima_window_sums(self.last_window.make_contiguous(), middle_idx)
+            } else {
+                (self.weighted_sum, self.trailing_sum, self.heading_sum)
+            };
+
+        let old_value = self
+            .last_window
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
+        let middle_value = self
+            .last_window
+            .get(middle_idx + 1)
+            .ok_or(TechalibError::InsufficientData)?;
 
         let (trima, new_weighted_sum, new_trailing_sum, new_heading_sum) = if is_odd {
             trima_next_odd_unchecked(
                 sample,
                 middle_value,
                 old_value,
-                self.weighted_sum,
-                self.trailing_sum,
-                self.heading_sum,
+                weighted_sum,
+                trailing_sum,
+                heading_sum,
                 self.inv_weight_sum,
             )
         } else {
@@ -172,22 +185,22 @@ impl State<Float> for TrimaState {
                 sample,
                 middle_value,
                 old_value,
-                self.weighted_sum,
-                self.trailing_sum,
-                self.heading_sum,
+                weighted_sum,
+                trailing_sum,
+                heading_sum,
                 self.inv_weight_sum,
             )
         };
 
         if !trima.is_finite() {
-            return Err(TechalibError::Overflow(0, trima));
+            return Err(TechalibError::Overflow(0, trima.to_f64()));
         }
 
         self.trima = trima;
         self.weighted_sum = new_weighted_sum;
         self.trailing_sum = new_trailing_sum;
         self.heading_sum = new_heading_sum;
-        self.last_window = window;
+        self.last_window.push(sample);
         Ok(())
     }
 }
@@ -205,9 +218,9 @@ impl State<Float> for TrimaState {
 /// ---
 /// A `Result` containing a [`TrimaResult`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn trima(data: &[Float], period: usize) -> Result<TrimaResult, TechalibError> {
+pub fn trima<T: TechalibFloat>(data: &[T], period: usize) -> Result<TrimaResult<T>, TechalibError> {
     let len = data.len();
-    let mut output = vec![0.0; len];
+    let mut output = vec![T::ZERO; len];
     let trima_state = trima_into(data, period, &mut output)?;
     Ok(TrimaResult {
         values: output,
@@ -233,11 +246,11 @@ pub fn trima(data: &[Float], period: usize) -> Result<TrimaResult, TechalibError
 /// ---
 /// A `Result` containing a [`TrimaState`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn trima_into(
-    data: &[Float],
+pub fn trima_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    output: &mut [Float],
-) -> Result<TrimaState, TechalibError> {
+    output: &mut [T],
+) -> Result<TrimaState<T>, TechalibError> {
     let len = data.len();
     let is_odd = period % 2 != 0;
     if period == 0 || period > len {
@@ -261,7 +274,10 @@ pub fn trima_into(
 
     output[period - 1] = trima;
     if !output[period - 1].is_finite() {
-        return Err(TechalibError::Overflow(period - 1, output[period - 1]));
+        return Err(TechalibError::Overflow(
+            period - 1,
+            output[period - 1].to_f64(),
+        ));
     }
     middle_idx += 1;
 
@@ -273,6 +289,10 @@ pub fn trima_into(
                     data[idx]
                 )));
             }
+            if precision::resync_due(idx, period) {
+                (sum, trailing_sum, heading_sum) =
+                    trima_window_sums(&data[idx - period..idx], get_middle_idx(period));
+            }
             (output[idx], sum, trailing_sum, heading_sum) = trima_next_odd_unchecked(
                 data[idx],
                 data[middle_idx],
@@ -283,7 +303,7 @@ pub fn trima_into(
                 inv_weight_sum,
             );
             if !output[idx].is_finite() {
-                return Err(TechalibError::Overflow(idx, output[idx]));
+                return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
             }
             middle_idx += 1;
         }
@@ -295,6 +315,10 @@ pub fn trima_into(
                     data[idx]
                 )));
             }
+            if precision::resync_due(idx, period) {
+                (sum, trailing_sum, heading_sum) =
+                    trima_window_sums(&data[idx - period..idx], get_middle_idx(period));
+            }
             (output[idx], sum, trailing_sum, heading_sum) = trima_next_even_unchecked(
                 data[idx],
                 data[middle_idx],
@@ -305,7 +329,7 @@ pub fn trima_into(
                 inv_weight_sum,
             );
             if !output[idx].is_finite() {
-                return Err(TechalibError::Overflow(idx, output[idx]));
+                return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
             }
             middle_idx += 1;
         }
@@ -316,22 +340,33 @@ pub fn trima_into(
         weighted_sum: sum,
         trailing_sum,
         heading_sum,
-        last_window: VecDeque::from(data[len - period..len].to_vec()),
+        last_window: RingBuffer::from(&data[len - period..len]),
         inv_weight_sum,
         period,
     })
 }
 
+batch_functions!(
+    trima_batch,
+    trima_batch_into,
+    trima,
+    trima_into,
+    TrimaResult,
+    TrimaState,
+    "TRIMA";
+    period: usize
+);
+
 #[inline(always)]
-fn trima_next_even_unchecked(
-    new_value: Float,
-    middle_value: Float,
-    old_value: Float,
-    sum: Float,
-    trailing_sum: Float,
-    heading_sum: Float,
-    inv_weight_sum: Float,
-) -> (Float, Float, Float, Float) {
+fn trima_next_even_unchecked<T: TechalibFloat>(
+    new_value: T,
+    middle_value: T,
+    old_value: T,
+    sum: T,
+    trailing_sum: T,
+    heading_sum: T,
+    inv_weight_sum: T,
+) -> (T, T, T, T) {
     let new_trailing_sum = trailing_sum - old_value + middle_value;
     let new_heading_sum = heading_sum - middle_value + new_value;
     let new_sum = sum - trailing_sum + new_heading_sum;
@@ -344,15 +379,15 @@ fn trima_next_even_unchecked(
 }
 
 #[inline(always)]
-fn trima_next_odd_unchecked(
-    new_value: Float,
-    middle_value: Float,
-    old_value: Float,
-    sum: Float,
-    trailing_sum: Float,
-    heading_sum: Float,
-    inv_weight_sum: Float,
-) -> (Float, Float, Float, Float) {
+fn trima_next_odd_unchecked<T: TechalibFloat>(
+    new_value: T,
+    middle_value: T,
+    old_value: T,
+    sum: T,
+    trailing_sum: T,
+    heading_sum: T,
+    inv_weight_sum: T,
+) -> (T, T, T, T) {
     let new_trailing_sum = trailing_sum - old_value + middle_value;
     let new_heading_sum = heading_sum - middle_value + new_value;
     let new_sum = sum - trailing_sum + new_heading_sum + middle_value;
@@ -365,40 +400,23 @@ fn trima_next_odd_unchecked(
 }
 
 #[inline(always)]
-fn init_trima_unchecked(
-    data: &[Float],
+fn init_trima_unchecked<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    output: &mut [Float],
-) -> Result<(Float, Float, Float, Float, Float, usize), TechalibError> {
+    output: &mut [T],
+) -> Result<(T, T, T, T, T, usize), TechalibError> {
     let middle_idx = get_middle_idx(period);
-    let mut trailing_sum: Float = 0.0;
-    let mut heading_sum: Float = 0.0;
-    let mut sum: Float = 0.0;
     let inv_weight_sum = trima_inv_weight_sum(period)?;
-    for idx in 0..=middle_idx {
-        let weight = (idx + 1) as Float;
-        let value = &data[idx];
-        if !value.is_finite() {
-            return Err(TechalibError::DataNonFinite(format!(
-                "data_array[{idx}] = {value:?}"
-            )));
-        }
-        trailing_sum += value;
-        sum += value * weight;
-        output[idx] = Float::NAN;
-    }
-    for (local_idx, idx) in (middle_idx + 1..period).rev().enumerate() {
-        let weight = (local_idx + 1) as Float;
-        let value = &data[idx];
-        if !value.is_finite() {
+    let (sum, trailing_sum, heading_sum) = trima_window_sums(&data[..period], middle_idx);
+    if !sum.is_finite() || !trailing_sum.is_finite() || !heading_sum.is_finite() {
+        if let Some(idx) = simd::first_non_finite(&data[..period]) {
             return Err(TechalibError::DataNonFinite(format!(
-                "data_array[{idx}] = {value:?}"
+                "data_array[{idx}] = {:?}",
+                data[idx]
             )));
         }
-        heading_sum += value;
-        sum += value * weight;
-        output[idx] = Float::NAN;
     }
+    output[..period].fill(T::NAN);
 
     Ok((
         sum * inv_weight_sum,
@@ -410,17 +428,35 @@ fn init_trima_unchecked(
     ))
 }
 
-fn trima_inv_weight_sum(period: usize) -> Result<Float, TechalibError> {
+/// Weighted sum, trailing sum and heading sum of a full window
+#[inline(always)]
+fn trima_window_sums<T: TechalibFloat>(window: &[T], middle_idx: usize) -> (T, T, T) {
+    let period = window.len();
+    let (trailing_sum, trailing_weighted_sum) =
+        simd::weighted_sums(&window[..=middle_idx], T::ONE, T::ONE);
+    let (heading_sum, heading_weighted_sum) = simd::weighted_sums(
+        &window[middle_idx + 1..],
+        T::from_usize(period - middle_idx - 1),
+        -T::ONE,
+    );
+    (
+        trailing_weighted_sum + heading_weighted_sum,
+        trailing_sum,
+        heading_sum,
+    )
+}
+
+fn trima_inv_weight_sum<T: TechalibFloat>(period: usize) -> Result<T, TechalibError> {
     if period <= 1 {
         return Err(TechalibError::BadParam(
             "TRIMA period must be greater than 1".to_string(),
         ));
     }
-    let p = (period / 2) as Float;
+    let p = T::from_usize(period / 2);
     if period % 2 == 0 {
-        Ok(1.0 / (p * (p + 1.0)))
+        Ok(T::ONE / (p * (p + T::ONE)))
     } else {
-        Ok(1.0 / ((p + 1.0) * (p + 1.0)))
+        Ok(T::ONE / ((p + T::ONE) * (p + T::ONE)))
     }
 }
 
@@ -0,0 +1,348 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Variable Index Dynamic Average (VIDYA) implementation
+//!
+//! Chande's exponential moving average whose alpha is scaled at each step
+//! by the absolute value of the Chande Momentum Oscillator (CMO):
+//! `alpha = 2 / (period + 1) × |CMO(cmo_period)|`. It is seeded with the
+//! simple moving average of the `period` values ending on the first bar
+//! where the CMO is defined.
+
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::ema::{ema_next_unchecked, period_to_alpha};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+
+/// Default period of the Chande Momentum Oscillator.
+pub const DEFAULT_CMO_PERIOD: usize = 9;
+
+/// VIDYA calculation result
+/// ---
+/// This struct holds the result and the state ([`VidyaState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated VIDYA values.
+/// - `state`: A [`VidyaState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct VidyaResult<T = Float> {
+    /// The calculated VIDYA values.
+    pub values: Vec<T>,
+    /// A [`VidyaState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: VidyaState<T>,
+}
+
+/// VIDYA calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `vidya`: The last calculated VIDYA value.
+///
+/// **State values**
+/// - `up_sum`: The sum of the positive changes over the CMO period.
+/// - `down_sum`: The sum of the negative changes (as positive values) over the CMO period.
+/// - `prev_value`: The last input value.
+/// - `last_changes`: A ring buffer containing the last `cmo_period` changes.
+///
+/// **Parameters**
+/// - `period`: The period of the exponential smoothing.
+/// - `cmo_period`: The period of the Chande Momentum Oscillator.
+#[derive(Debug, Clone)]
+pub struct VidyaState<T = Float> {
+    // Outputs
+    /// The last calculated VIDYA value.
+    pub vidya: T,
+
+    // State values
+    /// The sum of the positive changes over the CMO period.
+    pub up_sum: T,
+    /// The sum of the negative changes (as positive values) over the CMO period.
+    pub down_sum: T,
+    /// The last input value.
+    pub prev_value: T,
+    /// A ring buffer containing the last `cmo_period` changes.
+    pub last_changes: RingBuffer<T>,
+
+    // Parameters
+    /// The period of the exponential smoothing.
+    pub period: usize,
+    /// The period of the Chande Momentum Oscillator.
+    pub cmo_period: usize,
+}
+
+impl<T: TechalibFloat> State<T> for VidyaState<T> {
+    /// Update the [`VidyaState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the VIDYA state.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        check_param_unchecked(self.period, self.cmo_period)?;
+        if self.last_changes.len() != self.cmo_period {
+            return Err(TechalibError::BadParam(format!(
+                "VIDYA state changes length ({}) does not match cmo_period ({})",
+                self.last_changes.len(),
+                self.cmo_period
+            )));
+        }
+        if !sample.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
+        }
+        if !self.vidya.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.vidya = {:?}",
+                self.vidya
+            )));
+        }
+        if !self.prev_value.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.prev_value = {:?}",
+                self.prev_value
+            )));
+        }
+
+        let change = sample - self.prev_value;
+        let old_change = self
+            .last_changes
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
+
+        let (up_sum, down_sum) =
+            cmo_sums_next_unchecked(self.up_sum, self.down_sum, change, old_change);
+        let alpha =
+            period_to_alpha::<T>(self.period, None)? * cmo_unchecked(up_sum, down_sum).abs();
+        let vidya = ema_next_unchecked(sample, self.vidya, alpha);
+        if !vidya.is_finite() {
+            return Err(TechalibError::Overflow(0, vidya.to_f64()));
+        }
+
+        self.vidya = vidya;
+        self.up_sum = up_sum;
+        self.down_sum = down_sum;
+        self.prev_value = sample;
+        self.last_changes.push(change);
+        Ok(())
+    }
+}
+
+/// Lookback period for VIDYA calculation
+/// ---
+/// With `n = lookback_from_period(period, cmo_period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the VIDYA values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize, cmo_period: usize) -> usize {
+    (period - 1).max(cmo_period)
+}
+
+/// Calculation of the VIDYA function
+/// ---
+/// It returns a [`VidyaResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period of the exponential smoothing.
+/// - `cmo_period`: The period of the Chande Momentum Oscillator
+///   (default [`DEFAULT_CMO_PERIOD`]).
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`VidyaResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn vidya<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    cmo_period: usize,
+) -> Result<VidyaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let vidya_state = vidya_into(data, period, cmo_period, output.as_mut_slice())?;
+    Ok(VidyaResult {
+        values: output,
+        state: vidya_state,
+    })
+}
+
+/// Calculation of the VIDYA function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`VidyaState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period of the exponential smoothing.
+/// - `cmo_period`: The period of the Chande Momentum Oscillator
+///   (default [`DEFAULT_CMO_PERIOD`]).
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated VIDYA values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`VidyaState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn vidya_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    cmo_period: usize,
+    output: &mut [T],
+) -> Result<VidyaState<T>, TechalibError> {
+    check_param_unchecked(period, cmo_period)?;
+    let len = data.len();
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    let lookback = lookback_from_period(period, cmo_period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let base_alpha = period_to_alpha::<T>(period, None)?;
+    let (mut up_sum, mut down_sum) = (T::ZERO, T::ZERO);
+    let mut sum = T::ZERO;
+    for idx in 0..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        if idx > 0 {
+            let old_change = if idx > cmo_period {
+                data[idx - cmo_period] - data[idx - cmo_period - 1]
+            } else {
+                T::ZERO
+            };
+            (up_sum, down_sum) =
+                cmo_sums_next_unchecked(up_sum, down_sum, data[idx] - data[idx - 1], old_change);
+        }
+
+        if idx < lookback {
+            if idx + period > lookback {
+                sum += data[idx];
+            }
+            output[idx] = T::NAN;
+            continue;
+        }
+        output[idx] = if idx == lookback {
+            (sum + data[idx]) / T::from_usize(period)
+        } else {
+            let alpha = base_alpha * cmo_unchecked(up_sum, down_sum).abs();
+            ema_next_unchecked(data[idx], output[idx - 1], alpha)
+        };
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(VidyaState {
+        vidya: output[len - 1],
+        up_sum,
+        down_sum,
+        prev_value: data[len - 1],
+        last_changes: (len - cmo_period..len)
+            .map(|idx| data[idx] - data[idx - 1])
+            .collect(),
+        period,
+        cmo_period,
+    })
+}
+
+batch_functions!(
+    vidya_batch,
+    vidya_batch_into,
+    vidya,
+    vidya_into,
+    VidyaResult,
+    VidyaState,
+    "VIDYA";
+    period: usize, cmo_period: usize
+);
+
+/// Rolls the sums of the positive and negative changes, the `old_change`
+/// leaving the CMO window.
+#[inline(always)]
+fn cmo_sums_next_unchecked<T: TechalibFloat>(
+    up_sum: T,
+    down_sum: T,
+    change: T,
+    old_change: T,
+) -> (T, T) {
+    (
+        up_sum + change.max(T::ZERO) - old_change.max(T::ZERO),
+        down_sum + (-change).max(T::ZERO) - (-old_change).max(T::ZERO),
+    )
+}
+
+#[inline(always)]
+fn cmo_unchecked<T: TechalibFloat>(up_sum: T, down_sum: T) -> T {
+    let total = up_sum + down_sum;
+    if total <= T::ZERO {
+        return T::ZERO;
+    }
+    (up_sum - down_sum) / total
+}
+
+#[inline(always)]
+fn check_param_unchecked(period: usize, cmo_period: usize) -> Result<(), TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(
+            "VIDYA period must be greater than 1".to_string(),
+        ));
+    }
+    if cmo_period == 0 {
+        return Err(TechalibError::BadParam(
+            "VIDYA cmo_period must be greater than 0".to_string(),
+        ));
+    }
+    Ok(())
+}
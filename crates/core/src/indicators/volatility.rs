@@ -0,0 +1,533 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Historical Volatility (VOLATILITY) implementation
+//!
+//! The volatility is estimated over a rolling window of `period` bars with
+//! one of the [`VolatilityEstimator`], and annualised as
+//! `sqrt(variance × annualization)`, `annualization` being the number of
+//! bars in a year ([`DEFAULT_ANNUALIZATION`] for daily bars).
+//!
+//! With `o`, `h`, `l` and `c` the logarithms of the open, high, low and close
+//! of a bar, and `c'` the logarithm of the previous close:
+//! - [`VolatilityEstimator::CloseToClose`]: sample variance of `c - c'`.
+//! - [`VolatilityEstimator::Parkinson`]: mean of `(h - l)²` divided by `4 ln(2)`.
+//! - [`VolatilityEstimator::GarmanKlass`]: mean of
+//!   `(h - l)² / 2 - (2 ln(2) - 1)(c - o)²`.
+//! - [`VolatilityEstimator::RogersSatchell`]: mean of
+//!   `(h - c)(h - o) + (l - c)(l - o)`.
+//! - [`VolatilityEstimator::YangZhang`]: `σo² + k σc² + (1 - k) σrs²`, with
+//!   `σo²` the sample variance of the overnight returns `o - c'`, `σc²` the
+//!   sample variance of the open to close returns `c - o`, `σrs²` the
+//!   Rogers-Satchell variance and `k = 0.34 / (1.34 + (n + 1) / (n - 1))`.
+//!
+//! The estimators using the previous close have one more `NaN` value at
+//! the start. The sample variances are rolled with the numerically stable
+//! updates of the [`stats`](crate::indicators::stats) module.
+
+use alloc::collections::VecDeque;
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::stats::Moments;
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+
+/// Default annualization factor, the number of trading days in a year
+pub const DEFAULT_ANNUALIZATION: Float = 252.0;
+
+const LN_2: f64 = core::f64::consts::LN_2;
+
+/// Historical volatility estimator
+/// ---
+/// See the [module documentation](self) for the formulas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VolatilityEstimator {
+    /// Standard deviation of the close to close log returns.
+    CloseToClose,
+    /// Parkinson (1980) high-low estimator.
+    Parkinson,
+    /// Garman-Klass (1980) open-high-low-close estimator.
+    GarmanKlass,
+    /// Rogers-Satchell (1991) drift independent estimator.
+    RogersSatchell,
+    /// Yang-Zhang (2000) estimator, including the overnight returns.
+    YangZhang,
+}
+
+impl VolatilityEstimator {
+    /// Whether the estimator uses the close of the previous bar
+    #[inline(always)]
+    fn uses_prev_close(self) -> bool {
+        matches!(
+            self,
+            VolatilityEstimator::CloseToClose | VolatilityEstimator::YangZhang
+        )
+    }
+
+    /// Number of per-bar terms rolled by the estimator
+    #[inline(always)]
+    fn terms_count(self) -> usize {
+        match self {
+            VolatilityEstimator::YangZhang => 3,
+            _ => 1,
+        }
+    }
+
+    /// Minimum window of the estimator
+    #[inline(always)]
+    fn min_period(self) -> usize {
+        if self.uses_prev_close() {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+/// Historical Volatility calculation result
+/// ---
+/// This struct holds the result of the Historical Volatility calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: The annualised volatility values.
+/// - `state`: A [`VolatilityState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct VolatilityResult<T = Float> {
+    /// The annualised volatility values.
+    pub values: Vec<T>,
+    /// A [`VolatilityState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: VolatilityState<T>,
+}
+
+/// Historical Volatility calculation state
+/// ---
+/// This struct holds the state of the Historical Volatility calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `volatility`: The last annualised volatility value.
+///
+/// **State values**
+/// - `prev_close`: The close of the last bar.
+/// - `last_window`: The per-bar terms of the last `period` bars. The Yang-Zhang
+///   estimator uses the three terms (overnight, open to close and
+///   Rogers-Satchell), the other estimators only the first one.
+///
+/// **Parameters**
+/// - `period`: The size of the window.
+/// - `estimator`: The [`VolatilityEstimator`].
+/// - `annualization`: The number of bars in a year.
+#[derive(Debug, Clone)]
+pub struct VolatilityState<T = Float> {
+    // Outputs
+    /// The last annualised volatility value.
+    pub volatility: T,
+
+    // State values
+    /// The close of the last bar.
+    pub prev_close: T,
+    /// The per-bar terms of the last `period` bars.
+    pub last_window: RingBuffer<[T; 3]>,
+
+    // Parameters
+    /// The size of the window.
+    pub period: usize,
+    /// The [`VolatilityEstimator`].
+    pub estimator: VolatilityEstimator,
+    /// The number of bars in a year.
+    pub annualization: T,
+}
+
+impl<T: TechalibFloat> State<(T, T, T, T)> for VolatilityState<T> {
+    /// Update the [`VolatilityState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(open, high, low, close)` of the new bar. The columns
+    ///   which are not used by the estimator are ignored.
+    fn update(&mut self, sample: (T, T, T, T)) -> Result<(), TechalibError> {
+        check_param_unchecked(self.period, self.estimator, self.annualization)?;
+        if self.last_window.len() != self.period {
+            return Err(TechalibError::BadParam(format!(
+                "VOLATILITY state last_window length ({}) does not match period ({})",
+                self.last_window.len(),
+                self.period
+            )));
+        }
+        let (open, high, low, close) = sample;
+        check_bar_unchecked(0, self.estimator, open, high, low, close)?;
+        if self.estimator.uses_prev_close() {
+            check_price_unchecked(0, "prev_close", self.prev_close)?;
+        }
+
+        let new_terms = terms_unchecked(self.estimator, open, high, low, close, self.prev_close);
+        let window = || {
+            self.last_window
+                .iter()
+                .skip(1)
+                .chain(core::iter::once(&new_terms))
+        };
+
+        let mut moments = [Moments::default(); 3];
+        for (term, moment) in moments
+            .iter_mut()
+            .enumerate()
+            .take(self.estimator.terms_count())
+        {
+            *moment = Moments::from_window_unchecked(window().map(|terms| terms[term]));
+        }
+        let volatility =
+            volatility_unchecked(self.estimator, &moments, self.period, self.annualization);
+        if !volatility.is_finite() {
+            return Err(TechalibError::Overflow(0, volatility.to_f64()));
+        }
+
+        self.volatility = volatility;
+        self.prev_close = close;
+        self.last_window.push(new_terms);
+        Ok(())
+    }
+}
+
+/// Lookback period for Historical Volatility calculation
+/// ---
+/// With `n = lookback_from_period(period, estimator)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the volatility values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize, estimator: VolatilityEstimator) -> usize {
+    period - 1 + estimator.uses_prev_close() as usize
+}
+
+/// Calculation of the Historical Volatility function
+/// ---
+/// It returns a [`VolatilityResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the close to close estimator only uses the
+///   `close` column, the Parkinson estimator the `high` and `low` columns and
+///   the other estimators the `open`, `high`, `low` and `close` columns.
+/// - `period`: The size of the window.
+/// - `estimator`: The [`VolatilityEstimator`].
+/// - `annualization`: The number of bars in a year ([`DEFAULT_ANNUALIZATION`]
+///   for daily bars, `1.0` for a volatility per bar).
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`VolatilityResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn volatility<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    estimator: VolatilityEstimator,
+    annualization: T,
+) -> Result<VolatilityResult<T>, TechalibError> {
+    let len = match estimator {
+        VolatilityEstimator::Parkinson => ohlcv.high.len(),
+        _ => ohlcv.close.len(),
+    };
+    let mut output = vec![T::ZERO; len];
+
+    let volatility_state = volatility_into(
+        ohlcv,
+        period,
+        estimator,
+        annualization,
+        output.as_mut_slice(),
+    )?;
+
+    Ok(VolatilityResult {
+        values: output,
+        state: volatility_state,
+    })
+}
+
+/// Calculation of the Historical Volatility function
+/// ---
+/// It stores the results in the provided output array and
+/// return the state [`VolatilityState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the close to close estimator only uses the
+///   `close` column, the Parkinson estimator the `high` and `low` columns and
+///   the other estimators the `open`, `high`, `low` and `close` columns.
+/// - `period`: The size of the window.
+/// - `estimator`: The [`VolatilityEstimator`].
+/// - `annualization`: The number of bars in a year ([`DEFAULT_ANNUALIZATION`]
+///   for daily bars, `1.0` for a volatility per bar).
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice to store the annualised volatility values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`VolatilityState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn volatility_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    period: usize,
+    estimator: VolatilityEstimator,
+    annualization: T,
+    output: &mut [T],
+) -> Result<VolatilityState<T>, TechalibError> {
+    check_param_unchecked(period, estimator, annualization)?;
+
+    let len = match estimator {
+        VolatilityEstimator::CloseToClose => columns_len(&[("close", ohlcv.close)])?,
+        VolatilityEstimator::Parkinson => columns_len(&[("high", ohlcv.high), ("low", ohlcv.low)])?,
+        _ => columns_len(&[
+            ("open", ohlcv.open),
+            ("high", ohlcv.high),
+            ("low", ohlcv.low),
+            ("close", ohlcv.close),
+        ])?,
+    };
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_period(period, estimator);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let column = |values: &[T], idx: usize| values.get(idx).copied().unwrap_or(T::ONE);
+    let first = estimator.uses_prev_close() as usize;
+    let terms_count = estimator.terms_count();
+    let mut moments = [Moments::default(); 3];
+    let mut last_window = VecDeque::with_capacity(period + 1);
+    for (idx, out) in output[..len].iter_mut().enumerate() {
+        let (open, high, low, close) = (
+            column(ohlcv.open, idx),
+            column(ohlcv.high, idx),
+            column(ohlcv.low, idx),
+            column(ohlcv.close, idx),
+        );
+        check_bar_unchecked(idx, estimator, open, high, low, close)?;
+        if idx < first {
+            *out = T::NAN;
+            continue;
+        }
+
+        let prev_close = if first == 1 {
+            ohlcv.close[idx - 1]
+        } else {
+            T::ONE
+        };
+        let terms = terms_unchecked(estimator, open, high, low, close, prev_close);
+        last_window.push_back(terms);
+        let count = idx - first;
+        if count < period {
+            for (term, moment) in moments.iter_mut().enumerate().take(terms_count) {
+                moment.push_unchecked(count, terms[term]);
+            }
+        } else {
+            let old_terms = last_window
+                .pop_front()
+                .ok_or(TechalibError::InsufficientData)?;
+            for (term, moment) in moments.iter_mut().enumerate().take(terms_count) {
+                moment.roll_unchecked(
+                    period,
+                    last_window.iter().map(|terms| terms[term]),
+                    old_terms[term],
+                    terms[term],
+                    count % period == 0,
+                );
+            }
+        }
+
+        if idx < lookback {
+            *out = T::NAN;
+            continue;
+        }
+        *out = volatility_unchecked(estimator, &moments, period, annualization);
+        if !out.is_finite() {
+            return Err(TechalibError::Overflow(idx, (*out).to_f64()));
+        }
+    }
+
+    Ok(VolatilityState {
+        volatility: output[len - 1],
+        prev_close: column(ohlcv.close, len - 1),
+        last_window: RingBuffer::from(Vec::from(last_window)),
+        period,
+        estimator,
+        annualization,
+    })
+}
+
+batch_functions!(
+    volatility_batch,
+    volatility_batch_into,
+    volatility,
+    volatility_into,
+    VolatilityResult,
+    VolatilityState,
+    "Historical Volatility",
+    ohlcv -> [output];
+    period: usize, estimator: VolatilityEstimator, annualization: T
+);
+
+/// Per-bar terms of the estimator
+#[inline(always)]
+fn terms_unchecked<T: TechalibFloat>(
+    estimator: VolatilityEstimator,
+    open: T,
+    high: T,
+    low: T,
+    close: T,
+    prev_close: T,
+) -> [T; 3] {
+    match estimator {
+        VolatilityEstimator::CloseToClose => [(close / prev_close).ln(), T::ZERO, T::ZERO],
+        VolatilityEstimator::Parkinson => [(high / low).ln().powi(2), T::ZERO, T::ZERO],
+        VolatilityEstimator::GarmanKlass => {
+            let range = (high / low).ln();
+            let body = (close / open).ln();
+            [
+                T::from_f64(0.5) * range * range - T::from_f64(2.0 * LN_2 - 1.0) * body * body,
+                T::ZERO,
+                T::ZERO,
+            ]
+        }
+        VolatilityEstimator::RogersSatchell => [
+            rogers_satchell_unchecked(open, high, low, close),
+            T::ZERO,
+            T::ZERO,
+        ],
+        VolatilityEstimator::YangZhang => [
+            (open / prev_close).ln(),
+            (close / open).ln(),
+            rogers_satchell_unchecked(open, high, low, close),
+        ],
+    }
+}
+
+#[inline(always)]
+fn rogers_satchell_unchecked<T: TechalibFloat>(open: T, high: T, low: T, close: T) -> T {
+    (high / close).ln() * (high / open).ln() + (low / close).ln() * (low / open).ln()
+}
+
+#[inline(always)]
+fn volatility_unchecked<T: TechalibFloat>(
+    estimator: VolatilityEstimator,
+    moments: &[Moments<T>; 3],
+    period: usize,
+    annualization: T,
+) -> T {
+    let n = T::from_usize(period);
+    let variance = match estimator {
+        VolatilityEstimator::CloseToClose => moments[0].m2 / (n - T::ONE),
+        VolatilityEstimator::Parkinson => moments[0].mean / T::from_f64(4.0 * LN_2),
+        VolatilityEstimator::GarmanKlass | VolatilityEstimator::RogersSatchell => moments[0].mean,
+        VolatilityEstimator::YangZhang => {
+            let k = T::from_f64(0.34) / (T::from_f64(1.34) + (n + T::ONE) / (n - T::ONE));
+            (moments[0].m2 + k * moments[1].m2) / (n - T::ONE) + (T::ONE - k) * moments[2].mean
+        }
+    };
+    (variance.max(T::ZERO) * annualization).sqrt()
+}
+
+#[inline(always)]
+fn check_param_unchecked<T: TechalibFloat>(
+    period: usize,
+    estimator: VolatilityEstimator,
+    annualization: T,
+) -> Result<(), TechalibError> {
+    if period < estimator.min_period() {
+        return Err(TechalibError::BadParam(format!(
+            "Period must be greater than or equal to {} for {estimator:?}, got: {period}",
+            estimator.min_period()
+        )));
+    }
+    if !annualization.is_finite() || annualization <= T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "Annualization must be a finite value greater than 0, got: {annualization:?}"
+        )));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn check_bar_unchecked<T: TechalibFloat>(
+    idx: usize,
+    estimator: VolatilityEstimator,
+    open: T,
+    high: T,
+    low: T,
+    close: T,
+) -> Result<(), TechalibError> {
+    match estimator {
+        VolatilityEstimator::CloseToClose => check_price_unchecked(idx, "close", close),
+        VolatilityEstimator::Parkinson => {
+            check_price_unchecked(idx, "high", high)?;
+            check_price_unchecked(idx, "low", low)
+        }
+        _ => {
+            check_price_unchecked(idx, "open", open)?;
+            check_price_unchecked(idx, "high", high)?;
+            check_price_unchecked(idx, "low", low)?;
+            check_price_unchecked(idx, "close", close)
+        }
+    }
+}
+
+#[inline(always)]
+fn check_price_unchecked<T: TechalibFloat>(
+    idx: usize,
+    name: &str,
+    value: T,
+) -> Result<(), TechalibError> {
+    if !value.is_finite() || value <= T::ZERO {
+        return Err(TechalibError::DataNonFinite(format!(
+            "{name}[{idx}] = {value:?}, prices must be finite and greater than 0"
+        )));
+    }
+    Ok(())
+}
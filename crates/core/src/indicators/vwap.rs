@@ -0,0 +1,421 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Volume Weighted Average Price (VWAP) implementation
+//!
+//! The VWAP accumulates the prices weighted by the volumes since the start
+//! of the current session, and the bands are shifted by a multiple of the
+//! volume weighted standard deviation of the prices.
+//!
+//! Sessions are set with a [`VwapReset`]: the accumulation starts again at
+//! every session boundary. The first bar always starts a session.
+//! The weighted variance is updated incrementally (West's weighted version
+//! of Welford's algorithm), which avoids the cancellation of the
+//! `mean(p²) - mean(p)²` formula.
+//!
+//! While the cumulative volume of the session is zero, the outputs are `NaN`.
+
+use crate::errors::TechalibError;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Session boundaries of the VWAP
+/// ---
+/// The accumulation is reset at each bar starting a new session.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum VwapReset<'a> {
+    /// A single session covering all the bars.
+    #[default]
+    Never,
+    /// A mask with the same length as the input: `true` starts a new session.
+    Mask(&'a [bool]),
+    /// Indices of the bars starting a new session, in ascending order.
+    Anchors(&'a [usize]),
+}
+
+/// VWAP calculation result
+/// ---
+/// This struct holds the result of the VWAP calculation.
+///
+/// Attributes
+/// ---
+/// - `vwap`: The VWAP values.
+/// - `upper`: The upper band values.
+/// - `lower`: The lower band values.
+/// - `state`: A [`VwapState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct VwapResult<T = Float> {
+    /// The VWAP values.
+    pub vwap: Vec<T>,
+    /// The upper band values.
+    pub upper: Vec<T>,
+    /// The lower band values.
+    pub lower: Vec<T>,
+    /// A [`VwapState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: VwapState<T>,
+}
+
+/// VWAP calculation state
+/// ---
+/// This struct holds the state of the VWAP calculation.
+/// It is used to calculate the next values in an incremental way.
+/// Call [`VwapState::reset`] before the first bar of a new session.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `vwap`: The last VWAP value.
+/// - `upper`: The last upper band value.
+/// - `lower`: The last lower band value.
+///
+/// **State values**
+/// - `cum_volume`: The cumulative volume of the session.
+/// - `m2`: The volume weighted sum of the squared deviations of the session.
+///
+/// **Parameters**
+/// - `multiplier`: The multiplier of the standard deviation used to calculate the bands.
+#[derive(Debug, Clone, Copy)]
+pub struct VwapState<T = Float> {
+    // Outputs
+    /// The last VWAP value.
+    pub vwap: T,
+    /// The last upper band value.
+    pub upper: T,
+    /// The last lower band value.
+    pub lower: T,
+
+    // State values
+    /// The cumulative volume of the session.
+    pub cum_volume: T,
+    /// The volume weighted sum of the squared deviations of the session.
+    pub m2: T,
+
+    // Parameters
+    /// The multiplier of the standard deviation used to calculate the bands.
+    pub multiplier: T,
+}
+
+impl<T: TechalibFloat> VwapState<T> {
+    /// Start a new session
+    ///
+    /// The next update will be the first bar of the session.
+    pub fn reset(&mut self) {
+        self.vwap = T::NAN;
+        self.upper = T::NAN;
+        self.lower = T::NAN;
+        self.cum_volume = T::ZERO;
+        self.m2 = T::ZERO;
+    }
+}
+
+impl<T: TechalibFloat> State<(T, T)> for VwapState<T> {
+    /// Update the [`VwapState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(price, volume)` of the new bar.
+    fn update(&mut self, sample: (T, T)) -> Result<(), TechalibError> {
+        let (price, volume) = sample;
+        check_multiplier_unchecked(self.multiplier)?;
+        check_sample_unchecked(price, volume)?;
+        if !self.cum_volume.is_finite() || self.cum_volume < T::ZERO {
+            return Err(TechalibError::BadParam(format!(
+                "self.cum_volume must be finite and positive, got: {:?}",
+                self.cum_volume
+            )));
+        }
+        if self.cum_volume > T::ZERO && !(self.vwap.is_finite() && self.m2.is_finite()) {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.vwap = {:?}, self.m2 = {:?}",
+                self.vwap, self.m2
+            )));
+        }
+
+        let (cum_volume, vwap, m2) =
+            vwap_next_unchecked(price, volume, self.cum_volume, self.vwap, self.m2);
+        let (upper, lower) = vwap_bands_unchecked(vwap, cum_volume, m2, self.multiplier);
+        if cum_volume > T::ZERO && !(upper.is_finite() && lower.is_finite()) {
+            return Err(TechalibError::Overflow(0, upper.to_f64()));
+        }
+
+        self.vwap = vwap;
+        self.upper = upper;
+        self.lower = lower;
+        self.cum_volume = cum_volume;
+        self.m2 = m2;
+        Ok(())
+    }
+}
+
+/// Calculation of the VWAP function
+/// ---
+/// It returns a [`VwapResult`]
+///
+/// Input Arguments
+/// ---
+/// - `price`: A slice of [`Float`] representing the prices (the typical price is common).
+/// - `volume`: A slice of [`Float`] representing the volumes.
+/// - `reset`: The [`VwapReset`] session boundaries.
+/// - `multiplier`: The multiplier of the standard deviation used to calculate the bands.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`VwapResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn vwap<T: TechalibFloat>(
+    price: &[T],
+    volume: &[T],
+    reset: VwapReset,
+    multiplier: T,
+) -> Result<VwapResult<T>, TechalibError> {
+    let len = price.len();
+    let mut output_vwap = vec![T::ZERO; len];
+    let mut output_upper = vec![T::ZERO; len];
+    let mut output_lower = vec![T::ZERO; len];
+
+    let vwap_state = vwap_into(
+        price,
+        volume,
+        reset,
+        multiplier,
+        output_vwap.as_mut_slice(),
+        output_upper.as_mut_slice(),
+        output_lower.as_mut_slice(),
+    )?;
+
+    Ok(VwapResult {
+        vwap: output_vwap,
+        upper: output_upper,
+        lower: output_lower,
+        state: vwap_state,
+    })
+}
+
+/// Calculation of the VWAP function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`VwapState`].
+///
+/// Input Arguments
+/// ---
+/// - `price`: A slice of [`Float`] representing the prices (the typical price is common).
+/// - `volume`: A slice of [`Float`] representing the volumes.
+/// - `reset`: The [`VwapReset`] session boundaries.
+/// - `multiplier`: The multiplier of the standard deviation used to calculate the bands.
+///
+/// Output Arguments
+/// ---
+/// - `output_vwap`: A mutable slice to store the VWAP values.
+/// - `output_upper`: A mutable slice to store the upper band values.
+/// - `output_lower`: A mutable slice to store the lower band values.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`VwapState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn vwap_into<T: TechalibFloat>(
+    price: &[T],
+    volume: &[T],
+    reset: VwapReset,
+    multiplier: T,
+    output_vwap: &mut [T],
+    output_upper: &mut [T],
+    output_lower: &mut [T],
+) -> Result<VwapState<T>, TechalibError> {
+    check_multiplier_unchecked(multiplier)?;
+
+    let len = price.len();
+    if len == 0 {
+        return Err(TechalibError::InsufficientData);
+    }
+    if volume.len() != len {
+        return Err(TechalibError::BadParam(format!(
+            "Price and volume must have the same length, got: {} and {}",
+            len,
+            volume.len()
+        )));
+    }
+    if output_vwap.len() < len || output_upper.len() < len || output_lower.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+    match reset {
+        VwapReset::Never => {}
+        VwapReset::Mask(mask) => {
+            if mask.len() != len {
+                return Err(TechalibError::BadParam(format!(
+                    "Reset mask must have the same length as input data, got: {} and {}",
+                    mask.len(),
+                    len
+                )));
+            }
+        }
+        VwapReset::Anchors(anchors) => {
+            if anchors.windows(2).any(|w| w[0] > w[1]) {
+                return Err(TechalibError::BadParam(
+                    "Anchors must be in ascending order".to_string(),
+                ));
+            }
+            if let Some(last) = anchors.last().filter(|last| **last >= len) {
+                return Err(TechalibError::BadParam(format!(
+                    "Anchor {last} is out of the input data (length {len})"
+                )));
+            }
+        }
+    }
+
+    let mut state = VwapState {
+        vwap: T::NAN,
+        upper: T::NAN,
+        lower: T::NAN,
+        cum_volume: T::ZERO,
+        m2: T::ZERO,
+        multiplier,
+    };
+    let mut next_anchor = 0;
+    for idx in 0..len {
+        let is_reset = match reset {
+            VwapReset::Never => false,
+            VwapReset::Mask(mask) => mask[idx],
+            VwapReset::Anchors(anchors) => {
+                let start = next_anchor;
+                while next_anchor < anchors.len() && anchors[next_anchor] == idx {
+                    next_anchor += 1;
+                }
+                next_anchor > start
+            }
+        };
+        if is_reset {
+            state.reset();
+        }
+
+        if !price[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "price[{idx}] = {:?}",
+                price[idx]
+            )));
+        }
+        if !volume[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "volume[{idx}] = {:?}",
+                volume[idx]
+            )));
+        }
+        if volume[idx] < T::ZERO {
+            return Err(TechalibError::BadParam(format!(
+                "Volume must be positive, got: volume[{idx}] = {:?}",
+                volume[idx]
+            )));
+        }
+        (state.cum_volume, state.vwap, state.m2) = vwap_next_unchecked(
+            price[idx],
+            volume[idx],
+            state.cum_volume,
+            state.vwap,
+            state.m2,
+        );
+        (state.upper, state.lower) =
+            vwap_bands_unchecked(state.vwap, state.cum_volume, state.m2, multiplier);
+        if state.cum_volume > T::ZERO && !(state.upper.is_finite() && state.lower.is_finite()) {
+            return Err(TechalibError::Overflow(idx, state.upper.to_f64()));
+        }
+
+        output_vwap[idx] = state.vwap;
+        output_upper[idx] = state.upper;
+        output_lower[idx] = state.lower;
+    }
+
+    Ok(state)
+}
+
+/// Returns the cumulative volume, the VWAP and the weighted sum of the
+/// squared deviations including the new bar.
+#[inline(always)]
+fn vwap_next_unchecked<T: TechalibFloat>(
+    price: T,
+    volume: T,
+    prev_cum_volume: T,
+    prev_vwap: T,
+    prev_m2: T,
+) -> (T, T, T) {
+    let cum_volume = prev_cum_volume + volume;
+    if cum_volume == T::ZERO {
+        return (cum_volume, T::NAN, T::ZERO);
+    }
+    if prev_cum_volume == T::ZERO {
+        return (cum_volume, price, T::ZERO);
+    }
+    let delta = price - prev_vwap;
+    let vwap = prev_vwap + delta * volume / cum_volume;
+    let m2 = prev_m2 + volume * delta * (price - vwap);
+    (cum_volume, vwap, m2)
+}
+
+#[inline(always)]
+fn vwap_bands_unchecked<T: TechalibFloat>(vwap: T, cum_volume: T, m2: T, multiplier: T) -> (T, T) {
+    let width = multiplier * (m2 / cum_volume).max(T::ZERO).sqrt();
+    (vwap + width, vwap - width)
+}
+
+#[inline(always)]
+fn check_sample_unchecked<T: TechalibFloat>(price: T, volume: T) -> Result<(), TechalibError> {
+    if !price.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!("price = {price:?}")));
+    }
+    if !volume.is_finite() {
+        return Err(TechalibError::DataNonFinite(format!("volume = {volume:?}")));
+    }
+    if volume < T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "Volume must be positive, got: {volume:?}"
+        )));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn check_multiplier_unchecked<T: TechalibFloat>(multiplier: T) -> Result<(), TechalibError> {
+    if !multiplier.is_finite() || multiplier <= T::ZERO {
+        return Err(TechalibError::BadParam(format!(
+            "Multiplier must be a finite value greater than 0, got: {multiplier:?}"
+        )));
+    }
+    Ok(())
+}
@@ -40,10 +40,14 @@
 
 //! Weighted Moving Average (WMA) implementation
 
+use crate::batch::batch_functions;
 use crate::errors::TechalibError;
+use crate::precision;
+use crate::ring_buffer::RingBuffer;
+use crate::simd;
 use crate::traits::State;
-use crate::types::Float;
-use std::collections::VecDeque;
+use crate::types::{Float, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
 
 /// WMA calculation result
 /// ---
@@ -56,12 +60,12 @@ use std::collections::VecDeque;
 /// - `state`: A [`WmaState`], which can be used to calculate
 ///   the next values incrementally.
 #[derive(Debug)]
-pub struct WmaResult {
+pub struct WmaResult<T = Float> {
     /// The calculated WMA values.
-    pub values: Vec<Float>,
+    pub values: Vec<T>,
     /// A [`WmaState`], which can be used to calculate
     /// the next values incrementally.
-    pub state: WmaState,
+    pub state: WmaState<T>,
 }
 
 /// WMA calculation state
@@ -77,39 +81,50 @@ pub struct WmaResult {
 /// **State values**
 /// - `period_sub`: The sumation to subtract from the period sum.
 /// - `period_sum`: The weighted sum of the previous window.
-/// - `last_window`: A deque containing the last `period` values used for
+/// - `last_window`: A ring buffer containing the last `period` values used for
 ///   the WMA calculation.
 ///
 /// **Parameters**
 /// - `period`: The period used for the WMA calculation, which determines
 ///   how many values are averaged to compute the WMA.
 #[derive(Debug, Clone)]
-pub struct WmaState {
+pub struct WmaState<T = Float> {
     // Outputs
     /// The last calculated WMA value
-    pub wma: Float,
+    pub wma: T,
 
     // State values
     /// The sumation to subtract from the period sum
-    pub period_sub: Float,
+    pub period_sub: T,
     /// The weighted sum of the previous window
-    pub period_sum: Float,
-    /// A deque containing the last `period` values used for
+    pub period_sum: T,
+    /// A ring buffer containing the last `period` values used for
     /// the WMA calculation
-    pub last_window: VecDeque<Float>,
+    pub last_window: RingBuffer<T>,
 
     // Parameters
     /// The period used for the WMA calculation, which determines
     pub period: usize,
 }
 
-impl State<Float> for WmaState {
+impl<T: TechalibFloat> State<T> for WmaState<T> {
     /// Update the [`WmaState`] with a new sample
     ///
     /// Input Arguments
     /// ---
     /// - `sample`: The new input to update the WMA state
-    fn update(&mut self, sample: Float) -> Result<(), TechalibError> {
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        let next = self.next(sample)?;
+        self.commit_unchecked(sample, next);
+        Ok(())
+    }
+}
+
+impl<T: TechalibFloat> WmaState<T> {
+    /// Checks the state and `sample`, and returns the next `(wma, period_sub,
+    /// period_sum)` without changing the values of the state. It only takes
+    /// `&mut self` to make the window contiguous under `high-precision`.
+    pub(crate) fn next(&mut self, sample: T) -> Result<(T, T, T), TechalibError> {
         if self.period <= 1 {
             return Err(TechalibError::BadParam(
                 "WMA period must be greater than 1".to_string(),
@@ -140,34 +155,53 @@ impl State<Float> for WmaState {
             }
         }
 
-        let mut window = self.last_window.clone();
         let inv_weight_sum = inv_weight_sum_linear(self.period);
+        let (period_sub, period_sum) =
+            if precision::resync_due(self.last_window.head(), self.period) {
+                simd::weighted_sums(self.last_window.make_contiguous(), T::ZERO, T::ONE)
+            } else {
+                (self.period_sub, self.period_sum)
+            };
 
-        let old_value = window.pop_front().ok_or(TechalibError::InsufficientData)?;
-        window.push_back(sample);
+        let old_value = self
+            .last_window
+            .front()
+            .ok_or(TechalibError::InsufficientData)?;
 
         let (wma, new_period_sub, new_period_sum) = wma_next_unchecked(
             sample,
             old_value,
-            self.period as Float,
-            self.period_sub,
-            self.period_sum,
+            T::from_usize(self.period),
+            period_sub,
+            period_sum,
             inv_weight_sum,
         );
 
         if !wma.is_finite() {
-            return Err(TechalibError::Overflow(0, wma));
+            return Err(TechalibError::Overflow(0, wma.to_f64()));
         }
 
-        self.wma = wma;
-        self.period_sub = new_period_sub;
-        self.period_sum = new_period_sum;
-        self.last_window = window;
+        Ok((wma, new_period_sub, new_period_sum))
+    }
 
-        Ok(())
+    /// Pushes `sample` into the window and stores the values returned by
+    /// [`WmaState::next`].
+    pub(crate) fn commit_unchecked(&mut self, sample: T, next: (T, T, T)) {
+        (self.wma, self.period_sub, self.period_sum) = next;
+        self.last_window.push(sample);
     }
 }
 
+/// Lookback period for WMA calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the WMA values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    period - 1
+}
+
 /// Calculation of the WMA function
 /// ---
 /// It returns a [`WmaResult`]
@@ -181,9 +215,9 @@ impl State<Float> for WmaState {
 /// ---
 /// A `Result` containing a [`WmaResult`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn wma(data: &[Float], period: usize) -> Result<WmaResult, TechalibError> {
+pub fn wma<T: TechalibFloat>(data: &[T], period: usize) -> Result<WmaResult<T>, TechalibError> {
     let len = data.len();
-    let mut output = vec![0.0; len];
+    let mut output = vec![T::ZERO; len];
     let wma_state = wma_into(data, period, &mut output)?;
     Ok(WmaResult {
         values: output,
@@ -209,11 +243,11 @@ pub fn wma(data: &[Float], period: usize) -> Result<WmaResult, TechalibError> {
 /// ---
 /// A `Result` containing a [`WmaState`],
 /// or a [`TechalibError`] error if the calculation fails.
-pub fn wma_into(
-    data: &[Float],
+pub fn wma_into<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    output: &mut [Float],
-) -> Result<WmaState, TechalibError> {
+    output: &mut [T],
+) -> Result<WmaState<T>, TechalibError> {
     let len = data.len();
     let inv_weight_sum = inv_weight_sum_linear(period);
     if period == 0 || period > len {
@@ -242,16 +276,20 @@ pub fn wma_into(
                 data[idx]
             )));
         }
+        if precision::resync_due(idx, period) {
+            (period_sub, period_sum) =
+                simd::weighted_sums(&data[idx - period..idx], T::ZERO, T::ONE);
+        }
         (output[idx], period_sub, period_sum) = wma_next_unchecked(
             data[idx],
             data[idx - period],
-            period as Float,
+            T::from_usize(period),
             period_sub,
             period_sum,
             inv_weight_sum,
         );
         if !output[idx].is_finite() {
-            return Err(TechalibError::Overflow(idx, output[idx]));
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
         }
     }
     Ok(WmaState {
@@ -259,19 +297,30 @@ pub fn wma_into(
         period,
         period_sub,
         period_sum,
-        last_window: VecDeque::from(data[len - period..len].to_vec()),
+        last_window: RingBuffer::from(&data[len - period..len]),
     })
 }
 
+batch_functions!(
+    wma_batch,
+    wma_batch_into,
+    wma,
+    wma_into,
+    WmaResult,
+    WmaState,
+    "WMA";
+    period: usize
+);
+
 #[inline(always)]
-fn wma_next_unchecked(
-    new_value: Float,
-    old_value: Float,
-    period: Float,
-    period_sub: Float,
-    period_sum: Float,
-    inv_weight_sum: Float,
-) -> (Float, Float, Float) {
+pub(crate) fn wma_next_unchecked<T: TechalibFloat>(
+    new_value: T,
+    old_value: T,
+    period: T,
+    period_sub: T,
+    period_sum: T,
+    inv_weight_sum: T,
+) -> (T, T, T) {
     let new_period_sub = period_sub - old_value + new_value;
     let new_weighted_sum = period_sum + new_value * period;
     (
@@ -282,34 +331,33 @@ fn wma_next_unchecked(
 }
 
 #[inline(always)]
-fn init_wma_unchecked(
-    data: &[Float],
+fn init_wma_unchecked<T: TechalibFloat>(
+    data: &[T],
     period: usize,
-    inv_weight_sum: Float,
-    output: &mut [Float],
-) -> Result<(Float, Float), TechalibError> {
-    let mut period_sub: Float = 0.0;
-    let mut period_sum: Float = 0.0;
-    for idx in 0..period {
-        let weight = idx as Float;
-        let value = &data[idx];
-        if !value.is_finite() {
+    inv_weight_sum: T,
+    output: &mut [T],
+) -> Result<(T, T), TechalibError> {
+    let (period_sub, period_sum) = simd::weighted_sums(&data[..period], T::ZERO, T::ONE);
+    if !period_sub.is_finite() || !period_sum.is_finite() {
+        if let Some(idx) = simd::first_non_finite(&data[..period]) {
             return Err(TechalibError::DataNonFinite(format!(
-                "data_array[{idx}] = {value:?}"
+                "data_array[{idx}] = {:?}",
+                data[idx]
             )));
         }
-        period_sub += value;
-        period_sum += value * weight;
-        output[idx] = Float::NAN;
     }
+    output[..period].fill(T::NAN);
     output[period - 1] = (period_sum + period_sub) * inv_weight_sum;
     if !output[period - 1].is_finite() {
-        return Err(TechalibError::Overflow(period - 1, output[period - 1]));
+        return Err(TechalibError::Overflow(
+            period - 1,
+            output[period - 1].to_f64(),
+        ));
     }
     Ok((period_sub, period_sum))
 }
 
 #[inline(always)]
-fn inv_weight_sum_linear(period: usize) -> Float {
-    2.0 / (period * (period + 1)) as Float
+pub(crate) fn inv_weight_sum_linear<T: TechalibFloat>(period: usize) -> T {
+    T::from_f64(2.0) / T::from_usize(period * (period + 1))
 }
@@ -0,0 +1,495 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! ZigZag (ZIGZAG) implementation
+//!
+//! The ZigZag follows the swings of the highs and lows and confirms a pivot
+//! when the price reverses from the extreme of the current leg by at least
+//! the threshold ([`ZigzagThreshold`]).
+//!
+//! A pivot is only known once it is confirmed, which happens on a later bar
+//! than the pivot itself. The outputs keep both points of view:
+//! - `pivots` is aligned on the bar of the pivot, which is what is usually
+//!   plotted, but is only filled afterwards.
+//! - `confirmed` is aligned on the bar where the pivot is confirmed, and can
+//!   be used without lookahead.
+//!
+//! The last leg, going from the last confirmed pivot to the current extreme,
+//! is tentative: its end may still move (repaint). It is only reported by the
+//! state ([`ZigzagState::extreme`]), never in `pivots` or `confirmed`.
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::atr::{atr_next_unchecked, true_range_unchecked, AtrState};
+use crate::traits::State;
+use crate::types::{columns_len, Float, Ohlcv, TechalibFloat};
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+/// Reversal threshold of the ZigZag
+/// ---
+/// The minimum move from the extreme of the current leg
+/// needed to confirm a pivot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZigzagThreshold<T = Float> {
+    /// A percentage of the extreme price.
+    Percent(T),
+    /// A multiple of the Average True Range over `period`.
+    Atr {
+        /// The period of the ATR.
+        period: usize,
+        /// The multiplier of the ATR.
+        multiplier: T,
+    },
+}
+
+/// ZigZag calculation result
+/// ---
+/// This struct holds the result of the ZigZag calculation.
+///
+/// Attributes
+/// ---
+/// - `pivots`: The price of the confirmed pivots, on the bar of the pivot.
+/// - `confirmed`: The price of the confirmed pivots, on the bar where they are confirmed.
+/// - `direction`: The direction of the current leg, `1.0` up and `-1.0` down.
+/// - `state`: A [`ZigzagState`], which can be used to calculate the next values
+///   incrementally.
+#[derive(Debug)]
+pub struct ZigzagResult<T = Float> {
+    /// The price of the confirmed pivots, on the bar of the pivot.
+    pub pivots: Vec<T>,
+    /// The price of the confirmed pivots, on the bar where they are confirmed.
+    pub confirmed: Vec<T>,
+    /// The direction of the current leg, `1.0` up and `-1.0` down.
+    pub direction: Vec<T>,
+    /// A [`ZigzagState`], which can be used to calculate the next values
+    /// incrementally.
+    pub state: ZigzagState<T>,
+}
+
+/// ZigZag calculation state
+/// ---
+/// This struct holds the state of the ZigZag calculation.
+/// It is used to calculate the next values in an incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `direction`: The direction of the current leg, `NaN` before the first pivot.
+/// - `confirmed`: The price of the pivot confirmed by the last bar, `NaN` if none.
+/// - `last_pivot`: The price of the last confirmed pivot.
+/// - `last_pivot_idx`: The index of the last confirmed pivot.
+/// - `extreme`: The tentative end of the current leg.
+/// - `extreme_idx`: The index of the tentative end of the current leg.
+///
+/// **State values**
+/// - `max_high`, `max_high_idx`: The highest high before the first pivot.
+/// - `min_low`, `min_low_idx`: The lowest low before the first pivot.
+/// - `idx`: The index of the next bar.
+/// - `atr`: The [`AtrState`] of the threshold, `None` with a percentage threshold.
+///
+/// **Parameters**
+/// - `threshold`: The [`ZigzagThreshold`] used to confirm the pivots.
+#[derive(Debug, Clone, Copy)]
+pub struct ZigzagState<T = Float> {
+    // Outputs
+    /// The direction of the current leg, `NaN` before the first pivot.
+    pub direction: T,
+    /// The price of the pivot confirmed by the last bar, `NaN` if none.
+    pub confirmed: T,
+    /// The price of the last confirmed pivot.
+    pub last_pivot: T,
+    /// The index of the last confirmed pivot.
+    pub last_pivot_idx: usize,
+    /// The tentative end of the current leg.
+    pub extreme: T,
+    /// The index of the tentative end of the current leg.
+    pub extreme_idx: usize,
+
+    // State values
+    /// The highest high before the first pivot.
+    pub max_high: T,
+    /// The index of the highest high before the first pivot.
+    pub max_high_idx: usize,
+    /// The lowest low before the first pivot.
+    pub min_low: T,
+    /// The index of the lowest low before the first pivot.
+    pub min_low_idx: usize,
+    /// The index of the next bar.
+    pub idx: usize,
+    /// The [`AtrState`] of the threshold, `None` with a percentage threshold.
+    pub atr: Option<AtrState<T>>,
+
+    // Parameters
+    /// The [`ZigzagThreshold`] used to confirm the pivots.
+    pub threshold: ZigzagThreshold<T>,
+}
+
+impl<T: TechalibFloat> State<(T, T, T)> for ZigzagState<T> {
+    /// Update the [`ZigzagState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: A tuple `(high, low, close)` of the new bar.
+    ///   The close is only used by the ATR threshold.
+    fn update(&mut self, sample: (T, T, T)) -> Result<(), TechalibError> {
+        check_threshold_unchecked(self.threshold)?;
+        let (high, low, _) = sample;
+        if !high.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("high = {high:?}")));
+        }
+        if !low.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("low = {low:?}")));
+        }
+
+        let mut atr = self.atr;
+        let atr_value = match (self.threshold, atr.as_mut()) {
+            (ZigzagThreshold::Percent(_), _) => T::NAN,
+            (ZigzagThreshold::Atr { period, .. }, Some(atr)) if atr.period == period => {
+                atr.update(sample)?;
+                atr.atr
+            }
+            (ZigzagThreshold::Atr { period, .. }, _) => {
+                return Err(TechalibError::BadParam(format!(
+                    "ZIGZAG ATR state does not match the threshold period ({period})"
+                )));
+            }
+        };
+
+        self.advance_unchecked(high, low, atr_value);
+        self.atr = atr;
+        Ok(())
+    }
+}
+
+impl<T: TechalibFloat> ZigzagState<T> {
+    fn new(threshold: ZigzagThreshold<T>) -> Self {
+        ZigzagState {
+            direction: T::NAN,
+            confirmed: T::NAN,
+            last_pivot: T::NAN,
+            last_pivot_idx: 0,
+            extreme: T::NAN,
+            extreme_idx: 0,
+            max_high: T::NEG_INFINITY,
+            max_high_idx: 0,
+            min_low: T::INFINITY,
+            min_low_idx: 0,
+            idx: 0,
+            atr: None,
+            threshold,
+        }
+    }
+
+    #[inline(always)]
+    fn distance_unchecked(&self, reference: T, atr: T) -> T {
+        match self.threshold {
+            ZigzagThreshold::Percent(percent) => reference.abs() * percent / T::from_f64(100.0),
+            ZigzagThreshold::Atr { multiplier, .. } => multiplier * atr,
+        }
+    }
+
+    #[inline(always)]
+    fn confirm_unchecked(&mut self, pivot: T, pivot_idx: usize, direction: T) {
+        self.confirmed = pivot;
+        self.last_pivot = pivot;
+        self.last_pivot_idx = pivot_idx;
+        self.direction = direction;
+    }
+
+    /// Process a bar, the `atr` is ignored with a percentage threshold.
+    /// A new high (low) extends an up (down) leg and can not reverse it on the same bar.
+    #[inline(always)]
+    fn advance_unchecked(&mut self, high: T, low: T, atr: T) {
+        let idx = self.idx;
+        self.idx += 1;
+        self.confirmed = T::NAN;
+
+        if self.direction == T::ONE {
+            if high > self.extreme {
+                self.extreme = high;
+                self.extreme_idx = idx;
+            } else if self.extreme - low >= self.distance_unchecked(self.extreme, atr) {
+                self.confirm_unchecked(self.extreme, self.extreme_idx, -T::ONE);
+                self.extreme = low;
+                self.extreme_idx = idx;
+            }
+            return;
+        }
+        if self.direction == -T::ONE {
+            if low < self.extreme {
+                self.extreme = low;
+                self.extreme_idx = idx;
+            } else if high - self.extreme >= self.distance_unchecked(self.extreme, atr) {
+                self.confirm_unchecked(self.extreme, self.extreme_idx, T::ONE);
+                self.extreme = high;
+                self.extreme_idx = idx;
+            }
+            return;
+        }
+
+        // No pivot yet: the first leg starts from whichever extreme came first
+        if high > self.max_high {
+            self.max_high = high;
+            self.max_high_idx = idx;
+        }
+        if low < self.min_low {
+            self.min_low = low;
+            self.min_low_idx = idx;
+        }
+        let range = self.max_high - self.min_low;
+        if self.min_low_idx < self.max_high_idx
+            && range >= self.distance_unchecked(self.min_low, atr)
+        {
+            self.confirm_unchecked(self.min_low, self.min_low_idx, T::ONE);
+            self.extreme = self.max_high;
+            self.extreme_idx = self.max_high_idx;
+        } else if self.max_high_idx < self.min_low_idx
+            && range >= self.distance_unchecked(self.max_high, atr)
+        {
+            self.confirm_unchecked(self.max_high, self.max_high_idx, -T::ONE);
+            self.extreme = self.min_low;
+            self.extreme_idx = self.min_low_idx;
+        }
+    }
+}
+
+/// Lookback period for ZigZag calculation
+/// ---
+/// With `n = lookback_from_threshold(threshold)`, no pivot can be
+/// confirmed on the `n` first bars, as the ATR is not yet defined.
+/// The outputs are `NaN` until the first pivot is confirmed.
+#[inline(always)]
+pub fn lookback_from_threshold<T: TechalibFloat>(threshold: ZigzagThreshold<T>) -> usize {
+    match threshold {
+        ZigzagThreshold::Percent(_) => 0,
+        ZigzagThreshold::Atr { period, .. } => period,
+    }
+}
+
+/// Calculation of the ZigZag function
+/// ---
+/// It returns a [`ZigzagResult`]
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high` and `low` columns are used,
+///   and the `close` column with an ATR threshold.
+/// - `threshold`: The [`ZigzagThreshold`] used to confirm the pivots.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ZigzagResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn zigzag<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    threshold: ZigzagThreshold<T>,
+) -> Result<ZigzagResult<T>, TechalibError> {
+    let len = ohlcv.high.len();
+    let mut output_pivots = vec![T::ZERO; len];
+    let mut output_confirmed = vec![T::ZERO; len];
+    let mut output_direction = vec![T::ZERO; len];
+
+    let zigzag_state = zigzag_into(
+        ohlcv,
+        threshold,
+        output_pivots.as_mut_slice(),
+        output_confirmed.as_mut_slice(),
+        output_direction.as_mut_slice(),
+    )?;
+
+    Ok(ZigzagResult {
+        pivots: output_pivots,
+        confirmed: output_confirmed,
+        direction: output_direction,
+        state: zigzag_state,
+    })
+}
+
+/// Calculation of the ZigZag function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`ZigzagState`].
+///
+/// Input Arguments
+/// ---
+/// - `ohlcv`: An [`Ohlcv`] view, the `high` and `low` columns are used,
+///   and the `close` column with an ATR threshold.
+/// - `threshold`: The [`ZigzagThreshold`] used to confirm the pivots.
+///
+/// Output Arguments
+/// ---
+/// - `output_pivots`: A mutable slice to store the pivots, on the bar of the pivot.
+/// - `output_confirmed`: A mutable slice to store the pivots, on the bar where they are confirmed.
+/// - `output_direction`: A mutable slice to store the direction of the current leg.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ZigzagState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn zigzag_into<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    threshold: ZigzagThreshold<T>,
+    output_pivots: &mut [T],
+    output_confirmed: &mut [T],
+    output_direction: &mut [T],
+) -> Result<ZigzagState<T>, TechalibError> {
+    check_threshold_unchecked(threshold)?;
+
+    let (high, low, close) = (ohlcv.high, ohlcv.low, ohlcv.close);
+    let len = match threshold {
+        ZigzagThreshold::Percent(_) => columns_len(&[("high", high), ("low", low)])?,
+        ZigzagThreshold::Atr { .. } => {
+            columns_len(&[("high", high), ("low", low), ("close", close)])?
+        }
+    };
+    if output_pivots.len() < len || output_confirmed.len() < len || output_direction.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output arrays must be at least as long as the input data array".to_string(),
+        ));
+    }
+
+    let lookback = lookback_from_threshold(threshold);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let mut state = ZigzagState::new(threshold);
+    let mut atr = T::NAN;
+    let mut tr_sum = T::ZERO;
+    output_pivots[..len].fill(T::NAN);
+    for idx in 0..len {
+        check_finite_unchecked(ohlcv, threshold, idx)?;
+
+        if let ZigzagThreshold::Atr { period, .. } = threshold {
+            if idx > 0 {
+                let true_range = true_range_unchecked(high[idx], low[idx], close[idx - 1]);
+                if idx < lookback {
+                    tr_sum += true_range;
+                } else if idx == lookback {
+                    atr = (tr_sum + true_range) / T::from_usize(period);
+                } else {
+                    atr = atr_next_unchecked(true_range, atr, T::from_usize(period));
+                }
+            }
+            if idx >= lookback && !atr.is_finite() {
+                return Err(TechalibError::Overflow(idx, atr.to_f64()));
+            }
+        }
+
+        state.advance_unchecked(high[idx], low[idx], atr);
+        if !state.confirmed.is_nan() {
+            output_pivots[state.last_pivot_idx] = state.last_pivot;
+        }
+        output_confirmed[idx] = state.confirmed;
+        output_direction[idx] = state.direction;
+    }
+
+    if let ZigzagThreshold::Atr { period, .. } = threshold {
+        state.atr = Some(AtrState {
+            atr,
+            prev_close: close[len - 1],
+            period,
+        });
+    }
+    Ok(state)
+}
+
+batch_functions!(
+    zigzag_batch,
+    zigzag_batch_into,
+    zigzag,
+    zigzag_into,
+    ZigzagResult,
+    ZigzagState,
+    "ZigZag",
+    ohlcv -> [output_pivots, output_confirmed, output_direction];
+    threshold: ZigzagThreshold<T>
+);
+
+#[inline(always)]
+fn check_threshold_unchecked<T: TechalibFloat>(
+    threshold: ZigzagThreshold<T>,
+) -> Result<(), TechalibError> {
+    match threshold {
+        ZigzagThreshold::Percent(percent) => {
+            if !percent.is_finite() || percent <= T::ZERO {
+                return Err(TechalibError::BadParam(format!(
+                    "Percent threshold must be a finite value greater than 0, got: {percent:?}"
+                )));
+            }
+        }
+        ZigzagThreshold::Atr { period, multiplier } => {
+            if period == 0 {
+                return Err(TechalibError::BadParam(
+                    "ATR period must be greater than 0".to_string(),
+                ));
+            }
+            if !multiplier.is_finite() || multiplier <= T::ZERO {
+                return Err(TechalibError::BadParam(format!(
+                    "Multiplier must be a finite value greater than 0, got: {multiplier:?}"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn check_finite_unchecked<T: TechalibFloat>(
+    ohlcv: &Ohlcv<T>,
+    threshold: ZigzagThreshold<T>,
+    idx: usize,
+) -> Result<(), TechalibError> {
+    if !ohlcv.high[idx].is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "high[{idx}] = {:?}",
+            ohlcv.high[idx]
+        )));
+    }
+    if !ohlcv.low[idx].is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "low[{idx}] = {:?}",
+            ohlcv.low[idx]
+        )));
+    }
+    if matches!(threshold, ZigzagThreshold::Atr { .. }) && !ohlcv.close[idx].is_finite() {
+        return Err(TechalibError::DataNonFinite(format!(
+            "close[{idx}] = {:?}",
+            ohlcv.close[idx]
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,284 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Zero-Lag Exponential Moving Average (ZLEMA) implementation
+//!
+//! The [`ema`](crate::indicators::ema) of the de-lagged data
+//! `2 × data[i] - data[i - lag]`, with `lag = (period - 1) / 2`.
+//! As the EMA, it is seeded with the simple moving average of the
+//! first `period` de-lagged values.
+
+use alloc::{format, string::ToString, vec, vec::Vec};
+
+use crate::batch::batch_functions;
+use crate::errors::TechalibError;
+use crate::indicators::ema::{ema_next_unchecked, get_alpha_value};
+use crate::ring_buffer::RingBuffer;
+use crate::traits::State;
+use crate::types::{Float, TechalibFloat};
+
+/// ZLEMA calculation result
+/// ---
+/// This struct holds the result and the state ([`ZlemaState`])
+/// of the calculation.
+///
+/// Attributes
+/// ---
+/// - `values`: A vector of [`Float`] representing the calculated ZLEMA values.
+/// - `state`: A [`ZlemaState`], which can be used to calculate
+///   the next values incrementally.
+#[derive(Debug)]
+pub struct ZlemaResult<T = Float> {
+    /// The calculated ZLEMA values.
+    pub values: Vec<T>,
+    /// A [`ZlemaState`], which can be used to calculate
+    /// the next values incrementally.
+    pub state: ZlemaState<T>,
+}
+
+/// ZLEMA calculation state
+/// ---
+/// This struct holds the state of the calculation.
+/// It is used to calculate the next values in a incremental way.
+///
+/// Attributes
+/// ---
+/// **Last outputs values**
+/// - `zlema`: The last calculated ZLEMA value.
+///
+/// **State values**
+/// - `last_window`: A ring buffer containing the last `lag` input values.
+///
+/// **Parameters**
+/// - `period`: The period used for the ZLEMA calculation.
+/// - `alpha`: The alpha factor of the EMA of the de-lagged data.
+#[derive(Debug, Clone)]
+pub struct ZlemaState<T = Float> {
+    // Outputs
+    /// The last calculated ZLEMA value.
+    pub zlema: T,
+
+    // State values
+    /// A ring buffer containing the last `lag` input values.
+    pub last_window: RingBuffer<T>,
+
+    // Parameters
+    /// The period used for the ZLEMA calculation.
+    pub period: usize,
+    /// The alpha factor of the EMA of the de-lagged data.
+    pub alpha: T,
+}
+
+impl<T: TechalibFloat> State<T> for ZlemaState<T> {
+    /// Update the [`ZlemaState`] with a new sample
+    ///
+    /// Input Arguments
+    /// ---
+    /// - `sample`: The new input to update the ZLEMA state.
+    fn update(&mut self, sample: T) -> Result<(), TechalibError> {
+        if self.period <= 1 {
+            return Err(TechalibError::BadParam(
+                "ZLEMA period must be greater than 1".to_string(),
+            ));
+        }
+        let lag = lag_from_period(self.period);
+        if self.last_window.len() != lag {
+            return Err(TechalibError::BadParam(format!(
+                "ZLEMA state window length ({}) does not match lag ({lag})",
+                self.last_window.len(),
+            )));
+        }
+        if !sample.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!("sample = {sample:?}")));
+        }
+        if !self.zlema.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "self.zlema = {:?}",
+                self.zlema
+            )));
+        }
+        if !self.alpha.is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "alpha = {:?}",
+                self.alpha
+            )));
+        }
+
+        let lagged = self.last_window.front().unwrap_or(sample);
+
+        let zlema = ema_next_unchecked(T::from_f64(2.0) * sample - lagged, self.zlema, self.alpha);
+        if !zlema.is_finite() {
+            return Err(TechalibError::Overflow(0, zlema.to_f64()));
+        }
+        self.zlema = zlema;
+        self.last_window.push(sample);
+        Ok(())
+    }
+}
+
+/// Lookback period for ZLEMA calculation
+/// ---
+/// With `n = lookback_from_period(period)`,
+/// the `n` first values that will be return will be `NaN`
+/// and the next values will be the ZLEMA values.
+#[inline(always)]
+pub fn lookback_from_period(period: usize) -> usize {
+    lag_from_period(period) + period - 1
+}
+
+/// Calculation of the ZLEMA function
+/// ---
+/// It returns a [`ZlemaResult`]
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period for the ZLEMA calculation.
+/// - `alpha`: An optional alpha value for the EMA of the de-lagged data.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ZlemaResult`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn zlema<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    alpha: Option<T>,
+) -> Result<ZlemaResult<T>, TechalibError> {
+    let mut output = vec![T::ZERO; data.len()];
+    let zlema_state = zlema_into(data, period, alpha, output.as_mut_slice())?;
+    Ok(ZlemaResult {
+        values: output,
+        state: zlema_state,
+    })
+}
+
+/// Calculation of the ZLEMA function
+/// ---
+/// It stores the results in the provided output arrays and
+/// return the state [`ZlemaState`].
+///
+/// Input Arguments
+/// ---
+/// - `data`: A slice of [`Float`] representing the input data.
+/// - `period`: The period for the ZLEMA calculation.
+/// - `alpha`: An optional alpha value for the EMA of the de-lagged data.
+///
+/// Output Arguments
+/// ---
+/// - `output`: A mutable slice of [`Float`] where the calculated ZLEMA values will be stored.
+///
+/// Returns
+/// ---
+/// A `Result` containing a [`ZlemaState`],
+/// or a [`TechalibError`] error if the calculation fails.
+pub fn zlema_into<T: TechalibFloat>(
+    data: &[T],
+    period: usize,
+    alpha: Option<T>,
+    output: &mut [T],
+) -> Result<ZlemaState<T>, TechalibError> {
+    if period <= 1 {
+        return Err(TechalibError::BadParam(
+            "ZLEMA period must be greater than 1".to_string(),
+        ));
+    }
+    let len = data.len();
+    if output.len() < len {
+        return Err(TechalibError::BadParam(
+            "Output array must be at least as long as the input data array".to_string(),
+        ));
+    }
+    let lookback = lookback_from_period(period);
+    if len <= lookback {
+        return Err(TechalibError::InsufficientData);
+    }
+
+    let alpha = get_alpha_value(alpha, period)?;
+    let lag = lag_from_period(period);
+
+    let mut sum = T::ZERO;
+    for idx in 0..len {
+        if !data[idx].is_finite() {
+            return Err(TechalibError::DataNonFinite(format!(
+                "data[{idx}] = {:?}",
+                data[idx]
+            )));
+        }
+        if idx < lag {
+            output[idx] = T::NAN;
+            continue;
+        }
+
+        let delagged = T::from_f64(2.0) * data[idx] - data[idx - lag];
+        if idx < lookback {
+            sum += delagged;
+            output[idx] = T::NAN;
+            continue;
+        }
+        output[idx] = if idx == lookback {
+            (sum + delagged) / T::from_usize(period)
+        } else {
+            ema_next_unchecked(delagged, output[idx - 1], alpha)
+        };
+        if !output[idx].is_finite() {
+            return Err(TechalibError::Overflow(idx, output[idx].to_f64()));
+        }
+    }
+
+    Ok(ZlemaState {
+        zlema: output[len - 1],
+        last_window: RingBuffer::from(&data[len - lag..len]),
+        period,
+        alpha,
+    })
+}
+
+batch_functions!(
+    zlema_batch,
+    zlema_batch_into,
+    zlema,
+    zlema_into,
+    ZlemaResult,
+    ZlemaState,
+    "ZLEMA";
+    period: usize, alpha: Option<T>
+);
+
+#[inline(always)]
+fn lag_from_period(period: usize) -> usize {
+    (period - 1) / 2
+}
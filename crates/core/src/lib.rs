@@ -1,6 +1,21 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 //! This crate provides a collection of technical indicators and utilities for financial
 //! analysis.
+//!
+//! The `std` feature is enabled by default. Without it, the crate is `no_std` and only
+//! needs `alloc`: the `libm` feature must then be enabled so that the float math goes
+//! through `libm`, and the vectorised kernels are selected at compile time from the
+//! enabled target features.
+//!
+//! The opt-in `high-precision` feature bounds the drift of the rolling sums over long
+//! series by re-summing the window of SMA, BBANDS, KAMA, TRIMA and WMA once every
+//! `period` samples. It is a periodic exact re-sync, not a compensated summation.
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("techalib needs either the `std` or the `libm` feature for its float math");
+
+extern crate alloc;
 
 /// This module contains the error types used throughout the library.
 pub mod errors;
@@ -17,3 +32,29 @@ pub mod types;
 
 /// This module contains the traits used throughout the library.
 pub mod traits;
+
+/// This module contains the fixed-capacity ring buffer holding the last window
+/// of the indicator states, which rolls forward without allocating.
+pub mod ring_buffer;
+
+/// This module contains the indicators over Arrow `Float64Array` and `Float32Array`,
+/// which skip the null rows and return null during the warm-up period.
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+/// This module contains the helpers behind the `*_batch` entry points, which
+/// run one indicator over many series, and the `*_sweep` entry points, which
+/// run one indicator over many periods. Both can run in parallel with `rayon`.
+mod batch;
+
+/// This module contains the vectorised kernels used by the initial-window sums of
+/// SMA, WMA, TRIMA and BBANDS and by the SMA and BBANDS sweeps, with AVX paths for
+/// `f64` and `f32` and a scalar fallback. The batch entry points reach them through
+/// the single-series functions.
+mod simd;
+
+/// This module contains the switches of the `high-precision` feature, which
+/// periodically rebuilds the rolling sums of the moving averages from their window
+/// so that the rounding error does not grow with the length of the series. It is a
+/// periodic re-sync, not a compensated summation.
+mod precision;
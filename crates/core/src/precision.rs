@@ -0,0 +1,31 @@
+//! Switches of the `high-precision` feature
+//! ---
+//! The rolling sums of SMA, BBANDS, KAMA, TRIMA and WMA are updated by adding the
+//! new value and subtracting the one leaving the window, so their rounding error
+//! grows with the length of the series. The `high-precision` feature bounds it by
+//! periodically re-summing the window from scratch.
+//!
+//! This is a periodic exact re-sync, not a compensated (Kahan / Neumaier)
+//! summation: between two re-syncs, the incremental updates round exactly as in
+//! the default mode, and the window itself is summed without compensation. The
+//! error is then bounded by the one of `period` updates and of a single window
+//! sum, whatever the length of the series, but it is not reduced below it.
+
+/// Whether the high-precision mode is enabled
+/// ---
+/// When it is, the `_into` functions and the states rebuild their rolling sums
+/// from the window once every `period` samples, see [`resync_due`].
+pub(crate) const HIGH_PRECISION: bool = cfg!(feature = "high-precision");
+
+/// Whether the rolling sums must be rebuilt from the window before the update at `idx`
+/// ---
+/// It is always `false` unless the `high-precision` feature is enabled, in which case
+/// the sums are rebuilt once every `period` samples. The error of the incremental
+/// updates then stays bounded by `period` steps, whatever the length of the series.
+///
+/// The states pass the head of their `last_window`, which comes back to 0 every
+/// `period` updates, so that an update stays O(1) on average.
+#[inline(always)]
+pub(crate) fn resync_due(idx: usize, period: usize) -> bool {
+    HIGH_PRECISION && idx % period == 0
+}
@@ -0,0 +1,144 @@
+use crate::types::Float;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::Chain;
+use core::slice;
+
+/// Fixed-capacity ring buffer
+/// ---
+/// A window of values whose length never changes once it is built.
+/// [`RingBuffer::push`] overwrites the oldest value in place, so rolling
+/// the window forward never allocates.
+///
+/// It is built from a `Vec` and converted back to one with [`From`],
+/// the oldest value first.
+#[derive(Clone)]
+pub struct RingBuffer<T = Float> {
+    buffer: Vec<T>,
+    head: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// The number of values in the window
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Whether the window holds no value
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Iterates over the values, from the oldest to the newest
+    pub fn iter(&self) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back)
+    }
+
+    /// The two contiguous parts of the window, from the oldest to the newest
+    #[inline(always)]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (back, front) = self.buffer.split_at(self.head);
+        (front, back)
+    }
+
+    /// The position of the oldest value in the buffer
+    /// ---
+    /// It goes back to 0 once every `len` pushes, when the window is contiguous.
+    #[inline(always)]
+    pub(crate) fn head(&self) -> usize {
+        self.head
+    }
+
+    /// Rotates the buffer in place so that the window is contiguous
+    /// ---
+    /// It returns the values from the oldest to the newest, without allocating.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.buffer.rotate_left(self.head);
+        self.head = 0;
+        &mut self.buffer
+    }
+}
+
+impl<T: Copy> RingBuffer<T> {
+    /// The value at position `idx`, the oldest value being at position 0
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> Option<T> {
+        if idx >= self.buffer.len() {
+            return None;
+        }
+        let idx = self.head + idx;
+        Some(if idx < self.buffer.len() {
+            self.buffer[idx]
+        } else {
+            self.buffer[idx - self.buffer.len()]
+        })
+    }
+
+    /// The oldest value of the window
+    #[inline(always)]
+    pub fn front(&self) -> Option<T> {
+        self.get(0)
+    }
+
+    /// The newest value of the window
+    #[inline(always)]
+    pub fn back(&self) -> Option<T> {
+        self.get(self.buffer.len().checked_sub(1)?)
+    }
+
+    /// Replaces the oldest value of the window with `value`
+    /// ---
+    /// It returns the replaced value, or `None` if the window is empty.
+    #[inline(always)]
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if self.buffer.is_empty() {
+            return None;
+        }
+        let old_value = core::mem::replace(&mut self.buffer[self.head], value);
+        self.head += 1;
+        if self.head == self.buffer.len() {
+            self.head = 0;
+        }
+        Some(old_value)
+    }
+}
+
+impl<T> From<Vec<T>> for RingBuffer<T> {
+    fn from(buffer: Vec<T>) -> Self {
+        RingBuffer { buffer, head: 0 }
+    }
+}
+
+impl<T> FromIterator<T> for RingBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        RingBuffer::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T: Copy> From<&[T]> for RingBuffer<T> {
+    fn from(values: &[T]) -> Self {
+        RingBuffer::from(values.to_vec())
+    }
+}
+
+impl<T> From<RingBuffer<T>> for Vec<T> {
+    fn from(mut ring: RingBuffer<T>) -> Self {
+        ring.buffer.rotate_left(ring.head);
+        ring.buffer
+    }
+}
+
+impl<T: PartialEq> PartialEq for RingBuffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RingBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
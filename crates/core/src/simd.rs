@@ -0,0 +1,631 @@
+use crate::types::TechalibFloat;
+use core::any::TypeId;
+
+/// Sum of `data`
+/// ---
+/// The result is not finite if a value of `data` is not finite.
+#[inline]
+pub(crate) fn sum<T: TechalibFloat>(data: &[T]) -> T {
+    #[cfg(target_arch = "x86_64")]
+    if has_avx() {
+        if let Some(data) = cast::<T, f64>(data) {
+            return T::from_f64(unsafe { avx::sum_f64(data) });
+        }
+        if let Some(data) = cast::<T, f32>(data) {
+            return T::from_f64(unsafe { avx::sum_f32(data) } as f64);
+        }
+    }
+    scalar::sum(data)
+}
+
+/// Sum of `data` and sum of its squares
+/// ---
+/// The results are not finite if a value of `data` is not finite.
+#[inline]
+pub(crate) fn sum_and_sum_sq<T: TechalibFloat>(data: &[T]) -> (T, T) {
+    #[cfg(target_arch = "x86_64")]
+    if has_avx() {
+        if let Some(data) = cast::<T, f64>(data) {
+            let (sum, sum_sq) = unsafe { avx::sum_and_sum_sq_f64(data) };
+            return (T::from_f64(sum), T::from_f64(sum_sq));
+        }
+        if let Some(data) = cast::<T, f32>(data) {
+            let (sum, sum_sq) = unsafe { avx::sum_and_sum_sq_f32(data) };
+            return (T::from_f64(sum as f64), T::from_f64(sum_sq as f64));
+        }
+    }
+    scalar::sum_and_sum_sq(data)
+}
+
+/// Sum of `data` and sum of `data[i] * (first_weight + i * step)`
+/// ---
+/// The weights must be small integers, so that they are exact in `T`.
+/// The results are not finite if a value of `data` is not finite.
+#[inline]
+pub(crate) fn weighted_sums<T: TechalibFloat>(data: &[T], first_weight: T, step: T) -> (T, T) {
+    #[cfg(target_arch = "x86_64")]
+    if has_avx() {
+        if let Some(data) = cast::<T, f64>(data) {
+            let (sum, weighted_sum) =
+                unsafe { avx::weighted_sums_f64(data, first_weight.to_f64(), step.to_f64()) };
+            return (T::from_f64(sum), T::from_f64(weighted_sum));
+        }
+        if let Some(data) = cast::<T, f32>(data) {
+            let (sum, weighted_sum) = unsafe {
+                avx::weighted_sums_f32(data, first_weight.to_f64() as f32, step.to_f64() as f32)
+            };
+            return (T::from_f64(sum as f64), T::from_f64(weighted_sum as f64));
+        }
+    }
+    scalar::weighted_sums(data, first_weight, step)
+}
+
+/// Means of the windows of `period` values from prefix sums
+/// ---
+/// `output[i]` is `(sums[i + period] - sums[i]) * inv_period`,
+/// `sums` must hold at least `output.len() + period` values.
+#[inline]
+pub(crate) fn window_means<T: TechalibFloat>(
+    sums: &[f64],
+    period: usize,
+    inv_period: f64,
+    output: &mut [T],
+) {
+    debug_assert!(sums.len() >= output.len() + period);
+    #[cfg(target_arch = "x86_64")]
+    if has_avx() {
+        if let Some(output) = cast_mut::<T, f64>(output) {
+            unsafe { avx::window_means_f64(sums, period, inv_period, output) };
+            return;
+        }
+        if let Some(output) = cast_mut::<T, f32>(output) {
+            unsafe { avx::window_means_f32(sums, period, inv_period, output) };
+            return;
+        }
+    }
+    scalar::window_means(sums, period, inv_period, output)
+}
+
+/// Bollinger Bands of the windows of `period` values from prefix sums
+/// ---
+/// `sums` and `sums_sq` are the prefix sums of the values and of their squares,
+/// both centered on `center`. `output_upper[i]` is `output_middle[i] + up * std`
+/// and `output_lower[i]` is `output_middle[i] - down * std`, where `std` is the
+/// standard deviation of the window. With `sma_middle`, `output_middle[i]` is
+/// set to the mean of the window first, otherwise it must already be filled.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub(crate) fn window_bands<T: TechalibFloat>(
+    sums: &[f64],
+    sums_sq: &[f64],
+    period: usize,
+    center: f64,
+    multipliers: (T, T),
+    sma_middle: bool,
+    output_upper: &mut [T],
+    output_middle: &mut [T],
+    output_lower: &mut [T],
+) {
+    debug_assert!(sums.len() >= output_middle.len() + period);
+    debug_assert!(sums_sq.len() >= output_middle.len() + period);
+    debug_assert!(output_upper.len() == output_middle.len());
+    debug_assert!(output_lower.len() == output_middle.len());
+    let windows = scalar::Windows {
+        sums,
+        sums_sq,
+        period,
+        inv_period: 1.0 / period as f64,
+        center,
+    };
+    #[cfg(target_arch = "x86_64")]
+    if has_avx() {
+        let (up, down) = (multipliers.0.to_f64(), multipliers.1.to_f64());
+        if let (Some(upper), Some(middle), Some(lower)) = (
+            cast_mut::<T, f64>(output_upper),
+            cast_mut::<T, f64>(output_middle),
+            cast_mut::<T, f64>(output_lower),
+        ) {
+            unsafe {
+                avx::window_bands_f64(&windows, (up, down), sma_middle, upper, middle, lower)
+            };
+            return;
+        }
+        if let (Some(upper), Some(middle), Some(lower)) = (
+            cast_mut::<T, f32>(output_upper),
+            cast_mut::<T, f32>(output_middle),
+            cast_mut::<T, f32>(output_lower),
+        ) {
+            unsafe {
+                avx::window_bands_f32(
+                    &windows,
+                    (up as f32, down as f32),
+                    sma_middle,
+                    upper,
+                    middle,
+                    lower,
+                )
+            };
+            return;
+        }
+    }
+    scalar::window_bands(
+        &windows,
+        multipliers,
+        sma_middle,
+        output_upper,
+        output_middle,
+        output_lower,
+    )
+}
+
+/// Index of the first value of `data` which is not finite
+#[inline]
+pub(crate) fn first_non_finite<T: TechalibFloat>(data: &[T]) -> Option<usize> {
+    #[cfg(target_arch = "x86_64")]
+    if has_avx() {
+        if let Some(data) = cast::<T, f64>(data) {
+            return unsafe { avx::first_non_finite_f64(data) };
+        }
+        if let Some(data) = cast::<T, f32>(data) {
+            return unsafe { avx::first_non_finite_f32(data) };
+        }
+    }
+    data.iter().position(|value| !value.is_finite())
+}
+
+/// Whether the AVX kernels can be used
+/// ---
+/// It is detected at runtime with `std`, and from the target features
+/// enabled at compile time without it.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn has_avx() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::arch::is_x86_feature_detected!("avx")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx")
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn cast<T: 'static, U: 'static>(data: &[T]) -> Option<&[U]> {
+    if TypeId::of::<T>() == TypeId::of::<U>() {
+        // SAFETY: `T` and `U` are the same type.
+        Some(unsafe { &*(data as *const [T] as *const [U]) })
+    } else {
+        None
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn cast_mut<T: 'static, U: 'static>(data: &mut [T]) -> Option<&mut [U]> {
+    if TypeId::of::<T>() == TypeId::of::<U>() {
+        // SAFETY: `T` and `U` are the same type.
+        Some(unsafe { &mut *(data as *mut [T] as *mut [U]) })
+    } else {
+        None
+    }
+}
+
+mod scalar {
+    use crate::types::TechalibFloat;
+
+    /// Prefix sums shared by the window kernels
+    pub(super) struct Windows<'a> {
+        pub(super) sums: &'a [f64],
+        pub(super) sums_sq: &'a [f64],
+        pub(super) period: usize,
+        pub(super) inv_period: f64,
+        pub(super) center: f64,
+    }
+
+    impl Windows<'_> {
+        /// Mean and variance of the window starting at `idx`, the mean is centered
+        #[inline(always)]
+        pub(super) fn mean_and_variance(&self, idx: usize) -> (f64, f64) {
+            let mean = (self.sums[idx + self.period] - self.sums[idx]) * self.inv_period;
+            let variance = (self.sums_sq[idx + self.period] - self.sums_sq[idx]) * self.inv_period
+                - mean * mean;
+            (mean, variance)
+        }
+    }
+
+    pub(super) fn sum<T: TechalibFloat>(data: &[T]) -> T {
+        let mut sum = T::ZERO;
+        for &value in data {
+            sum += value;
+        }
+        sum
+    }
+
+    pub(super) fn sum_and_sum_sq<T: TechalibFloat>(data: &[T]) -> (T, T) {
+        let (mut sum, mut sum_sq) = (T::ZERO, T::ZERO);
+        for &value in data {
+            sum += value;
+            sum_sq += value * value;
+        }
+        (sum, sum_sq)
+    }
+
+    pub(super) fn weighted_sums<T: TechalibFloat>(data: &[T], first_weight: T, step: T) -> (T, T) {
+        let (mut sum, mut weighted_sum) = (T::ZERO, T::ZERO);
+        let mut weight = first_weight;
+        for &value in data {
+            sum += value;
+            weighted_sum += value * weight;
+            weight += step;
+        }
+        (sum, weighted_sum)
+    }
+
+    pub(super) fn window_means<T: TechalibFloat>(
+        sums: &[f64],
+        period: usize,
+        inv_period: f64,
+        output: &mut [T],
+    ) {
+        for (idx, value) in output.iter_mut().enumerate() {
+            *value = T::from_f64((sums[idx + period] - sums[idx]) * inv_period);
+        }
+    }
+
+    pub(super) fn window_bands<T: TechalibFloat>(
+        windows: &Windows,
+        (up, down): (T, T),
+        sma_middle: bool,
+        output_upper: &mut [T],
+        output_middle: &mut [T],
+        output_lower: &mut [T],
+    ) {
+        for idx in 0..output_middle.len() {
+            let (mean, variance) = windows.mean_and_variance(idx);
+            let std = T::from_f64(variance.abs().sqrt());
+            if sma_middle {
+                output_middle[idx] = T::from_f64(windows.center + mean);
+            }
+            output_upper[idx] = output_middle[idx] + up * std;
+            output_lower[idx] = output_middle[idx] - down * std;
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx {
+    use super::scalar::{self, Windows};
+    use core::arch::x86_64::*;
+
+    #[target_feature(enable = "avx")]
+    unsafe fn horizontal_sum_f64(values: __m256d) -> f64 {
+        let mut lanes = [0.0; 4];
+        _mm256_storeu_pd(lanes.as_mut_ptr(), values);
+        (lanes[0] + lanes[1]) + (lanes[2] + lanes[3])
+    }
+
+    #[target_feature(enable = "avx")]
+    unsafe fn horizontal_sum_f32(values: __m256) -> f32 {
+        let mut lanes = [0.0; 8];
+        _mm256_storeu_ps(lanes.as_mut_ptr(), values);
+        ((lanes[0] + lanes[1]) + (lanes[2] + lanes[3]))
+            + ((lanes[4] + lanes[5]) + (lanes[6] + lanes[7]))
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn sum_f64(data: &[f64]) -> f64 {
+        let chunks = data.chunks_exact(4);
+        let remainder = chunks.remainder();
+        let mut acc = _mm256_setzero_pd();
+        for chunk in chunks {
+            acc = _mm256_add_pd(acc, _mm256_loadu_pd(chunk.as_ptr()));
+        }
+        remainder
+            .iter()
+            .fold(horizontal_sum_f64(acc), |sum, value| sum + value)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn sum_f32(data: &[f32]) -> f32 {
+        let chunks = data.chunks_exact(8);
+        let remainder = chunks.remainder();
+        let mut acc = _mm256_setzero_ps();
+        for chunk in chunks {
+            acc = _mm256_add_ps(acc, _mm256_loadu_ps(chunk.as_ptr()));
+        }
+        remainder
+            .iter()
+            .fold(horizontal_sum_f32(acc), |sum, value| sum + value)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn sum_and_sum_sq_f64(data: &[f64]) -> (f64, f64) {
+        let chunks = data.chunks_exact(4);
+        let remainder = chunks.remainder();
+        let (mut acc, mut acc_sq) = (_mm256_setzero_pd(), _mm256_setzero_pd());
+        for chunk in chunks {
+            let values = _mm256_loadu_pd(chunk.as_ptr());
+            acc = _mm256_add_pd(acc, values);
+            acc_sq = _mm256_add_pd(acc_sq, _mm256_mul_pd(values, values));
+        }
+        remainder.iter().fold(
+            (horizontal_sum_f64(acc), horizontal_sum_f64(acc_sq)),
+            |(sum, sum_sq), value| (sum + value, sum_sq + value * value),
+        )
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn sum_and_sum_sq_f32(data: &[f32]) -> (f32, f32) {
+        let chunks = data.chunks_exact(8);
+        let remainder = chunks.remainder();
+        let (mut acc, mut acc_sq) = (_mm256_setzero_ps(), _mm256_setzero_ps());
+        for chunk in chunks {
+            let values = _mm256_loadu_ps(chunk.as_ptr());
+            acc = _mm256_add_ps(acc, values);
+            acc_sq = _mm256_add_ps(acc_sq, _mm256_mul_ps(values, values));
+        }
+        remainder.iter().fold(
+            (horizontal_sum_f32(acc), horizontal_sum_f32(acc_sq)),
+            |(sum, sum_sq), value| (sum + value, sum_sq + value * value),
+        )
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn weighted_sums_f64(
+        data: &[f64],
+        first_weight: f64,
+        step: f64,
+    ) -> (f64, f64) {
+        let chunks = data.chunks_exact(4);
+        let remainder = chunks.remainder();
+        let (mut acc, mut acc_weighted) = (_mm256_setzero_pd(), _mm256_setzero_pd());
+        let mut weights = _mm256_add_pd(
+            _mm256_set1_pd(first_weight),
+            _mm256_mul_pd(_mm256_set1_pd(step), _mm256_setr_pd(0.0, 1.0, 2.0, 3.0)),
+        );
+        let weights_step = _mm256_set1_pd(4.0 * step);
+        for chunk in chunks {
+            let values = _mm256_loadu_pd(chunk.as_ptr());
+            acc = _mm256_add_pd(acc, values);
+            acc_weighted = _mm256_add_pd(acc_weighted, _mm256_mul_pd(values, weights));
+            weights = _mm256_add_pd(weights, weights_step);
+        }
+        let mut weight = first_weight + step * (data.len() - remainder.len()) as f64;
+        let (mut sum, mut weighted_sum) =
+            (horizontal_sum_f64(acc), horizontal_sum_f64(acc_weighted));
+        for value in remainder {
+            sum += value;
+            weighted_sum += value * weight;
+            weight += step;
+        }
+        (sum, weighted_sum)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn weighted_sums_f32(
+        data: &[f32],
+        first_weight: f32,
+        step: f32,
+    ) -> (f32, f32) {
+        let chunks = data.chunks_exact(8);
+        let remainder = chunks.remainder();
+        let (mut acc, mut acc_weighted) = (_mm256_setzero_ps(), _mm256_setzero_ps());
+        let mut weights = _mm256_add_ps(
+            _mm256_set1_ps(first_weight),
+            _mm256_mul_ps(
+                _mm256_set1_ps(step),
+                _mm256_setr_ps(0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0),
+            ),
+        );
+        let weights_step = _mm256_set1_ps(8.0 * step);
+        for chunk in chunks {
+            let values = _mm256_loadu_ps(chunk.as_ptr());
+            acc = _mm256_add_ps(acc, values);
+            acc_weighted = _mm256_add_ps(acc_weighted, _mm256_mul_ps(values, weights));
+            weights = _mm256_add_ps(weights, weights_step);
+        }
+        let mut weight = first_weight + step * (data.len() - remainder.len()) as f32;
+        let (mut sum, mut weighted_sum) =
+            (horizontal_sum_f32(acc), horizontal_sum_f32(acc_weighted));
+        for value in remainder {
+            sum += value;
+            weighted_sum += value * weight;
+            weight += step;
+        }
+        (sum, weighted_sum)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn window_means_f64(
+        sums: &[f64],
+        period: usize,
+        inv_period: f64,
+        output: &mut [f64],
+    ) {
+        let len = output.len();
+        let heads = &sums[period..period + len];
+        let tails = &sums[..len];
+        let inv_period_vec = _mm256_set1_pd(inv_period);
+        let mut idx = 0;
+        while idx + 4 <= len {
+            let head = _mm256_loadu_pd(heads.as_ptr().add(idx));
+            let tail = _mm256_loadu_pd(tails.as_ptr().add(idx));
+            let mean = _mm256_mul_pd(_mm256_sub_pd(head, tail), inv_period_vec);
+            _mm256_storeu_pd(output.as_mut_ptr().add(idx), mean);
+            idx += 4;
+        }
+        for idx in idx..len {
+            output[idx] = (heads[idx] - tails[idx]) * inv_period;
+        }
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn window_means_f32(
+        sums: &[f64],
+        period: usize,
+        inv_period: f64,
+        output: &mut [f32],
+    ) {
+        let len = output.len();
+        let heads = &sums[period..period + len];
+        let tails = &sums[..len];
+        let inv_period_vec = _mm256_set1_pd(inv_period);
+        let mut idx = 0;
+        while idx + 4 <= len {
+            let head = _mm256_loadu_pd(heads.as_ptr().add(idx));
+            let tail = _mm256_loadu_pd(tails.as_ptr().add(idx));
+            let mean = _mm256_mul_pd(_mm256_sub_pd(head, tail), inv_period_vec);
+            _mm_storeu_ps(output.as_mut_ptr().add(idx), _mm256_cvtpd_ps(mean));
+            idx += 4;
+        }
+        for idx in idx..len {
+            output[idx] = ((heads[idx] - tails[idx]) * inv_period) as f32;
+        }
+    }
+
+    /// Means and standard deviations of the 4 windows starting at `idx`,
+    /// the means are not centered
+    #[target_feature(enable = "avx")]
+    unsafe fn window_means_and_stds_f64(windows: &Windows, idx: usize) -> (__m256d, __m256d) {
+        let period = windows.period;
+        let inv_period = _mm256_set1_pd(windows.inv_period);
+        let sum = _mm256_sub_pd(
+            _mm256_loadu_pd(windows.sums.as_ptr().add(idx + period)),
+            _mm256_loadu_pd(windows.sums.as_ptr().add(idx)),
+        );
+        let sum_sq = _mm256_sub_pd(
+            _mm256_loadu_pd(windows.sums_sq.as_ptr().add(idx + period)),
+            _mm256_loadu_pd(windows.sums_sq.as_ptr().add(idx)),
+        );
+        let mean = _mm256_mul_pd(sum, inv_period);
+        let variance = _mm256_sub_pd(_mm256_mul_pd(sum_sq, inv_period), _mm256_mul_pd(mean, mean));
+        let std = _mm256_sqrt_pd(_mm256_andnot_pd(_mm256_set1_pd(-0.0), variance));
+        (_mm256_add_pd(_mm256_set1_pd(windows.center), mean), std)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn window_bands_f64(
+        windows: &Windows,
+        (up, down): (f64, f64),
+        sma_middle: bool,
+        output_upper: &mut [f64],
+        output_middle: &mut [f64],
+        output_lower: &mut [f64],
+    ) {
+        let len = output_middle.len();
+        let (up_vec, down_vec) = (_mm256_set1_pd(up), _mm256_set1_pd(down));
+        let mut idx = 0;
+        while idx + 4 <= len {
+            let (mean, std) = window_means_and_stds_f64(windows, idx);
+            let middle = if sma_middle {
+                _mm256_storeu_pd(output_middle.as_mut_ptr().add(idx), mean);
+                mean
+            } else {
+                _mm256_loadu_pd(output_middle.as_ptr().add(idx))
+            };
+            let upper = _mm256_add_pd(middle, _mm256_mul_pd(up_vec, std));
+            let lower = _mm256_sub_pd(middle, _mm256_mul_pd(down_vec, std));
+            _mm256_storeu_pd(output_upper.as_mut_ptr().add(idx), upper);
+            _mm256_storeu_pd(output_lower.as_mut_ptr().add(idx), lower);
+            idx += 4;
+        }
+        let windows = Windows {
+            sums: &windows.sums[idx..],
+            sums_sq: &windows.sums_sq[idx..],
+            ..*windows
+        };
+        scalar::window_bands(
+            &windows,
+            (up, down),
+            sma_middle,
+            &mut output_upper[idx..],
+            &mut output_middle[idx..],
+            &mut output_lower[idx..],
+        );
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn window_bands_f32(
+        windows: &Windows,
+        (up, down): (f32, f32),
+        sma_middle: bool,
+        output_upper: &mut [f32],
+        output_middle: &mut [f32],
+        output_lower: &mut [f32],
+    ) {
+        let len = output_middle.len();
+        let (up_vec, down_vec) = (_mm_set1_ps(up), _mm_set1_ps(down));
+        let mut idx = 0;
+        while idx + 4 <= len {
+            let (mean, std) = window_means_and_stds_f64(windows, idx);
+            let std = _mm256_cvtpd_ps(std);
+            let middle = if sma_middle {
+                let mean = _mm256_cvtpd_ps(mean);
+                _mm_storeu_ps(output_middle.as_mut_ptr().add(idx), mean);
+                mean
+            } else {
+                _mm_loadu_ps(output_middle.as_ptr().add(idx))
+            };
+            let upper = _mm_add_ps(middle, _mm_mul_ps(up_vec, std));
+            let lower = _mm_sub_ps(middle, _mm_mul_ps(down_vec, std));
+            _mm_storeu_ps(output_upper.as_mut_ptr().add(idx), upper);
+            _mm_storeu_ps(output_lower.as_mut_ptr().add(idx), lower);
+            idx += 4;
+        }
+        let windows = Windows {
+            sums: &windows.sums[idx..],
+            sums_sq: &windows.sums_sq[idx..],
+            ..*windows
+        };
+        scalar::window_bands(
+            &windows,
+            (up, down),
+            sma_middle,
+            &mut output_upper[idx..],
+            &mut output_middle[idx..],
+            &mut output_lower[idx..],
+        );
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn first_non_finite_f64(data: &[f64]) -> Option<usize> {
+        let chunks = data.chunks_exact(4);
+        let remainder = chunks.remainder();
+        let (sign_mask, infinity) = (_mm256_set1_pd(-0.0), _mm256_set1_pd(f64::INFINITY));
+        for (chunk_idx, chunk) in chunks.enumerate() {
+            let values = _mm256_andnot_pd(sign_mask, _mm256_loadu_pd(chunk.as_ptr()));
+            // NaN compares false, like infinities
+            let finite = _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_LT_OQ>(values, infinity));
+            if finite != 0b1111 {
+                return Some(chunk_idx * 4 + (!finite).trailing_zeros() as usize);
+            }
+        }
+        let offset = data.len() - remainder.len();
+        remainder
+            .iter()
+            .position(|value| !value.is_finite())
+            .map(|idx| offset + idx)
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn first_non_finite_f32(data: &[f32]) -> Option<usize> {
+        let chunks = data.chunks_exact(8);
+        let remainder = chunks.remainder();
+        let (sign_mask, infinity) = (_mm256_set1_ps(-0.0), _mm256_set1_ps(f32::INFINITY));
+        for (chunk_idx, chunk) in chunks.enumerate() {
+            let values = _mm256_andnot_ps(sign_mask, _mm256_loadu_ps(chunk.as_ptr()));
+            // NaN compares false, like infinities
+            let finite = _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_LT_OQ>(values, infinity));
+            if finite != 0b1111_1111 {
+                return Some(chunk_idx * 8 + (!finite).trailing_zeros() as usize);
+            }
+        }
+        let offset = data.len() - remainder.len();
+        remainder
+            .iter()
+            .position(|value| !value.is_finite())
+            .map(|idx| offset + idx)
+    }
+}
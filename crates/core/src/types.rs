@@ -1,7 +1,272 @@
+use crate::errors::TechalibError;
+use alloc::format;
+use core::fmt::{Debug, Display};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Default floating point type of the states and results
+/// ---
+/// The indicators are generic over [`TechalibFloat`], `Float` is only the
+/// default type parameter of their states and results.
+///
 /// Alias for `f32`
 #[cfg(all(feature = "f32", not(feature = "f64")))]
 pub type Float = f32;
 
+/// Default floating point type of the states and results
+/// ---
+/// The indicators are generic over [`TechalibFloat`], `Float` is only the
+/// default type parameter of their states and results.
+///
 /// Alias for `f64`
 #[cfg(not(all(feature = "f32", not(feature = "f64"))))]
 pub type Float = f64;
+
+/// Floating point trait
+/// ---
+/// This trait is implemented for `f32` and `f64`. The indicator functions and
+/// states are generic over it, so both precisions can be used in the same
+/// program (e.g. `f32` for memory-heavy screens and `f64` for research).
+///
+/// The methods have the same behaviour as the inherent methods of `f32` and
+/// `f64` with the same name. Without the `std` feature, the math functions
+/// are the ones of `libm`.
+pub trait TechalibFloat:
+    Copy
+    + Default
+    + Debug
+    + Display
+    + PartialEq
+    + PartialOrd
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sum
+    + for<'a> Sum<&'a Self>
+{
+    /// `0.0`
+    const ZERO: Self;
+    /// `1.0`
+    const ONE: Self;
+    /// Not a Number (NaN)
+    const NAN: Self;
+    /// Infinity (∞)
+    const INFINITY: Self;
+    /// Negative infinity (−∞)
+    const NEG_INFINITY: Self;
+    /// Smallest finite value
+    const MIN: Self;
+    /// Largest finite value
+    const MAX: Self;
+    /// Machine epsilon
+    const EPSILON: Self;
+
+    /// Converts a `f64` to the nearest value of this type
+    fn from_f64(value: f64) -> Self;
+    /// Converts a `usize` to the nearest value of this type
+    fn from_usize(value: usize) -> Self;
+    /// Converts the value to `f64`
+    fn to_f64(self) -> f64;
+
+    /// Returns `true` if this value is NaN
+    fn is_nan(self) -> bool;
+    /// Returns `true` if this value is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+    /// Returns `true` if this value is positive or negative infinity
+    fn is_infinite(self) -> bool;
+    /// Returns the maximum of the two numbers, ignoring NaN
+    fn max(self, other: Self) -> Self;
+    /// Returns the minimum of the two numbers, ignoring NaN
+    fn min(self, other: Self) -> Self;
+    /// Restricts the value to the interval `[min, max]`
+    fn clamp(self, min: Self, max: Self) -> Self;
+    /// Absolute value
+    fn abs(self) -> Self;
+    /// Raises the value to an integer power
+    fn powi(self, n: i32) -> Self;
+    /// Raises the value to a floating point power
+    fn powf(self, n: Self) -> Self;
+    /// Square root
+    fn sqrt(self) -> Self;
+    /// Exponential function
+    fn exp(self) -> Self;
+    /// Natural logarithm
+    fn ln(self) -> Self;
+    /// Base 10 logarithm
+    fn log10(self) -> Self;
+    /// Largest integer less than or equal to the value
+    fn floor(self) -> Self;
+    /// Smallest integer greater than or equal to the value
+    fn ceil(self) -> Self;
+    /// Nearest integer, rounding half-way cases away from `0.0`
+    fn round(self) -> Self;
+    /// Sine (in radians)
+    fn sin(self) -> Self;
+    /// Cosine (in radians)
+    fn cos(self) -> Self;
+    /// Tangent (in radians)
+    fn tan(self) -> Self;
+    /// Arcsine (in radians)
+    fn asin(self) -> Self;
+    /// Arccosine (in radians)
+    fn acos(self) -> Self;
+    /// Arctangent (in radians)
+    fn atan(self) -> Self;
+    /// Hyperbolic sine
+    fn sinh(self) -> Self;
+    /// Hyperbolic cosine
+    fn cosh(self) -> Self;
+    /// Hyperbolic tangent
+    fn tanh(self) -> Self;
+}
+
+macro_rules! impl_techalib_float {
+    ($t:ident) => {
+        impl TechalibFloat for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const NAN: Self = $t::NAN;
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+            const MIN: Self = $t::MIN;
+            const MAX: Self = $t::MAX;
+            const EPSILON: Self = $t::EPSILON;
+
+            #[inline(always)]
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+            #[inline(always)]
+            fn from_usize(value: usize) -> Self {
+                value as $t
+            }
+            #[inline(always)]
+            fn to_f64(self) -> f64 {
+                self.into()
+            }
+
+            impl_techalib_float!(@forward $t, bool: is_nan, is_finite, is_infinite);
+            impl_techalib_float!(@math $t: abs => fabs, sqrt => sqrt, exp => exp, ln => log,
+                log10 => log10, floor => floor, ceil => ceil, round => round, sin => sin,
+                cos => cos, tan => tan, asin => asin, acos => acos, atan => atan, sinh => sinh,
+                cosh => cosh, tanh => tanh);
+
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+            #[inline(always)]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $t::clamp(self, min, max)
+            }
+            #[inline(always)]
+            fn powi(self, n: i32) -> Self {
+                #[cfg(feature = "std")]
+                {
+                    $t::powi(self, n)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::Libm::<$t>::pow(self, n as $t)
+                }
+            }
+            #[inline(always)]
+            fn powf(self, n: Self) -> Self {
+                #[cfg(feature = "std")]
+                {
+                    $t::powf(self, n)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::Libm::<$t>::pow(self, n)
+                }
+            }
+        }
+    };
+    (@math $t:ident: $($method:ident => $libm:ident),+) => {
+        $(
+            #[inline(always)]
+            fn $method(self) -> Self {
+                #[cfg(feature = "std")]
+                {
+                    $t::$method(self)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::Libm::<$t>::$libm(self)
+                }
+            }
+        )+
+    };
+    (@forward $t:ident, $ret:ty: $($method:ident),+) => {
+        $(
+            #[inline(always)]
+            fn $method(self) -> $ret {
+                $t::$method(self)
+            }
+        )+
+    };
+}
+
+impl_techalib_float!(f32);
+impl_techalib_float!(f64);
+
+/// OHLCV column view
+/// ---
+/// This struct holds borrowed columns of a series of bars.
+/// It is the input of the indicators calculated from several
+/// price columns (e.g. typical price, ATR, ...).
+///
+/// Each indicator only reads the columns it needs, the columns that are not
+/// used can be left empty (the default value).
+///
+/// Attributes
+/// ---
+/// - `open`: The open prices.
+/// - `high`: The high prices.
+/// - `low`: The low prices.
+/// - `close`: The close prices.
+/// - `volume`: The volumes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ohlcv<'a, T = Float> {
+    /// The open prices.
+    pub open: &'a [T],
+    /// The high prices.
+    pub high: &'a [T],
+    /// The low prices.
+    pub low: &'a [T],
+    /// The close prices.
+    pub close: &'a [T],
+    /// The volumes.
+    pub volume: &'a [T],
+}
+
+/// Checks that all the given columns have the same length and returns it.
+///
+/// Each column is given with its name, which is used in the error message.
+pub(crate) fn columns_len<T>(columns: &[(&str, &[T])]) -> Result<usize, TechalibError> {
+    let len = columns.first().map_or(0, |(_, column)| column.len());
+    for (name, column) in columns {
+        if column.len() != len {
+            return Err(TechalibError::BadParam(format!(
+                "Input columns must have the same length, `{name}` has length {} instead of {len}",
+                column.len()
+            )));
+        }
+    }
+    Ok(len)
+}
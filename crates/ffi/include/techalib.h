@@ -0,0 +1,4831 @@
+/* Techalib: A TECHnical Analysis LIBrary - C API */
+
+#ifndef TECHALIB_H
+#define TECHALIB_H
+
+/* Generated with cbindgen:0.29.2 */
+
+/* Warning: this file is generated by cbindgen from crates/ffi, do not edit it manually. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Status code of the C functions
+ * ---
+ * `TECHALIB_STATUS_OK` on success, otherwise the kind of the error.
+ * The first codes map one-to-one to the variants of `TechalibError`,
+ * the next ones are specific to the C API.
+ */
+typedef enum TechalibStatus {
+  /**
+   * The call succeeded.
+   */
+  TECHALIB_STATUS_OK = 0,
+  /**
+   * A parameter passed to the function is invalid.
+   */
+  TECHALIB_STATUS_BAD_PARAM = 1,
+  /**
+   * There is not enough data to perform the calculation.
+   */
+  TECHALIB_STATUS_INSUFFICIENT_DATA = 2,
+  /**
+   * A data point is not finite (NaN or infinity).
+   */
+  TECHALIB_STATUS_DATA_NON_FINITE = 3,
+  /**
+   * An overflow occurred during the calculation.
+   */
+  TECHALIB_STATUS_OVERFLOW = 4,
+  /**
+   * The function is not implemented yet.
+   */
+  TECHALIB_STATUS_NOT_IMPLEMENTED_YET = 5,
+  /**
+   * A required pointer is null.
+   */
+  TECHALIB_STATUS_NULL_POINTER = 6,
+  /**
+   * The buffer given to a `_serialize` function is too small.
+   */
+  TECHALIB_STATUS_BUFFER_TOO_SMALL = 7,
+  /**
+   * The bytes given to a `_deserialize` function are not a valid state.
+   */
+  TECHALIB_STATUS_INVALID_STATE = 8,
+  /**
+   * An unexpected panic was caught at the boundary of the C API.
+   */
+  TECHALIB_STATUS_PANIC = 9,
+} TechalibStatus;
+
+/**
+ * Moving average of the Bollinger Bands
+ */
+typedef enum TechalibBbandsMa {
+  /**
+   * Simple Moving Average.
+   */
+  TECHALIB_BBANDS_MA_SMA,
+  /**
+   * Exponential Moving Average.
+   */
+  TECHALIB_BBANDS_MA_EMA,
+  /**
+   * Hull Moving Average.
+   */
+  TECHALIB_BBANDS_MA_HMA,
+  /**
+   * Zero-Lag Exponential Moving Average.
+   */
+  TECHALIB_BBANDS_MA_ZLEMA,
+  /**
+   * Arnaud Legoux Moving Average, with the default offset and sigma.
+   */
+  TECHALIB_BBANDS_MA_ALMA,
+  /**
+   * Smoothed Moving Average.
+   */
+  TECHALIB_BBANDS_MA_SMMA,
+  /**
+   * Variable Index Dynamic Average, with the default CMO period.
+   */
+  TECHALIB_BBANDS_MA_VIDYA,
+} TechalibBbandsMa;
+
+/**
+ * Kind of moving average
+ */
+typedef enum TechalibMaKind {
+  /**
+   * Simple Moving Average.
+   */
+  TECHALIB_MA_KIND_SMA,
+  /**
+   * Exponential Moving Average.
+   */
+  TECHALIB_MA_KIND_EMA,
+  /**
+   * Weighted Moving Average.
+   */
+  TECHALIB_MA_KIND_WMA,
+  /**
+   * Double Exponential Moving Average.
+   */
+  TECHALIB_MA_KIND_DEMA,
+  /**
+   * Triple Exponential Moving Average.
+   */
+  TECHALIB_MA_KIND_TEMA,
+  /**
+   * Triangular Moving Average.
+   */
+  TECHALIB_MA_KIND_TRIMA,
+  /**
+   * Kaufman Adaptive Moving Average.
+   */
+  TECHALIB_MA_KIND_KAMA,
+  /**
+   * Tillson Triple Exponential Moving Average.
+   */
+  TECHALIB_MA_KIND_T3,
+  /**
+   * Hull Moving Average.
+   */
+  TECHALIB_MA_KIND_HMA,
+  /**
+   * Zero-Lag Exponential Moving Average.
+   */
+  TECHALIB_MA_KIND_ZLEMA,
+  /**
+   * Arnaud Legoux Moving Average.
+   */
+  TECHALIB_MA_KIND_ALMA,
+  /**
+   * Smoothed Moving Average.
+   */
+  TECHALIB_MA_KIND_SMMA,
+  /**
+   * Variable Index Dynamic Average.
+   */
+  TECHALIB_MA_KIND_VIDYA,
+} TechalibMaKind;
+
+/**
+ * Moving average of the MAVP
+ */
+typedef enum TechalibMavpMa {
+  /**
+   * Simple Moving Average.
+   */
+  TECHALIB_MAVP_MA_SMA,
+  /**
+   * Exponential Moving Average.
+   */
+  TECHALIB_MAVP_MA_EMA,
+  /**
+   * Weighted Moving Average.
+   */
+  TECHALIB_MAVP_MA_WMA,
+  /**
+   * Hull Moving Average.
+   */
+  TECHALIB_MAVP_MA_HMA,
+  /**
+   * Zero-Lag Exponential Moving Average.
+   */
+  TECHALIB_MAVP_MA_ZLEMA,
+  /**
+   * Arnaud Legoux Moving Average, with the default offset and sigma.
+   */
+  TECHALIB_MAVP_MA_ALMA,
+  /**
+   * Smoothed Moving Average.
+   */
+  TECHALIB_MAVP_MA_SMMA,
+  /**
+   * Variable Index Dynamic Average, with the default CMO period.
+   */
+  TECHALIB_MAVP_MA_VIDYA,
+} TechalibMavpMa;
+
+/**
+ * Calculation method of the pivot points
+ */
+typedef enum TechalibPivotMethod {
+  /**
+   * Classic (floor) pivot points.
+   */
+  TECHALIB_PIVOT_METHOD_CLASSIC,
+  /**
+   * Fibonacci pivot points.
+   */
+  TECHALIB_PIVOT_METHOD_FIBONACCI,
+  /**
+   * Camarilla pivot points.
+   */
+  TECHALIB_PIVOT_METHOD_CAMARILLA,
+  /**
+   * Woodie pivot points.
+   */
+  TECHALIB_PIVOT_METHOD_WOODIE,
+  /**
+   * DeMark pivot points.
+   */
+  TECHALIB_PIVOT_METHOD_DEMARK,
+} TechalibPivotMethod;
+
+/**
+ * Estimator of the historical volatility
+ */
+typedef enum TechalibVolatilityEstimator {
+  /**
+   * Standard deviation of the close-to-close log returns.
+   */
+  TECHALIB_VOLATILITY_ESTIMATOR_CLOSE_TO_CLOSE,
+  /**
+   * Parkinson estimator (high, low).
+   */
+  TECHALIB_VOLATILITY_ESTIMATOR_PARKINSON,
+  /**
+   * Garman-Klass estimator (open, high, low, close).
+   */
+  TECHALIB_VOLATILITY_ESTIMATOR_GARMAN_KLASS,
+  /**
+   * Rogers-Satchell estimator (open, high, low, close).
+   */
+  TECHALIB_VOLATILITY_ESTIMATOR_ROGERS_SATCHELL,
+  /**
+   * Yang-Zhang estimator (open, high, low, close).
+   */
+  TECHALIB_VOLATILITY_ESTIMATOR_YANG_ZHANG,
+} TechalibVolatilityEstimator;
+
+/**
+ * Kind of session reset of the VWAP
+ */
+typedef enum TechalibVwapResetKind {
+  /**
+   * The VWAP is never reset.
+   */
+  TECHALIB_VWAP_RESET_KIND_NEVER,
+  /**
+   * A new session starts at each index where `mask` is `true`.
+   */
+  TECHALIB_VWAP_RESET_KIND_MASK,
+  /**
+   * A new session starts at each index of `anchors`.
+   */
+  TECHALIB_VWAP_RESET_KIND_ANCHORS,
+} TechalibVwapResetKind;
+
+/**
+ * Kind of threshold of the ZigZag
+ */
+typedef enum TechalibZigzagThresholdKind {
+  /**
+   * A percentage of the extreme price.
+   */
+  TECHALIB_ZIGZAG_THRESHOLD_KIND_PERCENT,
+  /**
+   * A multiple of the Average True Range over `atr_period`.
+   */
+  TECHALIB_ZIGZAG_THRESHOLD_KIND_ATR,
+} TechalibZigzagThresholdKind;
+
+/**
+ * Opaque handle on an ALMA state
+ */
+typedef struct TechalibAlmaState TechalibAlmaState;
+
+/**
+ * Opaque handle on an ATR state
+ */
+typedef struct TechalibAtrState TechalibAtrState;
+
+/**
+ * Opaque handle on a BBANDS state
+ */
+typedef struct TechalibBBandsState TechalibBBandsState;
+
+/**
+ * Opaque handle on a CHANDELIER_EXIT state
+ */
+typedef struct TechalibChandelierExitState TechalibChandelierExitState;
+
+/**
+ * Opaque handle on a CMF state
+ */
+typedef struct TechalibCmfState TechalibCmfState;
+
+/**
+ * Opaque handle on a DEMA state
+ */
+typedef struct TechalibDemaState TechalibDemaState;
+
+/**
+ * Opaque handle on a DONCHIAN state
+ */
+typedef struct TechalibDonchianState TechalibDonchianState;
+
+/**
+ * Opaque handle on an ELDER_RAY state
+ */
+typedef struct TechalibElderRayState TechalibElderRayState;
+
+/**
+ * Opaque handle on an EMA state
+ */
+typedef struct TechalibEmaState TechalibEmaState;
+
+/**
+ * Opaque handle on an ENVELOPE state
+ */
+typedef struct TechalibEnvelopeState TechalibEnvelopeState;
+
+/**
+ * Opaque handle on an EOM state
+ */
+typedef struct TechalibEomState TechalibEomState;
+
+/**
+ * Opaque handle on an FORCE_INDEX state
+ */
+typedef struct TechalibForceIndexState TechalibForceIndexState;
+
+/**
+ * Opaque handle on an HEIKIN_ASHI state
+ */
+typedef struct TechalibHeikinAshiState TechalibHeikinAshiState;
+
+/**
+ * Opaque handle on an HMA state
+ */
+typedef struct TechalibHmaState TechalibHmaState;
+
+/**
+ * Opaque handle on an ICHIMOKU state
+ */
+typedef struct TechalibIchimokuState TechalibIchimokuState;
+
+/**
+ * Opaque handle on a KAMA state
+ */
+typedef struct TechalibKamaState TechalibKamaState;
+
+/**
+ * Opaque handle on a KELTNER state
+ */
+typedef struct TechalibKeltnerState TechalibKeltnerState;
+
+/**
+ * Opaque handle on a KURTOSIS state
+ */
+typedef struct TechalibKurtosisState TechalibKurtosisState;
+
+/**
+ * Opaque handle on an MA state
+ */
+typedef struct TechalibMaState TechalibMaState;
+
+/**
+ * Opaque handle on an MACD state
+ */
+typedef struct TechalibMacdState TechalibMacdState;
+
+/**
+ * Opaque handle on an MAVP state
+ */
+typedef struct TechalibMavpState TechalibMavpState;
+
+/**
+ * Opaque handle on an MAXINDEX state
+ */
+typedef struct TechalibMaxIndexState TechalibMaxIndexState;
+
+/**
+ * Opaque handle on an MAX state
+ */
+typedef struct TechalibMaxState TechalibMaxState;
+
+/**
+ * Opaque handle on an MEDIAN state
+ */
+typedef struct TechalibMedianState TechalibMedianState;
+
+/**
+ * Opaque handle on an MININDEX state
+ */
+typedef struct TechalibMinIndexState TechalibMinIndexState;
+
+/**
+ * Opaque handle on an MINMAX state
+ */
+typedef struct TechalibMinMaxState TechalibMinMaxState;
+
+/**
+ * Opaque handle on an MIN state
+ */
+typedef struct TechalibMinState TechalibMinState;
+
+/**
+ * Opaque handle on a PERCENT_RANK state
+ */
+typedef struct TechalibPercentRankState TechalibPercentRankState;
+
+/**
+ * Opaque handle on a PIVOTS state
+ */
+typedef struct TechalibPivotsState TechalibPivotsState;
+
+/**
+ * Opaque handle on an RSI state
+ */
+typedef struct TechalibRsiState TechalibRsiState;
+
+/**
+ * Opaque handle on an SKEW state
+ */
+typedef struct TechalibSkewState TechalibSkewState;
+
+/**
+ * Opaque handle on an SMA state
+ */
+typedef struct TechalibSmaState TechalibSmaState;
+
+/**
+ * Opaque handle on an SMMA state
+ */
+typedef struct TechalibSmmaState TechalibSmmaState;
+
+/**
+ * Opaque handle on an SUM state
+ */
+typedef struct TechalibSumState TechalibSumState;
+
+/**
+ * Opaque handle on an SUPERTREND state
+ */
+typedef struct TechalibSupertrendState TechalibSupertrendState;
+
+/**
+ * Opaque handle on an SWING state
+ */
+typedef struct TechalibSwingState TechalibSwingState;
+
+/**
+ * Opaque handle on a T3 state
+ */
+typedef struct TechalibT3State TechalibT3State;
+
+/**
+ * Opaque handle on a TEMA state
+ */
+typedef struct TechalibTemaState TechalibTemaState;
+
+/**
+ * Opaque handle on a TRIMA state
+ */
+typedef struct TechalibTrimaState TechalibTrimaState;
+
+/**
+ * Opaque handle on a VIDYA state
+ */
+typedef struct TechalibVidyaState TechalibVidyaState;
+
+/**
+ * Opaque handle on a VOLATILITY state
+ */
+typedef struct TechalibVolatilityState TechalibVolatilityState;
+
+/**
+ * Opaque handle on a VWAP state
+ */
+typedef struct TechalibVwapState TechalibVwapState;
+
+/**
+ * Opaque handle on a WMA state
+ */
+typedef struct TechalibWmaState TechalibWmaState;
+
+/**
+ * Opaque handle on a ZIGZAG state
+ */
+typedef struct TechalibZigzagState TechalibZigzagState;
+
+/**
+ * Opaque handle on a ZLEMA state
+ */
+typedef struct TechalibZlemaState TechalibZlemaState;
+
+/**
+ * Opaque handle on a ZSCORE state
+ */
+typedef struct TechalibZscoreState TechalibZscoreState;
+
+#if !(defined(TECHALIB_F32) && !defined(TECHALIB_F64))
+/**
+ * Floating point type of the C API
+ * ---
+ * `double`, or `float` when the library is built with the `f32` feature.
+ * It only depends on the features of this crate, the indicators are called
+ * with it whatever the default `Float` of `techalib` is.
+ */
+typedef double TechalibFloat;
+#endif
+
+#if (defined(TECHALIB_F32) && !defined(TECHALIB_F64))
+/**
+ * Floating point type of the C API
+ * ---
+ * `double`, or `float` when the library is built with the `f32` feature.
+ * It only depends on the features of this crate, the indicators are called
+ * with it whatever the default `Float` of `techalib` is.
+ */
+typedef float TechalibFloat;
+#endif
+
+/**
+ * OHLCV columns
+ * ---
+ * Each column is a pointer to `len` values. The columns that are not used
+ * by the indicator can be null.
+ */
+typedef struct TechalibOhlcv {
+  /**
+   * The open prices.
+   */
+  const TechalibFloat *open;
+  /**
+   * The high prices.
+   */
+  const TechalibFloat *high;
+  /**
+   * The low prices.
+   */
+  const TechalibFloat *low;
+  /**
+   * The close prices.
+   */
+  const TechalibFloat *close;
+  /**
+   * The volumes.
+   */
+  const TechalibFloat *volume;
+  /**
+   * The number of bars.
+   */
+  size_t len;
+} TechalibOhlcv;
+
+/**
+ * Moving average and its parameters
+ * ---
+ * Only the parameters of `kind` are read.
+ */
+typedef struct TechalibMaType {
+  /**
+   * The kind of moving average.
+   */
+  enum TechalibMaKind kind;
+  /**
+   * The smoothing factor of EMA, DEMA, TEMA, T3 and ZLEMA, `NaN` for the default one.
+   */
+  TechalibFloat alpha;
+  /**
+   * The volume factor of T3.
+   */
+  TechalibFloat volume_factor;
+  /**
+   * The offset of ALMA.
+   */
+  TechalibFloat offset;
+  /**
+   * The sigma of ALMA.
+   */
+  TechalibFloat sigma;
+  /**
+   * The period of the Chande Momentum Oscillator of VIDYA.
+   */
+  size_t cmo_period;
+} TechalibMaType;
+
+/**
+ * Periods of the Ichimoku Kinko Hyo
+ */
+typedef struct TechalibIchimokuPeriods {
+  /**
+   * The period of the Tenkan-sen.
+   */
+  size_t tenkan;
+  /**
+   * The period of the Kijun-sen.
+   */
+  size_t kijun;
+  /**
+   * The period of the Senkou span B.
+   */
+  size_t senkou_b;
+  /**
+   * The number of bars the spans are shifted by.
+   */
+  size_t displacement;
+} TechalibIchimokuPeriods;
+
+/**
+ * Session reset of the VWAP
+ * ---
+ * `mask` holds one value per bar and is only read with `TECHALIB_VWAP_RESET_KIND_MASK`,
+ * `anchors` holds `anchors_len` indices and is only read with
+ * `TECHALIB_VWAP_RESET_KIND_ANCHORS`.
+ */
+typedef struct TechalibVwapReset {
+  /**
+   * The kind of session reset.
+   */
+  enum TechalibVwapResetKind kind;
+  /**
+   * The new session flags, one per bar.
+   */
+  const bool *mask;
+  /**
+   * The indices of the first bar of each session.
+   */
+  const size_t *anchors;
+  /**
+   * The number of indices in `anchors`.
+   */
+  size_t anchors_len;
+} TechalibVwapReset;
+
+/**
+ * Threshold of the ZigZag
+ * ---
+ * Only the parameters of `kind` are read.
+ */
+typedef struct TechalibZigzagThreshold {
+  /**
+   * The kind of threshold.
+   */
+  enum TechalibZigzagThresholdKind kind;
+  /**
+   * The percentage of the extreme price.
+   */
+  TechalibFloat percent;
+  /**
+   * The period of the ATR.
+   */
+  size_t atr_period;
+  /**
+   * The multiplier of the ATR.
+   */
+  TechalibFloat atr_multiplier;
+} TechalibZigzagThreshold;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Message of the last error of the calling thread
+ * ---
+ * It returns a null-terminated string describing the last failed call made
+ * by the calling thread, or a null pointer if no call failed yet.
+ * The string is owned by the library and stays valid until the next failed
+ * call on the same thread.
+ */
+const char *techalib_last_error_message(void);
+
+/**
+ * Name of a status code
+ * ---
+ * It returns a static null-terminated string, e.g. `"InsufficientData"`.
+ */
+const char *techalib_status_name(enum TechalibStatus status);
+
+/**
+ * Calculation of the ALMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibAlmaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_alma_into(const TechalibFloat *data,
+                                       size_t len,
+                                       size_t period,
+                                       TechalibFloat offset,
+                                       TechalibFloat sigma,
+                                       TechalibFloat *output,
+                                       struct TechalibAlmaState **state);
+
+/**
+ * Update of an ALMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_alma_state_update(struct TechalibAlmaState *state,
+                                               TechalibFloat sample,
+                                               TechalibFloat *output);
+
+/**
+ * Serialization of an ALMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_alma_state_serialize(const struct TechalibAlmaState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of an ALMA state
+ * ---
+ * It stores in `*state` a new [`TechalibAlmaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_alma_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_alma_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibAlmaState **state);
+
+/**
+ * Release of an ALMA state
+ * ---
+ * It frees a [`TechalibAlmaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_alma_state_free(struct TechalibAlmaState *state);
+
+/**
+ * Calculation of the ATR function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibAtrState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_atr_into(const struct TechalibOhlcv *ohlcv,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibAtrState **state);
+
+/**
+ * Update of an ATR state
+ * ---
+ * It updates `state` with the `high`, `low` and `close` of a new bar and writes the new
+ * value to `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_atr_state_update(struct TechalibAtrState *state,
+                                              TechalibFloat high,
+                                              TechalibFloat low,
+                                              TechalibFloat close,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an ATR state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_atr_state_serialize(const struct TechalibAtrState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an ATR state
+ * ---
+ * It stores in `*state` a new [`TechalibAtrState`] read from the `len` bytes of a
+ * state serialized with [`techalib_atr_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_atr_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibAtrState **state);
+
+/**
+ * Release of an ATR state
+ * ---
+ * It frees a [`TechalibAtrState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_atr_state_free(struct TechalibAtrState *state);
+
+/**
+ * Calculation of the BBANDS function
+ * ---
+ * It stores the `len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibBBandsState`] in `*state`.
+ *
+ * - `std_dev_up`, `std_dev_down`: The multipliers of the standard deviation of the bands.
+ * - `alpha`: The smoothing factor of the EMA and ZLEMA, `NaN` for the default `2 / (period + 1)`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output_upper`, `output_middle` and `output_lower` must each be valid for writes of `len`
+ *   values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_bbands_into(const TechalibFloat *data,
+                                         size_t len,
+                                         size_t period,
+                                         TechalibFloat std_dev_up,
+                                         TechalibFloat std_dev_down,
+                                         enum TechalibBbandsMa ma_type,
+                                         TechalibFloat alpha,
+                                         TechalibFloat *output_upper,
+                                         TechalibFloat *output_middle,
+                                         TechalibFloat *output_lower,
+                                         struct TechalibBBandsState **state);
+
+/**
+ * Calculation of the BBANDS function with the %B and the bandwidth
+ * ---
+ * It stores the `len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibBBandsState`] in `*state`.
+ *
+ * - `std_dev_up`, `std_dev_down`: The multipliers of the standard deviation of the bands.
+ * - `alpha`: The smoothing factor of the EMA and ZLEMA, `NaN` for the default `2 / (period + 1)`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output_upper`, `output_middle`, `output_lower`, `output_percent_b` and `output_bandwidth`
+ *   must each be valid for writes of `len` values, and must not overlap the inputs, or each
+ *   other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_bbands_ext_into(const TechalibFloat *data,
+                                             size_t len,
+                                             size_t period,
+                                             TechalibFloat std_dev_up,
+                                             TechalibFloat std_dev_down,
+                                             enum TechalibBbandsMa ma_type,
+                                             TechalibFloat alpha,
+                                             TechalibFloat *output_upper,
+                                             TechalibFloat *output_middle,
+                                             TechalibFloat *output_lower,
+                                             TechalibFloat *output_percent_b,
+                                             TechalibFloat *output_bandwidth,
+                                             struct TechalibBBandsState **state);
+
+/**
+ * Update of a BBANDS state
+ * ---
+ * It updates `state` with a new sample and writes the new values to the
+ * output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_upper`, `output_middle`, `output_lower`, `output_percent_b` and `output_bandwidth`
+ *   must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_bbands_state_update(struct TechalibBBandsState *state,
+                                                 TechalibFloat sample,
+                                                 TechalibFloat *output_upper,
+                                                 TechalibFloat *output_middle,
+                                                 TechalibFloat *output_lower,
+                                                 TechalibFloat *output_percent_b,
+                                                 TechalibFloat *output_bandwidth);
+
+/**
+ * Serialization of a BBANDS state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_bbands_state_serialize(const struct TechalibBBandsState *state,
+                                                    uint8_t *buffer,
+                                                    size_t capacity,
+                                                    size_t *written);
+
+/**
+ * Deserialization of a BBANDS state
+ * ---
+ * It stores in `*state` a new [`TechalibBBandsState`] read from the `len` bytes of a
+ * state serialized with [`techalib_bbands_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_bbands_state_deserialize(const uint8_t *bytes,
+                                                      size_t len,
+                                                      struct TechalibBBandsState **state);
+
+/**
+ * Release of a BBANDS state
+ * ---
+ * It frees a [`TechalibBBandsState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_bbands_state_free(struct TechalibBBandsState *state);
+
+/**
+ * Calculation of the CHANDELIER_EXIT function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibChandelierExitState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_long_stop` and `output_short_stop` must each be valid for writes of `ohlcv.len`
+ *   values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_chandelier_exit_into(const struct TechalibOhlcv *ohlcv,
+                                                  size_t period,
+                                                  TechalibFloat multiplier,
+                                                  TechalibFloat *output_long_stop,
+                                                  TechalibFloat *output_short_stop,
+                                                  struct TechalibChandelierExitState **state);
+
+/**
+ * Update of a CHANDELIER_EXIT state
+ * ---
+ * It updates `state` with the `high`, `low` and `close` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_long_stop` and `output_short_stop` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_chandelier_exit_state_update(struct TechalibChandelierExitState *state,
+                                                          TechalibFloat high,
+                                                          TechalibFloat low,
+                                                          TechalibFloat close,
+                                                          TechalibFloat *output_long_stop,
+                                                          TechalibFloat *output_short_stop);
+
+/**
+ * Serialization of a CHANDELIER_EXIT state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_chandelier_exit_state_serialize(const struct TechalibChandelierExitState *state,
+                                                             uint8_t *buffer,
+                                                             size_t capacity,
+                                                             size_t *written);
+
+/**
+ * Deserialization of a CHANDELIER_EXIT state
+ * ---
+ * It stores in `*state` a new [`TechalibChandelierExitState`] read from the `len` bytes of a
+ * state serialized with [`techalib_chandelier_exit_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_chandelier_exit_state_deserialize(const uint8_t *bytes,
+                                                               size_t len,
+                                                               struct TechalibChandelierExitState **state);
+
+/**
+ * Release of a CHANDELIER_EXIT state
+ * ---
+ * It frees a [`TechalibChandelierExitState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_chandelier_exit_state_free(struct TechalibChandelierExitState *state);
+
+/**
+ * Calculation of the CMF function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibCmfState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_cmf_into(const struct TechalibOhlcv *ohlcv,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibCmfState **state);
+
+/**
+ * Update of a CMF state
+ * ---
+ * It updates `state` with the `high`, `low`, `close` and `volume` of a new bar and writes the new
+ * value to `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_cmf_state_update(struct TechalibCmfState *state,
+                                              TechalibFloat high,
+                                              TechalibFloat low,
+                                              TechalibFloat close,
+                                              TechalibFloat volume,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of a CMF state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_cmf_state_serialize(const struct TechalibCmfState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of a CMF state
+ * ---
+ * It stores in `*state` a new [`TechalibCmfState`] read from the `len` bytes of a
+ * state serialized with [`techalib_cmf_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_cmf_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibCmfState **state);
+
+/**
+ * Release of a CMF state
+ * ---
+ * It frees a [`TechalibCmfState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_cmf_state_free(struct TechalibCmfState *state);
+
+/**
+ * Calculation of the DEMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibDemaState`] in `*state`.
+ *
+ * - `alpha`: The smoothing factor, `NaN` for the default `2 / (period + 1)`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_dema_into(const TechalibFloat *data,
+                                       size_t len,
+                                       size_t period,
+                                       TechalibFloat alpha,
+                                       TechalibFloat *output,
+                                       struct TechalibDemaState **state);
+
+/**
+ * Update of a DEMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_dema_state_update(struct TechalibDemaState *state,
+                                               TechalibFloat sample,
+                                               TechalibFloat *output);
+
+/**
+ * Serialization of a DEMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_dema_state_serialize(const struct TechalibDemaState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of a DEMA state
+ * ---
+ * It stores in `*state` a new [`TechalibDemaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_dema_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_dema_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibDemaState **state);
+
+/**
+ * Release of a DEMA state
+ * ---
+ * It frees a [`TechalibDemaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_dema_state_free(struct TechalibDemaState *state);
+
+/**
+ * Calculation of the DONCHIAN function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibDonchianState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_upper`, `output_middle` and `output_lower` must each be valid for writes of
+ *   `ohlcv.len` values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_donchian_into(const struct TechalibOhlcv *ohlcv,
+                                           size_t period,
+                                           TechalibFloat *output_upper,
+                                           TechalibFloat *output_middle,
+                                           TechalibFloat *output_lower,
+                                           struct TechalibDonchianState **state);
+
+/**
+ * Update of a DONCHIAN state
+ * ---
+ * It updates `state` with the `high` and `low` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_upper`, `output_middle` and `output_lower` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_donchian_state_update(struct TechalibDonchianState *state,
+                                                   TechalibFloat high,
+                                                   TechalibFloat low,
+                                                   TechalibFloat *output_upper,
+                                                   TechalibFloat *output_middle,
+                                                   TechalibFloat *output_lower);
+
+/**
+ * Serialization of a DONCHIAN state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_donchian_state_serialize(const struct TechalibDonchianState *state,
+                                                      uint8_t *buffer,
+                                                      size_t capacity,
+                                                      size_t *written);
+
+/**
+ * Deserialization of a DONCHIAN state
+ * ---
+ * It stores in `*state` a new [`TechalibDonchianState`] read from the `len` bytes of a
+ * state serialized with [`techalib_donchian_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_donchian_state_deserialize(const uint8_t *bytes,
+                                                        size_t len,
+                                                        struct TechalibDonchianState **state);
+
+/**
+ * Release of a DONCHIAN state
+ * ---
+ * It frees a [`TechalibDonchianState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_donchian_state_free(struct TechalibDonchianState *state);
+
+/**
+ * Calculation of the ELDER_RAY function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibElderRayState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_bull_power` and `output_bear_power` must each be valid for writes of `ohlcv.len`
+ *   values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_elder_ray_into(const struct TechalibOhlcv *ohlcv,
+                                            size_t period,
+                                            TechalibFloat *output_bull_power,
+                                            TechalibFloat *output_bear_power,
+                                            struct TechalibElderRayState **state);
+
+/**
+ * Update of an ELDER_RAY state
+ * ---
+ * It updates `state` with the `high`, `low` and `close` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_bull_power` and `output_bear_power` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_elder_ray_state_update(struct TechalibElderRayState *state,
+                                                    TechalibFloat high,
+                                                    TechalibFloat low,
+                                                    TechalibFloat close,
+                                                    TechalibFloat *output_bull_power,
+                                                    TechalibFloat *output_bear_power);
+
+/**
+ * Serialization of an ELDER_RAY state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_elder_ray_state_serialize(const struct TechalibElderRayState *state,
+                                                       uint8_t *buffer,
+                                                       size_t capacity,
+                                                       size_t *written);
+
+/**
+ * Deserialization of an ELDER_RAY state
+ * ---
+ * It stores in `*state` a new [`TechalibElderRayState`] read from the `len` bytes of a
+ * state serialized with [`techalib_elder_ray_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_elder_ray_state_deserialize(const uint8_t *bytes,
+                                                         size_t len,
+                                                         struct TechalibElderRayState **state);
+
+/**
+ * Release of an ELDER_RAY state
+ * ---
+ * It frees a [`TechalibElderRayState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_elder_ray_state_free(struct TechalibElderRayState *state);
+
+/**
+ * Calculation of the EMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibEmaState`] in `*state`.
+ *
+ * - `alpha`: The smoothing factor, `NaN` for the default `2 / (period + 1)`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_ema_into(const TechalibFloat *data,
+                                      size_t len,
+                                      size_t period,
+                                      TechalibFloat alpha,
+                                      TechalibFloat *output,
+                                      struct TechalibEmaState **state);
+
+/**
+ * Update of an EMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_ema_state_update(struct TechalibEmaState *state,
+                                              TechalibFloat sample,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an EMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_ema_state_serialize(const struct TechalibEmaState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an EMA state
+ * ---
+ * It stores in `*state` a new [`TechalibEmaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_ema_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_ema_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibEmaState **state);
+
+/**
+ * Release of an EMA state
+ * ---
+ * It frees a [`TechalibEmaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_ema_state_free(struct TechalibEmaState *state);
+
+/**
+ * Calculation of the ENVELOPE function
+ * ---
+ * It stores the `len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibEnvelopeState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output_upper`, `output_middle` and `output_lower` must each be valid for writes of `len`
+ *   values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_envelope_into(const TechalibFloat *data,
+                                           size_t len,
+                                           size_t period,
+                                           TechalibFloat percent,
+                                           struct TechalibMaType ma_type,
+                                           TechalibFloat *output_upper,
+                                           TechalibFloat *output_middle,
+                                           TechalibFloat *output_lower,
+                                           struct TechalibEnvelopeState **state);
+
+/**
+ * Update of an ENVELOPE state
+ * ---
+ * It updates `state` with a new sample and writes the new values to the
+ * output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_upper`, `output_middle` and `output_lower` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_envelope_state_update(struct TechalibEnvelopeState *state,
+                                                   TechalibFloat sample,
+                                                   TechalibFloat *output_upper,
+                                                   TechalibFloat *output_middle,
+                                                   TechalibFloat *output_lower);
+
+/**
+ * Serialization of an ENVELOPE state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_envelope_state_serialize(const struct TechalibEnvelopeState *state,
+                                                      uint8_t *buffer,
+                                                      size_t capacity,
+                                                      size_t *written);
+
+/**
+ * Deserialization of an ENVELOPE state
+ * ---
+ * It stores in `*state` a new [`TechalibEnvelopeState`] read from the `len` bytes of a
+ * state serialized with [`techalib_envelope_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_envelope_state_deserialize(const uint8_t *bytes,
+                                                        size_t len,
+                                                        struct TechalibEnvelopeState **state);
+
+/**
+ * Release of an ENVELOPE state
+ * ---
+ * It frees a [`TechalibEnvelopeState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_envelope_state_free(struct TechalibEnvelopeState *state);
+
+/**
+ * Calculation of the EOM function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibEomState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_eom_into(const struct TechalibOhlcv *ohlcv,
+                                      size_t period,
+                                      TechalibFloat scale,
+                                      TechalibFloat *output,
+                                      struct TechalibEomState **state);
+
+/**
+ * Update of an EOM state
+ * ---
+ * It updates `state` with the `high`, `low` and `volume` of a new bar and writes the new
+ * value to `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_eom_state_update(struct TechalibEomState *state,
+                                              TechalibFloat high,
+                                              TechalibFloat low,
+                                              TechalibFloat volume,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an EOM state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_eom_state_serialize(const struct TechalibEomState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an EOM state
+ * ---
+ * It stores in `*state` a new [`TechalibEomState`] read from the `len` bytes of a
+ * state serialized with [`techalib_eom_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_eom_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibEomState **state);
+
+/**
+ * Release of an EOM state
+ * ---
+ * It frees a [`TechalibEomState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_eom_state_free(struct TechalibEomState *state);
+
+/**
+ * Calculation of the FORCE_INDEX function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibForceIndexState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_force_index_into(const struct TechalibOhlcv *ohlcv,
+                                              size_t period,
+                                              TechalibFloat *output,
+                                              struct TechalibForceIndexState **state);
+
+/**
+ * Update of an FORCE_INDEX state
+ * ---
+ * It updates `state` with the `close` and `volume` of a new bar and writes the new
+ * value to `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_force_index_state_update(struct TechalibForceIndexState *state,
+                                                      TechalibFloat close,
+                                                      TechalibFloat volume,
+                                                      TechalibFloat *output);
+
+/**
+ * Serialization of an FORCE_INDEX state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_force_index_state_serialize(const struct TechalibForceIndexState *state,
+                                                         uint8_t *buffer,
+                                                         size_t capacity,
+                                                         size_t *written);
+
+/**
+ * Deserialization of an FORCE_INDEX state
+ * ---
+ * It stores in `*state` a new [`TechalibForceIndexState`] read from the `len` bytes of a
+ * state serialized with [`techalib_force_index_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_force_index_state_deserialize(const uint8_t *bytes,
+                                                           size_t len,
+                                                           struct TechalibForceIndexState **state);
+
+/**
+ * Release of an FORCE_INDEX state
+ * ---
+ * It frees a [`TechalibForceIndexState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_force_index_state_free(struct TechalibForceIndexState *state);
+
+/**
+ * Calculation of the HEIKIN_ASHI function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibHeikinAshiState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_open`, `output_high`, `output_low` and `output_close` must each be valid for
+ *   writes of `ohlcv.len` values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_heikin_ashi_into(const struct TechalibOhlcv *ohlcv,
+                                              TechalibFloat *output_open,
+                                              TechalibFloat *output_high,
+                                              TechalibFloat *output_low,
+                                              TechalibFloat *output_close,
+                                              struct TechalibHeikinAshiState **state);
+
+/**
+ * Update of an HEIKIN_ASHI state
+ * ---
+ * It updates `state` with the `open`, `high`, `low` and `close` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_open`, `output_high`, `output_low` and `output_close` must each be null or valid
+ *   for a write.
+ */
+enum TechalibStatus techalib_heikin_ashi_state_update(struct TechalibHeikinAshiState *state,
+                                                      TechalibFloat open,
+                                                      TechalibFloat high,
+                                                      TechalibFloat low,
+                                                      TechalibFloat close,
+                                                      TechalibFloat *output_open,
+                                                      TechalibFloat *output_high,
+                                                      TechalibFloat *output_low,
+                                                      TechalibFloat *output_close);
+
+/**
+ * Serialization of an HEIKIN_ASHI state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_heikin_ashi_state_serialize(const struct TechalibHeikinAshiState *state,
+                                                         uint8_t *buffer,
+                                                         size_t capacity,
+                                                         size_t *written);
+
+/**
+ * Deserialization of an HEIKIN_ASHI state
+ * ---
+ * It stores in `*state` a new [`TechalibHeikinAshiState`] read from the `len` bytes of a
+ * state serialized with [`techalib_heikin_ashi_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_heikin_ashi_state_deserialize(const uint8_t *bytes,
+                                                           size_t len,
+                                                           struct TechalibHeikinAshiState **state);
+
+/**
+ * Release of an HEIKIN_ASHI state
+ * ---
+ * It frees a [`TechalibHeikinAshiState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_heikin_ashi_state_free(struct TechalibHeikinAshiState *state);
+
+/**
+ * Calculation of the HMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibHmaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_hma_into(const TechalibFloat *data,
+                                      size_t len,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibHmaState **state);
+
+/**
+ * Update of an HMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_hma_state_update(struct TechalibHmaState *state,
+                                              TechalibFloat sample,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an HMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_hma_state_serialize(const struct TechalibHmaState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an HMA state
+ * ---
+ * It stores in `*state` a new [`TechalibHmaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_hma_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_hma_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibHmaState **state);
+
+/**
+ * Release of an HMA state
+ * ---
+ * It frees a [`TechalibHmaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_hma_state_free(struct TechalibHmaState *state);
+
+/**
+ * Calculation of the ICHIMOKU function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibIchimokuState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_tenkan`, `output_kijun`, `output_senkou_a`, `output_senkou_b` and `output_chikou`
+ *   must each be valid for writes of `ohlcv.len` values, and must not overlap the inputs, or
+ *   each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_ichimoku_into(const struct TechalibOhlcv *ohlcv,
+                                           struct TechalibIchimokuPeriods periods,
+                                           TechalibFloat *output_tenkan,
+                                           TechalibFloat *output_kijun,
+                                           TechalibFloat *output_senkou_a,
+                                           TechalibFloat *output_senkou_b,
+                                           TechalibFloat *output_chikou,
+                                           struct TechalibIchimokuState **state);
+
+/**
+ * Update of an ICHIMOKU state
+ * ---
+ * It updates `state` with the `high`, `low` and `close` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_tenkan`, `output_kijun`, `output_senkou_a`, `output_senkou_b` and `output_chikou`
+ *   must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_ichimoku_state_update(struct TechalibIchimokuState *state,
+                                                   TechalibFloat high,
+                                                   TechalibFloat low,
+                                                   TechalibFloat close,
+                                                   TechalibFloat *output_tenkan,
+                                                   TechalibFloat *output_kijun,
+                                                   TechalibFloat *output_senkou_a,
+                                                   TechalibFloat *output_senkou_b,
+                                                   TechalibFloat *output_chikou);
+
+/**
+ * Serialization of an ICHIMOKU state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_ichimoku_state_serialize(const struct TechalibIchimokuState *state,
+                                                      uint8_t *buffer,
+                                                      size_t capacity,
+                                                      size_t *written);
+
+/**
+ * Deserialization of an ICHIMOKU state
+ * ---
+ * It stores in `*state` a new [`TechalibIchimokuState`] read from the `len` bytes of a
+ * state serialized with [`techalib_ichimoku_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_ichimoku_state_deserialize(const uint8_t *bytes,
+                                                        size_t len,
+                                                        struct TechalibIchimokuState **state);
+
+/**
+ * Release of an ICHIMOKU state
+ * ---
+ * It frees a [`TechalibIchimokuState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_ichimoku_state_free(struct TechalibIchimokuState *state);
+
+/**
+ * Calculation of the KAMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibKamaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_kama_into(const TechalibFloat *data,
+                                       size_t len,
+                                       size_t period,
+                                       TechalibFloat *output,
+                                       struct TechalibKamaState **state);
+
+/**
+ * Update of a KAMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_kama_state_update(struct TechalibKamaState *state,
+                                               TechalibFloat sample,
+                                               TechalibFloat *output);
+
+/**
+ * Serialization of a KAMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_kama_state_serialize(const struct TechalibKamaState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of a KAMA state
+ * ---
+ * It stores in `*state` a new [`TechalibKamaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_kama_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_kama_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibKamaState **state);
+
+/**
+ * Release of a KAMA state
+ * ---
+ * It frees a [`TechalibKamaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_kama_state_free(struct TechalibKamaState *state);
+
+/**
+ * Calculation of the KELTNER function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibKeltnerState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_upper`, `output_middle` and `output_lower` must each be valid for writes of
+ *   `ohlcv.len` values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_keltner_into(const struct TechalibOhlcv *ohlcv,
+                                          size_t period,
+                                          size_t atr_period,
+                                          TechalibFloat multiplier,
+                                          TechalibFloat *output_upper,
+                                          TechalibFloat *output_middle,
+                                          TechalibFloat *output_lower,
+                                          struct TechalibKeltnerState **state);
+
+/**
+ * Update of a KELTNER state
+ * ---
+ * It updates `state` with the `high`, `low` and `close` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_upper`, `output_middle` and `output_lower` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_keltner_state_update(struct TechalibKeltnerState *state,
+                                                  TechalibFloat high,
+                                                  TechalibFloat low,
+                                                  TechalibFloat close,
+                                                  TechalibFloat *output_upper,
+                                                  TechalibFloat *output_middle,
+                                                  TechalibFloat *output_lower);
+
+/**
+ * Serialization of a KELTNER state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_keltner_state_serialize(const struct TechalibKeltnerState *state,
+                                                     uint8_t *buffer,
+                                                     size_t capacity,
+                                                     size_t *written);
+
+/**
+ * Deserialization of a KELTNER state
+ * ---
+ * It stores in `*state` a new [`TechalibKeltnerState`] read from the `len` bytes of a
+ * state serialized with [`techalib_keltner_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_keltner_state_deserialize(const uint8_t *bytes,
+                                                       size_t len,
+                                                       struct TechalibKeltnerState **state);
+
+/**
+ * Release of a KELTNER state
+ * ---
+ * It frees a [`TechalibKeltnerState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_keltner_state_free(struct TechalibKeltnerState *state);
+
+/**
+ * Calculation of the KURTOSIS function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibKurtosisState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_kurtosis_into(const TechalibFloat *data,
+                                           size_t len,
+                                           size_t period,
+                                           TechalibFloat *output,
+                                           struct TechalibKurtosisState **state);
+
+/**
+ * Update of a KURTOSIS state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_kurtosis_state_update(struct TechalibKurtosisState *state,
+                                                   TechalibFloat sample,
+                                                   TechalibFloat *output);
+
+/**
+ * Serialization of a KURTOSIS state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_kurtosis_state_serialize(const struct TechalibKurtosisState *state,
+                                                      uint8_t *buffer,
+                                                      size_t capacity,
+                                                      size_t *written);
+
+/**
+ * Deserialization of a KURTOSIS state
+ * ---
+ * It stores in `*state` a new [`TechalibKurtosisState`] read from the `len` bytes of a
+ * state serialized with [`techalib_kurtosis_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_kurtosis_state_deserialize(const uint8_t *bytes,
+                                                        size_t len,
+                                                        struct TechalibKurtosisState **state);
+
+/**
+ * Release of a KURTOSIS state
+ * ---
+ * It frees a [`TechalibKurtosisState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_kurtosis_state_free(struct TechalibKurtosisState *state);
+
+/**
+ * Calculation of the MA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibMaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_ma_into(const TechalibFloat *data,
+                                     size_t len,
+                                     size_t period,
+                                     struct TechalibMaType ma_type,
+                                     TechalibFloat *output,
+                                     struct TechalibMaState **state);
+
+/**
+ * Update of an MA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_ma_state_update(struct TechalibMaState *state,
+                                             TechalibFloat sample,
+                                             TechalibFloat *output);
+
+/**
+ * Serialization of an MA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_ma_state_serialize(const struct TechalibMaState *state,
+                                                uint8_t *buffer,
+                                                size_t capacity,
+                                                size_t *written);
+
+/**
+ * Deserialization of an MA state
+ * ---
+ * It stores in `*state` a new [`TechalibMaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_ma_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_ma_state_deserialize(const uint8_t *bytes,
+                                                  size_t len,
+                                                  struct TechalibMaState **state);
+
+/**
+ * Release of an MA state
+ * ---
+ * It frees a [`TechalibMaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_ma_state_free(struct TechalibMaState *state);
+
+/**
+ * Calculation of the MACD function
+ * ---
+ * It stores the `len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibMacdState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output_macd`, `output_signal` and `output_histogram` must each be valid for writes of
+ *   `len` values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_macd_into(const TechalibFloat *data,
+                                       size_t len,
+                                       size_t fast_period,
+                                       size_t slow_period,
+                                       size_t signal_period,
+                                       TechalibFloat *output_macd,
+                                       TechalibFloat *output_signal,
+                                       TechalibFloat *output_histogram,
+                                       struct TechalibMacdState **state);
+
+/**
+ * Update of an MACD state
+ * ---
+ * It updates `state` with a new sample and writes the new values to the
+ * output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_macd`, `output_signal` and `output_histogram` must each be null or valid for a
+ *   write.
+ */
+enum TechalibStatus techalib_macd_state_update(struct TechalibMacdState *state,
+                                               TechalibFloat sample,
+                                               TechalibFloat *output_macd,
+                                               TechalibFloat *output_signal,
+                                               TechalibFloat *output_histogram);
+
+/**
+ * Serialization of an MACD state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_macd_state_serialize(const struct TechalibMacdState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of an MACD state
+ * ---
+ * It stores in `*state` a new [`TechalibMacdState`] read from the `len` bytes of a
+ * state serialized with [`techalib_macd_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_macd_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibMacdState **state);
+
+/**
+ * Release of an MACD state
+ * ---
+ * It frees a [`TechalibMacdState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_macd_state_free(struct TechalibMacdState *state);
+
+/**
+ * Calculation of the vector arithmetic ADD function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data0` must be valid for reads of `len` values.
+ * - `data1` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_add_into(const TechalibFloat *data0,
+                                      const TechalibFloat *data1,
+                                      size_t len,
+                                      TechalibFloat *output);
+
+/**
+ * Calculation of the vector arithmetic SUB function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data0` must be valid for reads of `len` values.
+ * - `data1` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_sub_into(const TechalibFloat *data0,
+                                      const TechalibFloat *data1,
+                                      size_t len,
+                                      TechalibFloat *output);
+
+/**
+ * Calculation of the vector arithmetic MULT function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data0` must be valid for reads of `len` values.
+ * - `data1` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_mult_into(const TechalibFloat *data0,
+                                       const TechalibFloat *data1,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the vector arithmetic DIV function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data0` must be valid for reads of `len` values.
+ * - `data1` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_div_into(const TechalibFloat *data0,
+                                      const TechalibFloat *data1,
+                                      size_t len,
+                                      TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric ACOS function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_acos_into(const TechalibFloat *data,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric ASIN function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_asin_into(const TechalibFloat *data,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric ATAN function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_atan_into(const TechalibFloat *data,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the vector CEIL function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_ceil_into(const TechalibFloat *data,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric COS function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_cos_into(const TechalibFloat *data, size_t len, TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric COSH function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_cosh_into(const TechalibFloat *data,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the vector arithmetic EXP function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_exp_into(const TechalibFloat *data, size_t len, TechalibFloat *output);
+
+/**
+ * Calculation of the vector FLOOR function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_floor_into(const TechalibFloat *data,
+                                        size_t len,
+                                        TechalibFloat *output);
+
+/**
+ * Calculation of the vector natural logarithm LN function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_ln_into(const TechalibFloat *data, size_t len, TechalibFloat *output);
+
+/**
+ * Calculation of the vector LOG10 function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_log10_into(const TechalibFloat *data,
+                                        size_t len,
+                                        TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric SIN function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_sin_into(const TechalibFloat *data, size_t len, TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric SINH function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_sinh_into(const TechalibFloat *data,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the vector square root SQRT function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_sqrt_into(const TechalibFloat *data,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric TAN function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_tan_into(const TechalibFloat *data, size_t len, TechalibFloat *output);
+
+/**
+ * Calculation of the vector trigonometric TANH function
+ * ---
+ * It stores the `len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_tanh_into(const TechalibFloat *data,
+                                       size_t len,
+                                       TechalibFloat *output);
+
+/**
+ * Calculation of the MAVP function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibMavpState`] in `*state`.
+ *
+ * - `periods`: The `len` periods to use for each value of `data`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `periods` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_mavp_into(const TechalibFloat *data,
+                                       const TechalibFloat *periods,
+                                       size_t len,
+                                       size_t min_period,
+                                       size_t max_period,
+                                       enum TechalibMavpMa ma_type,
+                                       TechalibFloat *output,
+                                       struct TechalibMavpState **state);
+
+/**
+ * Update of an MAVP state
+ * ---
+ * It updates `state` with a new sample and its period, and writes the new value
+ * to `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_mavp_state_update(struct TechalibMavpState *state,
+                                               TechalibFloat sample,
+                                               TechalibFloat period,
+                                               TechalibFloat *output);
+
+/**
+ * Serialization of an MAVP state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_mavp_state_serialize(const struct TechalibMavpState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of an MAVP state
+ * ---
+ * It stores in `*state` a new [`TechalibMavpState`] read from the `len` bytes of a
+ * state serialized with [`techalib_mavp_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_mavp_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibMavpState **state);
+
+/**
+ * Release of an MAVP state
+ * ---
+ * It frees a [`TechalibMavpState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_mavp_state_free(struct TechalibMavpState *state);
+
+/**
+ * Calculation of the MAX function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibMaxState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_max_into(const TechalibFloat *data,
+                                      size_t len,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibMaxState **state);
+
+/**
+ * Update of an MAX state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_max_state_update(struct TechalibMaxState *state,
+                                              TechalibFloat sample,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an MAX state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_max_state_serialize(const struct TechalibMaxState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an MAX state
+ * ---
+ * It stores in `*state` a new [`TechalibMaxState`] read from the `len` bytes of a
+ * state serialized with [`techalib_max_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_max_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibMaxState **state);
+
+/**
+ * Release of an MAX state
+ * ---
+ * It frees a [`TechalibMaxState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_max_state_free(struct TechalibMaxState *state);
+
+/**
+ * Calculation of the MAXINDEX function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibMaxIndexState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_maxindex_into(const TechalibFloat *data,
+                                           size_t len,
+                                           size_t period,
+                                           TechalibFloat *output,
+                                           struct TechalibMaxIndexState **state);
+
+/**
+ * Update of an MAXINDEX state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_maxindex_state_update(struct TechalibMaxIndexState *state,
+                                                   TechalibFloat sample,
+                                                   TechalibFloat *output);
+
+/**
+ * Serialization of an MAXINDEX state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_maxindex_state_serialize(const struct TechalibMaxIndexState *state,
+                                                      uint8_t *buffer,
+                                                      size_t capacity,
+                                                      size_t *written);
+
+/**
+ * Deserialization of an MAXINDEX state
+ * ---
+ * It stores in `*state` a new [`TechalibMaxIndexState`] read from the `len` bytes of a
+ * state serialized with [`techalib_maxindex_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_maxindex_state_deserialize(const uint8_t *bytes,
+                                                        size_t len,
+                                                        struct TechalibMaxIndexState **state);
+
+/**
+ * Release of an MAXINDEX state
+ * ---
+ * It frees a [`TechalibMaxIndexState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_maxindex_state_free(struct TechalibMaxIndexState *state);
+
+/**
+ * Calculation of the MEDIAN function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibMedianState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_median_into(const TechalibFloat *data,
+                                         size_t len,
+                                         size_t period,
+                                         TechalibFloat *output,
+                                         struct TechalibMedianState **state);
+
+/**
+ * Update of an MEDIAN state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_median_state_update(struct TechalibMedianState *state,
+                                                 TechalibFloat sample,
+                                                 TechalibFloat *output);
+
+/**
+ * Serialization of an MEDIAN state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_median_state_serialize(const struct TechalibMedianState *state,
+                                                    uint8_t *buffer,
+                                                    size_t capacity,
+                                                    size_t *written);
+
+/**
+ * Deserialization of an MEDIAN state
+ * ---
+ * It stores in `*state` a new [`TechalibMedianState`] read from the `len` bytes of a
+ * state serialized with [`techalib_median_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_median_state_deserialize(const uint8_t *bytes,
+                                                      size_t len,
+                                                      struct TechalibMedianState **state);
+
+/**
+ * Release of an MEDIAN state
+ * ---
+ * It frees a [`TechalibMedianState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_median_state_free(struct TechalibMedianState *state);
+
+/**
+ * Calculation of the MIN function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibMinState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_min_into(const TechalibFloat *data,
+                                      size_t len,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibMinState **state);
+
+/**
+ * Update of an MIN state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_min_state_update(struct TechalibMinState *state,
+                                              TechalibFloat sample,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an MIN state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_min_state_serialize(const struct TechalibMinState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an MIN state
+ * ---
+ * It stores in `*state` a new [`TechalibMinState`] read from the `len` bytes of a
+ * state serialized with [`techalib_min_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_min_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibMinState **state);
+
+/**
+ * Release of an MIN state
+ * ---
+ * It frees a [`TechalibMinState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_min_state_free(struct TechalibMinState *state);
+
+/**
+ * Calculation of the MININDEX function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibMinIndexState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_minindex_into(const TechalibFloat *data,
+                                           size_t len,
+                                           size_t period,
+                                           TechalibFloat *output,
+                                           struct TechalibMinIndexState **state);
+
+/**
+ * Update of an MININDEX state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_minindex_state_update(struct TechalibMinIndexState *state,
+                                                   TechalibFloat sample,
+                                                   TechalibFloat *output);
+
+/**
+ * Serialization of an MININDEX state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_minindex_state_serialize(const struct TechalibMinIndexState *state,
+                                                      uint8_t *buffer,
+                                                      size_t capacity,
+                                                      size_t *written);
+
+/**
+ * Deserialization of an MININDEX state
+ * ---
+ * It stores in `*state` a new [`TechalibMinIndexState`] read from the `len` bytes of a
+ * state serialized with [`techalib_minindex_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_minindex_state_deserialize(const uint8_t *bytes,
+                                                        size_t len,
+                                                        struct TechalibMinIndexState **state);
+
+/**
+ * Release of an MININDEX state
+ * ---
+ * It frees a [`TechalibMinIndexState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_minindex_state_free(struct TechalibMinIndexState *state);
+
+/**
+ * Calculation of the MINMAX function
+ * ---
+ * It stores the `len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibMinMaxState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output_min` and `output_max` must each be valid for writes of `len` values, and must not
+ *   overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_minmax_into(const TechalibFloat *data,
+                                         size_t len,
+                                         size_t period,
+                                         TechalibFloat *output_min,
+                                         TechalibFloat *output_max,
+                                         struct TechalibMinMaxState **state);
+
+/**
+ * Update of an MINMAX state
+ * ---
+ * It updates `state` with a new sample and writes the new values to the
+ * output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_min` and `output_max` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_minmax_state_update(struct TechalibMinMaxState *state,
+                                                 TechalibFloat sample,
+                                                 TechalibFloat *output_min,
+                                                 TechalibFloat *output_max);
+
+/**
+ * Serialization of an MINMAX state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_minmax_state_serialize(const struct TechalibMinMaxState *state,
+                                                    uint8_t *buffer,
+                                                    size_t capacity,
+                                                    size_t *written);
+
+/**
+ * Deserialization of an MINMAX state
+ * ---
+ * It stores in `*state` a new [`TechalibMinMaxState`] read from the `len` bytes of a
+ * state serialized with [`techalib_minmax_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_minmax_state_deserialize(const uint8_t *bytes,
+                                                      size_t len,
+                                                      struct TechalibMinMaxState **state);
+
+/**
+ * Release of an MINMAX state
+ * ---
+ * It frees a [`TechalibMinMaxState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_minmax_state_free(struct TechalibMinMaxState *state);
+
+/**
+ * Calculation of the PERCENT_RANK function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibPercentRankState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_percent_rank_into(const TechalibFloat *data,
+                                               size_t len,
+                                               size_t period,
+                                               TechalibFloat *output,
+                                               struct TechalibPercentRankState **state);
+
+/**
+ * Update of a PERCENT_RANK state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_percent_rank_state_update(struct TechalibPercentRankState *state,
+                                                       TechalibFloat sample,
+                                                       TechalibFloat *output);
+
+/**
+ * Serialization of a PERCENT_RANK state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_percent_rank_state_serialize(const struct TechalibPercentRankState *state,
+                                                          uint8_t *buffer,
+                                                          size_t capacity,
+                                                          size_t *written);
+
+/**
+ * Deserialization of a PERCENT_RANK state
+ * ---
+ * It stores in `*state` a new [`TechalibPercentRankState`] read from the `len` bytes of a
+ * state serialized with [`techalib_percent_rank_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_percent_rank_state_deserialize(const uint8_t *bytes,
+                                                            size_t len,
+                                                            struct TechalibPercentRankState **state);
+
+/**
+ * Release of a PERCENT_RANK state
+ * ---
+ * It frees a [`TechalibPercentRankState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_percent_rank_state_free(struct TechalibPercentRankState *state);
+
+/**
+ * Calculation of the PIVOTS function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibPivotsState`] in `*state`.
+ *
+ * - `new_session`: `ohlcv->len` flags, `true` on the first bar of each session.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `new_session` must be valid for reads of `len` values.
+ * - `output_pivot`, `output_r1`, `output_r2`, `output_r3`, `output_s1`, `output_s2` and
+ *   `output_s3` must each be valid for writes of `ohlcv.len` values, and must not overlap the
+ *   inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_pivots_into(const struct TechalibOhlcv *ohlcv,
+                                         const bool *new_session,
+                                         enum TechalibPivotMethod method,
+                                         TechalibFloat *output_pivot,
+                                         TechalibFloat *output_r1,
+                                         TechalibFloat *output_r2,
+                                         TechalibFloat *output_r3,
+                                         TechalibFloat *output_s1,
+                                         TechalibFloat *output_s2,
+                                         TechalibFloat *output_s3,
+                                         struct TechalibPivotsState **state);
+
+/**
+ * Session rollover of a PIVOTS state
+ * ---
+ * It starts a new session: the next update is the first bar of the session.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ */
+enum TechalibStatus techalib_pivots_state_rollover(struct TechalibPivotsState *state);
+
+/**
+ * Update of a PIVOTS state
+ * ---
+ * It updates `state` with the `open`, `high`, `low` and `close` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_pivot`, `output_r1`, `output_r2`, `output_r3`, `output_s1`, `output_s2` and
+ *   `output_s3` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_pivots_state_update(struct TechalibPivotsState *state,
+                                                 TechalibFloat open,
+                                                 TechalibFloat high,
+                                                 TechalibFloat low,
+                                                 TechalibFloat close,
+                                                 TechalibFloat *output_pivot,
+                                                 TechalibFloat *output_r1,
+                                                 TechalibFloat *output_r2,
+                                                 TechalibFloat *output_r3,
+                                                 TechalibFloat *output_s1,
+                                                 TechalibFloat *output_s2,
+                                                 TechalibFloat *output_s3);
+
+/**
+ * Serialization of a PIVOTS state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_pivots_state_serialize(const struct TechalibPivotsState *state,
+                                                    uint8_t *buffer,
+                                                    size_t capacity,
+                                                    size_t *written);
+
+/**
+ * Deserialization of a PIVOTS state
+ * ---
+ * It stores in `*state` a new [`TechalibPivotsState`] read from the `len` bytes of a
+ * state serialized with [`techalib_pivots_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_pivots_state_deserialize(const uint8_t *bytes,
+                                                      size_t len,
+                                                      struct TechalibPivotsState **state);
+
+/**
+ * Release of a PIVOTS state
+ * ---
+ * It frees a [`TechalibPivotsState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_pivots_state_free(struct TechalibPivotsState *state);
+
+/**
+ * Calculation of the AVGPRICE function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_avgprice_into(const struct TechalibOhlcv *ohlcv,
+                                           TechalibFloat *output);
+
+/**
+ * Calculation of the MEDPRICE function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_medprice_into(const struct TechalibOhlcv *ohlcv,
+                                           TechalibFloat *output);
+
+/**
+ * Calculation of the TYPPRICE function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_typprice_into(const struct TechalibOhlcv *ohlcv,
+                                           TechalibFloat *output);
+
+/**
+ * Calculation of the WCLPRICE function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ */
+enum TechalibStatus techalib_wclprice_into(const struct TechalibOhlcv *ohlcv,
+                                           TechalibFloat *output);
+
+/**
+ * Calculation of the RSI function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibRsiState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_rsi_into(const TechalibFloat *data,
+                                      size_t len,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibRsiState **state);
+
+/**
+ * Update of an RSI state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_rsi_state_update(struct TechalibRsiState *state,
+                                              TechalibFloat sample,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an RSI state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_rsi_state_serialize(const struct TechalibRsiState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an RSI state
+ * ---
+ * It stores in `*state` a new [`TechalibRsiState`] read from the `len` bytes of a
+ * state serialized with [`techalib_rsi_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_rsi_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibRsiState **state);
+
+/**
+ * Release of an RSI state
+ * ---
+ * It frees a [`TechalibRsiState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_rsi_state_free(struct TechalibRsiState *state);
+
+/**
+ * Calculation of the SKEW function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibSkewState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_skew_into(const TechalibFloat *data,
+                                       size_t len,
+                                       size_t period,
+                                       TechalibFloat *output,
+                                       struct TechalibSkewState **state);
+
+/**
+ * Update of an SKEW state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_skew_state_update(struct TechalibSkewState *state,
+                                               TechalibFloat sample,
+                                               TechalibFloat *output);
+
+/**
+ * Serialization of an SKEW state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_skew_state_serialize(const struct TechalibSkewState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of an SKEW state
+ * ---
+ * It stores in `*state` a new [`TechalibSkewState`] read from the `len` bytes of a
+ * state serialized with [`techalib_skew_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_skew_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibSkewState **state);
+
+/**
+ * Release of an SKEW state
+ * ---
+ * It frees a [`TechalibSkewState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_skew_state_free(struct TechalibSkewState *state);
+
+/**
+ * Calculation of the SMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibSmaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_sma_into(const TechalibFloat *data,
+                                      size_t len,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibSmaState **state);
+
+/**
+ * Update of an SMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_sma_state_update(struct TechalibSmaState *state,
+                                              TechalibFloat sample,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an SMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_sma_state_serialize(const struct TechalibSmaState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an SMA state
+ * ---
+ * It stores in `*state` a new [`TechalibSmaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_sma_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_sma_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibSmaState **state);
+
+/**
+ * Release of an SMA state
+ * ---
+ * It frees a [`TechalibSmaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_sma_state_free(struct TechalibSmaState *state);
+
+/**
+ * Calculation of the SMMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibSmmaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_smma_into(const TechalibFloat *data,
+                                       size_t len,
+                                       size_t period,
+                                       TechalibFloat *output,
+                                       struct TechalibSmmaState **state);
+
+/**
+ * Update of an SMMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_smma_state_update(struct TechalibSmmaState *state,
+                                               TechalibFloat sample,
+                                               TechalibFloat *output);
+
+/**
+ * Serialization of an SMMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_smma_state_serialize(const struct TechalibSmmaState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of an SMMA state
+ * ---
+ * It stores in `*state` a new [`TechalibSmmaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_smma_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_smma_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibSmmaState **state);
+
+/**
+ * Release of an SMMA state
+ * ---
+ * It frees a [`TechalibSmmaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_smma_state_free(struct TechalibSmmaState *state);
+
+/**
+ * Calculation of the SUM function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibSumState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_sum_into(const TechalibFloat *data,
+                                      size_t len,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibSumState **state);
+
+/**
+ * Update of an SUM state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_sum_state_update(struct TechalibSumState *state,
+                                              TechalibFloat sample,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of an SUM state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_sum_state_serialize(const struct TechalibSumState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of an SUM state
+ * ---
+ * It stores in `*state` a new [`TechalibSumState`] read from the `len` bytes of a
+ * state serialized with [`techalib_sum_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_sum_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibSumState **state);
+
+/**
+ * Release of an SUM state
+ * ---
+ * It frees a [`TechalibSumState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_sum_state_free(struct TechalibSumState *state);
+
+/**
+ * Calculation of the SUPERTREND function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibSupertrendState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_supertrend` and `output_direction` must each be valid for writes of `ohlcv.len`
+ *   values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_supertrend_into(const struct TechalibOhlcv *ohlcv,
+                                             size_t period,
+                                             TechalibFloat multiplier,
+                                             TechalibFloat *output_supertrend,
+                                             TechalibFloat *output_direction,
+                                             struct TechalibSupertrendState **state);
+
+/**
+ * Update of an SUPERTREND state
+ * ---
+ * It updates `state` with the `high`, `low` and `close` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_supertrend` and `output_direction` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_supertrend_state_update(struct TechalibSupertrendState *state,
+                                                     TechalibFloat high,
+                                                     TechalibFloat low,
+                                                     TechalibFloat close,
+                                                     TechalibFloat *output_supertrend,
+                                                     TechalibFloat *output_direction);
+
+/**
+ * Serialization of an SUPERTREND state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_supertrend_state_serialize(const struct TechalibSupertrendState *state,
+                                                        uint8_t *buffer,
+                                                        size_t capacity,
+                                                        size_t *written);
+
+/**
+ * Deserialization of an SUPERTREND state
+ * ---
+ * It stores in `*state` a new [`TechalibSupertrendState`] read from the `len` bytes of a
+ * state serialized with [`techalib_supertrend_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_supertrend_state_deserialize(const uint8_t *bytes,
+                                                          size_t len,
+                                                          struct TechalibSupertrendState **state);
+
+/**
+ * Release of an SUPERTREND state
+ * ---
+ * It frees a [`TechalibSupertrendState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_supertrend_state_free(struct TechalibSupertrendState *state);
+
+/**
+ * Calculation of the SWING function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibSwingState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_swing_high` and `output_swing_low` must each be valid for writes of `ohlcv.len`
+ *   values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_swing_into(const struct TechalibOhlcv *ohlcv,
+                                        size_t left,
+                                        size_t right,
+                                        TechalibFloat *output_swing_high,
+                                        TechalibFloat *output_swing_low,
+                                        struct TechalibSwingState **state);
+
+/**
+ * Update of an SWING state
+ * ---
+ * It updates `state` with the `high` and `low` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_swing_high` and `output_swing_low` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_swing_state_update(struct TechalibSwingState *state,
+                                                TechalibFloat high,
+                                                TechalibFloat low,
+                                                TechalibFloat *output_swing_high,
+                                                TechalibFloat *output_swing_low);
+
+/**
+ * Serialization of an SWING state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_swing_state_serialize(const struct TechalibSwingState *state,
+                                                   uint8_t *buffer,
+                                                   size_t capacity,
+                                                   size_t *written);
+
+/**
+ * Deserialization of an SWING state
+ * ---
+ * It stores in `*state` a new [`TechalibSwingState`] read from the `len` bytes of a
+ * state serialized with [`techalib_swing_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_swing_state_deserialize(const uint8_t *bytes,
+                                                     size_t len,
+                                                     struct TechalibSwingState **state);
+
+/**
+ * Release of an SWING state
+ * ---
+ * It frees a [`TechalibSwingState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_swing_state_free(struct TechalibSwingState *state);
+
+/**
+ * Calculation of the T3 function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibT3State`] in `*state`.
+ *
+ * - `alpha`: The smoothing factor, `NaN` for the default `2 / (period + 1)`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_t3_into(const TechalibFloat *data,
+                                     size_t len,
+                                     size_t period,
+                                     TechalibFloat volume_factor,
+                                     TechalibFloat alpha,
+                                     TechalibFloat *output,
+                                     struct TechalibT3State **state);
+
+/**
+ * Update of a T3 state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_t3_state_update(struct TechalibT3State *state,
+                                             TechalibFloat sample,
+                                             TechalibFloat *output);
+
+/**
+ * Serialization of a T3 state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_t3_state_serialize(const struct TechalibT3State *state,
+                                                uint8_t *buffer,
+                                                size_t capacity,
+                                                size_t *written);
+
+/**
+ * Deserialization of a T3 state
+ * ---
+ * It stores in `*state` a new [`TechalibT3State`] read from the `len` bytes of a
+ * state serialized with [`techalib_t3_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_t3_state_deserialize(const uint8_t *bytes,
+                                                  size_t len,
+                                                  struct TechalibT3State **state);
+
+/**
+ * Release of a T3 state
+ * ---
+ * It frees a [`TechalibT3State`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_t3_state_free(struct TechalibT3State *state);
+
+/**
+ * Calculation of the TEMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibTemaState`] in `*state`.
+ *
+ * - `alpha`: The smoothing factor, `NaN` for the default `2 / (period + 1)`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_tema_into(const TechalibFloat *data,
+                                       size_t len,
+                                       size_t period,
+                                       TechalibFloat alpha,
+                                       TechalibFloat *output,
+                                       struct TechalibTemaState **state);
+
+/**
+ * Update of a TEMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_tema_state_update(struct TechalibTemaState *state,
+                                               TechalibFloat sample,
+                                               TechalibFloat *output);
+
+/**
+ * Serialization of a TEMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_tema_state_serialize(const struct TechalibTemaState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of a TEMA state
+ * ---
+ * It stores in `*state` a new [`TechalibTemaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_tema_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_tema_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibTemaState **state);
+
+/**
+ * Release of a TEMA state
+ * ---
+ * It frees a [`TechalibTemaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_tema_state_free(struct TechalibTemaState *state);
+
+/**
+ * Calculation of the TRIMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibTrimaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_trima_into(const TechalibFloat *data,
+                                        size_t len,
+                                        size_t period,
+                                        TechalibFloat *output,
+                                        struct TechalibTrimaState **state);
+
+/**
+ * Update of a TRIMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_trima_state_update(struct TechalibTrimaState *state,
+                                                TechalibFloat sample,
+                                                TechalibFloat *output);
+
+/**
+ * Serialization of a TRIMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_trima_state_serialize(const struct TechalibTrimaState *state,
+                                                   uint8_t *buffer,
+                                                   size_t capacity,
+                                                   size_t *written);
+
+/**
+ * Deserialization of a TRIMA state
+ * ---
+ * It stores in `*state` a new [`TechalibTrimaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_trima_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_trima_state_deserialize(const uint8_t *bytes,
+                                                     size_t len,
+                                                     struct TechalibTrimaState **state);
+
+/**
+ * Release of a TRIMA state
+ * ---
+ * It frees a [`TechalibTrimaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_trima_state_free(struct TechalibTrimaState *state);
+
+/**
+ * Calculation of the VIDYA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibVidyaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_vidya_into(const TechalibFloat *data,
+                                        size_t len,
+                                        size_t period,
+                                        size_t cmo_period,
+                                        TechalibFloat *output,
+                                        struct TechalibVidyaState **state);
+
+/**
+ * Update of a VIDYA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_vidya_state_update(struct TechalibVidyaState *state,
+                                                TechalibFloat sample,
+                                                TechalibFloat *output);
+
+/**
+ * Serialization of a VIDYA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_vidya_state_serialize(const struct TechalibVidyaState *state,
+                                                   uint8_t *buffer,
+                                                   size_t capacity,
+                                                   size_t *written);
+
+/**
+ * Deserialization of a VIDYA state
+ * ---
+ * It stores in `*state` a new [`TechalibVidyaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_vidya_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_vidya_state_deserialize(const uint8_t *bytes,
+                                                     size_t len,
+                                                     struct TechalibVidyaState **state);
+
+/**
+ * Release of a VIDYA state
+ * ---
+ * It frees a [`TechalibVidyaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_vidya_state_free(struct TechalibVidyaState *state);
+
+/**
+ * Calculation of the VOLATILITY function
+ * ---
+ * It stores the `ohlcv->len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibVolatilityState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_volatility_into(const struct TechalibOhlcv *ohlcv,
+                                             size_t period,
+                                             enum TechalibVolatilityEstimator estimator,
+                                             TechalibFloat annualization,
+                                             TechalibFloat *output,
+                                             struct TechalibVolatilityState **state);
+
+/**
+ * Update of a VOLATILITY state
+ * ---
+ * It updates `state` with the `open`, `high`, `low` and `close` of a new bar and writes the new
+ * value to `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_volatility_state_update(struct TechalibVolatilityState *state,
+                                                     TechalibFloat open,
+                                                     TechalibFloat high,
+                                                     TechalibFloat low,
+                                                     TechalibFloat close,
+                                                     TechalibFloat *output);
+
+/**
+ * Serialization of a VOLATILITY state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_volatility_state_serialize(const struct TechalibVolatilityState *state,
+                                                        uint8_t *buffer,
+                                                        size_t capacity,
+                                                        size_t *written);
+
+/**
+ * Deserialization of a VOLATILITY state
+ * ---
+ * It stores in `*state` a new [`TechalibVolatilityState`] read from the `len` bytes of a
+ * state serialized with [`techalib_volatility_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_volatility_state_deserialize(const uint8_t *bytes,
+                                                          size_t len,
+                                                          struct TechalibVolatilityState **state);
+
+/**
+ * Release of a VOLATILITY state
+ * ---
+ * It frees a [`TechalibVolatilityState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_volatility_state_free(struct TechalibVolatilityState *state);
+
+/**
+ * Calculation of the VWAP function
+ * ---
+ * It stores the `len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibVwapState`] in `*state`.
+ *
+ * - `reset`: The session boundaries, see [`TechalibVwapReset`].
+ *
+ * Safety
+ * ---
+ * - `price` must be valid for reads of `len` values.
+ * - `volume` must be valid for reads of `len` values.
+ * - `reset.mask` must be valid for reads of `len` values when it is used.
+ * - `reset.anchors` must be valid for reads of `reset.anchors_len` values when they are used.
+ * - `output_vwap`, `output_upper` and `output_lower` must each be valid for writes of `len`
+ *   values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_vwap_into(const TechalibFloat *price,
+                                       const TechalibFloat *volume,
+                                       size_t len,
+                                       struct TechalibVwapReset reset,
+                                       TechalibFloat multiplier,
+                                       TechalibFloat *output_vwap,
+                                       TechalibFloat *output_upper,
+                                       TechalibFloat *output_lower,
+                                       struct TechalibVwapState **state);
+
+/**
+ * Session reset of a VWAP state
+ * ---
+ * It starts a new session: the next update is the first bar of the session.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ */
+enum TechalibStatus techalib_vwap_state_reset(struct TechalibVwapState *state);
+
+/**
+ * Update of a VWAP state
+ * ---
+ * It updates `state` with the `price` and `volume` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_vwap`, `output_upper` and `output_lower` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_vwap_state_update(struct TechalibVwapState *state,
+                                               TechalibFloat price,
+                                               TechalibFloat volume,
+                                               TechalibFloat *output_vwap,
+                                               TechalibFloat *output_upper,
+                                               TechalibFloat *output_lower);
+
+/**
+ * Serialization of a VWAP state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_vwap_state_serialize(const struct TechalibVwapState *state,
+                                                  uint8_t *buffer,
+                                                  size_t capacity,
+                                                  size_t *written);
+
+/**
+ * Deserialization of a VWAP state
+ * ---
+ * It stores in `*state` a new [`TechalibVwapState`] read from the `len` bytes of a
+ * state serialized with [`techalib_vwap_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_vwap_state_deserialize(const uint8_t *bytes,
+                                                    size_t len,
+                                                    struct TechalibVwapState **state);
+
+/**
+ * Release of a VWAP state
+ * ---
+ * It frees a [`TechalibVwapState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_vwap_state_free(struct TechalibVwapState *state);
+
+/**
+ * Calculation of the WMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibWmaState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_wma_into(const TechalibFloat *data,
+                                      size_t len,
+                                      size_t period,
+                                      TechalibFloat *output,
+                                      struct TechalibWmaState **state);
+
+/**
+ * Update of a WMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_wma_state_update(struct TechalibWmaState *state,
+                                              TechalibFloat sample,
+                                              TechalibFloat *output);
+
+/**
+ * Serialization of a WMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_wma_state_serialize(const struct TechalibWmaState *state,
+                                                 uint8_t *buffer,
+                                                 size_t capacity,
+                                                 size_t *written);
+
+/**
+ * Deserialization of a WMA state
+ * ---
+ * It stores in `*state` a new [`TechalibWmaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_wma_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_wma_state_deserialize(const uint8_t *bytes,
+                                                   size_t len,
+                                                   struct TechalibWmaState **state);
+
+/**
+ * Release of a WMA state
+ * ---
+ * It frees a [`TechalibWmaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_wma_state_free(struct TechalibWmaState *state);
+
+/**
+ * Calculation of the ZIGZAG function
+ * ---
+ * It stores the `ohlcv->len` calculated values in each output array and,
+ * when `state` is not null, a new [`TechalibZigzagState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+ *   values.
+ * - `output_pivots`, `output_confirmed` and `output_direction` must each be valid for writes
+ *   of `ohlcv.len` values, and must not overlap the inputs, or each other.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_zigzag_into(const struct TechalibOhlcv *ohlcv,
+                                         struct TechalibZigzagThreshold threshold,
+                                         TechalibFloat *output_pivots,
+                                         TechalibFloat *output_confirmed,
+                                         TechalibFloat *output_direction,
+                                         struct TechalibZigzagState **state);
+
+/**
+ * Update of a ZIGZAG state
+ * ---
+ * It updates `state` with the `high`, `low` and `close` of a new bar and writes the new
+ * values to the output pointers which are not null.
+ *
+ * The pivots are only known once confirmed, so only the confirmed pivot
+ * and the direction are available.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output_confirmed` and `output_direction` must each be null or valid for a write.
+ */
+enum TechalibStatus techalib_zigzag_state_update(struct TechalibZigzagState *state,
+                                                 TechalibFloat high,
+                                                 TechalibFloat low,
+                                                 TechalibFloat close,
+                                                 TechalibFloat *output_confirmed,
+                                                 TechalibFloat *output_direction);
+
+/**
+ * Serialization of a ZIGZAG state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_zigzag_state_serialize(const struct TechalibZigzagState *state,
+                                                    uint8_t *buffer,
+                                                    size_t capacity,
+                                                    size_t *written);
+
+/**
+ * Deserialization of a ZIGZAG state
+ * ---
+ * It stores in `*state` a new [`TechalibZigzagState`] read from the `len` bytes of a
+ * state serialized with [`techalib_zigzag_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_zigzag_state_deserialize(const uint8_t *bytes,
+                                                      size_t len,
+                                                      struct TechalibZigzagState **state);
+
+/**
+ * Release of a ZIGZAG state
+ * ---
+ * It frees a [`TechalibZigzagState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_zigzag_state_free(struct TechalibZigzagState *state);
+
+/**
+ * Calculation of the ZLEMA function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibZlemaState`] in `*state`.
+ *
+ * - `alpha`: The smoothing factor, `NaN` for the default `2 / (period + 1)`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_zlema_into(const TechalibFloat *data,
+                                        size_t len,
+                                        size_t period,
+                                        TechalibFloat alpha,
+                                        TechalibFloat *output,
+                                        struct TechalibZlemaState **state);
+
+/**
+ * Update of a ZLEMA state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_zlema_state_update(struct TechalibZlemaState *state,
+                                                TechalibFloat sample,
+                                                TechalibFloat *output);
+
+/**
+ * Serialization of a ZLEMA state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_zlema_state_serialize(const struct TechalibZlemaState *state,
+                                                   uint8_t *buffer,
+                                                   size_t capacity,
+                                                   size_t *written);
+
+/**
+ * Deserialization of a ZLEMA state
+ * ---
+ * It stores in `*state` a new [`TechalibZlemaState`] read from the `len` bytes of a
+ * state serialized with [`techalib_zlema_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_zlema_state_deserialize(const uint8_t *bytes,
+                                                     size_t len,
+                                                     struct TechalibZlemaState **state);
+
+/**
+ * Release of a ZLEMA state
+ * ---
+ * It frees a [`TechalibZlemaState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_zlema_state_free(struct TechalibZlemaState *state);
+
+/**
+ * Calculation of the ZSCORE function
+ * ---
+ * It stores the `len` calculated values in `output` and,
+ * when `state` is not null, a new [`TechalibZscoreState`] in `*state`.
+ *
+ * Safety
+ * ---
+ * - `data` must be valid for reads of `len` values.
+ * - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+ * - `state` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_zscore_into(const TechalibFloat *data,
+                                         size_t len,
+                                         size_t period,
+                                         TechalibFloat *output,
+                                         struct TechalibZscoreState **state);
+
+/**
+ * Update of a ZSCORE state
+ * ---
+ * It updates `state` with a new sample and writes the new value to
+ * `output` when it is not null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `output` must be null or valid for a write.
+ */
+enum TechalibStatus techalib_zscore_state_update(struct TechalibZscoreState *state,
+                                                 TechalibFloat sample,
+                                                 TechalibFloat *output);
+
+/**
+ * Serialization of a ZSCORE state
+ * ---
+ * It writes the size of the serialized state to `*written`, and the state to
+ * `buffer` when `capacity` is large enough. Otherwise, it returns
+ * `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+ *
+ * Safety
+ * ---
+ * - `state` must be a handle of this library which has not been freed.
+ * - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+ * - `written` must be valid for a write.
+ */
+enum TechalibStatus techalib_zscore_state_serialize(const struct TechalibZscoreState *state,
+                                                    uint8_t *buffer,
+                                                    size_t capacity,
+                                                    size_t *written);
+
+/**
+ * Deserialization of a ZSCORE state
+ * ---
+ * It stores in `*state` a new [`TechalibZscoreState`] read from the `len` bytes of a
+ * state serialized with [`techalib_zscore_state_serialize`].
+ *
+ * Safety
+ * ---
+ * - `bytes` must be valid for reads of `len` bytes.
+ * - `state` must be valid for a write.
+ */
+enum TechalibStatus techalib_zscore_state_deserialize(const uint8_t *bytes,
+                                                      size_t len,
+                                                      struct TechalibZscoreState **state);
+
+/**
+ * Release of a ZSCORE state
+ * ---
+ * It frees a [`TechalibZscoreState`], a null `state` is ignored.
+ *
+ * Safety
+ * ---
+ * - `state` must be null or a handle of this library which has not been freed yet.
+ * - `state` must not be used after this call.
+ */
+void techalib_zscore_state_free(struct TechalibZscoreState *state);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TECHALIB_H */
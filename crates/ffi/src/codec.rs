@@ -0,0 +1,449 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Binary encoding of the states
+//!
+//! The values are written in little-endian order, the `usize` as `u64`, the
+//! collections are prefixed with their length and the enums with the index
+//! of their variant.
+
+use crate::status::FfiError;
+use techalib::indicators::alma::AlmaState;
+use techalib::indicators::atr::AtrState;
+use techalib::indicators::bbands::{
+    BBandsMA, BBandsState, DeviationMulipliers, MovingAverageState,
+};
+use techalib::indicators::chandelier_exit::ChandelierExitState;
+use techalib::indicators::cmf::CmfState;
+use techalib::indicators::dema::DemaState;
+use techalib::indicators::donchian::DonchianState;
+use techalib::indicators::elder_ray::ElderRayState;
+use techalib::indicators::ema::EmaState;
+use techalib::indicators::envelope::EnvelopeState;
+use techalib::indicators::eom::EomState;
+use techalib::indicators::force_index::ForceIndexState;
+use techalib::indicators::heikin_ashi::HeikinAshiState;
+use techalib::indicators::hma::HmaState;
+use techalib::indicators::ichimoku::IchimokuState;
+use techalib::indicators::kama::KamaState;
+use techalib::indicators::keltner::KeltnerState;
+use techalib::indicators::ma::MaState;
+use techalib::indicators::macd::MacdState;
+use techalib::indicators::mavp::{MavpMA, MavpState};
+use techalib::indicators::max::MaxState;
+use techalib::indicators::maxindex::MaxIndexState;
+use techalib::indicators::min::MinState;
+use techalib::indicators::minindex::MinIndexState;
+use techalib::indicators::minmax::MinMaxState;
+use techalib::indicators::pivots::{PivotMethod, PivotsState};
+use techalib::indicators::rsi::RsiState;
+use techalib::indicators::sma::SmaState;
+use techalib::indicators::smma::SmmaState;
+use techalib::indicators::stats::kurtosis::KurtosisState;
+use techalib::indicators::stats::median::MedianState;
+use techalib::indicators::stats::percent_rank::PercentRankState;
+use techalib::indicators::stats::skew::SkewState;
+use techalib::indicators::stats::zscore::ZscoreState;
+use techalib::indicators::stats::Moments;
+use techalib::indicators::sum::SumState;
+use techalib::indicators::supertrend::SupertrendState;
+use techalib::indicators::swing::SwingState;
+use techalib::indicators::t3::{T3Coefficients, T3EmaValues, T3State};
+use techalib::indicators::tema::TemaState;
+use techalib::indicators::trima::TrimaState;
+use techalib::indicators::vidya::VidyaState;
+use techalib::indicators::volatility::{VolatilityEstimator, VolatilityState};
+use techalib::indicators::vwap::VwapState;
+use techalib::indicators::wma::WmaState;
+use techalib::indicators::zigzag::{ZigzagState, ZigzagThreshold};
+use techalib::indicators::zlema::ZlemaState;
+use techalib::ring_buffer::RingBuffer;
+use techalib::types::TechalibFloat;
+
+/// Reads the encoded values from a byte buffer
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes }
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8], FfiError> {
+        if self.bytes.len() < len {
+            return Err(FfiError::InvalidState("unexpected end of data".to_string()));
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    pub(crate) fn take_array<const N: usize>(&mut self) -> Result<[u8; N], FfiError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+/// Binary encoding of a value
+pub(crate) trait Codec: Sized {
+    /// Appends the encoded value to `out`
+    fn encode(&self, out: &mut Vec<u8>);
+    /// Reads a value encoded with [`Codec::encode`]
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError>;
+}
+
+macro_rules! codec_float {
+    ($($t:ty),+) => {
+        $(
+            impl Codec for $t {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+                fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+                    Ok(<$t>::from_le_bytes(reader.take_array()?))
+                }
+            }
+        )+
+    };
+}
+
+codec_float!(f32, f64);
+
+impl Codec for u8 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self);
+    }
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+        Ok(reader.take(1)?[0])
+    }
+}
+
+impl Codec for usize {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (*self as u64).encode(out);
+    }
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+        usize::try_from(u64::decode(reader)?)
+            .map_err(|_| FfiError::InvalidState("integer out of range".to_string()))
+    }
+}
+
+impl Codec for u64 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+        Ok(u64::from_le_bytes(reader.take_array()?))
+    }
+}
+
+impl<T: Codec> Codec for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.encode(out);
+            }
+        }
+    }
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+        match u8::decode(reader)? {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode(reader)?)),
+            tag => Err(invalid_tag("Option", tag)),
+        }
+    }
+}
+
+impl<T: Codec, const N: usize> Codec for [T; N] {
+    fn encode(&self, out: &mut Vec<u8>) {
+        for value in self {
+            value.encode(out);
+        }
+    }
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+        let values = (0..N)
+            .map(|_| T::decode(reader))
+            .collect::<Result<Vec<T>, FfiError>>()?;
+        values
+            .try_into()
+            .map_err(|_| FfiError::InvalidState("array length".to_string()))
+    }
+}
+
+impl<T: Codec> Codec for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.len().encode(out);
+        for value in self {
+            value.encode(out);
+        }
+    }
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+        let len = usize::decode(reader)?;
+        // Each value takes at least one byte, which bounds the allocation
+        // of a corrupted length.
+        let mut values = Vec::with_capacity(len.min(reader.remaining()));
+        for _ in 0..len {
+            values.push(T::decode(reader)?);
+        }
+        Ok(values)
+    }
+}
+
+impl<T: Codec + Copy> Codec for RingBuffer<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.len().encode(out);
+        for value in self.iter() {
+            value.encode(out);
+        }
+    }
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+        Ok(Vec::<T>::decode(reader)?.into())
+    }
+}
+
+fn invalid_tag(name: &str, tag: u8) -> FfiError {
+    FfiError::InvalidState(format!("unknown variant {tag} of `{name}`"))
+}
+
+/// Implements [`Codec`] for a struct of the core crate, field by field
+macro_rules! codec_struct {
+    ($($ty:ident { $($field:ident),+ $(,)? })+) => {
+        $(
+            impl<T: TechalibFloat + Codec> Codec for $ty<T> {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    $(self.$field.encode(out);)+
+                }
+                fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+                    Ok($ty {
+                        $($field: Codec::decode(reader)?,)+
+                    })
+                }
+            }
+        )+
+    };
+}
+
+/// Implements [`Codec`] for an enum of the core crate with unit variants
+/// or variants holding a single value
+macro_rules! codec_enum {
+    ($ty:ident<T> $variants:tt) => {
+        codec_enum!(@impl [T: TechalibFloat + Codec] $ty<T> $variants);
+    };
+    ($ty:ident $variants:tt) => {
+        codec_enum!(@impl [] $ty $variants);
+    };
+    (@impl [$($generics:tt)*] $ty:ty { $($tag:literal => $variant:ident $(($($value:ident),+))?),+ $(,)? }) => {
+        impl<$($generics)*> Codec for $ty {
+            fn encode(&self, out: &mut Vec<u8>) {
+                match self {
+                    $(
+                        Self::$variant $(($($value),+))? => {
+                            out.push($tag);
+                            $($($value.encode(out);)+)?
+                        }
+                    )+
+                }
+            }
+            fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+                match u8::decode(reader)? {
+                    $($tag => Ok(Self::$variant $(($({
+                        let $value = Codec::decode(reader)?;
+                        $value
+                    }),+))?),)+
+                    tag => Err(invalid_tag(stringify!($ty), tag)),
+                }
+            }
+        }
+    };
+}
+
+codec_struct! {
+    SmaState { sma, last_window, period }
+    EmaState { ema, period, alpha }
+    WmaState { wma, period_sub, period_sum, last_window, period }
+    DemaState { dema, ema_1, ema_2, period, alpha }
+    TemaState { tema, ema_1, ema_2, ema_3, period, alpha }
+    TrimaState { trima, weighted_sum, trailing_sum, heading_sum, last_window, inv_weight_sum, period }
+    KamaState { kama, roc_sum, last_window, trailing_value, period }
+    T3EmaValues { ema1, ema2, ema3, ema4, ema5, ema6 }
+    T3Coefficients { c1, c2, c3, c4 }
+    T3State { t3, ema_values, period, alpha, volume_factor, t3_coefficients }
+    HmaState { hma, half_wma, full_wma, hull_wma, period }
+    ZlemaState { zlema, last_window, period, alpha }
+    AlmaState { alma, last_window, period, offset, sigma }
+    SmmaState { smma, period }
+    VidyaState { vidya, up_sum, down_sum, prev_value, last_changes, period, cmo_period }
+    RsiState { rsi, prev_value, avg_gain, avg_loss, period }
+    SumState { sum, last_window, period }
+    MinState { min, last_window, period }
+    MaxState { max, last_window, period }
+    MinIndexState { minindex, min, index, last_window, period }
+    MaxIndexState { maxindex, max, index, last_window, period }
+    MinMaxState { min, max, last_window, period }
+    MacdState { macd, signal, histogram, fast_ema, slow_ema, fast_period, slow_period, signal_period }
+    MovingAverageState { sma, ma_square }
+    DeviationMulipliers { up, down }
+    BBandsState {
+        upper, middle, lower, percent_b, bandwidth, moving_averages, last_window, ma, period,
+        std_dev_mult, ma_type
+    }
+    EnvelopeState { upper, middle, lower, ma, percent }
+    MavpState {
+        mavp, period, period_sub, period_sum, emas, ma_states, last_window, min_period, max_period,
+        ma_type
+    }
+    Moments { mean, m2, m3, m4 }
+    ZscoreState { zscore, moments, last_window, period }
+    SkewState { skew, moments, last_window, period }
+    KurtosisState { kurtosis, moments, last_window, period }
+    PercentRankState { percent_rank, sorted_window, last_window, period }
+    MedianState { median, sorted_window, last_window, period }
+    AtrState { atr, prev_close, period }
+    ForceIndexState { force_index, prev_close, period, alpha }
+    DonchianState { upper, middle, lower, max_state, min_state }
+    EomState { eom, prev_high, prev_low, last_window, period, scale }
+    SupertrendState { supertrend, direction, final_upper, final_lower, atr, multiplier }
+    IchimokuState {
+        tenkan, kijun, senkou_a, senkou_b, chikou, tenkan_state, kijun_state, senkou_b_state,
+        senkou_a_ahead, senkou_b_ahead, displacement
+    }
+    PivotsState {
+        pivot, r1, r2, r3, s1, s2, s3, prev_open, prev_high, prev_low, prev_close, session_open,
+        session_high, session_low, session_close, method
+    }
+    SwingState { confirmed_high, confirmed_low, high_window, low_window, left, right }
+    HeikinAshiState { open, high, low, close }
+    KeltnerState { upper, middle, lower, ema, atr, multiplier }
+    ZigzagState {
+        direction, confirmed, last_pivot, last_pivot_idx, extreme, extreme_idx, max_high,
+        max_high_idx, min_low, min_low_idx, idx, atr, threshold
+    }
+    VolatilityState { volatility, prev_close, last_window, period, estimator, annualization }
+    ChandelierExitState { long_stop, short_stop, max_state, min_state, atr, multiplier }
+    ElderRayState { bull_power, bear_power, ema }
+    CmfState { cmf, mfv_sum, volume_sum, last_mfv, last_volume, period }
+    VwapState { vwap, upper, lower, cum_volume, m2, multiplier }
+}
+
+codec_enum!(MavpMA<T> {
+    0 => SMA,
+    1 => EMA,
+    2 => WMA,
+    3 => HMA,
+    4 => ZLEMA,
+    5 => ALMA(offset, sigma),
+    6 => SMMA,
+    7 => VIDYA(cmo_period),
+});
+
+codec_enum!(PivotMethod {
+    0 => Classic,
+    1 => Fibonacci,
+    2 => Camarilla,
+    3 => Woodie,
+    4 => Demark,
+});
+
+codec_enum!(VolatilityEstimator {
+    0 => CloseToClose,
+    1 => Parkinson,
+    2 => GarmanKlass,
+    3 => RogersSatchell,
+    4 => YangZhang,
+});
+
+codec_enum!(BBandsMA<T> {
+    0 => SMA,
+    1 => EMA(alpha),
+    2 => HMA,
+    3 => ZLEMA(alpha),
+    4 => ALMA(offset, sigma),
+    5 => SMMA,
+    6 => VIDYA(cmo_period),
+});
+
+codec_enum!(MaState<T> {
+    0 => SMA(state),
+    1 => EMA(state),
+    2 => WMA(state),
+    3 => DEMA(state),
+    4 => TEMA(state),
+    5 => TRIMA(state),
+    6 => KAMA(state),
+    7 => T3(state),
+    8 => HMA(state),
+    9 => ZLEMA(state),
+    10 => ALMA(state),
+    11 => SMMA(state),
+    12 => VIDYA(state),
+});
+
+impl<T: TechalibFloat + Codec> Codec for ZigzagThreshold<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            ZigzagThreshold::Percent(percent) => {
+                out.push(0);
+                percent.encode(out);
+            }
+            ZigzagThreshold::Atr { period, multiplier } => {
+                out.push(1);
+                period.encode(out);
+                multiplier.encode(out);
+            }
+        }
+    }
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, FfiError> {
+        match u8::decode(reader)? {
+            0 => Ok(ZigzagThreshold::Percent(Codec::decode(reader)?)),
+            1 => Ok(ZigzagThreshold::Atr {
+                period: Codec::decode(reader)?,
+                multiplier: Codec::decode(reader)?,
+            }),
+            tag => Err(invalid_tag("ZigzagThreshold", tag)),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! Opaque state handles
+//!
+//! A handle is a boxed state of the core crate, given to C as a pointer on an
+//! opaque struct. The functions of this module implement the parts of the C
+//! functions which are the same for every state.
+
+use crate::codec::{Codec, Reader};
+use crate::status::{ffi_call, FfiError, TechalibStatus};
+use crate::types::{input_slice, output_slice, write_output, TechalibFloat};
+
+/// Magic bytes at the start of a serialized state.
+const MAGIC: &[u8; 4] = b"TCHL";
+
+/// Version of the serialization format.
+const FORMAT_VERSION: u8 = 2;
+
+/// Opaque handle on a state of the core crate
+pub(crate) trait Handle: Sized {
+    /// The state of the core crate.
+    type State: Codec;
+    /// The name of the indicator, stored in the serialized state.
+    const NAME: &'static str;
+
+    fn new(state: Self::State) -> Self;
+    fn state(&self) -> &Self::State;
+    fn state_mut(&mut self) -> &mut Self::State;
+}
+
+/// Implements [`Handle`] for a newtype over a state of the core crate
+macro_rules! impl_handle {
+    ($handle:ident, $state:ty, $name:literal) => {
+        impl $crate::handle::Handle for $handle {
+            type State = $state;
+            const NAME: &'static str = $name;
+
+            fn new(state: Self::State) -> Self {
+                $handle(state)
+            }
+            fn state(&self) -> &Self::State {
+                &self.0
+            }
+            fn state_mut(&mut self) -> &mut Self::State {
+                &mut self.0
+            }
+        }
+    };
+}
+pub(crate) use impl_handle;
+
+/// Stores a new handle on `state` in `*out`, unless `out` is null
+pub(crate) unsafe fn store<H: Handle>(out: *mut *mut H, state: H::State) {
+    if !out.is_null() {
+        *out = Box::into_raw(Box::new(H::new(state)));
+    }
+}
+
+/// Mutably borrows the state behind a handle
+pub(crate) unsafe fn state_mut<'a, H: Handle + 'a>(
+    handle: *mut H,
+) -> Result<&'a mut H::State, FfiError> {
+    handle
+        .as_mut()
+        .map(H::state_mut)
+        .ok_or(FfiError::NullPointer("state"))
+}
+
+/// Releases a handle, a null handle being ignored
+pub(crate) unsafe fn free<H: Handle>(handle: *mut H) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Serializes the state behind a handle
+///
+/// The number of bytes of the serialized state is always written to `*written`.
+/// If `capacity` is too small, nothing is written to `buffer` and
+/// [`TechalibStatus::BufferTooSmall`] is returned, so that a first call with a
+/// null buffer gives the size to allocate.
+pub(crate) unsafe fn serialize<H: Handle>(
+    handle: *const H,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let handle = handle.as_ref().ok_or(FfiError::NullPointer("state"))?;
+        if written.is_null() {
+            return Err(FfiError::NullPointer("written"));
+        }
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FORMAT_VERSION);
+        bytes.push(size_of::<TechalibFloat>() as u8);
+        H::NAME.as_bytes().to_vec().encode(&mut bytes);
+        handle.state().encode(&mut bytes);
+
+        write_output(written, bytes.len());
+        if capacity < bytes.len() {
+            return Err(FfiError::BufferTooSmall(bytes.len()));
+        }
+        output_slice(buffer, bytes.len(), "buffer")?.copy_from_slice(&bytes);
+        Ok(())
+    })
+}
+
+/// Creates a handle from a state serialized with [`serialize`]
+pub(crate) unsafe fn deserialize<H: Handle>(
+    bytes: *const u8,
+    len: usize,
+    out: *mut *mut H,
+) -> TechalibStatus {
+    ffi_call(|| {
+        if out.is_null() {
+            return Err(FfiError::NullPointer("state"));
+        }
+        let mut reader = Reader::new(input_slice(bytes, len, "bytes")?);
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(FfiError::InvalidState("not a techalib state".to_string()));
+        }
+        let version = u8::decode(&mut reader)?;
+        if version != FORMAT_VERSION {
+            return Err(FfiError::InvalidState(format!(
+                "unsupported format version {version}"
+            )));
+        }
+        let float_size = u8::decode(&mut reader)?;
+        if usize::from(float_size) != size_of::<TechalibFloat>() {
+            return Err(FfiError::InvalidState(format!(
+                "the state holds {}-bit floats",
+                u32::from(float_size) * 8
+            )));
+        }
+        let name = Vec::<u8>::decode(&mut reader)?;
+        if name != H::NAME.as_bytes() {
+            return Err(FfiError::InvalidState(format!(
+                "expected a `{}` state, got a `{}` state",
+                H::NAME,
+                String::from_utf8_lossy(&name)
+            )));
+        }
+        let state = H::State::decode(&mut reader)?;
+        if !reader.is_empty() {
+            return Err(FfiError::InvalidState("trailing bytes".to_string()));
+        }
+        store(out, state);
+        Ok(())
+    })
+}
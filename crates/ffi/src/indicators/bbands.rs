@@ -0,0 +1,224 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Bollinger Bands (BBANDS)
+
+use crate::handle::{self, impl_handle};
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{
+    deviation_multipliers, input_slice, output_slice, write_output, TechalibBbandsMa, TechalibFloat,
+};
+use techalib::indicators::bbands::{bbands_ext_into, bbands_into, BBandsState};
+use techalib::traits::State;
+
+/// Opaque handle on a BBANDS state
+pub struct TechalibBBandsState(BBandsState<TechalibFloat>);
+
+impl_handle!(TechalibBBandsState, BBandsState<TechalibFloat>, "bbands");
+
+/// Calculation of the BBANDS function
+/// ---
+/// It stores the `len` calculated values in each output array and,
+/// when `state` is not null, a new [`TechalibBBandsState`] in `*state`.
+///
+/// - `std_dev_up`, `std_dev_down`: The multipliers of the standard deviation of the bands.
+/// - `alpha`: The smoothing factor of the EMA and ZLEMA, `NaN` for the default `2 / (period + 1)`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output_upper`, `output_middle` and `output_lower` must each be valid for writes of `len`
+///   values, and must not overlap the inputs, or each other.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_bbands_into(
+    data: *const TechalibFloat,
+    len: usize,
+    period: usize,
+    std_dev_up: TechalibFloat,
+    std_dev_down: TechalibFloat,
+    ma_type: TechalibBbandsMa,
+    alpha: TechalibFloat,
+    output_upper: *mut TechalibFloat,
+    output_middle: *mut TechalibFloat,
+    output_lower: *mut TechalibFloat,
+    state: *mut *mut TechalibBBandsState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let new_state = bbands_into(
+            input_slice(data, len, "data")?,
+            period,
+            deviation_multipliers(std_dev_up, std_dev_down),
+            ma_type.to_core(alpha),
+            output_slice(output_upper, len, "output_upper")?,
+            output_slice(output_middle, len, "output_middle")?,
+            output_slice(output_lower, len, "output_lower")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Calculation of the BBANDS function with the %B and the bandwidth
+/// ---
+/// It stores the `len` calculated values in each output array and,
+/// when `state` is not null, a new [`TechalibBBandsState`] in `*state`.
+///
+/// - `std_dev_up`, `std_dev_down`: The multipliers of the standard deviation of the bands.
+/// - `alpha`: The smoothing factor of the EMA and ZLEMA, `NaN` for the default `2 / (period + 1)`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output_upper`, `output_middle`, `output_lower`, `output_percent_b` and `output_bandwidth`
+///   must each be valid for writes of `len` values, and must not overlap the inputs, or each
+///   other.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_bbands_ext_into(
+    data: *const TechalibFloat,
+    len: usize,
+    period: usize,
+    std_dev_up: TechalibFloat,
+    std_dev_down: TechalibFloat,
+    ma_type: TechalibBbandsMa,
+    alpha: TechalibFloat,
+    output_upper: *mut TechalibFloat,
+    output_middle: *mut TechalibFloat,
+    output_lower: *mut TechalibFloat,
+    output_percent_b: *mut TechalibFloat,
+    output_bandwidth: *mut TechalibFloat,
+    state: *mut *mut TechalibBBandsState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let new_state = bbands_ext_into(
+            input_slice(data, len, "data")?,
+            period,
+            deviation_multipliers(std_dev_up, std_dev_down),
+            ma_type.to_core(alpha),
+            output_slice(output_upper, len, "output_upper")?,
+            output_slice(output_middle, len, "output_middle")?,
+            output_slice(output_lower, len, "output_lower")?,
+            output_slice(output_percent_b, len, "output_percent_b")?,
+            output_slice(output_bandwidth, len, "output_bandwidth")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Update of a BBANDS state
+/// ---
+/// It updates `state` with a new sample and writes the new values to the
+/// output pointers which are not null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `output_upper`, `output_middle`, `output_lower`, `output_percent_b` and `output_bandwidth`
+///   must each be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_bbands_state_update(
+    state: *mut TechalibBBandsState,
+    sample: TechalibFloat,
+    output_upper: *mut TechalibFloat,
+    output_middle: *mut TechalibFloat,
+    output_lower: *mut TechalibFloat,
+    output_percent_b: *mut TechalibFloat,
+    output_bandwidth: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let state = handle::state_mut(state)?;
+        state.update(sample)?;
+        write_output(output_upper, state.upper);
+        write_output(output_middle, state.middle);
+        write_output(output_lower, state.lower);
+        write_output(output_percent_b, state.percent_b);
+        write_output(output_bandwidth, state.bandwidth);
+        Ok(())
+    })
+}
+
+/// Serialization of a BBANDS state
+/// ---
+/// It writes the size of the serialized state to `*written`, and the state to
+/// `buffer` when `capacity` is large enough. Otherwise, it returns
+/// `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+/// - `written` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_bbands_state_serialize(
+    state: *const TechalibBBandsState,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    handle::serialize(state, buffer, capacity, written)
+}
+
+/// Deserialization of a BBANDS state
+/// ---
+/// It stores in `*state` a new [`TechalibBBandsState`] read from the `len` bytes of a
+/// state serialized with [`techalib_bbands_state_serialize`].
+///
+/// Safety
+/// ---
+/// - `bytes` must be valid for reads of `len` bytes.
+/// - `state` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_bbands_state_deserialize(
+    bytes: *const u8,
+    len: usize,
+    state: *mut *mut TechalibBBandsState,
+) -> TechalibStatus {
+    handle::deserialize(bytes, len, state)
+}
+
+/// Release of a BBANDS state
+/// ---
+/// It frees a [`TechalibBBandsState`], a null `state` is ignored.
+///
+/// Safety
+/// ---
+/// - `state` must be null or a handle of this library which has not been freed yet.
+/// - `state` must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_bbands_state_free(state: *mut TechalibBBandsState) {
+    handle::free(state)
+}
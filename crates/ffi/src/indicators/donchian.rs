@@ -0,0 +1,167 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Donchian Channels (DONCHIAN)
+
+use crate::handle::{self, impl_handle};
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{input_ref, output_slice, write_output, TechalibFloat, TechalibOhlcv};
+use techalib::indicators::donchian::{donchian_into, DonchianState};
+use techalib::traits::State;
+
+/// Opaque handle on a DONCHIAN state
+pub struct TechalibDonchianState(DonchianState<TechalibFloat>);
+
+impl_handle!(
+    TechalibDonchianState,
+    DonchianState<TechalibFloat>,
+    "donchian"
+);
+
+/// Calculation of the DONCHIAN function
+/// ---
+/// It stores the `ohlcv->len` calculated values in each output array and,
+/// when `state` is not null, a new [`TechalibDonchianState`] in `*state`.
+///
+/// Safety
+/// ---
+/// - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+///   values.
+/// - `output_upper`, `output_middle` and `output_lower` must each be valid for writes of
+///   `ohlcv.len` values, and must not overlap the inputs, or each other.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_donchian_into(
+    ohlcv: *const TechalibOhlcv,
+    period: usize,
+    output_upper: *mut TechalibFloat,
+    output_middle: *mut TechalibFloat,
+    output_lower: *mut TechalibFloat,
+    state: *mut *mut TechalibDonchianState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let ohlcv = input_ref(ohlcv, "ohlcv")?;
+        let len = ohlcv.len;
+        let new_state = donchian_into(
+            &ohlcv.view(),
+            period,
+            output_slice(output_upper, len, "output_upper")?,
+            output_slice(output_middle, len, "output_middle")?,
+            output_slice(output_lower, len, "output_lower")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Update of a DONCHIAN state
+/// ---
+/// It updates `state` with the `high` and `low` of a new bar and writes the new
+/// values to the output pointers which are not null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `output_upper`, `output_middle` and `output_lower` must each be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_donchian_state_update(
+    state: *mut TechalibDonchianState,
+    high: TechalibFloat,
+    low: TechalibFloat,
+    output_upper: *mut TechalibFloat,
+    output_middle: *mut TechalibFloat,
+    output_lower: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let state = handle::state_mut(state)?;
+        state.update((high, low))?;
+        write_output(output_upper, state.upper);
+        write_output(output_middle, state.middle);
+        write_output(output_lower, state.lower);
+        Ok(())
+    })
+}
+
+/// Serialization of a DONCHIAN state
+/// ---
+/// It writes the size of the serialized state to `*written`, and the state to
+/// `buffer` when `capacity` is large enough. Otherwise, it returns
+/// `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+/// - `written` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_donchian_state_serialize(
+    state: *const TechalibDonchianState,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    handle::serialize(state, buffer, capacity, written)
+}
+
+/// Deserialization of a DONCHIAN state
+/// ---
+/// It stores in `*state` a new [`TechalibDonchianState`] read from the `len` bytes of a
+/// state serialized with [`techalib_donchian_state_serialize`].
+///
+/// Safety
+/// ---
+/// - `bytes` must be valid for reads of `len` bytes.
+/// - `state` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_donchian_state_deserialize(
+    bytes: *const u8,
+    len: usize,
+    state: *mut *mut TechalibDonchianState,
+) -> TechalibStatus {
+    handle::deserialize(bytes, len, state)
+}
+
+/// Release of a DONCHIAN state
+/// ---
+/// It frees a [`TechalibDonchianState`], a null `state` is ignored.
+///
+/// Safety
+/// ---
+/// - `state` must be null or a handle of this library which has not been freed yet.
+/// - `state` must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_donchian_state_free(state: *mut TechalibDonchianState) {
+    handle::free(state)
+}
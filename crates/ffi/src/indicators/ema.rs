@@ -0,0 +1,155 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Exponential Moving Average (EMA)
+
+use crate::handle::{self, impl_handle};
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{input_slice, optional, output_slice, write_output, TechalibFloat};
+use techalib::indicators::ema::{ema_into, EmaState};
+use techalib::traits::State;
+
+/// Opaque handle on an EMA state
+pub struct TechalibEmaState(EmaState<TechalibFloat>);
+
+impl_handle!(TechalibEmaState, EmaState<TechalibFloat>, "ema");
+
+/// Calculation of the EMA function
+/// ---
+/// It stores the `len` calculated values in `output` and,
+/// when `state` is not null, a new [`TechalibEmaState`] in `*state`.
+///
+/// - `alpha`: The smoothing factor, `NaN` for the default `2 / (period + 1)`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ema_into(
+    data: *const TechalibFloat,
+    len: usize,
+    period: usize,
+    alpha: TechalibFloat,
+    output: *mut TechalibFloat,
+    state: *mut *mut TechalibEmaState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let new_state = ema_into(
+            input_slice(data, len, "data")?,
+            period,
+            optional(alpha),
+            output_slice(output, len, "output")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Update of an EMA state
+/// ---
+/// It updates `state` with a new sample and writes the new value to
+/// `output` when it is not null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `output` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ema_state_update(
+    state: *mut TechalibEmaState,
+    sample: TechalibFloat,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let state = handle::state_mut(state)?;
+        state.update(sample)?;
+        write_output(output, state.ema);
+        Ok(())
+    })
+}
+
+/// Serialization of an EMA state
+/// ---
+/// It writes the size of the serialized state to `*written`, and the state to
+/// `buffer` when `capacity` is large enough. Otherwise, it returns
+/// `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+/// - `written` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ema_state_serialize(
+    state: *const TechalibEmaState,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    handle::serialize(state, buffer, capacity, written)
+}
+
+/// Deserialization of an EMA state
+/// ---
+/// It stores in `*state` a new [`TechalibEmaState`] read from the `len` bytes of a
+/// state serialized with [`techalib_ema_state_serialize`].
+///
+/// Safety
+/// ---
+/// - `bytes` must be valid for reads of `len` bytes.
+/// - `state` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ema_state_deserialize(
+    bytes: *const u8,
+    len: usize,
+    state: *mut *mut TechalibEmaState,
+) -> TechalibStatus {
+    handle::deserialize(bytes, len, state)
+}
+
+/// Release of an EMA state
+/// ---
+/// It frees a [`TechalibEmaState`], a null `state` is ignored.
+///
+/// Safety
+/// ---
+/// - `state` must be null or a handle of this library which has not been freed yet.
+/// - `state` must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ema_state_free(state: *mut TechalibEmaState) {
+    handle::free(state)
+}
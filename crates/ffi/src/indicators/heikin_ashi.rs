@@ -0,0 +1,172 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Heikin-Ashi (HEIKIN_ASHI) candles
+
+use crate::handle::{self, impl_handle};
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{input_ref, output_slice, write_output, TechalibFloat, TechalibOhlcv};
+use techalib::indicators::heikin_ashi::{heikin_ashi_into, HeikinAshiState};
+use techalib::traits::State;
+
+/// Opaque handle on an HEIKIN_ASHI state
+pub struct TechalibHeikinAshiState(HeikinAshiState<TechalibFloat>);
+
+impl_handle!(
+    TechalibHeikinAshiState,
+    HeikinAshiState<TechalibFloat>,
+    "heikin_ashi"
+);
+
+/// Calculation of the HEIKIN_ASHI function
+/// ---
+/// It stores the `ohlcv->len` calculated values in each output array and,
+/// when `state` is not null, a new [`TechalibHeikinAshiState`] in `*state`.
+///
+/// Safety
+/// ---
+/// - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+///   values.
+/// - `output_open`, `output_high`, `output_low` and `output_close` must each be valid for
+///   writes of `ohlcv.len` values, and must not overlap the inputs, or each other.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_heikin_ashi_into(
+    ohlcv: *const TechalibOhlcv,
+    output_open: *mut TechalibFloat,
+    output_high: *mut TechalibFloat,
+    output_low: *mut TechalibFloat,
+    output_close: *mut TechalibFloat,
+    state: *mut *mut TechalibHeikinAshiState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let ohlcv = input_ref(ohlcv, "ohlcv")?;
+        let len = ohlcv.len;
+        let new_state = heikin_ashi_into(
+            &ohlcv.view(),
+            output_slice(output_open, len, "output_open")?,
+            output_slice(output_high, len, "output_high")?,
+            output_slice(output_low, len, "output_low")?,
+            output_slice(output_close, len, "output_close")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Update of an HEIKIN_ASHI state
+/// ---
+/// It updates `state` with the `open`, `high`, `low` and `close` of a new bar and writes the new
+/// values to the output pointers which are not null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `output_open`, `output_high`, `output_low` and `output_close` must each be null or valid
+///   for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_heikin_ashi_state_update(
+    state: *mut TechalibHeikinAshiState,
+    open: TechalibFloat,
+    high: TechalibFloat,
+    low: TechalibFloat,
+    close: TechalibFloat,
+    output_open: *mut TechalibFloat,
+    output_high: *mut TechalibFloat,
+    output_low: *mut TechalibFloat,
+    output_close: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let state = handle::state_mut(state)?;
+        state.update((open, high, low, close))?;
+        write_output(output_open, state.open);
+        write_output(output_high, state.high);
+        write_output(output_low, state.low);
+        write_output(output_close, state.close);
+        Ok(())
+    })
+}
+
+/// Serialization of an HEIKIN_ASHI state
+/// ---
+/// It writes the size of the serialized state to `*written`, and the state to
+/// `buffer` when `capacity` is large enough. Otherwise, it returns
+/// `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+/// - `written` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_heikin_ashi_state_serialize(
+    state: *const TechalibHeikinAshiState,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    handle::serialize(state, buffer, capacity, written)
+}
+
+/// Deserialization of an HEIKIN_ASHI state
+/// ---
+/// It stores in `*state` a new [`TechalibHeikinAshiState`] read from the `len` bytes of a
+/// state serialized with [`techalib_heikin_ashi_state_serialize`].
+///
+/// Safety
+/// ---
+/// - `bytes` must be valid for reads of `len` bytes.
+/// - `state` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_heikin_ashi_state_deserialize(
+    bytes: *const u8,
+    len: usize,
+    state: *mut *mut TechalibHeikinAshiState,
+) -> TechalibStatus {
+    handle::deserialize(bytes, len, state)
+}
+
+/// Release of an HEIKIN_ASHI state
+/// ---
+/// It frees a [`TechalibHeikinAshiState`], a null `state` is ignored.
+///
+/// Safety
+/// ---
+/// - `state` must be null or a handle of this library which has not been freed yet.
+/// - `state` must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_heikin_ashi_state_free(state: *mut TechalibHeikinAshiState) {
+    handle::free(state)
+}
@@ -0,0 +1,180 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Ichimoku Kinko Hyo (ICHIMOKU)
+
+use crate::handle::{self, impl_handle};
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{
+    input_ref, output_slice, write_output, TechalibFloat, TechalibIchimokuPeriods, TechalibOhlcv,
+};
+use techalib::indicators::ichimoku::{ichimoku_into, IchimokuState};
+use techalib::traits::State;
+
+/// Opaque handle on an ICHIMOKU state
+pub struct TechalibIchimokuState(IchimokuState<TechalibFloat>);
+
+impl_handle!(
+    TechalibIchimokuState,
+    IchimokuState<TechalibFloat>,
+    "ichimoku"
+);
+
+/// Calculation of the ICHIMOKU function
+/// ---
+/// It stores the `ohlcv->len` calculated values in each output array and,
+/// when `state` is not null, a new [`TechalibIchimokuState`] in `*state`.
+///
+/// Safety
+/// ---
+/// - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+///   values.
+/// - `output_tenkan`, `output_kijun`, `output_senkou_a`, `output_senkou_b` and `output_chikou`
+///   must each be valid for writes of `ohlcv.len` values, and must not overlap the inputs, or
+///   each other.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ichimoku_into(
+    ohlcv: *const TechalibOhlcv,
+    periods: TechalibIchimokuPeriods,
+    output_tenkan: *mut TechalibFloat,
+    output_kijun: *mut TechalibFloat,
+    output_senkou_a: *mut TechalibFloat,
+    output_senkou_b: *mut TechalibFloat,
+    output_chikou: *mut TechalibFloat,
+    state: *mut *mut TechalibIchimokuState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let ohlcv = input_ref(ohlcv, "ohlcv")?;
+        let len = ohlcv.len;
+        let new_state = ichimoku_into(
+            &ohlcv.view(),
+            periods.into(),
+            output_slice(output_tenkan, len, "output_tenkan")?,
+            output_slice(output_kijun, len, "output_kijun")?,
+            output_slice(output_senkou_a, len, "output_senkou_a")?,
+            output_slice(output_senkou_b, len, "output_senkou_b")?,
+            output_slice(output_chikou, len, "output_chikou")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Update of an ICHIMOKU state
+/// ---
+/// It updates `state` with the `high`, `low` and `close` of a new bar and writes the new
+/// values to the output pointers which are not null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `output_tenkan`, `output_kijun`, `output_senkou_a`, `output_senkou_b` and `output_chikou`
+///   must each be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ichimoku_state_update(
+    state: *mut TechalibIchimokuState,
+    high: TechalibFloat,
+    low: TechalibFloat,
+    close: TechalibFloat,
+    output_tenkan: *mut TechalibFloat,
+    output_kijun: *mut TechalibFloat,
+    output_senkou_a: *mut TechalibFloat,
+    output_senkou_b: *mut TechalibFloat,
+    output_chikou: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let state = handle::state_mut(state)?;
+        state.update((high, low, close))?;
+        write_output(output_tenkan, state.tenkan);
+        write_output(output_kijun, state.kijun);
+        write_output(output_senkou_a, state.senkou_a);
+        write_output(output_senkou_b, state.senkou_b);
+        write_output(output_chikou, state.chikou);
+        Ok(())
+    })
+}
+
+/// Serialization of an ICHIMOKU state
+/// ---
+/// It writes the size of the serialized state to `*written`, and the state to
+/// `buffer` when `capacity` is large enough. Otherwise, it returns
+/// `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+/// - `written` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ichimoku_state_serialize(
+    state: *const TechalibIchimokuState,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    handle::serialize(state, buffer, capacity, written)
+}
+
+/// Deserialization of an ICHIMOKU state
+/// ---
+/// It stores in `*state` a new [`TechalibIchimokuState`] read from the `len` bytes of a
+/// state serialized with [`techalib_ichimoku_state_serialize`].
+///
+/// Safety
+/// ---
+/// - `bytes` must be valid for reads of `len` bytes.
+/// - `state` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ichimoku_state_deserialize(
+    bytes: *const u8,
+    len: usize,
+    state: *mut *mut TechalibIchimokuState,
+) -> TechalibStatus {
+    handle::deserialize(bytes, len, state)
+}
+
+/// Release of an ICHIMOKU state
+/// ---
+/// It frees a [`TechalibIchimokuState`], a null `state` is ignored.
+///
+/// Safety
+/// ---
+/// - `state` must be null or a handle of this library which has not been freed yet.
+/// - `state` must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ichimoku_state_free(state: *mut TechalibIchimokuState) {
+    handle::free(state)
+}
@@ -0,0 +1,165 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Moving Average Convergence Divergence (MACD)
+
+use crate::handle::{self, impl_handle};
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{input_slice, output_slice, write_output, TechalibFloat};
+use techalib::indicators::macd::{macd_into, MacdState};
+use techalib::traits::State;
+
+/// Opaque handle on an MACD state
+pub struct TechalibMacdState(MacdState<TechalibFloat>);
+
+impl_handle!(TechalibMacdState, MacdState<TechalibFloat>, "macd");
+
+/// Calculation of the MACD function
+/// ---
+/// It stores the `len` calculated values in each output array and,
+/// when `state` is not null, a new [`TechalibMacdState`] in `*state`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output_macd`, `output_signal` and `output_histogram` must each be valid for writes of
+///   `len` values, and must not overlap the inputs, or each other.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_macd_into(
+    data: *const TechalibFloat,
+    len: usize,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    output_macd: *mut TechalibFloat,
+    output_signal: *mut TechalibFloat,
+    output_histogram: *mut TechalibFloat,
+    state: *mut *mut TechalibMacdState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let new_state = macd_into(
+            input_slice(data, len, "data")?,
+            fast_period,
+            slow_period,
+            signal_period,
+            output_slice(output_macd, len, "output_macd")?,
+            output_slice(output_signal, len, "output_signal")?,
+            output_slice(output_histogram, len, "output_histogram")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Update of an MACD state
+/// ---
+/// It updates `state` with a new sample and writes the new values to the
+/// output pointers which are not null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `output_macd`, `output_signal` and `output_histogram` must each be null or valid for a
+///   write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_macd_state_update(
+    state: *mut TechalibMacdState,
+    sample: TechalibFloat,
+    output_macd: *mut TechalibFloat,
+    output_signal: *mut TechalibFloat,
+    output_histogram: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let state = handle::state_mut(state)?;
+        state.update(sample)?;
+        write_output(output_macd, state.macd);
+        write_output(output_signal, state.signal);
+        write_output(output_histogram, state.histogram);
+        Ok(())
+    })
+}
+
+/// Serialization of an MACD state
+/// ---
+/// It writes the size of the serialized state to `*written`, and the state to
+/// `buffer` when `capacity` is large enough. Otherwise, it returns
+/// `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+/// - `written` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_macd_state_serialize(
+    state: *const TechalibMacdState,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    handle::serialize(state, buffer, capacity, written)
+}
+
+/// Deserialization of an MACD state
+/// ---
+/// It stores in `*state` a new [`TechalibMacdState`] read from the `len` bytes of a
+/// state serialized with [`techalib_macd_state_serialize`].
+///
+/// Safety
+/// ---
+/// - `bytes` must be valid for reads of `len` bytes.
+/// - `state` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_macd_state_deserialize(
+    bytes: *const u8,
+    len: usize,
+    state: *mut *mut TechalibMacdState,
+) -> TechalibStatus {
+    handle::deserialize(bytes, len, state)
+}
+
+/// Release of an MACD state
+/// ---
+/// It frees a [`TechalibMacdState`], a null `state` is ignored.
+///
+/// Safety
+/// ---
+/// - `state` must be null or a handle of this library which has not been freed yet.
+/// - `state` must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_macd_state_free(state: *mut TechalibMacdState) {
+    handle::free(state)
+}
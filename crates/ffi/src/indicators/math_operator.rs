@@ -0,0 +1,137 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Math Operator functions
+
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{input_slice, output_slice, TechalibFloat};
+use techalib::errors::TechalibError;
+use techalib::indicators::math_operator::{add_into, div_into, mult_into, sub_into};
+
+type Operator =
+    fn(&[TechalibFloat], &[TechalibFloat], &mut [TechalibFloat]) -> Result<(), TechalibError>;
+
+unsafe fn binary(
+    operator: Operator,
+    data0: *const TechalibFloat,
+    data1: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        operator(
+            input_slice(data0, len, "data0")?,
+            input_slice(data1, len, "data1")?,
+            output_slice(output, len, "output")?,
+        )?;
+        Ok(())
+    })
+}
+
+/// Calculation of the vector arithmetic ADD function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data0` must be valid for reads of `len` values.
+/// - `data1` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_add_into(
+    data0: *const TechalibFloat,
+    data1: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    binary(add_into, data0, data1, len, output)
+}
+
+/// Calculation of the vector arithmetic SUB function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data0` must be valid for reads of `len` values.
+/// - `data1` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_sub_into(
+    data0: *const TechalibFloat,
+    data1: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    binary(sub_into, data0, data1, len, output)
+}
+
+/// Calculation of the vector arithmetic MULT function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data0` must be valid for reads of `len` values.
+/// - `data1` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_mult_into(
+    data0: *const TechalibFloat,
+    data1: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    binary(mult_into, data0, data1, len, output)
+}
+
+/// Calculation of the vector arithmetic DIV function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data0` must be valid for reads of `len` values.
+/// - `data1` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_div_into(
+    data0: *const TechalibFloat,
+    data1: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    binary(div_into, data0, data1, len, output)
+}
@@ -0,0 +1,316 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Math Transform functions
+
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{input_slice, output_slice, TechalibFloat};
+use techalib::errors::TechalibError;
+use techalib::indicators::math_transform::{
+    acos_into, asin_into, atan_into, ceil_into, cos_into, cosh_into, exp_into, floor_into, ln_into,
+    log10_into, sin_into, sinh_into, sqrt_into, tan_into, tanh_into,
+};
+
+type Transform = fn(&[TechalibFloat], &mut [TechalibFloat]) -> Result<(), TechalibError>;
+
+unsafe fn unary(
+    transform: Transform,
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        transform(
+            input_slice(data, len, "data")?,
+            output_slice(output, len, "output")?,
+        )?;
+        Ok(())
+    })
+}
+
+/// Calculation of the vector trigonometric ACOS function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_acos_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(acos_into, data, len, output)
+}
+
+/// Calculation of the vector trigonometric ASIN function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_asin_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(asin_into, data, len, output)
+}
+
+/// Calculation of the vector trigonometric ATAN function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_atan_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(atan_into, data, len, output)
+}
+
+/// Calculation of the vector CEIL function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ceil_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(ceil_into, data, len, output)
+}
+
+/// Calculation of the vector trigonometric COS function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_cos_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(cos_into, data, len, output)
+}
+
+/// Calculation of the vector trigonometric COSH function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_cosh_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(cosh_into, data, len, output)
+}
+
+/// Calculation of the vector arithmetic EXP function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_exp_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(exp_into, data, len, output)
+}
+
+/// Calculation of the vector FLOOR function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_floor_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(floor_into, data, len, output)
+}
+
+/// Calculation of the vector natural logarithm LN function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_ln_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(ln_into, data, len, output)
+}
+
+/// Calculation of the vector LOG10 function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_log10_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(log10_into, data, len, output)
+}
+
+/// Calculation of the vector trigonometric SIN function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_sin_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(sin_into, data, len, output)
+}
+
+/// Calculation of the vector trigonometric SINH function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_sinh_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(sinh_into, data, len, output)
+}
+
+/// Calculation of the vector square root SQRT function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_sqrt_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(sqrt_into, data, len, output)
+}
+
+/// Calculation of the vector trigonometric TAN function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_tan_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(tan_into, data, len, output)
+}
+
+/// Calculation of the vector trigonometric TANH function
+/// ---
+/// It stores the `len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `data` must be valid for reads of `len` values.
+/// - `output` must be valid for writes of `len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_tanh_into(
+    data: *const TechalibFloat,
+    len: usize,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    unary(tanh_into, data, len, output)
+}
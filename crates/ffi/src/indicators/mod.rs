@@ -0,0 +1,84 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+pub mod alma;
+pub mod atr;
+pub mod bbands;
+pub mod chandelier_exit;
+pub mod cmf;
+pub mod dema;
+pub mod donchian;
+pub mod elder_ray;
+pub mod ema;
+pub mod envelope;
+pub mod eom;
+pub mod force_index;
+pub mod heikin_ashi;
+pub mod hma;
+pub mod ichimoku;
+pub mod kama;
+pub mod keltner;
+pub mod kurtosis;
+pub mod ma;
+pub mod macd;
+pub mod math_operator;
+pub mod math_transform;
+pub mod mavp;
+pub mod max;
+pub mod maxindex;
+pub mod median;
+pub mod min;
+pub mod minindex;
+pub mod minmax;
+pub mod percent_rank;
+pub mod pivots;
+pub mod price_transform;
+pub mod rsi;
+pub mod skew;
+pub mod sma;
+pub mod smma;
+pub mod sum;
+pub mod supertrend;
+pub mod swing;
+pub mod t3;
+pub mod tema;
+pub mod trima;
+pub mod vidya;
+pub mod volatility;
+pub mod vwap;
+pub mod wma;
+pub mod zigzag;
+pub mod zlema;
+pub mod zscore;
@@ -0,0 +1,208 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Pivot Points (PIVOTS)
+
+use crate::handle::{self, impl_handle};
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{
+    input_ref, input_slice, output_slice, write_output, TechalibFloat, TechalibOhlcv,
+    TechalibPivotMethod,
+};
+use techalib::indicators::pivots::{pivots_into, PivotsState};
+use techalib::traits::State;
+
+/// Opaque handle on a PIVOTS state
+pub struct TechalibPivotsState(PivotsState<TechalibFloat>);
+
+impl_handle!(TechalibPivotsState, PivotsState<TechalibFloat>, "pivots");
+
+/// Calculation of the PIVOTS function
+/// ---
+/// It stores the `ohlcv->len` calculated values in each output array and,
+/// when `state` is not null, a new [`TechalibPivotsState`] in `*state`.
+///
+/// - `new_session`: `ohlcv->len` flags, `true` on the first bar of each session.
+///
+/// Safety
+/// ---
+/// - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+///   values.
+/// - `new_session` must be valid for reads of `len` values.
+/// - `output_pivot`, `output_r1`, `output_r2`, `output_r3`, `output_s1`, `output_s2` and
+///   `output_s3` must each be valid for writes of `ohlcv.len` values, and must not overlap the
+///   inputs, or each other.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_pivots_into(
+    ohlcv: *const TechalibOhlcv,
+    new_session: *const bool,
+    method: TechalibPivotMethod,
+    output_pivot: *mut TechalibFloat,
+    output_r1: *mut TechalibFloat,
+    output_r2: *mut TechalibFloat,
+    output_r3: *mut TechalibFloat,
+    output_s1: *mut TechalibFloat,
+    output_s2: *mut TechalibFloat,
+    output_s3: *mut TechalibFloat,
+    state: *mut *mut TechalibPivotsState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let ohlcv = input_ref(ohlcv, "ohlcv")?;
+        let len = ohlcv.len;
+        let new_state = pivots_into(
+            &ohlcv.view(),
+            input_slice(new_session, len, "new_session")?,
+            method.into(),
+            output_slice(output_pivot, len, "output_pivot")?,
+            output_slice(output_r1, len, "output_r1")?,
+            output_slice(output_r2, len, "output_r2")?,
+            output_slice(output_r3, len, "output_r3")?,
+            output_slice(output_s1, len, "output_s1")?,
+            output_slice(output_s2, len, "output_s2")?,
+            output_slice(output_s3, len, "output_s3")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Session rollover of a PIVOTS state
+/// ---
+/// It starts a new session: the next update is the first bar of the session.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_pivots_state_rollover(
+    state: *mut TechalibPivotsState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        handle::state_mut(state)?.rollover();
+        Ok(())
+    })
+}
+
+/// Update of a PIVOTS state
+/// ---
+/// It updates `state` with the `open`, `high`, `low` and `close` of a new bar and writes the new
+/// values to the output pointers which are not null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `output_pivot`, `output_r1`, `output_r2`, `output_r3`, `output_s1`, `output_s2` and
+///   `output_s3` must each be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_pivots_state_update(
+    state: *mut TechalibPivotsState,
+    open: TechalibFloat,
+    high: TechalibFloat,
+    low: TechalibFloat,
+    close: TechalibFloat,
+    output_pivot: *mut TechalibFloat,
+    output_r1: *mut TechalibFloat,
+    output_r2: *mut TechalibFloat,
+    output_r3: *mut TechalibFloat,
+    output_s1: *mut TechalibFloat,
+    output_s2: *mut TechalibFloat,
+    output_s3: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let state = handle::state_mut(state)?;
+        state.update((open, high, low, close))?;
+        write_output(output_pivot, state.pivot);
+        write_output(output_r1, state.r1);
+        write_output(output_r2, state.r2);
+        write_output(output_r3, state.r3);
+        write_output(output_s1, state.s1);
+        write_output(output_s2, state.s2);
+        write_output(output_s3, state.s3);
+        Ok(())
+    })
+}
+
+/// Serialization of a PIVOTS state
+/// ---
+/// It writes the size of the serialized state to `*written`, and the state to
+/// `buffer` when `capacity` is large enough. Otherwise, it returns
+/// `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+/// - `written` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_pivots_state_serialize(
+    state: *const TechalibPivotsState,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    handle::serialize(state, buffer, capacity, written)
+}
+
+/// Deserialization of a PIVOTS state
+/// ---
+/// It stores in `*state` a new [`TechalibPivotsState`] read from the `len` bytes of a
+/// state serialized with [`techalib_pivots_state_serialize`].
+///
+/// Safety
+/// ---
+/// - `bytes` must be valid for reads of `len` bytes.
+/// - `state` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_pivots_state_deserialize(
+    bytes: *const u8,
+    len: usize,
+    state: *mut *mut TechalibPivotsState,
+) -> TechalibStatus {
+    handle::deserialize(bytes, len, state)
+}
+
+/// Release of a PIVOTS state
+/// ---
+/// It frees a [`TechalibPivotsState`], a null `state` is ignored.
+///
+/// Safety
+/// ---
+/// - `state` must be null or a handle of this library which has not been freed yet.
+/// - `state` must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_pivots_state_free(state: *mut TechalibPivotsState) {
+    handle::free(state)
+}
@@ -0,0 +1,126 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Price Transform functions
+
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{input_ref, output_slice, TechalibFloat, TechalibOhlcv};
+use techalib::errors::TechalibError;
+use techalib::indicators::price_transform::{
+    avgprice_into, medprice_into, typprice_into, wclprice_into,
+};
+use techalib::types::Ohlcv;
+
+type Transform = fn(&Ohlcv<TechalibFloat>, &mut [TechalibFloat]) -> Result<(), TechalibError>;
+
+unsafe fn price_transform(
+    transform: Transform,
+    ohlcv: *const TechalibOhlcv,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let ohlcv = input_ref(ohlcv, "ohlcv")?;
+        transform(&ohlcv.view(), output_slice(output, ohlcv.len, "output")?)?;
+        Ok(())
+    })
+}
+
+/// Calculation of the AVGPRICE function
+/// ---
+/// It stores the `ohlcv->len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+///   values.
+/// - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_avgprice_into(
+    ohlcv: *const TechalibOhlcv,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    price_transform(avgprice_into, ohlcv, output)
+}
+
+/// Calculation of the MEDPRICE function
+/// ---
+/// It stores the `ohlcv->len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+///   values.
+/// - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_medprice_into(
+    ohlcv: *const TechalibOhlcv,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    price_transform(medprice_into, ohlcv, output)
+}
+
+/// Calculation of the TYPPRICE function
+/// ---
+/// It stores the `ohlcv->len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+///   values.
+/// - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_typprice_into(
+    ohlcv: *const TechalibOhlcv,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    price_transform(typprice_into, ohlcv, output)
+}
+
+/// Calculation of the WCLPRICE function
+/// ---
+/// It stores the `ohlcv->len` calculated values in `output`.
+///
+/// Safety
+/// ---
+/// - `ohlcv` must be valid for a read, and its non-null columns valid for reads of `ohlcv.len`
+///   values.
+/// - `output` must be valid for writes of `ohlcv.len` values, and must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_wclprice_into(
+    ohlcv: *const TechalibOhlcv,
+    output: *mut TechalibFloat,
+) -> TechalibStatus {
+    price_transform(wclprice_into, ohlcv, output)
+}
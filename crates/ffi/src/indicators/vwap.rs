@@ -0,0 +1,188 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C functions of the Volume Weighted Average Price (VWAP)
+
+use crate::handle::{self, impl_handle};
+use crate::status::{ffi_call, TechalibStatus};
+use crate::types::{input_slice, output_slice, write_output, TechalibFloat, TechalibVwapReset};
+use techalib::indicators::vwap::{vwap_into, VwapState};
+use techalib::traits::State;
+
+/// Opaque handle on a VWAP state
+pub struct TechalibVwapState(VwapState<TechalibFloat>);
+
+impl_handle!(TechalibVwapState, VwapState<TechalibFloat>, "vwap");
+
+/// Calculation of the VWAP function
+/// ---
+/// It stores the `len` calculated values in each output array and,
+/// when `state` is not null, a new [`TechalibVwapState`] in `*state`.
+///
+/// - `reset`: The session boundaries, see [`TechalibVwapReset`].
+///
+/// Safety
+/// ---
+/// - `price` must be valid for reads of `len` values.
+/// - `volume` must be valid for reads of `len` values.
+/// - `reset.mask` must be valid for reads of `len` values when it is used.
+/// - `reset.anchors` must be valid for reads of `reset.anchors_len` values when they are used.
+/// - `output_vwap`, `output_upper` and `output_lower` must each be valid for writes of `len`
+///   values, and must not overlap the inputs, or each other.
+/// - `state` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_vwap_into(
+    price: *const TechalibFloat,
+    volume: *const TechalibFloat,
+    len: usize,
+    reset: TechalibVwapReset,
+    multiplier: TechalibFloat,
+    output_vwap: *mut TechalibFloat,
+    output_upper: *mut TechalibFloat,
+    output_lower: *mut TechalibFloat,
+    state: *mut *mut TechalibVwapState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let reset = reset.to_core(len)?;
+        let new_state = vwap_into(
+            input_slice(price, len, "price")?,
+            input_slice(volume, len, "volume")?,
+            reset,
+            multiplier,
+            output_slice(output_vwap, len, "output_vwap")?,
+            output_slice(output_upper, len, "output_upper")?,
+            output_slice(output_lower, len, "output_lower")?,
+        )?;
+        handle::store(state, new_state);
+        Ok(())
+    })
+}
+
+/// Session reset of a VWAP state
+/// ---
+/// It starts a new session: the next update is the first bar of the session.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_vwap_state_reset(
+    state: *mut TechalibVwapState,
+) -> TechalibStatus {
+    ffi_call(|| {
+        handle::state_mut(state)?.reset();
+        Ok(())
+    })
+}
+
+/// Update of a VWAP state
+/// ---
+/// It updates `state` with the `price` and `volume` of a new bar and writes the new
+/// values to the output pointers which are not null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `output_vwap`, `output_upper` and `output_lower` must each be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_vwap_state_update(
+    state: *mut TechalibVwapState,
+    price: TechalibFloat,
+    volume: TechalibFloat,
+    output_vwap: *mut TechalibFloat,
+    output_upper: *mut TechalibFloat,
+    output_lower: *mut TechalibFloat,
+) -> TechalibStatus {
+    ffi_call(|| {
+        let state = handle::state_mut(state)?;
+        state.update((price, volume))?;
+        write_output(output_vwap, state.vwap);
+        write_output(output_upper, state.upper);
+        write_output(output_lower, state.lower);
+        Ok(())
+    })
+}
+
+/// Serialization of a VWAP state
+/// ---
+/// It writes the size of the serialized state to `*written`, and the state to
+/// `buffer` when `capacity` is large enough. Otherwise, it returns
+/// `TECHALIB_STATUS_BUFFER_TOO_SMALL`, `buffer` can then be null.
+///
+/// Safety
+/// ---
+/// - `state` must be a handle of this library which has not been freed.
+/// - `buffer` must be valid for writes of `capacity` bytes, unless `capacity` is too small.
+/// - `written` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_vwap_state_serialize(
+    state: *const TechalibVwapState,
+    buffer: *mut u8,
+    capacity: usize,
+    written: *mut usize,
+) -> TechalibStatus {
+    handle::serialize(state, buffer, capacity, written)
+}
+
+/// Deserialization of a VWAP state
+/// ---
+/// It stores in `*state` a new [`TechalibVwapState`] read from the `len` bytes of a
+/// state serialized with [`techalib_vwap_state_serialize`].
+///
+/// Safety
+/// ---
+/// - `bytes` must be valid for reads of `len` bytes.
+/// - `state` must be valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_vwap_state_deserialize(
+    bytes: *const u8,
+    len: usize,
+    state: *mut *mut TechalibVwapState,
+) -> TechalibStatus {
+    handle::deserialize(bytes, len, state)
+}
+
+/// Release of a VWAP state
+/// ---
+/// It frees a [`TechalibVwapState`], a null `state` is ignored.
+///
+/// Safety
+/// ---
+/// - `state` must be null or a handle of this library which has not been freed yet.
+/// - `state` must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn techalib_vwap_state_free(state: *mut TechalibVwapState) {
+    handle::free(state)
+}
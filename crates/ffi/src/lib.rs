@@ -0,0 +1,72 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+//! C ABI of techalib
+//! ---
+//! This crate exposes the `_into` function of every indicator over `extern "C"`,
+//! together with opaque handles on their states. The C header is generated with
+//! `cbindgen` from this crate (see `cbindgen.toml`) and lives in `include/techalib.h`.
+//!
+//! Conventions
+//! ---
+//! - Every function returns a [`TechalibStatus`](status::TechalibStatus). On failure,
+//!   [`techalib_last_error_message`](status::techalib_last_error_message) gives the details.
+//! - Arrays are passed as a pointer and a length. A null pointer is only accepted
+//!   for an empty array. Inputs and outputs must not overlap.
+//! - The indicators calculated from several price columns take a
+//!   [`TechalibOhlcv`](types::TechalibOhlcv), whose unused columns can be null.
+//! - `techalib_<name>_into` fills the outputs and, when `state` is not null, stores a
+//!   new state handle in `*state`. The handle is owned by the caller and must be released
+//!   with `techalib_<name>_state_free`.
+//! - `techalib_<name>_state_update` feeds a new sample to a state and writes the new
+//!   values to the non-null output pointers.
+//! - `techalib_<name>_state_serialize` and `techalib_<name>_state_deserialize` convert a
+//!   state to and from a byte buffer, to persist it or to send it to another process.
+//!   The bytes can only be read back by a library built with the same float type.
+//! - Optional smoothing factors (`alpha`) are `NaN` to use the default value.
+
+#![allow(clippy::too_many_arguments)]
+
+mod codec;
+mod handle;
+
+/// This module contains the status codes and the error reporting of the C API.
+pub mod status;
+
+/// This module contains the `#[repr(C)]` parameter types of the C API.
+pub mod types;
+
+/// This module contains the C functions of the indicators, one module per indicator.
+pub mod indicators;
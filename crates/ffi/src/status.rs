@@ -0,0 +1,185 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use techalib::errors::TechalibError;
+
+/// Status code of the C functions
+/// ---
+/// `TECHALIB_STATUS_OK` on success, otherwise the kind of the error.
+/// The first codes map one-to-one to the variants of `TechalibError`,
+/// the next ones are specific to the C API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechalibStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A parameter passed to the function is invalid.
+    BadParam = 1,
+    /// There is not enough data to perform the calculation.
+    InsufficientData = 2,
+    /// A data point is not finite (NaN or infinity).
+    DataNonFinite = 3,
+    /// An overflow occurred during the calculation.
+    Overflow = 4,
+    /// The function is not implemented yet.
+    NotImplementedYet = 5,
+    /// A required pointer is null.
+    NullPointer = 6,
+    /// The buffer given to a `_serialize` function is too small.
+    BufferTooSmall = 7,
+    /// The bytes given to a `_deserialize` function are not a valid state.
+    InvalidState = 8,
+    /// An unexpected panic was caught at the boundary of the C API.
+    Panic = 9,
+}
+
+/// Error of a call of the C API
+pub(crate) enum FfiError {
+    Techalib(TechalibError),
+    NullPointer(&'static str),
+    BufferTooSmall(usize),
+    InvalidState(String),
+}
+
+impl From<TechalibError> for FfiError {
+    fn from(error: TechalibError) -> Self {
+        FfiError::Techalib(error)
+    }
+}
+
+impl From<&TechalibError> for TechalibStatus {
+    fn from(error: &TechalibError) -> Self {
+        match error {
+            TechalibError::BadParam(_) => TechalibStatus::BadParam,
+            TechalibError::InsufficientData => TechalibStatus::InsufficientData,
+            TechalibError::DataNonFinite(_) => TechalibStatus::DataNonFinite,
+            TechalibError::Overflow(_, _) => TechalibStatus::Overflow,
+            TechalibError::NotImplementedYet => TechalibStatus::NotImplementedYet,
+        }
+    }
+}
+
+impl FfiError {
+    fn status(&self) -> TechalibStatus {
+        match self {
+            FfiError::Techalib(error) => error.into(),
+            FfiError::NullPointer(_) => TechalibStatus::NullPointer,
+            FfiError::BufferTooSmall(_) => TechalibStatus::BufferTooSmall,
+            FfiError::InvalidState(_) => TechalibStatus::InvalidState,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            FfiError::Techalib(error) => error.to_string(),
+            FfiError::NullPointer(name) => format!("`{name}` must not be null"),
+            FfiError::BufferTooSmall(needed) => {
+                format!("The buffer is too small, {needed} bytes are needed")
+            }
+            FfiError::InvalidState(msg) => format!("Invalid state: {msg}"),
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+/// Runs the body of a C function
+///
+/// It converts the result to a [`TechalibStatus`], records the error message
+/// of the failures and stops the panics at the boundary of the C API.
+pub(crate) fn ffi_call(body: impl FnOnce() -> Result<(), FfiError>) -> TechalibStatus {
+    match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => TechalibStatus::Ok,
+        Ok(Err(error)) => {
+            set_last_error(error.message());
+            error.status()
+        }
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|msg| msg.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            set_last_error(format!("Panic: {message}"));
+            TechalibStatus::Panic
+        }
+    }
+}
+
+/// Message of the last error of the calling thread
+/// ---
+/// It returns a null-terminated string describing the last failed call made
+/// by the calling thread, or a null pointer if no call failed yet.
+/// The string is owned by the library and stays valid until the next failed
+/// call on the same thread.
+#[no_mangle]
+pub extern "C" fn techalib_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Name of a status code
+/// ---
+/// It returns a static null-terminated string, e.g. `"InsufficientData"`.
+#[no_mangle]
+pub extern "C" fn techalib_status_name(status: TechalibStatus) -> *const c_char {
+    let name: &'static [u8] = match status {
+        TechalibStatus::Ok => b"Ok\0",
+        TechalibStatus::BadParam => b"BadParam\0",
+        TechalibStatus::InsufficientData => b"InsufficientData\0",
+        TechalibStatus::DataNonFinite => b"DataNonFinite\0",
+        TechalibStatus::Overflow => b"Overflow\0",
+        TechalibStatus::NotImplementedYet => b"NotImplementedYet\0",
+        TechalibStatus::NullPointer => b"NullPointer\0",
+        TechalibStatus::BufferTooSmall => b"BufferTooSmall\0",
+        TechalibStatus::InvalidState => b"InvalidState\0",
+        TechalibStatus::Panic => b"Panic\0",
+    };
+    name.as_ptr().cast()
+}
@@ -0,0 +1,496 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use crate::status::FfiError;
+use techalib::indicators::alma::{DEFAULT_OFFSET, DEFAULT_SIGMA};
+use techalib::indicators::bbands::{BBandsMA, DeviationMulipliers};
+use techalib::indicators::ichimoku::IchimokuPeriods;
+use techalib::indicators::ma::MaType;
+use techalib::indicators::mavp::MavpMA;
+use techalib::indicators::pivots::PivotMethod;
+use techalib::indicators::vidya::DEFAULT_CMO_PERIOD;
+use techalib::indicators::volatility::VolatilityEstimator;
+use techalib::indicators::vwap::VwapReset;
+use techalib::indicators::zigzag::ZigzagThreshold;
+use techalib::types::{Ohlcv, TechalibFloat as _};
+
+/// Floating point type of the C API
+/// ---
+/// `double`, or `float` when the library is built with the `f32` feature.
+/// It only depends on the features of this crate, the indicators are called
+/// with it whatever the default `Float` of `techalib` is.
+#[cfg(not(all(feature = "f32", not(feature = "f64"))))]
+pub type TechalibFloat = f64;
+
+/// Floating point type of the C API
+/// ---
+/// `double`, or `float` when the library is built with the `f32` feature.
+/// It only depends on the features of this crate, the indicators are called
+/// with it whatever the default `Float` of `techalib` is.
+#[cfg(all(feature = "f32", not(feature = "f64")))]
+pub type TechalibFloat = f32;
+
+/// OHLCV columns
+/// ---
+/// Each column is a pointer to `len` values. The columns that are not used
+/// by the indicator can be null.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TechalibOhlcv {
+    /// The open prices.
+    pub open: *const TechalibFloat,
+    /// The high prices.
+    pub high: *const TechalibFloat,
+    /// The low prices.
+    pub low: *const TechalibFloat,
+    /// The close prices.
+    pub close: *const TechalibFloat,
+    /// The volumes.
+    pub volume: *const TechalibFloat,
+    /// The number of bars.
+    pub len: usize,
+}
+
+/// Moving average of the Bollinger Bands
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechalibBbandsMa {
+    /// Simple Moving Average.
+    Sma,
+    /// Exponential Moving Average.
+    Ema,
+    /// Hull Moving Average.
+    Hma,
+    /// Zero-Lag Exponential Moving Average.
+    Zlema,
+    /// Arnaud Legoux Moving Average, with the default offset and sigma.
+    Alma,
+    /// Smoothed Moving Average.
+    Smma,
+    /// Variable Index Dynamic Average, with the default CMO period.
+    Vidya,
+}
+
+/// Kind of moving average
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechalibMaKind {
+    /// Simple Moving Average.
+    Sma,
+    /// Exponential Moving Average.
+    Ema,
+    /// Weighted Moving Average.
+    Wma,
+    /// Double Exponential Moving Average.
+    Dema,
+    /// Triple Exponential Moving Average.
+    Tema,
+    /// Triangular Moving Average.
+    Trima,
+    /// Kaufman Adaptive Moving Average.
+    Kama,
+    /// Tillson Triple Exponential Moving Average.
+    T3,
+    /// Hull Moving Average.
+    Hma,
+    /// Zero-Lag Exponential Moving Average.
+    Zlema,
+    /// Arnaud Legoux Moving Average.
+    Alma,
+    /// Smoothed Moving Average.
+    Smma,
+    /// Variable Index Dynamic Average.
+    Vidya,
+}
+
+/// Moving average and its parameters
+/// ---
+/// Only the parameters of `kind` are read.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TechalibMaType {
+    /// The kind of moving average.
+    pub kind: TechalibMaKind,
+    /// The smoothing factor of EMA, DEMA, TEMA, T3 and ZLEMA, `NaN` for the default one.
+    pub alpha: TechalibFloat,
+    /// The volume factor of T3.
+    pub volume_factor: TechalibFloat,
+    /// The offset of ALMA.
+    pub offset: TechalibFloat,
+    /// The sigma of ALMA.
+    pub sigma: TechalibFloat,
+    /// The period of the Chande Momentum Oscillator of VIDYA.
+    pub cmo_period: usize,
+}
+
+/// Moving average of the MAVP
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechalibMavpMa {
+    /// Simple Moving Average.
+    Sma,
+    /// Exponential Moving Average.
+    Ema,
+    /// Weighted Moving Average.
+    Wma,
+    /// Hull Moving Average.
+    Hma,
+    /// Zero-Lag Exponential Moving Average.
+    Zlema,
+    /// Arnaud Legoux Moving Average, with the default offset and sigma.
+    Alma,
+    /// Smoothed Moving Average.
+    Smma,
+    /// Variable Index Dynamic Average, with the default CMO period.
+    Vidya,
+}
+
+/// Periods of the Ichimoku Kinko Hyo
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TechalibIchimokuPeriods {
+    /// The period of the Tenkan-sen.
+    pub tenkan: usize,
+    /// The period of the Kijun-sen.
+    pub kijun: usize,
+    /// The period of the Senkou span B.
+    pub senkou_b: usize,
+    /// The number of bars the spans are shifted by.
+    pub displacement: usize,
+}
+
+/// Calculation method of the pivot points
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechalibPivotMethod {
+    /// Classic (floor) pivot points.
+    Classic,
+    /// Fibonacci pivot points.
+    Fibonacci,
+    /// Camarilla pivot points.
+    Camarilla,
+    /// Woodie pivot points.
+    Woodie,
+    /// DeMark pivot points.
+    Demark,
+}
+
+/// Estimator of the historical volatility
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechalibVolatilityEstimator {
+    /// Standard deviation of the close-to-close log returns.
+    CloseToClose,
+    /// Parkinson estimator (high, low).
+    Parkinson,
+    /// Garman-Klass estimator (open, high, low, close).
+    GarmanKlass,
+    /// Rogers-Satchell estimator (open, high, low, close).
+    RogersSatchell,
+    /// Yang-Zhang estimator (open, high, low, close).
+    YangZhang,
+}
+
+/// Kind of session reset of the VWAP
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechalibVwapResetKind {
+    /// The VWAP is never reset.
+    Never,
+    /// A new session starts at each index where `mask` is `true`.
+    Mask,
+    /// A new session starts at each index of `anchors`.
+    Anchors,
+}
+
+/// Session reset of the VWAP
+/// ---
+/// `mask` holds one value per bar and is only read with `TECHALIB_VWAP_RESET_KIND_MASK`,
+/// `anchors` holds `anchors_len` indices and is only read with
+/// `TECHALIB_VWAP_RESET_KIND_ANCHORS`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TechalibVwapReset {
+    /// The kind of session reset.
+    pub kind: TechalibVwapResetKind,
+    /// The new session flags, one per bar.
+    pub mask: *const bool,
+    /// The indices of the first bar of each session.
+    pub anchors: *const usize,
+    /// The number of indices in `anchors`.
+    pub anchors_len: usize,
+}
+
+/// Kind of threshold of the ZigZag
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechalibZigzagThresholdKind {
+    /// A percentage of the extreme price.
+    Percent,
+    /// A multiple of the Average True Range over `atr_period`.
+    Atr,
+}
+
+/// Threshold of the ZigZag
+/// ---
+/// Only the parameters of `kind` are read.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TechalibZigzagThreshold {
+    /// The kind of threshold.
+    pub kind: TechalibZigzagThresholdKind,
+    /// The percentage of the extreme price.
+    pub percent: TechalibFloat,
+    /// The period of the ATR.
+    pub atr_period: usize,
+    /// The multiplier of the ATR.
+    pub atr_multiplier: TechalibFloat,
+}
+
+/// Converts an optional parameter, `NaN` meaning `None`
+#[inline(always)]
+pub(crate) fn optional(value: TechalibFloat) -> Option<TechalibFloat> {
+    if value.is_nan() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// The default ALMA offset, converted from the `Float` of `techalib`
+#[inline(always)]
+fn alma_offset() -> TechalibFloat {
+    TechalibFloat::from_f64(DEFAULT_OFFSET.to_f64())
+}
+
+/// The default ALMA sigma, converted from the `Float` of `techalib`
+#[inline(always)]
+fn alma_sigma() -> TechalibFloat {
+    TechalibFloat::from_f64(DEFAULT_SIGMA.to_f64())
+}
+
+/// Borrows the `len` values behind `ptr`
+///
+/// A null pointer is only accepted when `len` is 0.
+#[inline(always)]
+pub(crate) unsafe fn input_slice<'a, T>(
+    ptr: *const T,
+    len: usize,
+    name: &'static str,
+) -> Result<&'a [T], FfiError> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+    Ok(std::slice::from_raw_parts(ptr, len))
+}
+
+/// Mutably borrows the `len` values behind `ptr`
+///
+/// A null pointer is only accepted when `len` is 0.
+#[inline(always)]
+pub(crate) unsafe fn output_slice<'a, T>(
+    ptr: *mut T,
+    len: usize,
+    name: &'static str,
+) -> Result<&'a mut [T], FfiError> {
+    if len == 0 {
+        return Ok(&mut []);
+    }
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+    Ok(std::slice::from_raw_parts_mut(ptr, len))
+}
+
+/// Writes `value` behind `ptr` unless it is null
+#[inline(always)]
+pub(crate) unsafe fn write_output<T>(ptr: *mut T, value: T) {
+    if !ptr.is_null() {
+        *ptr = value;
+    }
+}
+
+/// Borrows the struct behind `ptr`
+#[inline(always)]
+pub(crate) unsafe fn input_ref<'a, T>(
+    ptr: *const T,
+    name: &'static str,
+) -> Result<&'a T, FfiError> {
+    ptr.as_ref().ok_or(FfiError::NullPointer(name))
+}
+
+impl TechalibOhlcv {
+    /// Borrows the columns, a null column being an empty one
+    pub(crate) unsafe fn view(&self) -> Ohlcv<'_, TechalibFloat> {
+        let column = |ptr: *const TechalibFloat| {
+            if ptr.is_null() {
+                &[][..]
+            } else {
+                std::slice::from_raw_parts(ptr, self.len)
+            }
+        };
+        Ohlcv {
+            open: column(self.open),
+            high: column(self.high),
+            low: column(self.low),
+            close: column(self.close),
+            volume: column(self.volume),
+        }
+    }
+}
+
+impl TechalibBbandsMa {
+    pub(crate) fn to_core(self, alpha: TechalibFloat) -> BBandsMA<TechalibFloat> {
+        match self {
+            TechalibBbandsMa::Sma => BBandsMA::SMA,
+            TechalibBbandsMa::Ema => BBandsMA::EMA(optional(alpha)),
+            TechalibBbandsMa::Hma => BBandsMA::HMA,
+            TechalibBbandsMa::Zlema => BBandsMA::ZLEMA(optional(alpha)),
+            TechalibBbandsMa::Alma => BBandsMA::ALMA(alma_offset(), alma_sigma()),
+            TechalibBbandsMa::Smma => BBandsMA::SMMA,
+            TechalibBbandsMa::Vidya => BBandsMA::VIDYA(DEFAULT_CMO_PERIOD),
+        }
+    }
+}
+
+pub(crate) fn deviation_multipliers(
+    up: TechalibFloat,
+    down: TechalibFloat,
+) -> DeviationMulipliers<TechalibFloat> {
+    DeviationMulipliers { up, down }
+}
+
+impl From<TechalibMaType> for MaType<TechalibFloat> {
+    fn from(ma_type: TechalibMaType) -> Self {
+        let alpha = optional(ma_type.alpha);
+        match ma_type.kind {
+            TechalibMaKind::Sma => MaType::SMA,
+            TechalibMaKind::Ema => MaType::EMA(alpha),
+            TechalibMaKind::Wma => MaType::WMA,
+            TechalibMaKind::Dema => MaType::DEMA(alpha),
+            TechalibMaKind::Tema => MaType::TEMA(alpha),
+            TechalibMaKind::Trima => MaType::TRIMA,
+            TechalibMaKind::Kama => MaType::KAMA,
+            TechalibMaKind::T3 => MaType::T3(ma_type.volume_factor, alpha),
+            TechalibMaKind::Hma => MaType::HMA,
+            TechalibMaKind::Zlema => MaType::ZLEMA(alpha),
+            TechalibMaKind::Alma => MaType::ALMA(ma_type.offset, ma_type.sigma),
+            TechalibMaKind::Smma => MaType::SMMA,
+            TechalibMaKind::Vidya => MaType::VIDYA(ma_type.cmo_period),
+        }
+    }
+}
+
+impl From<TechalibMavpMa> for MavpMA<TechalibFloat> {
+    fn from(ma_type: TechalibMavpMa) -> Self {
+        match ma_type {
+            TechalibMavpMa::Sma => MavpMA::SMA,
+            TechalibMavpMa::Ema => MavpMA::EMA,
+            TechalibMavpMa::Wma => MavpMA::WMA,
+            TechalibMavpMa::Hma => MavpMA::HMA,
+            TechalibMavpMa::Zlema => MavpMA::ZLEMA,
+            TechalibMavpMa::Alma => MavpMA::ALMA(alma_offset(), alma_sigma()),
+            TechalibMavpMa::Smma => MavpMA::SMMA,
+            TechalibMavpMa::Vidya => MavpMA::VIDYA(DEFAULT_CMO_PERIOD),
+        }
+    }
+}
+
+impl From<TechalibIchimokuPeriods> for IchimokuPeriods {
+    fn from(periods: TechalibIchimokuPeriods) -> Self {
+        IchimokuPeriods {
+            tenkan: periods.tenkan,
+            kijun: periods.kijun,
+            senkou_b: periods.senkou_b,
+            displacement: periods.displacement,
+        }
+    }
+}
+
+impl From<TechalibPivotMethod> for PivotMethod {
+    fn from(method: TechalibPivotMethod) -> Self {
+        match method {
+            TechalibPivotMethod::Classic => PivotMethod::Classic,
+            TechalibPivotMethod::Fibonacci => PivotMethod::Fibonacci,
+            TechalibPivotMethod::Camarilla => PivotMethod::Camarilla,
+            TechalibPivotMethod::Woodie => PivotMethod::Woodie,
+            TechalibPivotMethod::Demark => PivotMethod::Demark,
+        }
+    }
+}
+
+impl From<TechalibVolatilityEstimator> for VolatilityEstimator {
+    fn from(estimator: TechalibVolatilityEstimator) -> Self {
+        match estimator {
+            TechalibVolatilityEstimator::CloseToClose => VolatilityEstimator::CloseToClose,
+            TechalibVolatilityEstimator::Parkinson => VolatilityEstimator::Parkinson,
+            TechalibVolatilityEstimator::GarmanKlass => VolatilityEstimator::GarmanKlass,
+            TechalibVolatilityEstimator::RogersSatchell => VolatilityEstimator::RogersSatchell,
+            TechalibVolatilityEstimator::YangZhang => VolatilityEstimator::YangZhang,
+        }
+    }
+}
+
+impl TechalibVwapReset {
+    /// Borrows the reset flags or anchors, the mask holding `len` values
+    pub(crate) unsafe fn to_core<'a>(self, len: usize) -> Result<VwapReset<'a>, FfiError> {
+        Ok(match self.kind {
+            TechalibVwapResetKind::Never => VwapReset::Never,
+            TechalibVwapResetKind::Mask => {
+                VwapReset::Mask(input_slice(self.mask, len, "reset.mask")?)
+            }
+            TechalibVwapResetKind::Anchors => VwapReset::Anchors(input_slice(
+                self.anchors,
+                self.anchors_len,
+                "reset.anchors",
+            )?),
+        })
+    }
+}
+
+impl From<TechalibZigzagThreshold> for ZigzagThreshold<TechalibFloat> {
+    fn from(threshold: TechalibZigzagThreshold) -> Self {
+        match threshold.kind {
+            TechalibZigzagThresholdKind::Percent => ZigzagThreshold::Percent(threshold.percent),
+            TechalibZigzagThresholdKind::Atr => ZigzagThreshold::Atr {
+                period: threshold.atr_period,
+                multiplier: threshold.atr_multiplier,
+            },
+        }
+    }
+}
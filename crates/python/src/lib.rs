@@ -1,16 +1,45 @@
 use pyo3::prelude::*;
 
+mod py_alma;
+#[cfg(feature = "arrow")]
+mod py_arrow;
+mod py_atr;
 mod py_bbands;
+mod py_chandelier_exit;
+mod py_cmf;
 mod py_dema;
+mod py_donchian;
+mod py_elder_ray;
 mod py_ema;
+mod py_envelope;
+mod py_eom;
+mod py_force_index;
+mod py_heikin_ashi;
+mod py_hma;
+mod py_ichimoku;
 mod py_kama;
+mod py_keltner;
+mod py_ma;
 mod py_macd;
+mod py_mavp;
+mod py_pivots;
+mod py_price_transform;
 mod py_rsi;
 mod py_sma;
+mod py_smma;
+mod py_stats;
+mod py_supertrend;
+mod py_sweep;
+mod py_swing;
 mod py_t3;
 mod py_tema;
 mod py_trima;
+mod py_vidya;
+mod py_volatility;
+mod py_vwap;
 mod py_wma;
+mod py_zigzag;
+mod py_zlema;
 
 #[pymodule]
 fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -31,6 +60,7 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<py_macd::PyMacdState>()?;
 
     m.add_function(wrap_pyfunction!(py_bbands::bbands, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bbands::bbands_ext, m)?)?;
     m.add_function(wrap_pyfunction!(py_bbands::bbands_next, m)?)?;
     m.add_class::<py_bbands::PyBBandsState>()?;
     m.add_class::<py_bbands::PyBBandsMA>()?;
@@ -58,5 +88,163 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_kama::kama, m)?)?;
     m.add_function(wrap_pyfunction!(py_kama::kama_next, m)?)?;
     m.add_class::<py_kama::PyKamaState>()?;
+
+    m.add_function(wrap_pyfunction!(py_mavp::mavp, m)?)?;
+    m.add_function(wrap_pyfunction!(py_mavp::mavp_next, m)?)?;
+    m.add_class::<py_mavp::PyMavpState>()?;
+    m.add_class::<py_mavp::PyMavpMA>()?;
+
+    m.add_function(wrap_pyfunction!(py_price_transform::avgprice, m)?)?;
+    m.add_function(wrap_pyfunction!(py_price_transform::medprice, m)?)?;
+    m.add_function(wrap_pyfunction!(py_price_transform::typprice, m)?)?;
+    m.add_function(wrap_pyfunction!(py_price_transform::wclprice, m)?)?;
+
+    m.add_function(wrap_pyfunction!(py_ma::ma, m)?)?;
+    m.add_function(wrap_pyfunction!(py_ma::ma_next, m)?)?;
+    m.add_class::<py_ma::PyMaState>()?;
+    m.add_class::<py_ma::PyMaType>()?;
+
+    m.add_function(wrap_pyfunction!(py_atr::atr, m)?)?;
+    m.add_function(wrap_pyfunction!(py_atr::atr_next, m)?)?;
+    m.add_class::<py_atr::PyAtrState>()?;
+
+    m.add_function(wrap_pyfunction!(py_keltner::keltner, m)?)?;
+    m.add_function(wrap_pyfunction!(py_keltner::keltner_next, m)?)?;
+    m.add_class::<py_keltner::PyKeltnerState>()?;
+
+    m.add_function(wrap_pyfunction!(py_donchian::donchian, m)?)?;
+    m.add_function(wrap_pyfunction!(py_donchian::donchian_next, m)?)?;
+    m.add_class::<py_donchian::PyDonchianState>()?;
+
+    m.add_function(wrap_pyfunction!(py_envelope::envelope, m)?)?;
+    m.add_function(wrap_pyfunction!(py_envelope::envelope_next, m)?)?;
+    m.add_class::<py_envelope::PyEnvelopeState>()?;
+
+    m.add_function(wrap_pyfunction!(py_ichimoku::ichimoku, m)?)?;
+    m.add_function(wrap_pyfunction!(py_ichimoku::ichimoku_next, m)?)?;
+    m.add_class::<py_ichimoku::PyIchimokuState>()?;
+
+    m.add_function(wrap_pyfunction!(py_supertrend::supertrend, m)?)?;
+    m.add_function(wrap_pyfunction!(py_supertrend::supertrend_next, m)?)?;
+    m.add_class::<py_supertrend::PySupertrendState>()?;
+
+    m.add_function(wrap_pyfunction!(py_chandelier_exit::chandelier_exit, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        py_chandelier_exit::chandelier_exit_next,
+        m
+    )?)?;
+    m.add_class::<py_chandelier_exit::PyChandelierExitState>()?;
+
+    m.add_function(wrap_pyfunction!(py_vwap::vwap, m)?)?;
+    m.add_function(wrap_pyfunction!(py_vwap::vwap_next, m)?)?;
+    m.add_class::<py_vwap::PyVwapState>()?;
+
+    m.add_function(wrap_pyfunction!(py_heikin_ashi::heikin_ashi, m)?)?;
+    m.add_function(wrap_pyfunction!(py_heikin_ashi::heikin_ashi_next, m)?)?;
+    m.add_class::<py_heikin_ashi::PyHeikinAshiState>()?;
+
+    m.add_function(wrap_pyfunction!(py_pivots::pivots, m)?)?;
+    m.add_function(wrap_pyfunction!(py_pivots::pivots_next, m)?)?;
+    m.add_class::<py_pivots::PyPivotsState>()?;
+    m.add_class::<py_pivots::PyPivotMethod>()?;
+
+    m.add_function(wrap_pyfunction!(py_zigzag::zigzag, m)?)?;
+    m.add_function(wrap_pyfunction!(py_zigzag::zigzag_next, m)?)?;
+    m.add_class::<py_zigzag::PyZigzagState>()?;
+
+    m.add_function(wrap_pyfunction!(py_swing::swing, m)?)?;
+    m.add_function(wrap_pyfunction!(py_swing::swing_next, m)?)?;
+    m.add_class::<py_swing::PySwingState>()?;
+
+    m.add_function(wrap_pyfunction!(py_hma::hma, m)?)?;
+    m.add_function(wrap_pyfunction!(py_hma::hma_next, m)?)?;
+    m.add_class::<py_hma::PyHmaState>()?;
+
+    m.add_function(wrap_pyfunction!(py_zlema::zlema, m)?)?;
+    m.add_function(wrap_pyfunction!(py_zlema::zlema_next, m)?)?;
+    m.add_class::<py_zlema::PyZlemaState>()?;
+
+    m.add_function(wrap_pyfunction!(py_alma::alma, m)?)?;
+    m.add_function(wrap_pyfunction!(py_alma::alma_next, m)?)?;
+    m.add_class::<py_alma::PyAlmaState>()?;
+
+    m.add_function(wrap_pyfunction!(py_smma::smma, m)?)?;
+    m.add_function(wrap_pyfunction!(py_smma::smma_next, m)?)?;
+    m.add_class::<py_smma::PySmmaState>()?;
+
+    m.add_function(wrap_pyfunction!(py_vidya::vidya, m)?)?;
+    m.add_function(wrap_pyfunction!(py_vidya::vidya_next, m)?)?;
+    m.add_class::<py_vidya::PyVidyaState>()?;
+
+    m.add_function(wrap_pyfunction!(py_elder_ray::elder_ray, m)?)?;
+    m.add_function(wrap_pyfunction!(py_elder_ray::elder_ray_next, m)?)?;
+    m.add_class::<py_elder_ray::PyElderRayState>()?;
+
+    m.add_function(wrap_pyfunction!(py_force_index::force_index, m)?)?;
+    m.add_function(wrap_pyfunction!(py_force_index::force_index_next, m)?)?;
+    m.add_class::<py_force_index::PyForceIndexState>()?;
+
+    m.add_function(wrap_pyfunction!(py_eom::eom, m)?)?;
+    m.add_function(wrap_pyfunction!(py_eom::eom_next, m)?)?;
+    m.add_class::<py_eom::PyEomState>()?;
+
+    m.add_function(wrap_pyfunction!(py_cmf::cmf, m)?)?;
+    m.add_function(wrap_pyfunction!(py_cmf::cmf_next, m)?)?;
+    m.add_class::<py_cmf::PyCmfState>()?;
+
+    m.add_function(wrap_pyfunction!(py_stats::zscore, m)?)?;
+    m.add_function(wrap_pyfunction!(py_stats::zscore_next, m)?)?;
+    m.add_class::<py_stats::PyZscoreState>()?;
+
+    m.add_function(wrap_pyfunction!(py_stats::percent_rank, m)?)?;
+    m.add_function(wrap_pyfunction!(py_stats::percent_rank_next, m)?)?;
+    m.add_class::<py_stats::PyPercentRankState>()?;
+
+    m.add_function(wrap_pyfunction!(py_stats::skew, m)?)?;
+    m.add_function(wrap_pyfunction!(py_stats::skew_next, m)?)?;
+    m.add_class::<py_stats::PySkewState>()?;
+
+    m.add_function(wrap_pyfunction!(py_stats::kurtosis, m)?)?;
+    m.add_function(wrap_pyfunction!(py_stats::kurtosis_next, m)?)?;
+    m.add_class::<py_stats::PyKurtosisState>()?;
+
+    m.add_function(wrap_pyfunction!(py_stats::median, m)?)?;
+    m.add_function(wrap_pyfunction!(py_stats::median_next, m)?)?;
+    m.add_class::<py_stats::PyMedianState>()?;
+
+    m.add_function(wrap_pyfunction!(py_volatility::volatility, m)?)?;
+    m.add_function(wrap_pyfunction!(py_volatility::volatility_next, m)?)?;
+    m.add_class::<py_volatility::PyVolatilityState>()?;
+    m.add_class::<py_volatility::PyVolatilityEstimator>()?;
+
+    m.add_function(wrap_pyfunction!(py_sweep::sma_sweep, m)?)?;
+    m.add_function(wrap_pyfunction!(py_sweep::ema_sweep, m)?)?;
+    m.add_function(wrap_pyfunction!(py_sweep::rsi_sweep, m)?)?;
+    m.add_function(wrap_pyfunction!(py_sweep::bbands_sweep, m)?)?;
+    #[cfg(feature = "arrow")]
+    {
+        m.add_class::<py_arrow::PyArrowArray>()?;
+        m.add_function(wrap_pyfunction!(py_arrow::sma_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::ema_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::wma_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::dema_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::tema_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::trima_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::t3_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::kama_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::hma_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::zlema_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::alma_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::smma_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::vidya_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::rsi_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::zscore_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::percent_rank_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::skew_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::kurtosis_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::median_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::bbands_arrow, m)?)?;
+        m.add_function(wrap_pyfunction!(py_arrow::macd_arrow, m)?)?;
+    }
     Ok(())
 }
@@ -0,0 +1,317 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use std::sync::Arc;
+
+use arrow_array::ffi::{from_ffi, to_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::types::Float32Type;
+use arrow_array::{Array, ArrayRef, ArrowPrimitiveType, Float32Array, PrimitiveArray};
+use pyo3::exceptions::PyValueError;
+use pyo3::types::{PyAnyMethods, PyCapsule, PyCapsuleMethods};
+use pyo3::{pyclass, pyfunction, pymethods, Bound, PyAny, PyResult, Python};
+use techalib::arrow::{self, ArrowFloat, FloatArray};
+use techalib::indicators::alma::{DEFAULT_OFFSET, DEFAULT_SIGMA};
+use techalib::indicators::bbands::DeviationMulipliers;
+use techalib::indicators::vidya::DEFAULT_CMO_PERIOD;
+use techalib::types::Float;
+
+use crate::py_alma::PyAlmaState;
+use crate::py_bbands::{PyBBandsMA, PyBBandsState};
+use crate::py_dema::PyDemaState;
+use crate::py_ema::PyEmaState;
+use crate::py_hma::PyHmaState;
+use crate::py_kama::PyKamaState;
+use crate::py_macd::PyMacdState;
+use crate::py_rsi::PyRsiState;
+use crate::py_sma::PySmaState;
+use crate::py_smma::PySmmaState;
+use crate::py_stats::{
+    PyKurtosisState, PyMedianState, PyPercentRankState, PySkewState, PyZscoreState,
+};
+use crate::py_t3::PyT3State;
+use crate::py_tema::PyTemaState;
+use crate::py_trima::PyTrimaState;
+use crate::py_vidya::PyVidyaState;
+use crate::py_wma::PyWmaState;
+use crate::py_zlema::PyZlemaState;
+
+/// An Arrow array exported through the Arrow PyCapsule interface
+/// ---
+/// It can be imported without copy by any library implementing the interface,
+/// e.g. `pyarrow.array(obj)` or `polars.Series(obj)`.
+#[pyclass(name = "ArrowArray", module = "techalib._core", frozen)]
+pub struct PyArrowArray {
+    array: ArrayRef,
+}
+
+#[pymethods]
+impl PyArrowArray {
+    #[pyo3(signature = (requested_schema = None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let _ = requested_schema;
+        let (array, schema) =
+            to_ffi(&self.array.to_data()).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok((
+            PyCapsule::new(py, schema, Some(c"arrow_schema".into()))?,
+            PyCapsule::new(py, array, Some(c"arrow_array".into()))?,
+        ))
+    }
+
+    fn __len__(&self) -> usize {
+        self.array.len()
+    }
+
+    #[getter]
+    fn null_count(&self) -> usize {
+        self.array.null_count()
+    }
+}
+
+impl<A: ArrowPrimitiveType> From<PrimitiveArray<A>> for PyArrowArray {
+    fn from(array: PrimitiveArray<A>) -> Self {
+        PyArrowArray {
+            array: Arc::new(array),
+        }
+    }
+}
+
+/// An array imported by [`import_array`]
+/// ---
+/// - `Float`: an array of [`Float`], whose results come with the indicator state.
+/// - `Float32`: a `Float32Array` when [`Float`] is `f64`. It is processed in `f32`
+///   to avoid upcasting the column, and no state is returned since the Python
+///   states hold [`Float`] values.
+enum ImportedArray {
+    Float(FloatArray),
+    Float32(Float32Array),
+}
+
+/// Converts the parameters of the `*_arrow` functions for a `Float32Array`
+trait ToF32 {
+    type Output;
+    fn to_f32(self) -> Self::Output;
+}
+
+impl ToF32 for usize {
+    type Output = usize;
+    fn to_f32(self) -> usize {
+        self
+    }
+}
+
+impl ToF32 for Float {
+    type Output = f32;
+    #[allow(clippy::unnecessary_cast)]
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+}
+
+impl ToF32 for Option<Float> {
+    type Output = Option<f32>;
+    fn to_f32(self) -> Option<f32> {
+        self.map(ToF32::to_f32)
+    }
+}
+
+/// Imports an object implementing `__arrow_c_array__` as an [`ImportedArray`]
+/// ---
+/// The buffers are shared with the exporter, nothing is copied.
+fn import_array(data: &Bound<'_, PyAny>) -> PyResult<ImportedArray> {
+    let (schema, array): (Bound<PyCapsule>, Bound<PyCapsule>) =
+        data.call_method0("__arrow_c_array__")?.extract()?;
+    if schema.name()? != Some(c"arrow_schema") || array.name()? != Some(c"arrow_array") {
+        return Err(PyValueError::new_err(
+            "__arrow_c_array__ must return an arrow_schema and an arrow_array capsule",
+        ));
+    }
+
+    // SAFETY: the capsules hold an `ArrowSchema` and an `ArrowArray` of the C Data
+    // Interface. The array is moved out of its capsule, which marks it released.
+    let data = unsafe {
+        let schema = &*(schema.pointer() as *const FFI_ArrowSchema);
+        let array = FFI_ArrowArray::from_raw(array.pointer() as *mut FFI_ArrowArray);
+        from_ffi(array, schema)
+    }
+    .and_then(|data| data.validate().map(|_| data))
+    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let expected = <Float as ArrowFloat>::ArrowType::DATA_TYPE;
+    if data.data_type() == &expected {
+        Ok(ImportedArray::Float(FloatArray::from(data)))
+    } else if data.data_type() == &Float32Type::DATA_TYPE {
+        Ok(ImportedArray::Float32(Float32Array::from(data)))
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Expected an Arrow array of {expected} or {}, got {}",
+            Float32Type::DATA_TYPE,
+            data.data_type()
+        )))
+    }
+}
+
+macro_rules! py_arrow {
+    (
+        $name:ident,
+        $func:ident,
+        $py_state:ident,
+        ($($signature:tt)*)
+        $(; $($param:ident: $ty:ty),+)?
+    ) => {
+        #[pyfunction(signature = (data, $($signature)*))]
+        pub(crate) fn $name(
+            data: &Bound<'_, PyAny>,
+            $($($param: $ty),+)?
+        ) -> PyResult<(PyArrowArray, Option<$py_state>)> {
+            match import_array(data)? {
+                ImportedArray::Float(array) => {
+                    let result = arrow::$func(&array, $($($param),+)?)
+                        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+                    Ok((result.values.into(), Some(result.state.into())))
+                }
+                ImportedArray::Float32(array) => {
+                    let result = arrow::$func(&array, $($($param.to_f32()),+)?)
+                        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+                    Ok((result.values.into(), None))
+                }
+            }
+        }
+    };
+}
+
+py_arrow!(sma_arrow, sma, PySmaState, (period = 14); period: usize);
+py_arrow!(ema_arrow, ema, PyEmaState, (period = 14, alpha = None); period: usize, alpha: Option<Float>);
+py_arrow!(wma_arrow, wma, PyWmaState, (period = 14); period: usize);
+py_arrow!(dema_arrow, dema, PyDemaState, (period = 14, alpha = None); period: usize, alpha: Option<Float>);
+py_arrow!(tema_arrow, tema, PyTemaState, (period = 14, alpha = None); period: usize, alpha: Option<Float>);
+py_arrow!(trima_arrow, trima, PyTrimaState, (period = 14); period: usize);
+py_arrow!(t3_arrow, t3, PyT3State, (period = 5, vfactor = 0.7, alpha = None); period: usize, vfactor: Float, alpha: Option<Float>);
+py_arrow!(kama_arrow, kama, PyKamaState, (period = 30); period: usize);
+py_arrow!(hma_arrow, hma, PyHmaState, (period = 16); period: usize);
+py_arrow!(zlema_arrow, zlema, PyZlemaState, (period = 14, alpha = None); period: usize, alpha: Option<Float>);
+py_arrow!(alma_arrow, alma, PyAlmaState, (period = 9, offset = DEFAULT_OFFSET, sigma = DEFAULT_SIGMA); period: usize, offset: Float, sigma: Float);
+py_arrow!(smma_arrow, smma, PySmmaState, (period = 14); period: usize);
+py_arrow!(vidya_arrow, vidya, PyVidyaState, (period = 14, cmo_period = DEFAULT_CMO_PERIOD); period: usize, cmo_period: usize);
+py_arrow!(rsi_arrow, rsi, PyRsiState, (period = 14); period: usize);
+py_arrow!(zscore_arrow, zscore, PyZscoreState, (period = 20); period: usize);
+py_arrow!(percent_rank_arrow, percent_rank, PyPercentRankState, (period = 20); period: usize);
+py_arrow!(skew_arrow, skew, PySkewState, (period = 20); period: usize);
+py_arrow!(kurtosis_arrow, kurtosis, PyKurtosisState, (period = 20); period: usize);
+py_arrow!(median_arrow, median, PyMedianState, (period = 20); period: usize);
+
+#[pyfunction(signature = (data, period = 20, std_up = 2.0, std_down = 2.0, ma_type = PyBBandsMA::SMA))]
+pub(crate) fn bbands_arrow(
+    data: &Bound<'_, PyAny>,
+    period: usize,
+    std_up: Float,
+    std_down: Float,
+    ma_type: PyBBandsMA,
+) -> PyResult<(
+    PyArrowArray,
+    PyArrowArray,
+    PyArrowArray,
+    Option<PyBBandsState>,
+)> {
+    match import_array(data)? {
+        ImportedArray::Float(array) => {
+            let std_dev_mul = DeviationMulipliers {
+                up: std_up,
+                down: std_down,
+            };
+            let result = arrow::bbands(&array, period, std_dev_mul, ma_type.into())
+                .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+            Ok((
+                result.upper.into(),
+                result.middle.into(),
+                result.lower.into(),
+                Some(result.state.into()),
+            ))
+        }
+        ImportedArray::Float32(array) => {
+            let std_dev_mul = DeviationMulipliers {
+                up: std_up.to_f32(),
+                down: std_down.to_f32(),
+            };
+            let result = arrow::bbands(&array, period, std_dev_mul, ma_type.into())
+                .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+            Ok((
+                result.upper.into(),
+                result.middle.into(),
+                result.lower.into(),
+                None,
+            ))
+        }
+    }
+}
+
+#[pyfunction(signature = (data, fast_period = 12, slow_period = 26, signal_period = 9))]
+pub(crate) fn macd_arrow(
+    data: &Bound<'_, PyAny>,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+) -> PyResult<(
+    PyArrowArray,
+    PyArrowArray,
+    PyArrowArray,
+    Option<PyMacdState>,
+)> {
+    match import_array(data)? {
+        ImportedArray::Float(array) => {
+            let result = arrow::macd(&array, fast_period, slow_period, signal_period)
+                .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+            Ok((
+                result.macd.into(),
+                result.signal.into(),
+                result.histogram.into(),
+                Some(result.state.into()),
+            ))
+        }
+        ImportedArray::Float32(array) => {
+            let result = arrow::macd(&array, fast_period, slow_period, signal_period)
+                .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+            Ok((
+                result.macd.into(),
+                result.signal.into(),
+                result.histogram.into(),
+                None,
+            ))
+        }
+    }
+}
@@ -35,11 +35,15 @@
 
 use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
 use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::alma::{DEFAULT_OFFSET, DEFAULT_SIGMA};
 use techalib::indicators::bbands::{
-    bbands_into, BBandsMA, BBandsState, DeviationMulipliers, MovingAverageState,
+    bbands_ext_into, bbands_into, BBandsMA, BBandsState, DeviationMulipliers, MovingAverageState,
 };
+use techalib::indicators::vidya::DEFAULT_CMO_PERIOD;
 use techalib::traits::State;
-use techalib::types::Float;
+use techalib::types::{Float, TechalibFloat};
+
+use crate::py_ma::PyMaState;
 
 #[pyclass(name = "BBandsState")]
 #[derive(Debug, Clone)]
@@ -51,12 +55,18 @@ pub struct PyBBandsState {
     #[pyo3(get)]
     pub lower: Float,
     #[pyo3(get)]
+    pub percent_b: Float,
+    #[pyo3(get)]
+    pub bandwidth: Float,
+    #[pyo3(get)]
     pub mean_sma: Float,
     #[pyo3(get)]
     pub mean_sq: Float,
     #[pyo3(get)]
     pub window: Vec<Float>,
     #[pyo3(get)]
+    pub ma: Option<PyMaState>,
+    #[pyo3(get)]
     pub period: usize,
     #[pyo3(get)]
     pub std_up: Float,
@@ -68,14 +78,22 @@ pub struct PyBBandsState {
 
 #[pyclass(name = "BBandsMA")]
 #[derive(Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum PyBBandsMA {
     SMA,
     EMA,
+    HMA,
+    ZLEMA,
+    ALMA,
+    SMMA,
+    VIDYA,
 }
 
 #[pymethods]
 impl PyBBandsState {
     #[new]
+    #[pyo3(signature = (upper, middle, lower, mean_sma, mean_sq, window, period, std_up, std_down, ma_type, ma = None, percent_b = Float::NAN, bandwidth = Float::NAN))]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         upper: Float,
         middle: Float,
@@ -87,14 +105,20 @@ impl PyBBandsState {
         std_up: Float,
         std_down: Float,
         ma_type: PyBBandsMA,
+        ma: Option<PyMaState>,
+        percent_b: Float,
+        bandwidth: Float,
     ) -> Self {
         PyBBandsState {
             upper,
             middle,
             lower,
+            percent_b,
+            bandwidth,
             mean_sma,
             mean_sq,
             window,
+            ma,
             period,
             std_up,
             std_down,
@@ -108,8 +132,8 @@ impl PyBBandsState {
     #[getter]
     pub fn __repr__(&self) -> String {
         format!(
-            "BBandsState(upper: {}, middle: {}, lower: {}, mean_sq: {}, window: {:?}, period: {}, std_up: {}, std_down: {}, ma_type: {:?})",
-            self.upper, self.middle, self.lower, self.mean_sq, self.window, self.period, self.std_up, self.std_down, self.ma_type
+            "BBandsState(upper: {}, middle: {}, lower: {}, percent_b: {}, bandwidth: {}, mean_sq: {}, window: {:?}, ma: {:?}, period: {}, std_up: {}, std_down: {}, ma_type: {:?})",
+            self.upper, self.middle, self.lower, self.percent_b, self.bandwidth, self.mean_sq, self.window, self.ma.as_ref().map(|ma| &ma.inner), self.period, self.std_up, self.std_down, self.ma_type
         )
     }
 }
@@ -119,9 +143,12 @@ impl From<BBandsState> for PyBBandsState {
             upper: state.upper,
             middle: state.middle,
             lower: state.lower,
+            percent_b: state.percent_b,
+            bandwidth: state.bandwidth,
             mean_sma: state.moving_averages.sma,
             mean_sq: state.moving_averages.ma_square,
             window: state.last_window.into(),
+            ma: state.ma.map(PyMaState::from),
             period: state.period,
             std_up: state.std_dev_mult.up,
             std_down: state.std_dev_mult.down,
@@ -136,11 +163,14 @@ impl From<PyBBandsState> for BBandsState {
             upper: py_state.upper,
             middle: py_state.middle,
             lower: py_state.lower,
+            percent_b: py_state.percent_b,
+            bandwidth: py_state.bandwidth,
             moving_averages: MovingAverageState {
                 sma: py_state.mean_sma,
                 ma_square: py_state.mean_sq,
             },
             last_window: py_state.window.into(),
+            ma: py_state.ma.map(PyMaState::into),
             period: py_state.period,
             std_dev_mult: DeviationMulipliers {
                 up: py_state.std_up,
@@ -151,11 +181,19 @@ impl From<PyBBandsState> for BBandsState {
     }
 }
 
-impl From<PyBBandsMA> for BBandsMA {
+impl<T: TechalibFloat> From<PyBBandsMA> for BBandsMA<T> {
     fn from(py_ma: PyBBandsMA) -> Self {
         match py_ma {
             PyBBandsMA::SMA => BBandsMA::SMA,
             PyBBandsMA::EMA => BBandsMA::EMA(None),
+            PyBBandsMA::HMA => BBandsMA::HMA,
+            PyBBandsMA::ZLEMA => BBandsMA::ZLEMA(None),
+            PyBBandsMA::ALMA => BBandsMA::ALMA(
+                T::from_f64(DEFAULT_OFFSET.to_f64()),
+                T::from_f64(DEFAULT_SIGMA.to_f64()),
+            ),
+            PyBBandsMA::SMMA => BBandsMA::SMMA,
+            PyBBandsMA::VIDYA => BBandsMA::VIDYA(DEFAULT_CMO_PERIOD),
         }
     }
 }
@@ -165,11 +203,17 @@ impl From<BBandsMA> for PyBBandsMA {
         match ma {
             BBandsMA::SMA => PyBBandsMA::SMA,
             BBandsMA::EMA(_) => PyBBandsMA::EMA,
+            BBandsMA::HMA => PyBBandsMA::HMA,
+            BBandsMA::ZLEMA(_) => PyBBandsMA::ZLEMA,
+            BBandsMA::ALMA(_, _) => PyBBandsMA::ALMA,
+            BBandsMA::SMMA => PyBBandsMA::SMMA,
+            BBandsMA::VIDYA(_) => PyBBandsMA::VIDYA,
         }
     }
 }
 
 #[pyfunction(signature = (data, period = 20, std_up = 2.0, std_down = 2.0, ma_type = PyBBandsMA::SMA, release_gil = false))]
+#[allow(clippy::type_complexity)]
 pub(crate) fn bbands(
     py: Python,
     data: PyReadonlyArray1<Float>,
@@ -248,6 +292,104 @@ pub(crate) fn bbands(
     }
 }
 
+#[pyfunction(signature = (data, period = 20, std_up = 2.0, std_down = 2.0, ma_type = PyBBandsMA::SMA, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn bbands_ext(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    std_up: Float,
+    std_down: Float,
+    ma_type: PyBBandsMA,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyBBandsState,
+)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        let mut output_percent_b = vec![0.0; len];
+        let mut output_bandwidth = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                bbands_ext_into(
+                    input_slice,
+                    period,
+                    DeviationMulipliers {
+                        up: std_up,
+                        down: std_down,
+                    },
+                    ma_type.into(),
+                    output_upper.as_mut_slice(),
+                    output_middle.as_mut_slice(),
+                    output_lower.as_mut_slice(),
+                    output_percent_b.as_mut_slice(),
+                    output_bandwidth.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_upper.into_pyarray(py).into(),
+            output_middle.into_pyarray(py).into(),
+            output_lower.into_pyarray(py).into(),
+            output_percent_b.into_pyarray(py).into(),
+            output_bandwidth.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_upper = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_upper_slice = unsafe { py_out_upper.as_slice_mut()? };
+
+        let py_out_middle = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_middle_slice = unsafe { py_out_middle.as_slice_mut()? };
+
+        let py_out_lower = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_lower_slice = unsafe { py_out_lower.as_slice_mut()? };
+
+        let py_out_percent_b = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_percent_b_slice = unsafe { py_out_percent_b.as_slice_mut()? };
+
+        let py_out_bandwidth = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_bandwidth_slice = unsafe { py_out_bandwidth.as_slice_mut()? };
+
+        let state = bbands_ext_into(
+            input_slice,
+            period,
+            DeviationMulipliers {
+                up: std_up,
+                down: std_down,
+            },
+            ma_type.into(),
+            py_out_upper_slice,
+            py_out_middle_slice,
+            py_out_lower_slice,
+            py_out_percent_b_slice,
+            py_out_bandwidth_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_upper.into(),
+            py_out_middle.into(),
+            py_out_lower.into(),
+            py_out_percent_b.into(),
+            py_out_bandwidth.into(),
+            state.into(),
+        ))
+    }
+}
+
 #[pyfunction(signature = (new_value, bbands_state))]
 pub(crate) fn bbands_next(
     new_value: Float,
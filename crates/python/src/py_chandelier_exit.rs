@@ -0,0 +1,235 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::atr::AtrState;
+use techalib::indicators::chandelier_exit::{chandelier_exit_into, ChandelierExitState};
+use techalib::indicators::max::MaxState;
+use techalib::indicators::min::MinState;
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "ChandelierExitState")]
+#[derive(Debug, Clone)]
+pub struct PyChandelierExitState {
+    #[pyo3(get)]
+    pub long_stop: Float,
+    #[pyo3(get)]
+    pub short_stop: Float,
+    #[pyo3(get)]
+    pub highest: Float,
+    #[pyo3(get)]
+    pub lowest: Float,
+    #[pyo3(get)]
+    pub high_window: Vec<Float>,
+    #[pyo3(get)]
+    pub low_window: Vec<Float>,
+    #[pyo3(get)]
+    pub atr: Float,
+    #[pyo3(get)]
+    pub prev_close: Float,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub multiplier: Float,
+}
+
+#[pymethods]
+impl PyChandelierExitState {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        long_stop: Float,
+        short_stop: Float,
+        highest: Float,
+        lowest: Float,
+        high_window: Vec<Float>,
+        low_window: Vec<Float>,
+        atr: Float,
+        prev_close: Float,
+        period: usize,
+        multiplier: Float,
+    ) -> Self {
+        PyChandelierExitState {
+            long_stop,
+            short_stop,
+            highest,
+            lowest,
+            high_window,
+            low_window,
+            atr,
+            prev_close,
+            period,
+            multiplier,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ChandelierExitState(long_stop: {}, short_stop: {}, highest: {}, lowest: {}, high_window: {:?}, low_window: {:?}, atr: {}, prev_close: {}, period: {}, multiplier: {})",
+            self.long_stop, self.short_stop, self.highest, self.lowest, self.high_window, self.low_window, self.atr, self.prev_close, self.period, self.multiplier
+        )
+    }
+}
+
+impl From<ChandelierExitState> for PyChandelierExitState {
+    fn from(state: ChandelierExitState) -> Self {
+        PyChandelierExitState {
+            long_stop: state.long_stop,
+            short_stop: state.short_stop,
+            highest: state.max_state.max,
+            lowest: state.min_state.min,
+            high_window: state.max_state.last_window.into(),
+            low_window: state.min_state.last_window.into(),
+            atr: state.atr.atr,
+            prev_close: state.atr.prev_close,
+            period: state.atr.period,
+            multiplier: state.multiplier,
+        }
+    }
+}
+
+impl From<PyChandelierExitState> for ChandelierExitState {
+    fn from(py_state: PyChandelierExitState) -> Self {
+        ChandelierExitState {
+            long_stop: py_state.long_stop,
+            short_stop: py_state.short_stop,
+            max_state: MaxState {
+                max: py_state.highest,
+                last_window: py_state.high_window.into(),
+                period: py_state.period,
+            },
+            min_state: MinState {
+                min: py_state.lowest,
+                last_window: py_state.low_window.into(),
+                period: py_state.period,
+            },
+            atr: AtrState {
+                atr: py_state.atr,
+                prev_close: py_state.prev_close,
+                period: py_state.period,
+            },
+            multiplier: py_state.multiplier,
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, close, period = 22, multiplier = 3.0, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn chandelier_exit(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    period: usize,
+    multiplier: Float,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyChandelierExitState,
+)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output_long_stop = vec![0.0; len];
+        let mut output_short_stop = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                chandelier_exit_into(
+                    &ohlcv,
+                    period,
+                    multiplier,
+                    output_long_stop.as_mut_slice(),
+                    output_short_stop.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_long_stop.into_pyarray(py).into(),
+            output_short_stop.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_long_stop = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_long_stop_slice = unsafe { py_out_long_stop.as_slice_mut()? };
+
+        let py_out_short_stop = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_short_stop_slice = unsafe { py_out_short_stop.as_slice_mut()? };
+
+        let state = chandelier_exit_into(
+            &ohlcv,
+            period,
+            multiplier,
+            py_out_long_stop_slice,
+            py_out_short_stop_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_long_stop.into(),
+            py_out_short_stop.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, new_close, chandelier_exit_state))]
+pub(crate) fn chandelier_exit_next(
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    chandelier_exit_state: PyChandelierExitState,
+) -> PyResult<PyChandelierExitState> {
+    let mut chandelier_exit_state: ChandelierExitState = chandelier_exit_state.into();
+    chandelier_exit_state
+        .update((new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(chandelier_exit_state.into())
+}
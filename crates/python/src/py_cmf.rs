@@ -0,0 +1,170 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::cmf::{cmf_into, CmfState};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "CmfState")]
+#[derive(Debug, Clone)]
+pub struct PyCmfState {
+    #[pyo3(get)]
+    pub cmf: Float,
+    #[pyo3(get)]
+    pub mfv_sum: Float,
+    #[pyo3(get)]
+    pub volume_sum: Float,
+    #[pyo3(get)]
+    pub mfv_window: Vec<Float>,
+    #[pyo3(get)]
+    pub volume_window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+}
+
+#[pymethods]
+impl PyCmfState {
+    #[new]
+    pub fn new(
+        cmf: Float,
+        mfv_sum: Float,
+        volume_sum: Float,
+        mfv_window: Vec<Float>,
+        volume_window: Vec<Float>,
+        period: usize,
+    ) -> Self {
+        PyCmfState {
+            cmf,
+            mfv_sum,
+            volume_sum,
+            mfv_window,
+            volume_window,
+            period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "CmfState(cmf: {}, mfv_sum: {}, volume_sum: {}, mfv_window: {:?}, volume_window: {:?}, period: {})",
+            self.cmf, self.mfv_sum, self.volume_sum, self.mfv_window, self.volume_window, self.period
+        )
+    }
+}
+
+impl From<CmfState> for PyCmfState {
+    fn from(state: CmfState) -> Self {
+        PyCmfState {
+            cmf: state.cmf,
+            mfv_sum: state.mfv_sum,
+            volume_sum: state.volume_sum,
+            mfv_window: state.last_mfv.into(),
+            volume_window: state.last_volume.into(),
+            period: state.period,
+        }
+    }
+}
+
+impl From<PyCmfState> for CmfState {
+    fn from(py_state: PyCmfState) -> Self {
+        CmfState {
+            cmf: py_state.cmf,
+            mfv_sum: py_state.mfv_sum,
+            volume_sum: py_state.volume_sum,
+            last_mfv: py_state.mfv_window.into(),
+            last_volume: py_state.volume_window.into(),
+            period: py_state.period,
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, close, volume, period = 20, release_gil = false))]
+pub(crate) fn cmf(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    volume: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyCmfState)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        volume: volume.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| cmf_into(&ohlcv, period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let py_out = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_slice = unsafe { py_out.as_slice_mut()? };
+
+        let state = cmf_into(&ohlcv, period, py_out_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((py_out.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, new_close, new_volume, cmf_state))]
+pub(crate) fn cmf_next(
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    new_volume: Float,
+    cmf_state: PyCmfState,
+) -> PyResult<PyCmfState> {
+    let mut cmf_state: CmfState = cmf_state.into();
+    cmf_state
+        .update((new_high, new_low, new_close, new_volume))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(cmf_state.into())
+}
@@ -0,0 +1,211 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::donchian::{donchian_into, DonchianState};
+use techalib::indicators::max::MaxState;
+use techalib::indicators::min::MinState;
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "DonchianState")]
+#[derive(Debug, Clone)]
+pub struct PyDonchianState {
+    #[pyo3(get)]
+    pub upper: Float,
+    #[pyo3(get)]
+    pub middle: Float,
+    #[pyo3(get)]
+    pub lower: Float,
+    #[pyo3(get)]
+    pub high_window: Vec<Float>,
+    #[pyo3(get)]
+    pub low_window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+}
+
+#[pymethods]
+impl PyDonchianState {
+    #[new]
+    pub fn new(
+        upper: Float,
+        middle: Float,
+        lower: Float,
+        high_window: Vec<Float>,
+        low_window: Vec<Float>,
+        period: usize,
+    ) -> Self {
+        PyDonchianState {
+            upper,
+            middle,
+            lower,
+            high_window,
+            low_window,
+            period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "DonchianState(upper: {}, middle: {}, lower: {}, high_window: {:?}, low_window: {:?}, period: {})",
+            self.upper, self.middle, self.lower, self.high_window, self.low_window, self.period
+        )
+    }
+}
+
+impl From<DonchianState> for PyDonchianState {
+    fn from(state: DonchianState) -> Self {
+        PyDonchianState {
+            upper: state.upper,
+            middle: state.middle,
+            lower: state.lower,
+            high_window: state.max_state.last_window.into(),
+            low_window: state.min_state.last_window.into(),
+            period: state.max_state.period,
+        }
+    }
+}
+
+impl From<PyDonchianState> for DonchianState {
+    fn from(py_state: PyDonchianState) -> Self {
+        DonchianState {
+            upper: py_state.upper,
+            middle: py_state.middle,
+            lower: py_state.lower,
+            max_state: MaxState {
+                max: py_state.upper,
+                last_window: py_state.high_window.into(),
+                period: py_state.period,
+            },
+            min_state: MinState {
+                min: py_state.lower,
+                last_window: py_state.low_window.into(),
+                period: py_state.period,
+            },
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, period = 20, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn donchian(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyDonchianState,
+)> {
+    let len = high.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                donchian_into(
+                    &ohlcv,
+                    period,
+                    output_upper.as_mut_slice(),
+                    output_middle.as_mut_slice(),
+                    output_lower.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_upper.into_pyarray(py).into(),
+            output_middle.into_pyarray(py).into(),
+            output_lower.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_upper = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_upper_slice = unsafe { py_out_upper.as_slice_mut()? };
+
+        let py_out_middle = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_middle_slice = unsafe { py_out_middle.as_slice_mut()? };
+
+        let py_out_lower = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_lower_slice = unsafe { py_out_lower.as_slice_mut()? };
+
+        let state = donchian_into(
+            &ohlcv,
+            period,
+            py_out_upper_slice,
+            py_out_middle_slice,
+            py_out_lower_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_upper.into(),
+            py_out_middle.into(),
+            py_out_lower.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, donchian_state))]
+pub(crate) fn donchian_next(
+    new_high: Float,
+    new_low: Float,
+    donchian_state: PyDonchianState,
+) -> PyResult<PyDonchianState> {
+    let mut donchian_state: DonchianState = donchian_state.into();
+    donchian_state
+        .update((new_high, new_low))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(donchian_state.into())
+}
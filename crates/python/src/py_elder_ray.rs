@@ -0,0 +1,189 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::elder_ray::{elder_ray_into, ElderRayState};
+use techalib::indicators::ema::EmaState;
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "ElderRayState")]
+#[derive(Debug, Clone)]
+pub struct PyElderRayState {
+    #[pyo3(get)]
+    pub bull_power: Float,
+    #[pyo3(get)]
+    pub bear_power: Float,
+    #[pyo3(get)]
+    pub ema: Float,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub alpha: Float,
+}
+
+#[pymethods]
+impl PyElderRayState {
+    #[new]
+    pub fn new(
+        bull_power: Float,
+        bear_power: Float,
+        ema: Float,
+        period: usize,
+        alpha: Float,
+    ) -> Self {
+        PyElderRayState {
+            bull_power,
+            bear_power,
+            ema,
+            period,
+            alpha,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ElderRayState(bull_power: {}, bear_power: {}, ema: {}, period: {}, alpha: {})",
+            self.bull_power, self.bear_power, self.ema, self.period, self.alpha
+        )
+    }
+}
+
+impl From<ElderRayState> for PyElderRayState {
+    fn from(state: ElderRayState) -> Self {
+        PyElderRayState {
+            bull_power: state.bull_power,
+            bear_power: state.bear_power,
+            ema: state.ema.ema,
+            period: state.ema.period,
+            alpha: state.ema.alpha,
+        }
+    }
+}
+
+impl From<PyElderRayState> for ElderRayState {
+    fn from(py_state: PyElderRayState) -> Self {
+        ElderRayState {
+            bull_power: py_state.bull_power,
+            bear_power: py_state.bear_power,
+            ema: EmaState {
+                ema: py_state.ema,
+                period: py_state.period,
+                alpha: py_state.alpha,
+            },
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, close, period = 13, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn elder_ray(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, Py<PyArray1<Float>>, PyElderRayState)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output_bull_power = vec![0.0; len];
+        let mut output_bear_power = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                elder_ray_into(
+                    &ohlcv,
+                    period,
+                    output_bull_power.as_mut_slice(),
+                    output_bear_power.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_bull_power.into_pyarray(py).into(),
+            output_bear_power.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_bull_power = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_bull_power_slice = unsafe { py_out_bull_power.as_slice_mut()? };
+
+        let py_out_bear_power = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_bear_power_slice = unsafe { py_out_bear_power.as_slice_mut()? };
+
+        let state = elder_ray_into(
+            &ohlcv,
+            period,
+            py_out_bull_power_slice,
+            py_out_bear_power_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_bull_power.into(),
+            py_out_bear_power.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, new_close, elder_ray_state))]
+pub(crate) fn elder_ray_next(
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    elder_ray_state: PyElderRayState,
+) -> PyResult<PyElderRayState> {
+    let mut elder_ray_state: ElderRayState = elder_ray_state.into();
+    elder_ray_state
+        .update((new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(elder_ray_state.into())
+}
@@ -0,0 +1,195 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use crate::py_ma::{PyMaState, PyMaType};
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::envelope::{envelope_into, EnvelopeState};
+use techalib::traits::State;
+use techalib::types::Float;
+
+#[pyclass(name = "EnvelopeState")]
+#[derive(Debug, Clone)]
+pub struct PyEnvelopeState {
+    #[pyo3(get)]
+    pub upper: Float,
+    #[pyo3(get)]
+    pub middle: Float,
+    #[pyo3(get)]
+    pub lower: Float,
+    #[pyo3(get)]
+    pub ma: PyMaState,
+    #[pyo3(get)]
+    pub percent: Float,
+}
+
+#[pymethods]
+impl PyEnvelopeState {
+    #[new]
+    pub fn new(upper: Float, middle: Float, lower: Float, ma: PyMaState, percent: Float) -> Self {
+        PyEnvelopeState {
+            upper,
+            middle,
+            lower,
+            ma,
+            percent,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "EnvelopeState(upper: {}, middle: {}, lower: {}, ma: {}, percent: {})",
+            self.upper,
+            self.middle,
+            self.lower,
+            self.ma.__repr__(),
+            self.percent
+        )
+    }
+}
+
+impl From<EnvelopeState> for PyEnvelopeState {
+    fn from(state: EnvelopeState) -> Self {
+        PyEnvelopeState {
+            upper: state.upper,
+            middle: state.middle,
+            lower: state.lower,
+            ma: state.ma.into(),
+            percent: state.percent,
+        }
+    }
+}
+
+impl From<PyEnvelopeState> for EnvelopeState {
+    fn from(py_state: PyEnvelopeState) -> Self {
+        EnvelopeState {
+            upper: py_state.upper,
+            middle: py_state.middle,
+            lower: py_state.lower,
+            ma: py_state.ma.into(),
+            percent: py_state.percent,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 20, percent = 2.5, ma_type = PyMaType::SMA, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn envelope(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    percent: Float,
+    ma_type: PyMaType,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyEnvelopeState,
+)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                envelope_into(
+                    input_slice,
+                    period,
+                    percent,
+                    ma_type.into(),
+                    output_upper.as_mut_slice(),
+                    output_middle.as_mut_slice(),
+                    output_lower.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_upper.into_pyarray(py).into(),
+            output_middle.into_pyarray(py).into(),
+            output_lower.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_upper = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_upper_slice = unsafe { py_out_upper.as_slice_mut()? };
+
+        let py_out_middle = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_middle_slice = unsafe { py_out_middle.as_slice_mut()? };
+
+        let py_out_lower = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_lower_slice = unsafe { py_out_lower.as_slice_mut()? };
+
+        let state = envelope_into(
+            input_slice,
+            period,
+            percent,
+            ma_type.into(),
+            py_out_upper_slice,
+            py_out_middle_slice,
+            py_out_lower_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_upper.into(),
+            py_out_middle.into(),
+            py_out_lower.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_value, envelope_state))]
+pub(crate) fn envelope_next(
+    new_value: Float,
+    envelope_state: PyEnvelopeState,
+) -> PyResult<PyEnvelopeState> {
+    let mut envelope_state: EnvelopeState = envelope_state.into();
+    envelope_state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(envelope_state.into())
+}
@@ -0,0 +1,168 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::eom::{eom_into, EomState, DEFAULT_SCALE};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "EomState")]
+#[derive(Debug, Clone)]
+pub struct PyEomState {
+    #[pyo3(get)]
+    pub eom: Float,
+    #[pyo3(get)]
+    pub prev_high: Float,
+    #[pyo3(get)]
+    pub prev_low: Float,
+    #[pyo3(get)]
+    pub window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub scale: Float,
+}
+
+#[pymethods]
+impl PyEomState {
+    #[new]
+    pub fn new(
+        eom: Float,
+        prev_high: Float,
+        prev_low: Float,
+        window: Vec<Float>,
+        period: usize,
+        scale: Float,
+    ) -> Self {
+        PyEomState {
+            eom,
+            prev_high,
+            prev_low,
+            window,
+            period,
+            scale,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "EomState(eom: {}, prev_high: {}, prev_low: {}, window: {:?}, period: {}, scale: {})",
+            self.eom, self.prev_high, self.prev_low, self.window, self.period, self.scale
+        )
+    }
+}
+
+impl From<EomState> for PyEomState {
+    fn from(state: EomState) -> Self {
+        PyEomState {
+            eom: state.eom,
+            prev_high: state.prev_high,
+            prev_low: state.prev_low,
+            window: state.last_window.into(),
+            period: state.period,
+            scale: state.scale,
+        }
+    }
+}
+
+impl From<PyEomState> for EomState {
+    fn from(py_state: PyEomState) -> Self {
+        EomState {
+            eom: py_state.eom,
+            prev_high: py_state.prev_high,
+            prev_low: py_state.prev_low,
+            last_window: py_state.window.into(),
+            period: py_state.period,
+            scale: py_state.scale,
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, volume, period = 14, scale = DEFAULT_SCALE, release_gil = false))]
+pub(crate) fn eom(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    volume: PyReadonlyArray1<Float>,
+    period: usize,
+    scale: Float,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyEomState)> {
+    let len = high.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        volume: volume.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| eom_into(&ohlcv, period, scale, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let py_out = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_slice = unsafe { py_out.as_slice_mut()? };
+
+        let state = eom_into(&ohlcv, period, scale, py_out_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((py_out.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, new_volume, eom_state))]
+pub(crate) fn eom_next(
+    new_high: Float,
+    new_low: Float,
+    new_volume: Float,
+    eom_state: PyEomState,
+) -> PyResult<PyEomState> {
+    let mut eom_state: EomState = eom_state.into();
+    eom_state
+        .update((new_high, new_low, new_volume))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(eom_state.into())
+}
@@ -0,0 +1,147 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::force_index::{force_index_into, ForceIndexState};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "ForceIndexState")]
+#[derive(Debug, Clone)]
+pub struct PyForceIndexState {
+    #[pyo3(get)]
+    pub force_index: Float,
+    #[pyo3(get)]
+    pub prev_close: Float,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub alpha: Float,
+}
+
+#[pymethods]
+impl PyForceIndexState {
+    #[new]
+    pub fn new(force_index: Float, prev_close: Float, period: usize, alpha: Float) -> Self {
+        PyForceIndexState {
+            force_index,
+            prev_close,
+            period,
+            alpha,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ForceIndexState(force_index: {}, prev_close: {}, period: {}, alpha: {})",
+            self.force_index, self.prev_close, self.period, self.alpha
+        )
+    }
+}
+
+impl From<ForceIndexState> for PyForceIndexState {
+    fn from(state: ForceIndexState) -> Self {
+        PyForceIndexState {
+            force_index: state.force_index,
+            prev_close: state.prev_close,
+            period: state.period,
+            alpha: state.alpha,
+        }
+    }
+}
+
+impl From<PyForceIndexState> for ForceIndexState {
+    fn from(py_state: PyForceIndexState) -> Self {
+        ForceIndexState {
+            force_index: py_state.force_index,
+            prev_close: py_state.prev_close,
+            period: py_state.period,
+            alpha: py_state.alpha,
+        }
+    }
+}
+
+#[pyfunction(signature = (close, volume, period = 13, release_gil = false))]
+pub(crate) fn force_index(
+    py: Python,
+    close: PyReadonlyArray1<Float>,
+    volume: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyForceIndexState)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        close: close.as_slice()?,
+        volume: volume.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| force_index_into(&ohlcv, period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let py_out = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_slice = unsafe { py_out.as_slice_mut()? };
+
+        let state = force_index_into(&ohlcv, period, py_out_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((py_out.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_close, new_volume, force_index_state))]
+pub(crate) fn force_index_next(
+    new_close: Float,
+    new_volume: Float,
+    force_index_state: PyForceIndexState,
+) -> PyResult<PyForceIndexState> {
+    let mut force_index_state: ForceIndexState = force_index_state.into();
+    force_index_state
+        .update((new_close, new_volume))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(force_index_state.into())
+}
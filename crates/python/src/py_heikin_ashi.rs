@@ -0,0 +1,197 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::heikin_ashi::{heikin_ashi_into, HeikinAshiState};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "HeikinAshiState")]
+#[derive(Debug, Clone)]
+pub struct PyHeikinAshiState {
+    #[pyo3(get)]
+    pub open: Float,
+    #[pyo3(get)]
+    pub high: Float,
+    #[pyo3(get)]
+    pub low: Float,
+    #[pyo3(get)]
+    pub close: Float,
+}
+
+#[pymethods]
+impl PyHeikinAshiState {
+    #[new]
+    pub fn new(open: Float, high: Float, low: Float, close: Float) -> Self {
+        PyHeikinAshiState {
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "HeikinAshiState(open: {}, high: {}, low: {}, close: {})",
+            self.open, self.high, self.low, self.close
+        )
+    }
+}
+
+impl From<HeikinAshiState> for PyHeikinAshiState {
+    fn from(state: HeikinAshiState) -> Self {
+        PyHeikinAshiState {
+            open: state.open,
+            high: state.high,
+            low: state.low,
+            close: state.close,
+        }
+    }
+}
+
+impl From<PyHeikinAshiState> for HeikinAshiState {
+    fn from(py_state: PyHeikinAshiState) -> Self {
+        HeikinAshiState {
+            open: py_state.open,
+            high: py_state.high,
+            low: py_state.low,
+            close: py_state.close,
+        }
+    }
+}
+
+#[pyfunction(signature = (open, high, low, close, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn heikin_ashi(
+    py: Python,
+    open: PyReadonlyArray1<Float>,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyHeikinAshiState,
+)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        open: open.as_slice()?,
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output_open = vec![0.0; len];
+        let mut output_high = vec![0.0; len];
+        let mut output_low = vec![0.0; len];
+        let mut output_close = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                heikin_ashi_into(
+                    &ohlcv,
+                    output_open.as_mut_slice(),
+                    output_high.as_mut_slice(),
+                    output_low.as_mut_slice(),
+                    output_close.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_open.into_pyarray(py).into(),
+            output_high.into_pyarray(py).into(),
+            output_low.into_pyarray(py).into(),
+            output_close.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_open = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_open_slice = unsafe { py_out_open.as_slice_mut()? };
+
+        let py_out_high = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_high_slice = unsafe { py_out_high.as_slice_mut()? };
+
+        let py_out_low = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_low_slice = unsafe { py_out_low.as_slice_mut()? };
+
+        let py_out_close = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_close_slice = unsafe { py_out_close.as_slice_mut()? };
+
+        let state = heikin_ashi_into(
+            &ohlcv,
+            py_out_open_slice,
+            py_out_high_slice,
+            py_out_low_slice,
+            py_out_close_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_open.into(),
+            py_out_high.into(),
+            py_out_low.into(),
+            py_out_close.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_open, new_high, new_low, new_close, heikin_ashi_state))]
+pub(crate) fn heikin_ashi_next(
+    new_open: Float,
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    heikin_ashi_state: PyHeikinAshiState,
+) -> PyResult<PyHeikinAshiState> {
+    let mut heikin_ashi_state: HeikinAshiState = heikin_ashi_state.into();
+    heikin_ashi_state
+        .update((new_open, new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(heikin_ashi_state.into())
+}
@@ -0,0 +1,141 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::hma::{hma_into, HmaState};
+use techalib::traits::State;
+use techalib::types::Float;
+
+use crate::py_wma::PyWmaState;
+
+#[pyclass(name = "HmaState")]
+#[derive(Debug, Clone)]
+pub struct PyHmaState {
+    #[pyo3(get)]
+    pub hma: Float,
+    #[pyo3(get)]
+    pub half_wma: PyWmaState,
+    #[pyo3(get)]
+    pub full_wma: PyWmaState,
+    #[pyo3(get)]
+    pub hull_wma: PyWmaState,
+    #[pyo3(get)]
+    pub period: usize,
+}
+#[pymethods]
+impl PyHmaState {
+    #[new]
+    pub fn new(
+        hma: Float,
+        half_wma: PyWmaState,
+        full_wma: PyWmaState,
+        hull_wma: PyWmaState,
+        period: usize,
+    ) -> Self {
+        PyHmaState {
+            hma,
+            half_wma,
+            full_wma,
+            hull_wma,
+            period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!("HmaState(hma={}, period={})", self.hma, self.period)
+    }
+}
+impl From<HmaState> for PyHmaState {
+    fn from(state: HmaState) -> Self {
+        PyHmaState {
+            hma: state.hma,
+            half_wma: state.half_wma.into(),
+            full_wma: state.full_wma.into(),
+            hull_wma: state.hull_wma.into(),
+            period: state.period,
+        }
+    }
+}
+
+impl From<PyHmaState> for HmaState {
+    fn from(py_state: PyHmaState) -> Self {
+        HmaState {
+            hma: py_state.hma,
+            half_wma: py_state.half_wma.into(),
+            full_wma: py_state.full_wma.into(),
+            hull_wma: py_state.hull_wma.into(),
+            period: py_state.period,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 16, release_gil = false))]
+pub(crate) fn hma(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyHmaState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| hma_into(input_slice, period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = hma_into(input_slice, period, output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, hma_state))]
+pub(crate) fn hma_next(new_value: Float, hma_state: PyHmaState) -> PyResult<PyHmaState> {
+    let mut state: HmaState = hma_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
@@ -0,0 +1,274 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use crate::py_donchian::PyDonchianState;
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::ichimoku::{ichimoku_into, IchimokuPeriods, IchimokuState};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "IchimokuState")]
+#[derive(Debug, Clone)]
+pub struct PyIchimokuState {
+    #[pyo3(get)]
+    pub tenkan: Float,
+    #[pyo3(get)]
+    pub kijun: Float,
+    #[pyo3(get)]
+    pub senkou_a: Float,
+    #[pyo3(get)]
+    pub senkou_b: Float,
+    #[pyo3(get)]
+    pub chikou: Float,
+    #[pyo3(get)]
+    pub tenkan_state: PyDonchianState,
+    #[pyo3(get)]
+    pub kijun_state: PyDonchianState,
+    #[pyo3(get)]
+    pub senkou_b_state: PyDonchianState,
+    #[pyo3(get)]
+    pub senkou_a_ahead: Vec<Float>,
+    #[pyo3(get)]
+    pub senkou_b_ahead: Vec<Float>,
+    #[pyo3(get)]
+    pub displacement: usize,
+}
+
+#[pymethods]
+impl PyIchimokuState {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        tenkan: Float,
+        kijun: Float,
+        senkou_a: Float,
+        senkou_b: Float,
+        chikou: Float,
+        tenkan_state: PyDonchianState,
+        kijun_state: PyDonchianState,
+        senkou_b_state: PyDonchianState,
+        senkou_a_ahead: Vec<Float>,
+        senkou_b_ahead: Vec<Float>,
+        displacement: usize,
+    ) -> Self {
+        PyIchimokuState {
+            tenkan,
+            kijun,
+            senkou_a,
+            senkou_b,
+            chikou,
+            tenkan_state,
+            kijun_state,
+            senkou_b_state,
+            senkou_a_ahead,
+            senkou_b_ahead,
+            displacement,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "IchimokuState(tenkan: {}, kijun: {}, senkou_a: {}, senkou_b: {}, chikou: {}, tenkan_state: {}, kijun_state: {}, senkou_b_state: {}, senkou_a_ahead: {:?}, senkou_b_ahead: {:?}, displacement: {})",
+            self.tenkan,
+            self.kijun,
+            self.senkou_a,
+            self.senkou_b,
+            self.chikou,
+            self.tenkan_state.__repr__(),
+            self.kijun_state.__repr__(),
+            self.senkou_b_state.__repr__(),
+            self.senkou_a_ahead,
+            self.senkou_b_ahead,
+            self.displacement
+        )
+    }
+}
+
+impl From<IchimokuState> for PyIchimokuState {
+    fn from(state: IchimokuState) -> Self {
+        PyIchimokuState {
+            tenkan: state.tenkan,
+            kijun: state.kijun,
+            senkou_a: state.senkou_a,
+            senkou_b: state.senkou_b,
+            chikou: state.chikou,
+            tenkan_state: state.tenkan_state.into(),
+            kijun_state: state.kijun_state.into(),
+            senkou_b_state: state.senkou_b_state.into(),
+            senkou_a_ahead: state.senkou_a_ahead.into(),
+            senkou_b_ahead: state.senkou_b_ahead.into(),
+            displacement: state.displacement,
+        }
+    }
+}
+
+impl From<PyIchimokuState> for IchimokuState {
+    fn from(py_state: PyIchimokuState) -> Self {
+        IchimokuState {
+            tenkan: py_state.tenkan,
+            kijun: py_state.kijun,
+            senkou_a: py_state.senkou_a,
+            senkou_b: py_state.senkou_b,
+            chikou: py_state.chikou,
+            tenkan_state: py_state.tenkan_state.into(),
+            kijun_state: py_state.kijun_state.into(),
+            senkou_b_state: py_state.senkou_b_state.into(),
+            senkou_a_ahead: py_state.senkou_a_ahead.into(),
+            senkou_b_ahead: py_state.senkou_b_ahead.into(),
+            displacement: py_state.displacement,
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, close, tenkan = 9, kijun = 26, senkou_b = 52, displacement = 26, release_gil = false))]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn ichimoku(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    tenkan: usize,
+    kijun: usize,
+    senkou_b: usize,
+    displacement: usize,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyIchimokuState,
+)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+    let periods = IchimokuPeriods {
+        tenkan,
+        kijun,
+        senkou_b,
+        displacement,
+    };
+
+    if release_gil {
+        let mut output_tenkan = vec![0.0; len];
+        let mut output_kijun = vec![0.0; len];
+        let mut output_senkou_a = vec![0.0; len];
+        let mut output_senkou_b = vec![0.0; len];
+        let mut output_chikou = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                ichimoku_into(
+                    &ohlcv,
+                    periods,
+                    output_tenkan.as_mut_slice(),
+                    output_kijun.as_mut_slice(),
+                    output_senkou_a.as_mut_slice(),
+                    output_senkou_b.as_mut_slice(),
+                    output_chikou.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_tenkan.into_pyarray(py).into(),
+            output_kijun.into_pyarray(py).into(),
+            output_senkou_a.into_pyarray(py).into(),
+            output_senkou_b.into_pyarray(py).into(),
+            output_chikou.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_tenkan = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_tenkan_slice = unsafe { py_out_tenkan.as_slice_mut()? };
+
+        let py_out_kijun = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_kijun_slice = unsafe { py_out_kijun.as_slice_mut()? };
+
+        let py_out_senkou_a = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_senkou_a_slice = unsafe { py_out_senkou_a.as_slice_mut()? };
+
+        let py_out_senkou_b = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_senkou_b_slice = unsafe { py_out_senkou_b.as_slice_mut()? };
+
+        let py_out_chikou = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_chikou_slice = unsafe { py_out_chikou.as_slice_mut()? };
+
+        let state = ichimoku_into(
+            &ohlcv,
+            periods,
+            py_out_tenkan_slice,
+            py_out_kijun_slice,
+            py_out_senkou_a_slice,
+            py_out_senkou_b_slice,
+            py_out_chikou_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_tenkan.into(),
+            py_out_kijun.into(),
+            py_out_senkou_a.into(),
+            py_out_senkou_b.into(),
+            py_out_chikou.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, new_close, ichimoku_state))]
+pub(crate) fn ichimoku_next(
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    ichimoku_state: PyIchimokuState,
+) -> PyResult<PyIchimokuState> {
+    let mut ichimoku_state: IchimokuState = ichimoku_state.into();
+    ichimoku_state
+        .update((new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(ichimoku_state.into())
+}
@@ -0,0 +1,237 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::atr::AtrState;
+use techalib::indicators::ema::EmaState;
+use techalib::indicators::keltner::{keltner_into, KeltnerState};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "KeltnerState")]
+#[derive(Debug, Clone)]
+pub struct PyKeltnerState {
+    #[pyo3(get)]
+    pub upper: Float,
+    #[pyo3(get)]
+    pub middle: Float,
+    #[pyo3(get)]
+    pub lower: Float,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub alpha: Float,
+    #[pyo3(get)]
+    pub atr: Float,
+    #[pyo3(get)]
+    pub prev_close: Float,
+    #[pyo3(get)]
+    pub atr_period: usize,
+    #[pyo3(get)]
+    pub multiplier: Float,
+}
+
+#[pymethods]
+impl PyKeltnerState {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        upper: Float,
+        middle: Float,
+        lower: Float,
+        period: usize,
+        alpha: Float,
+        atr: Float,
+        prev_close: Float,
+        atr_period: usize,
+        multiplier: Float,
+    ) -> Self {
+        PyKeltnerState {
+            upper,
+            middle,
+            lower,
+            period,
+            alpha,
+            atr,
+            prev_close,
+            atr_period,
+            multiplier,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "KeltnerState(upper: {}, middle: {}, lower: {}, period: {}, alpha: {}, atr: {}, prev_close: {}, atr_period: {}, multiplier: {})",
+            self.upper, self.middle, self.lower, self.period, self.alpha, self.atr, self.prev_close, self.atr_period, self.multiplier
+        )
+    }
+}
+
+impl From<KeltnerState> for PyKeltnerState {
+    fn from(state: KeltnerState) -> Self {
+        PyKeltnerState {
+            upper: state.upper,
+            middle: state.middle,
+            lower: state.lower,
+            period: state.ema.period,
+            alpha: state.ema.alpha,
+            atr: state.atr.atr,
+            prev_close: state.atr.prev_close,
+            atr_period: state.atr.period,
+            multiplier: state.multiplier,
+        }
+    }
+}
+
+impl From<PyKeltnerState> for KeltnerState {
+    fn from(py_state: PyKeltnerState) -> Self {
+        KeltnerState {
+            upper: py_state.upper,
+            middle: py_state.middle,
+            lower: py_state.lower,
+            ema: EmaState {
+                ema: py_state.middle,
+                period: py_state.period,
+                alpha: py_state.alpha,
+            },
+            atr: AtrState {
+                atr: py_state.atr,
+                prev_close: py_state.prev_close,
+                period: py_state.atr_period,
+            },
+            multiplier: py_state.multiplier,
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, close, period = 20, atr_period = 10, multiplier = 2.0, release_gil = false))]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn keltner(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    period: usize,
+    atr_period: usize,
+    multiplier: Float,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyKeltnerState,
+)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                keltner_into(
+                    &ohlcv,
+                    period,
+                    atr_period,
+                    multiplier,
+                    output_upper.as_mut_slice(),
+                    output_middle.as_mut_slice(),
+                    output_lower.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_upper.into_pyarray(py).into(),
+            output_middle.into_pyarray(py).into(),
+            output_lower.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_upper = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_upper_slice = unsafe { py_out_upper.as_slice_mut()? };
+
+        let py_out_middle = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_middle_slice = unsafe { py_out_middle.as_slice_mut()? };
+
+        let py_out_lower = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_lower_slice = unsafe { py_out_lower.as_slice_mut()? };
+
+        let state = keltner_into(
+            &ohlcv,
+            period,
+            atr_period,
+            multiplier,
+            py_out_upper_slice,
+            py_out_middle_slice,
+            py_out_lower_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_upper.into(),
+            py_out_middle.into(),
+            py_out_lower.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, new_close, keltner_state))]
+pub(crate) fn keltner_next(
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    keltner_state: PyKeltnerState,
+) -> PyResult<PyKeltnerState> {
+    let mut keltner_state: KeltnerState = keltner_state.into();
+    keltner_state
+        .update((new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(keltner_state.into())
+}
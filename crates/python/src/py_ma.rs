@@ -0,0 +1,156 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::alma::{DEFAULT_OFFSET, DEFAULT_SIGMA};
+use techalib::indicators::ma::{ma_into, MaState, MaType};
+use techalib::indicators::vidya::DEFAULT_CMO_PERIOD;
+use techalib::traits::State;
+use techalib::types::Float;
+
+#[pyclass(name = "MaType")]
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum PyMaType {
+    SMA,
+    EMA,
+    WMA,
+    DEMA,
+    TEMA,
+    TRIMA,
+    KAMA,
+    T3,
+    HMA,
+    ZLEMA,
+    ALMA,
+    SMMA,
+    VIDYA,
+}
+
+/// The state of the moving averages can differ a lot from one type to
+/// another, so it is kept opaque on the Python side.
+#[pyclass(name = "MaState")]
+#[derive(Debug, Clone)]
+pub struct PyMaState {
+    pub inner: MaState,
+}
+
+#[pymethods]
+impl PyMaState {
+    #[getter]
+    pub fn value(&self) -> Float {
+        self.inner.value()
+    }
+    #[getter]
+    pub fn period(&self) -> usize {
+        self.inner.period()
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!("MaState({:?})", self.inner)
+    }
+}
+
+impl From<MaState> for PyMaState {
+    fn from(state: MaState) -> Self {
+        PyMaState { inner: state }
+    }
+}
+
+impl From<PyMaState> for MaState {
+    fn from(py_state: PyMaState) -> Self {
+        py_state.inner
+    }
+}
+
+impl From<PyMaType> for MaType {
+    fn from(py_ma: PyMaType) -> Self {
+        match py_ma {
+            PyMaType::SMA => MaType::SMA,
+            PyMaType::EMA => MaType::EMA(None),
+            PyMaType::WMA => MaType::WMA,
+            PyMaType::DEMA => MaType::DEMA(None),
+            PyMaType::TEMA => MaType::TEMA(None),
+            PyMaType::TRIMA => MaType::TRIMA,
+            PyMaType::KAMA => MaType::KAMA,
+            PyMaType::T3 => MaType::T3(0.7, None),
+            PyMaType::HMA => MaType::HMA,
+            PyMaType::ZLEMA => MaType::ZLEMA(None),
+            PyMaType::ALMA => MaType::ALMA(DEFAULT_OFFSET, DEFAULT_SIGMA),
+            PyMaType::SMMA => MaType::SMMA,
+            PyMaType::VIDYA => MaType::VIDYA(DEFAULT_CMO_PERIOD),
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 30, ma_type = PyMaType::SMA, release_gil = false))]
+pub(crate) fn ma(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    ma_type: PyMaType,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyMaState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| ma_into(input_slice, period, ma_type.into(), output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = ma_into(input_slice, period, ma_type.into(), output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, ma_state))]
+pub(crate) fn ma_next(new_value: Float, ma_state: PyMaState) -> PyResult<PyMaState> {
+    let mut state: MaState = ma_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
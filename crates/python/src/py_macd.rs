@@ -62,6 +62,7 @@ pub struct PyMacdState {
 #[pymethods]
 impl PyMacdState {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         macd: Float,
         signal: Float,
@@ -125,6 +126,7 @@ impl From<PyMacdState> for MacdState {
 }
 
 #[pyfunction(signature = (data, fast_period = 12, slow_period = 26, signal_period = 9, release_gil = false))]
+#[allow(clippy::type_complexity)]
 pub(crate) fn macd(
     py: Python,
     data: PyReadonlyArray1<Float>,
@@ -160,12 +162,12 @@ pub(crate) fn macd(
             })
             .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
 
-        return Ok((
+        Ok((
             output_macd.into_pyarray(py).into(),
             output_signal.into_pyarray(py).into(),
             output_histogram.into_pyarray(py).into(),
             macd_state.into(),
-        ));
+        ))
     } else {
         let py_array_macd = PyArray1::<Float>::zeros(py, [len], false);
         let output_macd_data = unsafe { py_array_macd.as_slice_mut()? };
@@ -187,12 +189,12 @@ pub(crate) fn macd(
         )
         .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
 
-        return Ok((
+        Ok((
             py_array_macd.into(),
             py_array_signal.into(),
             py_array_histogram.into(),
             macd_state.into(),
-        ));
+        ))
     }
 }
 
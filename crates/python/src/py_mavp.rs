@@ -0,0 +1,257 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::alma::{DEFAULT_OFFSET, DEFAULT_SIGMA};
+use techalib::indicators::mavp::{mavp_into, MavpMA, MavpState};
+use techalib::indicators::vidya::DEFAULT_CMO_PERIOD;
+use techalib::traits::State;
+use techalib::types::Float;
+
+use crate::py_ma::PyMaState;
+
+#[pyclass(name = "MavpState")]
+#[derive(Debug, Clone)]
+pub struct PyMavpState {
+    #[pyo3(get)]
+    pub mavp: Float,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub period_sub: Float,
+    #[pyo3(get)]
+    pub period_sum: Float,
+    #[pyo3(get)]
+    pub emas: Vec<Float>,
+    #[pyo3(get)]
+    pub ma_states: Vec<PyMaState>,
+    #[pyo3(get)]
+    pub window: Vec<Float>,
+    #[pyo3(get)]
+    pub min_period: usize,
+    #[pyo3(get)]
+    pub max_period: usize,
+    #[pyo3(get)]
+    pub ma_type: PyMavpMA,
+}
+
+#[pyclass(name = "MavpMA")]
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum PyMavpMA {
+    SMA,
+    EMA,
+    WMA,
+    HMA,
+    ZLEMA,
+    ALMA,
+    SMMA,
+    VIDYA,
+}
+
+#[pymethods]
+impl PyMavpState {
+    #[new]
+    #[pyo3(signature = (mavp, period, period_sub, period_sum, emas, window, min_period, max_period, ma_type, ma_states = Vec::new()))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mavp: Float,
+        period: usize,
+        period_sub: Float,
+        period_sum: Float,
+        emas: Vec<Float>,
+        window: Vec<Float>,
+        min_period: usize,
+        max_period: usize,
+        ma_type: PyMavpMA,
+        ma_states: Vec<PyMaState>,
+    ) -> Self {
+        PyMavpState {
+            mavp,
+            period,
+            period_sub,
+            period_sum,
+            emas,
+            ma_states,
+            window,
+            min_period,
+            max_period,
+            ma_type,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "MavpState(mavp: {}, period: {}, period_sub: {}, period_sum: {}, emas: {:?}, ma_states: {:?}, window: {:?}, min_period: {}, max_period: {}, ma_type: {:?})",
+            self.mavp, self.period, self.period_sub, self.period_sum, self.emas, self.ma_states.iter().map(|ma| &ma.inner).collect::<Vec<_>>(), self.window, self.min_period, self.max_period, self.ma_type
+        )
+    }
+}
+
+impl From<MavpState> for PyMavpState {
+    fn from(state: MavpState) -> Self {
+        PyMavpState {
+            mavp: state.mavp,
+            period: state.period,
+            period_sub: state.period_sub,
+            period_sum: state.period_sum,
+            emas: state.emas,
+            ma_states: state.ma_states.into_iter().map(PyMaState::from).collect(),
+            window: state.last_window.into(),
+            min_period: state.min_period,
+            max_period: state.max_period,
+            ma_type: state.ma_type.into(),
+        }
+    }
+}
+
+impl From<PyMavpState> for MavpState {
+    fn from(py_state: PyMavpState) -> Self {
+        MavpState {
+            mavp: py_state.mavp,
+            period: py_state.period,
+            period_sub: py_state.period_sub,
+            period_sum: py_state.period_sum,
+            emas: py_state.emas,
+            ma_states: py_state
+                .ma_states
+                .into_iter()
+                .map(PyMaState::into)
+                .collect(),
+            last_window: py_state.window.into(),
+            min_period: py_state.min_period,
+            max_period: py_state.max_period,
+            ma_type: py_state.ma_type.into(),
+        }
+    }
+}
+
+impl From<PyMavpMA> for MavpMA {
+    fn from(py_ma: PyMavpMA) -> Self {
+        match py_ma {
+            PyMavpMA::SMA => MavpMA::SMA,
+            PyMavpMA::EMA => MavpMA::EMA,
+            PyMavpMA::WMA => MavpMA::WMA,
+            PyMavpMA::HMA => MavpMA::HMA,
+            PyMavpMA::ZLEMA => MavpMA::ZLEMA,
+            PyMavpMA::ALMA => MavpMA::ALMA(DEFAULT_OFFSET, DEFAULT_SIGMA),
+            PyMavpMA::SMMA => MavpMA::SMMA,
+            PyMavpMA::VIDYA => MavpMA::VIDYA(DEFAULT_CMO_PERIOD),
+        }
+    }
+}
+
+impl From<MavpMA> for PyMavpMA {
+    fn from(ma: MavpMA) -> Self {
+        match ma {
+            MavpMA::SMA => PyMavpMA::SMA,
+            MavpMA::EMA => PyMavpMA::EMA,
+            MavpMA::WMA => PyMavpMA::WMA,
+            MavpMA::HMA => PyMavpMA::HMA,
+            MavpMA::ZLEMA => PyMavpMA::ZLEMA,
+            MavpMA::ALMA(_, _) => PyMavpMA::ALMA,
+            MavpMA::SMMA => PyMavpMA::SMMA,
+            MavpMA::VIDYA(_) => PyMavpMA::VIDYA,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, periods, min_period = 2, max_period = 30, ma_type = PyMavpMA::SMA, release_gil = false))]
+pub(crate) fn mavp(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    periods: PyReadonlyArray1<Float>,
+    min_period: usize,
+    max_period: usize,
+    ma_type: PyMavpMA,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyMavpState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+    let periods_slice = periods.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                mavp_into(
+                    input_slice,
+                    periods_slice,
+                    min_period,
+                    max_period,
+                    ma_type.into(),
+                    output.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let py_out = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_slice = unsafe { py_out.as_slice_mut()? };
+
+        let state = mavp_into(
+            input_slice,
+            periods_slice,
+            min_period,
+            max_period,
+            ma_type.into(),
+            py_out_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((py_out.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, new_period, mavp_state))]
+pub(crate) fn mavp_next(
+    new_value: Float,
+    new_period: Float,
+    mavp_state: PyMavpState,
+) -> PyResult<PyMavpState> {
+    let mut mavp_state: MavpState = mavp_state.into();
+    mavp_state
+        .update((new_value, new_period))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(mavp_state.into())
+}
@@ -0,0 +1,362 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::pivots::{pivots_into, PivotMethod, PivotsState};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "PivotMethod")]
+#[derive(Debug, Clone, Copy)]
+pub enum PyPivotMethod {
+    Classic,
+    Fibonacci,
+    Camarilla,
+    Woodie,
+    Demark,
+}
+
+impl From<PyPivotMethod> for PivotMethod {
+    fn from(py_method: PyPivotMethod) -> Self {
+        match py_method {
+            PyPivotMethod::Classic => PivotMethod::Classic,
+            PyPivotMethod::Fibonacci => PivotMethod::Fibonacci,
+            PyPivotMethod::Camarilla => PivotMethod::Camarilla,
+            PyPivotMethod::Woodie => PivotMethod::Woodie,
+            PyPivotMethod::Demark => PivotMethod::Demark,
+        }
+    }
+}
+
+impl From<PivotMethod> for PyPivotMethod {
+    fn from(method: PivotMethod) -> Self {
+        match method {
+            PivotMethod::Classic => PyPivotMethod::Classic,
+            PivotMethod::Fibonacci => PyPivotMethod::Fibonacci,
+            PivotMethod::Camarilla => PyPivotMethod::Camarilla,
+            PivotMethod::Woodie => PyPivotMethod::Woodie,
+            PivotMethod::Demark => PyPivotMethod::Demark,
+        }
+    }
+}
+
+#[pyclass(name = "PivotsState")]
+#[derive(Debug, Clone)]
+pub struct PyPivotsState {
+    #[pyo3(get)]
+    pub pivot: Float,
+    #[pyo3(get)]
+    pub r1: Float,
+    #[pyo3(get)]
+    pub r2: Float,
+    #[pyo3(get)]
+    pub r3: Float,
+    #[pyo3(get)]
+    pub s1: Float,
+    #[pyo3(get)]
+    pub s2: Float,
+    #[pyo3(get)]
+    pub s3: Float,
+    #[pyo3(get)]
+    pub prev_open: Float,
+    #[pyo3(get)]
+    pub prev_high: Float,
+    #[pyo3(get)]
+    pub prev_low: Float,
+    #[pyo3(get)]
+    pub prev_close: Float,
+    #[pyo3(get)]
+    pub session_open: Float,
+    #[pyo3(get)]
+    pub session_high: Float,
+    #[pyo3(get)]
+    pub session_low: Float,
+    #[pyo3(get)]
+    pub session_close: Float,
+    #[pyo3(get)]
+    pub method: PyPivotMethod,
+}
+
+#[pymethods]
+impl PyPivotsState {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        pivot: Float,
+        r1: Float,
+        r2: Float,
+        r3: Float,
+        s1: Float,
+        s2: Float,
+        s3: Float,
+        prev_open: Float,
+        prev_high: Float,
+        prev_low: Float,
+        prev_close: Float,
+        session_open: Float,
+        session_high: Float,
+        session_low: Float,
+        session_close: Float,
+        method: PyPivotMethod,
+    ) -> Self {
+        PyPivotsState {
+            pivot,
+            r1,
+            r2,
+            r3,
+            s1,
+            s2,
+            s3,
+            prev_open,
+            prev_high,
+            prev_low,
+            prev_close,
+            session_open,
+            session_high,
+            session_low,
+            session_close,
+            method,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "PivotsState(pivot: {}, r1: {}, r2: {}, r3: {}, s1: {}, s2: {}, s3: {}, prev_open: {}, prev_high: {}, prev_low: {}, prev_close: {}, session_open: {}, session_high: {}, session_low: {}, session_close: {}, method: {:?})",
+            self.pivot,
+            self.r1,
+            self.r2,
+            self.r3,
+            self.s1,
+            self.s2,
+            self.s3,
+            self.prev_open,
+            self.prev_high,
+            self.prev_low,
+            self.prev_close,
+            self.session_open,
+            self.session_high,
+            self.session_low,
+            self.session_close,
+            self.method
+        )
+    }
+}
+
+impl From<PivotsState> for PyPivotsState {
+    fn from(state: PivotsState) -> Self {
+        PyPivotsState {
+            pivot: state.pivot,
+            r1: state.r1,
+            r2: state.r2,
+            r3: state.r3,
+            s1: state.s1,
+            s2: state.s2,
+            s3: state.s3,
+            prev_open: state.prev_open,
+            prev_high: state.prev_high,
+            prev_low: state.prev_low,
+            prev_close: state.prev_close,
+            session_open: state.session_open,
+            session_high: state.session_high,
+            session_low: state.session_low,
+            session_close: state.session_close,
+            method: state.method.into(),
+        }
+    }
+}
+
+impl From<PyPivotsState> for PivotsState {
+    fn from(py_state: PyPivotsState) -> Self {
+        PivotsState {
+            pivot: py_state.pivot,
+            r1: py_state.r1,
+            r2: py_state.r2,
+            r3: py_state.r3,
+            s1: py_state.s1,
+            s2: py_state.s2,
+            s3: py_state.s3,
+            prev_open: py_state.prev_open,
+            prev_high: py_state.prev_high,
+            prev_low: py_state.prev_low,
+            prev_close: py_state.prev_close,
+            session_open: py_state.session_open,
+            session_high: py_state.session_high,
+            session_low: py_state.session_low,
+            session_close: py_state.session_close,
+            method: py_state.method.into(),
+        }
+    }
+}
+
+#[pyfunction(signature = (open, high, low, close, new_session, method = PyPivotMethod::Classic, release_gil = false))]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn pivots(
+    py: Python,
+    open: PyReadonlyArray1<Float>,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    new_session: PyReadonlyArray1<bool>,
+    method: PyPivotMethod,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyPivotsState,
+)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        open: open.as_slice()?,
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+    let new_session = new_session.as_slice()?;
+
+    if release_gil {
+        let mut output_pivot = vec![0.0; len];
+        let mut output_r1 = vec![0.0; len];
+        let mut output_r2 = vec![0.0; len];
+        let mut output_r3 = vec![0.0; len];
+        let mut output_s1 = vec![0.0; len];
+        let mut output_s2 = vec![0.0; len];
+        let mut output_s3 = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                pivots_into(
+                    &ohlcv,
+                    new_session,
+                    method.into(),
+                    output_pivot.as_mut_slice(),
+                    output_r1.as_mut_slice(),
+                    output_r2.as_mut_slice(),
+                    output_r3.as_mut_slice(),
+                    output_s1.as_mut_slice(),
+                    output_s2.as_mut_slice(),
+                    output_s3.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_pivot.into_pyarray(py).into(),
+            output_r1.into_pyarray(py).into(),
+            output_r2.into_pyarray(py).into(),
+            output_r3.into_pyarray(py).into(),
+            output_s1.into_pyarray(py).into(),
+            output_s2.into_pyarray(py).into(),
+            output_s3.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_pivot = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_pivot_slice = unsafe { py_out_pivot.as_slice_mut()? };
+
+        let py_out_r1 = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_r1_slice = unsafe { py_out_r1.as_slice_mut()? };
+
+        let py_out_r2 = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_r2_slice = unsafe { py_out_r2.as_slice_mut()? };
+
+        let py_out_r3 = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_r3_slice = unsafe { py_out_r3.as_slice_mut()? };
+
+        let py_out_s1 = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_s1_slice = unsafe { py_out_s1.as_slice_mut()? };
+
+        let py_out_s2 = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_s2_slice = unsafe { py_out_s2.as_slice_mut()? };
+
+        let py_out_s3 = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_s3_slice = unsafe { py_out_s3.as_slice_mut()? };
+
+        let state = pivots_into(
+            &ohlcv,
+            new_session,
+            method.into(),
+            py_out_pivot_slice,
+            py_out_r1_slice,
+            py_out_r2_slice,
+            py_out_r3_slice,
+            py_out_s1_slice,
+            py_out_s2_slice,
+            py_out_s3_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_pivot.into(),
+            py_out_r1.into(),
+            py_out_r2.into(),
+            py_out_r3.into(),
+            py_out_s1.into(),
+            py_out_s2.into(),
+            py_out_s3.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_open, new_high, new_low, new_close, pivots_state, new_session = false))]
+pub(crate) fn pivots_next(
+    new_open: Float,
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    pivots_state: PyPivotsState,
+    new_session: bool,
+) -> PyResult<PyPivotsState> {
+    let mut pivots_state: PivotsState = pivots_state.into();
+    if new_session {
+        pivots_state.rollover();
+    }
+    pivots_state
+        .update((new_open, new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(pivots_state.into())
+}
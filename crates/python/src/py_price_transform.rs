@@ -0,0 +1,134 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyfunction, Py, PyResult, Python};
+use techalib::errors::TechalibError;
+use techalib::indicators::price_transform::{
+    avgprice_into, medprice_into, typprice_into, wclprice_into,
+};
+use techalib::types::{Float, Ohlcv};
+
+fn price_transform(
+    py: Python,
+    ohlcv: Ohlcv,
+    len: usize,
+    release_gil: bool,
+    transform_into: fn(&Ohlcv, &mut [Float]) -> Result<(), TechalibError>,
+) -> PyResult<Py<PyArray1<Float>>> {
+    if release_gil {
+        let mut output = vec![0.0; len];
+        py.allow_threads(|| transform_into(&ohlcv, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok(output.into_pyarray(py).into())
+    } else {
+        let py_array_out = PyArray1::<Float>::zeros(py, [len], false);
+        let py_array_ptr = unsafe { py_array_out.as_slice_mut()? };
+
+        transform_into(&ohlcv, py_array_ptr)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok(py_array_out.into())
+    }
+}
+
+#[pyfunction(signature = (open, high, low, close, release_gil = false))]
+pub(crate) fn avgprice(
+    py: Python,
+    open: PyReadonlyArray1<Float>,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    release_gil: bool,
+) -> PyResult<Py<PyArray1<Float>>> {
+    let ohlcv = Ohlcv {
+        open: open.as_slice()?,
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+    price_transform(py, ohlcv, close.len(), release_gil, avgprice_into)
+}
+
+#[pyfunction(signature = (high, low, release_gil = false))]
+pub(crate) fn medprice(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    release_gil: bool,
+) -> PyResult<Py<PyArray1<Float>>> {
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        ..Default::default()
+    };
+    price_transform(py, ohlcv, high.len(), release_gil, medprice_into)
+}
+
+#[pyfunction(signature = (high, low, close, release_gil = false))]
+pub(crate) fn typprice(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    release_gil: bool,
+) -> PyResult<Py<PyArray1<Float>>> {
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+    price_transform(py, ohlcv, close.len(), release_gil, typprice_into)
+}
+
+#[pyfunction(signature = (high, low, close, release_gil = false))]
+pub(crate) fn wclprice(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    release_gil: bool,
+) -> PyResult<Py<PyArray1<Float>>> {
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+    price_transform(py, ohlcv, close.len(), release_gil, wclprice_into)
+}
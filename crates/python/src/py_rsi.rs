@@ -126,7 +126,7 @@ pub(crate) fn rsi(
             .allow_threads(|| rsi_into(input_slice, period, output.as_mut_slice()))
             .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
 
-        return Ok((output.into_pyarray(py).into(), rsi_state.into()));
+        Ok((output.into_pyarray(py).into(), rsi_state.into()))
     } else {
         let output_array = PyArray1::<Float>::zeros(py, [len], false);
         let output_slice = unsafe { output_array.as_slice_mut()? };
@@ -134,7 +134,7 @@ pub(crate) fn rsi(
         let rsi_state = rsi_into(input_slice, period, output_slice)
             .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
 
-        return Ok((output_array.into(), rsi_state.into()));
+        Ok((output_array.into(), rsi_state.into()))
     }
 }
 
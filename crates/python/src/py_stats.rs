@@ -0,0 +1,607 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::stats::kurtosis::{kurtosis_into, KurtosisState};
+use techalib::indicators::stats::median::{median_into, MedianState};
+use techalib::indicators::stats::percent_rank::{percent_rank_into, PercentRankState};
+use techalib::indicators::stats::skew::{skew_into, SkewState};
+use techalib::indicators::stats::zscore::{zscore_into, ZscoreState};
+use techalib::indicators::stats::Moments;
+use techalib::traits::State;
+use techalib::types::Float;
+
+#[pyclass(name = "ZscoreState")]
+#[derive(Debug, Clone)]
+pub struct PyZscoreState {
+    #[pyo3(get)]
+    pub zscore: Float,
+    #[pyo3(get)]
+    pub mean: Float,
+    #[pyo3(get)]
+    pub m2: Float,
+    #[pyo3(get)]
+    pub m3: Float,
+    #[pyo3(get)]
+    pub m4: Float,
+    #[pyo3(get)]
+    pub window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+}
+
+#[pymethods]
+impl PyZscoreState {
+    #[new]
+    pub fn new(
+        zscore: Float,
+        mean: Float,
+        m2: Float,
+        m3: Float,
+        m4: Float,
+        window: Vec<Float>,
+        period: usize,
+    ) -> Self {
+        PyZscoreState {
+            zscore,
+            mean,
+            m2,
+            m3,
+            m4,
+            window,
+            period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ZscoreState(zscore: {}, mean: {}, m2: {}, m3: {}, m4: {}, window: {:?}, period: {})",
+            self.zscore, self.mean, self.m2, self.m3, self.m4, self.window, self.period
+        )
+    }
+}
+
+impl From<ZscoreState> for PyZscoreState {
+    fn from(state: ZscoreState) -> Self {
+        PyZscoreState {
+            zscore: state.zscore,
+            mean: state.moments.mean,
+            m2: state.moments.m2,
+            m3: state.moments.m3,
+            m4: state.moments.m4,
+            window: state.last_window.into(),
+            period: state.period,
+        }
+    }
+}
+
+impl From<PyZscoreState> for ZscoreState {
+    fn from(py_state: PyZscoreState) -> Self {
+        ZscoreState {
+            zscore: py_state.zscore,
+            moments: Moments {
+                mean: py_state.mean,
+                m2: py_state.m2,
+                m3: py_state.m3,
+                m4: py_state.m4,
+            },
+            last_window: py_state.window.into(),
+            period: py_state.period,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 20, release_gil = false))]
+pub(crate) fn zscore(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyZscoreState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| zscore_into(input_slice, period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = zscore_into(input_slice, period, output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, zscore_state))]
+pub(crate) fn zscore_next(
+    new_value: Float,
+    zscore_state: PyZscoreState,
+) -> PyResult<PyZscoreState> {
+    let mut state: ZscoreState = zscore_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
+
+#[pyclass(name = "PercentRankState")]
+#[derive(Debug, Clone)]
+pub struct PyPercentRankState {
+    #[pyo3(get)]
+    pub percent_rank: Float,
+    #[pyo3(get)]
+    pub sorted_window: Vec<Float>,
+    #[pyo3(get)]
+    pub window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+}
+
+#[pymethods]
+impl PyPercentRankState {
+    #[new]
+    pub fn new(
+        percent_rank: Float,
+        sorted_window: Vec<Float>,
+        window: Vec<Float>,
+        period: usize,
+    ) -> Self {
+        PyPercentRankState {
+            percent_rank,
+            sorted_window,
+            window,
+            period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "PercentRankState(percent_rank: {}, sorted_window: {:?}, window: {:?}, period: {})",
+            self.percent_rank, self.sorted_window, self.window, self.period
+        )
+    }
+}
+
+impl From<PercentRankState> for PyPercentRankState {
+    fn from(state: PercentRankState) -> Self {
+        PyPercentRankState {
+            percent_rank: state.percent_rank,
+            sorted_window: state.sorted_window,
+            window: state.last_window.into(),
+            period: state.period,
+        }
+    }
+}
+
+impl From<PyPercentRankState> for PercentRankState {
+    fn from(py_state: PyPercentRankState) -> Self {
+        PercentRankState {
+            percent_rank: py_state.percent_rank,
+            sorted_window: py_state.sorted_window,
+            last_window: py_state.window.into(),
+            period: py_state.period,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 20, release_gil = false))]
+pub(crate) fn percent_rank(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyPercentRankState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| percent_rank_into(input_slice, period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = percent_rank_into(input_slice, period, output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, percent_rank_state))]
+pub(crate) fn percent_rank_next(
+    new_value: Float,
+    percent_rank_state: PyPercentRankState,
+) -> PyResult<PyPercentRankState> {
+    let mut state: PercentRankState = percent_rank_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
+
+#[pyclass(name = "SkewState")]
+#[derive(Debug, Clone)]
+pub struct PySkewState {
+    #[pyo3(get)]
+    pub skew: Float,
+    #[pyo3(get)]
+    pub mean: Float,
+    #[pyo3(get)]
+    pub m2: Float,
+    #[pyo3(get)]
+    pub m3: Float,
+    #[pyo3(get)]
+    pub m4: Float,
+    #[pyo3(get)]
+    pub window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+}
+
+#[pymethods]
+impl PySkewState {
+    #[new]
+    pub fn new(
+        skew: Float,
+        mean: Float,
+        m2: Float,
+        m3: Float,
+        m4: Float,
+        window: Vec<Float>,
+        period: usize,
+    ) -> Self {
+        PySkewState {
+            skew,
+            mean,
+            m2,
+            m3,
+            m4,
+            window,
+            period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "SkewState(skew: {}, mean: {}, m2: {}, m3: {}, m4: {}, window: {:?}, period: {})",
+            self.skew, self.mean, self.m2, self.m3, self.m4, self.window, self.period
+        )
+    }
+}
+
+impl From<SkewState> for PySkewState {
+    fn from(state: SkewState) -> Self {
+        PySkewState {
+            skew: state.skew,
+            mean: state.moments.mean,
+            m2: state.moments.m2,
+            m3: state.moments.m3,
+            m4: state.moments.m4,
+            window: state.last_window.into(),
+            period: state.period,
+        }
+    }
+}
+
+impl From<PySkewState> for SkewState {
+    fn from(py_state: PySkewState) -> Self {
+        SkewState {
+            skew: py_state.skew,
+            moments: Moments {
+                mean: py_state.mean,
+                m2: py_state.m2,
+                m3: py_state.m3,
+                m4: py_state.m4,
+            },
+            last_window: py_state.window.into(),
+            period: py_state.period,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 20, release_gil = false))]
+pub(crate) fn skew(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PySkewState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| skew_into(input_slice, period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = skew_into(input_slice, period, output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, skew_state))]
+pub(crate) fn skew_next(new_value: Float, skew_state: PySkewState) -> PyResult<PySkewState> {
+    let mut state: SkewState = skew_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
+
+#[pyclass(name = "KurtosisState")]
+#[derive(Debug, Clone)]
+pub struct PyKurtosisState {
+    #[pyo3(get)]
+    pub kurtosis: Float,
+    #[pyo3(get)]
+    pub mean: Float,
+    #[pyo3(get)]
+    pub m2: Float,
+    #[pyo3(get)]
+    pub m3: Float,
+    #[pyo3(get)]
+    pub m4: Float,
+    #[pyo3(get)]
+    pub window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+}
+
+#[pymethods]
+impl PyKurtosisState {
+    #[new]
+    pub fn new(
+        kurtosis: Float,
+        mean: Float,
+        m2: Float,
+        m3: Float,
+        m4: Float,
+        window: Vec<Float>,
+        period: usize,
+    ) -> Self {
+        PyKurtosisState {
+            kurtosis,
+            mean,
+            m2,
+            m3,
+            m4,
+            window,
+            period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "KurtosisState(kurtosis: {}, mean: {}, m2: {}, m3: {}, m4: {}, window: {:?}, period: {})",
+            self.kurtosis, self.mean, self.m2, self.m3, self.m4, self.window, self.period
+        )
+    }
+}
+
+impl From<KurtosisState> for PyKurtosisState {
+    fn from(state: KurtosisState) -> Self {
+        PyKurtosisState {
+            kurtosis: state.kurtosis,
+            mean: state.moments.mean,
+            m2: state.moments.m2,
+            m3: state.moments.m3,
+            m4: state.moments.m4,
+            window: state.last_window.into(),
+            period: state.period,
+        }
+    }
+}
+
+impl From<PyKurtosisState> for KurtosisState {
+    fn from(py_state: PyKurtosisState) -> Self {
+        KurtosisState {
+            kurtosis: py_state.kurtosis,
+            moments: Moments {
+                mean: py_state.mean,
+                m2: py_state.m2,
+                m3: py_state.m3,
+                m4: py_state.m4,
+            },
+            last_window: py_state.window.into(),
+            period: py_state.period,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 20, release_gil = false))]
+pub(crate) fn kurtosis(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyKurtosisState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| kurtosis_into(input_slice, period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = kurtosis_into(input_slice, period, output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, kurtosis_state))]
+pub(crate) fn kurtosis_next(
+    new_value: Float,
+    kurtosis_state: PyKurtosisState,
+) -> PyResult<PyKurtosisState> {
+    let mut state: KurtosisState = kurtosis_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
+
+#[pyclass(name = "MedianState")]
+#[derive(Debug, Clone)]
+pub struct PyMedianState {
+    #[pyo3(get)]
+    pub median: Float,
+    #[pyo3(get)]
+    pub sorted_window: Vec<Float>,
+    #[pyo3(get)]
+    pub window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+}
+
+#[pymethods]
+impl PyMedianState {
+    #[new]
+    pub fn new(
+        median: Float,
+        sorted_window: Vec<Float>,
+        window: Vec<Float>,
+        period: usize,
+    ) -> Self {
+        PyMedianState {
+            median,
+            sorted_window,
+            window,
+            period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "MedianState(median: {}, sorted_window: {:?}, window: {:?}, period: {})",
+            self.median, self.sorted_window, self.window, self.period
+        )
+    }
+}
+
+impl From<MedianState> for PyMedianState {
+    fn from(state: MedianState) -> Self {
+        PyMedianState {
+            median: state.median,
+            sorted_window: state.sorted_window,
+            window: state.last_window.into(),
+            period: state.period,
+        }
+    }
+}
+
+impl From<PyMedianState> for MedianState {
+    fn from(py_state: PyMedianState) -> Self {
+        MedianState {
+            median: py_state.median,
+            sorted_window: py_state.sorted_window,
+            last_window: py_state.window.into(),
+            period: py_state.period,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 20, release_gil = false))]
+pub(crate) fn median(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyMedianState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| median_into(input_slice, period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = median_into(input_slice, period, output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, median_state))]
+pub(crate) fn median_next(
+    new_value: Float,
+    median_state: PyMedianState,
+) -> PyResult<PyMedianState> {
+    let mut state: MedianState = median_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
@@ -0,0 +1,211 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::atr::AtrState;
+use techalib::indicators::supertrend::{supertrend_into, SupertrendState};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "SupertrendState")]
+#[derive(Debug, Clone)]
+pub struct PySupertrendState {
+    #[pyo3(get)]
+    pub supertrend: Float,
+    #[pyo3(get)]
+    pub direction: Float,
+    #[pyo3(get)]
+    pub final_upper: Float,
+    #[pyo3(get)]
+    pub final_lower: Float,
+    #[pyo3(get)]
+    pub atr: Float,
+    #[pyo3(get)]
+    pub prev_close: Float,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub multiplier: Float,
+}
+
+#[pymethods]
+impl PySupertrendState {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        supertrend: Float,
+        direction: Float,
+        final_upper: Float,
+        final_lower: Float,
+        atr: Float,
+        prev_close: Float,
+        period: usize,
+        multiplier: Float,
+    ) -> Self {
+        PySupertrendState {
+            supertrend,
+            direction,
+            final_upper,
+            final_lower,
+            atr,
+            prev_close,
+            period,
+            multiplier,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "SupertrendState(supertrend: {}, direction: {}, final_upper: {}, final_lower: {}, atr: {}, prev_close: {}, period: {}, multiplier: {})",
+            self.supertrend, self.direction, self.final_upper, self.final_lower, self.atr, self.prev_close, self.period, self.multiplier
+        )
+    }
+}
+
+impl From<SupertrendState> for PySupertrendState {
+    fn from(state: SupertrendState) -> Self {
+        PySupertrendState {
+            supertrend: state.supertrend,
+            direction: state.direction,
+            final_upper: state.final_upper,
+            final_lower: state.final_lower,
+            atr: state.atr.atr,
+            prev_close: state.atr.prev_close,
+            period: state.atr.period,
+            multiplier: state.multiplier,
+        }
+    }
+}
+
+impl From<PySupertrendState> for SupertrendState {
+    fn from(py_state: PySupertrendState) -> Self {
+        SupertrendState {
+            supertrend: py_state.supertrend,
+            direction: py_state.direction,
+            final_upper: py_state.final_upper,
+            final_lower: py_state.final_lower,
+            atr: AtrState {
+                atr: py_state.atr,
+                prev_close: py_state.prev_close,
+                period: py_state.period,
+            },
+            multiplier: py_state.multiplier,
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, close, period = 10, multiplier = 3.0, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn supertrend(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    period: usize,
+    multiplier: Float,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, Py<PyArray1<Float>>, PySupertrendState)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output_supertrend = vec![0.0; len];
+        let mut output_direction = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                supertrend_into(
+                    &ohlcv,
+                    period,
+                    multiplier,
+                    output_supertrend.as_mut_slice(),
+                    output_direction.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_supertrend.into_pyarray(py).into(),
+            output_direction.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_supertrend = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_supertrend_slice = unsafe { py_out_supertrend.as_slice_mut()? };
+
+        let py_out_direction = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_direction_slice = unsafe { py_out_direction.as_slice_mut()? };
+
+        let state = supertrend_into(
+            &ohlcv,
+            period,
+            multiplier,
+            py_out_supertrend_slice,
+            py_out_direction_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_supertrend.into(),
+            py_out_direction.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, new_close, supertrend_state))]
+pub(crate) fn supertrend_next(
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    supertrend_state: PySupertrendState,
+) -> PyResult<PySupertrendState> {
+    let mut supertrend_state: SupertrendState = supertrend_state.into();
+    supertrend_state
+        .update((new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(supertrend_state.into())
+}
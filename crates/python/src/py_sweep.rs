@@ -0,0 +1,180 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::npyffi::NPY_ORDER;
+use numpy::{IntoPyArray, PyArray2, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyfunction, Py, PyResult, Python};
+use techalib::indicators::bbands::{bbands_sweep_into, DeviationMulipliers};
+use techalib::indicators::ema::ema_sweep_into;
+use techalib::indicators::rsi::rsi_sweep_into;
+use techalib::indicators::sma::sma_sweep_into;
+use techalib::types::Float;
+
+use crate::py_bbands::{PyBBandsMA, PyBBandsState};
+use crate::py_ema::PyEmaState;
+use crate::py_rsi::PyRsiState;
+use crate::py_sma::PySmaState;
+
+fn into_pyarray2(
+    py: Python,
+    values: Vec<Float>,
+    len: usize,
+    periods: usize,
+) -> PyResult<Py<PyArray2<Float>>> {
+    Ok(values
+        .into_pyarray(py)
+        .reshape_with_order([len, periods], NPY_ORDER::NPY_FORTRANORDER)?
+        .into())
+}
+
+macro_rules! py_sweep {
+    ($name:ident, $sweep_into:ident, $py_state:ident) => {
+        #[pyfunction(signature = (data, periods, release_gil = false))]
+        pub(crate) fn $name(
+            py: Python,
+            data: PyReadonlyArray1<Float>,
+            periods: Vec<usize>,
+            release_gil: bool,
+        ) -> PyResult<(Py<PyArray2<Float>>, Vec<$py_state>)> {
+            let len = data.len();
+            let slice = data.as_slice()?;
+
+            if release_gil {
+                let mut output = vec![0.0; len * periods.len()];
+                let states = py
+                    .allow_threads(|| $sweep_into(slice, &periods, output.as_mut_slice()))
+                    .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+                Ok((
+                    into_pyarray2(py, output, len, periods.len())?,
+                    states.into_iter().map(Into::into).collect(),
+                ))
+            } else {
+                let py_array_out = PyArray2::<Float>::zeros(py, [len, periods.len()], true);
+                let py_array_ptr = unsafe { py_array_out.as_slice_mut()? };
+
+                let states = $sweep_into(slice, &periods, py_array_ptr)
+                    .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+                Ok((
+                    py_array_out.into(),
+                    states.into_iter().map(Into::into).collect(),
+                ))
+            }
+        }
+    };
+}
+
+py_sweep!(sma_sweep, sma_sweep_into, PySmaState);
+py_sweep!(ema_sweep, ema_sweep_into, PyEmaState);
+py_sweep!(rsi_sweep, rsi_sweep_into, PyRsiState);
+
+#[pyfunction(signature = (data, periods, std_up = 2.0, std_down = 2.0, ma_type = PyBBandsMA::SMA, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn bbands_sweep(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    periods: Vec<usize>,
+    std_up: Float,
+    std_down: Float,
+    ma_type: PyBBandsMA,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray2<Float>>,
+    Py<PyArray2<Float>>,
+    Py<PyArray2<Float>>,
+    Vec<PyBBandsState>,
+)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+    let std_dev_mul = DeviationMulipliers {
+        up: std_up,
+        down: std_down,
+    };
+
+    if release_gil {
+        let mut output_upper = vec![0.0; len * periods.len()];
+        let mut output_middle = vec![0.0; len * periods.len()];
+        let mut output_lower = vec![0.0; len * periods.len()];
+
+        let states = py
+            .allow_threads(|| {
+                bbands_sweep_into(
+                    input_slice,
+                    &periods,
+                    std_dev_mul,
+                    ma_type.into(),
+                    output_upper.as_mut_slice(),
+                    output_middle.as_mut_slice(),
+                    output_lower.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            into_pyarray2(py, output_upper, len, periods.len())?,
+            into_pyarray2(py, output_middle, len, periods.len())?,
+            into_pyarray2(py, output_lower, len, periods.len())?,
+            states.into_iter().map(Into::into).collect(),
+        ))
+    } else {
+        let py_out_upper = PyArray2::<Float>::zeros(py, [len, periods.len()], true);
+        let py_out_upper_slice = unsafe { py_out_upper.as_slice_mut()? };
+
+        let py_out_middle = PyArray2::<Float>::zeros(py, [len, periods.len()], true);
+        let py_out_middle_slice = unsafe { py_out_middle.as_slice_mut()? };
+
+        let py_out_lower = PyArray2::<Float>::zeros(py, [len, periods.len()], true);
+        let py_out_lower_slice = unsafe { py_out_lower.as_slice_mut()? };
+
+        let states = bbands_sweep_into(
+            input_slice,
+            &periods,
+            std_dev_mul,
+            ma_type.into(),
+            py_out_upper_slice,
+            py_out_middle_slice,
+            py_out_lower_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_upper.into(),
+            py_out_middle.into(),
+            py_out_lower.into(),
+            states.into_iter().map(Into::into).collect(),
+        ))
+    }
+}
@@ -0,0 +1,192 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::swing::{swing_into, SwingState};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "SwingState")]
+#[derive(Debug, Clone)]
+pub struct PySwingState {
+    #[pyo3(get)]
+    pub confirmed_high: Float,
+    #[pyo3(get)]
+    pub confirmed_low: Float,
+    #[pyo3(get)]
+    pub high_window: Vec<Float>,
+    #[pyo3(get)]
+    pub low_window: Vec<Float>,
+    #[pyo3(get)]
+    pub left: usize,
+    #[pyo3(get)]
+    pub right: usize,
+}
+
+#[pymethods]
+impl PySwingState {
+    #[new]
+    pub fn new(
+        confirmed_high: Float,
+        confirmed_low: Float,
+        high_window: Vec<Float>,
+        low_window: Vec<Float>,
+        left: usize,
+        right: usize,
+    ) -> Self {
+        PySwingState {
+            confirmed_high,
+            confirmed_low,
+            high_window,
+            low_window,
+            left,
+            right,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "SwingState(confirmed_high: {}, confirmed_low: {}, high_window: {:?}, low_window: {:?}, left: {}, right: {})",
+            self.confirmed_high, self.confirmed_low, self.high_window, self.low_window, self.left, self.right
+        )
+    }
+}
+
+impl From<SwingState> for PySwingState {
+    fn from(state: SwingState) -> Self {
+        PySwingState {
+            confirmed_high: state.confirmed_high,
+            confirmed_low: state.confirmed_low,
+            high_window: state.high_window.into(),
+            low_window: state.low_window.into(),
+            left: state.left,
+            right: state.right,
+        }
+    }
+}
+
+impl From<PySwingState> for SwingState {
+    fn from(py_state: PySwingState) -> Self {
+        SwingState {
+            confirmed_high: py_state.confirmed_high,
+            confirmed_low: py_state.confirmed_low,
+            high_window: py_state.high_window.into(),
+            low_window: py_state.low_window.into(),
+            left: py_state.left,
+            right: py_state.right,
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, left = 2, right = 2, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn swing(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    left: usize,
+    right: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, Py<PyArray1<Float>>, PySwingState)> {
+    let len = high.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output_swing_high = vec![0.0; len];
+        let mut output_swing_low = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                swing_into(
+                    &ohlcv,
+                    left,
+                    right,
+                    output_swing_high.as_mut_slice(),
+                    output_swing_low.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_swing_high.into_pyarray(py).into(),
+            output_swing_low.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_swing_high = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_swing_high_slice = unsafe { py_out_swing_high.as_slice_mut()? };
+
+        let py_out_swing_low = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_swing_low_slice = unsafe { py_out_swing_low.as_slice_mut()? };
+
+        let state = swing_into(
+            &ohlcv,
+            left,
+            right,
+            py_out_swing_high_slice,
+            py_out_swing_low_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_swing_high.into(),
+            py_out_swing_low.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, swing_state))]
+pub(crate) fn swing_next(
+    new_high: Float,
+    new_low: Float,
+    swing_state: PySwingState,
+) -> PyResult<PySwingState> {
+    let mut swing_state: SwingState = swing_state.into();
+    swing_state
+        .update((new_high, new_low))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(swing_state.into())
+}
@@ -77,6 +77,7 @@ pub struct PyT3State {
 #[pymethods]
 impl PyT3State {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         t3: Float,
         ema1: Float,
@@ -0,0 +1,155 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::vidya::{vidya_into, VidyaState, DEFAULT_CMO_PERIOD};
+use techalib::traits::State;
+use techalib::types::Float;
+
+#[pyclass(name = "VidyaState")]
+#[derive(Debug, Clone)]
+pub struct PyVidyaState {
+    #[pyo3(get)]
+    pub vidya: Float,
+    #[pyo3(get)]
+    pub up_sum: Float,
+    #[pyo3(get)]
+    pub down_sum: Float,
+    #[pyo3(get)]
+    pub prev_value: Float,
+    #[pyo3(get)]
+    pub changes: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub cmo_period: usize,
+}
+#[pymethods]
+impl PyVidyaState {
+    #[new]
+    pub fn new(
+        vidya: Float,
+        up_sum: Float,
+        down_sum: Float,
+        prev_value: Float,
+        changes: Vec<Float>,
+        period: usize,
+        cmo_period: usize,
+    ) -> Self {
+        PyVidyaState {
+            vidya,
+            up_sum,
+            down_sum,
+            prev_value,
+            changes,
+            period,
+            cmo_period,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "VidyaState(vidya={}, up_sum={}, down_sum={}, prev_value={}, changes={:?}, period={}, cmo_period={})",
+            self.vidya, self.up_sum, self.down_sum, self.prev_value, self.changes, self.period, self.cmo_period
+        )
+    }
+}
+impl From<VidyaState> for PyVidyaState {
+    fn from(state: VidyaState) -> Self {
+        PyVidyaState {
+            vidya: state.vidya,
+            up_sum: state.up_sum,
+            down_sum: state.down_sum,
+            prev_value: state.prev_value,
+            changes: state.last_changes.into(),
+            period: state.period,
+            cmo_period: state.cmo_period,
+        }
+    }
+}
+
+impl From<PyVidyaState> for VidyaState {
+    fn from(py_state: PyVidyaState) -> Self {
+        VidyaState {
+            vidya: py_state.vidya,
+            up_sum: py_state.up_sum,
+            down_sum: py_state.down_sum,
+            prev_value: py_state.prev_value,
+            last_changes: py_state.changes.into(),
+            period: py_state.period,
+            cmo_period: py_state.cmo_period,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 14, cmo_period = DEFAULT_CMO_PERIOD, release_gil = false))]
+pub(crate) fn vidya(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    cmo_period: usize,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyVidyaState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| vidya_into(input_slice, period, cmo_period, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = vidya_into(input_slice, period, cmo_period, output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, vidya_state))]
+pub(crate) fn vidya_next(new_value: Float, vidya_state: PyVidyaState) -> PyResult<PyVidyaState> {
+    let mut state: VidyaState = vidya_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
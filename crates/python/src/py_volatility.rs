@@ -0,0 +1,223 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::volatility::{
+    volatility_into, VolatilityEstimator, VolatilityState, DEFAULT_ANNUALIZATION,
+};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "VolatilityEstimator")]
+#[derive(Debug, Clone, Copy)]
+pub enum PyVolatilityEstimator {
+    CloseToClose,
+    Parkinson,
+    GarmanKlass,
+    RogersSatchell,
+    YangZhang,
+}
+
+impl From<PyVolatilityEstimator> for VolatilityEstimator {
+    fn from(py_estimator: PyVolatilityEstimator) -> Self {
+        match py_estimator {
+            PyVolatilityEstimator::CloseToClose => VolatilityEstimator::CloseToClose,
+            PyVolatilityEstimator::Parkinson => VolatilityEstimator::Parkinson,
+            PyVolatilityEstimator::GarmanKlass => VolatilityEstimator::GarmanKlass,
+            PyVolatilityEstimator::RogersSatchell => VolatilityEstimator::RogersSatchell,
+            PyVolatilityEstimator::YangZhang => VolatilityEstimator::YangZhang,
+        }
+    }
+}
+
+impl From<VolatilityEstimator> for PyVolatilityEstimator {
+    fn from(estimator: VolatilityEstimator) -> Self {
+        match estimator {
+            VolatilityEstimator::CloseToClose => PyVolatilityEstimator::CloseToClose,
+            VolatilityEstimator::Parkinson => PyVolatilityEstimator::Parkinson,
+            VolatilityEstimator::GarmanKlass => PyVolatilityEstimator::GarmanKlass,
+            VolatilityEstimator::RogersSatchell => PyVolatilityEstimator::RogersSatchell,
+            VolatilityEstimator::YangZhang => PyVolatilityEstimator::YangZhang,
+        }
+    }
+}
+
+#[pyclass(name = "VolatilityState")]
+#[derive(Debug, Clone)]
+pub struct PyVolatilityState {
+    #[pyo3(get)]
+    pub volatility: Float,
+    #[pyo3(get)]
+    pub prev_close: Float,
+    #[pyo3(get)]
+    pub window: Vec<[Float; 3]>,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub estimator: PyVolatilityEstimator,
+    #[pyo3(get)]
+    pub annualization: Float,
+}
+
+#[pymethods]
+impl PyVolatilityState {
+    #[new]
+    pub fn new(
+        volatility: Float,
+        prev_close: Float,
+        window: Vec<[Float; 3]>,
+        period: usize,
+        estimator: PyVolatilityEstimator,
+        annualization: Float,
+    ) -> Self {
+        PyVolatilityState {
+            volatility,
+            prev_close,
+            window,
+            period,
+            estimator,
+            annualization,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "VolatilityState(volatility: {}, prev_close: {}, window: {:?}, period: {}, estimator: {:?}, annualization: {})",
+            self.volatility, self.prev_close, self.window, self.period, self.estimator, self.annualization
+        )
+    }
+}
+
+impl From<VolatilityState> for PyVolatilityState {
+    fn from(state: VolatilityState) -> Self {
+        PyVolatilityState {
+            volatility: state.volatility,
+            prev_close: state.prev_close,
+            window: state.last_window.into(),
+            period: state.period,
+            estimator: state.estimator.into(),
+            annualization: state.annualization,
+        }
+    }
+}
+
+impl From<PyVolatilityState> for VolatilityState {
+    fn from(py_state: PyVolatilityState) -> Self {
+        VolatilityState {
+            volatility: py_state.volatility,
+            prev_close: py_state.prev_close,
+            last_window: py_state.window.into(),
+            period: py_state.period,
+            estimator: py_state.estimator.into(),
+            annualization: py_state.annualization,
+        }
+    }
+}
+
+#[pyfunction(signature = (open, high, low, close, period = 20, estimator = PyVolatilityEstimator::CloseToClose, annualization = DEFAULT_ANNUALIZATION, release_gil = false))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn volatility(
+    py: Python,
+    open: PyReadonlyArray1<Float>,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: PyReadonlyArray1<Float>,
+    period: usize,
+    estimator: PyVolatilityEstimator,
+    annualization: Float,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyVolatilityState)> {
+    let len = close.len();
+    let ohlcv = Ohlcv {
+        open: open.as_slice()?,
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: close.as_slice()?,
+        ..Default::default()
+    };
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                volatility_into(
+                    &ohlcv,
+                    period,
+                    estimator.into(),
+                    annualization,
+                    output.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let py_out = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_slice = unsafe { py_out.as_slice_mut()? };
+
+        let state = volatility_into(
+            &ohlcv,
+            period,
+            estimator.into(),
+            annualization,
+            py_out_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((py_out.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_open, new_high, new_low, new_close, volatility_state))]
+pub(crate) fn volatility_next(
+    new_open: Float,
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    volatility_state: PyVolatilityState,
+) -> PyResult<PyVolatilityState> {
+    let mut volatility_state: VolatilityState = volatility_state.into();
+    volatility_state
+        .update((new_open, new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(volatility_state.into())
+}
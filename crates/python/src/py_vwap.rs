@@ -0,0 +1,219 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::vwap::{vwap_into, VwapReset, VwapState};
+use techalib::traits::State;
+use techalib::types::Float;
+
+#[pyclass(name = "VwapState")]
+#[derive(Debug, Clone)]
+pub struct PyVwapState {
+    #[pyo3(get)]
+    pub vwap: Float,
+    #[pyo3(get)]
+    pub upper: Float,
+    #[pyo3(get)]
+    pub lower: Float,
+    #[pyo3(get)]
+    pub cum_volume: Float,
+    #[pyo3(get)]
+    pub m2: Float,
+    #[pyo3(get)]
+    pub multiplier: Float,
+}
+
+#[pymethods]
+impl PyVwapState {
+    #[new]
+    pub fn new(
+        vwap: Float,
+        upper: Float,
+        lower: Float,
+        cum_volume: Float,
+        m2: Float,
+        multiplier: Float,
+    ) -> Self {
+        PyVwapState {
+            vwap,
+            upper,
+            lower,
+            cum_volume,
+            m2,
+            multiplier,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "VwapState(vwap: {}, upper: {}, lower: {}, cum_volume: {}, m2: {}, multiplier: {})",
+            self.vwap, self.upper, self.lower, self.cum_volume, self.m2, self.multiplier
+        )
+    }
+}
+
+impl From<VwapState> for PyVwapState {
+    fn from(state: VwapState) -> Self {
+        PyVwapState {
+            vwap: state.vwap,
+            upper: state.upper,
+            lower: state.lower,
+            cum_volume: state.cum_volume,
+            m2: state.m2,
+            multiplier: state.multiplier,
+        }
+    }
+}
+
+impl From<PyVwapState> for VwapState {
+    fn from(py_state: PyVwapState) -> Self {
+        VwapState {
+            vwap: py_state.vwap,
+            upper: py_state.upper,
+            lower: py_state.lower,
+            cum_volume: py_state.cum_volume,
+            m2: py_state.m2,
+            multiplier: py_state.multiplier,
+        }
+    }
+}
+
+#[pyfunction(signature = (price, volume, reset_mask = None, anchors = None, multiplier = 2.0, release_gil = false))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn vwap(
+    py: Python,
+    price: PyReadonlyArray1<Float>,
+    volume: PyReadonlyArray1<Float>,
+    reset_mask: Option<PyReadonlyArray1<bool>>,
+    anchors: Option<Vec<usize>>,
+    multiplier: Float,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyVwapState,
+)> {
+    let len = price.len();
+    let price_slice = price.as_slice()?;
+    let volume_slice = volume.as_slice()?;
+    let reset = match (&reset_mask, &anchors) {
+        (Some(_), Some(_)) => {
+            return Err(PyValueError::new_err(
+                "reset_mask and anchors cannot be used together",
+            ))
+        }
+        (Some(mask), None) => VwapReset::Mask(mask.as_slice()?),
+        (None, Some(anchors)) => VwapReset::Anchors(anchors),
+        (None, None) => VwapReset::Never,
+    };
+
+    if release_gil {
+        let mut output_vwap = vec![0.0; len];
+        let mut output_upper = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                vwap_into(
+                    price_slice,
+                    volume_slice,
+                    reset,
+                    multiplier,
+                    output_vwap.as_mut_slice(),
+                    output_upper.as_mut_slice(),
+                    output_lower.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_vwap.into_pyarray(py).into(),
+            output_upper.into_pyarray(py).into(),
+            output_lower.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_vwap = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_vwap_slice = unsafe { py_out_vwap.as_slice_mut()? };
+
+        let py_out_upper = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_upper_slice = unsafe { py_out_upper.as_slice_mut()? };
+
+        let py_out_lower = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_lower_slice = unsafe { py_out_lower.as_slice_mut()? };
+
+        let state = vwap_into(
+            price_slice,
+            volume_slice,
+            reset,
+            multiplier,
+            py_out_vwap_slice,
+            py_out_upper_slice,
+            py_out_lower_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_vwap.into(),
+            py_out_upper.into(),
+            py_out_lower.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_price, new_volume, vwap_state, reset = false))]
+pub(crate) fn vwap_next(
+    new_price: Float,
+    new_volume: Float,
+    vwap_state: PyVwapState,
+    reset: bool,
+) -> PyResult<PyVwapState> {
+    let mut vwap_state: VwapState = vwap_state.into();
+    if reset {
+        vwap_state.reset();
+    }
+    vwap_state
+        .update((new_price, new_volume))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(vwap_state.into())
+}
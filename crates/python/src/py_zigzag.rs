@@ -0,0 +1,311 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::atr::AtrState;
+use techalib::indicators::zigzag::{zigzag_into, ZigzagState, ZigzagThreshold};
+use techalib::traits::State;
+use techalib::types::{Float, Ohlcv};
+
+#[pyclass(name = "ZigzagState")]
+#[derive(Debug, Clone)]
+pub struct PyZigzagState {
+    #[pyo3(get)]
+    pub direction: Float,
+    #[pyo3(get)]
+    pub confirmed: Float,
+    #[pyo3(get)]
+    pub last_pivot: Float,
+    #[pyo3(get)]
+    pub last_pivot_idx: usize,
+    #[pyo3(get)]
+    pub extreme: Float,
+    #[pyo3(get)]
+    pub extreme_idx: usize,
+    #[pyo3(get)]
+    pub max_high: Float,
+    #[pyo3(get)]
+    pub max_high_idx: usize,
+    #[pyo3(get)]
+    pub min_low: Float,
+    #[pyo3(get)]
+    pub min_low_idx: usize,
+    #[pyo3(get)]
+    pub idx: usize,
+    #[pyo3(get)]
+    pub atr: Option<Float>,
+    #[pyo3(get)]
+    pub prev_close: Option<Float>,
+    #[pyo3(get)]
+    pub percent: Option<Float>,
+    #[pyo3(get)]
+    pub atr_period: Option<usize>,
+    #[pyo3(get)]
+    pub atr_multiplier: Option<Float>,
+}
+
+#[pymethods]
+impl PyZigzagState {
+    #[new]
+    #[pyo3(signature = (direction, confirmed, last_pivot, last_pivot_idx, extreme, extreme_idx, max_high, max_high_idx, min_low, min_low_idx, idx, atr = None, prev_close = None, percent = None, atr_period = None, atr_multiplier = None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        direction: Float,
+        confirmed: Float,
+        last_pivot: Float,
+        last_pivot_idx: usize,
+        extreme: Float,
+        extreme_idx: usize,
+        max_high: Float,
+        max_high_idx: usize,
+        min_low: Float,
+        min_low_idx: usize,
+        idx: usize,
+        atr: Option<Float>,
+        prev_close: Option<Float>,
+        percent: Option<Float>,
+        atr_period: Option<usize>,
+        atr_multiplier: Option<Float>,
+    ) -> Self {
+        PyZigzagState {
+            direction,
+            confirmed,
+            last_pivot,
+            last_pivot_idx,
+            extreme,
+            extreme_idx,
+            max_high,
+            max_high_idx,
+            min_low,
+            min_low_idx,
+            idx,
+            atr,
+            prev_close,
+            percent,
+            atr_period,
+            atr_multiplier,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ZigzagState(direction: {}, confirmed: {}, last_pivot: {}, last_pivot_idx: {}, extreme: {}, extreme_idx: {}, max_high: {}, max_high_idx: {}, min_low: {}, min_low_idx: {}, idx: {}, atr: {:?}, prev_close: {:?}, percent: {:?}, atr_period: {:?}, atr_multiplier: {:?})",
+            self.direction,
+            self.confirmed,
+            self.last_pivot,
+            self.last_pivot_idx,
+            self.extreme,
+            self.extreme_idx,
+            self.max_high,
+            self.max_high_idx,
+            self.min_low,
+            self.min_low_idx,
+            self.idx,
+            self.atr,
+            self.prev_close,
+            self.percent,
+            self.atr_period,
+            self.atr_multiplier
+        )
+    }
+}
+
+impl From<ZigzagState> for PyZigzagState {
+    fn from(state: ZigzagState) -> Self {
+        let (percent, atr_period, atr_multiplier) = match state.threshold {
+            ZigzagThreshold::Percent(percent) => (Some(percent), None, None),
+            ZigzagThreshold::Atr { period, multiplier } => (None, Some(period), Some(multiplier)),
+        };
+        PyZigzagState {
+            direction: state.direction,
+            confirmed: state.confirmed,
+            last_pivot: state.last_pivot,
+            last_pivot_idx: state.last_pivot_idx,
+            extreme: state.extreme,
+            extreme_idx: state.extreme_idx,
+            max_high: state.max_high,
+            max_high_idx: state.max_high_idx,
+            min_low: state.min_low,
+            min_low_idx: state.min_low_idx,
+            idx: state.idx,
+            atr: state.atr.map(|atr| atr.atr),
+            prev_close: state.atr.map(|atr| atr.prev_close),
+            percent,
+            atr_period,
+            atr_multiplier,
+        }
+    }
+}
+
+impl From<PyZigzagState> for ZigzagState {
+    fn from(py_state: PyZigzagState) -> Self {
+        let threshold = match py_state.atr_period {
+            Some(period) => ZigzagThreshold::Atr {
+                period,
+                multiplier: py_state.atr_multiplier.unwrap_or(Float::NAN),
+            },
+            None => ZigzagThreshold::Percent(py_state.percent.unwrap_or(Float::NAN)),
+        };
+        let atr = match (py_state.atr, py_state.prev_close, py_state.atr_period) {
+            (Some(atr), Some(prev_close), Some(period)) => Some(AtrState {
+                atr,
+                prev_close,
+                period,
+            }),
+            _ => None,
+        };
+        ZigzagState {
+            direction: py_state.direction,
+            confirmed: py_state.confirmed,
+            last_pivot: py_state.last_pivot,
+            last_pivot_idx: py_state.last_pivot_idx,
+            extreme: py_state.extreme,
+            extreme_idx: py_state.extreme_idx,
+            max_high: py_state.max_high,
+            max_high_idx: py_state.max_high_idx,
+            min_low: py_state.min_low,
+            min_low_idx: py_state.min_low_idx,
+            idx: py_state.idx,
+            atr,
+            threshold,
+        }
+    }
+}
+
+#[pyfunction(signature = (high, low, close = None, percent = 5.0, atr_period = None, atr_multiplier = 3.0, release_gil = false))]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn zigzag(
+    py: Python,
+    high: PyReadonlyArray1<Float>,
+    low: PyReadonlyArray1<Float>,
+    close: Option<PyReadonlyArray1<Float>>,
+    percent: Float,
+    atr_period: Option<usize>,
+    atr_multiplier: Float,
+    release_gil: bool,
+) -> PyResult<(
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    Py<PyArray1<Float>>,
+    PyZigzagState,
+)> {
+    let len = high.len();
+    let ohlcv = Ohlcv {
+        high: high.as_slice()?,
+        low: low.as_slice()?,
+        close: match close.as_ref() {
+            Some(close) => close.as_slice()?,
+            None => &[],
+        },
+        ..Default::default()
+    };
+    let threshold = match atr_period {
+        Some(period) => ZigzagThreshold::Atr {
+            period,
+            multiplier: atr_multiplier,
+        },
+        None => ZigzagThreshold::Percent(percent),
+    };
+
+    if release_gil {
+        let mut output_pivots = vec![0.0; len];
+        let mut output_confirmed = vec![0.0; len];
+        let mut output_direction = vec![0.0; len];
+
+        let state = py
+            .allow_threads(|| {
+                zigzag_into(
+                    &ohlcv,
+                    threshold,
+                    output_pivots.as_mut_slice(),
+                    output_confirmed.as_mut_slice(),
+                    output_direction.as_mut_slice(),
+                )
+            })
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            output_pivots.into_pyarray(py).into(),
+            output_confirmed.into_pyarray(py).into(),
+            output_direction.into_pyarray(py).into(),
+            state.into(),
+        ))
+    } else {
+        let py_out_pivots = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_pivots_slice = unsafe { py_out_pivots.as_slice_mut()? };
+
+        let py_out_confirmed = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_confirmed_slice = unsafe { py_out_confirmed.as_slice_mut()? };
+
+        let py_out_direction = PyArray1::<Float>::zeros(py, [len], false);
+        let py_out_direction_slice = unsafe { py_out_direction.as_slice_mut()? };
+
+        let state = zigzag_into(
+            &ohlcv,
+            threshold,
+            py_out_pivots_slice,
+            py_out_confirmed_slice,
+            py_out_direction_slice,
+        )
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+        Ok((
+            py_out_pivots.into(),
+            py_out_confirmed.into(),
+            py_out_direction.into(),
+            state.into(),
+        ))
+    }
+}
+
+#[pyfunction(signature = (new_high, new_low, new_close, zigzag_state))]
+pub(crate) fn zigzag_next(
+    new_high: Float,
+    new_low: Float,
+    new_close: Float,
+    zigzag_state: PyZigzagState,
+) -> PyResult<PyZigzagState> {
+    let mut zigzag_state: ZigzagState = zigzag_state.into();
+    zigzag_state
+        .update((new_high, new_low, new_close))
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+
+    Ok(zigzag_state.into())
+}
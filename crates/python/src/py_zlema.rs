@@ -0,0 +1,132 @@
+/*
+    BSD 3-Clause License
+
+    Copyright (c) 2025, Guillaume GOBIN (Guitheg)
+
+    Redistribution and use in source and binary forms, with or without modification,
+    are permitted provided that the following conditions are met:
+
+    1. Redistributions of source code must retain the above copyright notice,
+    this list of conditions and the following disclaimer.
+
+    2. Redistributions in binary form must reproduce the above copyright notice,
+    this list of conditions and the following disclaimer in the documentation and/or
+    other materials provided with the distribution.
+
+    3. Neither the name of the copyright holder nor the names of its contributors
+    may be used to endorse or promote products derived from this software without
+    specific prior written permission.
+
+    THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+    AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+    WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+    DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+    FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+    DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+    SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+    CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+    OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF
+    THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+/*
+    List of contributors:
+    - Guitheg: Initial implementation
+*/
+
+use numpy::{IntoPyArray, PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::{exceptions::PyValueError, pyclass, pyfunction, pymethods, Py, PyResult, Python};
+use techalib::indicators::zlema::{zlema_into, ZlemaState};
+use techalib::traits::State;
+use techalib::types::Float;
+
+#[pyclass(name = "ZlemaState")]
+#[derive(Debug, Clone)]
+pub struct PyZlemaState {
+    #[pyo3(get)]
+    pub zlema: Float,
+    #[pyo3(get)]
+    pub window: Vec<Float>,
+    #[pyo3(get)]
+    pub period: usize,
+    #[pyo3(get)]
+    pub alpha: Float,
+}
+#[pymethods]
+impl PyZlemaState {
+    #[new]
+    pub fn new(zlema: Float, window: Vec<Float>, period: usize, alpha: Float) -> Self {
+        PyZlemaState {
+            zlema,
+            window,
+            period,
+            alpha,
+        }
+    }
+    #[getter]
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+    #[getter]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ZlemaState(zlema={}, window={:?}, period={}, alpha={})",
+            self.zlema, self.window, self.period, self.alpha
+        )
+    }
+}
+impl From<ZlemaState> for PyZlemaState {
+    fn from(state: ZlemaState) -> Self {
+        PyZlemaState {
+            zlema: state.zlema,
+            window: state.last_window.into(),
+            period: state.period,
+            alpha: state.alpha,
+        }
+    }
+}
+
+impl From<PyZlemaState> for ZlemaState {
+    fn from(py_state: PyZlemaState) -> Self {
+        ZlemaState {
+            zlema: py_state.zlema,
+            last_window: py_state.window.into(),
+            period: py_state.period,
+            alpha: py_state.alpha,
+        }
+    }
+}
+
+#[pyfunction(signature = (data, period = 14, alpha = None, release_gil = false))]
+pub(crate) fn zlema(
+    py: Python,
+    data: PyReadonlyArray1<Float>,
+    period: usize,
+    alpha: Option<Float>,
+    release_gil: bool,
+) -> PyResult<(Py<PyArray1<Float>>, PyZlemaState)> {
+    let len = data.len();
+    let input_slice = data.as_slice()?;
+
+    if release_gil {
+        let mut output = vec![0.0; len];
+        let state = py
+            .allow_threads(|| zlema_into(input_slice, period, alpha, output.as_mut_slice()))
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output.into_pyarray(py).into(), state.into()))
+    } else {
+        let output_array = PyArray1::<Float>::zeros(py, [len], false);
+        let output_slice = unsafe { output_array.as_slice_mut()? };
+        let state = zlema_into(input_slice, period, alpha, output_slice)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+        Ok((output_array.into(), state.into()))
+    }
+}
+
+#[pyfunction(signature = (new_value, zlema_state))]
+pub(crate) fn zlema_next(new_value: Float, zlema_state: PyZlemaState) -> PyResult<PyZlemaState> {
+    let mut state: ZlemaState = zlema_state.into();
+    state
+        .update(new_value)
+        .map_err(|e| PyValueError::new_err(format!("{:?}", e)))?;
+    Ok(state.into())
+}
@@ -3,7 +3,35 @@ from typing import NamedTuple, Optional
 from numpy.typing import NDArray
 from typing import Tuple
 
+from .arrow import *
+from .sweep import *
+from .volatility import *
+from .stats import *
+from .cmf import *
+from .eom import *
+from .force_index import *
+from .elder_ray import *
+from .zlema import *
+from .vidya import *
+from .smma import *
+from .hma import *
+from .alma import *
+from .zigzag import *
+from .swing import *
+from .pivots import *
+from .heikin_ashi import *
+from .vwap import *
+from .chandelier_exit import *
+from .supertrend import *
+from .ichimoku import *
+from .envelope import *
+from .donchian import *
+from .keltner import *
+from .atr import *
+from .ma import *
 from .kama import *
+from .mavp import *
+from .price_transform import *
 from .t3 import *
 from .trima import *
 from .tema import *
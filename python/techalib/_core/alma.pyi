@@ -0,0 +1,77 @@
+from dataclasses import dataclass
+from typing import List, NamedTuple, Optional, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class AlmaState:
+    """State for the Alma computation"""
+    alma: float
+    window: List[float]
+    period: int
+    offset: float
+    sigma: float
+    ...
+
+class AlmaResult(NamedTuple):
+    """Result of the Alma computation"""
+    values: NDArray
+    state: AlmaState
+
+def alma(
+    data: NDArray,
+    period: int = 9,
+    offset: float = 0.85,
+    sigma: float = 6.0,
+    release_gil: bool = False
+) -> AlmaResult | Tuple[NDArray, AlmaState]:
+    """
+    ALMA: Arnaud Legoux Moving Average
+    ----------
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array. Must satisfy len(data) > lookback.
+    period : int
+        Size of the rolling window (must be ``> 1``).
+    offset : float, default 0.85
+        Position of the gaussian peak in the window, from ``0`` (oldest)
+        to ``1`` (newest).
+    sigma : float, default 6.0
+        Sharpness of the gaussian weights (must be ``> 0``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    AlmaResult
+        A named tuple containing the result of the Alma computation.
+        - values: **NDArray** of the same length as *data* containing the ALMA.
+        - state: **AlmaState** with (alma: float, window: List[float], period: int, offset: float, sigma: float)
+    """
+    ...
+
+def alma_next(
+    new_value: float,
+    state: AlmaState
+) -> AlmaState:
+    """
+    Update the Alma state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point to include in the ALMA.
+
+    state : AlmaState
+        The current state of the Alma computation.
+
+    Returns
+    -------
+    AlmaState
+        The updated state after including the new value.
+    """
+    ...
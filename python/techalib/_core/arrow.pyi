@@ -0,0 +1,302 @@
+from typing import NamedTuple, Optional, Protocol, Tuple
+
+from .alma import AlmaState
+from .bbands import BBandsMA, BBandsState
+from .dema import DemaState
+from .ema import EmaState
+from .hma import HmaState
+from .kama import KamaState
+from .macd import MacdState
+from .rsi import RsiState
+from .sma import SmaState
+from .smma import SmmaState
+from .stats import ZscoreState, PercentRankState, SkewState, KurtosisState, MedianState
+from .t3 import T3State
+from .tema import TemaState
+from .trima import TrimaState
+from .vidya import VidyaState
+from .wma import WmaState
+from .zlema import ZlemaState
+
+class ArrowArrayExportable(Protocol):
+    """Any object implementing the Arrow PyCapsule interface, e.g. a ``pyarrow.Array``"""
+    def __arrow_c_array__(self, requested_schema: object = None) -> Tuple[object, object]: ...
+
+class ArrowArray:
+    """
+    Float array returned by the ``*_arrow`` functions
+
+    It implements the Arrow PyCapsule interface and can be imported without copy,
+    e.g. with ``pyarrow.array(values)``. It has the type of the input array. The
+    warm-up period and the null rows of the input are null.
+    """
+    null_count: int
+    def __arrow_c_array__(self, requested_schema: object = None) -> Tuple[object, object]: ...
+    def __len__(self) -> int: ...
+
+class SmaArrowResult(NamedTuple):
+    """Result of the SMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[SmaState]
+
+def sma_arrow(data: ArrowArrayExportable, period: int = 14) -> SmaArrowResult | Tuple[ArrowArray, Optional[SmaState]]:
+    """
+    Same as ``sma`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class EmaArrowResult(NamedTuple):
+    """Result of the EMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[EmaState]
+
+def ema_arrow(data: ArrowArrayExportable, period: int = 14, alpha: Optional[float] = None) -> EmaArrowResult | Tuple[ArrowArray, Optional[EmaState]]:
+    """
+    Same as ``ema`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class WmaArrowResult(NamedTuple):
+    """Result of the WMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[WmaState]
+
+def wma_arrow(data: ArrowArrayExportable, period: int = 14) -> WmaArrowResult | Tuple[ArrowArray, Optional[WmaState]]:
+    """
+    Same as ``wma`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class DemaArrowResult(NamedTuple):
+    """Result of the DEMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[DemaState]
+
+def dema_arrow(data: ArrowArrayExportable, period: int = 14, alpha: Optional[float] = None) -> DemaArrowResult | Tuple[ArrowArray, Optional[DemaState]]:
+    """
+    Same as ``dema`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class TemaArrowResult(NamedTuple):
+    """Result of the TEMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[TemaState]
+
+def tema_arrow(data: ArrowArrayExportable, period: int = 14, alpha: Optional[float] = None) -> TemaArrowResult | Tuple[ArrowArray, Optional[TemaState]]:
+    """
+    Same as ``tema`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class TrimaArrowResult(NamedTuple):
+    """Result of the TRIMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[TrimaState]
+
+def trima_arrow(data: ArrowArrayExportable, period: int = 14) -> TrimaArrowResult | Tuple[ArrowArray, Optional[TrimaState]]:
+    """
+    Same as ``trima`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class T3ArrowResult(NamedTuple):
+    """Result of the T3 computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[T3State]
+
+def t3_arrow(data: ArrowArrayExportable, period: int = 5, vfactor: float = 0.7, alpha: Optional[float] = None) -> T3ArrowResult | Tuple[ArrowArray, Optional[T3State]]:
+    """
+    Same as ``t3`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class KamaArrowResult(NamedTuple):
+    """Result of the KAMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[KamaState]
+
+def kama_arrow(data: ArrowArrayExportable, period: int = 30) -> KamaArrowResult | Tuple[ArrowArray, Optional[KamaState]]:
+    """
+    Same as ``kama`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class HmaArrowResult(NamedTuple):
+    """Result of the HMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[HmaState]
+
+def hma_arrow(data: ArrowArrayExportable, period: int = 16) -> HmaArrowResult | Tuple[ArrowArray, Optional[HmaState]]:
+    """
+    Same as ``hma`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class ZlemaArrowResult(NamedTuple):
+    """Result of the ZLEMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[ZlemaState]
+
+def zlema_arrow(data: ArrowArrayExportable, period: int = 14, alpha: Optional[float] = None) -> ZlemaArrowResult | Tuple[ArrowArray, Optional[ZlemaState]]:
+    """
+    Same as ``zlema`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class AlmaArrowResult(NamedTuple):
+    """Result of the ALMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[AlmaState]
+
+def alma_arrow(data: ArrowArrayExportable, period: int = 9, offset: float = 0.85, sigma: float = 6.0) -> AlmaArrowResult | Tuple[ArrowArray, Optional[AlmaState]]:
+    """
+    Same as ``alma`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class SmmaArrowResult(NamedTuple):
+    """Result of the SMMA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[SmmaState]
+
+def smma_arrow(data: ArrowArrayExportable, period: int = 14) -> SmmaArrowResult | Tuple[ArrowArray, Optional[SmmaState]]:
+    """
+    Same as ``smma`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class VidyaArrowResult(NamedTuple):
+    """Result of the VIDYA computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[VidyaState]
+
+def vidya_arrow(data: ArrowArrayExportable, period: int = 14, cmo_period: int = 9) -> VidyaArrowResult | Tuple[ArrowArray, Optional[VidyaState]]:
+    """
+    Same as ``vidya`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class RsiArrowResult(NamedTuple):
+    """Result of the RSI computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[RsiState]
+
+def rsi_arrow(data: ArrowArrayExportable, period: int = 14) -> RsiArrowResult | Tuple[ArrowArray, Optional[RsiState]]:
+    """
+    Same as ``rsi`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class ZscoreArrowResult(NamedTuple):
+    """Result of the ZSCORE computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[ZscoreState]
+
+def zscore_arrow(data: ArrowArrayExportable, period: int = 20) -> ZscoreArrowResult | Tuple[ArrowArray, Optional[ZscoreState]]:
+    """
+    Same as ``zscore`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class PercentRankArrowResult(NamedTuple):
+    """Result of the PERCENT_RANK computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[PercentRankState]
+
+def percent_rank_arrow(data: ArrowArrayExportable, period: int = 20) -> PercentRankArrowResult | Tuple[ArrowArray, Optional[PercentRankState]]:
+    """
+    Same as ``percent_rank`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class SkewArrowResult(NamedTuple):
+    """Result of the SKEW computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[SkewState]
+
+def skew_arrow(data: ArrowArrayExportable, period: int = 20) -> SkewArrowResult | Tuple[ArrowArray, Optional[SkewState]]:
+    """
+    Same as ``skew`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class KurtosisArrowResult(NamedTuple):
+    """Result of the KURTOSIS computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[KurtosisState]
+
+def kurtosis_arrow(data: ArrowArrayExportable, period: int = 20) -> KurtosisArrowResult | Tuple[ArrowArray, Optional[KurtosisState]]:
+    """
+    Same as ``kurtosis`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class MedianArrowResult(NamedTuple):
+    """Result of the MEDIAN computation over an Arrow array"""
+    values: ArrowArray
+    state: Optional[MedianState]
+
+def median_arrow(data: ArrowArrayExportable, period: int = 20) -> MedianArrowResult | Tuple[ArrowArray, Optional[MedianState]]:
+    """
+    Same as ``median`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class BBandsArrowResult(NamedTuple):
+    """Result of the Bollinger Bands computation over an Arrow array"""
+    upper: ArrowArray
+    middle: ArrowArray
+    lower: ArrowArray
+    state: Optional[BBandsState]
+
+def bbands_arrow(
+    data: ArrowArrayExportable,
+    period: int = 20,
+    std_up: float = 2.0,
+    std_down: float = 2.0,
+    ma_type: BBandsMA = BBandsMA.SMA,
+) -> BBandsArrowResult | Tuple[ArrowArray, ArrowArray, ArrowArray, Optional[BBandsState]]:
+    """
+    Same as ``bbands`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
+
+class MacdArrowResult(NamedTuple):
+    """Result of the MACD computation over an Arrow array"""
+    macd: ArrowArray
+    signal: ArrowArray
+    histogram: ArrowArray
+    state: Optional[MacdState]
+
+def macd_arrow(
+    data: ArrowArrayExportable,
+    fast_period: int = 12,
+    slow_period: int = 26,
+    signal_period: int = 9,
+) -> MacdArrowResult | Tuple[ArrowArray, ArrowArray, ArrowArray, Optional[MacdState]]:
+    """
+    Same as ``macd`` over a ``Float64`` or ``Float32`` Arrow array. Null rows are skipped.
+    A ``Float32`` array is processed in ``float32`` and returns no state.
+    """
+    ...
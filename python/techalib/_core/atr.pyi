@@ -0,0 +1,85 @@
+from dataclasses import dataclass
+from typing import NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class AtrState:
+    """State for the ATR computation"""
+    atr: float
+    prev_close: float
+    period: int
+    ...
+
+class AtrResult(NamedTuple):
+    """Result of the ATR computation"""
+    values: NDArray
+    state: AtrState
+
+def atr(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    period: int = 14,
+    release_gil: bool = False
+) -> AtrResult | Tuple[NDArray, AtrState]:
+    """
+    ATR: Average True Range.
+    ----------
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    period : int, default 14
+        The period used for the Wilder smoothing of the true range.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    AtrResult
+        A named tuple containing the result of the ATR computation.
+        - values: **NDArray** with the computed ATR values.
+        - state: `AtrState`
+    """
+    ...
+
+def atr_next(
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: AtrState
+) -> AtrState:
+    """
+    Update the ATR state with the next bar.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : AtrState
+        The current state of the ATR computation.
+
+    Returns
+    -------
+    AtrState
+        The updated state after including the new bar.
+    """
+    ...
@@ -1,10 +1,12 @@
 
 from dataclasses import dataclass
-from typing import NamedTuple, Tuple, List
+from typing import NamedTuple, Tuple, List, Optional
 from enum import Enum
 
 from numpy.typing import NDArray
 
+from .ma import MaState
+
 @dataclass(frozen=True)
 class BBandsState:
     """State for the BBands computation"""
@@ -14,9 +16,12 @@ class BBandsState:
     mean_sma: float
     mean_sq: float
     window: List[float]
+    ma: Optional[MaState]
     period: int
     std_up: float
     std_down: float
+    percent_b: float
+    bandwidth: float
     ...
 
 class BBandsResult(NamedTuple):
@@ -26,9 +31,23 @@ class BBandsResult(NamedTuple):
     lower: NDArray
     state: BBandsState
 
+class BBandsExtResult(NamedTuple):
+    """Result of the BBands computation with %B and bandwidth"""
+    upper: NDArray
+    middle: NDArray
+    lower: NDArray
+    percent_b: NDArray
+    bandwidth: NDArray
+    state: BBandsState
+
 class BBandsMA(Enum):
     SMA = 0
     EMA = 1
+    HMA = 2
+    ZLEMA = 3
+    ALMA = 4
+    SMMA = 5
+    VIDYA = 6
 
 def bbands(
     data: NDArray,
@@ -61,6 +80,12 @@ def bbands(
         Options:
         - BBandsMA.SMA: Simple Moving Average
         - BBandsMA.EMA: Exponential Moving Average
+        - BBandsMA.HMA: Hull Moving Average
+        - BBandsMA.ZLEMA: Zero-Lag Exponential Moving Average
+        - BBandsMA.ALMA: Arnaud Legoux Moving Average (offset of 0.85, sigma of 6)
+        - BBandsMA.SMMA: Smoothed Moving Average
+        - BBandsMA.VIDYA: Variable Index Dynamic Average (CMO period of 9)
+        The standard deviation is always the one of the last ``period`` values.
 
     release_gil : bool, default False
         If ``True``, the GIL is released during the computation.
@@ -73,7 +98,38 @@ def bbands(
         - upper: **NDArray** with the upper Bollinger Band values.
         - middle: **NDArray** with the middle Bollinger Band values (moving average).
         - lower: **NDArray** with the lower Bollinger Band values.
-        - state: **BBandsState** with (upper: float, middle: float, lower: float, mean_sma: float, mean_sq: float, window: List[float], period: int, std_up: float, std_down: float)
+        - state: **BBandsState** with (upper: float, middle: float, lower: float, mean_sma: float, mean_sq: float, window: List[float], period: int, std_up: float, std_down: float, percent_b: float, bandwidth: float)
+    """
+    ...
+
+def bbands_ext(
+    data: NDArray,
+    period: int = 20,
+    std_up: float = 2.0,
+    std_down: float = 2.0,
+    ma_type: BBandsMA = BBandsMA.SMA,
+    release_gil: bool = False
+) -> BBandsExtResult | Tuple[NDArray, NDArray, NDArray, NDArray, NDArray, BBandsState]:
+    """
+    BBands with %B and bandwidth, computed in the same pass as the bands.
+    ----------
+
+    Parameters
+    ----------
+    Same parameters as `bbands`.
+
+    Returns
+    -------
+    BBandsExtResult
+        A named tuple containing the result of the BBands computation.
+        - upper: **NDArray** with the upper Bollinger Band values.
+        - middle: **NDArray** with the middle Bollinger Band values (moving average).
+        - lower: **NDArray** with the lower Bollinger Band values.
+        - percent_b: **NDArray** with the %B values, ``(data - lower) / (upper - lower)``,
+          ``NaN`` when the bands are merged.
+        - bandwidth: **NDArray** with the bandwidth values, ``(upper - lower) / middle``,
+          ``NaN`` when the middle band is zero.
+        - state: **BBandsState**
     """
     ...
 
@@ -0,0 +1,98 @@
+from dataclasses import dataclass
+from typing import NamedTuple, List, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class ChandelierExitState:
+    """State for the Chandelier Exit computation"""
+    long_stop: float
+    short_stop: float
+    highest: float
+    lowest: float
+    high_window: List[float]
+    low_window: List[float]
+    atr: float
+    prev_close: float
+    period: int
+    multiplier: float
+    ...
+
+class ChandelierExitResult(NamedTuple):
+    """Result of the Chandelier Exit computation"""
+    long_stop: NDArray
+    short_stop: NDArray
+    state: ChandelierExitState
+
+def chandelier_exit(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    period: int = 22,
+    multiplier: float = 3.0,
+    release_gil: bool = False
+) -> ChandelierExitResult | Tuple[NDArray, NDArray, ChandelierExitState]:
+    """
+    Chandelier Exit: ATR based trailing stops.
+    ----------
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    period : int, default 22
+        The period of the highest high, the lowest low and the ATR.
+
+    multiplier : float, default 3.0
+        The multiplier of the ATR used to calculate the stops.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    ChandelierExitResult
+        A named tuple containing the result of the Chandelier Exit computation.
+        - long_stop: **NDArray** with the stops of long positions.
+        - short_stop: **NDArray** with the stops of short positions.
+        - state: `ChandelierExitState`
+    """
+    ...
+
+def chandelier_exit_next(
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: ChandelierExitState
+) -> ChandelierExitState:
+    """
+    Update the Chandelier Exit state with the next bar.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : ChandelierExitState
+        The current state of the Chandelier Exit computation.
+
+    Returns
+    -------
+    ChandelierExitState
+        The updated state after including the new bar.
+    """
+    ...
@@ -0,0 +1,96 @@
+from dataclasses import dataclass
+from typing import NamedTuple, List, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class CmfState:
+    """State for the Chaikin Money Flow computation"""
+    cmf: float
+    mfv_sum: float
+    volume_sum: float
+    mfv_window: List[float]
+    volume_window: List[float]
+    period: int
+    ...
+
+class CmfResult(NamedTuple):
+    """Result of the Chaikin Money Flow computation"""
+    values: NDArray
+    state: CmfState
+
+def cmf(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    volume: NDArray,
+    period: int = 20,
+    release_gil: bool = False
+) -> CmfResult | Tuple[NDArray, CmfState]:
+    """
+    CMF: Chaikin Money Flow
+    ----------
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    volume : NDArray
+        Volumes.
+
+    period : int, default 20
+        The period of the money flow volume and volume sums.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    CmfResult
+        A named tuple containing the result of the Chaikin Money Flow computation.
+        - values: **NDArray** with the CMF, between ``-1`` and ``1``.
+        - state: `CmfState`
+    """
+    ...
+
+def cmf_next(
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    new_volume: float,
+    state: CmfState
+) -> CmfState:
+    """
+    Update the Chaikin Money Flow state with the next bar.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    new_volume : float
+        The volume of the new bar.
+
+    state : CmfState
+        The current state of the Chaikin Money Flow computation.
+
+    Returns
+    -------
+    CmfState
+        The updated state after including the new bar.
+    """
+    ...
@@ -0,0 +1,84 @@
+from dataclasses import dataclass
+from typing import NamedTuple, List, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class DonchianState:
+    """State for the Donchian Channels computation"""
+    upper: float
+    middle: float
+    lower: float
+    high_window: List[float]
+    low_window: List[float]
+    period: int
+    ...
+
+class DonchianResult(NamedTuple):
+    """Result of the Donchian Channels computation"""
+    upper: NDArray
+    middle: NDArray
+    lower: NDArray
+    state: DonchianState
+
+def donchian(
+    high: NDArray,
+    low: NDArray,
+    period: int = 20,
+    release_gil: bool = False
+) -> DonchianResult | Tuple[NDArray, NDArray, NDArray, DonchianState]:
+    """
+    Donchian: Donchian Channels computation.
+    ----------
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    period : int, default 20
+        The number of bars over which the highest high and lowest low are searched.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    DonchianResult
+        A named tuple containing the result of the Donchian Channels computation.
+        - upper: **NDArray** with the highest highs.
+        - middle: **NDArray** with the average of the upper and lower bands.
+        - lower: **NDArray** with the lowest lows.
+        - state: `DonchianState`
+    """
+    ...
+
+def donchian_next(
+    new_high: float,
+    new_low: float,
+    state: DonchianState
+) -> DonchianState:
+    """
+    Update the Donchian Channels state with the next bar.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    state : DonchianState
+        The current state of the Donchian Channels computation.
+
+    Returns
+    -------
+    DonchianState
+        The updated state after including the new bar.
+    """
+    ...
@@ -0,0 +1,89 @@
+from dataclasses import dataclass
+from typing import NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class ElderRayState:
+    """State for the Elder Ray Index computation"""
+    bull_power: float
+    bear_power: float
+    ema: float
+    period: int
+    alpha: float
+    ...
+
+class ElderRayResult(NamedTuple):
+    """Result of the Elder Ray Index computation"""
+    bull_power: NDArray
+    bear_power: NDArray
+    state: ElderRayState
+
+def elder_ray(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    period: int = 13,
+    release_gil: bool = False
+) -> ElderRayResult | Tuple[NDArray, NDArray, ElderRayState]:
+    """
+    Elder Ray Index: bull and bear power against an EMA of the close.
+    ----------
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    period : int, default 13
+        The period of the EMA of the close (must be ``> 1``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    ElderRayResult
+        A named tuple containing the result of the Elder Ray Index computation.
+        - bull_power: **NDArray** with ``high - ema``.
+        - bear_power: **NDArray** with ``low - ema``.
+        - state: `ElderRayState`
+    """
+    ...
+
+def elder_ray_next(
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: ElderRayState
+) -> ElderRayState:
+    """
+    Update the Elder Ray Index state with the next bar.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : ElderRayState
+        The current state of the Elder Ray Index computation.
+
+    Returns
+    -------
+    ElderRayState
+        The updated state after including the new bar.
+    """
+    ...
@@ -0,0 +1,85 @@
+from dataclasses import dataclass
+from typing import NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+from .ma import MaState, MaType
+
+@dataclass(frozen=True)
+class EnvelopeState:
+    """State for the Moving Average Envelope computation"""
+    upper: float
+    middle: float
+    lower: float
+    ma: MaState
+    percent: float
+    ...
+
+class EnvelopeResult(NamedTuple):
+    """Result of the Moving Average Envelope computation"""
+    upper: NDArray
+    middle: NDArray
+    lower: NDArray
+    state: EnvelopeState
+
+def envelope(
+    data: NDArray,
+    period: int = 20,
+    percent: float = 2.5,
+    ma_type: MaType = MaType.SMA,
+    release_gil: bool = False
+) -> EnvelopeResult | Tuple[NDArray, NDArray, NDArray, EnvelopeState]:
+    """
+    Envelope: Moving Average Envelope computation.
+    ----------
+
+    Parameters
+    ----------
+    data : NDArray
+        Input data for the envelope computation, typically a price series.
+
+    period : int, default 20
+        The period of the moving average.
+
+    percent : float, default 2.5
+        The distance of the bands from the moving average, in percent.
+
+    ma_type : MaType, default MaType.SMA
+        The type of moving average to use for the middle band.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    EnvelopeResult
+        A named tuple containing the result of the envelope computation.
+        - upper: **NDArray** with the upper band values.
+        - middle: **NDArray** with the middle band values (moving average).
+        - lower: **NDArray** with the lower band values.
+        - state: `EnvelopeState`
+    """
+    ...
+
+def envelope_next(
+    new_value: float,
+    state: EnvelopeState
+) -> EnvelopeState:
+    """
+    Update the envelope state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The new value to include in the envelope computation.
+
+    state : EnvelopeState
+        The current state of the envelope computation.
+
+    Returns
+    -------
+    EnvelopeState
+        The updated state after including the new value.
+    """
+    ...
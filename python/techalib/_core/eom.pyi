@@ -0,0 +1,92 @@
+from dataclasses import dataclass
+from typing import NamedTuple, List, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class EomState:
+    """State for the Ease of Movement computation"""
+    eom: float
+    prev_high: float
+    prev_low: float
+    window: List[float]
+    period: int
+    scale: float
+    ...
+
+class EomResult(NamedTuple):
+    """Result of the Ease of Movement computation"""
+    values: NDArray
+    state: EomState
+
+def eom(
+    high: NDArray,
+    low: NDArray,
+    volume: NDArray,
+    period: int = 14,
+    scale: float = 10000.0,
+    release_gil: bool = False
+) -> EomResult | Tuple[NDArray, EomState]:
+    """
+    EOM: Ease of Movement
+    ----------
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    volume : NDArray
+        Volumes. A bar without volume has a raw value of ``0``.
+
+    period : int, default 14
+        The period of the simple moving average of the raw values.
+
+    scale : float, default 10000.0
+        The scale of the volume in the box ratio.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    EomResult
+        A named tuple containing the result of the Ease of Movement computation.
+        - values: **NDArray** with the Ease of Movement.
+        - state: `EomState`
+    """
+    ...
+
+def eom_next(
+    new_high: float,
+    new_low: float,
+    new_volume: float,
+    state: EomState
+) -> EomState:
+    """
+    Update the Ease of Movement state with the next bar.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_volume : float
+        The volume of the new bar.
+
+    state : EomState
+        The current state of the Ease of Movement computation.
+
+    Returns
+    -------
+    EomState
+        The updated state after including the new bar.
+    """
+    ...
@@ -0,0 +1,78 @@
+from dataclasses import dataclass
+from typing import NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class ForceIndexState:
+    """State for the Force Index computation"""
+    force_index: float
+    prev_close: float
+    period: int
+    alpha: float
+    ...
+
+class ForceIndexResult(NamedTuple):
+    """Result of the Force Index computation"""
+    values: NDArray
+    state: ForceIndexState
+
+def force_index(
+    close: NDArray,
+    volume: NDArray,
+    period: int = 13,
+    release_gil: bool = False
+) -> ForceIndexResult | Tuple[NDArray, ForceIndexState]:
+    """
+    Force Index: EMA of the change of the close multiplied by the volume.
+    ----------
+
+    Parameters
+    ----------
+    close : NDArray
+        Close prices.
+
+    volume : NDArray
+        Volumes.
+
+    period : int, default 13
+        The period of the EMA of the raw force (``1`` gives the raw force).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    ForceIndexResult
+        A named tuple containing the result of the Force Index computation.
+        - values: **NDArray** with the Force Index.
+        - state: `ForceIndexState`
+    """
+    ...
+
+def force_index_next(
+    new_close: float,
+    new_volume: float,
+    state: ForceIndexState
+) -> ForceIndexState:
+    """
+    Update the Force Index state with the next bar.
+
+    Parameters
+    ----------
+    new_close : float
+        The close of the new bar.
+
+    new_volume : float
+        The volume of the new bar.
+
+    state : ForceIndexState
+        The current state of the Force Index computation.
+
+    Returns
+    -------
+    ForceIndexState
+        The updated state after including the new bar.
+    """
+    ...
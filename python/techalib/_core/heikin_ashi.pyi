@@ -0,0 +1,99 @@
+from dataclasses import dataclass
+from typing import NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class HeikinAshiState:
+    """State for the Heikin-Ashi computation"""
+    open: float
+    high: float
+    low: float
+    close: float
+    ...
+
+class HeikinAshiResult(NamedTuple):
+    """Result of the Heikin-Ashi computation"""
+    open: NDArray
+    high: NDArray
+    low: NDArray
+    close: NDArray
+    state: HeikinAshiState
+
+def heikin_ashi(
+    open: NDArray,
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    release_gil: bool = False
+) -> HeikinAshiResult | Tuple[NDArray, NDArray, NDArray, NDArray, HeikinAshiState]:
+    """
+    Heikin-Ashi: Heikin-Ashi candles computation.
+    ----------
+
+    The returned candles can be used directly as the input of the other
+    indicators (e.g. ``tx.atr(ha.high, ha.low, ha.close)``).
+
+    Parameters
+    ----------
+    open : NDArray
+        Open prices.
+
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    HeikinAshiResult
+        A named tuple containing the Heikin-Ashi candles.
+        - open: **NDArray** with the Heikin-Ashi open prices.
+        - high: **NDArray** with the Heikin-Ashi high prices.
+        - low: **NDArray** with the Heikin-Ashi low prices.
+        - close: **NDArray** with the Heikin-Ashi close prices.
+        - state: `HeikinAshiState`
+    """
+    ...
+
+def heikin_ashi_next(
+    new_open: float,
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: HeikinAshiState
+) -> HeikinAshiState:
+    """
+    Calculate the next Heikin-Ashi candle.
+
+    Parameters
+    ----------
+    new_open : float
+        The open of the new bar.
+
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : HeikinAshiState
+        The last Heikin-Ashi candle.
+
+    Returns
+    -------
+    HeikinAshiState
+        The new Heikin-Ashi candle.
+    """
+    ...
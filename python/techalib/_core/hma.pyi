@@ -0,0 +1,73 @@
+from dataclasses import dataclass
+from typing import List, NamedTuple, Optional, Tuple
+
+from numpy.typing import NDArray
+
+from .wma import WmaState
+
+@dataclass(frozen=True)
+class HmaState:
+    """State for the Hma computation"""
+    hma: float
+    half_wma: WmaState
+    full_wma: WmaState
+    hull_wma: WmaState
+    period: int
+    ...
+
+class HmaResult(NamedTuple):
+    """Result of the Hma computation"""
+    values: NDArray
+    state: HmaState
+
+def hma(
+    data: NDArray,
+    period: int = 16,
+    release_gil: bool = False
+) -> HmaResult | Tuple[NDArray, HmaState]:
+    """
+    HMA: Hull Moving Average
+    ----------
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array. Must satisfy len(data) > lookback.
+    period : int
+        Period of the full WMA (must be ``>= 4``). The half WMA uses
+        ``period // 2`` and the final WMA ``floor(sqrt(period))``.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    HmaResult
+        A named tuple containing the result of the Hma computation.
+        - values: **NDArray** of the same length as *data* containing the HMA.
+        - state: **HmaState** with (hma: float, half_wma: WmaState, full_wma: WmaState, hull_wma: WmaState, period: int)
+    """
+    ...
+
+def hma_next(
+    new_value: float,
+    state: HmaState
+) -> HmaState:
+    """
+    Update the Hma state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point to include in the HMA.
+
+    state : HmaState
+        The current state of the Hma computation.
+
+    Returns
+    -------
+    HmaState
+        The updated state after including the new value.
+    """
+    ...
@@ -0,0 +1,125 @@
+from dataclasses import dataclass
+from typing import NamedTuple, List, Tuple
+
+from numpy.typing import NDArray
+
+from .donchian import DonchianState
+
+@dataclass(frozen=True)
+class IchimokuState:
+    """State for the Ichimoku Kinko Hyo computation"""
+    tenkan: float
+    kijun: float
+    senkou_a: float
+    senkou_b: float
+    chikou: float
+    tenkan_state: DonchianState
+    kijun_state: DonchianState
+    senkou_b_state: DonchianState
+    senkou_a_ahead: List[float]
+    senkou_b_ahead: List[float]
+    displacement: int
+    ...
+
+class IchimokuResult(NamedTuple):
+    """Result of the Ichimoku Kinko Hyo computation"""
+    tenkan: NDArray
+    kijun: NDArray
+    senkou_a: NDArray
+    senkou_b: NDArray
+    chikou: NDArray
+    state: IchimokuState
+
+def ichimoku(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    tenkan: int = 9,
+    kijun: int = 26,
+    senkou_b: int = 52,
+    displacement: int = 26,
+    release_gil: bool = False
+) -> IchimokuResult | Tuple[NDArray, NDArray, NDArray, NDArray, NDArray, IchimokuState]:
+    """
+    Ichimoku: Ichimoku Kinko Hyo computation.
+    ----------
+
+    All the outputs have the same length as the input and the value at
+    index ``i`` is the value plotted at bar ``i``. The Senkou spans are
+    shifted forward by ``displacement`` bars, the values projected beyond
+    the last bar are in ``state.senkou_a_ahead`` and ``state.senkou_b_ahead``.
+    The Chikou span is shifted back, so its last ``displacement`` values are NaN.
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    tenkan : int, default 9
+        The period of the Tenkan-sen (conversion line).
+
+    kijun : int, default 26
+        The period of the Kijun-sen (base line).
+
+    senkou_b : int, default 52
+        The period of the Senkou span B.
+
+    displacement : int, default 26
+        The number of bars the spans are shifted by.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    IchimokuResult
+        A named tuple containing the result of the Ichimoku computation.
+        - tenkan: **NDArray** with the Tenkan-sen values.
+        - kijun: **NDArray** with the Kijun-sen values.
+        - senkou_a: **NDArray** with the Senkou span A values, shifted forward.
+        - senkou_b: **NDArray** with the Senkou span B values, shifted forward.
+        - chikou: **NDArray** with the Chikou span values, shifted back.
+        - state: `IchimokuState`
+    """
+    ...
+
+def ichimoku_next(
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: IchimokuState
+) -> IchimokuState:
+    """
+    Update the Ichimoku state with the next bar.
+
+    ``state.senkou_a`` and ``state.senkou_b`` are the spans plotted at the
+    new bar, and the spans calculated from the new bar are appended to
+    ``state.senkou_a_ahead`` and ``state.senkou_b_ahead``.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : IchimokuState
+        The current state of the Ichimoku computation.
+
+    Returns
+    -------
+    IchimokuState
+        The updated state after including the new bar.
+    """
+    ...
@@ -0,0 +1,103 @@
+from dataclasses import dataclass
+from typing import NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class KeltnerState:
+    """State for the Keltner Channels computation"""
+    upper: float
+    middle: float
+    lower: float
+    period: int
+    alpha: float
+    atr: float
+    prev_close: float
+    atr_period: int
+    multiplier: float
+    ...
+
+class KeltnerResult(NamedTuple):
+    """Result of the Keltner Channels computation"""
+    upper: NDArray
+    middle: NDArray
+    lower: NDArray
+    state: KeltnerState
+
+def keltner(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    period: int = 20,
+    atr_period: int = 10,
+    multiplier: float = 2.0,
+    release_gil: bool = False
+) -> KeltnerResult | Tuple[NDArray, NDArray, NDArray, KeltnerState]:
+    """
+    Keltner: Keltner Channels computation.
+    ----------
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    period : int, default 20
+        The period of the EMA of the close (middle band).
+
+    atr_period : int, default 10
+        The period of the ATR.
+
+    multiplier : float, default 2.0
+        The multiplier of the ATR used for the upper and lower bands.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    KeltnerResult
+        A named tuple containing the result of the Keltner Channels computation.
+        - upper: **NDArray** with the upper band values.
+        - middle: **NDArray** with the middle band values (EMA of the close).
+        - lower: **NDArray** with the lower band values.
+        - state: `KeltnerState`
+    """
+    ...
+
+def keltner_next(
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: KeltnerState
+) -> KeltnerState:
+    """
+    Update the Keltner Channels state with the next bar.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : KeltnerState
+        The current state of the Keltner Channels computation.
+
+    Returns
+    -------
+    KeltnerState
+        The updated state after including the new bar.
+    """
+    ...
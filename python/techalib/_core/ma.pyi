@@ -0,0 +1,88 @@
+from typing import NamedTuple, Tuple
+from enum import Enum
+
+from numpy.typing import NDArray
+
+class MaState:
+    """State for the MA computation, opaque as it depends on the MA type"""
+    value: float
+    period: int
+    ...
+
+class MaResult(NamedTuple):
+    """Result of the MA computation"""
+    values: NDArray
+    state: MaState
+
+class MaType(Enum):
+    SMA = 0
+    EMA = 1
+    WMA = 2
+    DEMA = 3
+    TEMA = 4
+    TRIMA = 5
+    KAMA = 6
+    T3 = 7
+    HMA = 8
+    ZLEMA = 9
+    ALMA = 10
+    SMMA = 11
+    VIDYA = 12
+
+def ma(
+    data: NDArray,
+    period: int = 30,
+    ma_type: MaType = MaType.SMA,
+    release_gil: bool = False
+) -> MaResult | Tuple[NDArray, MaState]:
+    """
+    MA: Moving Average of any type.
+    ----------
+
+    Parameters
+    ----------
+    data : NDArray
+        Input data for the MA computation, typically a price series.
+
+    period : int, default 30
+        The period of the moving average.
+
+    ma_type : MaType, default MaType.SMA
+        The type of moving average to compute. EMA based averages use the
+        default alpha, T3 uses a volume factor of 0.7, ALMA an offset of 0.85
+        and a sigma of 6, and VIDYA a CMO period of 9.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    MaResult
+        A named tuple containing the result of the MA computation.
+        - values: **NDArray** with the computed MA values.
+        - state: `MaState`
+    """
+    ...
+
+def ma_next(
+    new_value: float,
+    state: MaState
+) -> MaState:
+    """
+    Update the MA state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The new value to include in the MA computation.
+
+    state : MaState
+        The current state of the MA computation.
+
+    Returns
+    -------
+    MaState
+        The updated state after including the new value.
+    """
+    ...
@@ -0,0 +1,114 @@
+from dataclasses import dataclass
+from typing import NamedTuple, List, Tuple
+from enum import Enum
+
+from numpy.typing import NDArray
+
+from .ma import MaState
+
+@dataclass(frozen=True)
+class MavpState:
+    """State for the MAVP computation"""
+    mavp: float
+    period: int
+    period_sub: float
+    period_sum: float
+    emas: List[float]
+    ma_states: List[MaState]
+    window: List[float]
+    min_period: int
+    max_period: int
+    ...
+
+class MavpResult(NamedTuple):
+    """Result of the MAVP computation"""
+    values: NDArray
+    state: MavpState
+
+class MavpMA(Enum):
+    SMA = 0
+    EMA = 1
+    WMA = 2
+    HMA = 3
+    ZLEMA = 4
+    ALMA = 5
+    SMMA = 6
+    VIDYA = 7
+
+def mavp(
+    data: NDArray,
+    periods: NDArray,
+    min_period: int = 2,
+    max_period: int = 30,
+    ma_type: MavpMA = MavpMA.SMA,
+    release_gil: bool = False
+) -> MavpResult | Tuple[NDArray, MavpState]:
+    """
+    MAVP: Moving Average with Variable Period.
+    ----------
+
+    Parameters
+    ----------
+    data : NDArray
+        Input data for the MAVP computation, typically a price series.
+
+    periods : NDArray
+        The period to use for each value of ``data``. Periods are truncated
+        and clamped between ``min_period`` and ``max_period``.
+
+    min_period : int, default 2
+        The lowest period allowed.
+
+    max_period : int, default 30
+        The highest period allowed.
+
+    ma_type : MavpMA, default MavpMA.SMA
+        The type of moving average to use.
+        Options:
+        - MavpMA.SMA: Simple Moving Average
+        - MavpMA.EMA: Exponential Moving Average
+        - MavpMA.WMA: Weighted Moving Average
+        - MavpMA.HMA: Hull Moving Average
+        - MavpMA.ZLEMA: Zero-Lag Exponential Moving Average
+        - MavpMA.ALMA: Arnaud Legoux Moving Average (offset of 0.85, sigma of 6)
+        - MavpMA.SMMA: Smoothed Moving Average
+        - MavpMA.VIDYA: Variable Index Dynamic Average (CMO period of 9)
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    MavpResult
+        A named tuple containing the result of the MAVP computation.
+        - values: **NDArray** with the computed MAVP values.
+        - state: `MavpState`
+    """
+    ...
+
+def mavp_next(
+    new_value: float,
+    new_period: float,
+    state: MavpState
+) -> MavpState:
+    """
+    Update the MAVP state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The new value to include in the MAVP computation.
+
+    new_period : float
+        The period to use for the new value.
+
+    state : MavpState
+        The current state of the MAVP computation.
+
+    Returns
+    -------
+    MavpState
+        The updated state after including the new value.
+    """
+    ...
@@ -0,0 +1,135 @@
+from dataclasses import dataclass
+from enum import Enum
+from typing import NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+class PivotMethod(Enum):
+    Classic = 0
+    Fibonacci = 1
+    Camarilla = 2
+    Woodie = 3
+    Demark = 4
+
+@dataclass(frozen=True)
+class PivotsState:
+    """State for the Pivot Points computation"""
+    pivot: float
+    r1: float
+    r2: float
+    r3: float
+    s1: float
+    s2: float
+    s3: float
+    prev_open: float
+    prev_high: float
+    prev_low: float
+    prev_close: float
+    session_open: float
+    session_high: float
+    session_low: float
+    session_close: float
+    method: PivotMethod
+    ...
+
+class PivotsResult(NamedTuple):
+    """Result of the Pivot Points computation"""
+    pivot: NDArray
+    r1: NDArray
+    r2: NDArray
+    r3: NDArray
+    s1: NDArray
+    s2: NDArray
+    s3: NDArray
+    state: PivotsState
+
+def pivots(
+    open: NDArray,
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    new_session: NDArray,
+    method: PivotMethod = PivotMethod.Classic,
+    release_gil: bool = False
+) -> PivotsResult | Tuple[NDArray, NDArray, NDArray, NDArray, NDArray, NDArray, NDArray, PivotsState]:
+    """
+    Pivot Points: support and resistance levels from the previous session.
+    ----------
+
+    The levels of a session are computed from the open, high, low and close
+    of the previous session and are repeated on every bar of the session.
+    The levels of the first session are ``NaN``.
+
+    Parameters
+    ----------
+    open : NDArray
+        Open prices.
+
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    new_session : NDArray
+        Boolean mask, ``True`` on the first bar of each session.
+
+    method : PivotMethod, default PivotMethod.Classic
+        The formulas used to compute the levels.
+        With ``Demark``, only ``pivot``, ``r1`` and ``s1`` are defined.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    PivotsResult
+        A named tuple containing the result of the Pivot Points computation.
+        - pivot: **NDArray** with the pivot levels.
+        - r1, r2, r3: **NDArray** with the resistance levels.
+        - s1, s2, s3: **NDArray** with the support levels.
+        - state: `PivotsState`
+    """
+    ...
+
+def pivots_next(
+    new_open: float,
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: PivotsState,
+    new_session: bool = False
+) -> PivotsState:
+    """
+    Update the Pivot Points state with the next bar.
+
+    Parameters
+    ----------
+    new_open : float
+        The open of the new bar.
+
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : PivotsState
+        The current state of the Pivot Points computation.
+
+    new_session : bool, default False
+        ``True`` if the new bar opens a new session.
+
+    Returns
+    -------
+    PivotsState
+        The updated state of the Pivot Points computation.
+    """
+    ...
@@ -0,0 +1,129 @@
+from numpy.typing import NDArray
+
+def avgprice(
+    open: NDArray,
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    release_gil: bool = False
+) -> NDArray:
+    """
+    AVGPRICE: Average Price
+    ----------
+    Computes ``(open + high + low + close) / 4`` bar by bar.
+
+    Parameters
+    ----------
+    open : 1-D array
+        Open prices.
+    high : 1-D array
+        High prices, same length as *open*.
+    low : 1-D array
+        Low prices, same length as *open*.
+    close : 1-D array
+        Close prices, same length as *open*.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    NDArray
+        **1-D array** of the same length as the inputs. A ``NaN`` in the
+        inputs gives a ``NaN`` at the same index.
+    """
+    ...
+
+def medprice(
+    high: NDArray,
+    low: NDArray,
+    release_gil: bool = False
+) -> NDArray:
+    """
+    MEDPRICE: Median Price
+    ----------
+    Computes ``(high + low) / 2`` bar by bar.
+
+    Parameters
+    ----------
+    high : 1-D array
+        High prices.
+    low : 1-D array
+        Low prices, same length as *high*.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    NDArray
+        **1-D array** of the same length as the inputs. A ``NaN`` in the
+        inputs gives a ``NaN`` at the same index.
+    """
+    ...
+
+def typprice(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    release_gil: bool = False
+) -> NDArray:
+    """
+    TYPPRICE: Typical Price
+    ----------
+    Computes ``(high + low + close) / 3`` bar by bar.
+
+    Parameters
+    ----------
+    high : 1-D array
+        High prices.
+    low : 1-D array
+        Low prices, same length as *high*.
+    close : 1-D array
+        Close prices, same length as *high*.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    NDArray
+        **1-D array** of the same length as the inputs. A ``NaN`` in the
+        inputs gives a ``NaN`` at the same index.
+    """
+    ...
+
+def wclprice(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    release_gil: bool = False
+) -> NDArray:
+    """
+    WCLPRICE: Weighted Close Price
+    ----------
+    Computes ``(high + low + 2 * close) / 4`` bar by bar.
+
+    Parameters
+    ----------
+    high : 1-D array
+        High prices.
+    low : 1-D array
+        Low prices, same length as *high*.
+    close : 1-D array
+        Close prices, same length as *high*.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    NDArray
+        **1-D array** of the same length as the inputs. A ``NaN`` in the
+        inputs gives a ``NaN`` at the same index.
+    """
+    ...
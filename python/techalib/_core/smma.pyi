@@ -0,0 +1,68 @@
+from dataclasses import dataclass
+from typing import List, NamedTuple, Optional, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class SmmaState:
+    """State for the Smma computation"""
+    smma: float
+    period: int
+    ...
+
+class SmmaResult(NamedTuple):
+    """Result of the Smma computation"""
+    values: NDArray
+    state: SmmaState
+
+def smma(
+    data: NDArray,
+    period: int = 14,
+    release_gil: bool = False
+) -> SmmaResult | Tuple[NDArray, SmmaState]:
+    """
+    SMMA: Smoothed Moving Average (Wilder's RMA)
+    ----------
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array. Must satisfy len(data) > lookback.
+    period : int
+        Size of the smoothing window (must be ``> 0``).
+        The smoothing factor is ``1 / period``.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    SmmaResult
+        A named tuple containing the result of the Smma computation.
+        - values: **NDArray** of the same length as *data* containing the SMMA.
+        - state: **SmmaState** with (smma: float, period: int)
+    """
+    ...
+
+def smma_next(
+    new_value: float,
+    state: SmmaState
+) -> SmmaState:
+    """
+    Update the Smma state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point to include in the SMMA.
+
+    state : SmmaState
+        The current state of the Smma computation.
+
+    Returns
+    -------
+    SmmaState
+        The updated state after including the new value.
+    """
+    ...
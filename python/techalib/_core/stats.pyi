@@ -0,0 +1,352 @@
+from dataclasses import dataclass
+from typing import List, NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class ZscoreState:
+    """State for the Rolling Z-Score computation"""
+    zscore: float
+    mean: float
+    m2: float
+    m3: float
+    m4: float
+    window: List[float]
+    period: int
+    ...
+
+class ZscoreResult(NamedTuple):
+    """Result of the Rolling Z-Score computation"""
+    values: NDArray
+    state: ZscoreState
+
+def zscore(
+    data: NDArray,
+    period: int = 20,
+    release_gil: bool = False
+) -> ZscoreResult | Tuple[NDArray, ZscoreState]:
+    """
+    Rolling Z-Score
+    ----------
+    Distance of the last value from the mean of the window, in population
+    standard deviations. A constant window gives ``0``.
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array.
+
+    period : int, default 20
+        Size of the window (must be ``>= 2``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    ZscoreResult
+        A named tuple containing the result of the Rolling Z-Score computation.
+        - values: **NDArray** of the same length as *data*.
+        - state: **ZscoreState** with (zscore: float, mean: float, m2: float, m3: float, m4: float, window: List[float], period: int)
+    """
+    ...
+
+def zscore_next(
+    new_value: float,
+    state: ZscoreState
+) -> ZscoreState:
+    """
+    Update the Rolling Z-Score state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point.
+
+    state : ZscoreState
+        The current state of the Rolling Z-Score computation.
+
+    Returns
+    -------
+    ZscoreState
+        The updated state after including the new value.
+    """
+    ...
+
+@dataclass(frozen=True)
+class PercentRankState:
+    """State for the Rolling Percent Rank computation"""
+    percent_rank: float
+    sorted_window: List[float]
+    window: List[float]
+    period: int
+    ...
+
+class PercentRankResult(NamedTuple):
+    """Result of the Rolling Percent Rank computation"""
+    values: NDArray
+    state: PercentRankState
+
+def percent_rank(
+    data: NDArray,
+    period: int = 20,
+    release_gil: bool = False
+) -> PercentRankResult | Tuple[NDArray, PercentRankState]:
+    """
+    Rolling Percent Rank
+    ----------
+    Percentage of the ``period`` previous values lower than or equal to
+    the current value, between ``0`` and ``100``.
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array.
+
+    period : int, default 20
+        Number of previous values (must be ``>= 1``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    PercentRankResult
+        A named tuple containing the result of the Rolling Percent Rank computation.
+        - values: **NDArray** of the same length as *data*.
+        - state: **PercentRankState** with (percent_rank: float, sorted_window: List[float], window: List[float], period: int)
+    """
+    ...
+
+def percent_rank_next(
+    new_value: float,
+    state: PercentRankState
+) -> PercentRankState:
+    """
+    Update the Rolling Percent Rank state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point.
+
+    state : PercentRankState
+        The current state of the Rolling Percent Rank computation.
+
+    Returns
+    -------
+    PercentRankState
+        The updated state after including the new value.
+    """
+    ...
+
+@dataclass(frozen=True)
+class SkewState:
+    """State for the Rolling Skewness computation"""
+    skew: float
+    mean: float
+    m2: float
+    m3: float
+    m4: float
+    window: List[float]
+    period: int
+    ...
+
+class SkewResult(NamedTuple):
+    """Result of the Rolling Skewness computation"""
+    values: NDArray
+    state: SkewState
+
+def skew(
+    data: NDArray,
+    period: int = 20,
+    release_gil: bool = False
+) -> SkewResult | Tuple[NDArray, SkewState]:
+    """
+    Rolling Skewness
+    ----------
+    Bias corrected (adjusted Fisher-Pearson) skewness of the window,
+    as ``pandas.Series.rolling(period).skew()``. A constant window gives ``0``.
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array.
+
+    period : int, default 20
+        Size of the window (must be ``>= 3``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    SkewResult
+        A named tuple containing the result of the Rolling Skewness computation.
+        - values: **NDArray** of the same length as *data*.
+        - state: **SkewState** with (skew: float, mean: float, m2: float, m3: float, m4: float, window: List[float], period: int)
+    """
+    ...
+
+def skew_next(
+    new_value: float,
+    state: SkewState
+) -> SkewState:
+    """
+    Update the Rolling Skewness state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point.
+
+    state : SkewState
+        The current state of the Rolling Skewness computation.
+
+    Returns
+    -------
+    SkewState
+        The updated state after including the new value.
+    """
+    ...
+
+@dataclass(frozen=True)
+class KurtosisState:
+    """State for the Rolling Kurtosis computation"""
+    kurtosis: float
+    mean: float
+    m2: float
+    m3: float
+    m4: float
+    window: List[float]
+    period: int
+    ...
+
+class KurtosisResult(NamedTuple):
+    """Result of the Rolling Kurtosis computation"""
+    values: NDArray
+    state: KurtosisState
+
+def kurtosis(
+    data: NDArray,
+    period: int = 20,
+    release_gil: bool = False
+) -> KurtosisResult | Tuple[NDArray, KurtosisState]:
+    """
+    Rolling Kurtosis
+    ----------
+    Bias corrected excess kurtosis of the window,
+    as ``pandas.Series.rolling(period).kurt()``. A constant window gives ``0``.
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array.
+
+    period : int, default 20
+        Size of the window (must be ``>= 4``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    KurtosisResult
+        A named tuple containing the result of the Rolling Kurtosis computation.
+        - values: **NDArray** of the same length as *data*.
+        - state: **KurtosisState** with (kurtosis: float, mean: float, m2: float, m3: float, m4: float, window: List[float], period: int)
+    """
+    ...
+
+def kurtosis_next(
+    new_value: float,
+    state: KurtosisState
+) -> KurtosisState:
+    """
+    Update the Rolling Kurtosis state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point.
+
+    state : KurtosisState
+        The current state of the Rolling Kurtosis computation.
+
+    Returns
+    -------
+    KurtosisState
+        The updated state after including the new value.
+    """
+    ...
+
+@dataclass(frozen=True)
+class MedianState:
+    """State for the Rolling Median computation"""
+    median: float
+    sorted_window: List[float]
+    window: List[float]
+    period: int
+    ...
+
+class MedianResult(NamedTuple):
+    """Result of the Rolling Median computation"""
+    values: NDArray
+    state: MedianState
+
+def median(
+    data: NDArray,
+    period: int = 20,
+    release_gil: bool = False
+) -> MedianResult | Tuple[NDArray, MedianState]:
+    """
+    Rolling Median
+    ----------
+    Median of the window, the mean of the two middle values for an even period.
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array.
+
+    period : int, default 20
+        Size of the window (must be ``>= 1``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    MedianResult
+        A named tuple containing the result of the Rolling Median computation.
+        - values: **NDArray** of the same length as *data*.
+        - state: **MedianState** with (median: float, sorted_window: List[float], window: List[float], period: int)
+    """
+    ...
+
+def median_next(
+    new_value: float,
+    state: MedianState
+) -> MedianState:
+    """
+    Update the Rolling Median state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point.
+
+    state : MedianState
+        The current state of the Rolling Median computation.
+
+    Returns
+    -------
+    MedianState
+        The updated state after including the new value.
+    """
+    ...
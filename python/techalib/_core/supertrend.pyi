@@ -0,0 +1,96 @@
+from dataclasses import dataclass
+from typing import NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class SupertrendState:
+    """State for the SuperTrend computation"""
+    supertrend: float
+    direction: float
+    final_upper: float
+    final_lower: float
+    atr: float
+    prev_close: float
+    period: int
+    multiplier: float
+    ...
+
+class SupertrendResult(NamedTuple):
+    """Result of the SuperTrend computation"""
+    supertrend: NDArray
+    direction: NDArray
+    state: SupertrendState
+
+def supertrend(
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    period: int = 10,
+    multiplier: float = 3.0,
+    release_gil: bool = False
+) -> SupertrendResult | Tuple[NDArray, NDArray, SupertrendState]:
+    """
+    SuperTrend: ATR based trailing line.
+    ----------
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    period : int, default 10
+        The period of the ATR.
+
+    multiplier : float, default 3.0
+        The multiplier of the ATR used to calculate the bands.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    SupertrendResult
+        A named tuple containing the result of the SuperTrend computation.
+        - supertrend: **NDArray** with the SuperTrend line.
+        - direction: **NDArray** with ``1.0`` in an uptrend and ``-1.0`` in a downtrend.
+        - state: `SupertrendState`
+    """
+    ...
+
+def supertrend_next(
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: SupertrendState
+) -> SupertrendState:
+    """
+    Update the SuperTrend state with the next bar.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : SupertrendState
+        The current state of the SuperTrend computation.
+
+    Returns
+    -------
+    SupertrendState
+        The updated state after including the new bar.
+    """
+    ...
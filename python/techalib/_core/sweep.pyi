@@ -0,0 +1,169 @@
+from typing import NamedTuple, List, Sequence, Tuple
+
+from numpy.typing import NDArray
+
+from .bbands import BBandsMA, BBandsState
+from .ema import EmaState
+from .rsi import RsiState
+from .sma import SmaState
+
+class SmaSweepResult(NamedTuple):
+    """Result of the SMA sweep computation"""
+    values: NDArray
+    states: List[SmaState]
+
+class EmaSweepResult(NamedTuple):
+    """Result of the EMA sweep computation"""
+    values: NDArray
+    states: List[EmaState]
+
+class RsiSweepResult(NamedTuple):
+    """Result of the RSI sweep computation"""
+    values: NDArray
+    states: List[RsiState]
+
+class BBandsSweepResult(NamedTuple):
+    """Result of the BBands sweep computation"""
+    upper: NDArray
+    middle: NDArray
+    lower: NDArray
+    states: List[BBandsState]
+
+def sma_sweep(
+    data: NDArray,
+    periods: Sequence[int],
+    release_gil: bool = False
+) -> SmaSweepResult | Tuple[NDArray, List[SmaState]]:
+    """
+    SMA Sweep: Simple Moving Average for several periods
+    ----------
+
+    A single prefix sum of *data* is shared by every period.
+
+    Parameters
+    ----------
+    data : 1-D array
+        One dimensional array. Must satisfy
+        ``len(data) >= max(periods)``.
+    periods : Sequence[int]
+        Sizes of the rolling windows (each must be ``> 1``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    SmaSweepResult
+        A named tuple containing the result of the SMA sweep computation.
+        - values: **2-D array** of shape ``(len(data), len(periods))``, one column per period.
+        - states: **List[SmaState]**, one per period.
+    """
+    ...
+
+def ema_sweep(
+    data: NDArray,
+    periods: Sequence[int],
+    release_gil: bool = False
+) -> EmaSweepResult | Tuple[NDArray, List[EmaState]]:
+    """
+    EMA Sweep: Exponential Moving Average for several periods
+    ----------
+
+    The alpha of each period is ``2 / (period + 1)``.
+
+    Parameters
+    ----------
+    data : 1-D array
+        One dimensional array. Must satisfy
+        ``len(data) >= max(periods)``.
+    periods : Sequence[int]
+        Periods of the EMA (each must be ``> 1``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    EmaSweepResult
+        A named tuple containing the result of the EMA sweep computation.
+        - values: **2-D array** of shape ``(len(data), len(periods))``, one column per period.
+        - states: **List[EmaState]**, one per period.
+    """
+    ...
+
+def rsi_sweep(
+    data: NDArray,
+    periods: Sequence[int],
+    release_gil: bool = False
+) -> RsiSweepResult | Tuple[NDArray, List[RsiState]]:
+    """
+    RSI Sweep: Relative Strength Index for several periods
+    ----------
+
+    The price changes are computed once and shared by every period.
+
+    Parameters
+    ----------
+    data : 1-D array
+        One dimensional array. Must satisfy
+        ``len(data) > max(periods)``.
+    periods : Sequence[int]
+        Periods of the RSI (each must be ``> 1``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    RsiSweepResult
+        A named tuple containing the result of the RSI sweep computation.
+        - values: **2-D array** of shape ``(len(data), len(periods))``, one column per period.
+        - states: **List[RsiState]**, one per period.
+    """
+    ...
+
+def bbands_sweep(
+    data: NDArray,
+    periods: Sequence[int],
+    std_up: float = 2.0,
+    std_down: float = 2.0,
+    ma_type: BBandsMA = BBandsMA.SMA,
+    release_gil: bool = False
+) -> BBandsSweepResult | Tuple[NDArray, NDArray, NDArray, List[BBandsState]]:
+    """
+    BBands Sweep: Bollinger Bands for several periods
+    ----------
+
+    The sums of the values and of their squares are computed once and
+    shared by every period.
+
+    Parameters
+    ----------
+    data : 1-D array
+        One dimensional array. Must satisfy
+        ``len(data) >= max(periods)``.
+    periods : Sequence[int]
+        Sizes of the rolling windows (each must be ``> 1``).
+    std_up : float, default 2.0
+        Multiplier of the standard deviation for the upper band.
+    std_down : float, default 2.0
+        Multiplier of the standard deviation for the lower band.
+    ma_type : BBandsMA, default BBandsMA.SMA
+        Moving average used for the middle band.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    BBandsSweepResult
+        A named tuple containing the result of the BBands sweep computation.
+        - upper, middle, lower: **2-D arrays** of shape ``(len(data), len(periods))``,
+          one column per period.
+        - states: **List[BBandsState]**, one per period.
+    """
+    ...
@@ -0,0 +1,94 @@
+from dataclasses import dataclass
+from typing import List, NamedTuple, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class SwingState:
+    """State for the Swing High/Low computation"""
+    confirmed_high: float
+    confirmed_low: float
+    high_window: List[float]
+    low_window: List[float]
+    left: int
+    right: int
+    ...
+
+class SwingResult(NamedTuple):
+    """Result of the Swing High/Low computation"""
+    swing_high: NDArray
+    swing_low: NDArray
+    state: SwingState
+
+def swing(
+    high: NDArray,
+    low: NDArray,
+    left: int = 2,
+    right: int = 2,
+    release_gil: bool = False
+) -> SwingResult | Tuple[NDArray, NDArray, SwingState]:
+    """
+    Swing High/Low: fractal detection of the local highs and lows.
+    ----------
+
+    A swing high is strictly higher than the ``left`` previous highs and
+    higher than or equal to the ``right`` next highs. The swings are aligned
+    on their own bar but are only known ``right`` bars later, so the
+    ``right`` last values are always ``NaN``.
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    left : int, default 2
+        The number of bars before the swing.
+
+    right : int, default 2
+        The number of bars after the swing.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    SwingResult
+        A named tuple containing the result of the Swing High/Low computation.
+        - swing_high: **NDArray** with the swing highs.
+        - swing_low: **NDArray** with the swing lows.
+        - state: `SwingState`
+    """
+    ...
+
+def swing_next(
+    new_high: float,
+    new_low: float,
+    state: SwingState
+) -> SwingState:
+    """
+    Update the Swing High/Low state with the next bar.
+
+    ``state.confirmed_high`` and ``state.confirmed_low`` hold the swings
+    located ``right`` bars before the new bar, confirmed by it.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    state : SwingState
+        The current state of the Swing High/Low computation.
+
+    Returns
+    -------
+    SwingState
+        The updated state of the Swing High/Low computation.
+    """
+    ...
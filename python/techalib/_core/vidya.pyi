@@ -0,0 +1,76 @@
+from dataclasses import dataclass
+from typing import List, NamedTuple, Optional, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class VidyaState:
+    """State for the Vidya computation"""
+    vidya: float
+    up_sum: float
+    down_sum: float
+    prev_value: float
+    changes: List[float]
+    period: int
+    cmo_period: int
+    ...
+
+class VidyaResult(NamedTuple):
+    """Result of the Vidya computation"""
+    values: NDArray
+    state: VidyaState
+
+def vidya(
+    data: NDArray,
+    period: int = 14,
+    cmo_period: int = 9,
+    release_gil: bool = False
+) -> VidyaResult | Tuple[NDArray, VidyaState]:
+    """
+    VIDYA: Variable Index Dynamic Average
+    ----------
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array. Must satisfy len(data) > lookback.
+    period : int
+        Period of the underlying EMA (must be ``> 1``).
+    cmo_period : int, default 9
+        Period of the Chande Momentum Oscillator scaling the smoothing
+        factor (must be ``> 0``).
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    VidyaResult
+        A named tuple containing the result of the Vidya computation.
+        - values: **NDArray** of the same length as *data* containing the VIDYA.
+        - state: **VidyaState** with (vidya: float, up_sum: float, down_sum: float, prev_value: float, changes: List[float], period: int, cmo_period: int)
+    """
+    ...
+
+def vidya_next(
+    new_value: float,
+    state: VidyaState
+) -> VidyaState:
+    """
+    Update the Vidya state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point to include in the VIDYA.
+
+    state : VidyaState
+        The current state of the Vidya computation.
+
+    Returns
+    -------
+    VidyaState
+        The updated state after including the new value.
+    """
+    ...
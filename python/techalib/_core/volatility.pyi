@@ -0,0 +1,118 @@
+from dataclasses import dataclass
+from enum import Enum
+from typing import NamedTuple, List, Tuple
+
+from numpy.typing import NDArray
+
+class VolatilityEstimator(Enum):
+    CloseToClose = 0
+    Parkinson = 1
+    GarmanKlass = 2
+    RogersSatchell = 3
+    YangZhang = 4
+
+@dataclass(frozen=True)
+class VolatilityState:
+    """State for the Historical Volatility computation"""
+    volatility: float
+    prev_close: float
+    window: List[Tuple[float, float, float]]
+    period: int
+    estimator: VolatilityEstimator
+    annualization: float
+    ...
+
+class VolatilityResult(NamedTuple):
+    """Result of the Historical Volatility computation"""
+    values: NDArray
+    state: VolatilityState
+
+def volatility(
+    open: NDArray,
+    high: NDArray,
+    low: NDArray,
+    close: NDArray,
+    period: int = 20,
+    estimator: VolatilityEstimator = VolatilityEstimator.CloseToClose,
+    annualization: float = 252.0,
+    release_gil: bool = False
+) -> VolatilityResult | Tuple[NDArray, VolatilityState]:
+    """
+    Historical Volatility: annualised volatility over a rolling window.
+    ----------
+
+    The variance of the window is estimated with the selected estimator and
+    annualised as ``sqrt(variance * annualization)``. The ``CloseToClose`` and
+    ``YangZhang`` estimators use the previous close, so their first ``period``
+    values are ``NaN``, against ``period - 1`` for the other estimators.
+
+    Parameters
+    ----------
+    open : NDArray
+        Open prices.
+
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray
+        Close prices.
+
+    period : int, default 20
+        The size of the window.
+
+    estimator : VolatilityEstimator, default VolatilityEstimator.CloseToClose
+        The estimator of the variance. ``CloseToClose`` only uses the close
+        prices and ``Parkinson`` only the high and low prices.
+
+    annualization : float, default 252.0
+        The number of bars in a year, ``1.0`` gives the volatility per bar.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    VolatilityResult
+        A named tuple containing the result of the Historical Volatility computation.
+        - values: **NDArray** with the annualised volatility values.
+        - state: `VolatilityState`
+    """
+    ...
+
+def volatility_next(
+    new_open: float,
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: VolatilityState
+) -> VolatilityState:
+    """
+    Update the Historical Volatility state with the next bar.
+
+    Parameters
+    ----------
+    new_open : float
+        The open of the new bar.
+
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar.
+
+    state : VolatilityState
+        The current state of the Historical Volatility computation.
+
+    Returns
+    -------
+    VolatilityState
+        The updated state after including the new bar.
+    """
+    ...
@@ -0,0 +1,102 @@
+from dataclasses import dataclass
+from typing import NamedTuple, List, Optional, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class VwapState:
+    """State for the VWAP computation"""
+    vwap: float
+    upper: float
+    lower: float
+    cum_volume: float
+    m2: float
+    multiplier: float
+    ...
+
+class VwapResult(NamedTuple):
+    """Result of the VWAP computation"""
+    vwap: NDArray
+    upper: NDArray
+    lower: NDArray
+    state: VwapState
+
+def vwap(
+    price: NDArray,
+    volume: NDArray,
+    reset_mask: Optional[NDArray] = None,
+    anchors: Optional[List[int]] = None,
+    multiplier: float = 2.0,
+    release_gil: bool = False
+) -> VwapResult | Tuple[NDArray, NDArray, NDArray, VwapState]:
+    """
+    VWAP: Volume Weighted Average Price with standard deviation bands.
+    ----------
+
+    The accumulation starts again at every session boundary, the first bar
+    always starts a session. While the cumulative volume of the session is
+    zero, the outputs are NaN.
+
+    Parameters
+    ----------
+    price : NDArray
+        Prices, the typical price is common.
+
+    volume : NDArray
+        Volumes.
+
+    reset_mask : NDArray, optional
+        A boolean array with the same length as ``price``,
+        ``True`` starts a new session.
+
+    anchors : List[int], optional
+        Indices of the bars starting a new session, in ascending order.
+        Cannot be used together with ``reset_mask``.
+
+    multiplier : float, default 2.0
+        The multiplier of the standard deviation used to calculate the bands.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    VwapResult
+        A named tuple containing the result of the VWAP computation.
+        - vwap: **NDArray** with the VWAP values.
+        - upper: **NDArray** with the upper band values.
+        - lower: **NDArray** with the lower band values.
+        - state: `VwapState`
+    """
+    ...
+
+def vwap_next(
+    new_price: float,
+    new_volume: float,
+    state: VwapState,
+    reset: bool = False
+) -> VwapState:
+    """
+    Update the VWAP state with the next bar.
+
+    Parameters
+    ----------
+    new_price : float
+        The price of the new bar.
+
+    new_volume : float
+        The volume of the new bar.
+
+    state : VwapState
+        The current state of the VWAP computation.
+
+    reset : bool, default False
+        If ``True``, the new bar starts a new session.
+
+    Returns
+    -------
+    VwapState
+        The updated state after including the new bar.
+    """
+    ...
@@ -0,0 +1,122 @@
+from dataclasses import dataclass
+from typing import NamedTuple, Optional, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class ZigzagState:
+    """State for the ZigZag computation"""
+    direction: float
+    confirmed: float
+    last_pivot: float
+    last_pivot_idx: int
+    extreme: float
+    extreme_idx: int
+    max_high: float
+    max_high_idx: int
+    min_low: float
+    min_low_idx: int
+    idx: int
+    atr: Optional[float]
+    prev_close: Optional[float]
+    percent: Optional[float]
+    atr_period: Optional[int]
+    atr_multiplier: Optional[float]
+    ...
+
+class ZigzagResult(NamedTuple):
+    """Result of the ZigZag computation"""
+    pivots: NDArray
+    confirmed: NDArray
+    direction: NDArray
+    state: ZigzagState
+
+def zigzag(
+    high: NDArray,
+    low: NDArray,
+    close: Optional[NDArray] = None,
+    percent: float = 5.0,
+    atr_period: Optional[int] = None,
+    atr_multiplier: float = 3.0,
+    release_gil: bool = False
+) -> ZigzagResult | Tuple[NDArray, NDArray, NDArray, ZigzagState]:
+    """
+    ZigZag: swings confirmed by a percentage or ATR reversal.
+    ----------
+
+    A pivot is confirmed when the price reverses from the extreme of the
+    current leg by at least the threshold. The last leg, from the last
+    confirmed pivot to ``state.extreme``, is tentative and may repaint:
+    it never appears in ``pivots`` or ``confirmed``.
+
+    Parameters
+    ----------
+    high : NDArray
+        High prices.
+
+    low : NDArray
+        Low prices.
+
+    close : NDArray, optional
+        Close prices, required with an ATR threshold.
+
+    percent : float, default 5.0
+        The reversal threshold, in percent of the extreme price.
+        Ignored when ``atr_period`` is given.
+
+    atr_period : int, optional
+        If given, the reversal threshold is ``atr_multiplier`` times the ATR
+        over ``atr_period``.
+
+    atr_multiplier : float, default 3.0
+        The multiplier of the ATR threshold.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    ZigzagResult
+        A named tuple containing the result of the ZigZag computation.
+        - pivots: **NDArray** with the confirmed pivots, on the bar of the pivot.
+        - confirmed: **NDArray** with the confirmed pivots, on the bar where
+          they are confirmed (no lookahead).
+        - direction: **NDArray** with the direction of the current leg
+          (``1.0`` up, ``-1.0`` down).
+        - state: `ZigzagState`
+    """
+    ...
+
+def zigzag_next(
+    new_high: float,
+    new_low: float,
+    new_close: float,
+    state: ZigzagState
+) -> ZigzagState:
+    """
+    Update the ZigZag state with the next bar.
+
+    ``state.confirmed`` holds the pivot confirmed by this bar, if any, and
+    ``state.extreme`` the tentative end of the current leg.
+
+    Parameters
+    ----------
+    new_high : float
+        The high of the new bar.
+
+    new_low : float
+        The low of the new bar.
+
+    new_close : float
+        The close of the new bar, only used with an ATR threshold.
+
+    state : ZigzagState
+        The current state of the ZigZag computation.
+
+    Returns
+    -------
+    ZigzagState
+        The updated state of the ZigZag computation.
+    """
+    ...
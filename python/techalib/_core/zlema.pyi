@@ -0,0 +1,73 @@
+from dataclasses import dataclass
+from typing import List, NamedTuple, Optional, Tuple
+
+from numpy.typing import NDArray
+
+@dataclass(frozen=True)
+class ZlemaState:
+    """State for the Zlema computation"""
+    zlema: float
+    window: List[float]
+    period: int
+    alpha: float
+    ...
+
+class ZlemaResult(NamedTuple):
+    """Result of the Zlema computation"""
+    values: NDArray
+    state: ZlemaState
+
+def zlema(
+    data: NDArray,
+    period: int = 14,
+    alpha: Optional[float] = None,
+    release_gil: bool = False
+) -> ZlemaResult | Tuple[NDArray, ZlemaState]:
+    """
+    ZLEMA: Zero-Lag Exponential Moving Average
+    ----------
+
+    Parameters
+    ----------
+    data : NDArray
+        One dimensional array. Must satisfy len(data) > lookback.
+    period : int
+        Period of the EMA (must be ``> 1``).
+        The lag removed from the data is ``(period - 1) // 2``.
+    alpha : float, optional
+        Smoothing factor, ``2 / (period + 1)`` when ``None``.
+
+    release_gil : bool, default False
+        If ``True``, the GIL is released during the computation.
+        This is useful when using this function in a multi-threaded context.
+
+    Returns
+    -------
+    ZlemaResult
+        A named tuple containing the result of the Zlema computation.
+        - values: **NDArray** of the same length as *data* containing the ZLEMA.
+        - state: **ZlemaState** with (zlema: float, window: List[float], period: int, alpha: float)
+    """
+    ...
+
+def zlema_next(
+    new_value: float,
+    state: ZlemaState
+) -> ZlemaState:
+    """
+    Update the Zlema state with the next data.
+
+    Parameters
+    ----------
+    new_value : float
+        The next data point to include in the ZLEMA.
+
+    state : ZlemaState
+        The current state of the Zlema computation.
+
+    Returns
+    -------
+    ZlemaState
+        The updated state after including the new value.
+    """
+    ...
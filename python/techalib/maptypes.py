@@ -1,6 +1,60 @@
 from collections import namedtuple
 
 FCT_TO_NAMEDTUPLE = {
+    "sma_arrow": namedtuple("SmaArrowResult", ["values", "state"]),
+    "ema_arrow": namedtuple("EmaArrowResult", ["values", "state"]),
+    "wma_arrow": namedtuple("WmaArrowResult", ["values", "state"]),
+    "dema_arrow": namedtuple("DemaArrowResult", ["values", "state"]),
+    "tema_arrow": namedtuple("TemaArrowResult", ["values", "state"]),
+    "trima_arrow": namedtuple("TrimaArrowResult", ["values", "state"]),
+    "t3_arrow": namedtuple("T3ArrowResult", ["values", "state"]),
+    "kama_arrow": namedtuple("KamaArrowResult", ["values", "state"]),
+    "hma_arrow": namedtuple("HmaArrowResult", ["values", "state"]),
+    "zlema_arrow": namedtuple("ZlemaArrowResult", ["values", "state"]),
+    "alma_arrow": namedtuple("AlmaArrowResult", ["values", "state"]),
+    "smma_arrow": namedtuple("SmmaArrowResult", ["values", "state"]),
+    "vidya_arrow": namedtuple("VidyaArrowResult", ["values", "state"]),
+    "rsi_arrow": namedtuple("RsiArrowResult", ["values", "state"]),
+    "zscore_arrow": namedtuple("ZscoreArrowResult", ["values", "state"]),
+    "percent_rank_arrow": namedtuple("PercentRankArrowResult", ["values", "state"]),
+    "skew_arrow": namedtuple("SkewArrowResult", ["values", "state"]),
+    "kurtosis_arrow": namedtuple("KurtosisArrowResult", ["values", "state"]),
+    "median_arrow": namedtuple("MedianArrowResult", ["values", "state"]),
+    "bbands_arrow": namedtuple("BBandsArrowResult", ["upper", "middle", "lower", "state"]),
+    "macd_arrow": namedtuple("MacdArrowResult", ["macd", "signal", "histogram", "state"]),
+    "sma_sweep": namedtuple("SmaSweepResult", ["values", "states"]),
+    "ema_sweep": namedtuple("EmaSweepResult", ["values", "states"]),
+    "rsi_sweep": namedtuple("RsiSweepResult", ["values", "states"]),
+    "bbands_sweep": namedtuple("BBandsSweepResult", ["upper", "middle", "lower", "states"]),
+    "volatility": namedtuple("VolatilityResult", ["values", "state"]),
+    "zscore": namedtuple("ZscoreResult", ["values", "state"]),
+    "percent_rank": namedtuple("PercentRankResult", ["values", "state"]),
+    "skew": namedtuple("SkewResult", ["values", "state"]),
+    "kurtosis": namedtuple("KurtosisResult", ["values", "state"]),
+    "median": namedtuple("MedianResult", ["values", "state"]),
+    "cmf": namedtuple("CmfResult", ["values", "state"]),
+    "eom": namedtuple("EomResult", ["values", "state"]),
+    "force_index": namedtuple("ForceIndexResult", ["values", "state"]),
+    "elder_ray": namedtuple("ElderRayResult", ["bull_power", "bear_power", "state"]),
+    "vidya": namedtuple("VidyaResult", ["values", "state"]),
+    "smma": namedtuple("SmmaResult", ["values", "state"]),
+    "alma": namedtuple("AlmaResult", ["values", "state"]),
+    "zlema": namedtuple("ZlemaResult", ["values", "state"]),
+    "hma": namedtuple("HmaResult", ["values", "state"]),
+    "zigzag": namedtuple("ZigzagResult", ["pivots", "confirmed", "direction", "state"]),
+    "swing": namedtuple("SwingResult", ["swing_high", "swing_low", "state"]),
+    "pivots": namedtuple("PivotsResult", ["pivot", "r1", "r2", "r3", "s1", "s2", "s3", "state"]),
+    "heikin_ashi": namedtuple("HeikinAshiResult", ["open", "high", "low", "close", "state"]),
+    "vwap": namedtuple("VwapResult", ["vwap", "upper", "lower", "state"]),
+    "chandelier_exit": namedtuple("ChandelierExitResult", ["long_stop", "short_stop", "state"]),
+    "supertrend": namedtuple("SupertrendResult", ["supertrend", "direction", "state"]),
+    "ichimoku": namedtuple("IchimokuResult", ["tenkan", "kijun", "senkou_a", "senkou_b", "chikou", "state"]),
+    "envelope": namedtuple("EnvelopeResult", ["upper", "middle", "lower", "state"]),
+    "donchian": namedtuple("DonchianResult", ["upper", "middle", "lower", "state"]),
+    "keltner": namedtuple("KeltnerResult", ["upper", "middle", "lower", "state"]),
+    "atr": namedtuple("AtrResult", ["values", "state"]),
+    "ma": namedtuple("MaResult", ["values", "state"]),
+    "mavp": namedtuple("MavpResult", ["values", "state"]),
     "kama": namedtuple("KamaResult", ["values" ,"state"]),
     "t3": namedtuple("T3Result", ["values" ,"state"]),
     "trima": namedtuple("TrimaResult", ["values", "state"]),
@@ -8,6 +62,7 @@ FCT_TO_NAMEDTUPLE = {
     "dema": namedtuple("DemaResult", ["values" ,"state"]),
     "wma": namedtuple("WmaResult", ["values", "state"]),
     "bbands": namedtuple("BbandsResult", ["upper", "middle", "lower", "state"]),
+    "bbands_ext": namedtuple("BbandsExtResult", ["upper", "middle", "lower", "percent_b", "bandwidth", "state"]),
     "ema": namedtuple("EmaResult", ["values", "state"]),
     "sma": namedtuple("SmaResult", ["values", "state"]),
     "rsi": namedtuple("RsiResult", ["values", "state"]),
@@ -0,0 +1,151 @@
+"""
+Polars expression namespace
+----------
+
+Importing this module registers the ``techalib`` namespace on Polars expressions:
+
+    import polars as pl
+    import techalib.polars
+
+    df.with_columns(
+        pl.col("close").techalib.ema(20).alias("ema_20"),
+        pl.col("close").techalib.bbands(20).alias("bbands"),
+    )
+
+The columns are passed to techalib through the Arrow C Data Interface, so
+``Float64`` and ``Float32`` columns are not copied. ``Float32`` columns are
+processed in ``float32`` and give ``Float32`` results, other numeric columns are
+cast to ``Float64``. Null rows are skipped by the indicator and stay null in the
+result, as well as the warm-up period. Indicators with several outputs return a
+struct column.
+"""
+
+from typing import Callable, Optional, Sequence
+
+import polars as pl
+import pyarrow as pa
+
+from . import _core
+
+def _to_arrow(series: pl.Series) -> pa.Array:
+    if series.dtype != pl.Float32:
+        series = series.cast(pl.Float64)
+    return series.rechunk().to_arrow()
+
+def _to_series(name: str, values: object) -> pl.Series:
+    return pl.Series(name, pa.array(values))
+
+@pl.api.register_expr_namespace("techalib")
+class TechalibExpr:
+    """Indicators of techalib over a Polars expression"""
+
+    def __init__(self, expr: pl.Expr) -> None:
+        self._expr = expr
+
+    def _map(self, function: Callable, *args) -> pl.Expr:
+        def apply(series: pl.Series) -> pl.Series:
+            values, _ = function(_to_arrow(series), *args)
+            return _to_series(series.name, values)
+        return self._expr.map_batches(apply)
+
+    def _map_struct(self, function: Callable, fields: Sequence[str], *args) -> pl.Expr:
+        def apply(series: pl.Series) -> pl.Series:
+            *outputs, _ = function(_to_arrow(series), *args)
+            return pl.DataFrame(
+                [_to_series(field, values) for field, values in zip(fields, outputs)]
+            ).to_struct(series.name)
+        return self._expr.map_batches(apply)
+
+    def sma(self, period: int = 14) -> pl.Expr:
+        """SMA: Simple Moving Average"""
+        return self._map(_core.sma_arrow, period)
+
+    def ema(self, period: int = 14, alpha: Optional[float] = None) -> pl.Expr:
+        """EMA / EWMA: Exponential (Weighted) Moving Average"""
+        return self._map(_core.ema_arrow, period, alpha)
+
+    def wma(self, period: int = 14) -> pl.Expr:
+        """WMA: Weighted Moving Average"""
+        return self._map(_core.wma_arrow, period)
+
+    def dema(self, period: int = 14, alpha: Optional[float] = None) -> pl.Expr:
+        """DEMA: Double Exponential Moving Average"""
+        return self._map(_core.dema_arrow, period, alpha)
+
+    def tema(self, period: int = 14, alpha: Optional[float] = None) -> pl.Expr:
+        """TEMA: Triple Exponential Moving Average"""
+        return self._map(_core.tema_arrow, period, alpha)
+
+    def trima(self, period: int = 14) -> pl.Expr:
+        """TRIMA: Triangular Moving Average"""
+        return self._map(_core.trima_arrow, period)
+
+    def t3(self, period: int = 5, vfactor: float = 0.7, alpha: Optional[float] = None) -> pl.Expr:
+        """T3: Tillson Triple Moving Average"""
+        return self._map(_core.t3_arrow, period, vfactor, alpha)
+
+    def kama(self, period: int = 30) -> pl.Expr:
+        """KAMA: Kaufman Adaptive Moving Average"""
+        return self._map(_core.kama_arrow, period)
+
+    def hma(self, period: int = 16) -> pl.Expr:
+        """HMA: Hull Moving Average"""
+        return self._map(_core.hma_arrow, period)
+
+    def zlema(self, period: int = 14, alpha: Optional[float] = None) -> pl.Expr:
+        """ZLEMA: Zero-Lag Exponential Moving Average"""
+        return self._map(_core.zlema_arrow, period, alpha)
+
+    def alma(self, period: int = 9, offset: float = 0.85, sigma: float = 6.0) -> pl.Expr:
+        """ALMA: Arnaud Legoux Moving Average"""
+        return self._map(_core.alma_arrow, period, offset, sigma)
+
+    def smma(self, period: int = 14) -> pl.Expr:
+        """SMMA: Smoothed Moving Average (Wilder's RMA)"""
+        return self._map(_core.smma_arrow, period)
+
+    def vidya(self, period: int = 14, cmo_period: int = 9) -> pl.Expr:
+        """VIDYA: Variable Index Dynamic Average"""
+        return self._map(_core.vidya_arrow, period, cmo_period)
+
+    def rsi(self, period: int = 14) -> pl.Expr:
+        """RSI: Relative Strength Index"""
+        return self._map(_core.rsi_arrow, period)
+
+    def zscore(self, period: int = 20) -> pl.Expr:
+        """Rolling Z-Score"""
+        return self._map(_core.zscore_arrow, period)
+
+    def percent_rank(self, period: int = 20) -> pl.Expr:
+        """Rolling Percent Rank"""
+        return self._map(_core.percent_rank_arrow, period)
+
+    def skew(self, period: int = 20) -> pl.Expr:
+        """Rolling Skewness"""
+        return self._map(_core.skew_arrow, period)
+
+    def kurtosis(self, period: int = 20) -> pl.Expr:
+        """Rolling Excess Kurtosis"""
+        return self._map(_core.kurtosis_arrow, period)
+
+    def median(self, period: int = 20) -> pl.Expr:
+        """Rolling Median"""
+        return self._map(_core.median_arrow, period)
+
+    def bbands(
+        self,
+        period: int = 20,
+        std_up: float = 2.0,
+        std_down: float = 2.0,
+        ma_type: _core.BBandsMA = _core.BBandsMA.SMA,
+    ) -> pl.Expr:
+        """Bollinger Bands, as a struct of ``upper``, ``middle`` and ``lower``"""
+        return self._map_struct(
+            _core.bbands_arrow, ("upper", "middle", "lower"), period, std_up, std_down, ma_type
+        )
+
+    def macd(self, fast_period: int = 12, slow_period: int = 26, signal_period: int = 9) -> pl.Expr:
+        """MACD, as a struct of ``macd``, ``signal`` and ``histogram``"""
+        return self._map_struct(
+            _core.macd_arrow, ("macd", "signal", "histogram"), fast_period, slow_period, signal_period
+        )
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+# Builds the static library of the C API and runs the C test harness against
+# the generated header. Run it from the root of the repository.
+
+TARGET_DIR=${CARGO_TARGET_DIR:-target}
+CC=${CC:-cc}
+
+cargo build -p techalib-ffi
+
+"$CC" -std=c99 -Wall -Wextra -Werror \
+    -I crates/ffi/include \
+    tests/c/test_techalib.c \
+    "$TARGET_DIR/debug/libtechalib_ffi.a" \
+    -lm -lpthread -ldl \
+    -o "$TARGET_DIR/test_techalib_c"
+
+"$TARGET_DIR/test_techalib_c"
@@ -0,0 +1,169 @@
+/*
+    Test harness of the C API of techalib.
+
+    Run it from the root of the repository with:
+        bash tests/c/run.sh
+*/
+#include <math.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "techalib.h"
+
+#define LEN 64
+#define NEXT 16
+
+static int failures = 0;
+
+#define CHECK(cond)                                                        \
+    do {                                                                   \
+        if (!(cond)) {                                                     \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,         \
+                    __LINE__, #cond);                                      \
+            failures++;                                                    \
+        }                                                                  \
+    } while (0)
+
+#define CHECK_STATUS(call, expected)                                       \
+    do {                                                                   \
+        TechalibStatus status_ = (call);                                   \
+        if (status_ != (expected)) {                                       \
+            const char *message_ = techalib_last_error_message();          \
+            fprintf(stderr, "%s:%d: %s returned %s instead of %s (%s)\n",  \
+                    __FILE__, __LINE__, #call,                             \
+                    techalib_status_name(status_),                         \
+                    techalib_status_name(expected),                        \
+                    message_ ? message_ : "no message");                   \
+            failures++;                                                    \
+        }                                                                  \
+    } while (0)
+
+static int close_enough(TechalibFloat a, TechalibFloat b) {
+    if (isnan(a) || isnan(b)) {
+        return isnan(a) && isnan(b);
+    }
+    return fabs((double)(a - b)) <= 1e-9 * (1.0 + fabs((double)b));
+}
+
+static void fill_prices(TechalibFloat *close, TechalibFloat *high, TechalibFloat *low,
+                        size_t len) {
+    for (size_t i = 0; i < len; i++) {
+        close[i] = (TechalibFloat)(100.0 + 10.0 * sin((double)i / 5.0) + (double)i / 10.0);
+        high[i] = close[i] + (TechalibFloat)(1.0 + (double)(i % 3));
+        low[i] = close[i] - (TechalibFloat)(1.0 + (double)(i % 4));
+    }
+}
+
+static void test_ema_into_and_update(const TechalibFloat *data) {
+    TechalibFloat expected[LEN + NEXT];
+    TechalibFloat output[LEN];
+    TechalibEmaState *state = NULL;
+
+    CHECK_STATUS(techalib_ema_into(data, LEN + NEXT, 10, NAN, expected, NULL), TECHALIB_STATUS_OK);
+    CHECK_STATUS(techalib_ema_into(data, LEN, 10, NAN, output, &state), TECHALIB_STATUS_OK);
+    CHECK(state != NULL);
+    CHECK(isnan(output[0]));
+    for (size_t i = 0; i < LEN; i++) {
+        CHECK(close_enough(output[i], expected[i]));
+    }
+    for (size_t i = 0; i < NEXT; i++) {
+        TechalibFloat value = NAN;
+        CHECK_STATUS(techalib_ema_state_update(state, data[LEN + i], &value), TECHALIB_STATUS_OK);
+        CHECK(close_enough(value, expected[LEN + i]));
+    }
+    CHECK_STATUS(techalib_ema_state_update(state, NAN, NULL), TECHALIB_STATUS_DATA_NON_FINITE);
+    techalib_ema_state_free(state);
+}
+
+static void test_bbands_serialization(const TechalibFloat *data) {
+    TechalibFloat upper[LEN], middle[LEN], lower[LEN];
+    TechalibBBandsState *state = NULL;
+    TechalibBBandsState *restored = NULL;
+    size_t size = 0;
+
+    CHECK_STATUS(techalib_bbands_into(data, LEN, 20, 2.0, 2.0, TECHALIB_BBANDS_MA_SMA, NAN, upper,
+                                      middle, lower, &state),
+                 TECHALIB_STATUS_OK);
+
+    CHECK_STATUS(techalib_bbands_state_serialize(state, NULL, 0, &size),
+                 TECHALIB_STATUS_BUFFER_TOO_SMALL);
+    CHECK(size > 0);
+    uint8_t *buffer = malloc(size);
+    size_t written = 0;
+    CHECK_STATUS(techalib_bbands_state_serialize(state, buffer, size, &written), TECHALIB_STATUS_OK);
+    CHECK(written == size);
+    CHECK_STATUS(techalib_bbands_state_deserialize(buffer, written, &restored), TECHALIB_STATUS_OK);
+
+    for (size_t i = 0; i < NEXT; i++) {
+        TechalibFloat a[5], b[5];
+        CHECK_STATUS(techalib_bbands_state_update(state, data[LEN + i], &a[0], &a[1], &a[2], &a[3],
+                                                  &a[4]),
+                     TECHALIB_STATUS_OK);
+        CHECK_STATUS(techalib_bbands_state_update(restored, data[LEN + i], &b[0], &b[1], &b[2],
+                                                  &b[3], &b[4]),
+                     TECHALIB_STATUS_OK);
+        for (size_t j = 0; j < 5; j++) {
+            CHECK(a[j] == b[j]);
+        }
+    }
+
+    TechalibSmaState *wrong_kind = NULL;
+    CHECK_STATUS(techalib_sma_state_deserialize(buffer, written, &wrong_kind),
+                 TECHALIB_STATUS_INVALID_STATE);
+    CHECK(wrong_kind == NULL);
+    CHECK_STATUS(techalib_bbands_state_deserialize(buffer, written - 1, &restored),
+                 TECHALIB_STATUS_INVALID_STATE);
+
+    free(buffer);
+    techalib_bbands_state_free(state);
+    techalib_bbands_state_free(restored);
+}
+
+static void test_ohlcv(const TechalibFloat *close, const TechalibFloat *high,
+                       const TechalibFloat *low) {
+    TechalibFloat output[LEN];
+    TechalibAtrState *state = NULL;
+    TechalibOhlcv ohlcv = {NULL, high, low, close, NULL, LEN};
+
+    CHECK_STATUS(techalib_atr_into(&ohlcv, 14, output, &state), TECHALIB_STATUS_OK);
+    CHECK(output[LEN - 1] > 0.0);
+    TechalibFloat atr = NAN;
+    CHECK_STATUS(techalib_atr_state_update(state, high[LEN], low[LEN], close[LEN], &atr),
+                 TECHALIB_STATUS_OK);
+    CHECK(atr > 0.0);
+    techalib_atr_state_free(state);
+
+    TechalibFloat typprice[LEN];
+    CHECK_STATUS(techalib_typprice_into(&ohlcv, typprice), TECHALIB_STATUS_OK);
+    CHECK(close_enough(typprice[3], (high[3] + low[3] + close[3]) / 3.0));
+}
+
+static void test_errors(const TechalibFloat *data) {
+    TechalibFloat output[LEN];
+
+    CHECK_STATUS(techalib_sma_into(data, 5, 10, output, NULL), TECHALIB_STATUS_INSUFFICIENT_DATA);
+    CHECK(techalib_last_error_message() != NULL);
+    CHECK_STATUS(techalib_sma_into(NULL, LEN, 10, output, NULL), TECHALIB_STATUS_NULL_POINTER);
+    CHECK(strstr(techalib_last_error_message(), "data") != NULL);
+    CHECK_STATUS(techalib_rsi_state_update(NULL, 1.0, NULL), TECHALIB_STATUS_NULL_POINTER);
+    CHECK_STATUS(techalib_ema_into(data, LEN, 1, NAN, output, NULL), TECHALIB_STATUS_BAD_PARAM);
+    techalib_sma_state_free(NULL);
+}
+
+int main(void) {
+    TechalibFloat close[LEN + NEXT], high[LEN + NEXT], low[LEN + NEXT];
+    fill_prices(close, high, low, LEN + NEXT);
+
+    test_ema_into_and_update(close);
+    test_bbands_serialization(close);
+    test_ohlcv(close, high, low);
+    test_errors(close);
+
+    if (failures) {
+        fprintf(stderr, "❌ %d check(s) failed\n", failures);
+        return 1;
+    }
+    printf("✅ C API tests passed\n");
+    return 0;
+}
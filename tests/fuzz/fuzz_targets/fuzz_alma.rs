@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::alma::alma;
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<Float>, u8, Float, Float)| {
+    let (v, w, offset, sigma) = data;
+    let w = (w as usize % v.len().saturating_add(1)).max(1);
+    let _ = alma(&v, w, offset, sigma);
+});
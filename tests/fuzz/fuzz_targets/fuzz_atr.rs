@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::atr::atr;
+use techalib::types::{Float, Ohlcv};
+
+fuzz_target!(|data: (Vec<(Float, Float, Float)>, u8)| {
+    let (bars, period) = data;
+    let high: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let low: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let close: Vec<Float> = bars.iter().map(|b| b.2).collect();
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let _ = atr(&ohlcv, period as usize);
+});
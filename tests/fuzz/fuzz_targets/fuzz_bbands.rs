@@ -1,7 +1,7 @@
 #![no_main]
 
 use libfuzzer_sys::fuzz_target;
-use techalib::indicators::bbands::{bbands, BBandsMA, DeviationMulipliers};
+use techalib::indicators::bbands::{bbands, bbands_ext, BBandsMA, DeviationMulipliers};
 use techalib::types::Float;
 
 fuzz_target!(|data: (Vec<Float>, u8, Float, Float)| {
@@ -16,4 +16,13 @@ fuzz_target!(|data: (Vec<Float>, u8, Float, Float)| {
         },
         BBandsMA::SMA,
     );
+    let _ = bbands_ext(
+        &data,
+        period,
+        DeviationMulipliers {
+            up: std_up,
+            down: std_down,
+        },
+        BBandsMA::EMA(None),
+    );
 });
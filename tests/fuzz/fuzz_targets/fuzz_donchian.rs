@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::donchian::donchian;
+use techalib::types::{Float, Ohlcv};
+
+fuzz_target!(|data: (Vec<Float>, Vec<Float>, u8)| {
+    let (high, low, period) = data;
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        ..Default::default()
+    };
+    let _ = donchian(&ohlcv, period as usize);
+});
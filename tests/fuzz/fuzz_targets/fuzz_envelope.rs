@@ -0,0 +1,27 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::envelope::envelope;
+use techalib::indicators::ma::MaType;
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<Float>, u8, Float, u8)| {
+    let (data, period, percent, ma_type) = data;
+    let period = (period as usize % data.len().saturating_add(1)).max(1);
+    let ma_type = match ma_type % 13 {
+        0 => MaType::SMA,
+        1 => MaType::EMA(None),
+        2 => MaType::WMA,
+        3 => MaType::DEMA(None),
+        4 => MaType::TEMA(None),
+        5 => MaType::TRIMA,
+        6 => MaType::KAMA,
+        7 => MaType::T3(0.7, None),
+        8 => MaType::HMA,
+        9 => MaType::ZLEMA(None),
+        10 => MaType::ALMA(0.85, 6.0),
+        11 => MaType::SMMA,
+        _ => MaType::VIDYA(9),
+    };
+    let _ = envelope(&data, period, percent, ma_type);
+});
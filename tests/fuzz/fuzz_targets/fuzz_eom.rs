@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::eom::eom;
+use techalib::types::{Float, Ohlcv};
+
+fuzz_target!(|data: (Vec<(Float, Float, Float)>, u8, Float)| {
+    let (bars, period, scale) = data;
+    let high: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let low: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let volume: Vec<Float> = bars.iter().map(|b| b.2).collect();
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        volume: &volume,
+        ..Default::default()
+    };
+    let _ = eom(&ohlcv, period as usize, scale);
+});
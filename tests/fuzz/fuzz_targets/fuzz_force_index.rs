@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::force_index::force_index;
+use techalib::types::{Float, Ohlcv};
+
+fuzz_target!(|data: (Vec<(Float, Float)>, u8)| {
+    let (bars, period) = data;
+    let close: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let volume: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let ohlcv = Ohlcv {
+        close: &close,
+        volume: &volume,
+        ..Default::default()
+    };
+    let _ = force_index(&ohlcv, period as usize);
+});
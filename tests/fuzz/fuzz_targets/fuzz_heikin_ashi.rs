@@ -0,0 +1,22 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::heikin_ashi::heikin_ashi;
+use techalib::types::{Float, Ohlcv};
+
+type Bar = (Float, Float, Float, Float);
+
+fuzz_target!(|bars: Vec<Bar>| {
+    let open: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let high: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let low: Vec<Float> = bars.iter().map(|b| b.2).collect();
+    let close: Vec<Float> = bars.iter().map(|b| b.3).collect();
+    let ohlcv = Ohlcv {
+        open: &open,
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let _ = heikin_ashi(&ohlcv);
+});
@@ -0,0 +1,27 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::ichimoku::{ichimoku, IchimokuPeriods};
+use techalib::types::{Float, Ohlcv};
+
+type Bar = (Float, Float, Float);
+
+fuzz_target!(|data: (Vec<Bar>, u8, u8, u8, u8)| {
+    let (bars, tenkan, kijun, senkou_b, displacement) = data;
+    let high: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let low: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let close: Vec<Float> = bars.iter().map(|b| b.2).collect();
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let periods = IchimokuPeriods {
+        tenkan: tenkan as usize,
+        kijun: kijun as usize,
+        senkou_b: senkou_b as usize,
+        displacement: displacement as usize,
+    };
+    let _ = ichimoku(&ohlcv, periods);
+});
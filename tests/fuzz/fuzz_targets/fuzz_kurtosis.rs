@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::stats::kurtosis::kurtosis;
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<Float>, u8)| {
+    let (input, period) = data;
+    let _ = kurtosis(&input, period as usize);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::math_operator::{add, div, mult, sub};
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<Float>, Vec<Float>)| {
+    let (data0, data1) = data;
+    let _ = add(&data0, &data1);
+    let _ = sub(&data0, &data1);
+    let _ = mult(&data0, &data1);
+    let _ = div(&data0, &data1);
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::mavp::{mavp, MavpMA};
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<(Float, Float)>, u8, u8, u8)| {
+    let (v, min_period, extra_period, ma_type) = data;
+    let (values, periods): (Vec<Float>, Vec<Float>) = v.into_iter().unzip();
+    let min_period = min_period as usize;
+    let max_period = min_period + extra_period as usize;
+    let ma_type = match ma_type % 3 {
+        0 => MavpMA::SMA,
+        1 => MavpMA::EMA,
+        _ => MavpMA::WMA,
+    };
+    let _ = mavp(&values, &periods, min_period, max_period, ma_type);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::stats::percent_rank::percent_rank;
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<Float>, u8)| {
+    let (input, period) = data;
+    let _ = percent_rank(&input, period as usize);
+});
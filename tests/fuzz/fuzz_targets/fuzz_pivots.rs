@@ -0,0 +1,31 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::pivots::{pivots, PivotMethod};
+use techalib::types::{Float, Ohlcv};
+
+type Bar = (Float, Float, Float, Float, bool);
+
+fuzz_target!(|data: (Vec<Bar>, u8)| {
+    let (bars, method) = data;
+    let open: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let high: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let low: Vec<Float> = bars.iter().map(|b| b.2).collect();
+    let close: Vec<Float> = bars.iter().map(|b| b.3).collect();
+    let new_session: Vec<bool> = bars.iter().map(|b| b.4).collect();
+    let ohlcv = Ohlcv {
+        open: &open,
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let method = match method % 5 {
+        0 => PivotMethod::Classic,
+        1 => PivotMethod::Fibonacci,
+        2 => PivotMethod::Camarilla,
+        3 => PivotMethod::Woodie,
+        _ => PivotMethod::Demark,
+    };
+    let _ = pivots(&ohlcv, &new_session, method);
+});
@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::price_transform::{avgprice, medprice, typprice, wclprice};
+use techalib::types::{Float, Ohlcv};
+
+fuzz_target!(|data: (Vec<Float>, Vec<Float>, Vec<Float>, Vec<Float>)| {
+    let (open, high, low, close) = data;
+    let ohlcv = Ohlcv {
+        open: &open,
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let _ = avgprice(&ohlcv);
+    let _ = medprice(&ohlcv);
+    let _ = typprice(&ohlcv);
+    let _ = wclprice(&ohlcv);
+});
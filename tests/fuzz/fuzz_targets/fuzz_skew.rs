@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::stats::skew::skew;
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<Float>, u8)| {
+    let (input, period) = data;
+    let _ = skew(&input, period as usize);
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::swing::swing;
+use techalib::types::{Float, Ohlcv};
+
+fuzz_target!(|data: (Vec<(Float, Float)>, u8, u8)| {
+    let (bars, left, right) = data;
+    let high: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let low: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        ..Default::default()
+    };
+    let _ = swing(&ohlcv, left as usize, right as usize);
+});
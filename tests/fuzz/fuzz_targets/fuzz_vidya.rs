@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::vidya::vidya;
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<Float>, u8, u8)| {
+    let (v, w, cmo) = data;
+    let w = (w as usize % v.len().saturating_add(1)).max(1);
+    let _ = vidya(&v, w, cmo as usize);
+});
@@ -0,0 +1,32 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::volatility::{volatility, VolatilityEstimator};
+use techalib::types::{Float, Ohlcv};
+
+type Bar = (Float, Float, Float, Float);
+
+const ESTIMATORS: [VolatilityEstimator; 5] = [
+    VolatilityEstimator::CloseToClose,
+    VolatilityEstimator::Parkinson,
+    VolatilityEstimator::GarmanKlass,
+    VolatilityEstimator::RogersSatchell,
+    VolatilityEstimator::YangZhang,
+];
+
+fuzz_target!(|data: (Vec<Bar>, u8, u8, Float)| {
+    let (bars, period, estimator, annualization) = data;
+    let open: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let high: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let low: Vec<Float> = bars.iter().map(|b| b.2).collect();
+    let close: Vec<Float> = bars.iter().map(|b| b.3).collect();
+    let ohlcv = Ohlcv {
+        open: &open,
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let estimator = ESTIMATORS[estimator as usize % ESTIMATORS.len()];
+    let _ = volatility(&ohlcv, period as usize, estimator, annualization);
+});
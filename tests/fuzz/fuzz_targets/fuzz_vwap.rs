@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::vwap::{vwap, VwapReset};
+use techalib::types::Float;
+
+fuzz_target!(|data: (Vec<(Float, Float, bool)>, Vec<usize>, Float)| {
+    let (bars, anchors, multiplier) = data;
+    let price: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let volume: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let mask: Vec<bool> = bars.iter().map(|b| b.2).collect();
+    let _ = vwap(&price, &volume, VwapReset::Never, multiplier);
+    let _ = vwap(&price, &volume, VwapReset::Mask(&mask), multiplier);
+    let _ = vwap(&price, &volume, VwapReset::Anchors(&anchors), multiplier);
+});
@@ -0,0 +1,27 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use techalib::indicators::zigzag::{zigzag, ZigzagThreshold};
+use techalib::types::{Float, Ohlcv};
+
+fuzz_target!(|data: (Vec<(Float, Float, Float)>, bool, u8, Float)| {
+    let (bars, use_atr, period, value) = data;
+    let high: Vec<Float> = bars.iter().map(|b| b.0).collect();
+    let low: Vec<Float> = bars.iter().map(|b| b.1).collect();
+    let close: Vec<Float> = bars.iter().map(|b| b.2).collect();
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let threshold = if use_atr {
+        ZigzagThreshold::Atr {
+            period: period as usize,
+            multiplier: value,
+        }
+    } else {
+        ZigzagThreshold::Percent(value)
+    };
+    let _ = zigzag(&ohlcv, threshold);
+});
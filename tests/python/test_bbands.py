@@ -50,8 +50,36 @@ def test_bbands_matype_ema_success(csv_loader):
     assert(next_state.middle == final_result.state.middle)
     assert(next_state.lower == final_result.state.lower)
 
+def test_bbands_matype_hma_success(csv_loader):
+    df = csv_loader("bbands")
+    result = tx.bbands(df["close"].iloc[:-1], period=20, ma_type=tx.BBandsMA.HMA)
+    final_result = tx.bbands(df["close"], period=20, ma_type=tx.BBandsMA.HMA)
+    next_state = tx.bbands_next(df["close"].iloc[-1], result.state)
+    testing.assert_allclose(result.upper, final_result.upper[:-1])
+    testing.assert_allclose(result.middle, final_result.middle[:-1])
+    testing.assert_allclose(result.lower, final_result.lower[:-1])
+    testing.assert_allclose(final_result.middle, tx.ma(df["close"], 20, tx.MaType.HMA).values)
+    assert(abs(next_state.middle - final_result.state.middle) < 1e-8)
+    assert(next_state.ma.value == next_state.middle)
+
 def test_thread_bbands(thread_test):
     def bbands_tx_lambda(data):
       return tx.bbands(data, 20, 2.0, 2.0, release_gil = True)
 
     thread_test(bbands_tx_lambda, n_threads=4)
+
+def test_bbands_ext_success(csv_loader):
+    df = csv_loader("bbands")
+    data = np.array(df["close"])
+    result = tx.bbands_ext(data[:-1], period=20, std_up=2.0, std_down=2.0)
+    final_result = tx.bbands_ext(data, period=20, std_up=2.0, std_down=2.0)
+    bands = tx.bbands(data, period=20, std_up=2.0, std_down=2.0)
+
+    next_state = tx.bbands_next(data[-1], result.state)
+    testing.assert_allclose(final_result.upper, bands.upper)
+    testing.assert_allclose(final_result.lower, bands.lower)
+    testing.assert_allclose(final_result.percent_b, (data - bands.lower) / (bands.upper - bands.lower))
+    testing.assert_allclose(final_result.bandwidth, (bands.upper - bands.lower) / bands.middle)
+    testing.assert_allclose(result.percent_b, final_result.percent_b[:-1])
+    assert(abs(next_state.percent_b - final_result.state.percent_b) < 1e-8)
+    assert(abs(next_state.bandwidth - final_result.state.bandwidth) < 1e-8)
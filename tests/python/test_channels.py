@@ -0,0 +1,77 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _ohlc(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    open_ = close + rng.normal(0.0, 0.5, n)
+    high = np.maximum(open_, close) + rng.random(n)
+    low = np.minimum(open_, close) - rng.random(n)
+    return open_, high, low, close
+
+def _slow_atr(high, low, close, period):
+    tr = np.maximum(high[1:] - low[1:], np.maximum(np.abs(high[1:] - close[:-1]), np.abs(low[1:] - close[:-1])))
+    out = np.full(len(close), np.nan)
+    out[period] = tr[:period].mean()
+    for i in range(period + 1, len(close)):
+        out[i] = (out[i - 1] * (period - 1) + tr[i - 1]) / period
+    return out
+
+def test_atr_numpy_success():
+    _, high, low, close = _ohlc()
+    result = tx.atr(high[:-1], low[:-1], close[:-1], 14)
+    final_result = tx.atr(high, low, close, 14)
+
+    next_state = tx.atr_next(high[-1], low[-1], close[-1], result.state)
+    testing.assert_allclose(result.values, final_result.values[:-1])
+    testing.assert_allclose(final_result.values, _slow_atr(high, low, close, 14), atol=1e-8)
+    assert(abs(next_state.atr - final_result.state.atr) < 1e-8)
+
+def test_keltner_numpy_success():
+    _, high, low, close = _ohlc()
+    result = tx.keltner(high[:-1], low[:-1], close[:-1], 20, 10, 2.0)
+    final_result = tx.keltner(high, low, close, 20, 10, 2.0)
+
+    next_state = tx.keltner_next(high[-1], low[-1], close[-1], result.state)
+    atr = _slow_atr(high, low, close, 10)
+    middle = tx.ema(close, 20).values
+    testing.assert_allclose(result.upper, final_result.upper[:-1])
+    testing.assert_allclose(final_result.middle[19:], middle[19:], atol=1e-8)
+    testing.assert_allclose(final_result.upper[19:], (middle + 2.0 * atr)[19:], atol=1e-8)
+    assert(abs(next_state.upper - final_result.state.upper) < 1e-8)
+    assert(abs(next_state.lower - final_result.state.lower) < 1e-8)
+
+def test_donchian_numpy_success():
+    _, high, low, _ = _ohlc()
+    result = tx.donchian(high[:-1], low[:-1], 20)
+    final_result = tx.donchian(high, low, 20)
+
+    next_state = tx.donchian_next(high[-1], low[-1], result.state)
+    testing.assert_allclose(result.upper, final_result.upper[:-1])
+    expected_upper = np.array([high[i - 19:i + 1].max() for i in range(19, len(high))])
+    expected_lower = np.array([low[i - 19:i + 1].min() for i in range(19, len(low))])
+    testing.assert_allclose(final_result.upper[19:], expected_upper)
+    testing.assert_allclose(final_result.lower[19:], expected_lower)
+    assert(next_state.upper == final_result.state.upper)
+    assert(next_state.lower == final_result.state.lower)
+    assert(next_state.high_window == final_result.state.high_window)
+
+def test_envelope_numpy_success():
+    _, _, _, close = _ohlc()
+    for ma_type in [tx.MaType.SMA, tx.MaType.EMA, tx.MaType.KAMA]:
+        result = tx.envelope(close[:-1], 20, 2.5, ma_type)
+        final_result = tx.envelope(close, 20, 2.5, ma_type)
+
+        next_state = tx.envelope_next(close[-1], result.state)
+        middle = tx.ma(close, 20, ma_type).values
+        testing.assert_allclose(result.upper, final_result.upper[:-1])
+        testing.assert_allclose(final_result.middle, middle)
+        testing.assert_allclose(final_result.upper, middle * 1.025)
+        assert(abs(next_state.middle - final_result.state.middle) < 1e-8)
+
+def test_thread_envelope(thread_test):
+    def envelope_tx_lambda(data):
+        return tx.envelope(data, 20, 2.5, release_gil = True)
+
+    thread_test(envelope_tx_lambda, n_threads=4)
@@ -0,0 +1,36 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _ohlc(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    open_ = close + rng.normal(0.0, 0.5, n)
+    high = np.maximum(open_, close) + rng.random(n)
+    low = np.minimum(open_, close) - rng.random(n)
+    return open_, high, low, close
+
+def test_heikin_ashi_numpy_success():
+    open_, high, low, close = _ohlc()
+    result = tx.heikin_ashi(open_, high, low, close)
+
+    ha_close = (open_ + high + low + close) / 4.0
+    ha_open = np.empty_like(close)
+    ha_open[0] = (open_[0] + close[0]) / 2.0
+    for i in range(1, len(close)):
+        ha_open[i] = (ha_open[i - 1] + ha_close[i - 1]) / 2.0
+    testing.assert_allclose(result.close, ha_close)
+    testing.assert_allclose(result.open, ha_open)
+    testing.assert_allclose(result.high, np.maximum(high, np.maximum(ha_open, ha_close)))
+    testing.assert_allclose(result.low, np.minimum(low, np.minimum(ha_open, ha_close)))
+
+def test_heikin_ashi_next_success():
+    open_, high, low, close = _ohlc()
+    result = tx.heikin_ashi(open_[:-1], high[:-1], low[:-1], close[:-1])
+    final_result = tx.heikin_ashi(open_, high, low, close)
+
+    next_state = tx.heikin_ashi_next(open_[-1], high[-1], low[-1], close[-1], result.state)
+    assert(next_state.open == final_result.state.open)
+    assert(next_state.close == final_result.state.close)
+    atr = tx.atr(final_result.high, final_result.low, final_result.close, 14)
+    assert(np.isfinite(atr.values[14:]).all())
@@ -0,0 +1,56 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _ohlc(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    open_ = close + rng.normal(0.0, 0.5, n)
+    high = np.maximum(open_, close) + rng.random(n)
+    low = np.minimum(open_, close) - rng.random(n)
+    return open_, high, low, close
+
+def _midpoint(high, low, period):
+    out = np.full(len(high), np.nan)
+    for i in range(period - 1, len(high)):
+        out[i] = (high[i - period + 1:i + 1].max() + low[i - period + 1:i + 1].min()) / 2.0
+    return out
+
+def _shift(data, n):
+    out = np.full(len(data), np.nan)
+    if n >= 0:
+        out[n:] = data[:len(data) - n]
+    else:
+        out[:n] = data[-n:]
+    return out
+
+def test_ichimoku_numpy_success():
+    _, high, low, close = _ohlc()
+    result = tx.ichimoku(high, low, close)
+
+    tenkan = _midpoint(high, low, 9)
+    kijun = _midpoint(high, low, 26)
+    senkou_a = (tenkan + kijun) / 2.0
+    senkou_b = _midpoint(high, low, 52)
+    testing.assert_allclose(result.tenkan, tenkan)
+    testing.assert_allclose(result.kijun, kijun)
+    testing.assert_allclose(result.senkou_a, _shift(senkou_a, 26))
+    testing.assert_allclose(result.senkou_b, _shift(senkou_b, 26))
+    testing.assert_allclose(result.chikou, _shift(close, -26))
+    testing.assert_allclose(result.state.senkou_a_ahead, senkou_a[-26:])
+    testing.assert_allclose(result.state.senkou_b_ahead, senkou_b[-26:])
+
+def test_ichimoku_next_success():
+    _, high, low, close = _ohlc()
+    result = tx.ichimoku(high[:-1], low[:-1], close[:-1])
+    final_result = tx.ichimoku(high, low, close)
+
+    next_state = tx.ichimoku_next(high[-1], low[-1], close[-1], result.state)
+    testing.assert_allclose(result.tenkan, final_result.tenkan[:-1])
+    assert(abs(next_state.tenkan - final_result.state.tenkan) < 1e-8)
+    assert(abs(next_state.kijun - final_result.state.kijun) < 1e-8)
+    assert(abs(next_state.senkou_a - final_result.senkou_a[-1]) < 1e-8)
+    assert(abs(next_state.senkou_b - final_result.senkou_b[-1]) < 1e-8)
+    assert(next_state.chikou == close[-1])
+    testing.assert_allclose(next_state.senkou_a_ahead, final_result.state.senkou_a_ahead)
+    testing.assert_allclose(next_state.senkou_b_ahead, final_result.state.senkou_b_ahead)
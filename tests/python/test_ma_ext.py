@@ -0,0 +1,53 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+import pytest
+
+def _close(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    return 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+
+CASES = [
+    (tx.hma, tx.hma_next, "hma", {"period": 16}),
+    (tx.zlema, tx.zlema_next, "zlema", {"period": 14}),
+    (tx.alma, tx.alma_next, "alma", {"period": 9, "offset": 0.85, "sigma": 6.0}),
+    (tx.smma, tx.smma_next, "smma", {"period": 14}),
+    (tx.vidya, tx.vidya_next, "vidya", {"period": 14, "cmo_period": 9}),
+]
+
+@pytest.mark.parametrize("fct,fct_next,field,kwargs", CASES)
+def test_ma_ext_numpy_success(fct, fct_next, field, kwargs):
+    close = _close()
+    result = fct(close[:-1], **kwargs)
+    final_result = fct(close, **kwargs)
+
+    next_state = fct_next(close[-1], result.state)
+    testing.assert_allclose(result.values, final_result.values[:-1])
+    assert(abs(getattr(next_state, field) - getattr(final_result.state, field)) < 1e-8)
+
+def test_smma_matches_rsi_smoothing():
+    close = _close()
+    gains = np.maximum(np.diff(close), 0.0)
+    result = tx.smma(gains, 14)
+    alpha = 1.0 / 14
+    expected = gains[:14].mean()
+    for value in gains[14:]:
+        expected += alpha * (value - expected)
+    assert(abs(result.state.smma - expected) < 1e-8)
+
+def test_ma_ext_in_ma_type():
+    close = _close()
+    testing.assert_allclose(
+        tx.ma(close, 16, tx.MaType.HMA).values,
+        tx.hma(close, 16).values,
+    )
+    testing.assert_allclose(
+        tx.ma(close, 14, tx.MaType.SMMA).values,
+        tx.smma(close, 14).values,
+    )
+
+def test_thread_ma_ext(thread_test):
+    def hma_tx_lambda(data):
+        return tx.hma(data, 16, release_gil = True)
+
+    thread_test(hma_tx_lambda, n_threads=4)
@@ -0,0 +1,61 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _periods(n: int):
+    return (np.arange(n) * 7 % 40).astype(np.float64) + 0.5
+
+def _slow_sma(data, periods, min_period, max_period):
+    out = np.full(len(data), np.nan)
+    for i in range(max_period - 1, len(data)):
+        p = int(np.clip(int(periods[i]), min_period, max_period))
+        out[i] = data[i + 1 - p:i + 1].mean()
+    return out
+
+def test_mavp_numpy_success(csv_loader):
+    df = csv_loader("sma")
+    data = np.array(df["close"])
+    periods = _periods(len(data))
+    result = tx.mavp(data[:-1], periods[:-1], 2, 30)
+    final_result = tx.mavp(data, periods, 2, 30)
+
+    next_state = tx.mavp_next(data[-1], periods[-1], result.state)
+    testing.assert_allclose(result.values, final_result.values[:-1])
+    testing.assert_allclose(final_result.values, _slow_sma(data, periods, 2, 30), atol=1e-8)
+    assert(abs(next_state.mavp - final_result.state.mavp) < 1e-8)
+    assert(next_state.window == final_result.state.window)
+
+def test_mavp_constant_period_is_sma(csv_loader):
+    df = csv_loader("sma")
+    periods = np.full(len(df["close"]), 30.0)
+    result = tx.mavp(df["close"], periods, 2, 30)
+    testing.assert_allclose(result.values, df["out"], atol=1e-8)
+
+def test_mavp_ema_wma_next_success(csv_loader):
+    df = csv_loader("sma")
+    data = np.array(df["close"])
+    periods = _periods(len(data))
+    for ma_type in [tx.MavpMA.EMA, tx.MavpMA.WMA]:
+        result = tx.mavp(data[:-1], periods[:-1], 5, 30, ma_type)
+        final_result = tx.mavp(data, periods, 5, 30, ma_type)
+        next_state = tx.mavp_next(data[-1], periods[-1], result.state)
+        testing.assert_allclose(result.values, final_result.values[:-1])
+        assert(abs(next_state.mavp - final_result.state.mavp) < 1e-8)
+
+def test_mavp_ma_states_next_success(csv_loader):
+    df = csv_loader("sma")
+    data = np.array(df["close"])
+    periods = _periods(len(data))
+    for ma_type in [tx.MavpMA.HMA, tx.MavpMA.ZLEMA, tx.MavpMA.ALMA, tx.MavpMA.SMMA, tx.MavpMA.VIDYA]:
+        result = tx.mavp(data[:-1], periods[:-1], 5, 30, ma_type)
+        final_result = tx.mavp(data, periods, 5, 30, ma_type)
+        next_state = tx.mavp_next(data[-1], periods[-1], result.state)
+        testing.assert_allclose(result.values, final_result.values[:-1])
+        assert(len(next_state.ma_states) == 26)
+        assert(abs(next_state.mavp - final_result.state.mavp) < 1e-8)
+
+def test_thread_mavp(thread_test):
+    def mavp_tx_lambda(data):
+        return tx.mavp(data, _periods(len(data)), 2, 30, release_gil = True)
+
+    thread_test(mavp_tx_lambda, n_threads=4)
@@ -0,0 +1,55 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _ohlc(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    open_ = close + rng.normal(0.0, 0.5, n)
+    high = np.maximum(open_, close) + rng.random(n)
+    low = np.minimum(open_, close) - rng.random(n)
+    return open_, high, low, close
+
+def _sessions(n: int, size: int = 24):
+    new_session = np.zeros(n, dtype=bool)
+    new_session[::size] = True
+    return new_session
+
+def test_pivots_classic_numpy_success():
+    open_, high, low, close = _ohlc()
+    new_session = _sessions(len(close))
+    result = tx.pivots(open_, high, low, close, new_session)
+
+    assert np.isnan(result.pivot[:24]).all()
+    for start in range(24, len(close), 24):
+        prev = slice(start - 24, start)
+        h, l, c = high[prev].max(), low[prev].min(), close[prev][-1]
+        p = (h + l + c) / 3.0
+        end = min(start + 24, len(close))
+        testing.assert_allclose(result.pivot[start:end], p)
+        testing.assert_allclose(result.r1[start:end], 2.0 * p - l)
+        testing.assert_allclose(result.s1[start:end], 2.0 * p - h)
+        testing.assert_allclose(result.r2[start:end], p + (h - l))
+        testing.assert_allclose(result.s2[start:end], p - (h - l))
+
+def test_pivots_next_success():
+    open_, high, low, close = _ohlc()
+    new_session = _sessions(len(close))
+    for method in [tx.PivotMethod.Classic, tx.PivotMethod.Fibonacci, tx.PivotMethod.Camarilla, tx.PivotMethod.Woodie, tx.PivotMethod.Demark]:
+        result = tx.pivots(open_[:-25], high[:-25], low[:-25], close[:-25], new_session[:-25], method=method)
+        final_result = tx.pivots(open_, high, low, close, new_session, method=method)
+
+        state = result.state
+        for i in range(len(close) - 25, len(close)):
+            state = tx.pivots_next(open_[i], high[i], low[i], close[i], state, new_session=bool(new_session[i]))
+            testing.assert_allclose(state.pivot, final_result.pivot[i])
+            testing.assert_allclose(state.r1, final_result.r1[i])
+            testing.assert_allclose(state.s1, final_result.s1[i])
+
+def test_pivots_mask_length_error():
+    open_, high, low, close = _ohlc()
+    try:
+        tx.pivots(open_, high, low, close, _sessions(len(close) - 1))
+        assert False
+    except ValueError:
+        pass
@@ -0,0 +1,64 @@
+import pytest
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+pl = pytest.importorskip("polars")
+pa = pytest.importorskip("pyarrow")
+import techalib.polars  # noqa: E402,F401
+
+def test_ema_arrow_success(csv_loader):
+    df = csv_loader("ema")
+    close = np.array(df["close"])
+    result = tx.ema_arrow(pa.array(close), 30)
+    expected = tx.ema(close, 30)
+
+    values = pa.array(result.values)
+    assert values.null_count == 29
+    testing.assert_allclose(values.to_numpy(zero_copy_only=False), expected.values)
+    assert result.state.ema == expected.state.ema
+
+def test_arrow_skips_nulls():
+    data = pa.array([1.0, None, 2.0, None, 3.0, 4.0])
+    result = tx.sma_arrow(data, 3)
+
+    assert pa.array(result.values).to_pylist() == [None, None, None, None, 2.0, 3.0]
+
+def test_polars_namespace_success(csv_loader):
+    df = pl.from_pandas(csv_loader("ema"))
+    out = df.with_columns(
+        pl.col("close").techalib.ema(30).alias("ema_30"),
+        pl.col("close").techalib.bbands(20).alias("bbands"),
+    )
+    close = df["close"].to_numpy()
+
+    assert out["ema_30"].null_count() == 29
+    testing.assert_allclose(out["ema_30"].to_numpy(), tx.ema(close, 30).values)
+    bbands = tx.bbands(close, 20)
+    testing.assert_allclose(out["bbands"].struct.field("upper").to_numpy(), bbands.upper)
+    testing.assert_allclose(out["bbands"].struct.field("lower").to_numpy(), bbands.lower)
+
+def test_polars_namespace_over_groups():
+    df = pl.DataFrame({
+        "symbol": ["a"] * 5 + ["b"] * 5,
+        "close": [float(i) for i in range(10)],
+    })
+    out = df.with_columns(pl.col("close").techalib.sma(2).over("symbol").alias("sma"))
+
+    assert out["sma"].to_list() == [None, 0.5, 1.5, 2.5, 3.5, None, 5.5, 6.5, 7.5, 8.5]
+
+def test_arrow_float32():
+    data = pa.array([1.0, 2.0, None, 3.0, 4.0], type=pa.float32())
+    values, state = tx.sma_arrow(data, 2)
+
+    values = pa.array(values)
+    assert values.type == pa.float32()
+    assert values.to_pylist() == [None, 1.5, None, 2.5, 3.5]
+    assert state is None
+
+def test_polars_namespace_keeps_float32():
+    df = pl.DataFrame({"close": pl.Series([1.0, 2.0, 3.0, 4.0], dtype=pl.Float32)})
+    out = df.with_columns(pl.col("close").techalib.sma(2).alias("sma"))
+
+    assert out["sma"].dtype == pl.Float32
+    assert out["sma"].to_list() == [None, 1.5, 2.5, 3.5]
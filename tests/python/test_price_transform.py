@@ -0,0 +1,40 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _ohlc(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    open_ = close + rng.normal(0.0, 0.5, n)
+    high = np.maximum(open_, close) + rng.random(n)
+    low = np.minimum(open_, close) - rng.random(n)
+    return open_, high, low, close
+
+def test_avgprice_numpy_success():
+    open_, high, low, close = _ohlc()
+    result = tx.avgprice(open_, high, low, close)
+    testing.assert_allclose(result, (open_ + high + low + close) / 4.0)
+
+def test_medprice_numpy_success():
+    _, high, low, _ = _ohlc()
+    result = tx.medprice(high, low)
+    testing.assert_allclose(result, (high + low) / 2.0)
+
+def test_typprice_numpy_success():
+    _, high, low, close = _ohlc()
+    result = tx.typprice(high, low, close)
+    testing.assert_allclose(result, (high + low + close) / 3.0)
+
+def test_wclprice_pandas_success():
+    import pandas as pd
+    _, high, low, close = _ohlc()
+    result = tx.wclprice(pd.Series(high), pd.Series(low), pd.Series(close))
+    assert isinstance(result, pd.Series)
+    testing.assert_allclose(result, (high + low + 2.0 * close) / 4.0)
+
+def test_typprice_nan_propagates():
+    _, high, low, close = _ohlc(10)
+    close[3] = np.nan
+    result = tx.typprice(high, low, close, release_gil=True)
+    assert np.isnan(result[3])
+    assert np.isfinite(np.delete(result, 3)).all()
@@ -0,0 +1,48 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+import pandas as pd
+import pytest
+
+def _data(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    return 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+
+@pytest.mark.parametrize("name", ["zscore", "percent_rank", "skew", "kurtosis", "median"])
+def test_stats_next_success(name):
+    data = _data()
+    fct = getattr(tx, name)
+    fct_next = getattr(tx, f"{name}_next")
+    result = fct(data[:-1], 20)
+    final_result = fct(data, 20)
+
+    next_state = fct_next(data[-1], result.state)
+    testing.assert_allclose(result.values, final_result.values[:-1])
+    assert(abs(getattr(next_state, name) - getattr(final_result.state, name)) < 1e-8)
+
+def test_stats_match_pandas():
+    data = _data()
+    rolling = pd.Series(data).rolling(20)
+    testing.assert_allclose(tx.skew(data, 20).values, rolling.skew(), rtol=1e-7)
+    testing.assert_allclose(tx.kurtosis(data, 20).values, rolling.kurt(), rtol=1e-7)
+    testing.assert_allclose(tx.median(data, 20).values, rolling.median())
+    zscore = (data - rolling.mean()) / rolling.std(ddof=0)
+    testing.assert_allclose(tx.zscore(data, 20).values, zscore, rtol=1e-7)
+
+def test_zscore_large_offset():
+    rng = np.random.default_rng(7)
+    data = 1e9 + rng.random(10_000)
+    rolling = pd.Series(data - 1e9).rolling(20)
+    expected = (data - 1e9 - rolling.mean()) / rolling.std(ddof=0)
+    testing.assert_allclose(tx.zscore(data, 20).values, expected, rtol=1e-5, atol=1e-5)
+
+def test_percent_rank_bounds():
+    result = tx.percent_rank(_data(), 20)
+    assert(np.all(np.isnan(result.values[:20])))
+    assert(np.all((result.values[20:] >= 0.0) & (result.values[20:] <= 100.0)))
+
+def test_thread_median(thread_test):
+    def median_tx_lambda(data):
+        return tx.median(data, 20, release_gil = True)
+
+    thread_test(median_tx_lambda, n_threads=4)
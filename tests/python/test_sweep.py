@@ -0,0 +1,54 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+PERIODS = [2, 5, 14, 30]
+
+def _close(n: int = 500, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    return 100.0 * np.exp(np.cumsum(rng.normal(0.0, 0.01, n)))
+
+def test_sma_sweep_numpy_success():
+    data = _close()
+    result = tx.sma_sweep(data, PERIODS)
+
+    assert result.values.shape == (len(data), len(PERIODS))
+    for i, period in enumerate(PERIODS):
+        expected = tx.sma(data, period)
+        testing.assert_allclose(result.values[:, i], expected.values)
+        assert result.states[i].period == period
+
+def test_ema_rsi_sweep_numpy_success():
+    data = _close()
+    ema_result = tx.ema_sweep(data, PERIODS)
+    rsi_result = tx.rsi_sweep(data, PERIODS, release_gil=True)
+
+    for i, period in enumerate(PERIODS):
+        testing.assert_allclose(ema_result.values[:, i], tx.ema(data, period).values)
+        testing.assert_allclose(rsi_result.values[:, i], tx.rsi(data, period).values)
+
+def test_bbands_sweep_numpy_success():
+    data = _close()
+    result = tx.bbands_sweep(data, PERIODS, std_up=2.0, std_down=1.5)
+
+    for i, period in enumerate(PERIODS):
+        expected = tx.bbands(data, period, std_up=2.0, std_down=1.5)
+        testing.assert_allclose(result.upper[:, i], expected.upper)
+        testing.assert_allclose(result.middle[:, i], expected.middle)
+        testing.assert_allclose(result.lower[:, i], expected.lower)
+
+def test_sma_sweep_next_success():
+    data = _close()
+    result = tx.sma_sweep(data[:-1], PERIODS)
+
+    for i, period in enumerate(PERIODS):
+        next_state = tx.sma_next(data[-1], result.states[i])
+        assert abs(next_state.sma - tx.sma(data, period).state.sma) < 1e-8
+
+def test_sma_sweep_period_too_large_error():
+    data = _close(20)
+    try:
+        tx.sma_sweep(data, [5, 30])
+        assert False
+    except ValueError:
+        pass
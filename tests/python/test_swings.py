@@ -0,0 +1,62 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _ohlc(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    open_ = close + rng.normal(0.0, 0.5, n)
+    high = np.maximum(open_, close) + rng.random(n)
+    low = np.minimum(open_, close) - rng.random(n)
+    return open_, high, low, close
+
+def test_zigzag_percent_numpy_success():
+    _, high, low, _ = _ohlc()
+    result = tx.zigzag(high, low, percent=5.0)
+
+    pivots = result.pivots[~np.isnan(result.pivots)]
+    confirmed = result.confirmed[~np.isnan(result.confirmed)]
+    testing.assert_allclose(pivots, confirmed)
+    assert set(np.unique(result.direction[~np.isnan(result.direction)])) <= {-1.0, 1.0}
+
+def test_zigzag_confirmed_has_no_lookahead():
+    _, high, low, close = _ohlc()
+    full = tx.zigzag(high, low, close, atr_period=14, atr_multiplier=3.0)
+    for end in range(100, len(close), 150):
+        prefix = tx.zigzag(high[:end], low[:end], close[:end], atr_period=14, atr_multiplier=3.0)
+        testing.assert_allclose(prefix.confirmed, full.confirmed[:end])
+        testing.assert_allclose(prefix.direction, full.direction[:end])
+
+def test_zigzag_next_success():
+    _, high, low, close = _ohlc()
+    result = tx.zigzag(high[:-5], low[:-5], close[:-5], atr_period=14)
+    final_result = tx.zigzag(high, low, close, atr_period=14)
+
+    state = result.state
+    for i in range(len(close) - 5, len(close)):
+        state = tx.zigzag_next(high[i], low[i], close[i], state)
+        testing.assert_allclose(state.confirmed, final_result.confirmed[i])
+        testing.assert_allclose(state.direction, final_result.direction[i])
+    assert state.extreme == final_result.state.extreme
+    assert state.extreme_idx == final_result.state.extreme_idx
+
+def test_swing_numpy_success():
+    _, high, low, _ = _ohlc()
+    result = tx.swing(high, low, left=2, right=2)
+
+    for i in range(2, len(high) - 2):
+        is_high = all(high[i] > high[i - k] for k in (1, 2)) and all(high[i] >= high[i + k] for k in (1, 2))
+        assert is_high == (not np.isnan(result.swing_high[i]))
+    assert np.isnan(result.swing_high[-2:]).all()
+    assert np.isnan(result.swing_low[-2:]).all()
+
+def test_swing_next_success():
+    _, high, low, _ = _ohlc()
+    result = tx.swing(high[:-5], low[:-5], left=3, right=2)
+    final_result = tx.swing(high, low, left=3, right=2)
+
+    state = result.state
+    for i in range(len(high) - 5, len(high)):
+        state = tx.swing_next(high[i], low[i], state)
+        testing.assert_allclose(state.confirmed_high, final_result.swing_high[i - 2])
+        testing.assert_allclose(state.confirmed_low, final_result.swing_low[i - 2])
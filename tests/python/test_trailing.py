@@ -0,0 +1,40 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _ohlc(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    open_ = close + rng.normal(0.0, 0.5, n)
+    high = np.maximum(open_, close) + rng.random(n)
+    low = np.minimum(open_, close) - rng.random(n)
+    return open_, high, low, close
+
+def test_supertrend_numpy_success():
+    _, high, low, close = _ohlc()
+    result = tx.supertrend(high[:-1], low[:-1], close[:-1], 10, 3.0)
+    final_result = tx.supertrend(high, low, close, 10, 3.0)
+
+    next_state = tx.supertrend_next(high[-1], low[-1], close[-1], result.state)
+    testing.assert_allclose(result.supertrend, final_result.supertrend[:-1])
+    testing.assert_allclose(result.direction, final_result.direction[:-1])
+    up = final_result.direction == 1.0
+    assert(np.all(final_result.supertrend[up] <= close[up]))
+    assert(np.all(final_result.supertrend[10:][~up[10:]] >= close[10:][~up[10:]]))
+    assert(abs(next_state.supertrend - final_result.state.supertrend) < 1e-8)
+    assert(next_state.direction == final_result.state.direction)
+
+def test_chandelier_exit_numpy_success():
+    _, high, low, close = _ohlc()
+    result = tx.chandelier_exit(high[:-1], low[:-1], close[:-1], 22, 3.0)
+    final_result = tx.chandelier_exit(high, low, close, 22, 3.0)
+
+    next_state = tx.chandelier_exit_next(high[-1], low[-1], close[-1], result.state)
+    atr = tx.atr(high, low, close, 22).values
+    highest = np.array([high[max(0, i - 21):i + 1].max() for i in range(len(high))])
+    lowest = np.array([low[max(0, i - 21):i + 1].min() for i in range(len(low))])
+    testing.assert_allclose(result.long_stop, final_result.long_stop[:-1])
+    testing.assert_allclose(final_result.long_stop[22:], (highest - 3.0 * atr)[22:], atol=1e-8)
+    testing.assert_allclose(final_result.short_stop[22:], (lowest + 3.0 * atr)[22:], atol=1e-8)
+    assert(abs(next_state.long_stop - final_result.state.long_stop) < 1e-8)
+    assert(abs(next_state.short_stop - final_result.state.short_stop) < 1e-8)
@@ -0,0 +1,65 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+ESTIMATORS = [
+    tx.VolatilityEstimator.CloseToClose,
+    tx.VolatilityEstimator.Parkinson,
+    tx.VolatilityEstimator.GarmanKlass,
+    tx.VolatilityEstimator.RogersSatchell,
+    tx.VolatilityEstimator.YangZhang,
+]
+
+def _ohlc(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 * np.exp(np.cumsum(rng.normal(0.0, 0.01, n)))
+    open_ = close * np.exp(rng.normal(0.0, 0.005, n))
+    high = np.maximum(open_, close) * (1.0 + rng.random(n) * 0.01)
+    low = np.minimum(open_, close) * (1.0 - rng.random(n) * 0.01)
+    return open_, high, low, close
+
+def test_volatility_close_to_close_numpy_success():
+    open_, high, low, close = _ohlc()
+    result = tx.volatility(open_, high, low, close, 20)
+
+    returns = np.log(close[1:] / close[:-1])
+    expected = np.full(len(close), np.nan)
+    for i in range(20, len(close)):
+        expected[i] = np.std(returns[i - 20:i], ddof=1) * np.sqrt(252.0)
+    testing.assert_allclose(result.values, expected)
+
+def test_volatility_parkinson_numpy_success():
+    open_, high, low, close = _ohlc()
+    result = tx.volatility(open_, high, low, close, 10, estimator=tx.VolatilityEstimator.Parkinson, annualization=1.0)
+
+    terms = np.log(high / low) ** 2
+    expected = np.full(len(close), np.nan)
+    for i in range(9, len(close)):
+        expected[i] = np.sqrt(terms[i - 9:i + 1].mean() / (4.0 * np.log(2.0)))
+    testing.assert_allclose(result.values, expected)
+
+def test_volatility_next_success():
+    open_, high, low, close = _ohlc()
+    for estimator in ESTIMATORS:
+        result = tx.volatility(open_[:-1], high[:-1], low[:-1], close[:-1], 20, estimator=estimator)
+        final_result = tx.volatility(open_, high, low, close, 20, estimator=estimator)
+
+        next_state = tx.volatility_next(open_[-1], high[-1], low[-1], close[-1], result.state)
+        testing.assert_allclose(result.values, final_result.values[:-1])
+        assert(next_state.estimator == estimator)
+        assert(abs(next_state.volatility - final_result.state.volatility) < 1e-8)
+
+def test_volatility_non_positive_price_error():
+    open_, high, low, close = _ohlc()
+    low[10] = 0.0
+    try:
+        tx.volatility(open_, high, low, close, 20, estimator=tx.VolatilityEstimator.Parkinson)
+        assert False
+    except ValueError:
+        pass
+
+def test_thread_volatility(thread_test):
+    def volatility_tx_lambda(data):
+        return tx.volatility(data + 1.0, data + 2.0, data + 0.5, data + 1.5, 20, estimator=tx.VolatilityEstimator.YangZhang, release_gil = True)
+
+    thread_test(volatility_tx_lambda, n_threads=4)
@@ -0,0 +1,61 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _ohlcv(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    close = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    open_ = close + rng.normal(0.0, 0.5, n)
+    high = np.maximum(open_, close) + rng.random(n)
+    low = np.minimum(open_, close) - rng.random(n)
+    volume = 1000.0 + rng.random(n) * 500.0
+    return open_, high, low, close, volume
+
+def test_elder_ray_numpy_success():
+    _, high, low, close, _ = _ohlcv()
+    result = tx.elder_ray(high[:-1], low[:-1], close[:-1], 13)
+    final_result = tx.elder_ray(high, low, close, 13)
+
+    next_state = tx.elder_ray_next(high[-1], low[-1], close[-1], result.state)
+    testing.assert_allclose(result.bull_power, final_result.bull_power[:-1])
+    testing.assert_allclose(result.bear_power, final_result.bear_power[:-1])
+    ema = tx.ema(close, 13).values
+    testing.assert_allclose(final_result.bull_power, high - ema)
+    assert(abs(next_state.bull_power - final_result.state.bull_power) < 1e-8)
+    assert(abs(next_state.bear_power - final_result.state.bear_power) < 1e-8)
+
+def test_force_index_numpy_success():
+    _, _, _, close, volume = _ohlcv()
+    result = tx.force_index(close[:-1], volume[:-1], 13)
+    final_result = tx.force_index(close, volume, 13)
+
+    next_state = tx.force_index_next(close[-1], volume[-1], result.state)
+    testing.assert_allclose(result.values, final_result.values[:-1])
+    raw = tx.force_index(close, volume, 1).values
+    testing.assert_allclose(raw[1:], np.diff(close) * volume[1:])
+    assert(abs(next_state.force_index - final_result.state.force_index) < 1e-6)
+
+def test_eom_numpy_success():
+    _, high, low, _, volume = _ohlcv()
+    result = tx.eom(high[:-1], low[:-1], volume[:-1], 14)
+    final_result = tx.eom(high, low, volume, 14)
+
+    next_state = tx.eom_next(high[-1], low[-1], volume[-1], result.state)
+    testing.assert_allclose(result.values, final_result.values[:-1])
+    assert(abs(next_state.eom - final_result.state.eom) < 1e-8)
+
+def test_cmf_numpy_success():
+    _, high, low, close, volume = _ohlcv()
+    result = tx.cmf(high[:-1], low[:-1], close[:-1], volume[:-1], 20)
+    final_result = tx.cmf(high, low, close, volume, 20)
+
+    next_state = tx.cmf_next(high[-1], low[-1], close[-1], volume[-1], result.state)
+    testing.assert_allclose(result.values, final_result.values[:-1])
+    assert(np.all(np.abs(final_result.values[19:]) <= 1.0))
+    assert(abs(next_state.cmf - final_result.state.cmf) < 1e-8)
+
+def test_thread_cmf(thread_test):
+    def cmf_tx_lambda(data):
+        return tx.cmf(data + 1.0, data - 1.0, data, data, 20, release_gil = True)
+
+    thread_test(cmf_tx_lambda, n_threads=4)
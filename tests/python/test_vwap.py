@@ -0,0 +1,53 @@
+import techalib as tx
+from numpy import testing
+import numpy as np
+
+def _price_volume(n: int = 1000, seed: int = 42):
+    rng = np.random.default_rng(seed)
+    price = 100.0 + np.cumsum(rng.normal(0.0, 1.5, n))
+    volume = rng.random(n) * 1000.0
+    return price, volume
+
+def _slow_vwap(price, volume, resets, multiplier):
+    out_vwap = np.full(len(price), np.nan)
+    out_upper = np.full(len(price), np.nan)
+    start = 0
+    for i in range(len(price)):
+        if resets[i]:
+            start = i
+        p, v = price[start:i + 1], volume[start:i + 1]
+        mean = (p * v).sum() / v.sum()
+        out_vwap[i] = mean
+        out_upper[i] = mean + multiplier * np.sqrt((v * (p - mean) ** 2).sum() / v.sum())
+    return out_vwap, out_upper
+
+def test_vwap_numpy_success():
+    price, volume = _price_volume()
+    resets = np.arange(len(price)) % 100 == 0
+    result = tx.vwap(price, volume, reset_mask=resets, multiplier=2.0)
+
+    expected_vwap, expected_upper = _slow_vwap(price, volume, resets, 2.0)
+    testing.assert_allclose(result.vwap, expected_vwap, atol=1e-8)
+    testing.assert_allclose(result.upper, expected_upper, atol=1e-8)
+
+def test_vwap_anchors_success():
+    price, volume = _price_volume()
+    anchors = [100, 350, 900]
+    resets = np.isin(np.arange(len(price)), anchors)
+    from_mask = tx.vwap(price, volume, reset_mask=resets)
+    from_anchors = tx.vwap(price, volume, anchors=anchors)
+    testing.assert_allclose(from_mask.vwap, from_anchors.vwap)
+    testing.assert_allclose(from_mask.lower, from_anchors.lower)
+
+def test_vwap_next_success():
+    price, volume = _price_volume()
+    resets = np.arange(len(price)) % 100 == 0
+    result = tx.vwap(price[:-1], volume[:-1], reset_mask=resets[:-1])
+    final_result = tx.vwap(price, volume, reset_mask=resets)
+
+    next_state = tx.vwap_next(price[-1], volume[-1], result.state, reset=bool(resets[-1]))
+    assert(abs(next_state.vwap - final_result.state.vwap) < 1e-8)
+    assert(abs(next_state.upper - final_result.state.upper) < 1e-8)
+
+    new_session = tx.vwap_next(price[-1], volume[-1], result.state, reset=True)
+    assert(new_session.vwap == price[-1])
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fs::File, path::Path};
 
-use techalib::types::Float;
+use techalib::types::{Float, Ohlcv};
 
 use csv::ReaderBuilder;
 
@@ -30,3 +30,56 @@ pub fn load_generated_csv(file_name: &str) -> Result<HashMap<String, Vec<Float>>
     }
     Ok(columns)
 }
+
+/// Bars built from the `close` column of a generated CSV file.
+///
+/// The generated files only contain a close column, so the other columns are
+/// derived from it deterministically: the open is the next close, the high and
+/// the low are widened around the body and the volume oscillates.
+pub struct GeneratedOhlcv {
+    pub open: Vec<Float>,
+    pub high: Vec<Float>,
+    pub low: Vec<Float>,
+    pub close: Vec<Float>,
+    pub volume: Vec<Float>,
+}
+
+impl GeneratedOhlcv {
+    pub fn view(&self) -> Ohlcv<'_> {
+        Ohlcv {
+            open: &self.open,
+            high: &self.high,
+            low: &self.low,
+            close: &self.close,
+            volume: &self.volume,
+        }
+    }
+}
+
+pub fn load_generated_ohlcv(file_name: &str) -> Result<GeneratedOhlcv, csv::Error> {
+    let columns = load_generated_csv(file_name)?;
+    let close = columns.get("close").unwrap().clone();
+    let open: Vec<Float> = close.iter().skip(1).chain([&close[0]]).cloned().collect();
+    let high = open
+        .iter()
+        .zip(&close)
+        .enumerate()
+        .map(|(i, (o, c))| o.max(*c) + (i % 7) as Float * 0.1)
+        .collect();
+    let low = open
+        .iter()
+        .zip(&close)
+        .enumerate()
+        .map(|(i, (o, c))| o.min(*c) - (i % 5) as Float * 0.1)
+        .collect();
+    let volume = (0..close.len())
+        .map(|i| 1000.0 + ((i * 37) % 101) as Float * 10.0)
+        .collect();
+    Ok(GeneratedOhlcv {
+        open,
+        high,
+        low,
+        close,
+        volume,
+    })
+}
@@ -1,25 +1,119 @@
 #[cfg(test)]
 pub(crate) mod helper;
 
+#[cfg(test)]
+pub(crate) mod tests_alma;
+#[cfg(test)]
+pub(crate) mod tests_arrow;
+#[cfg(test)]
+pub(crate) mod tests_atr;
+#[cfg(test)]
+pub(crate) mod tests_batch;
 #[cfg(test)]
 pub(crate) mod tests_bbands;
 #[cfg(test)]
+pub(crate) mod tests_chandelier_exit;
+#[cfg(test)]
+pub(crate) mod tests_cli;
+#[cfg(test)]
+pub(crate) mod tests_cmf;
+#[cfg(test)]
 pub(crate) mod tests_dema;
 #[cfg(test)]
+pub(crate) mod tests_donchian;
+#[cfg(test)]
+pub(crate) mod tests_elder_ray;
+#[cfg(test)]
 pub(crate) mod tests_ema;
 #[cfg(test)]
+pub(crate) mod tests_envelope;
+#[cfg(test)]
+pub(crate) mod tests_eom;
+#[cfg(test)]
+pub(crate) mod tests_ffi;
+#[cfg(test)]
+pub(crate) mod tests_force_index;
+#[cfg(test)]
+pub(crate) mod tests_generic_float;
+#[cfg(test)]
+pub(crate) mod tests_heikin_ashi;
+#[cfg(test)]
+pub(crate) mod tests_hma;
+#[cfg(test)]
+pub(crate) mod tests_ichimoku;
+#[cfg(test)]
 pub(crate) mod tests_kama;
 #[cfg(test)]
+pub(crate) mod tests_keltner;
+#[cfg(test)]
+pub(crate) mod tests_kurtosis;
+#[cfg(test)]
+pub(crate) mod tests_ma;
+#[cfg(test)]
 pub(crate) mod tests_macd;
 #[cfg(test)]
+pub(crate) mod tests_math_operator;
+#[cfg(test)]
+pub(crate) mod tests_math_transform;
+#[cfg(test)]
+pub(crate) mod tests_mavp;
+#[cfg(test)]
+pub(crate) mod tests_max;
+#[cfg(test)]
+pub(crate) mod tests_maxindex;
+#[cfg(test)]
+pub(crate) mod tests_median;
+#[cfg(test)]
+pub(crate) mod tests_min;
+#[cfg(test)]
+pub(crate) mod tests_minindex;
+#[cfg(test)]
+pub(crate) mod tests_minmax;
+#[cfg(test)]
+pub(crate) mod tests_percent_rank;
+#[cfg(test)]
+pub(crate) mod tests_pivots;
+#[cfg(test)]
+pub(crate) mod tests_precision;
+#[cfg(test)]
+pub(crate) mod tests_price_transform;
+#[cfg(test)]
+pub(crate) mod tests_ring_buffer;
+#[cfg(test)]
 pub(crate) mod tests_rsi;
 #[cfg(test)]
+pub(crate) mod tests_simd;
+#[cfg(test)]
+pub(crate) mod tests_skew;
+#[cfg(test)]
 pub(crate) mod tests_sma;
 #[cfg(test)]
+pub(crate) mod tests_smma;
+#[cfg(test)]
+pub(crate) mod tests_sum;
+#[cfg(test)]
+pub(crate) mod tests_supertrend;
+#[cfg(test)]
+pub(crate) mod tests_sweep;
+#[cfg(test)]
+pub(crate) mod tests_swing;
+#[cfg(test)]
 pub(crate) mod tests_t3;
 #[cfg(test)]
 pub(crate) mod tests_tema;
 #[cfg(test)]
 pub(crate) mod tests_trima;
 #[cfg(test)]
+pub(crate) mod tests_vidya;
+#[cfg(test)]
+pub(crate) mod tests_volatility;
+#[cfg(test)]
+pub(crate) mod tests_vwap;
+#[cfg(test)]
 pub(crate) mod tests_wma;
+#[cfg(test)]
+pub(crate) mod tests_zigzag;
+#[cfg(test)]
+pub(crate) mod tests_zlema;
+#[cfg(test)]
+pub(crate) mod tests_zscore;
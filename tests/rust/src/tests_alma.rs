@@ -0,0 +1,141 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::alma::{alma, DEFAULT_OFFSET, DEFAULT_SIGMA},
+    traits::State,
+    types::Float,
+};
+
+fn slow_alma(data: &[Float], period: usize, offset: Float, sigma: Float) -> Vec<Float> {
+    let m = offset * (period - 1) as Float;
+    let s = period as Float / sigma;
+    let mut out = vec![Float::NAN; data.len()];
+    for i in period - 1..data.len() {
+        let mut num = 0.0;
+        let mut den = 0.0;
+        for j in 0..period {
+            let w = (-((j as Float - m) * (j as Float - m)) / (2.0 * s * s)).exp();
+            num += w * data[i + 1 - period + j];
+            den += w;
+        }
+        out[i] = num / den;
+    }
+    out
+}
+
+fn no_lookahead_alma(file_name: &str, period: usize, offset: Float, sigma: Float) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_alma(input, period, offset, sigma);
+
+    let output = alma(&input[0..last_idx], period, offset, sigma);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate ALMA: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert!(
+            approx_eq_float(new_state.alma, expected[last_idx + i], 1e-8),
+            "Next expected {}, but got {}",
+            expected[last_idx + i],
+            new_state.alma
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_alma("sma.csv", 9, DEFAULT_OFFSET, DEFAULT_SIGMA);
+}
+
+#[test]
+fn no_lookahead_centered_ok() {
+    no_lookahead_alma("sma.csv", 20, 0.5, 3.0);
+}
+
+#[test]
+fn constant_ok() {
+    let input = vec![3.5; 50];
+    let result = alma(&input, 9, DEFAULT_OFFSET, DEFAULT_SIGMA).unwrap();
+    assert!(result.values[8..]
+        .iter()
+        .all(|v| approx_eq_float(*v, 3.5, 1e-12)));
+}
+
+#[test]
+fn bad_offset_err() {
+    let input = vec![1.0; 50];
+    assert!(matches!(
+        alma(&input, 9, 1.5, DEFAULT_SIGMA),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        alma(&input, 9, Float::NAN, DEFAULT_SIGMA),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn bad_sigma_err() {
+    let input = vec![1.0; 50];
+    assert!(matches!(
+        alma(&input, 9, DEFAULT_OFFSET, 0.0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let input = vec![1.0; 8];
+    assert!(matches!(
+        alma(&input, 9, DEFAULT_OFFSET, DEFAULT_SIGMA),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut input = vec![1.0; 50];
+    input[30] = Float::NAN;
+    assert!(matches!(
+        alma(&input, 9, DEFAULT_OFFSET, DEFAULT_SIGMA),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 20..200),
+        period in 2usize..20,
+        offset in 0.0f64..=1.0,
+        sigma in 0.5f64..10.0,
+    ) {
+        let result = alma(&input, period, offset, sigma).unwrap();
+        let expected = slow_alma(&input, period, offset, sigma);
+        for (o, e) in result.values.iter().zip(&expected) {
+            if e.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - e).abs() <= 1e-6 * e.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::helper::generated::load_generated_csv;
+use arrow_array::{Array, Float32Array, Float64Array};
+use techalib::{
+    arrow,
+    errors::TechalibError,
+    indicators::{
+        bbands::{bbands, BBandsMA, DeviationMulipliers},
+        ema::ema,
+        macd::macd,
+        rsi::rsi,
+        sma::sma,
+    },
+    types::Float,
+};
+
+fn assert_array_eq(actual: &Float64Array, expected: &[Float]) {
+    assert_eq!(actual.len(), expected.len());
+    for (idx, (value, &expected)) in actual.iter().zip(expected).enumerate() {
+        match value {
+            None => assert!(expected.is_nan(), "[{idx}] null, expected {expected}"),
+            Some(value) => assert_eq!(value, expected, "[{idx}]"),
+        }
+    }
+}
+
+fn close() -> Vec<Float> {
+    let columns = load_generated_csv("ema.csv").unwrap();
+    columns.get("close").unwrap().clone()
+}
+
+#[test]
+fn arrow_without_nulls_matches_slices() {
+    let input = close();
+    let array = Float64Array::from(input.clone());
+
+    let output = arrow::ema(&array, 30, None).unwrap();
+    let expected = ema(&input, 30, None).unwrap();
+    assert_array_eq(&output.values, &expected.values);
+    assert_eq!(output.values.null_count(), 29);
+    assert_eq!(output.state.ema, expected.state.ema);
+
+    let output = arrow::rsi(&array, 14).unwrap();
+    assert_array_eq(&output.values, &rsi(&input, 14).unwrap().values);
+}
+
+#[test]
+fn arrow_warm_up_is_null() {
+    let input = close();
+    let array = Float64Array::from(input.clone());
+
+    let output = arrow::sma(&array, 10).unwrap();
+    let expected = sma(&input, 10).unwrap();
+    assert_eq!(output.values.null_count(), 9);
+    for idx in 0..input.len() {
+        assert_eq!(
+            output.values.is_null(idx),
+            expected.values[idx].is_nan(),
+            "[{idx}]"
+        );
+    }
+    assert!(output.values.values()[..9]
+        .iter()
+        .all(|value| value.is_nan()));
+    assert_eq!(output.values.values()[9..], expected.values[9..]);
+}
+
+#[test]
+fn arrow_skips_null_rows() {
+    let input = close();
+    let with_nulls: Vec<Option<Float>> = input
+        .iter()
+        .enumerate()
+        .map(|(idx, &value)| (idx % 7 != 3).then_some(value))
+        .collect();
+    let valid: Vec<Float> = with_nulls.iter().flatten().copied().collect();
+    let array = Float64Array::from(with_nulls.clone());
+
+    let output = arrow::ema(&array, 10, None).unwrap();
+    let expected = ema(&valid, 10, None).unwrap();
+    assert_eq!(output.values.len(), input.len());
+    assert_eq!(output.state.ema, expected.state.ema);
+
+    let mut expected_values = expected.values.iter();
+    for (idx, value) in with_nulls.iter().enumerate() {
+        if value.is_none() {
+            assert!(output.values.is_null(idx), "[{idx}] should be null");
+            continue;
+        }
+        let expected = *expected_values.next().unwrap();
+        if expected.is_nan() {
+            assert!(output.values.is_null(idx), "[{idx}] should be null");
+        } else {
+            assert_eq!(output.values.value(idx), expected, "[{idx}]");
+        }
+    }
+}
+
+#[test]
+fn arrow_multi_outputs() {
+    let input = close();
+    let array = Float64Array::from(input.clone());
+
+    let std_dev_mul = DeviationMulipliers { up: 2.0, down: 2.0 };
+    let output = arrow::bbands(&array, 20, std_dev_mul, BBandsMA::SMA).unwrap();
+    let expected = bbands(&input, 20, std_dev_mul, BBandsMA::SMA).unwrap();
+    assert_array_eq(&output.upper, &expected.upper);
+    assert_array_eq(&output.middle, &expected.middle);
+    assert_array_eq(&output.lower, &expected.lower);
+
+    let output = arrow::macd(&array, 12, 26, 9).unwrap();
+    let expected = macd(&input, 12, 26, 9).unwrap();
+    assert_array_eq(&output.macd, &expected.macd);
+    assert_array_eq(&output.signal, &expected.signal);
+    assert_array_eq(&output.histogram, &expected.histogram);
+}
+
+#[test]
+fn arrow_float32_array() {
+    let input: Vec<f32> = close().iter().map(|&value| value as f32).collect();
+    let array = Float32Array::from(input.clone());
+
+    let output = arrow::sma(&array, 5).unwrap();
+    let expected = sma(&input, 5).unwrap();
+    assert_eq!(output.values.null_count(), 4);
+    for (value, expected) in output.values.iter().skip(4).zip(&expected.values[4..]) {
+        assert_eq!(value, Some(*expected));
+    }
+}
+
+#[test]
+fn arrow_not_enough_valid_values() {
+    let array = Float64Array::from(vec![Some(1.0), None, Some(2.0), None, Some(3.0)]);
+    assert!(matches!(
+        arrow::sma(&array, 4),
+        Err(TechalibError::InsufficientData)
+    ));
+    let output = arrow::sma(&array, 3).unwrap();
+    assert_eq!(
+        output.values.iter().collect::<Vec<_>>(),
+        vec![None, None, None, None, Some(2.0)]
+    );
+}
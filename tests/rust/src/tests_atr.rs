@@ -0,0 +1,201 @@
+use crate::helper::{
+    assert::approx_eq_float,
+    generated::{assert_vec_eq_gen_data, load_generated_csv, load_generated_ohlcv},
+};
+
+use crate::expect_err_overflow_or_ok_with;
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::atr::{atr, AtrResult},
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+pub(crate) fn slow_atr(
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    period: usize,
+) -> Vec<Float> {
+    let mut out = vec![Float::NAN; close.len()];
+    let true_range: Vec<Float> = (0..close.len())
+        .map(|i| {
+            if i == 0 {
+                Float::NAN
+            } else {
+                (high[i] - low[i])
+                    .max((high[i] - close[i - 1]).abs())
+                    .max((low[i] - close[i - 1]).abs())
+            }
+        })
+        .collect();
+    out[period] = true_range[1..=period].iter().sum::<Float>() / period as Float;
+    for i in period + 1..close.len() {
+        out[i] = (out[i - 1] * (period - 1) as Float + true_range[i]) / period as Float;
+    }
+    out
+}
+
+fn no_lookahead_atr(
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    expected: &[Float],
+    period: usize,
+) {
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        ..Default::default()
+    };
+    let output = atr(&ohlcv, period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate ATR: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_eq_gen_data(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx], close[idx])).unwrap();
+        assert!(
+            approx_eq_float(new_state.atr, expected[idx], 1e-8),
+            "Next [{}] expected {}, but got {}",
+            i,
+            expected[idx],
+            new_state.atr
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let (high, low, close) = (&bars.high, &bars.low, &bars.close);
+    no_lookahead_atr(high, low, close, &slow_atr(high, low, close, 14), 14);
+}
+
+#[test]
+#[ignore = "needs the TA-Lib fixture from tools/generate_testdata.py"]
+fn generated_with_no_lookahead_ok() {
+    let columns = load_generated_csv("atr.csv").unwrap();
+    let high = columns.get("high").unwrap();
+    let low = columns.get("low").unwrap();
+    let close = columns.get("close").unwrap();
+    no_lookahead_atr(high, low, close, columns.get("out").unwrap(), 14);
+}
+
+#[test]
+fn period_1_is_true_range() {
+    let high = [2.0, 4.0, 3.0, 6.0];
+    let low = [1.0, 2.0, 1.0, 5.0];
+    let close = [1.5, 3.0, 2.0, 5.5];
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let result = atr::<Float>(&ohlcv, 1).unwrap();
+    assert!(result.values[0].is_nan());
+    assert_eq!(&result.values[1..], &[2.5, 2.0, 4.0]);
+}
+
+#[test]
+fn finite_extreme_err_overflow_or_ok_all_finite() {
+    let high = vec![Float::MAX, Float::MAX - 1.0, Float::MAX, Float::MAX - 2.0];
+    let low = vec![Float::MIN, Float::MIN + 1.0, Float::MIN, Float::MIN + 2.0];
+    let close = vec![0.0, 1.0, 2.0, 3.0];
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    expect_err_overflow_or_ok_with!(atr(&ohlcv, 2), |result: AtrResult| {
+        assert!(
+            result.values.iter().skip(2).all(|v| v.is_finite()),
+            "Expected all values to be finite"
+        );
+    });
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let high = [2.0, 4.0, Float::NAN, 6.0];
+    let low = [1.0, 2.0, 1.0, 5.0];
+    let close = [1.5, 3.0, 2.0, 5.5];
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    assert!(matches!(
+        atr(&ohlcv, 2),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn empty_input_err() {
+    assert!(matches!(
+        atr(&Ohlcv::<Float>::default(), 14),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn columns_length_mismatch_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 3.0, 4.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 2.0, 3.0],
+        ..Default::default()
+    };
+    assert!(matches!(atr(&ohlcv, 1), Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn period_0_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 3.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 2.0],
+        ..Default::default()
+    };
+    assert!(matches!(atr(&ohlcv, 0), Err(TechalibError::BadParam(_))));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3, 0.0f64..1.0), 2..200),
+        period in 1usize..50
+    ) {
+        prop_assume!(period < bars.len());
+        let close: Vec<Float> = bars.iter().map(|b| b.0).collect();
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.2).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1 * (1.0 - b.2)).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, close: &close, ..Default::default() };
+        let out = atr(&ohlcv, period).unwrap().values;
+        let slow = slow_atr(&high, &low, &close, period);
+        for (o, expect) in out.iter().zip(slow) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
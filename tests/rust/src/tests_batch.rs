@@ -0,0 +1,357 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_csv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::{
+        alma::{alma, alma_batch},
+        atr::{atr, atr_batch},
+        bbands::{bbands, bbands_batch, BBandsMA, DeviationMulipliers},
+        ema::{ema, ema_batch, ema_batch_into},
+        keltner::{keltner, keltner_batch, keltner_batch_into, keltner_into},
+        ma::{ma, ma_batch, ma_batch_into, MaType},
+        macd::{macd, macd_batch},
+        rsi::{rsi, rsi_batch},
+        sma::{sma, sma_batch, sma_batch_into, sma_into},
+        stats::zscore::{zscore, zscore_batch},
+        t3::{t3, t3_batch},
+    },
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn load_series() -> Vec<Vec<Float>> {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    vec![
+        input.clone(),
+        input[7..].to_vec(),
+        input[..input.len() / 2].to_vec(),
+        input.iter().rev().copied().collect(),
+    ]
+}
+
+fn load_ohlcv_series() -> Vec<[Vec<Float>; 3]> {
+    load_series()
+        .into_iter()
+        .map(|close| {
+            let high = close
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c + 1.0 + (i % 5) as Float)
+                .collect();
+            let low = close
+                .iter()
+                .enumerate()
+                .map(|(i, c)| c - 1.0 - (i % 3) as Float)
+                .collect();
+            [high, low, close]
+        })
+        .collect()
+}
+
+fn as_ohlcv(series: &[[Vec<Float>; 3]]) -> Vec<Ohlcv<'_>> {
+    series
+        .iter()
+        .map(|[high, low, close]| Ohlcv {
+            high,
+            low,
+            close,
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn as_slices(series: &[Vec<Float>]) -> Vec<&[Float]> {
+    series.iter().map(|s| s.as_slice()).collect()
+}
+
+#[test]
+fn batch_matches_single_series_ok() {
+    let series = load_series();
+    let slices = as_slices(&series);
+
+    let results = sma_batch(&slices, 30).unwrap();
+    assert_eq!(results.len(), series.len());
+    for (data, result) in series.iter().zip(&results) {
+        assert_vec_close(&sma(data, 30).unwrap().values, &result.values);
+    }
+
+    let results = ema_batch(&slices, 30, None).unwrap();
+    for (data, result) in series.iter().zip(&results) {
+        assert_vec_close(&ema(data, 30, None).unwrap().values, &result.values);
+    }
+
+    let results = rsi_batch(&slices, 14).unwrap();
+    for (data, result) in series.iter().zip(&results) {
+        assert_vec_close(&rsi(data, 14).unwrap().values, &result.values);
+    }
+
+    let results = t3_batch(&slices, 5, 0.7, None).unwrap();
+    for (data, result) in series.iter().zip(&results) {
+        assert_vec_close(&t3(data, 5, 0.7, None).unwrap().values, &result.values);
+    }
+
+    let results = alma_batch(&slices, 20, 0.85, 6.0).unwrap();
+    for (data, result) in series.iter().zip(&results) {
+        assert_vec_close(&alma(data, 20, 0.85, 6.0).unwrap().values, &result.values);
+    }
+
+    let results = ma_batch(&slices, 20, MaType::KAMA).unwrap();
+    for (data, result) in series.iter().zip(&results) {
+        assert_vec_close(&ma(data, 20, MaType::KAMA).unwrap().values, &result.values);
+    }
+
+    let results = zscore_batch(&slices, 20).unwrap();
+    for (data, result) in series.iter().zip(&results) {
+        assert_vec_close(&zscore(data, 20).unwrap().values, &result.values);
+    }
+}
+
+#[test]
+fn batch_multi_output_matches_single_series_ok() {
+    let series = load_series();
+    let slices = as_slices(&series);
+
+    let multipliers = DeviationMulipliers { up: 2.0, down: 2.0 };
+    let results = bbands_batch(&slices, 20, multipliers, BBandsMA::SMA).unwrap();
+    assert_eq!(results.len(), series.len());
+    for (data, result) in series.iter().zip(&results) {
+        let expected = bbands(data, 20, multipliers, BBandsMA::SMA).unwrap();
+        assert_vec_close(&expected.upper, &result.upper);
+        assert_vec_close(&expected.middle, &result.middle);
+        assert_vec_close(&expected.lower, &result.lower);
+    }
+
+    let results = macd_batch(&slices, 12, 26, 9).unwrap();
+    for (data, result) in series.iter().zip(&results) {
+        let expected = macd(data, 12, 26, 9).unwrap();
+        assert_vec_close(&expected.macd, &result.macd);
+        assert_vec_close(&expected.signal, &result.signal);
+        assert_vec_close(&expected.histogram, &result.histogram);
+    }
+}
+
+#[test]
+fn batch_ohlcv_matches_single_series_ok() {
+    let series = load_ohlcv_series();
+    let ohlcv = as_ohlcv(&series);
+
+    let results = atr_batch(&ohlcv, 14).unwrap();
+    assert_eq!(results.len(), series.len());
+    for (input, result) in ohlcv.iter().zip(&results) {
+        assert_vec_close(&atr(input, 14).unwrap().values, &result.values);
+    }
+
+    let results = keltner_batch(&ohlcv, 20, 10, 2.0).unwrap();
+    for (input, result) in ohlcv.iter().zip(&results) {
+        let expected = keltner(input, 20, 10, 2.0).unwrap();
+        assert_vec_close(&expected.upper, &result.upper);
+        assert_vec_close(&expected.middle, &result.middle);
+        assert_vec_close(&expected.lower, &result.lower);
+    }
+}
+
+#[test]
+fn batch_into_ohlcv_column_major_ok() {
+    let series = load_ohlcv_series();
+    let series_len = 200;
+    let flatten = |column: usize| -> Vec<Float> {
+        series
+            .iter()
+            .flat_map(|s| s[column][..series_len].iter().copied())
+            .collect()
+    };
+    let (high, low, close) = (flatten(0), flatten(1), flatten(2));
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let mut upper = vec![0.0; close.len()];
+    let mut middle = vec![0.0; close.len()];
+    let mut lower = vec![0.0; close.len()];
+
+    let states = keltner_batch_into(
+        &ohlcv,
+        series_len,
+        20,
+        10,
+        2.0,
+        &mut upper,
+        &mut middle,
+        &mut lower,
+    )
+    .unwrap();
+    assert_eq!(states.len(), series.len());
+    for (i, state) in states.iter().enumerate() {
+        let range = i * series_len..(i + 1) * series_len;
+        let column = Ohlcv {
+            high: &high[range.clone()],
+            low: &low[range.clone()],
+            close: &close[range.clone()],
+            ..Default::default()
+        };
+        let mut expected = [
+            vec![0.0; series_len],
+            vec![0.0; series_len],
+            vec![0.0; series_len],
+        ];
+        let [expected_upper, expected_middle, expected_lower] = &mut expected;
+        let expected_state = keltner_into(
+            &column,
+            20,
+            10,
+            2.0,
+            expected_upper,
+            expected_middle,
+            expected_lower,
+        )
+        .unwrap();
+        assert_vec_close(expected_upper, &upper[range.clone()]);
+        assert_vec_close(expected_middle, &middle[range.clone()]);
+        assert_vec_close(expected_lower, &lower[range]);
+        assert_eq!(state.upper, expected_state.upper);
+    }
+}
+
+#[test]
+fn batch_into_ohlcv_column_mismatch_err() {
+    let high = [2.0; 8];
+    let low = [1.0; 7];
+    let close = [1.5; 8];
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let mut output = [[0.0; 8]; 3];
+    let [upper, middle, lower] = &mut output;
+    let result = keltner_batch_into(&ohlcv, 4, 2, 2, 2.0, upper, middle, lower);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn batch_states_update_ok() {
+    let series = load_series();
+    let slices: Vec<&[Float]> = series.iter().map(|s| &s[..s.len() - 1]).collect();
+
+    let results = ema_batch(&slices, 30, None).unwrap();
+    for (data, mut result) in series.iter().zip(results) {
+        let expected = ema(data, 30, None).unwrap();
+        result.state.update(data[data.len() - 1]).unwrap();
+        assert_eq!(result.state.ema, expected.state.ema);
+    }
+}
+
+#[test]
+fn batch_into_column_major_ok() {
+    let series = load_series();
+    let series_len = 200;
+    let data: Vec<Float> = series
+        .iter()
+        .flat_map(|s| s[..series_len].iter().copied())
+        .collect();
+    let mut output = vec![0.0; data.len()];
+
+    let states = sma_batch_into(&data, series_len, 30, &mut output).unwrap();
+    assert_eq!(states.len(), series.len());
+    for (i, state) in states.iter().enumerate() {
+        let column = &data[i * series_len..(i + 1) * series_len];
+        let mut expected = vec![0.0; series_len];
+        let expected_state = sma_into(column, 30, &mut expected).unwrap();
+        assert_vec_close(&expected, &output[i * series_len..(i + 1) * series_len]);
+        assert_eq!(state.sma, expected_state.sma);
+    }
+
+    let states = ma_batch_into(&data, series_len, 20, MaType::HMA, &mut output).unwrap();
+    for (i, state) in states.iter().enumerate() {
+        let column = &data[i * series_len..(i + 1) * series_len];
+        let expected = ma(column, 20, MaType::HMA).unwrap();
+        assert_vec_close(
+            &expected.values,
+            &output[i * series_len..(i + 1) * series_len],
+        );
+        assert_eq!(state.period(), expected.state.period());
+    }
+}
+
+#[test]
+fn batch_empty_ok() {
+    let results = sma_batch::<Float>(&[], 30).unwrap();
+    assert!(results.is_empty());
+
+    let states = sma_batch_into::<Float>(&[], 10, 30, &mut []).unwrap();
+    assert!(states.is_empty());
+}
+
+#[test]
+fn batch_propagates_error_err() {
+    let series = load_series();
+    let short = [1.0, 2.0, 3.0];
+    let mut slices = as_slices(&series);
+    slices.insert(2, &short);
+
+    let result = sma_batch(&slices, 30);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+
+    let mut with_nan = series[0].clone();
+    with_nan[100] = Float::NAN;
+    let slices = [series[1].as_slice(), with_nan.as_slice()];
+    let result = ema_batch(&slices, 30, None);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn batch_into_zero_series_len_err() {
+    let data = [1.0, 2.0, 3.0, 4.0];
+    let mut output = [0.0; 4];
+    let result = sma_batch_into(&data, 0, 2, &mut output);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn batch_into_uneven_length_err() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let mut output = [0.0; 5];
+    let result = sma_batch_into(&data, 2, 2, &mut output);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn batch_into_output_mismatch_err() {
+    let data = [1.0, 2.0, 3.0, 4.0];
+    let mut output = [0.0; 3];
+    let result = sma_batch_into(&data, 2, 2, &mut output);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        data in vec(-1e6f64..1e6, 40..400),
+        columns in 1usize..8,
+        period in 2usize..20,
+    ) {
+        let series_len = data.len() / columns;
+        prop_assume!(series_len >= period);
+        let data = &data[..series_len * columns];
+        let mut output = vec![0.0; data.len()];
+
+        let states = ema_batch_into(data, series_len, period, None, &mut output).unwrap();
+        prop_assert_eq!(states.len(), columns);
+        for (i, (column, out)) in data
+            .chunks(series_len)
+            .zip(output.chunks(series_len))
+            .enumerate()
+        {
+            let expected = ema(column, period, None).unwrap();
+            for (e, o) in expected.values.iter().zip(out) {
+                prop_assert!((e.is_nan() && o.is_nan()) || e == o);
+            }
+            prop_assert_eq!(states[i].ema, expected.state.ema);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    expect_err_overflow_or_ok_with,
+    assert_vec_float_eq, expect_err_overflow_or_ok_with,
     helper::{
         assert::approx_eq_float,
         generated::{assert_vec_eq_gen_data, load_generated_csv},
@@ -8,7 +8,12 @@ use crate::{
 
 use techalib::{
     errors::TechalibError,
-    indicators::bbands::{bbands, BBandsMA, BBandsResult, DeviationMulipliers},
+    indicators::{
+        bbands::{
+            bbands, bbands_ext, bbands_ext_into, BBandsMA, BBandsResult, DeviationMulipliers,
+        },
+        ma::ma,
+    },
     traits::State,
     types::Float,
 };
@@ -77,6 +82,104 @@ fn generated_with_no_lookahead_ema_ok() {
     generated_and_no_lookahead_bbands("bbands_matype-1.csv", 20, BBandsMA::EMA(None));
 }
 
+#[test]
+fn state_update_over_several_windows_ok() {
+    let columns = load_generated_csv("bbands.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let upper = columns.get("upper").unwrap();
+    let middle = columns.get("middle").unwrap();
+    let lower = columns.get("lower").unwrap();
+
+    let period = 20;
+    let start = 2 * period;
+    let multipliers = DeviationMulipliers { up: 2.0, down: 2.0 };
+    let mut state = bbands(&input[..start], period, multipliers, BBandsMA::SMA)
+        .unwrap()
+        .state;
+    for (idx, &sample) in input.iter().enumerate().skip(start) {
+        state.update(sample).unwrap();
+        assert!(approx_eq_float(state.upper, upper[idx], 1e-8));
+        assert!(approx_eq_float(state.middle, middle[idx], 1e-8));
+        assert!(approx_eq_float(state.lower, lower[idx], 1e-8));
+    }
+}
+
+fn ma_middle_band_no_lookahead(period: usize, ma_type: BBandsMA) {
+    let columns = load_generated_csv("bbands.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let multipliers = DeviationMulipliers { up: 2.0, down: 1.5 };
+
+    let middle = ma(input, period, ma_type.into()).unwrap().values;
+    let std: Vec<Float> = (0..input.len())
+        .map(|idx| {
+            if idx + 1 < period {
+                return Float::NAN;
+            }
+            let window = &input[idx + 1 - period..=idx];
+            let mean = window.iter().sum::<Float>() / period as Float;
+            let variance = window
+                .iter()
+                .map(|v| (v - mean) * (v - mean))
+                .sum::<Float>()
+                / period as Float;
+            variance.sqrt()
+        })
+        .collect();
+    let upper: Vec<Float> = middle.iter().zip(&std).map(|(m, s)| m + 2.0 * s).collect();
+    let lower: Vec<Float> = middle.iter().zip(&std).map(|(m, s)| m - 1.5 * s).collect();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+    let result = bbands(&input[0..last_idx], period, multipliers, ma_type).unwrap();
+    assert_vec_float_eq!(&upper[0..last_idx], &result.upper, 1e-8);
+    assert_vec_float_eq!(&middle[0..last_idx], &result.middle, 1e-8);
+    assert_vec_float_eq!(&lower[0..last_idx], &result.lower, 1e-8);
+
+    let mut state = result.state;
+    for (idx, &sample) in input.iter().enumerate().skip(last_idx) {
+        state.update(sample).unwrap();
+        assert!(approx_eq_float(state.upper, upper[idx], 1e-8));
+        assert!(approx_eq_float(state.middle, middle[idx], 1e-8));
+        assert!(approx_eq_float(state.lower, lower[idx], 1e-8));
+    }
+}
+
+#[test]
+fn hma_middle_band_with_no_lookahead_ok() {
+    ma_middle_band_no_lookahead(20, BBandsMA::HMA);
+}
+
+#[test]
+fn zlema_middle_band_with_no_lookahead_ok() {
+    ma_middle_band_no_lookahead(20, BBandsMA::ZLEMA(None));
+}
+
+#[test]
+fn alma_middle_band_with_no_lookahead_ok() {
+    ma_middle_band_no_lookahead(20, BBandsMA::ALMA(0.85, 6.0));
+}
+
+#[test]
+fn smma_middle_band_with_no_lookahead_ok() {
+    ma_middle_band_no_lookahead(20, BBandsMA::SMMA);
+}
+
+#[test]
+fn vidya_middle_band_with_no_lookahead_ok() {
+    ma_middle_band_no_lookahead(20, BBandsMA::VIDYA(9));
+}
+
+#[test]
+fn state_without_ma_state_err() {
+    let columns = load_generated_csv("bbands.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let multipliers = DeviationMulipliers { up: 2.0, down: 2.0 };
+    let mut state = bbands(input, 20, multipliers, BBandsMA::HMA).unwrap().state;
+    state.ma = None;
+    assert!(matches!(state.update(1.0), Err(TechalibError::BadParam(_))));
+}
+
 #[test]
 fn all_zeros() {
     let n = 30;
@@ -278,3 +381,112 @@ fn finite_neg_extreme_err_overflow_or_ok_all_finite() {
         }
     );
 }
+
+fn percent_b_and_bandwidth_no_lookahead(file_name: &str, period: usize, ma_type: BBandsMA) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+    let upper = columns.get("upper").unwrap();
+    let middle = columns.get("middle").unwrap();
+    let lower = columns.get("lower").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let percent_b: Vec<Float> = (0..len)
+        .map(|i| (input[i] - lower[i]) / (upper[i] - lower[i]))
+        .collect();
+    let bandwidth: Vec<Float> = (0..len)
+        .map(|i| (upper[i] - lower[i]) / middle[i])
+        .collect();
+
+    let result = bbands_ext(
+        &input[0..last_idx],
+        period,
+        DeviationMulipliers { up: 2.0, down: 2.0 },
+        ma_type,
+    )
+    .unwrap();
+
+    assert_vec_eq_gen_data(&upper[0..last_idx], &result.upper);
+    assert_vec_eq_gen_data(&percent_b[0..last_idx], &result.percent_b);
+    assert_vec_eq_gen_data(&bandwidth[0..last_idx], &result.bandwidth);
+    assert_eq!(result.state.percent_b, result.percent_b[last_idx - 1]);
+    assert_eq!(result.state.bandwidth, result.bandwidth[last_idx - 1]);
+
+    let mut state = result.state;
+    for i in 0..next_count {
+        state.update(input[last_idx + i]).unwrap();
+        assert!(
+            approx_eq_float(state.percent_b, percent_b[last_idx + i], 1e-8),
+            "Next expected {}, but got {}",
+            percent_b[last_idx + i],
+            state.percent_b
+        );
+        assert!(
+            approx_eq_float(state.bandwidth, bandwidth[last_idx + i], 1e-8),
+            "Next expected {}, but got {}",
+            bandwidth[last_idx + i],
+            state.bandwidth
+        );
+    }
+}
+
+#[test]
+fn percent_b_and_bandwidth_with_no_lookahead_ok() {
+    percent_b_and_bandwidth_no_lookahead("bbands.csv", 20, BBandsMA::SMA);
+}
+
+#[test]
+fn percent_b_and_bandwidth_with_no_lookahead_ema_ok() {
+    percent_b_and_bandwidth_no_lookahead("bbands_matype-1.csv", 20, BBandsMA::EMA(None));
+}
+
+#[test]
+fn percent_b_and_bandwidth_undefined_are_nan() {
+    let data: Vec<Float> = vec![0.0; 10];
+    let result = bbands_ext(
+        &data,
+        3,
+        DeviationMulipliers { up: 2.0, down: 2.0 },
+        BBandsMA::SMA,
+    )
+    .unwrap();
+    assert!(result.percent_b.iter().all(|v| v.is_nan()));
+    assert!(result.bandwidth.iter().all(|v| v.is_nan()));
+}
+
+#[test]
+fn ext_longer_output_ok() {
+    let data: Vec<Float> = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    let multipliers = DeviationMulipliers { up: 2.0, down: 2.0 };
+    let expected = bbands_ext(&data, 3, multipliers, BBandsMA::SMA).unwrap();
+
+    let len = data.len();
+    let mut upper = vec![-1.0; len + 2];
+    let mut middle = vec![-1.0; len + 2];
+    let mut lower = vec![-1.0; len + 2];
+    let mut percent_b = vec![-1.0; len + 2];
+    let mut bandwidth = vec![-1.0; len + 2];
+    bbands_ext_into(
+        &data,
+        3,
+        multipliers,
+        BBandsMA::SMA,
+        &mut upper,
+        &mut middle,
+        &mut lower,
+        &mut percent_b,
+        &mut bandwidth,
+    )
+    .unwrap();
+
+    assert_vec_float_eq!(expected.upper, upper[..len], 1e-12);
+    assert_vec_float_eq!(expected.middle, middle[..len], 1e-12);
+    assert_vec_float_eq!(expected.lower, lower[..len], 1e-12);
+    assert_vec_float_eq!(expected.percent_b, percent_b[..len], 1e-12);
+    assert_vec_float_eq!(expected.bandwidth, bandwidth[..len], 1e-12);
+    for output in [&upper, &middle, &lower, &percent_b, &bandwidth] {
+        assert_eq!(output[len..], [-1.0, -1.0]);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_ohlcv,
+};
+
+use crate::tests_atr::slow_atr;
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::chandelier_exit::chandelier_exit,
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_chandelier_exit(
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    period: usize,
+    multiplier: Float,
+) -> (Vec<Float>, Vec<Float>) {
+    let atr = slow_atr(high, low, close, period);
+    let mut long_stop = vec![Float::NAN; close.len()];
+    let mut short_stop = vec![Float::NAN; close.len()];
+    for i in period..close.len() {
+        let highest = high[i + 1 - period..=i]
+            .iter()
+            .cloned()
+            .fold(Float::MIN, Float::max);
+        let lowest = low[i + 1 - period..=i]
+            .iter()
+            .cloned()
+            .fold(Float::MAX, Float::min);
+        long_stop[i] = highest - multiplier * atr[i];
+        short_stop[i] = lowest + multiplier * atr[i];
+    }
+    (long_stop, short_stop)
+}
+
+fn no_lookahead_chandelier_exit(file_name: &str, period: usize, multiplier: Float) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low, close) = (&bars.high, &bars.low, &bars.close);
+
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let (expected_long, expected_short) =
+        slow_chandelier_exit(high, low, close, period, multiplier);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        ..Default::default()
+    };
+    let output = chandelier_exit(&ohlcv, period, multiplier);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate CHANDELIER_EXIT: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected_long[0..last_idx], &result.long_stop);
+    assert_vec_close(&expected_short[0..last_idx], &result.short_stop);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx], close[idx])).unwrap();
+        assert!(approx_eq_float(
+            new_state.long_stop,
+            expected_long[idx],
+            1e-8
+        ));
+        assert!(approx_eq_float(
+            new_state.short_stop,
+            expected_short[idx],
+            1e-8
+        ));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_chandelier_exit("sma.csv", 22, 3.0);
+}
+
+#[test]
+fn no_lookahead_short_period_ok() {
+    no_lookahead_chandelier_exit("sma.csv", 2, 1.5);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0, 5.0],
+        low: &[1.0, 2.0, 1.0, 5.0, 4.0],
+        close: &[1.5, 3.0, Float::NAN, 5.5, 4.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        chandelier_exit(&ohlcv, 2, 3.0),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 3.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        chandelier_exit(&ohlcv, 2, 3.0),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn period_1_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        close: &[1.5, 3.0, 2.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        chandelier_exit(&ohlcv, 1, 3.0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn bad_multiplier_err() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    assert!(matches!(
+        chandelier_exit(&bars.view(), 22, -1.0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3, -1.0f64..1.0), 3..200),
+        period in 2usize..50,
+        multiplier in 0.1f64..5.0,
+    ) {
+        prop_assume!(period < bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let close: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.2).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, close: &close, ..Default::default() };
+        let result = chandelier_exit(&ohlcv, period, multiplier).unwrap();
+        let (long_stop, short_stop) = slow_chandelier_exit(&high, &low, &close, period, multiplier);
+        for (o, expect) in result.long_stop.iter().chain(&result.short_stop)
+            .zip(long_stop.iter().chain(&short_stop)) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::helper::generated::{load_generated_ohlcv, GeneratedOhlcv};
+use clap::Parser;
+use parquet::{
+    data_type::{ByteArray, ByteArrayType, DoubleType},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::parser::parse_message_type,
+};
+use std::{path::PathBuf, sync::Arc};
+use techalib::{indicators::ema::ema, types::Float};
+use techalib_cli::{
+    compute_columns,
+    error::CliError,
+    indicators::INDICATORS,
+    read_input,
+    spec::IndicatorSpec,
+    table::{Table, WriteOptions},
+    Args,
+};
+
+fn ohlcv_table(ohlcv: &GeneratedOhlcv) -> Table {
+    let mut csv = String::from("date,Open,High,Low,Close,Volume\n");
+    for i in 0..ohlcv.close.len() {
+        csv += &format!(
+            "d{i},{},{},{},{},{}\n",
+            ohlcv.open[i], ohlcv.high[i], ohlcv.low[i], ohlcv.close[i], ohlcv.volume[i]
+        );
+    }
+    Table::read_csv(csv.as_bytes(), b',').unwrap()
+}
+
+fn args(cli: &[&str]) -> Args {
+    Args::try_parse_from(["techalib"].iter().chain(cli)).unwrap()
+}
+
+fn temp_path(file_name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("techalib-cli-{}-{file_name}", std::process::id()))
+}
+
+#[test]
+fn spec_parsing() {
+    let spec: IndicatorSpec = "BBands:20, 2,2.5=bb".parse().unwrap();
+    assert_eq!(spec.name, "bbands");
+    assert_eq!(spec.params, vec!["20", "2", "2.5"]);
+    assert_eq!(spec.alias.as_deref(), Some("bb"));
+    assert_eq!(spec.to_string(), "bbands:20,2,2.5=bb");
+
+    let spec: IndicatorSpec = "rsi".parse().unwrap();
+    assert!(spec.params.is_empty() && spec.alias.is_none());
+
+    assert!(matches!(
+        "".parse::<IndicatorSpec>(),
+        Err(CliError::Spec(_))
+    ));
+    assert!(matches!(
+        "ema:20=".parse::<IndicatorSpec>(),
+        Err(CliError::Spec(_))
+    ));
+}
+
+#[test]
+fn every_indicator_with_defaults() {
+    let ohlcv = load_generated_ohlcv("sma.csv").unwrap();
+    let table = ohlcv_table(&ohlcv);
+    for (name, _, outputs, _) in INDICATORS {
+        let columns =
+            compute_columns(&table, &args(&["-i", name])).unwrap_or_else(|e| panic!("{name}: {e}"));
+        let expected = if outputs.is_empty() {
+            1
+        } else {
+            outputs.split(',').count()
+        };
+        assert_eq!(columns.len(), expected, "{name}");
+        for column in &columns {
+            assert!(column.name.starts_with(name), "{}", column.name);
+            assert_eq!(column.values.len(), ohlcv.close.len(), "{}", column.name);
+        }
+    }
+}
+
+#[test]
+fn column_naming() {
+    let ohlcv = load_generated_ohlcv("sma.csv").unwrap();
+    let table = ohlcv_table(&ohlcv);
+
+    let columns = compute_columns(
+        &table,
+        &args(&["-i", "ema", "-i", "bbands:10,1.5", "-i", "sma:5=fast"]),
+    )
+    .unwrap();
+    let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "ema_14",
+            "bbands_10_1.5_2_upper",
+            "bbands_10_1.5_2_middle",
+            "bbands_10_1.5_2_lower",
+            "fast",
+        ]
+    );
+    let expected = ema(&ohlcv.close, 14, None).unwrap().values;
+    assert_eq!(columns[0].values.len(), expected.len());
+    for (got, expected) in columns[0].values.iter().zip(&expected) {
+        assert!(got == expected || (got.is_nan() && expected.is_nan()));
+    }
+
+    let duplicate = compute_columns(&table, &args(&["-i", "ema", "-i", "ema:14"]));
+    assert!(matches!(duplicate, Err(CliError::Spec(_))));
+}
+
+#[test]
+fn input_errors() {
+    let table = Table::read_csv("close,high\n1,2\n2,x\n3,4\n".as_bytes(), b',').unwrap();
+
+    assert!(matches!(
+        compute_columns(&table, &args(&["-i", "avgprice"])),
+        Err(CliError::MissingColumn(column)) if column == "open"
+    ));
+    assert!(matches!(
+        compute_columns(&table, &args(&["-i", "max:2", "--price", "high"])),
+        Err(CliError::InvalidNumber { row: 1, .. })
+    ));
+    let error = compute_columns(&table, &args(&["-i", "sma:5"])).unwrap_err();
+    assert!(matches!(error, CliError::Indicator { .. }));
+    assert_eq!(error.to_string(), "sma:5: Insufficient data");
+    assert!(matches!(
+        compute_columns(&table, &args(&["-i", "sma:2,3"])),
+        Err(CliError::Spec(_))
+    ));
+    assert!(matches!(
+        compute_columns(&table, &args(&["-i", "nope"])),
+        Err(CliError::Spec(_))
+    ));
+}
+
+#[test]
+fn write_warm_up() {
+    let table = Table::read_csv("t;close\na;1\nb;2\nc;4\n".as_bytes(), b';').unwrap();
+    let columns = compute_columns(&table, &args(&["-i", "sma:2"])).unwrap();
+
+    let mut options = WriteOptions {
+        delimiter: b';',
+        na: String::new(),
+        drop_na: false,
+        precision: None,
+    };
+    let mut out = Vec::new();
+    table.write_csv(&mut out, &columns, &options).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "t;close;sma_2\na;1;\nb;2;1.5\nc;4;3\n"
+    );
+
+    options.na = "NaN".to_string();
+    options.precision = Some(2);
+    let mut out = Vec::new();
+    table.write_csv(&mut out, &columns, &options).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "t;close;sma_2\na;1;NaN\nb;2;1.50\nc;4;3.00\n"
+    );
+
+    options.drop_na = true;
+    let mut out = Vec::new();
+    table.write_csv(&mut out, &columns, &options).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "t;close;sma_2\nb;2;1.50\nc;4;3.00\n"
+    );
+}
+
+#[test]
+fn read_parquet() {
+    let path = temp_path("prices.parquet");
+    let schema = parse_message_type(
+        "message prices { REQUIRED BYTE_ARRAY date (UTF8); REQUIRED DOUBLE close; }",
+    )
+    .unwrap();
+    let dates: Vec<ByteArray> = ["a", "b", "c", "d"].map(ByteArray::from).to_vec();
+    let close: Vec<f64> = vec![1.0, 2.0, 4.0, 8.0];
+
+    let file = std::fs::File::create(&path).unwrap();
+    let mut writer = SerializedFileWriter::new(
+        file,
+        Arc::new(schema),
+        Arc::new(WriterProperties::builder().build()),
+    )
+    .unwrap();
+    let mut row_group = writer.next_row_group().unwrap();
+    let mut column = row_group.next_column().unwrap().unwrap();
+    column
+        .typed::<ByteArrayType>()
+        .write_batch(&dates, None, None)
+        .unwrap();
+    column.close().unwrap();
+    let mut column = row_group.next_column().unwrap().unwrap();
+    column
+        .typed::<DoubleType>()
+        .write_batch(&close, None, None)
+        .unwrap();
+    column.close().unwrap();
+    row_group.close().unwrap();
+    writer.close().unwrap();
+
+    let args = args(&[path.to_str().unwrap(), "-i", "sma:2"]);
+    let table = read_input(&args).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(table.headers, vec!["date", "close"]);
+    assert_eq!(&table.rows[0][0], "a");
+    let columns = compute_columns(&table, &args).unwrap();
+    let expected: Vec<Float> = vec![Float::NAN, 1.5, 3.0, 6.0];
+    assert!(columns[0].values[0].is_nan());
+    assert_eq!(columns[0].values[1..], expected[1..]);
+}
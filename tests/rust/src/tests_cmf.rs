@@ -0,0 +1,161 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_ohlcv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::cmf::cmf,
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_cmf(
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    volume: &[Float],
+    period: usize,
+) -> Vec<Float> {
+    let mut output = vec![Float::NAN; close.len()];
+    for (i, out) in output.iter_mut().enumerate().skip(period - 1) {
+        let mut mfv_sum = 0.0;
+        let mut volume_sum = 0.0;
+        for j in i + 1 - period..=i {
+            if high[j] != low[j] {
+                mfv_sum +=
+                    ((close[j] - low[j]) - (high[j] - close[j])) / (high[j] - low[j]) * volume[j];
+            }
+            volume_sum += volume[j];
+        }
+        *out = if volume_sum == 0.0 {
+            0.0
+        } else {
+            mfv_sum / volume_sum
+        };
+    }
+    output
+}
+
+fn no_lookahead_cmf(file_name: &str, period: usize) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low, close, volume) = (&bars.high, &bars.low, &bars.close, &bars.volume);
+
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_cmf(high, low, close, volume, period);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        volume: &volume[0..last_idx],
+        ..Default::default()
+    };
+    let output = cmf(&ohlcv, period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate CMF: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state
+            .update((high[idx], low[idx], close[idx], volume[idx]))
+            .unwrap();
+        assert!(approx_eq_float(new_state.cmf, expected[idx], 1e-8));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_cmf("sma.csv", 20);
+}
+
+#[test]
+fn no_lookahead_period_1_ok() {
+    no_lookahead_cmf("sma.csv", 1);
+}
+
+#[test]
+fn bounded_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let result = cmf(&bars.view(), 20).unwrap();
+    assert!(result.values[19..]
+        .iter()
+        .all(|value| (-1.0..=1.0).contains(value)));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0, 5.0],
+        low: &[1.0, 2.0, 1.0, 5.0, 4.0],
+        close: &[1.5, 3.0, 2.0, 5.5, 4.5],
+        volume: &[10.0, 10.0, 10.0, Float::NAN, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        cmf(&ohlcv, 2),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 3.0],
+        volume: &[10.0, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        cmf(&ohlcv, 3),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn period_0_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 3.0],
+        volume: &[10.0, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(cmf(&ohlcv, 0), Err(TechalibError::BadParam(_))));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e3f64..1e3, 0.0f64..1e2, -1.0f64..1.0, 0.0f64..1e4), 1..200),
+        period in 1usize..50,
+    ) {
+        prop_assume!(period <= bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let close: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.2).collect();
+        let volume: Vec<Float> = bars.iter().map(|b| b.3).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, close: &close, volume: &volume, ..Default::default() };
+        let result = cmf(&ohlcv, period).unwrap();
+        let expected = slow_cmf(&high, &low, &close, &volume, period);
+        for (o, expect) in result.values.iter().zip(&expected) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6);
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_ohlcv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::donchian::donchian,
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_donchian(
+    high: &[Float],
+    low: &[Float],
+    period: usize,
+) -> (Vec<Float>, Vec<Float>, Vec<Float>) {
+    let mut upper = vec![Float::NAN; high.len()];
+    let mut middle = vec![Float::NAN; high.len()];
+    let mut lower = vec![Float::NAN; high.len()];
+    for i in period - 1..high.len() {
+        upper[i] = high[i + 1 - period..=i]
+            .iter()
+            .cloned()
+            .fold(Float::MIN, Float::max);
+        lower[i] = low[i + 1 - period..=i]
+            .iter()
+            .cloned()
+            .fold(Float::MAX, Float::min);
+        middle[i] = (upper[i] + lower[i]) / 2.0;
+    }
+    (upper, middle, lower)
+}
+
+fn no_lookahead_donchian(file_name: &str, period: usize) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low) = (&bars.high, &bars.low);
+
+    let len = high.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let (expected_upper, expected_middle, expected_lower) = slow_donchian(high, low, period);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        ..Default::default()
+    };
+    let output = donchian(&ohlcv, period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate DONCHIAN: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected_upper[0..last_idx], &result.upper);
+    assert_vec_close(&expected_middle[0..last_idx], &result.middle);
+    assert_vec_close(&expected_lower[0..last_idx], &result.lower);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx])).unwrap();
+        assert_eq!(new_state.upper, expected_upper[idx]);
+        assert_eq!(new_state.middle, expected_middle[idx]);
+        assert_eq!(new_state.lower, expected_lower[idx]);
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_donchian("sma.csv", 20);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0],
+        low: &[1.0, Float::NAN, 1.0, 5.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        donchian(&ohlcv, 2),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn empty_input_err() {
+    assert!(matches!(
+        donchian(&Ohlcv::<Float>::default(), 20),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn columns_length_mismatch_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        donchian(&ohlcv, 2),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn period_1_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        donchian(&ohlcv, 1),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3), 2..200),
+        period in 2usize..50
+    ) {
+        prop_assume!(period <= bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, ..Default::default() };
+        let result = donchian(&ohlcv, period).unwrap();
+        let (upper, middle, lower) = slow_donchian(&high, &low, period);
+        for (o, expect) in result.upper.iter().chain(&result.middle).chain(&result.lower)
+            .zip(upper.iter().chain(&middle).chain(&lower)) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-9);
+            }
+        }
+    }
+}
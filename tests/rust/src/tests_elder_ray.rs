@@ -0,0 +1,168 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_ohlcv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::elder_ray::elder_ray,
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_ema(data: &[Float], period: usize) -> Vec<Float> {
+    let alpha = 2.0 / (period as Float + 1.0);
+    let mut output = vec![Float::NAN; data.len()];
+    output[period - 1] = data[..period].iter().sum::<Float>() / period as Float;
+    for i in period..data.len() {
+        output[i] = alpha * data[i] + (1.0 - alpha) * output[i - 1];
+    }
+    output
+}
+
+fn slow_elder_ray(
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    period: usize,
+) -> (Vec<Float>, Vec<Float>) {
+    let ema = slow_ema(close, period);
+    let bull_power = high.iter().zip(&ema).map(|(h, e)| h - e).collect();
+    let bear_power = low.iter().zip(&ema).map(|(l, e)| l - e).collect();
+    (bull_power, bear_power)
+}
+
+fn no_lookahead_elder_ray(file_name: &str, period: usize) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low, close) = (&bars.high, &bars.low, &bars.close);
+
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let (expected_bull, expected_bear) = slow_elder_ray(high, low, close, period);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        ..Default::default()
+    };
+    let output = elder_ray(&ohlcv, period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate ELDER_RAY: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected_bull[0..last_idx], &result.bull_power);
+    assert_vec_close(&expected_bear[0..last_idx], &result.bear_power);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx], close[idx])).unwrap();
+        assert!(approx_eq_float(
+            new_state.bull_power,
+            expected_bull[idx],
+            1e-8
+        ));
+        assert!(approx_eq_float(
+            new_state.bear_power,
+            expected_bear[idx],
+            1e-8
+        ));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_elder_ray("sma.csv", 13);
+}
+
+#[test]
+fn no_lookahead_short_period_ok() {
+    no_lookahead_elder_ray("sma.csv", 2);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, Float::NAN, 6.0, 5.0],
+        low: &[1.0, 2.0, 1.0, 5.0, 4.0],
+        close: &[1.5, 3.0, 2.0, 5.5, 4.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        elder_ray(&ohlcv, 2),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 3.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        elder_ray(&ohlcv, 3),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn period_1_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        close: &[1.5, 3.0, 2.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        elder_ray(&ohlcv, 1),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn mismatched_columns_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 3.0, 2.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        elder_ray(&ohlcv, 2),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3, -1.0f64..1.0), 3..200),
+        period in 2usize..50,
+    ) {
+        prop_assume!(period <= bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let close: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.2).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, close: &close, ..Default::default() };
+        let result = elder_ray(&ohlcv, period).unwrap();
+        let (bull_power, bear_power) = slow_elder_ray(&high, &low, &close, period);
+        for (o, expect) in result.bull_power.iter().chain(&result.bear_power)
+            .zip(bull_power.iter().chain(&bear_power)) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -105,10 +105,16 @@ fn period_1() {
 
 #[test]
 fn test_period_to_alpha() {
-    assert_eq!(period_to_alpha(10, None).unwrap(), 0.18181818181818182);
-    assert_eq!(period_to_alpha(10, Some(2.0)).unwrap(), 0.18181818181818182);
-    assert!(period_to_alpha(0, None).is_err());
-    assert!(period_to_alpha(10, Some(0.0)).is_err());
+    assert_eq!(
+        period_to_alpha::<Float>(10, None).unwrap(),
+        0.18181818181818182
+    );
+    assert_eq!(
+        period_to_alpha::<Float>(10, Some(2.0)).unwrap(),
+        0.18181818181818182
+    );
+    assert!(period_to_alpha::<Float>(0, None).is_err());
+    assert!(period_to_alpha::<Float>(10, Some(0.0)).is_err());
 }
 
 #[test]
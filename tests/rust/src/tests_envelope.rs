@@ -0,0 +1,154 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use crate::expect_err_overflow_or_ok_with;
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::{
+        envelope::{envelope, EnvelopeResult},
+        ma::{ma, MaType},
+    },
+    traits::State,
+    types::Float,
+};
+
+fn slow_envelope(
+    data: &[Float],
+    period: usize,
+    percent: Float,
+) -> (Vec<Float>, Vec<Float>, Vec<Float>) {
+    let mut upper = vec![Float::NAN; data.len()];
+    let mut middle = vec![Float::NAN; data.len()];
+    let mut lower = vec![Float::NAN; data.len()];
+    for i in period - 1..data.len() {
+        middle[i] = data[i + 1 - period..=i].iter().sum::<Float>() / period as Float;
+        upper[i] = middle[i] * (1.0 + percent / 100.0);
+        lower[i] = middle[i] * (1.0 - percent / 100.0);
+    }
+    (upper, middle, lower)
+}
+
+fn no_lookahead_envelope(file_name: &str, period: usize, percent: Float, ma_type: MaType) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let middle = ma(input, period, ma_type).unwrap().values;
+
+    let output = envelope(&input[0..last_idx], period, percent, ma_type);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate ENVELOPE: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&middle[0..last_idx], &result.middle);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update(input[idx]).unwrap();
+        let width = middle[idx] * percent / 100.0;
+        assert!(
+            approx_eq_float(new_state.middle, middle[idx], 1e-8),
+            "Next [{}] expected {}, but got {}",
+            i,
+            middle[idx],
+            new_state.middle
+        );
+        assert!(approx_eq_float(new_state.upper, middle[idx] + width, 1e-8));
+        assert!(approx_eq_float(new_state.lower, middle[idx] - width, 1e-8));
+    }
+}
+
+#[test]
+fn no_lookahead_sma_ok() {
+    no_lookahead_envelope("sma.csv", 20, 2.5, MaType::SMA);
+}
+
+#[test]
+fn no_lookahead_kama_ok() {
+    no_lookahead_envelope("sma.csv", 20, 5.0, MaType::KAMA);
+}
+
+#[test]
+fn no_lookahead_t3_ok() {
+    no_lookahead_envelope("sma.csv", 5, 1.0, MaType::T3(0.7, None));
+}
+
+#[test]
+fn finite_extreme_err_overflow_or_ok_all_finite() {
+    let data = vec![
+        Float::MAX - 3.0,
+        Float::MAX - 2.0,
+        Float::MAX - 5.0,
+        Float::MAX - 6.0,
+        Float::MAX - 8.0,
+        Float::MAX - 1.0,
+    ];
+    let period = 3;
+    expect_err_overflow_or_ok_with!(
+        envelope(&data, period, 10.0, MaType::WMA),
+        |result: EnvelopeResult| {
+            assert!(
+                result.upper.iter().skip(period).all(|v| v.is_finite()),
+                "Expected all values to be finite"
+            );
+        }
+    );
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let data = vec![1.0, 2.0, 3.0, Float::NAN, 1.0, 2.0, 3.0];
+    let result = envelope(&data, 3, 2.5, MaType::SMA);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    let result = envelope(&data, 14, 2.5, MaType::EMA(None));
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+#[test]
+fn bad_percent_err() {
+    let data = vec![1.0, 2.0, 3.0, 4.0];
+    assert!(matches!(
+        envelope(&data, 2, 0.0, MaType::SMA),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        envelope(&data, 2, Float::INFINITY, MaType::SMA),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 2..200),
+        period in 2usize..50,
+        percent in 0.01f64..50.0
+    ) {
+        prop_assume!(period <= input.len());
+        let result = envelope(&input, period, percent, MaType::SMA).unwrap();
+        let (upper, middle, lower) = slow_envelope(&input, period, percent);
+        for (o, expect) in result.upper.iter().chain(&result.middle).chain(&result.lower)
+            .zip(upper.iter().chain(&middle).chain(&lower)) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6);
+            }
+        }
+    }
+}
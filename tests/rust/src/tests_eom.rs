@@ -0,0 +1,161 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_ohlcv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::eom::{eom, DEFAULT_SCALE},
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_eom(
+    high: &[Float],
+    low: &[Float],
+    volume: &[Float],
+    period: usize,
+    scale: Float,
+) -> Vec<Float> {
+    let mut raw = vec![Float::NAN; high.len()];
+    for i in 1..high.len() {
+        let distance = (high[i] + low[i]) / 2.0 - (high[i - 1] + low[i - 1]) / 2.0;
+        raw[i] = if volume[i] == 0.0 {
+            0.0
+        } else {
+            distance / (volume[i] / scale / (high[i] - low[i]))
+        };
+    }
+    let mut output = vec![Float::NAN; high.len()];
+    for i in period..high.len() {
+        output[i] = raw[i + 1 - period..=i].iter().sum::<Float>() / period as Float;
+    }
+    output
+}
+
+fn no_lookahead_eom(file_name: &str, period: usize, scale: Float) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low, volume) = (&bars.high, &bars.low, &bars.volume);
+
+    let len = high.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_eom(high, low, volume, period, scale);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        volume: &volume[0..last_idx],
+        ..Default::default()
+    };
+    let output = eom(&ohlcv, period, scale);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate EOM: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state
+            .update((high[idx], low[idx], volume[idx]))
+            .unwrap();
+        assert!(approx_eq_float(new_state.eom, expected[idx], 1e-8));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_eom("sma.csv", 14, DEFAULT_SCALE);
+}
+
+#[test]
+fn no_lookahead_period_1_ok() {
+    no_lookahead_eom("sma.csv", 1, 1.0);
+}
+
+#[test]
+fn zero_volume_ok() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 6.0, 8.0],
+        low: &[1.0, 2.0, 3.0, 4.0],
+        volume: &[10.0, 0.0, 0.0, 20.0],
+        ..Default::default()
+    };
+    let result = eom::<Float>(&ohlcv, 1, 1.0).unwrap();
+    assert!(result.values[0].is_nan());
+    assert_eq!(result.values[1], 0.0);
+    assert_eq!(result.values[2], 0.0);
+    assert_eq!(result.values[3], 0.3);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0, 5.0],
+        low: &[1.0, Float::NAN, 1.0, 5.0, 4.0],
+        volume: &[10.0, 10.0, 10.0, 10.0, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        eom(&ohlcv, 2, DEFAULT_SCALE),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        volume: &[10.0, 10.0, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        eom(&ohlcv, 3, DEFAULT_SCALE),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn bad_scale_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        volume: &[10.0, 10.0, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        eom(&ohlcv, 1, 0.0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e3f64..1e3, 0.0f64..1e2, 1.0f64..1e4), 2..200),
+        period in 1usize..50,
+    ) {
+        prop_assume!(period < bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let volume: Vec<Float> = bars.iter().map(|b| b.2).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, volume: &volume, ..Default::default() };
+        let result = eom(&ohlcv, period, DEFAULT_SCALE).unwrap();
+        let expected = slow_eom(&high, &low, &volume, period, DEFAULT_SCALE);
+        for (o, expect) in result.values.iter().zip(&expected) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
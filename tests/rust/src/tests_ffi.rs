@@ -0,0 +1,356 @@
+use crate::helper::generated::{load_generated_csv, load_generated_ohlcv, GeneratedOhlcv};
+use std::ffi::CStr;
+use std::ptr::{null, null_mut};
+use techalib::indicators::ema::ema;
+use techalib::types::Float;
+use techalib_ffi::indicators::ema::{techalib_ema_into, techalib_ema_state_update};
+use techalib_ffi::indicators::ichimoku::{
+    techalib_ichimoku_into, techalib_ichimoku_state_deserialize, techalib_ichimoku_state_free,
+    techalib_ichimoku_state_serialize, techalib_ichimoku_state_update, TechalibIchimokuState,
+};
+use techalib_ffi::indicators::ma::{
+    techalib_ma_into, techalib_ma_state_deserialize, techalib_ma_state_free,
+    techalib_ma_state_serialize, techalib_ma_state_update, TechalibMaState,
+};
+use techalib_ffi::indicators::sma::{
+    techalib_sma_into, techalib_sma_state_deserialize, techalib_sma_state_free, TechalibSmaState,
+};
+use techalib_ffi::indicators::zigzag::{
+    techalib_zigzag_into, techalib_zigzag_state_deserialize, techalib_zigzag_state_free,
+    techalib_zigzag_state_serialize, techalib_zigzag_state_update, TechalibZigzagState,
+};
+use techalib_ffi::status::{techalib_last_error_message, TechalibStatus};
+use techalib_ffi::types::{
+    TechalibIchimokuPeriods, TechalibMaKind, TechalibMaType, TechalibOhlcv,
+    TechalibZigzagThreshold, TechalibZigzagThresholdKind,
+};
+
+type Serialize<H> = unsafe extern "C" fn(*const H, *mut u8, usize, *mut usize) -> TechalibStatus;
+type Deserialize<H> = unsafe extern "C" fn(*const u8, usize, *mut *mut H) -> TechalibStatus;
+
+fn serialize<H>(state: *const H, serialize: Serialize<H>) -> Vec<u8> {
+    let mut size = 0;
+    let status = unsafe { serialize(state, null_mut(), 0, &mut size) };
+    assert_eq!(status, TechalibStatus::BufferTooSmall);
+    let mut bytes = vec![0; size];
+    let mut written = 0;
+    let status = unsafe { serialize(state, bytes.as_mut_ptr(), bytes.len(), &mut written) };
+    assert_eq!(status, TechalibStatus::Ok);
+    assert_eq!(written, size);
+    bytes
+}
+
+fn roundtrip<H>(state: *const H, ser: Serialize<H>, de: Deserialize<H>) -> *mut H {
+    let bytes = serialize(state, ser);
+    let mut restored = null_mut();
+    let status = unsafe { de(bytes.as_ptr(), bytes.len(), &mut restored) };
+    assert_eq!(status, TechalibStatus::Ok);
+    assert!(!restored.is_null());
+    restored
+}
+
+fn ohlcv_view(ohlcv: &GeneratedOhlcv, len: usize) -> TechalibOhlcv {
+    TechalibOhlcv {
+        open: ohlcv.open.as_ptr(),
+        high: ohlcv.high.as_ptr(),
+        low: ohlcv.low.as_ptr(),
+        close: ohlcv.close.as_ptr(),
+        volume: ohlcv.volume.as_ptr(),
+        len,
+    }
+}
+
+fn same(a: Float, b: Float) -> bool {
+    (a.is_nan() && b.is_nan()) || a == b
+}
+
+fn last_error_message() -> String {
+    let message = techalib_last_error_message();
+    assert!(!message.is_null());
+    unsafe { CStr::from_ptr(message) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn ema_into_and_update_match_core() {
+    let columns = load_generated_csv("ema.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let len = input.len() - 20;
+    let expected = ema(input, 30, None).unwrap().values;
+
+    let mut output = vec![0.0; len];
+    let mut state = null_mut();
+    let status = unsafe {
+        techalib_ema_into(
+            input.as_ptr(),
+            len,
+            30,
+            Float::NAN,
+            output.as_mut_ptr(),
+            &mut state,
+        )
+    };
+    assert_eq!(status, TechalibStatus::Ok);
+    assert!(output.iter().zip(&expected).all(|(&a, &b)| same(a, b)));
+
+    for idx in len..input.len() {
+        let mut value = Float::NAN;
+        let status = unsafe { techalib_ema_state_update(state, input[idx], &mut value) };
+        assert_eq!(status, TechalibStatus::Ok);
+        assert!((value - expected[idx]).abs() < 1e-9);
+    }
+    unsafe { techalib_ffi::indicators::ema::techalib_ema_state_free(state) };
+}
+
+#[test]
+fn ma_state_roundtrip_ok() {
+    let columns = load_generated_csv("kama.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let len = input.len() - 20;
+    let ma_type = TechalibMaType {
+        kind: TechalibMaKind::Kama,
+        alpha: Float::NAN,
+        volume_factor: 0.0,
+        offset: 0.0,
+        sigma: 0.0,
+        cmo_period: 0,
+    };
+
+    let mut output = vec![0.0; len];
+    let mut state: *mut TechalibMaState = null_mut();
+    let status = unsafe {
+        techalib_ma_into(
+            input.as_ptr(),
+            len,
+            30,
+            ma_type,
+            output.as_mut_ptr(),
+            &mut state,
+        )
+    };
+    assert_eq!(status, TechalibStatus::Ok);
+    let restored = roundtrip(
+        state,
+        techalib_ma_state_serialize,
+        techalib_ma_state_deserialize,
+    );
+
+    for &sample in &input[len..] {
+        let (mut a, mut b) = (Float::NAN, Float::NAN);
+        unsafe {
+            assert_eq!(
+                techalib_ma_state_update(state, sample, &mut a),
+                TechalibStatus::Ok
+            );
+            assert_eq!(
+                techalib_ma_state_update(restored, sample, &mut b),
+                TechalibStatus::Ok
+            );
+        }
+        assert!(same(a, b));
+    }
+    unsafe {
+        techalib_ma_state_free(state);
+        techalib_ma_state_free(restored);
+    }
+}
+
+#[test]
+fn ichimoku_state_roundtrip_ok() {
+    let ohlcv = load_generated_ohlcv("sma.csv").unwrap();
+    let len = ohlcv.close.len() - 20;
+    let view = ohlcv_view(&ohlcv, len);
+    let periods = TechalibIchimokuPeriods {
+        tenkan: 9,
+        kijun: 26,
+        senkou_b: 52,
+        displacement: 26,
+    };
+
+    let mut outputs = vec![vec![0.0; len]; 5];
+    let mut state: *mut TechalibIchimokuState = null_mut();
+    let status = unsafe {
+        let [tenkan, kijun, senkou_a, senkou_b, chikou] = &mut outputs[..] else {
+            unreachable!()
+        };
+        techalib_ichimoku_into(
+            &view,
+            periods,
+            tenkan.as_mut_ptr(),
+            kijun.as_mut_ptr(),
+            senkou_a.as_mut_ptr(),
+            senkou_b.as_mut_ptr(),
+            chikou.as_mut_ptr(),
+            &mut state,
+        )
+    };
+    assert_eq!(status, TechalibStatus::Ok);
+    let restored = roundtrip(
+        state,
+        techalib_ichimoku_state_serialize,
+        techalib_ichimoku_state_deserialize,
+    );
+
+    for idx in len..ohlcv.close.len() {
+        let (mut a, mut b) = ([Float::NAN; 5], [Float::NAN; 5]);
+        for (state, out) in [(state, &mut a), (restored, &mut b)] {
+            let [tenkan, kijun, senkou_a, senkou_b, chikou] = out;
+            let status = unsafe {
+                techalib_ichimoku_state_update(
+                    state,
+                    ohlcv.high[idx],
+                    ohlcv.low[idx],
+                    ohlcv.close[idx],
+                    tenkan,
+                    kijun,
+                    senkou_a,
+                    senkou_b,
+                    chikou,
+                )
+            };
+            assert_eq!(status, TechalibStatus::Ok);
+        }
+        assert!(a.iter().zip(&b).all(|(&a, &b)| same(a, b)));
+    }
+    unsafe {
+        techalib_ichimoku_state_free(state);
+        techalib_ichimoku_state_free(restored);
+    }
+}
+
+#[test]
+fn zigzag_atr_state_roundtrip_ok() {
+    let ohlcv = load_generated_ohlcv("sma.csv").unwrap();
+    let len = ohlcv.close.len() - 20;
+    let view = ohlcv_view(&ohlcv, len);
+    let threshold = TechalibZigzagThreshold {
+        kind: TechalibZigzagThresholdKind::Atr,
+        percent: Float::NAN,
+        atr_period: 14,
+        atr_multiplier: 2.0,
+    };
+
+    let mut outputs = vec![vec![0.0; len]; 3];
+    let mut state: *mut TechalibZigzagState = null_mut();
+    let status = unsafe {
+        let [pivots, confirmed, direction] = &mut outputs[..] else {
+            unreachable!()
+        };
+        techalib_zigzag_into(
+            &view,
+            threshold,
+            pivots.as_mut_ptr(),
+            confirmed.as_mut_ptr(),
+            direction.as_mut_ptr(),
+            &mut state,
+        )
+    };
+    assert_eq!(status, TechalibStatus::Ok);
+    let restored = roundtrip(
+        state,
+        techalib_zigzag_state_serialize,
+        techalib_zigzag_state_deserialize,
+    );
+
+    for idx in len..ohlcv.close.len() {
+        let (mut a, mut b) = ([Float::NAN; 2], [Float::NAN; 2]);
+        for (state, [confirmed, direction]) in [(state, &mut a), (restored, &mut b)] {
+            let status = unsafe {
+                techalib_zigzag_state_update(
+                    state,
+                    ohlcv.high[idx],
+                    ohlcv.low[idx],
+                    ohlcv.close[idx],
+                    confirmed,
+                    direction,
+                )
+            };
+            assert_eq!(status, TechalibStatus::Ok);
+        }
+        assert!(a.iter().zip(&b).all(|(&a, &b)| same(a, b)));
+    }
+    unsafe {
+        techalib_zigzag_state_free(state);
+        techalib_zigzag_state_free(restored);
+    }
+}
+
+#[test]
+fn errors_status_and_message() {
+    let data: Vec<Float> = (0..10).map(|v| v as Float).collect();
+    let mut output = vec![0.0; data.len()];
+
+    let status = unsafe {
+        techalib_sma_into(
+            data.as_ptr(),
+            data.len(),
+            20,
+            output.as_mut_ptr(),
+            null_mut(),
+        )
+    };
+    assert_eq!(status, TechalibStatus::InsufficientData);
+
+    let status =
+        unsafe { techalib_sma_into(null(), data.len(), 5, output.as_mut_ptr(), null_mut()) };
+    assert_eq!(status, TechalibStatus::NullPointer);
+    assert!(last_error_message().contains("`data`"));
+
+    let status = unsafe { techalib_sma_into(data.as_ptr(), 0, 5, null_mut(), null_mut()) };
+    assert_eq!(status, TechalibStatus::InsufficientData);
+}
+
+#[test]
+fn deserialize_invalid_state_err() {
+    let ohlcv = load_generated_ohlcv("sma.csv").unwrap();
+    let len = ohlcv.close.len();
+    let view = ohlcv_view(&ohlcv, len);
+    let threshold = TechalibZigzagThreshold {
+        kind: TechalibZigzagThresholdKind::Percent,
+        percent: 5.0,
+        atr_period: 0,
+        atr_multiplier: Float::NAN,
+    };
+    let mut outputs = vec![vec![0.0; len]; 3];
+    let mut state = null_mut();
+    let status = unsafe {
+        techalib_zigzag_into(
+            &view,
+            threshold,
+            outputs[0].as_mut_ptr(),
+            outputs[1].as_mut_ptr(),
+            outputs[2].as_mut_ptr(),
+            &mut state,
+        )
+    };
+    assert_eq!(status, TechalibStatus::Ok);
+    let bytes = serialize(state, techalib_zigzag_state_serialize);
+    unsafe { techalib_zigzag_state_free(state) };
+
+    let mut wrong_kind: *mut TechalibSmaState = null_mut();
+    let status =
+        unsafe { techalib_sma_state_deserialize(bytes.as_ptr(), bytes.len(), &mut wrong_kind) };
+    assert_eq!(status, TechalibStatus::InvalidState);
+    assert!(wrong_kind.is_null());
+    assert!(last_error_message().contains("`zigzag`"));
+
+    let mut truncated = null_mut();
+    let status = unsafe {
+        techalib_zigzag_state_deserialize(bytes.as_ptr(), bytes.len() - 1, &mut truncated)
+    };
+    assert_eq!(status, TechalibStatus::InvalidState);
+
+    let mut corrupted_bytes = bytes.clone();
+    corrupted_bytes[0] = b'X';
+    let status = unsafe {
+        techalib_zigzag_state_deserialize(
+            corrupted_bytes.as_ptr(),
+            corrupted_bytes.len(),
+            &mut truncated,
+        )
+    };
+    assert_eq!(status, TechalibStatus::InvalidState);
+    assert!(truncated.is_null());
+    unsafe { techalib_sma_state_free(wrong_kind) };
+}
@@ -0,0 +1,129 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_ohlcv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::force_index::force_index,
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_force_index(close: &[Float], volume: &[Float], period: usize) -> Vec<Float> {
+    let alpha = 2.0 / (period as Float + 1.0);
+    let mut output = vec![Float::NAN; close.len()];
+    let force: Vec<Float> = (1..close.len())
+        .map(|i| (close[i] - close[i - 1]) * volume[i])
+        .collect();
+    output[period] = force[..period].iter().sum::<Float>() / period as Float;
+    for i in period + 1..close.len() {
+        output[i] = alpha * force[i - 1] + (1.0 - alpha) * output[i - 1];
+    }
+    output
+}
+
+fn no_lookahead_force_index(file_name: &str, period: usize) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (close, volume) = (&bars.close, &bars.volume);
+
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_force_index(close, volume, period);
+
+    let ohlcv = Ohlcv {
+        close: &close[0..last_idx],
+        volume: &volume[0..last_idx],
+        ..Default::default()
+    };
+    let output = force_index(&ohlcv, period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate FORCE_INDEX: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((close[idx], volume[idx])).unwrap();
+        assert!(approx_eq_float(new_state.force_index, expected[idx], 1e-6));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_force_index("sma.csv", 13);
+}
+
+#[test]
+fn no_lookahead_period_1_ok() {
+    no_lookahead_force_index("sma.csv", 1);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        close: &[1.0, 2.0, 3.0, 4.0, 5.0],
+        volume: &[10.0, 10.0, Float::NAN, 10.0, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        force_index(&ohlcv, 2),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        close: &[1.0, 2.0, 3.0],
+        volume: &[10.0, 10.0, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        force_index(&ohlcv, 3),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn period_0_err() {
+    let ohlcv = Ohlcv {
+        close: &[1.0, 2.0, 3.0],
+        volume: &[10.0, 10.0, 10.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        force_index(&ohlcv, 0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e3f64..1e3, 0.0f64..1e4), 2..200),
+        period in 1usize..50,
+    ) {
+        prop_assume!(period < bars.len());
+        let close: Vec<Float> = bars.iter().map(|b| b.0).collect();
+        let volume: Vec<Float> = bars.iter().map(|b| b.1).collect();
+        let ohlcv = Ohlcv { close: &close, volume: &volume, ..Default::default() };
+        let result = force_index(&ohlcv, period).unwrap();
+        let expected = slow_force_index(&close, &volume, period);
+        for (o, expect) in result.values.iter().zip(&expected) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
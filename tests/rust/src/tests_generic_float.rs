@@ -0,0 +1,102 @@
+use crate::helper::generated::{load_generated_csv, load_generated_ohlcv};
+
+use techalib::{
+    indicators::{
+        atr::atr,
+        bbands::{bbands, BBandsMA, DeviationMulipliers},
+        ema::ema,
+        ma::{ma, MaType},
+        rsi::rsi,
+        sma::{sma, SmaState},
+        volatility::{volatility, VolatilityEstimator, DEFAULT_ANNUALIZATION},
+    },
+    traits::State,
+    types::{Float, Ohlcv, TechalibFloat},
+};
+
+fn to_f32(values: &[Float]) -> Vec<f32> {
+    values.iter().map(|&v| v as f32).collect()
+}
+
+fn assert_same_values<T: TechalibFloat>(expected: &[f64], got: &[T]) {
+    assert_eq!(expected.len(), got.len());
+    for (i, (e, g)) in expected.iter().zip(got).enumerate() {
+        let g = g.to_f64();
+        assert!(
+            (e.is_nan() && g.is_nan()) || (e - g).abs() <= 1e-4 * e.abs().max(1.0),
+            "[{i}] Expected: {e}, got: {g}"
+        );
+    }
+}
+
+#[test]
+fn f32_and_f64_coexist_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input: Vec<f64> = columns
+        .get("close")
+        .unwrap()
+        .iter()
+        .map(|&v| v.to_f64())
+        .collect();
+    let input_f32: Vec<f32> = input.iter().map(|&v| v as f32).collect();
+
+    let expected = sma(&input, 30).unwrap().values;
+    assert_same_values(&expected, &sma(&input_f32, 30).unwrap().values);
+
+    let expected = ema(&input, 30, None).unwrap().values;
+    assert_same_values(&expected, &ema(&input_f32, 30, None).unwrap().values);
+
+    let expected = rsi(&input, 14).unwrap().values;
+    assert_same_values(&expected, &rsi(&input_f32, 14).unwrap().values);
+
+    let expected = ma(&input, 20, MaType::ALMA(0.85, 6.0)).unwrap().values;
+    let got = ma(&input_f32, 20, MaType::ALMA(0.85, 6.0)).unwrap().values;
+    assert_same_values(&expected, &got);
+
+    let multipliers = DeviationMulipliers { up: 2.0, down: 2.0 };
+    let expected = bbands(&input, 20, multipliers, BBandsMA::SMA).unwrap();
+    let multipliers = DeviationMulipliers { up: 2.0, down: 2.0 };
+    let got = bbands(&input_f32, 20, multipliers, BBandsMA::SMA).unwrap();
+    assert_same_values(&expected.upper, &got.upper);
+    assert_same_values(&expected.lower, &got.lower);
+}
+
+#[test]
+fn ohlcv_f32_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let (open, high, low, close) = (
+        to_f32(&bars.open),
+        to_f32(&bars.high),
+        to_f32(&bars.low),
+        to_f32(&bars.close),
+    );
+    let ohlcv = Ohlcv {
+        open: &open,
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+
+    let expected = atr(&bars.view(), 14).unwrap().values;
+    let expected: Vec<f64> = expected.iter().map(|&v| v.to_f64()).collect();
+    assert_same_values(&expected, &atr(&ohlcv, 14).unwrap().values);
+
+    let estimator = VolatilityEstimator::YangZhang;
+    let expected = volatility(&bars.view(), 20, estimator, DEFAULT_ANNUALIZATION)
+        .unwrap()
+        .values;
+    let expected: Vec<f64> = expected.iter().map(|&v| v.to_f64()).collect();
+    let got = volatility(&ohlcv, 20, estimator, DEFAULT_ANNUALIZATION as f32).unwrap();
+    assert_same_values(&expected, &got.values);
+}
+
+#[test]
+fn f32_state_update_ok() {
+    let data: Vec<f32> = (0..50).map(|i| (i as f32 * 0.3).sin() + 10.0).collect();
+    let result = sma(&data[..49], 10).unwrap();
+    let mut state: SmaState<f32> = result.state;
+    state.update(data[49]).unwrap();
+    let expected = sma(&data, 10).unwrap().state.sma;
+    assert!((state.sma - expected).abs() < 1e-5);
+}
@@ -0,0 +1,165 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_ohlcv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::{atr::atr, heikin_ashi::heikin_ashi},
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+type Candles = (Vec<Float>, Vec<Float>, Vec<Float>, Vec<Float>);
+
+fn slow_heikin_ashi(ohlcv: &Ohlcv) -> Candles {
+    let len = ohlcv.close.len();
+    let (mut open, mut high, mut low, mut close) = (
+        vec![0.0; len],
+        vec![0.0; len],
+        vec![0.0; len],
+        vec![0.0; len],
+    );
+    for i in 0..len {
+        close[i] = (ohlcv.open[i] + ohlcv.high[i] + ohlcv.low[i] + ohlcv.close[i]) / 4.0;
+        open[i] = if i == 0 {
+            (ohlcv.open[0] + ohlcv.close[0]) / 2.0
+        } else {
+            (open[i - 1] + close[i - 1]) / 2.0
+        };
+        high[i] = ohlcv.high[i].max(open[i]).max(close[i]);
+        low[i] = ohlcv.low[i].min(open[i]).min(close[i]);
+    }
+    (open, high, low, close)
+}
+
+#[test]
+fn no_lookahead_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let ohlcv = bars.view();
+
+    let len = ohlcv.close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let (open, high, low, close) = slow_heikin_ashi(&ohlcv);
+
+    let input = Ohlcv {
+        open: &ohlcv.open[0..last_idx],
+        high: &ohlcv.high[0..last_idx],
+        low: &ohlcv.low[0..last_idx],
+        close: &ohlcv.close[0..last_idx],
+        ..Default::default()
+    };
+    let output = heikin_ashi(&input);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate HEIKIN_ASHI: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&open[0..last_idx], &result.open);
+    assert_vec_close(&high[0..last_idx], &result.high);
+    assert_vec_close(&low[0..last_idx], &result.low);
+    assert_vec_close(&close[0..last_idx], &result.close);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state
+            .update((
+                ohlcv.open[idx],
+                ohlcv.high[idx],
+                ohlcv.low[idx],
+                ohlcv.close[idx],
+            ))
+            .unwrap();
+        assert_eq!(new_state.open, open[idx]);
+        assert_eq!(new_state.high, high[idx]);
+        assert_eq!(new_state.low, low[idx]);
+        assert_eq!(new_state.close, close[idx]);
+    }
+}
+
+#[test]
+fn view_as_indicator_input_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let result = heikin_ashi(&bars.view()).unwrap();
+    let (_, high, low, close) = slow_heikin_ashi(&bars.view());
+    let slow_ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+
+    let from_view = atr(&result.view(), 14).unwrap();
+    let expected = atr(&slow_ohlcv, 14).unwrap();
+    assert_vec_close(&expected.values, &from_view.values);
+
+    let with_volume = Ohlcv {
+        volume: &bars.volume,
+        ..result.view()
+    };
+    assert_eq!(with_volume.volume.len(), with_volume.close.len());
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        open: &[1.5, 3.0, 2.0],
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, Float::NAN, 1.0],
+        close: &[1.8, 3.5, 2.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        heikin_ashi(&ohlcv),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn empty_input_err() {
+    assert!(matches!(
+        heikin_ashi(&Ohlcv::<Float>::default()),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn columns_length_mismatch_err() {
+    let ohlcv = Ohlcv {
+        open: &[1.5, 3.0],
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        close: &[1.8, 3.5, 2.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        heikin_ashi(&ohlcv),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3, -1.0f64..1.0, -1.0f64..1.0), 1..200),
+    ) {
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let open: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.2).collect();
+        let close: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.3).collect();
+        let ohlcv = Ohlcv { open: &open, high: &high, low: &low, close: &close, ..Default::default() };
+        let result = heikin_ashi(&ohlcv).unwrap();
+        let (s_open, s_high, s_low, s_close) = slow_heikin_ashi(&ohlcv);
+        prop_assert_eq!(&result.open, &s_open);
+        prop_assert_eq!(&result.high, &s_high);
+        prop_assert_eq!(&result.low, &s_low);
+        prop_assert_eq!(&result.close, &s_close);
+        for i in 0..close.len() {
+            prop_assert!(result.high[i] >= result.open[i].max(result.close[i]));
+            prop_assert!(result.low[i] <= result.open[i].min(result.close[i]));
+        }
+    }
+}
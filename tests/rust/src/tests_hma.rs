@@ -0,0 +1,139 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::hma::{hma, lookback_from_period},
+    traits::State,
+    types::Float,
+};
+
+fn slow_wma(data: &[Float], period: usize) -> Vec<Float> {
+    let weight_sum = (period * (period + 1) / 2) as Float;
+    (0..data.len())
+        .map(|i| {
+            if i + 1 < period || data[i + 1 - period..=i].iter().any(|v| v.is_nan()) {
+                return Float::NAN;
+            }
+            (0..period)
+                .map(|j| data[i + 1 - period + j] * (j + 1) as Float)
+                .sum::<Float>()
+                / weight_sum
+        })
+        .collect()
+}
+
+fn slow_hma(data: &[Float], period: usize) -> Vec<Float> {
+    let half = slow_wma(data, period / 2);
+    let full = slow_wma(data, period);
+    let diff: Vec<Float> = half.iter().zip(&full).map(|(h, f)| 2.0 * h - f).collect();
+    slow_wma(&diff, (period as Float).sqrt() as usize)
+}
+
+fn no_lookahead_hma(file_name: &str, period: usize) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_hma(input, period);
+
+    let output = hma(&input[0..last_idx], period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate HMA: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert!(
+            approx_eq_float(new_state.hma, expected[last_idx + i], 1e-8),
+            "Next expected {}, but got {}",
+            expected[last_idx + i],
+            new_state.hma
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_hma("sma.csv", 16);
+}
+
+#[test]
+fn no_lookahead_odd_period_ok() {
+    no_lookahead_hma("sma.csv", 21);
+}
+
+#[test]
+fn lookback_ok() {
+    let input: Vec<Float> = (0..100).map(|v| v as Float).collect();
+    let result = hma(&input, 16).unwrap();
+    let lookback = lookback_from_period(16);
+    assert_eq!(lookback, 18);
+    assert!(result.values[..lookback].iter().all(|v| v.is_nan()));
+    assert!(result.values[lookback..].iter().all(|v| v.is_finite()));
+}
+
+#[test]
+fn linear_has_no_lag_ok() {
+    let input: Vec<Float> = (0..100).map(|v| v as Float).collect();
+    let result = hma(&input, 9).unwrap();
+    let lookback = lookback_from_period(9);
+    for (value, expected) in result.values[lookback..].iter().zip(&input[lookback..]) {
+        assert!(approx_eq_float(*value, *expected, 1e-9));
+    }
+}
+
+#[test]
+fn period_too_small_err() {
+    let input = vec![1.0; 50];
+    assert!(matches!(hma(&input, 3), Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let input = vec![1.0; 18];
+    assert!(matches!(
+        hma(&input, 16),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut input = vec![1.0; 50];
+    input[30] = Float::NAN;
+    assert!(matches!(
+        hma(&input, 9),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 40..200),
+        period in 4usize..20,
+    ) {
+        let result = hma(&input, period).unwrap();
+        let expected = slow_hma(&input, period);
+        for (o, e) in result.values.iter().zip(&expected) {
+            if e.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - e).abs() <= 1e-6 * e.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_ohlcv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::ichimoku::{ichimoku, IchimokuPeriods},
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_midpoint(high: &[Float], low: &[Float], period: usize) -> Vec<Float> {
+    let mut out = vec![Float::NAN; high.len()];
+    for i in period - 1..high.len() {
+        let highest = high[i + 1 - period..=i]
+            .iter()
+            .cloned()
+            .fold(Float::MIN, Float::max);
+        let lowest = low[i + 1 - period..=i]
+            .iter()
+            .cloned()
+            .fold(Float::MAX, Float::min);
+        out[i] = (highest + lowest) / 2.0;
+    }
+    out
+}
+
+/// Unshifted lines: tenkan, kijun, senkou A, senkou B
+fn slow_ichimoku_lines(
+    high: &[Float],
+    low: &[Float],
+    periods: IchimokuPeriods,
+) -> (Vec<Float>, Vec<Float>, Vec<Float>, Vec<Float>) {
+    let tenkan = slow_midpoint(high, low, periods.tenkan);
+    let kijun = slow_midpoint(high, low, periods.kijun);
+    let senkou_a = tenkan
+        .iter()
+        .zip(&kijun)
+        .map(|(t, k)| (t + k) / 2.0)
+        .collect();
+    let senkou_b = slow_midpoint(high, low, periods.senkou_b);
+    (tenkan, kijun, senkou_a, senkou_b)
+}
+
+fn shift(data: &[Float], shift: isize) -> Vec<Float> {
+    (0..data.len() as isize)
+        .map(|i| {
+            let j = i - shift;
+            if j >= 0 && (j as usize) < data.len() {
+                data[j as usize]
+            } else {
+                Float::NAN
+            }
+        })
+        .collect()
+}
+
+fn approx_eq(a: Float, b: Float) -> bool {
+    (a.is_nan() && b.is_nan()) || (a - b).abs() <= 1e-9
+}
+
+fn no_lookahead_ichimoku(file_name: &str, periods: IchimokuPeriods) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low, close) = (&bars.high, &bars.low, &bars.close);
+    let displacement = periods.displacement;
+
+    let len = high.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let (tenkan, kijun, raw_senkou_a, raw_senkou_b) = slow_ichimoku_lines(high, low, periods);
+    let senkou_a = shift(&raw_senkou_a, displacement as isize);
+    let senkou_b = shift(&raw_senkou_b, displacement as isize);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        ..Default::default()
+    };
+    let output = ichimoku(&ohlcv, periods);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate ICHIMOKU: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&tenkan[0..last_idx], &result.tenkan);
+    assert_vec_close(&kijun[0..last_idx], &result.kijun);
+    assert_vec_close(&senkou_a[0..last_idx], &result.senkou_a);
+    assert_vec_close(&senkou_b[0..last_idx], &result.senkou_b);
+    assert_vec_close(
+        &shift(&close[0..last_idx], -(displacement as isize)),
+        &result.chikou,
+    );
+    assert_vec_close(
+        &raw_senkou_a[last_idx - displacement..last_idx],
+        &Vec::from(result.state.senkou_a_ahead.clone()),
+    );
+    assert_vec_close(
+        &raw_senkou_b[last_idx - displacement..last_idx],
+        &Vec::from(result.state.senkou_b_ahead.clone()),
+    );
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx], close[idx])).unwrap();
+        assert!(approx_eq(new_state.tenkan, tenkan[idx]));
+        assert!(approx_eq(new_state.kijun, kijun[idx]));
+        assert!(approx_eq(new_state.senkou_a, senkou_a[idx]));
+        assert!(approx_eq(new_state.senkou_b, senkou_b[idx]));
+        assert_eq!(new_state.chikou, close[idx]);
+        assert_eq!(new_state.senkou_a_ahead.len(), displacement);
+        if displacement > 0 {
+            assert!(approx_eq(
+                new_state.senkou_a_ahead.back().unwrap(),
+                raw_senkou_a[idx]
+            ));
+            assert!(approx_eq(
+                new_state.senkou_b_ahead.back().unwrap(),
+                raw_senkou_b[idx]
+            ));
+        }
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_ichimoku("sma.csv", IchimokuPeriods::default());
+}
+
+#[test]
+fn no_lookahead_short_periods_ok() {
+    no_lookahead_ichimoku(
+        "sma.csv",
+        IchimokuPeriods {
+            tenkan: 3,
+            kijun: 5,
+            senkou_b: 8,
+            displacement: 4,
+        },
+    );
+}
+
+#[test]
+fn no_displacement_ok() {
+    no_lookahead_ichimoku(
+        "sma.csv",
+        IchimokuPeriods {
+            displacement: 0,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn displacement_longer_than_input_ok() {
+    let high = [2.0, 4.0, 3.0, 6.0, 5.0];
+    let low = [1.0, 2.0, 1.0, 5.0, 4.0];
+    let close = [1.5, 3.0, 2.0, 5.5, 4.5];
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let periods = IchimokuPeriods {
+        tenkan: 2,
+        kijun: 3,
+        senkou_b: 4,
+        displacement: 8,
+    };
+    let result = ichimoku::<Float>(&ohlcv, periods).unwrap();
+    assert!(result.senkou_a.iter().all(|v| v.is_nan()));
+    assert!(result.chikou.iter().all(|v| v.is_nan()));
+
+    let ahead: Vec<Float> = result.state.senkou_b_ahead.into();
+    assert_eq!(ahead.len(), 8);
+    assert!(ahead[..6].iter().all(|v| v.is_nan()));
+    assert_eq!(ahead[6], 3.5);
+    assert_eq!(ahead[7], 3.5);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0],
+        low: &[1.0, 2.0, 1.0, 5.0],
+        close: &[1.5, Float::NAN, 2.0, 5.5],
+        ..Default::default()
+    };
+    let periods = IchimokuPeriods {
+        tenkan: 2,
+        kijun: 2,
+        senkou_b: 3,
+        displacement: 1,
+    };
+    assert!(matches!(
+        ichimoku(&ohlcv, periods),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn empty_input_err() {
+    assert!(matches!(
+        ichimoku(&Ohlcv::<Float>::default(), IchimokuPeriods::default()),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn columns_length_mismatch_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        close: &[1.5, 3.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        ichimoku(&ohlcv, IchimokuPeriods::default()),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn period_1_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        close: &[1.5, 3.0, 2.0],
+        ..Default::default()
+    };
+    let periods = IchimokuPeriods {
+        tenkan: 1,
+        kijun: 2,
+        senkou_b: 3,
+        displacement: 1,
+    };
+    assert!(matches!(
+        ichimoku(&ohlcv, periods),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3), 2..200),
+        tenkan in 2usize..20,
+        kijun in 2usize..40,
+        senkou_b in 2usize..60,
+        displacement in 0usize..40,
+    ) {
+        let periods = IchimokuPeriods { tenkan, kijun, senkou_b, displacement };
+        prop_assume!(tenkan.max(kijun).max(senkou_b) <= bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let close: Vec<Float> = bars.iter().map(|b| b.0).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, close: &close, ..Default::default() };
+        let result = ichimoku(&ohlcv, periods).unwrap();
+        let (tenkan, kijun, senkou_a, senkou_b) = slow_ichimoku_lines(&high, &low, periods);
+        let senkou_a = shift(&senkou_a, displacement as isize);
+        let senkou_b = shift(&senkou_b, displacement as isize);
+        let chikou = shift(&close, -(displacement as isize));
+        for (o, expect) in result.tenkan.iter()
+            .chain(&result.kijun)
+            .chain(&result.senkou_a)
+            .chain(&result.senkou_b)
+            .chain(&result.chikou)
+            .zip(tenkan.iter().chain(&kijun).chain(&senkou_a).chain(&senkou_b).chain(&chikou)) {
+            prop_assert!(approx_eq(*o, *expect));
+        }
+    }
+}
@@ -51,6 +51,26 @@ fn generated_with_no_lookahead_ok() {
     generated_and_no_lookahead_kama("kama.csv", 30)
 }
 
+#[test]
+fn state_update_over_several_windows_ok() {
+    let columns = load_generated_csv("kama.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let expected = columns.get("out").unwrap();
+
+    let period = 30;
+    let start = 2 * period;
+    let mut state = kama(&input[..start], period).unwrap().state;
+    for (idx, &sample) in input.iter().enumerate().skip(start) {
+        state.update(sample).unwrap();
+        assert!(
+            approx_eq_float(state.kama, expected[idx], 1e-8),
+            "[{idx}] Expected {}, but got {}",
+            expected[idx],
+            state.kama
+        );
+    }
+}
+
 #[test]
 fn finite_extreme_err_overflow_or_ok_all_finite() {
     let data = vec![
@@ -0,0 +1,144 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_ohlcv,
+};
+
+use crate::tests_atr::slow_atr;
+use techalib::{
+    errors::TechalibError,
+    indicators::{
+        ema::ema,
+        keltner::{keltner, lookback_from_period},
+    },
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn no_lookahead_keltner(file_name: &str, period: usize, atr_period: usize, multiplier: Float) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low, close) = (&bars.high, &bars.low, &bars.close);
+
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let lookback = lookback_from_period(period, atr_period);
+    let middle = ema(close, period, None).unwrap().values;
+    let atr = slow_atr(high, low, close, atr_period);
+    let nan_lookback = |i: usize, v: Float| if i < lookback { Float::NAN } else { v };
+    let expected_middle: Vec<Float> = (0..len).map(|i| nan_lookback(i, middle[i])).collect();
+    let expected_upper: Vec<Float> = (0..len)
+        .map(|i| nan_lookback(i, middle[i] + multiplier * atr[i]))
+        .collect();
+    let expected_lower: Vec<Float> = (0..len)
+        .map(|i| nan_lookback(i, middle[i] - multiplier * atr[i]))
+        .collect();
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        ..Default::default()
+    };
+    let output = keltner(&ohlcv, period, atr_period, multiplier);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate KELTNER: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected_upper[0..last_idx], &result.upper);
+    assert_vec_close(&expected_middle[0..last_idx], &result.middle);
+    assert_vec_close(&expected_lower[0..last_idx], &result.lower);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx], close[idx])).unwrap();
+        assert!(
+            approx_eq_float(new_state.upper, expected_upper[idx], 1e-8),
+            "Next [{}] expected upper {}, but got {}",
+            i,
+            expected_upper[idx],
+            new_state.upper
+        );
+        assert!(approx_eq_float(
+            new_state.middle,
+            expected_middle[idx],
+            1e-8
+        ));
+        assert!(approx_eq_float(new_state.lower, expected_lower[idx], 1e-8));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_keltner("sma.csv", 20, 10, 2.0);
+}
+
+#[test]
+fn no_lookahead_atr_period_longer_ok() {
+    no_lookahead_keltner("sma.csv", 10, 30, 1.5);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let high = [2.0, 4.0, 3.0, 6.0, 5.0];
+    let low = [1.0, 2.0, 1.0, 5.0, 4.0];
+    let close = [1.5, 3.0, Float::NAN, 5.5, 4.5];
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    assert!(matches!(
+        keltner(&ohlcv, 2, 2, 2.0),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn empty_input_err() {
+    assert!(matches!(
+        keltner(&Ohlcv::default(), 20, 10, 2.0),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn bad_multiplier_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 3.0, 4.0],
+        low: &[1.0, 2.0, 3.0],
+        close: &[1.5, 2.0, 3.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        keltner(&ohlcv, 2, 1, 0.0),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        keltner(&ohlcv, 2, 1, Float::NAN),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn next_with_nan_err_keeps_state() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 3.0, 4.0, 5.0],
+        low: &[1.0, 2.0, 3.0, 4.0],
+        close: &[1.5, 2.0, 3.5, 4.5],
+        ..Default::default()
+    };
+    let mut state = keltner(&ohlcv, 2, 2, 2.0).unwrap().state;
+    let before = state;
+    assert!(matches!(
+        state.update((Float::NAN, 4.0, 4.5)),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+    assert_eq!(state.ema.ema, before.ema.ema);
+    assert_eq!(state.atr.atr, before.atr.atr);
+}
@@ -0,0 +1,136 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use techalib::{
+    errors::TechalibError,
+    indicators::{
+        alma::alma,
+        dema::dema,
+        ema::ema,
+        hma::hma,
+        kama::kama,
+        ma::{lookback_from_period, ma, MaType},
+        sma::sma,
+        smma::smma,
+        t3::t3,
+        tema::tema,
+        trima::trima,
+        vidya::vidya,
+        wma::wma,
+        zlema::zlema,
+    },
+    traits::State,
+    types::Float,
+};
+
+const MA_TYPES: [MaType; 13] = [
+    MaType::SMA,
+    MaType::EMA(None),
+    MaType::WMA,
+    MaType::DEMA(None),
+    MaType::TEMA(None),
+    MaType::TRIMA,
+    MaType::KAMA,
+    MaType::T3(0.7, None),
+    MaType::HMA,
+    MaType::ZLEMA(None),
+    MaType::ALMA(0.85, 6.0),
+    MaType::SMMA,
+    MaType::VIDYA(9),
+];
+
+fn direct_ma(data: &[Float], period: usize, ma_type: MaType) -> Vec<Float> {
+    match ma_type {
+        MaType::SMA => sma(data, period).unwrap().values,
+        MaType::EMA(alpha) => ema(data, period, alpha).unwrap().values,
+        MaType::WMA => wma(data, period).unwrap().values,
+        MaType::DEMA(alpha) => dema(data, period, alpha).unwrap().values,
+        MaType::TEMA(alpha) => tema(data, period, alpha).unwrap().values,
+        MaType::TRIMA => trima(data, period).unwrap().values,
+        MaType::KAMA => kama(data, period).unwrap().values,
+        MaType::T3(volume_factor, alpha) => t3(data, period, volume_factor, alpha).unwrap().values,
+        MaType::HMA => hma(data, period).unwrap().values,
+        MaType::ZLEMA(alpha) => zlema(data, period, alpha).unwrap().values,
+        MaType::ALMA(offset, sigma) => alma(data, period, offset, sigma).unwrap().values,
+        MaType::SMMA => smma(data, period).unwrap().values,
+        MaType::VIDYA(cmo_period) => vidya(data, period, cmo_period).unwrap().values,
+    }
+}
+
+#[test]
+fn dispatch_matches_direct_call() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let period = 10;
+    for ma_type in MA_TYPES {
+        let result = ma(input, period, ma_type).unwrap();
+        assert_vec_close(&direct_ma(input, period, ma_type), &result.values);
+        assert_eq!(result.state.period(), period);
+    }
+}
+
+#[test]
+fn lookback_matches_nan_count() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let period = 10;
+    for ma_type in MA_TYPES {
+        let result = ma(input, period, ma_type).unwrap();
+        let lookback = lookback_from_period(period, ma_type);
+        assert!(
+            result.values[..lookback].iter().all(|v| v.is_nan()),
+            "{ma_type:?} values before the lookback must be NaN"
+        );
+        assert!(
+            result.values[lookback..].iter().all(|v| v.is_finite()),
+            "{ma_type:?} values after the lookback must be finite"
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let period = 10;
+    let next_count = 5;
+    let last_idx = input.len() - (1 + next_count);
+    for ma_type in MA_TYPES {
+        let expected = direct_ma(input, period, ma_type);
+        let mut state = ma(&input[..last_idx], period, ma_type).unwrap().state;
+        for i in 0..next_count {
+            state.update(input[last_idx + i]).unwrap();
+            assert!(
+                approx_eq_float(state.value(), expected[last_idx + i], 1e-8),
+                "{ma_type:?} next [{}] expected {}, but got {}",
+                i,
+                expected[last_idx + i],
+                state.value()
+            );
+        }
+    }
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut data = [1.0, 2.0, 3.0, 4.0].repeat(10);
+    data[38] = Float::NAN;
+    for ma_type in MA_TYPES {
+        assert!(
+            matches!(ma(&data, 4, ma_type), Err(TechalibError::DataNonFinite(_))),
+            "{ma_type:?} must fail on NaN"
+        );
+    }
+}
+
+#[test]
+fn output_length_mismatch_err() {
+    let data = vec![1.0, 2.0, 3.0];
+    let mut output = vec![0.0; 2];
+    assert!(matches!(
+        techalib::indicators::ma::ma_into(&data, 2, MaType::SMA, &mut output),
+        Err(TechalibError::BadParam(_))
+    ));
+}
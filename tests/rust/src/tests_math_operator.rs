@@ -0,0 +1,89 @@
+use crate::helper::{
+    assert::assert_vec_close,
+    generated::{assert_vec_eq_gen_data, load_generated_csv},
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::math_operator::{add, add_into, div, mult, sub},
+    types::Float,
+};
+
+#[test]
+fn element_wise_ok() {
+    let data0 = vec![1.0, 2.0, 3.0, 4.0];
+    let data1 = vec![2.0, 4.0, -1.0, 0.5];
+    assert_vec_close(&[3.0, 6.0, 2.0, 4.5], &add(&data0, &data1).unwrap());
+    assert_vec_close(&[-1.0, -2.0, 4.0, 3.5], &sub(&data0, &data1).unwrap());
+    assert_vec_close(&[2.0, 8.0, -3.0, 2.0], &mult(&data0, &data1).unwrap());
+    assert_vec_close(&[0.5, 0.5, -3.0, 8.0], &div(&data0, &data1).unwrap());
+}
+
+type Operator = fn(&[Float], &[Float]) -> Result<Vec<Float>, TechalibError>;
+
+#[test]
+#[ignore = "needs the TA-Lib fixtures from tools/generate_testdata.py"]
+fn generated_ok() {
+    let operators: [(&str, Operator); 4] = [
+        ("add.csv", add),
+        ("sub.csv", sub),
+        ("mult.csv", mult),
+        ("div.csv", div),
+    ];
+    for (file_name, operator) in operators {
+        let columns = load_generated_csv(file_name).unwrap();
+        let high = columns.get("high").unwrap();
+        let low = columns.get("low").unwrap();
+        let expected = columns.get("out").unwrap();
+        assert_vec_eq_gen_data(expected, &operator(high, low).unwrap());
+    }
+}
+
+#[test]
+fn nan_propagates_ok() {
+    let data0 = vec![1.0, Float::NAN, 3.0];
+    let data1 = vec![2.0, 4.0, Float::NAN];
+    let result = add(&data0, &data1).unwrap();
+    assert_eq!(result[0], 3.0);
+    assert!(result[1].is_nan());
+    assert!(result[2].is_nan());
+}
+
+#[test]
+fn div_by_zero_ok() {
+    let result = div(&[1.0, 0.0], &[0.0, 0.0]).unwrap();
+    assert_eq!(result[0], Float::INFINITY);
+    assert!(result[1].is_nan());
+}
+
+#[test]
+fn length_mismatch_err() {
+    let result = add(&[1.0, 2.0], &[1.0]);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+
+    let mut output = vec![0.0; 1];
+    let result = add_into(&[1.0, 2.0], &[1.0, 2.0], &mut output);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    let result = mult(&data, &data);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec((-1e6f64..1e6, -1e6f64..1e6), 1..200),
+    ) {
+        let (data0, data1): (Vec<Float>, Vec<Float>) = input.into_iter().unzip();
+        let added = add(&data0, &data1).unwrap();
+        let subbed = sub(&added, &data1).unwrap();
+        for (o, expect) in subbed.iter().zip(&data0) {
+            prop_assert!((o - expect).abs() <= 1e-6);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::helper::{
+    assert::assert_vec_close,
+    generated::{assert_vec_eq_gen_data, load_generated_csv},
+};
+
+use techalib::{
+    errors::TechalibError,
+    indicators::math_transform::{
+        acos, asin, atan, ceil, cos, cosh, exp, floor, ln, ln_into, log10, sin, sinh, sqrt,
+        sqrt_into, tan, tanh,
+    },
+    types::Float,
+};
+
+type Transform = fn(&[Float]) -> Result<Vec<Float>, TechalibError>;
+type Reference = fn(Float) -> Float;
+
+fn transforms() -> Vec<(&'static str, Transform, Reference)> {
+    vec![
+        ("acos.csv", acos, Float::acos),
+        ("asin.csv", asin, Float::asin),
+        ("atan.csv", atan, Float::atan),
+        ("ceil.csv", ceil, Float::ceil),
+        ("cos.csv", cos, Float::cos),
+        ("cosh.csv", cosh, Float::cosh),
+        ("exp.csv", exp, Float::exp),
+        ("floor.csv", floor, Float::floor),
+        ("ln.csv", ln, Float::ln),
+        ("log10.csv", log10, Float::log10),
+        ("sin.csv", sin, Float::sin),
+        ("sinh.csv", sinh, Float::sinh),
+        ("sqrt.csv", sqrt, Float::sqrt),
+        ("tan.csv", tan, Float::tan),
+        ("tanh.csv", tanh, Float::tanh),
+    ]
+}
+
+#[test]
+fn generated_element_wise_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input: Vec<Float> = columns
+        .get("close")
+        .unwrap()
+        .iter()
+        .map(|v| v / 200.0)
+        .collect();
+
+    for (_, transform, expected_fn) in transforms() {
+        let expected: Vec<Float> = input.iter().map(|v| expected_fn(*v)).collect();
+        let result = transform(&input).unwrap();
+        assert_vec_close(&expected, &result);
+    }
+}
+
+#[test]
+#[ignore = "needs the TA-Lib fixtures from tools/generate_testdata.py"]
+fn generated_ok() {
+    for (file_name, transform, _) in transforms() {
+        let columns = load_generated_csv(file_name).unwrap();
+        let input = columns.get("close").unwrap();
+        let expected = columns.get("out").unwrap();
+        assert_vec_eq_gen_data(expected, &transform(input).unwrap());
+    }
+}
+
+#[test]
+fn nan_propagates_ok() {
+    let data = vec![0.5, Float::NAN, 0.25];
+    for (_, transform, _) in transforms() {
+        let result = transform(&data).unwrap();
+        assert!(result[0].is_finite());
+        assert!(result[1].is_nan());
+        assert!(result[2].is_finite());
+    }
+}
+
+#[test]
+fn out_of_domain_is_nan() {
+    let data = vec![-1.0, 4.0];
+    let result = sqrt::<Float>(&data).unwrap();
+    assert!(result[0].is_nan());
+    assert_eq!(result[1], 2.0);
+
+    let result = acos::<Float>(&[2.0]).unwrap();
+    assert!(result[0].is_nan());
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    for (_, transform, _) in transforms() {
+        let result = transform(&data);
+        assert!(matches!(result, Err(TechalibError::InsufficientData)));
+    }
+}
+
+#[test]
+fn output_length_mismatch_err() {
+    let data = vec![1.0, 2.0, 3.0];
+    let mut output = vec![0.0; 2];
+    let result = ln_into(&data, &mut output);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn longer_output_ok() {
+    let data = vec![1.0, 4.0];
+    let mut output = vec![-1.0; 3];
+    sqrt_into(&data, &mut output).unwrap();
+    assert_eq!(output, vec![1.0, 2.0, -1.0]);
+}
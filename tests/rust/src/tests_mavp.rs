@@ -0,0 +1,306 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use crate::expect_err_overflow_or_ok_with;
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::{
+        alma::{DEFAULT_OFFSET, DEFAULT_SIGMA},
+        ma::ma,
+        mavp::{lookback_from_ma_type, mavp, MavpMA, MavpResult},
+        vidya::DEFAULT_CMO_PERIOD,
+    },
+    traits::State,
+    types::Float,
+};
+
+fn clamp(period: Float, min_period: usize, max_period: usize) -> usize {
+    (period as usize).clamp(min_period, max_period)
+}
+
+fn slow_mavp(
+    data: &[Float],
+    periods: &[Float],
+    min_period: usize,
+    max_period: usize,
+    ma_type: MavpMA,
+) -> Vec<Float> {
+    let lookback = lookback_from_ma_type(min_period, max_period, ma_type);
+    let mut out = vec![Float::NAN; data.len()];
+    match ma_type {
+        MavpMA::SMA => {
+            for i in lookback..data.len() {
+                let p = clamp(periods[i], min_period, max_period);
+                out[i] = data[i + 1 - p..=i].iter().sum::<Float>() / p as Float;
+            }
+        }
+        MavpMA::WMA => {
+            for i in lookback..data.len() {
+                let p = clamp(periods[i], min_period, max_period);
+                let weighted: Float = data[i + 1 - p..=i]
+                    .iter()
+                    .enumerate()
+                    .map(|(k, v)| v * (k + 1) as Float)
+                    .sum();
+                out[i] = weighted / (p * (p + 1) / 2) as Float;
+            }
+        }
+        MavpMA::EMA => {
+            let emas: Vec<Vec<Float>> = (min_period..=max_period)
+                .map(|p| {
+                    let alpha = 2.0 / (p as Float + 1.0);
+                    let mut ema = vec![Float::NAN; data.len()];
+                    ema[lookback] =
+                        data[lookback + 1 - p..=lookback].iter().sum::<Float>() / p as Float;
+                    for i in lookback + 1..data.len() {
+                        ema[i] = alpha * data[i] + (1.0 - alpha) * ema[i - 1];
+                    }
+                    ema
+                })
+                .collect();
+            for (i, (o, &period)) in out.iter_mut().zip(periods).enumerate().skip(lookback) {
+                *o = emas[clamp(period, min_period, max_period) - min_period][i];
+            }
+        }
+        _ => {
+            let mas: Vec<Vec<Float>> = (min_period..=max_period)
+                .map(|p| ma(data, p, ma_type.into()).unwrap().values)
+                .collect();
+            for (i, (o, &period)) in out.iter_mut().zip(periods).enumerate().skip(lookback) {
+                *o = mas[clamp(period, min_period, max_period) - min_period][i];
+            }
+        }
+    }
+    out
+}
+
+fn gen_periods(len: usize) -> Vec<Float> {
+    (0..len).map(|i| ((i * 7) % 40) as Float + 0.5).collect()
+}
+
+fn no_lookahead_mavp(file_name: &str, min_period: usize, max_period: usize, ma_type: MavpMA) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+    let periods = gen_periods(input.len());
+    let expected = slow_mavp(input, &periods, min_period, max_period, ma_type);
+    check_no_lookahead_mavp(input, &periods, &expected, min_period, max_period, ma_type);
+}
+
+fn check_no_lookahead_mavp(
+    input: &[Float],
+    periods: &[Float],
+    expected: &[Float],
+    min_period: usize,
+    max_period: usize,
+    ma_type: MavpMA,
+) {
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let output = mavp(
+        &input[0..last_idx],
+        &periods[0..last_idx],
+        min_period,
+        max_period,
+        ma_type,
+    );
+    assert!(
+        output.is_ok(),
+        "Failed to calculate MAVP: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state
+            .update((input[last_idx + i], periods[last_idx + i]))
+            .unwrap();
+        assert!(
+            approx_eq_float(new_state.mavp, expected[last_idx + i], 1e-8),
+            "Next [{}] expected {}, but got {}",
+            i,
+            expected[last_idx + i],
+            new_state.mavp
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_sma_ok() {
+    no_lookahead_mavp("sma.csv", 2, 30, MavpMA::SMA);
+}
+
+#[test]
+#[ignore = "needs the TA-Lib fixture from tools/generate_testdata.py"]
+fn generated_with_no_lookahead_ok() {
+    let columns = load_generated_csv("mavp.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let periods = columns.get("periods").unwrap();
+    let expected = columns.get("out").unwrap();
+    check_no_lookahead_mavp(input, periods, expected, 2, 30, MavpMA::SMA);
+}
+
+#[test]
+fn no_lookahead_ema_ok() {
+    no_lookahead_mavp("sma.csv", 5, 30, MavpMA::EMA);
+}
+
+#[test]
+fn no_lookahead_wma_ok() {
+    no_lookahead_mavp("sma.csv", 2, 30, MavpMA::WMA);
+}
+
+#[test]
+fn no_lookahead_hma_ok() {
+    no_lookahead_mavp("sma.csv", 4, 30, MavpMA::HMA);
+}
+
+#[test]
+fn no_lookahead_zlema_ok() {
+    no_lookahead_mavp("sma.csv", 2, 30, MavpMA::ZLEMA);
+}
+
+#[test]
+fn no_lookahead_alma_ok() {
+    no_lookahead_mavp(
+        "sma.csv",
+        2,
+        30,
+        MavpMA::ALMA(DEFAULT_OFFSET, DEFAULT_SIGMA),
+    );
+}
+
+#[test]
+fn no_lookahead_smma_ok() {
+    no_lookahead_mavp("sma.csv", 2, 30, MavpMA::SMMA);
+}
+
+#[test]
+fn no_lookahead_vidya_ok() {
+    no_lookahead_mavp("sma.csv", 2, 30, MavpMA::VIDYA(DEFAULT_CMO_PERIOD));
+}
+
+#[test]
+fn constant_period_matches_sma() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let expected = columns.get("out").unwrap();
+    let periods = vec![30.0; input.len()];
+    let result = mavp(input, &periods, 2, 30, MavpMA::SMA).unwrap();
+    assert_vec_close(expected, &result.values);
+}
+
+#[test]
+fn finite_extreme_err_overflow_or_ok_all_finite() {
+    let data = vec![
+        Float::MAX - 3.0,
+        Float::MAX - 2.0,
+        Float::MAX - 5.0,
+        Float::MAX - 6.0,
+        Float::MAX - 8.0,
+        Float::MAX - 1.0,
+    ];
+    let periods = vec![2.0, 3.0, 2.0, 3.0, 2.0, 3.0];
+    expect_err_overflow_or_ok_with!(
+        mavp(&data, &periods, 2, 3, MavpMA::WMA),
+        |result: MavpResult| {
+            assert!(
+                result.values.iter().skip(2).all(|v| v.is_finite()),
+                "Expected all values to be finite"
+            );
+        }
+    );
+}
+
+#[test]
+fn next_with_finite_neg_extreme_err_overflow_or_ok_all_finite() {
+    let data = vec![5.0, 10.0, 30.0, 3.0, 5.0, 6.0, 8.0];
+    let periods = vec![3.0; data.len()];
+    let result = mavp(&data, &periods, 2, 3, MavpMA::SMA).unwrap();
+    let mut state = result.state;
+    expect_err_overflow_or_ok_with!(state.update((Float::MIN + 5.0, 2.0)), |_| {
+        assert!(state.mavp.is_finite(), "Expected all values to be finite");
+    });
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let data = vec![1.0, 2.0, 3.0, Float::NAN, 1.0, 2.0, 3.0];
+    let periods = vec![3.0; data.len()];
+    let result = mavp(&data, &periods, 2, 3, MavpMA::SMA);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn unexpected_nan_period_err() {
+    let data = vec![1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0];
+    let mut periods = vec![3.0; data.len()];
+    periods[4] = Float::NAN;
+    let result = mavp(&data, &periods, 2, 3, MavpMA::EMA);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    let result = mavp(&data, &data, 2, 14, MavpMA::SMA);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+#[test]
+fn periods_length_mismatch_err() {
+    let data = vec![1.0, 2.0, 3.0, 4.0];
+    let result = mavp(&data, &[2.0, 2.0], 2, 3, MavpMA::SMA);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn bad_period_bounds_err() {
+    let data = vec![1.0, 2.0, 3.0, 4.0];
+    let periods = vec![2.0; data.len()];
+    assert!(matches!(
+        mavp(&data, &periods, 1, 3, MavpMA::SMA),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        mavp(&data, &periods, 3, 2, MavpMA::SMA),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 2..200),
+        raw_periods in vec(0.0f64..60.0, 200),
+        min_period in 2usize..10,
+        extra_period in 0usize..40,
+        ma_type in prop_oneof![
+            Just(MavpMA::SMA),
+            Just(MavpMA::EMA),
+            Just(MavpMA::WMA),
+            Just(MavpMA::ZLEMA),
+            Just(MavpMA::SMMA)
+        ]
+    ) {
+        let max_period = min_period + extra_period;
+        prop_assume!(lookback_from_ma_type(min_period, max_period, ma_type) < input.len());
+        let periods = &raw_periods[..input.len()];
+        let out = mavp(&input, periods, min_period, max_period, ma_type).unwrap().values;
+        let slow = slow_mavp(&input, periods, min_period, max_period, ma_type);
+        for (o, expect) in out.iter().zip(slow) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6);
+            }
+        }
+    }
+}
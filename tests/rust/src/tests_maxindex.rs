@@ -0,0 +1,98 @@
+use crate::helper::generated::load_generated_csv;
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError, indicators::maxindex::maxindex, traits::State, types::Float,
+};
+
+fn slow_maxindex(data: &[Float], period: usize) -> Vec<Float> {
+    let mut out = vec![Float::NAN; data.len()];
+    let mut max_idx = 0;
+    for i in period - 1..data.len() {
+        let trailing_idx = i + 1 - period;
+        if i == period - 1 || max_idx < trailing_idx {
+            max_idx = trailing_idx;
+            for j in trailing_idx..=i {
+                if data[j] > data[max_idx] {
+                    max_idx = j;
+                }
+            }
+        } else if data[i] >= data[max_idx] {
+            max_idx = i;
+        }
+        out[i] = max_idx as Float;
+    }
+    out
+}
+
+fn no_lookahead_maxindex(input: &[Float], expected: &[Float], period: usize) {
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let result = maxindex(&input[0..last_idx], period).unwrap();
+    assert!(result.values[..period - 1].iter().all(|v| v.is_nan()));
+    assert_eq!(
+        &result.values[period - 1..],
+        &expected[period - 1..last_idx]
+    );
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert_eq!(
+            new_state.maxindex as Float,
+            expected[last_idx + i],
+            "Next [{i}]"
+        );
+        assert_eq!(new_state.max, input[new_state.maxindex]);
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    no_lookahead_maxindex(input, &slow_maxindex(input, 30), 30);
+}
+
+#[test]
+#[ignore = "needs the TA-Lib fixture from tools/generate_testdata.py"]
+fn generated_with_no_lookahead_ok() {
+    let columns = load_generated_csv("maxindex.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    no_lookahead_maxindex(input, columns.get("out").unwrap(), 30);
+}
+
+#[test]
+fn ties_keep_the_first_index_on_rescan() {
+    let result = maxindex(&[1.0, 3.0, 3.0, 2.0, 1.0], 3).unwrap();
+    assert_eq!(result.values[2..], [1.0, 1.0, 2.0]);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let data = vec![1.0, 2.0, 3.0, Float::NAN, 1.0, 2.0, 3.0];
+    let result = maxindex(&data, 3);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    let result = maxindex(&data, 14);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 2..200),
+        period in 2usize..50
+    ) {
+        prop_assume!(period <= input.len());
+        let out = maxindex(&input, period).unwrap().values;
+        let expected = slow_maxindex(&input, period);
+        prop_assert_eq!(&out[period - 1..], &expected[period - 1..]);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError, indicators::stats::median::median, traits::State, types::Float,
+};
+
+fn slow_median(data: &[Float], period: usize) -> Vec<Float> {
+    let mut output = vec![Float::NAN; data.len()];
+    for i in period - 1..data.len() {
+        let mut window = data[i + 1 - period..=i].to_vec();
+        window.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        output[i] = if period % 2 == 0 {
+            (window[period / 2 - 1] + window[period / 2]) / 2.0
+        } else {
+            window[period / 2]
+        };
+    }
+    output
+}
+
+fn no_lookahead_median(file_name: &str, period: usize) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_median(input, period);
+
+    let input_prev = &input[0..last_idx];
+    let output = median(input_prev, period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate MEDIAN: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update(input[idx]).unwrap();
+        assert!(approx_eq_float(new_state.median, expected[idx], 1e-8));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_median("sma.csv", 21);
+}
+
+#[test]
+fn no_lookahead_min_period_ok() {
+    no_lookahead_median("sma.csv", 1);
+}
+
+#[test]
+fn repeated_values_ok() {
+    let input = [1.0, 1.0, 2.0, 2.0, 1.0, 3.0, 3.0, 1.0];
+    let result = median(&input, 4).unwrap();
+    assert_vec_close(
+        &[Float::NAN, Float::NAN, Float::NAN, 1.5, 1.5, 2.0, 2.5, 2.0],
+        &result.values,
+    );
+}
+
+#[test]
+fn state_update_repeated_values_ok() {
+    let input: Vec<Float> = (0..200).map(|i| ((i * 7) % 5) as Float).collect();
+    let period = 6;
+    let expected = slow_median(&input, period);
+
+    let mut state = median(&input[..period], period).unwrap().state;
+    for (idx, &sample) in input.iter().enumerate().skip(period) {
+        state.update(sample).unwrap();
+        assert_eq!(state.median, expected[idx], "[{idx}]");
+        assert!(state
+            .sorted_window
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1]));
+    }
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut data = [1.0, 2.0, 4.0, 8.0, 16.0].repeat(4);
+    data[12] = Float::NAN;
+    assert!(matches!(
+        median(&data, 5),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let data = [1.0, 2.0, 4.0, 8.0, 16.0];
+    assert!(matches!(
+        median(&data, 10),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn bad_period_err() {
+    let data = [1.0, 2.0, 4.0, 8.0, 16.0];
+    assert!(matches!(
+        median(&data, 1 - 1),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e3f64..1e3, 1..200),
+        period in 1usize..30,
+    ) {
+        prop_assume!(period < input.len());
+        let result = median(&input, period).unwrap();
+        let expected = slow_median(&input, period);
+        for (o, expect) in result.values.iter().zip(&expected) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_csv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{errors::TechalibError, indicators::min::min, traits::State, types::Float};
+
+fn slow_min(data: &[Float], period: usize) -> Vec<Float> {
+    let mut out = vec![Float::NAN; data.len()];
+    for i in period - 1..data.len() {
+        out[i] = data[i + 1 - period..=i]
+            .iter()
+            .cloned()
+            .fold(Float::MAX, Float::min);
+    }
+    out
+}
+
+fn no_lookahead_min(input: &[Float], expected: &[Float], period: usize) {
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let output = min(&input[0..last_idx], period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate MIN: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert_eq!(new_state.min, expected[last_idx + i], "Next [{i}]");
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    no_lookahead_min(input, &slow_min(input, 30), 30);
+}
+
+#[test]
+#[ignore = "needs the TA-Lib fixture from tools/generate_testdata.py"]
+fn generated_with_no_lookahead_ok() {
+    let columns = load_generated_csv("min.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    no_lookahead_min(input, columns.get("out").unwrap(), 30);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let data = vec![1.0, 2.0, 3.0, Float::NAN, 1.0, 2.0, 3.0];
+    let result = min(&data, 3);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    let result = min(&data, 14);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+#[test]
+fn period_1_err() {
+    let result = min(&[1.0, 2.0, 3.0], 1);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 2..200),
+        period in 2usize..50
+    ) {
+        prop_assume!(period <= input.len());
+        let out = min(&input[..period], period).unwrap();
+        let mut state = out.state;
+        let mut values = out.values;
+        for &value in &input[period..] {
+            state.update(value).unwrap();
+            values.push(state.min);
+        }
+        let expected = slow_min(&input, period);
+        prop_assert_eq!(&min(&input, period).unwrap().values[period - 1..], &expected[period - 1..]);
+        prop_assert_eq!(&values[period - 1..], &expected[period - 1..]);
+    }
+}
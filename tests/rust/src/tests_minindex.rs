@@ -0,0 +1,98 @@
+use crate::helper::generated::load_generated_csv;
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError, indicators::minindex::minindex, traits::State, types::Float,
+};
+
+fn slow_minindex(data: &[Float], period: usize) -> Vec<Float> {
+    let mut out = vec![Float::NAN; data.len()];
+    let mut min_idx = 0;
+    for i in period - 1..data.len() {
+        let trailing_idx = i + 1 - period;
+        if i == period - 1 || min_idx < trailing_idx {
+            min_idx = trailing_idx;
+            for j in trailing_idx..=i {
+                if data[j] < data[min_idx] {
+                    min_idx = j;
+                }
+            }
+        } else if data[i] <= data[min_idx] {
+            min_idx = i;
+        }
+        out[i] = min_idx as Float;
+    }
+    out
+}
+
+fn no_lookahead_minindex(input: &[Float], expected: &[Float], period: usize) {
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let result = minindex(&input[0..last_idx], period).unwrap();
+    assert!(result.values[..period - 1].iter().all(|v| v.is_nan()));
+    assert_eq!(
+        &result.values[period - 1..],
+        &expected[period - 1..last_idx]
+    );
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert_eq!(
+            new_state.minindex as Float,
+            expected[last_idx + i],
+            "Next [{i}]"
+        );
+        assert_eq!(new_state.min, input[new_state.minindex]);
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    no_lookahead_minindex(input, &slow_minindex(input, 30), 30);
+}
+
+#[test]
+#[ignore = "needs the TA-Lib fixture from tools/generate_testdata.py"]
+fn generated_with_no_lookahead_ok() {
+    let columns = load_generated_csv("minindex.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    no_lookahead_minindex(input, columns.get("out").unwrap(), 30);
+}
+
+#[test]
+fn ties_keep_the_first_index_on_rescan() {
+    let result = minindex(&[3.0, 1.0, 1.0, 2.0, 3.0], 3).unwrap();
+    assert_eq!(result.values[2..], [1.0, 1.0, 2.0]);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let data = vec![1.0, 2.0, 3.0, Float::NAN, 1.0, 2.0, 3.0];
+    let result = minindex(&data, 3);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    let result = minindex(&data, 14);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 2..200),
+        period in 2usize..50
+    ) {
+        prop_assume!(period <= input.len());
+        let out = minindex(&input, period).unwrap().values;
+        let expected = slow_minindex(&input, period);
+        prop_assert_eq!(&out[period - 1..], &expected[period - 1..]);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::helper::generated::load_generated_csv;
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::{max::max, min::min, minmax::minmax},
+    traits::State,
+    types::Float,
+};
+
+fn no_lookahead_minmax(
+    input: &[Float],
+    expected_min: &[Float],
+    expected_max: &[Float],
+    period: usize,
+) {
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let result = minmax(&input[0..last_idx], period).unwrap();
+    for idx in period - 1..last_idx {
+        assert_eq!(result.min[idx], expected_min[idx], "min[{idx}]");
+        assert_eq!(result.max[idx], expected_max[idx], "max[{idx}]");
+    }
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert_eq!(new_state.min, expected_min[last_idx + i], "Next [{i}]");
+        assert_eq!(new_state.max, expected_max[last_idx + i], "Next [{i}]");
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let expected_min = min(input, 30).unwrap().values;
+    let expected_max = max(input, 30).unwrap().values;
+    no_lookahead_minmax(input, &expected_min, &expected_max, 30);
+}
+
+#[test]
+#[ignore = "needs the TA-Lib fixture from tools/generate_testdata.py"]
+fn generated_with_no_lookahead_ok() {
+    let columns = load_generated_csv("minmax.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let expected_min = columns.get("min").unwrap();
+    let expected_max = columns.get("max").unwrap();
+    no_lookahead_minmax(input, expected_min, expected_max, 30);
+}
+
+#[test]
+fn lookback_is_nan() {
+    let result = minmax::<Float>(&[3.0, 1.0, 2.0, 5.0], 3).unwrap();
+    assert!(result.min[..2].iter().all(|v| v.is_nan()));
+    assert!(result.max[..2].iter().all(|v| v.is_nan()));
+    assert_eq!(result.min[2..], [1.0, 1.0]);
+    assert_eq!(result.max[2..], [3.0, 5.0]);
+}
+
+#[test]
+fn non_finite_err() {
+    let data = vec![1.0, 2.0, Float::INFINITY, 1.0, 2.0, 3.0];
+    let result = minmax(&data, 3);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    let result = minmax(&data, 14);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 2..200),
+        period in 2usize..50
+    ) {
+        prop_assume!(period <= input.len());
+        let out = minmax(&input, period).unwrap();
+        for idx in period - 1..input.len() {
+            prop_assert!(out.min[idx] <= out.max[idx]);
+            prop_assert!(input[idx + 1 - period..=idx].contains(&out.min[idx]));
+            prop_assert!(input[idx + 1 - period..=idx].contains(&out.max[idx]));
+        }
+    }
+}
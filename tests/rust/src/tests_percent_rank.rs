@@ -0,0 +1,118 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError, indicators::stats::percent_rank::percent_rank, traits::State,
+    types::Float,
+};
+
+fn slow_percent_rank(data: &[Float], period: usize) -> Vec<Float> {
+    let mut output = vec![Float::NAN; data.len()];
+    for i in period..data.len() {
+        let count = data[i - period..i]
+            .iter()
+            .filter(|&&v| v <= data[i])
+            .count();
+        output[i] = 100.0 * count as Float / period as Float;
+    }
+    output
+}
+
+fn no_lookahead_percent_rank(file_name: &str, period: usize) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_percent_rank(input, period);
+
+    let input_prev = &input[0..last_idx];
+    let output = percent_rank(input_prev, period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate PERCENT_RANK: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update(input[idx]).unwrap();
+        assert!(approx_eq_float(new_state.percent_rank, expected[idx], 1e-8));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_percent_rank("sma.csv", 20);
+}
+
+#[test]
+fn no_lookahead_min_period_ok() {
+    no_lookahead_percent_rank("sma.csv", 1);
+}
+
+#[test]
+fn repeated_values_ok() {
+    let input = [1.0, 2.0, 2.0, 3.0, 2.0, 1.0];
+    let result = percent_rank(&input, 3).unwrap();
+    assert_vec_close(
+        &[Float::NAN, Float::NAN, Float::NAN, 100.0, 200.0 / 3.0, 0.0],
+        &result.values,
+    );
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut data = [1.0, 2.0, 4.0, 8.0, 16.0].repeat(4);
+    data[12] = Float::NAN;
+    assert!(matches!(
+        percent_rank(&data, 5),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let data = [1.0, 2.0, 4.0, 8.0, 16.0];
+    assert!(matches!(
+        percent_rank(&data, 10),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn bad_period_err() {
+    let data = [1.0, 2.0, 4.0, 8.0, 16.0];
+    assert!(matches!(
+        percent_rank(&data, 1 - 1),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e3f64..1e3, 1..200),
+        period in 1usize..30,
+    ) {
+        prop_assume!(period < input.len());
+        let result = percent_rank(&input, period).unwrap();
+        let expected = slow_percent_rank(&input, period);
+        for (o, expect) in result.values.iter().zip(&expected) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_ohlcv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::pivots::{pivots, PivotMethod},
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+const METHODS: [PivotMethod; 5] = [
+    PivotMethod::Classic,
+    PivotMethod::Fibonacci,
+    PivotMethod::Camarilla,
+    PivotMethod::Woodie,
+    PivotMethod::Demark,
+];
+
+/// Levels `[P, R1, R2, R3, S1, S2, S3]` of every bar
+fn slow_pivots(ohlcv: &Ohlcv, new_session: &[bool], method: PivotMethod) -> Vec<[Float; 7]> {
+    let len = ohlcv.close.len();
+    let mut starts: Vec<usize> = (0..len).filter(|&i| i == 0 || new_session[i]).collect();
+    starts.push(len);
+
+    let mut levels = vec![[Float::NAN; 7]; len];
+    for k in 1..starts.len() - 1 {
+        let (prev, current) = (starts[k - 1]..starts[k], starts[k]..starts[k + 1]);
+        let o = ohlcv.open[prev.start];
+        let h = ohlcv.high[prev.clone()]
+            .iter()
+            .cloned()
+            .fold(Float::MIN, Float::max);
+        let l = ohlcv.low[prev.clone()]
+            .iter()
+            .cloned()
+            .fold(Float::MAX, Float::min);
+        let c = ohlcv.close[prev.end - 1];
+        let r = h - l;
+        let session_levels = match method {
+            PivotMethod::Classic | PivotMethod::Woodie => {
+                let p = if method == PivotMethod::Classic {
+                    (h + l + c) / 3.0
+                } else {
+                    (h + l + 2.0 * ohlcv.open[current.start]) / 4.0
+                };
+                [
+                    p,
+                    2.0 * p - l,
+                    p + r,
+                    h + 2.0 * (p - l),
+                    2.0 * p - h,
+                    p - r,
+                    l - 2.0 * (h - p),
+                ]
+            }
+            PivotMethod::Fibonacci => {
+                let p = (h + l + c) / 3.0;
+                [
+                    p,
+                    p + 0.382 * r,
+                    p + 0.618 * r,
+                    p + r,
+                    p - 0.382 * r,
+                    p - 0.618 * r,
+                    p - r,
+                ]
+            }
+            PivotMethod::Camarilla => [
+                (h + l + c) / 3.0,
+                c + r * 1.1 / 12.0,
+                c + r * 1.1 / 6.0,
+                c + r * 1.1 / 4.0,
+                c - r * 1.1 / 12.0,
+                c - r * 1.1 / 6.0,
+                c - r * 1.1 / 4.0,
+            ],
+            PivotMethod::Demark => {
+                let x = if c < o {
+                    h + 2.0 * l + c
+                } else if c > o {
+                    2.0 * h + l + c
+                } else {
+                    h + l + 2.0 * c
+                };
+                [
+                    x / 4.0,
+                    x / 2.0 - l,
+                    Float::NAN,
+                    Float::NAN,
+                    x / 2.0 - h,
+                    Float::NAN,
+                    Float::NAN,
+                ]
+            }
+        };
+        for bar in levels[current].iter_mut() {
+            *bar = session_levels;
+        }
+    }
+    levels
+}
+
+fn column(levels: &[[Float; 7]], idx: usize) -> Vec<Float> {
+    levels.iter().map(|l| l[idx]).collect()
+}
+
+fn no_lookahead_pivots(file_name: &str, session_length: usize, method: PivotMethod) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let ohlcv = bars.view();
+
+    let len = ohlcv.close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+    let new_session: Vec<bool> = (0..len).map(|i| i % session_length == 0).collect();
+
+    let expected = slow_pivots(&ohlcv, &new_session, method);
+
+    let input = Ohlcv {
+        open: &ohlcv.open[0..last_idx],
+        high: &ohlcv.high[0..last_idx],
+        low: &ohlcv.low[0..last_idx],
+        close: &ohlcv.close[0..last_idx],
+        ..Default::default()
+    };
+    let output = pivots(&input, &new_session[0..last_idx], method);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate PIVOTS: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    for (level, values) in [
+        &result.pivot,
+        &result.r1,
+        &result.r2,
+        &result.r3,
+        &result.s1,
+        &result.s2,
+        &result.s3,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        assert_vec_close(&column(&expected, level)[0..last_idx], values);
+    }
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        if new_session[idx] {
+            new_state.rollover();
+        }
+        new_state
+            .update((
+                ohlcv.open[idx],
+                ohlcv.high[idx],
+                ohlcv.low[idx],
+                ohlcv.close[idx],
+            ))
+            .unwrap();
+        let got = [
+            new_state.pivot,
+            new_state.r1,
+            new_state.r2,
+            new_state.r3,
+            new_state.s1,
+            new_state.s2,
+            new_state.s3,
+        ];
+        assert_vec_close(&expected[idx], &got);
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    for method in METHODS {
+        no_lookahead_pivots("sma.csv", 48, method);
+    }
+}
+
+#[test]
+fn no_lookahead_rollover_in_next_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    // The session boundary falls in the incremental part of the test
+    let session_length = bars.close.len() - 3;
+    for method in METHODS {
+        no_lookahead_pivots("sma.csv", session_length, method);
+    }
+}
+
+#[test]
+fn first_session_is_nan() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let new_session: Vec<bool> = (0..bars.close.len()).map(|i| i % 24 == 0).collect();
+    let result = pivots(&bars.view(), &new_session, PivotMethod::Classic).unwrap();
+    assert!(result.pivot[..24].iter().all(|v| v.is_nan()));
+    assert!(result.pivot[24..].iter().all(|v| v.is_finite()));
+    assert!(result.pivot[24..48].iter().all(|v| *v == result.pivot[24]));
+}
+
+#[test]
+fn repeated_rollover_keeps_previous_session() {
+    let ohlcv = Ohlcv {
+        open: &[1.0, 2.0],
+        high: &[3.0, 4.0],
+        low: &[0.5, 1.5],
+        close: &[2.0, 3.0],
+        ..Default::default()
+    };
+    let mut state = pivots(&ohlcv, &[true, false], PivotMethod::Classic)
+        .unwrap()
+        .state;
+    state.rollover();
+    state.rollover();
+    assert_eq!(state.prev_high, 4.0);
+    assert_eq!(state.prev_low, 0.5);
+    assert_eq!(state.prev_close, 3.0);
+    state.update((3.0, 3.5, 2.5, 3.0)).unwrap();
+    assert_eq!(state.pivot, 2.5);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        open: &[1.5, 3.0, 2.0],
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, Float::NAN, 1.0],
+        close: &[1.8, 3.5, 2.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        pivots(&ohlcv, &[true, false, true], PivotMethod::Classic),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn empty_input_err() {
+    assert!(matches!(
+        pivots(&Ohlcv::<Float>::default(), &[], PivotMethod::Classic),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn session_mask_length_err() {
+    let ohlcv = Ohlcv {
+        open: &[1.5, 3.0, 2.0],
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        close: &[1.8, 3.5, 2.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        pivots(&ohlcv, &[true, false], PivotMethod::Woodie),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3, -1.0f64..1.0, -1.0f64..1.0, proptest::bool::weighted(0.1)), 1..200),
+        method_idx in 0usize..5,
+    ) {
+        let method = METHODS[method_idx];
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let open: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.2).collect();
+        let close: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.3).collect();
+        let new_session: Vec<bool> = bars.iter().map(|b| b.4).collect();
+        let ohlcv = Ohlcv { open: &open, high: &high, low: &low, close: &close, ..Default::default() };
+        let result = pivots(&ohlcv, &new_session, method).unwrap();
+        let expected = slow_pivots(&ohlcv, &new_session, method);
+        for (level, values) in [&result.pivot, &result.r1, &result.r2, &result.r3, &result.s1, &result.s2, &result.s3]
+            .into_iter()
+            .enumerate() {
+            for (o, e) in values.iter().zip(column(&expected, level)) {
+                if e.is_nan() {
+                    prop_assert!(o.is_nan());
+                } else {
+                    prop_assert!((o - e).abs() <= 1e-6 * e.abs().max(1.0));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use techalib::{indicators::sma::sma, types::TechalibFloat};
+#[cfg(feature = "high-precision")]
+use techalib::{
+    indicators::{
+        bbands::{bbands, BBandsMA, DeviationMulipliers},
+        kama::kama,
+        trima::trima,
+        wma::wma,
+    },
+    traits::State,
+};
+
+const LEN: usize = 10_000_003;
+const PERIOD: usize = 20;
+#[cfg(feature = "high-precision")]
+const STEP: usize = 99_991;
+
+/// A noisy series around `offset`, offsets far from zero
+/// maximise the cancellation in the rolling sums
+fn signal<T: TechalibFloat>(len: usize, offset: f64) -> Vec<T> {
+    let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+    (0..len)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let noise = (seed >> 11) as f64 / (1u64 << 53) as f64 * 20.0 - 10.0;
+            T::from_f64(offset + noise)
+        })
+        .collect()
+}
+
+fn max_abs<T: TechalibFloat>(data: &[T]) -> f64 {
+    data.iter()
+        .fold(0.0, |acc: f64, v| acc.max(v.to_f64().abs()))
+}
+
+/// Error bound of a window of `PERIOD` values, independent of the series length
+fn bound<T: TechalibFloat>(scale: f64) -> f64 {
+    4.0 * PERIOD as f64 * T::EPSILON.to_f64() * scale
+}
+
+/// Weighted mean of the `period` values ending at `idx`, computed from scratch in `f64`
+fn exact_window<T: TechalibFloat>(
+    data: &[T],
+    idx: usize,
+    period: usize,
+    weight: impl Fn(usize) -> f64,
+) -> f64 {
+    let window = &data[idx + 1 - period..=idx];
+    let (sum, weight_sum) = window
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(sum, weight_sum), (i, v)| {
+            (sum + weight(i) * v.to_f64(), weight_sum + weight(i))
+        });
+    sum / weight_sum
+}
+
+#[cfg(feature = "high-precision")]
+fn checked_indices(len: usize, period: usize) -> impl Iterator<Item = usize> {
+    (period - 1..len)
+        .step_by(STEP)
+        .chain(std::iter::once(len - 1))
+}
+
+#[cfg(feature = "high-precision")]
+fn assert_close(name: &str, idx: usize, expected: f64, got: f64, bound: f64) {
+    let error = (expected - got).abs();
+    assert!(
+        error <= bound,
+        "{name}[{idx}]: expected {expected}, got {got}, error {error:e} > bound {bound:e}"
+    );
+}
+
+#[cfg(feature = "high-precision")]
+fn sma_error_bounded<T: TechalibFloat>() {
+    let data = signal::<T>(LEN, 1e4);
+    let bound = bound::<T>(max_abs(&data));
+    let result = sma(&data, PERIOD).unwrap();
+    for idx in checked_indices(LEN, PERIOD) {
+        let expected = exact_window(&data, idx, PERIOD, |_| 1.0);
+        assert_close("sma", idx, expected, result.values[idx].to_f64(), bound);
+    }
+
+    let mut state = result.state;
+    let mut data = data;
+    data.extend(signal::<T>(2 * PERIOD + 1, 1e4));
+    for idx in LEN..data.len() {
+        state.update(data[idx]).unwrap();
+        let expected = exact_window(&data, idx, PERIOD, |_| 1.0);
+        assert_close("sma state", idx, expected, state.sma.to_f64(), bound);
+    }
+}
+
+#[cfg(feature = "high-precision")]
+fn wma_error_bounded<T: TechalibFloat>() {
+    let data = signal::<T>(LEN, 1e4);
+    let bound = bound::<T>(max_abs(&data));
+    let weight = |i: usize| (i + 1) as f64;
+    let result = wma(&data, PERIOD).unwrap();
+    for idx in checked_indices(LEN, PERIOD) {
+        let expected = exact_window(&data, idx, PERIOD, weight);
+        assert_close("wma", idx, expected, result.values[idx].to_f64(), bound);
+    }
+
+    let mut state = result.state;
+    let mut data = data;
+    data.extend(signal::<T>(2 * PERIOD + 1, 1e4));
+    for idx in LEN..data.len() {
+        state.update(data[idx]).unwrap();
+        let expected = exact_window(&data, idx, PERIOD, weight);
+        assert_close("wma state", idx, expected, state.wma.to_f64(), bound);
+    }
+}
+
+#[cfg(feature = "high-precision")]
+fn trima_error_bounded<T: TechalibFloat>(period: usize) {
+    let data = signal::<T>(LEN, 1e4);
+    let bound = bound::<T>(max_abs(&data));
+    let weight = |i: usize| (i + 1).min(period - i) as f64;
+    let result = trima(&data, period).unwrap();
+    for idx in checked_indices(LEN, period) {
+        let expected = exact_window(&data, idx, period, weight);
+        assert_close("trima", idx, expected, result.values[idx].to_f64(), bound);
+    }
+
+    let mut state = result.state;
+    let mut data = data;
+    data.extend(signal::<T>(2 * period + 1, 1e4));
+    for idx in LEN..data.len() {
+        state.update(data[idx]).unwrap();
+        let expected = exact_window(&data, idx, period, weight);
+        assert_close("trima state", idx, expected, state.trima.to_f64(), bound);
+    }
+}
+
+#[cfg(feature = "high-precision")]
+fn bbands_error_bounded<T: TechalibFloat>() {
+    let data = signal::<T>(LEN, 1e4);
+    let scale = max_abs(&data);
+    let mult = T::from_f64(2.0);
+    let multipliers = DeviationMulipliers {
+        up: mult,
+        down: mult,
+    };
+    let result = bbands(&data, PERIOD, multipliers, BBandsMA::SMA).unwrap();
+    for idx in checked_indices(LEN, PERIOD) {
+        let mean = exact_window(&data, idx, PERIOD, |_| 1.0);
+        let mean_sq = data[idx + 1 - PERIOD..=idx]
+            .iter()
+            .map(|v| v.to_f64() * v.to_f64())
+            .sum::<f64>()
+            / PERIOD as f64;
+        let middle = result.middle[idx].to_f64();
+        let std = (result.upper[idx] - result.middle[idx]).to_f64() / 2.0;
+        assert_close("bbands middle", idx, mean, middle, bound::<T>(scale));
+        assert_close(
+            "bbands variance",
+            idx,
+            mean_sq - mean * mean,
+            std * std,
+            bound::<T>(scale * scale),
+        );
+    }
+}
+
+#[cfg(feature = "high-precision")]
+fn kama_error_bounded<T: TechalibFloat>() {
+    // Around 1e4 the differences are exact multiples of the same ulp,
+    // so their sums do not drift: center the series on zero instead
+    let data = signal::<T>(LEN, 0.0);
+    let result = kama(&data, PERIOD).unwrap();
+    let expected = data[LEN - PERIOD - 1..]
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs().to_f64())
+        .sum::<f64>();
+    let scale = PERIOD as f64 * 20.0;
+    assert_close(
+        "kama roc_sum",
+        LEN - 1,
+        expected,
+        result.state.roc_sum.to_f64(),
+        bound::<T>(scale),
+    );
+}
+
+/// Streams a whole series through a state, which re-sums its window
+/// once every `PERIOD` updates
+#[cfg(feature = "high-precision")]
+#[test]
+fn sma_state_error_bounded_f32_ok() {
+    let data = signal::<f32>(LEN, 1e4);
+    let bound = bound::<f32>(max_abs(&data));
+    let mut state = sma(&data[..PERIOD], PERIOD).unwrap().state;
+    for idx in PERIOD..LEN {
+        state.update(data[idx]).unwrap();
+        if idx % STEP == 0 || idx == LEN - 1 {
+            let expected = exact_window(&data, idx, PERIOD, |_| 1.0);
+            assert_close("sma state", idx, expected, state.sma.to_f64(), bound);
+        }
+    }
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn sma_error_bounded_f64_ok() {
+    sma_error_bounded::<f64>();
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn sma_error_bounded_f32_ok() {
+    sma_error_bounded::<f32>();
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn wma_error_bounded_f64_ok() {
+    wma_error_bounded::<f64>();
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn wma_error_bounded_f32_ok() {
+    wma_error_bounded::<f32>();
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn trima_error_bounded_f64_ok() {
+    trima_error_bounded::<f64>(PERIOD);
+    trima_error_bounded::<f64>(PERIOD + 1);
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn trima_error_bounded_f32_ok() {
+    trima_error_bounded::<f32>(PERIOD);
+    trima_error_bounded::<f32>(PERIOD + 1);
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn bbands_error_bounded_f64_ok() {
+    bbands_error_bounded::<f64>();
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn bbands_error_bounded_f32_ok() {
+    bbands_error_bounded::<f32>();
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn kama_error_bounded_f64_ok() {
+    kama_error_bounded::<f64>();
+}
+
+#[cfg(feature = "high-precision")]
+#[test]
+fn kama_error_bounded_f32_ok() {
+    kama_error_bounded::<f32>();
+}
+
+/// Error of the last SMA value of a `len` samples series
+fn sma_last_error<T: TechalibFloat>(len: usize) -> (f64, f64) {
+    let data = signal::<T>(len, 1e4);
+    let result = sma(&data, PERIOD).unwrap();
+    let expected = exact_window(&data, len - 1, PERIOD, |_| 1.0);
+    let error = (expected - result.values[len - 1].to_f64()).abs();
+    (error, bound::<T>(max_abs(&data)))
+}
+
+#[test]
+#[cfg_attr(
+    not(feature = "high-precision"),
+    ignore = "runs 10M samples, enabled with the high-precision feature"
+)]
+fn sma_drift_f32() {
+    let (short, _) = sma_last_error::<f32>(100 * PERIOD);
+    let (long, bound) = sma_last_error::<f32>(LEN);
+    if cfg!(feature = "high-precision") {
+        assert!(
+            short <= bound,
+            "short series error {short:e} > bound {bound:e}"
+        );
+        assert!(
+            long <= bound,
+            "long series error {long:e} > bound {bound:e}"
+        );
+    } else {
+        assert!(
+            long > bound && long > 10.0 * short,
+            "expected the error to grow with the series: {short:e} -> {long:e}, bound {bound:e}"
+        );
+    }
+}
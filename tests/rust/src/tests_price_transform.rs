@@ -0,0 +1,138 @@
+use crate::helper::{
+    assert::assert_vec_close,
+    generated::{assert_vec_eq_gen_data, load_generated_csv, load_generated_ohlcv},
+};
+
+use techalib::{
+    errors::TechalibError,
+    indicators::price_transform::{avgprice, medprice, typprice, wclprice, wclprice_into},
+    types::{Float, Ohlcv},
+};
+
+#[test]
+fn generated_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let ohlcv = bars.view();
+    let (open, high, low, close) = (&bars.open, &bars.high, &bars.low, &bars.close);
+    let len = close.len();
+
+    let expected: Vec<Float> = (0..len)
+        .map(|i| (open[i] + high[i] + low[i] + close[i]) / 4.0)
+        .collect();
+    assert_vec_close(&expected, &avgprice(&ohlcv).unwrap());
+
+    let expected: Vec<Float> = (0..len).map(|i| (high[i] + low[i]) / 2.0).collect();
+    assert_vec_close(&expected, &medprice(&ohlcv).unwrap());
+
+    let expected: Vec<Float> = (0..len)
+        .map(|i| (high[i] + low[i] + close[i]) / 3.0)
+        .collect();
+    assert_vec_close(&expected, &typprice(&ohlcv).unwrap());
+
+    let expected: Vec<Float> = (0..len)
+        .map(|i| (high[i] + low[i] + 2.0 * close[i]) / 4.0)
+        .collect();
+    assert_vec_close(&expected, &wclprice(&ohlcv).unwrap());
+}
+
+type PriceTransform = fn(&Ohlcv<Float>) -> Result<Vec<Float>, TechalibError>;
+
+#[test]
+#[ignore = "needs the TA-Lib fixtures from tools/generate_testdata.py"]
+fn generated_talib_ok() {
+    let transforms: [(&str, PriceTransform); 4] = [
+        ("avgprice.csv", avgprice),
+        ("medprice.csv", medprice),
+        ("typprice.csv", typprice),
+        ("wclprice.csv", wclprice),
+    ];
+    for (file_name, transform) in transforms {
+        let columns = load_generated_csv(file_name).unwrap();
+        let column = |name: &str| columns.get(name).map_or(&[][..], |values| &values[..]);
+        let ohlcv = Ohlcv {
+            open: column("open"),
+            high: column("high"),
+            low: column("low"),
+            close: column("close"),
+            ..Default::default()
+        };
+        assert_vec_eq_gen_data(columns.get("out").unwrap(), &transform(&ohlcv).unwrap());
+    }
+}
+
+#[test]
+fn unused_columns_can_be_empty() {
+    let high = [2.0, 4.0];
+    let low = [1.0, 2.0];
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        ..Default::default()
+    };
+    assert_eq!(medprice(&ohlcv).unwrap(), vec![1.5, 3.0]);
+    assert!(matches!(typprice(&ohlcv), Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn nan_propagates_ok() {
+    let high = [2.0, Float::NAN, 4.0];
+    let low = [1.0, 1.0, 2.0];
+    let close = [1.5, 1.5, 3.0];
+    let ohlcv = Ohlcv {
+        high: &high,
+        low: &low,
+        close: &close,
+        ..Default::default()
+    };
+    let result = typprice(&ohlcv).unwrap();
+    assert_eq!(result[0], 1.5);
+    assert!(result[1].is_nan());
+    assert_eq!(result[2], 3.0);
+}
+
+#[test]
+fn columns_length_mismatch_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 3.0],
+        low: &[1.0],
+        close: &[1.5, 2.0],
+        ..Default::default()
+    };
+    assert!(matches!(wclprice(&ohlcv), Err(TechalibError::BadParam(_))));
+
+    let ohlcv = Ohlcv {
+        high: &[2.0, 3.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 2.0],
+        ..Default::default()
+    };
+    let mut output = vec![0.0; 1];
+    assert!(matches!(
+        wclprice_into(&ohlcv, &mut output),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn longer_output_ok() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 3.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 2.0],
+        ..Default::default()
+    };
+    let mut output = vec![-1.0; 3];
+    wclprice_into(&ohlcv, &mut output).unwrap();
+    assert_eq!(output[0], 1.5);
+    assert_eq!(output[1], 2.25);
+    assert_eq!(output[2], -1.0);
+}
+
+#[test]
+fn empty_input_err() {
+    let ohlcv = Ohlcv::<Float>::default();
+    assert!(matches!(
+        avgprice(&ohlcv),
+        Err(TechalibError::InsufficientData)
+    ));
+}
@@ -0,0 +1,31 @@
+use techalib::{ring_buffer::RingBuffer, types::Float};
+
+#[test]
+fn push_rolls_window_ok() {
+    let mut ring = RingBuffer::from(vec![1.0, 2.0, 3.0]);
+    assert_eq!(ring.push(4.0), Some(1.0));
+    assert_eq!(ring.push(5.0), Some(2.0));
+    assert_eq!(ring.front(), Some(3.0));
+    assert_eq!(ring.back(), Some(5.0));
+    assert_eq!(ring.get(1), Some(4.0));
+    assert_eq!(ring.get(3), None);
+    assert_eq!(
+        ring.iter().copied().collect::<Vec<_>>(),
+        vec![3.0, 4.0, 5.0]
+    );
+    assert_eq!(ring.as_slices(), (&[3.0][..], &[4.0, 5.0][..]));
+    assert_eq!(ring, RingBuffer::from(vec![3.0, 4.0, 5.0]));
+    assert_eq!(Vec::from(ring.clone()), vec![3.0, 4.0, 5.0]);
+    assert_eq!(ring.make_contiguous(), &[3.0, 4.0, 5.0]);
+    assert_eq!(ring.push(6.0), Some(3.0));
+    assert_eq!(ring, [4.0, 5.0, 6.0].into_iter().collect());
+}
+
+#[test]
+fn empty_ring_ok() {
+    let mut ring = RingBuffer::<Float>::from(Vec::new());
+    assert!(ring.is_empty());
+    assert_eq!(ring.push(1.0), None);
+    assert_eq!(ring.front(), None);
+    assert_eq!(ring.back(), None);
+}
@@ -106,7 +106,7 @@ fn alternating_up_down() {
 
 #[test]
 fn all_values_approximatelly_same() {
-    let data = &[
+    let data: &[Float] = &[
         10.1, 9.9, 10.0, 10.2, 10.1, 10.12, 10.11, 10.12, 10.11, 10.10, 10.09, 10.10,
     ];
     let period = 3;
@@ -226,7 +226,7 @@ proptest! {
         period in 1..50
 ) {
         let period = period as usize;
-        let result = rsi(&data, period);
+        let result = rsi::<Float>(&data, period);
 
         if data.len() <= period || period <= 1 {
             prop_assert!(result.is_err());
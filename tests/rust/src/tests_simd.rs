@@ -0,0 +1,136 @@
+use techalib::{
+    errors::TechalibError,
+    indicators::{
+        bbands::{bbands, bbands_sweep, BBandsMA, DeviationMulipliers},
+        sma::{sma, sma_sweep},
+        wma::wma,
+    },
+    types::TechalibFloat,
+};
+
+// The lengths cross the 4 and 8 lanes of the AVX kernels and their remainders.
+const MAX_LEN: usize = 40;
+
+fn values<T: TechalibFloat>(len: usize) -> Vec<T> {
+    (0..len)
+        .map(|i| T::from_f64(((i * 7919) % 101) as f64 * 0.37 - 12.5))
+        .collect()
+}
+
+fn mean_and_std(window: &[f64]) -> (f64, f64) {
+    let mean = window.iter().sum::<f64>() / window.len() as f64;
+    let variance =
+        window.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / window.len() as f64;
+    (mean, variance.sqrt())
+}
+
+fn assert_close<T: TechalibFloat>(expected: f64, got: T, tolerance: f64) {
+    let got = got.to_f64();
+    assert!(
+        (expected - got).abs() <= tolerance * expected.abs().max(1.0),
+        "Expected: {expected}, got: {got}"
+    );
+}
+
+fn initial_window_ok<T: TechalibFloat>(tolerance: f64) {
+    for len in 2..MAX_LEN {
+        let data = values::<T>(len);
+        let window: Vec<f64> = data.iter().map(|v| v.to_f64()).collect();
+        let (mean, std) = mean_and_std(&window);
+        let weighted = window
+            .iter()
+            .enumerate()
+            .map(|(i, v)| v * (i + 1) as f64)
+            .sum::<f64>()
+            / (len * (len + 1) / 2) as f64;
+
+        assert_close(mean, sma(&data, len).unwrap().values[len - 1], tolerance);
+        assert_close(
+            weighted,
+            wma(&data, len).unwrap().values[len - 1],
+            tolerance,
+        );
+        let multipliers = DeviationMulipliers {
+            up: T::from_f64(2.0),
+            down: T::from_f64(1.5),
+        };
+        let result = bbands(&data, len, multipliers, BBandsMA::SMA).unwrap();
+        assert_close(mean, result.middle[len - 1], tolerance);
+        assert_close(mean + 2.0 * std, result.upper[len - 1], tolerance);
+        assert_close(mean - 1.5 * std, result.lower[len - 1], tolerance);
+    }
+}
+
+#[test]
+fn initial_window_f64_ok() {
+    initial_window_ok::<f64>(1e-9);
+}
+
+#[test]
+fn initial_window_f32_ok() {
+    initial_window_ok::<f32>(1e-4);
+}
+
+fn sweep_windows_ok<T: TechalibFloat>(tolerance: f64) {
+    let data = values::<T>(MAX_LEN);
+    let window: Vec<f64> = data.iter().map(|v| v.to_f64()).collect();
+    let periods: Vec<usize> = (2..10).collect();
+    let multipliers = DeviationMulipliers {
+        up: T::from_f64(2.0),
+        down: T::from_f64(1.5),
+    };
+    let sma_result = sma_sweep(&data, &periods).unwrap();
+    let bbands_result = bbands_sweep(&data, &periods, multipliers, BBandsMA::SMA).unwrap();
+    for (column, &period) in periods.iter().enumerate() {
+        for idx in period - 1..MAX_LEN {
+            let (mean, std) = mean_and_std(&window[idx + 1 - period..=idx]);
+            let idx = column * MAX_LEN + idx;
+            assert_close(mean, sma_result.values[idx], tolerance);
+            assert_close(mean, bbands_result.middle[idx], tolerance);
+            assert_close(mean + 2.0 * std, bbands_result.upper[idx], tolerance);
+            assert_close(mean - 1.5 * std, bbands_result.lower[idx], tolerance);
+        }
+    }
+}
+
+#[test]
+fn sweep_windows_f64_ok() {
+    sweep_windows_ok::<f64>(1e-9);
+}
+
+#[test]
+fn sweep_windows_f32_ok() {
+    sweep_windows_ok::<f32>(1e-4);
+}
+
+fn non_finite_index_ok<T: TechalibFloat>() {
+    for len in 2..MAX_LEN {
+        for bad in [T::NAN, T::INFINITY, T::NEG_INFINITY] {
+            let bad_idx = len / 2;
+            let mut data = values::<T>(len);
+            data[bad_idx] = bad;
+            let position = format!("[{bad_idx}]");
+
+            let result = sma(&data, len);
+            assert!(
+                matches!(&result, Err(TechalibError::DataNonFinite(msg)) if msg.contains(&position)),
+                "{result:?}"
+            );
+            let result = sma_sweep(&data, &[len]);
+            assert!(
+                matches!(&result, Err(TechalibError::DataNonFinite(msg)) if msg.contains(&position)),
+                "{result:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn non_finite_index_f64_ok() {
+    non_finite_index_ok::<f64>();
+}
+
+#[test]
+fn non_finite_index_f32_ok() {
+    non_finite_index_ok::<f32>();
+}
@@ -0,0 +1,110 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::{ema::ema, smma::smma},
+    traits::State,
+    types::Float,
+};
+
+fn slow_smma(data: &[Float], period: usize) -> Vec<Float> {
+    let mut out = vec![Float::NAN; data.len()];
+    out[period - 1] = data[..period].iter().sum::<Float>() / period as Float;
+    for i in period..data.len() {
+        out[i] = (out[i - 1] * (period - 1) as Float + data[i]) / period as Float;
+    }
+    out
+}
+
+fn no_lookahead_smma(file_name: &str, period: usize) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_smma(input, period);
+
+    let output = smma(&input[0..last_idx], period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate SMMA: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert!(
+            approx_eq_float(new_state.smma, expected[last_idx + i], 1e-8),
+            "Next expected {}, but got {}",
+            expected[last_idx + i],
+            new_state.smma
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_smma("sma.csv", 14);
+}
+
+#[test]
+fn same_as_ema_with_wilder_alpha_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let result = smma(input, 14).unwrap();
+    let expected = ema(input, 14, Some(1.0 / 14.0)).unwrap();
+    assert_eq!(expected.values[13..].to_vec(), result.values[13..].to_vec());
+}
+
+#[test]
+fn period_1_err() {
+    let input = vec![1.0; 50];
+    assert!(matches!(smma(&input, 1), Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let input = vec![1.0; 13];
+    assert!(matches!(
+        smma(&input, 14),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut input = vec![1.0; 50];
+    input[30] = Float::NAN;
+    assert!(matches!(
+        smma(&input, 14),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 20..200),
+        period in 2usize..20,
+    ) {
+        let result = smma(&input, period).unwrap();
+        let expected = slow_smma(&input, period);
+        for (o, e) in result.values.iter().zip(&expected) {
+            if e.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - e).abs() <= 1e-6 * e.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use crate::expect_err_overflow_or_ok_with;
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::sum::{sum, SumResult},
+    traits::State,
+    types::Float,
+};
+
+fn slow_sum(data: &[Float], period: usize) -> Vec<Float> {
+    let mut out = vec![Float::NAN; data.len()];
+    for i in period - 1..data.len() {
+        out[i] = data[i + 1 - period..=i].iter().sum();
+    }
+    out
+}
+
+fn no_lookahead_sum(input: &[Float], expected: &[Float], period: usize) {
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let output = sum(&input[0..last_idx], period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate SUM: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert!(
+            approx_eq_float(new_state.sum, expected[last_idx + i], 1e-8),
+            "Next [{}] expected {}, but got {}",
+            i,
+            expected[last_idx + i],
+            new_state.sum
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    no_lookahead_sum(input, &slow_sum(input, 30), 30);
+}
+
+#[test]
+#[ignore = "needs the TA-Lib fixture from tools/generate_testdata.py"]
+fn generated_with_no_lookahead_ok() {
+    let columns = load_generated_csv("sum.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    no_lookahead_sum(input, columns.get("out").unwrap(), 30);
+}
+
+#[test]
+fn finite_extreme_err_overflow_or_ok_all_finite() {
+    let data = vec![
+        Float::MAX - 3.0,
+        Float::MAX - 2.0,
+        Float::MAX - 5.0,
+        Float::MAX - 6.0,
+        Float::MAX - 8.0,
+        Float::MAX - 1.0,
+    ];
+    let period = 3;
+    expect_err_overflow_or_ok_with!(sum(&data, period), |result: SumResult| {
+        assert!(
+            result.values.iter().skip(period).all(|v| v.is_finite()),
+            "Expected all values to be finite"
+        );
+    });
+}
+
+#[test]
+fn next_with_finite_neg_extreme_err_overflow_or_ok_all_finite() {
+    let data = vec![5.0, 10.0, 30.0, 3.0, 5.0, 6.0, 8.0];
+    let period = 3;
+    let result = sum(&data, period).unwrap();
+    let mut state = result.state;
+    expect_err_overflow_or_ok_with!(state.update(Float::MIN + 5.0), |_| {
+        assert!(state.sum.is_finite(), "Expected all values to be finite");
+    });
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let data = vec![1.0, 2.0, 3.0, Float::NAN, 1.0, 2.0, 3.0];
+    let result = sum(&data, 3);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn empty_input_err() {
+    let data: [Float; 0] = [];
+    let result = sum(&data, 14);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+#[test]
+fn period_1_err() {
+    let result = sum(&[1.0, 2.0, 3.0], 1);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 2..200),
+        period in 2usize..50
+    ) {
+        prop_assume!(period <= input.len());
+        let out = sum(&input, period).unwrap().values;
+        let slow = slow_sum(&input, period);
+        for (o, expect) in out.iter().zip(slow) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6);
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_ohlcv,
+};
+
+use crate::tests_atr::slow_atr;
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::supertrend::{lookback_from_period, supertrend},
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_supertrend(
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    period: usize,
+    multiplier: Float,
+) -> (Vec<Float>, Vec<Float>) {
+    let atr = slow_atr(high, low, close, period);
+    let mut line = vec![Float::NAN; close.len()];
+    let mut direction = vec![Float::NAN; close.len()];
+    let mut upper = vec![Float::NAN; close.len()];
+    let mut lower = vec![Float::NAN; close.len()];
+    for i in period..close.len() {
+        let hl2 = (high[i] + low[i]) / 2.0;
+        upper[i] = hl2 + multiplier * atr[i];
+        lower[i] = hl2 - multiplier * atr[i];
+        if i == period {
+            direction[i] = -1.0;
+        } else {
+            if upper[i] >= upper[i - 1] && close[i - 1] <= upper[i - 1] {
+                upper[i] = upper[i - 1];
+            }
+            if lower[i] <= lower[i - 1] && close[i - 1] >= lower[i - 1] {
+                lower[i] = lower[i - 1];
+            }
+            direction[i] = if line[i - 1] == upper[i - 1] {
+                if close[i] > upper[i] {
+                    1.0
+                } else {
+                    -1.0
+                }
+            } else if close[i] < lower[i] {
+                -1.0
+            } else {
+                1.0
+            };
+        }
+        line[i] = if direction[i] > 0.0 {
+            lower[i]
+        } else {
+            upper[i]
+        };
+    }
+    (line, direction)
+}
+
+fn no_lookahead_supertrend(file_name: &str, period: usize, multiplier: Float) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low, close) = (&bars.high, &bars.low, &bars.close);
+
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let (expected_line, expected_direction) = slow_supertrend(high, low, close, period, multiplier);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        ..Default::default()
+    };
+    let output = supertrend(&ohlcv, period, multiplier);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate SUPERTREND: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected_line[0..last_idx], &result.supertrend);
+    assert_vec_close(&expected_direction[0..last_idx], &result.direction);
+    assert!(result.direction[lookback_from_period(period)..]
+        .iter()
+        .any(|d| *d > 0.0));
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx], close[idx])).unwrap();
+        assert!(approx_eq_float(
+            new_state.supertrend,
+            expected_line[idx],
+            1e-8
+        ));
+        assert_eq!(new_state.direction, expected_direction[idx]);
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_supertrend("sma.csv", 10, 3.0);
+}
+
+#[test]
+fn no_lookahead_tight_ok() {
+    no_lookahead_supertrend("sma.csv", 7, 1.0);
+}
+
+#[test]
+fn line_follows_direction() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let result = supertrend(&bars.view(), 10, 2.0).unwrap();
+    let lookback = lookback_from_period(10);
+    for idx in lookback..bars.close.len() {
+        if result.direction[idx] > 0.0 {
+            assert!(result.supertrend[idx] <= bars.close[idx]);
+        } else {
+            assert!(result.supertrend[idx] >= bars.close[idx]);
+        }
+    }
+}
+
+#[test]
+fn bad_direction_state_err() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let mut state = supertrend(&bars.view(), 10, 3.0).unwrap().state;
+    state.direction = 0.0;
+    assert!(matches!(
+        state.update((1.0, 0.5, 0.8)),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0, 5.0],
+        low: &[1.0, 2.0, 1.0, 5.0, 4.0],
+        close: &[1.5, 3.0, Float::NAN, 5.5, 4.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        supertrend(&ohlcv, 2, 3.0),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 3.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        supertrend(&ohlcv, 2, 3.0),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn bad_multiplier_err() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    assert!(matches!(
+        supertrend(&bars.view(), 10, 0.0),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        supertrend(&bars.view(), 10, Float::NAN),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3, -1.0f64..1.0), 2..200),
+        period in 1usize..50,
+        multiplier in 0.1f64..5.0,
+    ) {
+        prop_assume!(period < bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let close: Vec<Float> = bars.iter().map(|b| b.0 + b.1 * b.2).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, close: &close, ..Default::default() };
+        let result = supertrend(&ohlcv, period, multiplier).unwrap();
+        let (line, direction) = slow_supertrend(&high, &low, &close, period, multiplier);
+        for (o, expect) in result.direction.iter().zip(&direction) {
+            prop_assert!((o.is_nan() && expect.is_nan()) || o == expect);
+        }
+        for (o, expect) in result.supertrend.iter().zip(&line) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_csv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::{
+        bbands::{bbands, bbands_sweep, BBandsMA, DeviationMulipliers},
+        ema::{ema, ema_sweep},
+        rsi::{rsi, rsi_sweep, rsi_sweep_into},
+        sma::{sma, sma_sweep, sma_sweep_into},
+    },
+    traits::State,
+    types::Float,
+};
+
+const PERIODS: [usize; 6] = [2, 5, 14, 20, 30, 50];
+
+fn load_input() -> Vec<Float> {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    columns.get("close").unwrap().clone()
+}
+
+#[test]
+fn sma_sweep_ok() {
+    let input = load_input();
+    let len = input.len();
+    let result = sma_sweep(&input, &PERIODS).unwrap();
+    assert_eq!(result.values.len(), len * PERIODS.len());
+    assert_eq!(result.states.len(), PERIODS.len());
+    for (i, &period) in PERIODS.iter().enumerate() {
+        let expected = sma(&input, period).unwrap();
+        assert_vec_close(&expected.values, &result.values[i * len..(i + 1) * len]);
+        assert_eq!(result.states[i].period, period);
+        assert_eq!(result.states[i].last_window, expected.state.last_window);
+    }
+}
+
+#[test]
+fn ema_sweep_ok() {
+    let input = load_input();
+    let len = input.len();
+    let result = ema_sweep(&input, &PERIODS).unwrap();
+    for (i, &period) in PERIODS.iter().enumerate() {
+        let expected = ema(&input, period, None).unwrap();
+        assert_vec_close(&expected.values, &result.values[i * len..(i + 1) * len]);
+        assert_eq!(result.states[i].alpha, expected.state.alpha);
+    }
+}
+
+#[test]
+fn rsi_sweep_ok() {
+    let input = load_input();
+    let len = input.len();
+    let result = rsi_sweep(&input, &PERIODS).unwrap();
+    for (i, &period) in PERIODS.iter().enumerate() {
+        let expected = rsi(&input, period).unwrap();
+        assert_vec_close(&expected.values, &result.values[i * len..(i + 1) * len]);
+    }
+}
+
+#[test]
+fn bbands_sweep_ok() {
+    let input = load_input();
+    let len = input.len();
+    let multipliers = DeviationMulipliers { up: 2.0, down: 1.5 };
+    for ma_type in [
+        BBandsMA::SMA,
+        BBandsMA::EMA(None),
+        BBandsMA::SMMA,
+        BBandsMA::ALMA(0.85, 6.0),
+    ] {
+        let result = bbands_sweep(&input, &PERIODS, multipliers, ma_type).unwrap();
+        for (i, &period) in PERIODS.iter().enumerate() {
+            let expected = bbands(&input, period, multipliers, ma_type).unwrap();
+            let column = i * len..(i + 1) * len;
+            assert_vec_close(&expected.upper, &result.upper[column.clone()]);
+            assert_vec_close(&expected.middle, &result.middle[column.clone()]);
+            assert_vec_close(&expected.lower, &result.lower[column]);
+        }
+    }
+}
+
+#[test]
+fn sweep_states_update_ok() {
+    let input = load_input();
+    let len = input.len();
+    let mut sweep = sma_sweep(&input[..len - 1], &PERIODS).unwrap();
+    let mut rsi_sweep = rsi_sweep(&input[..len - 1], &PERIODS).unwrap();
+    for (i, &period) in PERIODS.iter().enumerate() {
+        sweep.states[i].update(input[len - 1]).unwrap();
+        let expected = sma(&input, period).unwrap();
+        assert!((sweep.states[i].sma - expected.state.sma).abs() < 1e-8);
+
+        rsi_sweep.states[i].update(input[len - 1]).unwrap();
+        let expected = rsi(&input, period).unwrap();
+        assert!((rsi_sweep.states[i].rsi - expected.state.rsi).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn sweep_empty_periods_ok() {
+    let input = load_input();
+    let result = sma_sweep(&input, &[]).unwrap();
+    assert!(result.values.is_empty());
+    assert!(result.states.is_empty());
+}
+
+#[test]
+fn sweep_period_too_large_err() {
+    let input = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let result = sma_sweep(&input, &[2, 6]);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+    let result = rsi_sweep(&input, &[2, 5]);
+    assert!(matches!(result, Err(TechalibError::InsufficientData)));
+}
+
+#[test]
+fn sweep_period_1_err() {
+    let input = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let result = ema_sweep(&input, &[3, 1]);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn sweep_nan_err() {
+    let mut input = load_input();
+    input[40] = Float::NAN;
+    let result = sma_sweep(&input, &PERIODS);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+    let result = rsi_sweep(&input, &PERIODS);
+    assert!(matches!(result, Err(TechalibError::DataNonFinite(_))));
+}
+
+#[test]
+fn sweep_output_mismatch_err() {
+    let input = load_input();
+    let mut output = vec![0.0; input.len() * PERIODS.len() - 1];
+    let result = sma_sweep_into(&input, &PERIODS, &mut output);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+    let result = rsi_sweep_into(&input, &PERIODS, &mut output);
+    assert!(matches!(result, Err(TechalibError::BadParam(_))));
+}
+
+#[test]
+fn sweep_longer_output_ok() {
+    let input = load_input();
+    let len = input.len();
+    let mut output = vec![-1.0; len * PERIODS.len() + 3];
+    sma_sweep_into(&input, &PERIODS, &mut output).unwrap();
+    let expected = sma_sweep(&input, &PERIODS).unwrap();
+    assert_vec_close(&expected.values, &output[..len * PERIODS.len()]);
+    assert_eq!(output[len * PERIODS.len()..], [-1.0, -1.0, -1.0]);
+}
+
+#[test]
+fn sma_sweep_long_series_ok() {
+    // Over two million values, prefix sums running from the start of the series
+    // lose about 1e-11 on the window means, as much as the drift of the rolling SMA.
+    // The sweep is then compared to the exact means as well.
+    let len = 2_000_000;
+    let input: Vec<f64> = (0..len)
+        .map(|i| ((i * 7919) % 1013) as f64 / 1013.0)
+        .collect();
+    let periods = [2, 14, 50];
+    let result = sma_sweep(&input, &periods).unwrap();
+    for (i, &period) in periods.iter().enumerate() {
+        let expected = sma(&input, period).unwrap();
+        let values = &result.values[i * len..(i + 1) * len];
+        for idx in period - 1..len {
+            let exact = input[idx + 1 - period..=idx].iter().sum::<f64>() / period as f64;
+            let (e, o) = (expected.values[idx], values[idx]);
+            assert!((e - o).abs() <= 1e-10, "[{idx}] Expected: {e}, got: {o}");
+            assert!(
+                (exact - o).abs() <= 1e-12,
+                "[{idx}] Exact: {exact}, got: {o}"
+            );
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e3f64..1e3, 60..300),
+        periods in vec(2usize..50, 1..6),
+    ) {
+        let len = input.len();
+        let result = sma_sweep(&input, &periods).unwrap();
+        for (i, &period) in periods.iter().enumerate() {
+            let expected = sma(&input, period).unwrap();
+            for (e, o) in expected.values.iter().zip(&result.values[i * len..(i + 1) * len]) {
+                if e.is_nan() {
+                    prop_assert!(o.is_nan());
+                } else {
+                    prop_assert!((e - o).abs() <= 1e-8 * e.abs().max(1.0));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_ohlcv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::swing::swing,
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_swing(
+    high: &[Float],
+    low: &[Float],
+    left: usize,
+    right: usize,
+) -> (Vec<Float>, Vec<Float>) {
+    let len = high.len();
+    let mut swing_high = vec![Float::NAN; len];
+    let mut swing_low = vec![Float::NAN; len];
+    for i in left..len.saturating_sub(right) {
+        let before = i - left..i;
+        let mut after = i + 1..=i + right;
+        if before.clone().all(|j| high[i] > high[j]) && after.clone().all(|j| high[i] >= high[j]) {
+            swing_high[i] = high[i];
+        }
+        if before.clone().all(|j| low[i] < low[j]) && after.all(|j| low[i] <= low[j]) {
+            swing_low[i] = low[i];
+        }
+    }
+    (swing_high, swing_low)
+}
+
+fn no_lookahead_swing(file_name: &str, left: usize, right: usize) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low) = (&bars.high, &bars.low);
+
+    let len = high.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let (expected_high, expected_low) = slow_swing(high, low, left, right);
+    let (expected_partial_high, expected_partial_low) =
+        slow_swing(&high[0..last_idx], &low[0..last_idx], left, right);
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        ..Default::default()
+    };
+    let output = swing(&ohlcv, left, right);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate SWING: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected_partial_high, &result.swing_high);
+    assert_vec_close(&expected_partial_low, &result.swing_low);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx])).unwrap();
+        assert_vec_close(
+            &[expected_high[idx - right], expected_low[idx - right]],
+            &[new_state.confirmed_high, new_state.confirmed_low],
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_fractal_ok() {
+    no_lookahead_swing("sma.csv", 2, 2);
+}
+
+#[test]
+fn no_lookahead_asymmetric_ok() {
+    no_lookahead_swing("sma.csv", 5, 3);
+}
+
+#[test]
+fn flat_top_marked_once_ok() {
+    let ohlcv = Ohlcv {
+        high: &[1.0, 2.0, 3.0, 3.0, 2.0, 1.0],
+        low: &[0.5, 1.5, 2.5, 2.5, 1.5, 0.5],
+        ..Default::default()
+    };
+    let result = swing(&ohlcv, 2, 2).unwrap();
+    let nan = Float::NAN;
+    assert_vec_close(&[nan, nan, 3.0, nan, nan, nan], &result.swing_high);
+    assert_vec_close(&[nan; 6], &result.swing_low);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0, 5.0],
+        low: &[1.0, 2.0, Float::NAN, 5.0, 4.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        swing(&ohlcv, 1, 1),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0],
+        low: &[1.0, 2.0, 1.0, 5.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        swing(&ohlcv, 2, 2),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn zero_side_err() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    assert!(matches!(
+        swing(&bars.view(), 0, 2),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        swing(&bars.view(), 2, 0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e6f64..1e6, 0.0f64..1e3), 3..200),
+        left in 1usize..10,
+        right in 1usize..10,
+    ) {
+        prop_assume!(left + right < bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 - b.1).collect();
+        let ohlcv = Ohlcv { high: &high, low: &low, ..Default::default() };
+        let result = swing(&ohlcv, left, right).unwrap();
+        let (swing_high, swing_low) = slow_swing(&high, &low, left, right);
+        for (o, expect) in result.swing_high.iter().chain(&result.swing_low)
+            .zip(swing_high.iter().chain(&swing_low)) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!(o == expect);
+            }
+        }
+    }
+}
@@ -67,6 +67,30 @@ fn generated_with_no_lookahead_period_3_ok() {
     generated_and_no_lookahead_trima("trima_timeperiod-3.csv", 3);
 }
 
+fn state_update_over_several_windows(file_name: &str, period: usize) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+    let expected = columns.get("out").unwrap();
+
+    let start = 2 * period;
+    let mut state = trima(&input[..start], period).unwrap().state;
+    for (idx, &sample) in input.iter().enumerate().skip(start) {
+        state.update(sample).unwrap();
+        assert!(
+            approx_eq_float(state.trima, expected[idx], 1e-8),
+            "[{idx}] Expected {}, but got {}",
+            expected[idx],
+            state.trima
+        );
+    }
+}
+
+#[test]
+fn state_update_over_several_windows_ok() {
+    state_update_over_several_windows("trima.csv", 30);
+    state_update_over_several_windows("trima_timeperiod-25.csv", 25);
+}
+
 #[test]
 fn zeros_ok() {
     let input = vec![0.0; 100];
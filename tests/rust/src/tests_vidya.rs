@@ -0,0 +1,143 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::vidya::{vidya, DEFAULT_CMO_PERIOD},
+    traits::State,
+    types::Float,
+};
+
+fn slow_vidya(data: &[Float], period: usize, cmo_period: usize) -> Vec<Float> {
+    let lookback = (period - 1).max(cmo_period);
+    let mut out = vec![Float::NAN; data.len()];
+    out[lookback] = data[lookback + 1 - period..=lookback].iter().sum::<Float>() / period as Float;
+    for i in lookback + 1..data.len() {
+        let (mut up, mut down) = (0.0, 0.0);
+        for j in i + 1 - cmo_period..=i {
+            let change = data[j] - data[j - 1];
+            if change > 0.0 {
+                up += change;
+            } else {
+                down -= change;
+            }
+        }
+        let cmo = if up + down > 0.0 {
+            (up - down) / (up + down)
+        } else {
+            0.0
+        };
+        let alpha = 2.0 / (period as Float + 1.0) * cmo.abs();
+        out[i] = alpha * data[i] + (1.0 - alpha) * out[i - 1];
+    }
+    out
+}
+
+fn no_lookahead_vidya(file_name: &str, period: usize, cmo_period: usize) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_vidya(input, period, cmo_period);
+
+    let output = vidya(&input[0..last_idx], period, cmo_period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate VIDYA: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert!(
+            approx_eq_float(new_state.vidya, expected[last_idx + i], 1e-8),
+            "Next expected {}, but got {}",
+            expected[last_idx + i],
+            new_state.vidya
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_vidya("sma.csv", 14, DEFAULT_CMO_PERIOD);
+}
+
+#[test]
+fn no_lookahead_long_cmo_ok() {
+    no_lookahead_vidya("sma.csv", 5, 20);
+}
+
+#[test]
+fn flat_input_is_constant_ok() {
+    let mut input = vec![2.0; 50];
+    input[..10]
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, v)| *v += i as Float);
+    let result = vidya(&input, 5, 3).unwrap();
+    // Without any change over the CMO period, the average stops moving
+    let frozen = result.values[13];
+    assert!(result.values[13..].iter().all(|v| *v == frozen));
+}
+
+#[test]
+fn bad_param_err() {
+    let input = vec![1.0; 50];
+    assert!(matches!(
+        vidya(&input, 1, DEFAULT_CMO_PERIOD),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        vidya(&input, 14, 0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let input = vec![1.0; 20];
+    assert!(matches!(
+        vidya(&input, 5, 20),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut input = vec![1.0; 50];
+    input[30] = Float::NAN;
+    assert!(matches!(
+        vidya(&input, 14, DEFAULT_CMO_PERIOD),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e3f64..1e3, 40..200),
+        period in 2usize..20,
+        cmo_period in 1usize..20,
+    ) {
+        let result = vidya(&input, period, cmo_period).unwrap();
+        let expected = slow_vidya(&input, period, cmo_period);
+        for (o, e) in result.values.iter().zip(&expected) {
+            if e.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - e).abs() <= 1e-6 * e.abs().max(1.0));
+            }
+        }
+    }
+}
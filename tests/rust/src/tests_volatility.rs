@@ -0,0 +1,289 @@
+use crate::helper::{assert::approx_eq_float, generated::load_generated_ohlcv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::volatility::{volatility, VolatilityEstimator, DEFAULT_ANNUALIZATION},
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+const ESTIMATORS: [VolatilityEstimator; 5] = [
+    VolatilityEstimator::CloseToClose,
+    VolatilityEstimator::Parkinson,
+    VolatilityEstimator::GarmanKlass,
+    VolatilityEstimator::RogersSatchell,
+    VolatilityEstimator::YangZhang,
+];
+
+fn mean(values: &[Float]) -> Float {
+    values.iter().sum::<Float>() / values.len() as Float
+}
+
+fn sample_variance(values: &[Float]) -> Float {
+    let m = mean(values);
+    values.iter().map(|v| (v - m).powi(2)).sum::<Float>() / (values.len() - 1) as Float
+}
+
+fn rogers_satchell(o: Float, h: Float, l: Float, c: Float) -> Float {
+    (h / c).ln() * (h / o).ln() + (l / c).ln() * (l / o).ln()
+}
+
+fn slow_volatility(
+    ohlcv: &Ohlcv,
+    period: usize,
+    estimator: VolatilityEstimator,
+    annualization: Float,
+) -> Vec<Float> {
+    let (o, h, l, c) = (ohlcv.open, ohlcv.high, ohlcv.low, ohlcv.close);
+    let len = match estimator {
+        VolatilityEstimator::Parkinson => h.len(),
+        _ => c.len(),
+    };
+    let ln2 = (2.0 as Float).ln();
+    let mut output = vec![Float::NAN; len];
+    let uses_prev_close = matches!(
+        estimator,
+        VolatilityEstimator::CloseToClose | VolatilityEstimator::YangZhang
+    );
+    let start = period - 1 + uses_prev_close as usize;
+    for (i, out) in output.iter_mut().enumerate().skip(start) {
+        let bars = i + 1 - period..=i;
+        let variance = match estimator {
+            VolatilityEstimator::CloseToClose => {
+                let returns: Vec<Float> = bars.map(|j| (c[j] / c[j - 1]).ln()).collect();
+                sample_variance(&returns)
+            }
+            VolatilityEstimator::Parkinson => {
+                let terms: Vec<Float> = bars.map(|j| (h[j] / l[j]).ln().powi(2)).collect();
+                mean(&terms) / (4.0 * ln2)
+            }
+            VolatilityEstimator::GarmanKlass => {
+                let terms: Vec<Float> = bars
+                    .map(|j| {
+                        0.5 * (h[j] / l[j]).ln().powi(2)
+                            - (2.0 * ln2 - 1.0) * (c[j] / o[j]).ln().powi(2)
+                    })
+                    .collect();
+                mean(&terms)
+            }
+            VolatilityEstimator::RogersSatchell => {
+                let terms: Vec<Float> = bars
+                    .map(|j| rogers_satchell(o[j], h[j], l[j], c[j]))
+                    .collect();
+                mean(&terms)
+            }
+            VolatilityEstimator::YangZhang => {
+                let n = period as Float;
+                let k = 0.34 / (1.34 + (n + 1.0) / (n - 1.0));
+                let overnight: Vec<Float> = bars.clone().map(|j| (o[j] / c[j - 1]).ln()).collect();
+                let open_close: Vec<Float> = bars.clone().map(|j| (c[j] / o[j]).ln()).collect();
+                let rs: Vec<Float> = bars
+                    .map(|j| rogers_satchell(o[j], h[j], l[j], c[j]))
+                    .collect();
+                sample_variance(&overnight)
+                    + k * sample_variance(&open_close)
+                    + (1.0 - k) * mean(&rs)
+            }
+        };
+        *out = (variance.max(0.0) * annualization).sqrt();
+    }
+    output
+}
+
+fn assert_vec_rel_close(expected: &[Float], got: &[Float]) {
+    assert_eq!(expected.len(), got.len());
+    for (i, (e, g)) in expected.iter().zip(got).enumerate() {
+        assert!(
+            (e.is_nan() && g.is_nan()) || (e - g).abs() <= 1e-8 * e.abs().max(1.0),
+            "[{i}] Expected: {e}, got: {g}"
+        );
+    }
+}
+
+fn no_lookahead_volatility(file_name: &str, period: usize, estimator: VolatilityEstimator) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (open, high, low, close) = (&bars.open, &bars.high, &bars.low, &bars.close);
+
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_volatility(&bars.view(), period, estimator, DEFAULT_ANNUALIZATION);
+
+    let ohlcv = Ohlcv {
+        open: &open[0..last_idx],
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        ..Default::default()
+    };
+    let output = volatility(&ohlcv, period, estimator, DEFAULT_ANNUALIZATION);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate VOLATILITY: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_rel_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state
+            .update((open[idx], high[idx], low[idx], close[idx]))
+            .unwrap();
+        assert!(approx_eq_float(new_state.volatility, expected[idx], 1e-8));
+    }
+}
+
+#[test]
+fn no_lookahead_close_to_close_ok() {
+    no_lookahead_volatility("sma.csv", 20, VolatilityEstimator::CloseToClose);
+}
+
+#[test]
+fn no_lookahead_parkinson_ok() {
+    no_lookahead_volatility("sma.csv", 20, VolatilityEstimator::Parkinson);
+}
+
+#[test]
+fn no_lookahead_garman_klass_ok() {
+    no_lookahead_volatility("sma.csv", 20, VolatilityEstimator::GarmanKlass);
+}
+
+#[test]
+fn no_lookahead_rogers_satchell_ok() {
+    no_lookahead_volatility("sma.csv", 20, VolatilityEstimator::RogersSatchell);
+}
+
+#[test]
+fn no_lookahead_yang_zhang_ok() {
+    no_lookahead_volatility("sma.csv", 20, VolatilityEstimator::YangZhang);
+}
+
+#[test]
+fn no_lookahead_min_period_ok() {
+    no_lookahead_volatility("sma.csv", 2, VolatilityEstimator::CloseToClose);
+    no_lookahead_volatility("sma.csv", 1, VolatilityEstimator::Parkinson);
+    no_lookahead_volatility("sma.csv", 2, VolatilityEstimator::YangZhang);
+}
+
+#[test]
+fn close_to_close_only_uses_close_ok() {
+    let ohlcv = Ohlcv {
+        close: &[100.0, 101.0, 99.0, 102.0, 103.0],
+        ..Default::default()
+    };
+    let result = volatility(&ohlcv, 3, VolatilityEstimator::CloseToClose, 1.0).unwrap();
+    assert_vec_rel_close(
+        &slow_volatility(&ohlcv, 3, VolatilityEstimator::CloseToClose, 1.0),
+        &result.values,
+    );
+    assert!(result.values[2].is_nan());
+    assert!(result.values[3].is_finite());
+}
+
+#[test]
+fn non_positive_price_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0, 6.0, 5.0],
+        low: &[1.0, 2.0, 0.0, 5.0, 4.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        volatility(&ohlcv, 2, VolatilityEstimator::Parkinson, 1.0),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        close: &[1.5, 3.0, Float::NAN, 5.5, 4.5],
+        ..Default::default()
+    };
+    assert!(matches!(
+        volatility(&ohlcv, 2, VolatilityEstimator::CloseToClose, 1.0),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn missing_column_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, 3.0],
+        low: &[1.0, 2.0, 1.0],
+        close: &[1.5, 3.0, 2.0],
+        ..Default::default()
+    };
+    assert!(volatility(&ohlcv, 2, VolatilityEstimator::GarmanKlass, 1.0).is_err());
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        close: &[1.5, 3.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        volatility(&ohlcv, 2, VolatilityEstimator::CloseToClose, 1.0),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn period_1_close_to_close_err() {
+    let ohlcv = Ohlcv {
+        close: &[1.5, 3.0, 2.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        volatility(&ohlcv, 1, VolatilityEstimator::CloseToClose, 1.0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn bad_annualization_err() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    for annualization in [0.0, -252.0, Float::NAN, Float::INFINITY] {
+        assert!(matches!(
+            volatility(
+                &bars.view(),
+                20,
+                VolatilityEstimator::YangZhang,
+                annualization
+            ),
+            Err(TechalibError::BadParam(_))
+        ));
+    }
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((1.0f64..1e4, 0.0f64..0.1, -1.0f64..1.0, -1.0f64..1.0), 3..200),
+        period in 2usize..50,
+        estimator_idx in 0usize..5,
+        annualization in 1.0f64..365.0,
+    ) {
+        prop_assume!(period + 1 < bars.len());
+        let estimator = ESTIMATORS[estimator_idx];
+        let high: Vec<Float> = bars.iter().map(|b| b.0 * (1.0 + b.1)).collect();
+        let low: Vec<Float> = bars.iter().map(|b| b.0 / (1.0 + b.1)).collect();
+        let open: Vec<Float> = bars.iter().map(|b| b.0 * (1.0 + b.1 * b.2).max(1.0 / (1.0 + b.1))).collect();
+        let close: Vec<Float> = bars.iter().map(|b| b.0 * (1.0 + b.1 * b.3).max(1.0 / (1.0 + b.1))).collect();
+        let ohlcv = Ohlcv { open: &open, high: &high, low: &low, close: &close, ..Default::default() };
+        let result = volatility(&ohlcv, period, estimator, annualization).unwrap();
+        let expected = slow_volatility(&ohlcv, period, estimator, annualization);
+        for (o, expect) in result.values.iter().zip(&expected) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_ohlcv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::vwap::{vwap, VwapReset},
+    traits::State,
+    types::Float,
+};
+
+fn slow_vwap(
+    price: &[Float],
+    volume: &[Float],
+    resets: &[bool],
+    multiplier: Float,
+) -> (Vec<Float>, Vec<Float>, Vec<Float>) {
+    let len = price.len();
+    let mut out_vwap = vec![Float::NAN; len];
+    let mut out_upper = vec![Float::NAN; len];
+    let mut out_lower = vec![Float::NAN; len];
+    let mut start = 0;
+    for i in 0..len {
+        if resets[i] {
+            start = i;
+        }
+        let cum_volume: Float = volume[start..=i].iter().sum();
+        if cum_volume == 0.0 {
+            continue;
+        }
+        let mean = (start..=i).map(|j| price[j] * volume[j]).sum::<Float>() / cum_volume;
+        let variance = (start..=i)
+            .map(|j| volume[j] * (price[j] - mean).powi(2))
+            .sum::<Float>()
+            / cum_volume;
+        out_vwap[i] = mean;
+        out_upper[i] = mean + multiplier * variance.sqrt();
+        out_lower[i] = mean - multiplier * variance.sqrt();
+    }
+    (out_vwap, out_upper, out_lower)
+}
+
+fn no_lookahead_vwap(file_name: &str, session_length: usize, multiplier: Float) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (price, volume) = (&bars.close, &bars.volume);
+
+    let len = price.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+    let resets: Vec<bool> = (0..len).map(|i| i % session_length == 0).collect();
+
+    let (expected_vwap, expected_upper, expected_lower) =
+        slow_vwap(price, volume, &resets, multiplier);
+
+    let output = vwap(
+        &price[0..last_idx],
+        &volume[0..last_idx],
+        VwapReset::Mask(&resets[0..last_idx]),
+        multiplier,
+    );
+    assert!(
+        output.is_ok(),
+        "Failed to calculate VWAP: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected_vwap[0..last_idx], &result.vwap);
+    assert_vec_close(&expected_upper[0..last_idx], &result.upper);
+    assert_vec_close(&expected_lower[0..last_idx], &result.lower);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        if resets[idx] {
+            new_state.reset();
+        }
+        new_state.update((price[idx], volume[idx])).unwrap();
+        assert!(approx_eq_float(new_state.vwap, expected_vwap[idx], 1e-8));
+        assert!(approx_eq_float(new_state.upper, expected_upper[idx], 1e-8));
+        assert!(approx_eq_float(new_state.lower, expected_lower[idx], 1e-8));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_vwap("sma.csv", 100, 2.0);
+}
+
+#[test]
+fn no_lookahead_reset_in_next_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    // The session boundary falls in the incremental part of the test
+    no_lookahead_vwap("sma.csv", bars.close.len() - 4, 1.0);
+}
+
+#[test]
+fn single_session_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let (price, volume) = (&bars.close, &bars.volume);
+    let resets: Vec<bool> = (0..price.len()).map(|i| i == 0).collect();
+    let (expected_vwap, expected_upper, _) = slow_vwap(price, volume, &resets, 2.0);
+
+    let result = vwap(price, volume, VwapReset::Never, 2.0).unwrap();
+    assert_vec_close(&expected_vwap, &result.vwap);
+    assert_vec_close(&expected_upper, &result.upper);
+}
+
+#[test]
+fn anchors_match_mask() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let (price, volume) = (&bars.close, &bars.volume);
+    let anchors = [10, 10, 250, 251, 700];
+    let mask: Vec<bool> = (0..price.len()).map(|i| anchors.contains(&i)).collect();
+
+    let from_mask = vwap(price, volume, VwapReset::Mask(&mask), 2.0).unwrap();
+    let from_anchors = vwap(price, volume, VwapReset::Anchors(&anchors), 2.0).unwrap();
+    assert_vec_close(&from_mask.vwap, &from_anchors.vwap);
+    assert_vec_close(&from_mask.upper, &from_anchors.upper);
+    assert_vec_close(&from_mask.lower, &from_anchors.lower);
+    assert_eq!(from_anchors.vwap[10], price[10]);
+}
+
+#[test]
+fn zero_volume_is_nan() {
+    let price = [10.0, 11.0, 12.0, 13.0];
+    let volume = [0.0, 0.0, 2.0, 2.0];
+    let result = vwap::<Float>(&price, &volume, VwapReset::Never, 2.0).unwrap();
+    assert!(result.vwap[0].is_nan());
+    assert!(result.upper[1].is_nan());
+    assert_eq!(result.vwap[2], 12.0);
+    assert_eq!(result.upper[2], 12.0);
+    assert_eq!(result.vwap[3], 12.5);
+    assert!(approx_eq_float(result.upper[3], 13.5, 1e-12));
+}
+
+#[test]
+fn state_reset_ok() {
+    let price = [10.0, 11.0, 12.0];
+    let volume = [1.0, 1.0, 1.0];
+    let mut state = vwap::<Float>(&price, &volume, VwapReset::Never, 2.0)
+        .unwrap()
+        .state;
+    state.reset();
+    assert!(state.vwap.is_nan());
+    state.update((20.0, 3.0)).unwrap();
+    assert_eq!(state.vwap, 20.0);
+    assert_eq!(state.cum_volume, 3.0);
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let price = [10.0, Float::NAN, 12.0];
+    let volume = [1.0, 1.0, 1.0];
+    assert!(matches!(
+        vwap::<Float>(&price, &volume, VwapReset::Never, 2.0),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn negative_volume_err() {
+    let price = [10.0, 11.0, 12.0];
+    let volume = [1.0, -1.0, 1.0];
+    assert!(matches!(
+        vwap::<Float>(&price, &volume, VwapReset::Never, 2.0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn empty_input_err() {
+    assert!(matches!(
+        vwap(&[], &[], VwapReset::Never, 2.0),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn bad_resets_err() {
+    let price = [10.0, 11.0, 12.0];
+    let volume = [1.0, 1.0, 1.0];
+    assert!(matches!(
+        vwap(&price, &volume, VwapReset::Mask(&[true, false]), 2.0),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        vwap(&price, &volume, VwapReset::Anchors(&[2, 1]), 2.0),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        vwap(&price, &volume, VwapReset::Anchors(&[1, 3]), 2.0),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e4f64..1e4, 0.0f64..1e3, proptest::bool::weighted(0.1)), 1..200),
+        multiplier in 0.1f64..5.0,
+    ) {
+        let price: Vec<Float> = bars.iter().map(|b| b.0).collect();
+        let volume: Vec<Float> = bars.iter().map(|b| b.1).collect();
+        let resets: Vec<bool> = bars.iter().map(|b| b.2).collect();
+        let result = vwap(&price, &volume, VwapReset::Mask(&resets), multiplier).unwrap();
+        let mut slow_resets = resets.clone();
+        slow_resets[0] = true;
+        let (out_vwap, upper, lower) = slow_vwap(&price, &volume, &slow_resets, multiplier);
+        for (o, expect) in result.vwap.iter().chain(&result.upper).chain(&result.lower)
+            .zip(out_vwap.iter().chain(&upper).chain(&lower)) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
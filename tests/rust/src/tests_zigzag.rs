@@ -0,0 +1,291 @@
+use crate::helper::{assert::assert_vec_close, generated::load_generated_ohlcv};
+
+use crate::tests_atr::slow_atr;
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::zigzag::{zigzag, ZigzagThreshold},
+    traits::State,
+    types::{Float, Ohlcv},
+};
+
+fn slow_zigzag(
+    high: &[Float],
+    low: &[Float],
+    distance: impl Fn(Float, usize) -> Float,
+) -> (Vec<Float>, Vec<Float>, Vec<Float>) {
+    let len = high.len();
+    let mut pivots = vec![Float::NAN; len];
+    let mut confirmed = vec![Float::NAN; len];
+    let mut direction = vec![Float::NAN; len];
+    let mut dir = 0.0;
+    let (mut extreme, mut extreme_idx) = (Float::NAN, 0);
+    for i in 0..len {
+        if dir == 0.0 {
+            let mut hi = 0;
+            let mut lo = 0;
+            for j in 0..=i {
+                if high[j] > high[hi] {
+                    hi = j;
+                }
+                if low[j] < low[lo] {
+                    lo = j;
+                }
+            }
+            let range = high[hi] - low[lo];
+            if lo < hi && range >= distance(low[lo], i) {
+                pivots[lo] = low[lo];
+                confirmed[i] = low[lo];
+                dir = 1.0;
+                (extreme, extreme_idx) = (high[hi], hi);
+            } else if hi < lo && range >= distance(high[hi], i) {
+                pivots[hi] = high[hi];
+                confirmed[i] = high[hi];
+                dir = -1.0;
+                (extreme, extreme_idx) = (low[lo], lo);
+            }
+        } else if dir == 1.0 {
+            if high[i] > extreme {
+                (extreme, extreme_idx) = (high[i], i);
+            } else if extreme - low[i] >= distance(extreme, i) {
+                pivots[extreme_idx] = extreme;
+                confirmed[i] = extreme;
+                dir = -1.0;
+                (extreme, extreme_idx) = (low[i], i);
+            }
+        } else if low[i] < extreme {
+            (extreme, extreme_idx) = (low[i], i);
+        } else if high[i] - extreme >= distance(extreme, i) {
+            pivots[extreme_idx] = extreme;
+            confirmed[i] = extreme;
+            dir = 1.0;
+            (extreme, extreme_idx) = (high[i], i);
+        }
+        if dir != 0.0 {
+            direction[i] = dir;
+        }
+    }
+    (pivots, confirmed, direction)
+}
+
+fn slow_zigzag_threshold(
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    threshold: ZigzagThreshold,
+) -> (Vec<Float>, Vec<Float>, Vec<Float>) {
+    match threshold {
+        ZigzagThreshold::Percent(percent) => {
+            slow_zigzag(high, low, |reference, _| reference.abs() * percent / 100.0)
+        }
+        ZigzagThreshold::Atr { period, multiplier } => {
+            let atr = slow_atr(high, low, close, period);
+            slow_zigzag(high, low, |_, i| multiplier * atr[i])
+        }
+    }
+}
+
+fn no_lookahead_zigzag(file_name: &str, threshold: ZigzagThreshold) {
+    let bars = load_generated_ohlcv(file_name).unwrap();
+    let (high, low, close) = (&bars.high, &bars.low, &bars.close);
+
+    let len = close.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let (_, expected_confirmed, expected_direction) =
+        slow_zigzag_threshold(high, low, close, threshold);
+    let (expected_pivots, _, _) = slow_zigzag_threshold(
+        &high[0..last_idx],
+        &low[0..last_idx],
+        &close[0..last_idx],
+        threshold,
+    );
+
+    let ohlcv = Ohlcv {
+        high: &high[0..last_idx],
+        low: &low[0..last_idx],
+        close: &close[0..last_idx],
+        ..Default::default()
+    };
+    let output = zigzag(&ohlcv, threshold);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate ZIGZAG: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected_pivots, &result.pivots);
+    assert_vec_close(&expected_confirmed[0..last_idx], &result.confirmed);
+    assert_vec_close(&expected_direction[0..last_idx], &result.direction);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update((high[idx], low[idx], close[idx])).unwrap();
+        assert_vec_close(
+            &[expected_confirmed[idx], expected_direction[idx]],
+            &[new_state.confirmed, new_state.direction],
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_percent_ok() {
+    no_lookahead_zigzag("sma.csv", ZigzagThreshold::Percent(5.0));
+}
+
+#[test]
+fn no_lookahead_atr_ok() {
+    no_lookahead_zigzag(
+        "sma.csv",
+        ZigzagThreshold::Atr {
+            period: 14,
+            multiplier: 3.0,
+        },
+    );
+}
+
+#[test]
+fn known_swings_ok() {
+    let ohlcv = Ohlcv {
+        high: &[10.0, 9.8, 10.5, 12.5, 12.0, 11.5, 11.0, 11.2, 13.0],
+        low: &[9.0, 9.2, 9.6, 10.5, 11.5, 11.0, 10.0, 10.5, 12.0],
+        ..Default::default()
+    };
+    let result = zigzag(&ohlcv, ZigzagThreshold::Percent(10.0)).unwrap();
+    let nan = Float::NAN;
+    assert_vec_close(
+        &[9.0, nan, nan, 12.5, nan, nan, 10.0, nan, nan],
+        &result.pivots,
+    );
+    assert_vec_close(
+        &[nan, nan, 9.0, nan, nan, 12.5, nan, 10.0, nan],
+        &result.confirmed,
+    );
+    assert_vec_close(
+        &[nan, nan, 1.0, 1.0, 1.0, -1.0, -1.0, 1.0, 1.0],
+        &result.direction,
+    );
+
+    // The tentative last leg goes from the last pivot to the highest high since
+    assert_eq!(result.state.last_pivot, 10.0);
+    assert_eq!(result.state.last_pivot_idx, 6);
+    assert_eq!(result.state.extreme, 13.0);
+    assert_eq!(result.state.extreme_idx, 8);
+}
+
+#[test]
+fn confirmed_matches_every_prefix_ok() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    let threshold = ZigzagThreshold::Percent(3.0);
+    let full = zigzag(&bars.view(), threshold).unwrap();
+    for end in (1..bars.close.len()).step_by(37) {
+        let ohlcv = Ohlcv {
+            high: &bars.high[0..end],
+            low: &bars.low[0..end],
+            ..Default::default()
+        };
+        let prefix = zigzag(&ohlcv, threshold).unwrap();
+        assert_vec_close(&full.confirmed[0..end], &prefix.confirmed);
+        assert_vec_close(&full.direction[0..end], &prefix.direction);
+        for (p, f) in prefix.pivots.iter().zip(&full.pivots) {
+            assert!(p.is_nan() || p == f);
+        }
+    }
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0, Float::NAN, 6.0, 5.0],
+        low: &[1.0, 2.0, 1.0, 5.0, 4.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        zigzag(&ohlcv, ZigzagThreshold::Percent(5.0)),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let ohlcv = Ohlcv {
+        high: &[2.0, 4.0],
+        low: &[1.0, 2.0],
+        close: &[1.5, 3.0],
+        ..Default::default()
+    };
+    assert!(matches!(
+        zigzag(
+            &ohlcv,
+            ZigzagThreshold::Atr {
+                period: 2,
+                multiplier: 3.0
+            }
+        ),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn bad_threshold_err() {
+    let bars = load_generated_ohlcv("sma.csv").unwrap();
+    assert!(matches!(
+        zigzag(&bars.view(), ZigzagThreshold::Percent(0.0)),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        zigzag(
+            &bars.view(),
+            ZigzagThreshold::Atr {
+                period: 0,
+                multiplier: 3.0
+            }
+        ),
+        Err(TechalibError::BadParam(_))
+    ));
+    assert!(matches!(
+        zigzag(
+            &bars.view(),
+            ZigzagThreshold::Atr {
+                period: 14,
+                multiplier: Float::NAN
+            }
+        ),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        bars in vec((-1e3f64..1e3, 0.0f64..1e2, -1.0f64..1.0), 3..200),
+        percent in 0.1f64..20.0,
+        period in 1usize..20,
+        multiplier in 0.1f64..5.0,
+        use_atr in any::<bool>(),
+    ) {
+        prop_assume!(period < bars.len());
+        let high: Vec<Float> = bars.iter().map(|b| 2e3 + b.0 + b.1).collect();
+        let low: Vec<Float> = bars.iter().map(|b| 2e3 + b.0 - b.1).collect();
+        let close: Vec<Float> = bars.iter().map(|b| 2e3 + b.0 + b.1 * b.2).collect();
+        let threshold = if use_atr {
+            ZigzagThreshold::Atr { period, multiplier }
+        } else {
+            ZigzagThreshold::Percent(percent)
+        };
+        let ohlcv = Ohlcv { high: &high, low: &low, close: &close, ..Default::default() };
+        let result = zigzag(&ohlcv, threshold).unwrap();
+        let (pivots, confirmed, direction) = slow_zigzag_threshold(&high, &low, &close, threshold);
+        for (o, expect) in result.pivots.iter().chain(&result.confirmed).chain(&result.direction)
+            .zip(pivots.iter().chain(&confirmed).chain(&direction)) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!(o == expect);
+            }
+        }
+    }
+}
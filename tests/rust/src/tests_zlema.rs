@@ -0,0 +1,124 @@
+use crate::helper::{
+    assert::{approx_eq_float, assert_vec_close},
+    generated::load_generated_csv,
+};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError,
+    indicators::{ema::ema, zlema::zlema},
+    traits::State,
+    types::Float,
+};
+
+fn slow_zlema(data: &[Float], period: usize) -> Vec<Float> {
+    let lag = (period - 1) / 2;
+    let alpha = 2.0 / (period as Float + 1.0);
+    let delagged: Vec<Float> = (lag..data.len())
+        .map(|i| 2.0 * data[i] - data[i - lag])
+        .collect();
+    let mut out = vec![Float::NAN; data.len()];
+    let start = lag + period - 1;
+    out[start] = delagged[..period].iter().sum::<Float>() / period as Float;
+    for i in start + 1..data.len() {
+        out[i] = alpha * delagged[i - lag] + (1.0 - alpha) * out[i - 1];
+    }
+    out
+}
+
+fn no_lookahead_zlema(file_name: &str, period: usize) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_zlema(input, period);
+
+    let output = zlema(&input[0..last_idx], period, None);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate ZLEMA: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        new_state.update(input[last_idx + i]).unwrap();
+        assert!(
+            approx_eq_float(new_state.zlema, expected[last_idx + i], 1e-8),
+            "Next expected {}, but got {}",
+            expected[last_idx + i],
+            new_state.zlema
+        );
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_zlema("sma.csv", 20);
+}
+
+#[test]
+fn no_lookahead_short_period_ok() {
+    no_lookahead_zlema("sma.csv", 2);
+}
+
+#[test]
+fn period_2_is_ema_ok() {
+    let columns = load_generated_csv("sma.csv").unwrap();
+    let input = columns.get("close").unwrap();
+    let result = zlema(input, 2, None).unwrap();
+    let expected = ema(input, 2, None).unwrap();
+    assert_vec_close(&expected.values, &result.values);
+}
+
+#[test]
+fn period_1_err() {
+    let input = vec![1.0; 50];
+    assert!(matches!(
+        zlema(&input, 1, None),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let input = vec![1.0; 13];
+    assert!(matches!(
+        zlema(&input, 10, None),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut input = vec![1.0; 50];
+    input[30] = Float::NAN;
+    assert!(matches!(
+        zlema(&input, 10, None),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e6f64..1e6, 40..200),
+        period in 2usize..20,
+    ) {
+        let result = zlema(&input, period, None).unwrap();
+        let expected = slow_zlema(&input, period);
+        for (o, e) in result.values.iter().zip(&expected) {
+            if e.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - e).abs() <= 1e-6 * e.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::helper::{assert::approx_eq_float, generated::load_generated_csv};
+
+use proptest::{collection::vec, prelude::*};
+use techalib::{
+    errors::TechalibError, indicators::stats::zscore::zscore, traits::State, types::Float,
+};
+
+fn two_pass_moments(window: &[Float]) -> (Float, Float, Float, Float) {
+    let n = window.len() as Float;
+    let mean = window.iter().sum::<Float>() / n;
+    let moment = |p: i32| window.iter().map(|x| (x - mean).powi(p)).sum::<Float>();
+    (mean, moment(2), moment(3), moment(4))
+}
+
+fn slow_zscore(data: &[Float], period: usize) -> Vec<Float> {
+    let mut output = vec![Float::NAN; data.len()];
+    for i in period - 1..data.len() {
+        let (mean, m2, _, _) = two_pass_moments(&data[i + 1 - period..=i]);
+        output[i] = if m2 == 0.0 {
+            0.0
+        } else {
+            (data[i] - mean) / (m2 / period as Float).sqrt()
+        };
+    }
+    output
+}
+
+// The rolling updates lose some relative precision when the variance of the
+// window drops, compared to the two-pass reference
+fn assert_vec_rel_close(expected: &[Float], got: &[Float]) {
+    assert_eq!(expected.len(), got.len());
+    for (i, (e, g)) in expected.iter().zip(got).enumerate() {
+        assert!(
+            (e.is_nan() && g.is_nan()) || (e - g).abs() <= 1e-7 * e.abs().max(1.0),
+            "[{i}] Expected: {e}, got: {g}"
+        );
+    }
+}
+
+fn no_lookahead_zscore(file_name: &str, period: usize) {
+    let columns = load_generated_csv(file_name).unwrap();
+    let input = columns.get("close").unwrap();
+
+    let len = input.len();
+    let next_count = 5;
+    let last_idx = len - (1 + next_count);
+
+    let expected = slow_zscore(input, period);
+
+    let input_prev = &input[0..last_idx];
+    let output = zscore(input_prev, period);
+    assert!(
+        output.is_ok(),
+        "Failed to calculate ZSCORE: {:?}",
+        output.err()
+    );
+    let result = output.unwrap();
+
+    assert_vec_rel_close(&expected[0..last_idx], &result.values);
+
+    let mut new_state = result.state;
+    for i in 0..next_count {
+        let idx = last_idx + i;
+        new_state.update(input[idx]).unwrap();
+        assert!(approx_eq_float(new_state.zscore, expected[idx], 1e-9));
+    }
+}
+
+#[test]
+fn no_lookahead_ok() {
+    no_lookahead_zscore("sma.csv", 20);
+}
+
+#[test]
+fn no_lookahead_min_period_ok() {
+    no_lookahead_zscore("sma.csv", 2);
+}
+
+#[test]
+fn large_offset_ok() {
+    let input: Vec<Float> = (0..10_000)
+        .map(|i| 1e9 + ((i * 7919) % 101) as Float * 0.01)
+        .collect();
+    let result = zscore(&input, 20).unwrap();
+    let expected = slow_zscore(&input, 20);
+    for (o, expect) in result.values.iter().zip(&expected).skip(19) {
+        assert!(
+            (o - expect).abs() <= 1e-5 * expect.abs().max(1.0),
+            "{o} != {expect}"
+        );
+    }
+}
+
+#[test]
+fn constant_ok() {
+    let input = vec![3.0; 50];
+    let result = zscore(&input, 10).unwrap();
+    assert!(result.values[9..].iter().all(|&value| value == 0.0));
+}
+
+#[test]
+fn unexpected_nan_err() {
+    let mut data = [1.0, 2.0, 4.0, 8.0, 16.0].repeat(4);
+    data[12] = Float::NAN;
+    assert!(matches!(
+        zscore(&data, 5),
+        Err(TechalibError::DataNonFinite(_))
+    ));
+}
+
+#[test]
+fn insufficient_data_err() {
+    let data = [1.0, 2.0, 4.0, 8.0, 16.0];
+    assert!(matches!(
+        zscore(&data, 10),
+        Err(TechalibError::InsufficientData)
+    ));
+}
+
+#[test]
+fn bad_period_err() {
+    let data = [1.0, 2.0, 4.0, 8.0, 16.0];
+    assert!(matches!(
+        zscore(&data, 2 - 1),
+        Err(TechalibError::BadParam(_))
+    ));
+}
+
+proptest! {
+    #[test]
+    fn proptest(
+        input in vec(-1e3f64..1e3, 1..200),
+        period in 2usize..30,
+    ) {
+        prop_assume!(period < input.len());
+        let result = zscore(&input, period).unwrap();
+        let expected = slow_zscore(&input, period);
+        for (o, expect) in result.values.iter().zip(&expected) {
+            if expect.is_nan() {
+                prop_assert!(o.is_nan());
+            } else {
+                prop_assert!((o - expect).abs() <= 1e-6 * expect.abs().max(1.0));
+            }
+        }
+    }
+}
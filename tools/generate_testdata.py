@@ -1,5 +1,5 @@
 import csv
-from typing import List
+from typing import Callable, List, Optional
 import numpy as np
 import pandas as pd
 import talib
@@ -13,13 +13,24 @@ DATA_DIR.mkdir(parents=True, exist_ok=True)
 RAND = np.random.default_rng(seed=42)
 
 class Configuration():
-    def __init__(self, module: object, fct_name: str, input_names: List[str], parameters: dict, output_names: List[str], sample_size: int = 1000):
+    def __init__(self, module: object, fct_name: str, input_names: List[str], parameters: dict, output_names: List[str], sample_size: int = 1000, prepare: Optional[Callable[[pd.DataFrame], pd.DataFrame]] = None, round_inputs: bool = False):
         self.module = module
         self.fct_name = fct_name
         self.input_names = input_names
         self.parameters = parameters
         self.output_names = output_names
         self.sample_size = sample_size
+        self.prepare = prepare
+        self.round_inputs = round_inputs
+
+def to_unit_range(data: pd.DataFrame) -> pd.DataFrame:
+    """Maps the close prices into [-1, 1], the domain of ACOS and ASIN."""
+    return data.assign(close=np.sin(data["close"]))
+
+def with_periods(data: pd.DataFrame) -> pd.DataFrame:
+    """Adds the integer `periods` column used by MAVP, spanning beyond its clamps."""
+    rng = np.random.default_rng(seed=42)
+    return data.assign(periods=rng.integers(1, 40, size=len(data)).astype(float))
 
 CONFIG_DICT = {
     "EMA": Configuration(talib, "EMA", ["close"], dict(timeperiod=30), ["out"]),
@@ -33,11 +44,48 @@ CONFIG_DICT = {
     "TRIMA": Configuration(talib, "TRIMA", ["close"], dict(timeperiod=30), ["out"]),
     "T3": Configuration(talib, "T3", ["close"], dict(timeperiod=20, vfactor=0.7), ["out"]),
     "KAMA": Configuration(talib, "KAMA", ["close"], dict(timeperiod=30), ["out"]),
+    "ACOS": Configuration(talib, "ACOS", ["close"], dict(), ["out"], prepare=to_unit_range, round_inputs=True),
+    "ASIN": Configuration(talib, "ASIN", ["close"], dict(), ["out"], prepare=to_unit_range, round_inputs=True),
+    "ATAN": Configuration(talib, "ATAN", ["close"], dict(), ["out"], round_inputs=True),
+    "CEIL": Configuration(talib, "CEIL", ["close"], dict(), ["out"], round_inputs=True),
+    "COS": Configuration(talib, "COS", ["close"], dict(), ["out"], round_inputs=True),
+    "COSH": Configuration(talib, "COSH", ["close"], dict(), ["out"], round_inputs=True),
+    "EXP": Configuration(talib, "EXP", ["close"], dict(), ["out"], round_inputs=True),
+    "FLOOR": Configuration(talib, "FLOOR", ["close"], dict(), ["out"], round_inputs=True),
+    "LN": Configuration(talib, "LN", ["close"], dict(), ["out"], round_inputs=True),
+    "LOG10": Configuration(talib, "LOG10", ["close"], dict(), ["out"], round_inputs=True),
+    "SIN": Configuration(talib, "SIN", ["close"], dict(), ["out"], round_inputs=True),
+    "SINH": Configuration(talib, "SINH", ["close"], dict(), ["out"], round_inputs=True),
+    "SQRT": Configuration(talib, "SQRT", ["close"], dict(), ["out"], round_inputs=True),
+    "TAN": Configuration(talib, "TAN", ["close"], dict(), ["out"], round_inputs=True),
+    "TANH": Configuration(talib, "TANH", ["close"], dict(), ["out"], round_inputs=True),
+    "ADD": Configuration(talib, "ADD", ["high", "low"], dict(), ["out"], round_inputs=True),
+    "SUB": Configuration(talib, "SUB", ["high", "low"], dict(), ["out"], round_inputs=True),
+    "MULT": Configuration(talib, "MULT", ["high", "low"], dict(), ["out"], round_inputs=True),
+    "DIV": Configuration(talib, "DIV", ["high", "low"], dict(), ["out"], round_inputs=True),
+    "MAX": Configuration(talib, "MAX", ["close"], dict(timeperiod=30), ["out"], round_inputs=True),
+    "MIN": Configuration(talib, "MIN", ["close"], dict(timeperiod=30), ["out"], round_inputs=True),
+    "MAXINDEX": Configuration(talib, "MAXINDEX", ["close"], dict(timeperiod=30), ["out"], round_inputs=True),
+    "MININDEX": Configuration(talib, "MININDEX", ["close"], dict(timeperiod=30), ["out"], round_inputs=True),
+    "MINMAX": Configuration(talib, "MINMAX", ["close"], dict(timeperiod=30), ["min", "max"], round_inputs=True),
+    "SUM": Configuration(talib, "SUM", ["close"], dict(timeperiod=30), ["out"], round_inputs=True),
+    "AVGPRICE": Configuration(talib, "AVGPRICE", ["open", "high", "low", "close"], dict(), ["out"], round_inputs=True),
+    "MEDPRICE": Configuration(talib, "MEDPRICE", ["high", "low"], dict(), ["out"], round_inputs=True),
+    "TYPPRICE": Configuration(talib, "TYPPRICE", ["high", "low", "close"], dict(), ["out"], round_inputs=True),
+    "WCLPRICE": Configuration(talib, "WCLPRICE", ["high", "low", "close"], dict(), ["out"], round_inputs=True),
+    "MAVP": Configuration(talib, "MAVP", ["close", "periods"], dict(minperiod=2, maxperiod=30, matype=0), ["out"], prepare=with_periods, round_inputs=True),
+    "ATR": Configuration(talib, "ATR", ["high", "low", "close"], dict(timeperiod=14), ["out"], round_inputs=True),
 }
 
 def generate_test_data(filename: str, configuration: Configuration, seed: int):
     logger.info(f"📊 ({configuration.fct_name}) Generating test data with parameters: {configuration.parameters}")
     generated_data = ohlcv.random_walk(configuration.sample_size, scale=1.5, start_offset = 50, seed = seed)
+    if configuration.prepare is not None:
+        generated_data = configuration.prepare(generated_data)
+    if configuration.round_inputs:
+        # The inputs are rounded like the CSV, so that the written inputs are exactly the ones the
+        # outputs were computed from. It matters for the transforms which amplify small errors (EXP, TAN).
+        generated_data = generated_data.round(8)
     output_data = getattr(configuration.module, configuration.fct_name).__call__(
         *[generated_data[name].values for name in configuration.input_names],
         **configuration.parameters